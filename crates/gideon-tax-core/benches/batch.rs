@@ -0,0 +1,71 @@
+//! Batch benchmark: computes the spine for a synthetic batch of returns and
+//! reports wall time and heap allocations per return.
+//!
+//! Run with `cargo bench -p gideon-tax-core --bench batch`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use gideon_tax_core::rules::y2025::Rules2025;
+use gideon_tax_core::spine::{ReturnInput, compute_spine};
+use gideon_tax_core::{FilingStatus, Usd};
+
+const BATCH_SIZE: usize = 100_000;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn input(i: usize) -> ReturnInput {
+    let statuses = [
+        FilingStatus::Single,
+        FilingStatus::MarriedFilingJointly,
+        FilingStatus::HeadOfHousehold,
+    ];
+    let wages = (i as i64 % 200) * 1_000;
+    ReturnInput {
+        num_dependents: (i % 3) as u32,
+        w2_wages: Usd::from_dollars(wages),
+        fed_withholding: Usd::from_dollars(wages / 10),
+        ..ReturnInput::new(2025, statuses[i % statuses.len()])
+    }
+}
+
+fn main() {
+    let inputs: Vec<ReturnInput> = (0..BATCH_SIZE).map(input).collect();
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for inp in &inputs {
+        black_box(compute_spine(&Rules2025, black_box(inp)).unwrap());
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+    println!(
+        "compute_spine x {BATCH_SIZE}: {elapsed:?} ({:.0} ns/return), {:.2} allocations/return",
+        elapsed.as_nanos() as f64 / BATCH_SIZE as f64,
+        allocations as f64 / BATCH_SIZE as f64,
+    );
+}
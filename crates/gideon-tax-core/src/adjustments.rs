@@ -0,0 +1,30 @@
+use crate::Usd;
+
+/// Adjustments to income (Schedule 1, Part II).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Adjustments {
+    /// Business expenses of reservists, performing artists, and fee-basis
+    /// government officials, from Form 2106 (line 12).
+    pub business_expenses_reservists: Usd,
+    /// HSA deduction from Form 8889 (line 13).
+    pub hsa_deduction: Usd,
+    /// Moving expenses for members of the Armed Forces, from Form 3903
+    /// (line 14).
+    pub moving_expenses: Usd,
+    /// Deductible traditional IRA contributions (line 20).
+    pub ira_deduction: Usd,
+    /// Excess deductions of section 67(e) expenses from Schedule K-1
+    /// (Form 1041), box 11, code A (line 24k).
+    pub section_67e_excess_deductions: Usd,
+}
+
+impl Adjustments {
+    /// Total adjustments to income (line 26).
+    pub fn total(&self) -> Usd {
+        self.business_expenses_reservists
+            + self.hsa_deduction
+            + self.moving_expenses
+            + self.ira_deduction
+            + self.section_67e_excess_deductions
+    }
+}
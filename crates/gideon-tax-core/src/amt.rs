@@ -0,0 +1,221 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::capital_gain_tax;
+use crate::ledger::{Key, Ledger};
+use crate::minimum_tax_credit::{AmtItem, PreferenceKind};
+use crate::rules::TaxYearRules;
+use crate::spine::ReturnInput;
+use crate::{Rate, Usd};
+
+/// Rate on taxable excess up to
+/// [`amt_rate_threshold`](TaxYearRules::amt_rate_threshold).
+pub const LOWER_RATE: Rate = Rate::from_percent(26);
+
+/// Rate on taxable excess above
+/// [`amt_rate_threshold`](TaxYearRules::amt_rate_threshold).
+pub const UPPER_RATE: Rate = Rate::from_percent(28);
+
+/// One adjustment or preference on Form 6251, Part I.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmtAdjustment {
+    pub item: AmtItem,
+    /// Positive amounts increase AMTI.
+    pub amount: Usd,
+}
+
+/// Form 6251, Alternative Minimum Tax—Individuals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Form6251 {
+    /// Line 4.
+    pub amti: Usd,
+    /// Line 5.
+    pub exemption: Usd,
+    /// Line 6.
+    pub taxable_excess: Usd,
+    /// Line 9.
+    pub tentative_minimum_tax: Usd,
+    /// Line 10.
+    pub regular_tax: Usd,
+    /// Line 11.
+    pub amt: Usd,
+}
+
+/// Computes Form 6251 for the return in `ledger`, with `adjustments`
+/// other than line 2a, which is taken from the return.
+///
+/// Qualified dividends and net capital gain are taxed at the capital gain
+/// rates in Part III, without the 25% rate on unrecaptured section 1250
+/// gain. There is no AMT foreign tax credit. The AMT is not added to the
+/// spine's total tax.
+///
+/// See: <https://www.irs.gov/instructions/i6251>
+pub fn form_6251(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    adjustments: &[AmtAdjustment],
+) -> Form6251 {
+    let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let status = input.filing_status;
+    let agi = get(Key::AGI);
+    let line_2a = if input.itemizes(rules, agi) {
+        input.schedule_a(rules, agi).taxes
+    } else {
+        get(Key::Deductions)
+    };
+    let amti =
+        get(Key::TaxableIncome) + line_2a + adjustments.iter().map(|a| a.amount).sum::<Usd>();
+
+    let phaseout = (amti - rules.amt_exemption_phaseout_start(status)).max(Usd::ZERO)
+        * rules.amt_exemption_phaseout_rate();
+    let exemption = (rules.amt_exemption(status) - phaseout).max(Usd::ZERO);
+    let taxable_excess = (amti - exemption).max(Usd::ZERO);
+    let (qualified_dividends, net_capital_gain) = input.preferential_income(rules);
+    let tentative_minimum_tax = tentative_minimum_tax(
+        rules,
+        status,
+        taxable_excess,
+        qualified_dividends + net_capital_gain,
+        get(Key::TaxableIncome),
+    );
+    let regular_tax = get(Key::RegularTax) + get(Key::ChildInvestmentTax);
+    Form6251 {
+        amti,
+        exemption,
+        taxable_excess,
+        tentative_minimum_tax,
+        regular_tax,
+        amt: (tentative_minimum_tax - regular_tax).max(Usd::ZERO),
+    }
+}
+
+/// Tax on `taxable_excess` (Form 6251, line 7), with `preferential`
+/// qualified dividends and net capital gain taxed as in Part III.
+fn tentative_minimum_tax(
+    rules: &dyn TaxYearRules,
+    status: FilingStatus,
+    taxable_excess: Usd,
+    preferential: Usd,
+    taxable_income: Usd,
+) -> Usd {
+    let threshold = rules.amt_rate_threshold(status);
+    let at_amt_rates = |amount: Usd| {
+        amount.min(threshold) * LOWER_RATE + (amount - threshold).max(Usd::ZERO) * UPPER_RATE
+    };
+    let line_41 = at_amt_rates(taxable_excess);
+    if preferential == Usd::ZERO {
+        return line_41;
+    }
+    let [zero_rate_top, middle_rate_top] = rules.capital_gain_rate_thresholds(status);
+    // Line 5 of the Qualified Dividends and Capital Gain Tax Worksheet.
+    let ordinary_income = (taxable_income - preferential).max(Usd::ZERO);
+    let line_16 = taxable_excess.min(preferential);
+    let line_18 = at_amt_rates(taxable_excess - line_16);
+    let line_21 = (zero_rate_top - ordinary_income).max(Usd::ZERO);
+    let line_23 = line_21.min(line_16);
+    let line_29 = (middle_rate_top - (line_21 + ordinary_income)).max(Usd::ZERO);
+    let line_30 = (line_16 - line_23).min(line_29);
+    let line_33 = line_16 - line_23 - line_30;
+    let line_40 =
+        line_18 + line_30 * capital_gain_tax::MIDDLE_RATE + line_33 * capital_gain_tax::UPPER_RATE;
+    line_40.min(line_41)
+}
+
+/// AMT figured with only exclusion items (Form 8801, Part I), for the
+/// part of the AMT that generates the minimum tax credit.
+pub fn exclusion_items_amt(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    adjustments: &[AmtAdjustment],
+) -> Usd {
+    let exclusion: Vec<AmtAdjustment> = adjustments
+        .iter()
+        .filter(|a| a.item.kind() == PreferenceKind::Exclusion)
+        .copied()
+        .collect();
+    form_6251(rules, input, ledger, &exclusion).amt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Owner;
+    use crate::passthrough::{EntityKind, LossLimits, PassthroughItems};
+    use crate::rules::y2025::Rules2025;
+    use crate::rules::y2026::Rules2026Projected;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn standard_deduction_added_back() {
+        let inp = input(100_000, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let f = form_6251(&Rules2025, &inp, &ledger, &[]);
+        assert_eq!(f.amti, Usd::from_dollars(100_000));
+        assert_eq!(f.taxable_excess, Usd::from_dollars(11_900));
+        assert_eq!(f.tentative_minimum_tax, Usd::from_dollars(3_094));
+        assert_eq!(f.amt, Usd::ZERO);
+    }
+
+    #[test]
+    fn capital_gain_taxed_at_capital_gain_rates() {
+        let mut inp = input(0, 0);
+        inp.passthroughs.push(PassthroughItems {
+            entity_name: "Acme LP".to_string(),
+            entity_ein: "12-3456789".to_string(),
+            kind: EntityKind::Partnership,
+            owner: Owner::Taxpayer,
+            material_participation: false,
+            ordinary_income: Usd::ZERO,
+            rental_income: Usd::ZERO,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: Usd::ZERO,
+            section_179_deduction: Usd::ZERO,
+            interest: Usd::ZERO,
+            ordinary_dividends: Usd::ZERO,
+            qualified_dividends: Usd::ZERO,
+            net_short_term_capital_gain: Usd::ZERO,
+            net_long_term_capital_gain: Usd::from_dollars(500_000),
+            self_employment_earnings: Usd::ZERO,
+            qualified_business_income: Usd::ZERO,
+            loss_limits: LossLimits::default(),
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let f = form_6251(&Rules2025, &inp, &ledger, &[]);
+        assert_eq!(f.taxable_excess, Usd::from_dollars(411_900));
+        // 48,350 at 0% and the rest at 15%, below 26% and 28% on all of it.
+        assert_eq!(
+            f.tentative_minimum_tax,
+            Usd::from_dollars(411_900 - 48_350) * capital_gain_tax::MIDDLE_RATE
+        );
+        assert_eq!(f.amt, Usd::ZERO);
+    }
+
+    #[test]
+    fn exemption_phases_out() {
+        let inp = input(1_000_000, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let f = form_6251(&Rules2025, &inp, &ledger, &[]);
+        // 25% of 373,650 exceeds the 88,100 exemption.
+        assert_eq!(f.exemption, Usd::ZERO);
+        assert_eq!(
+            f.tentative_minimum_tax,
+            Usd::from_dollars(239_100) * LOWER_RATE + Usd::from_dollars(760_900) * UPPER_RATE
+        );
+    }
+
+    #[test]
+    fn exemption_phases_out_at_half_in_2026() {
+        let inp = ReturnInput {
+            tax_year: 2026,
+            ..input(600_000, 0)
+        };
+        let ledger = compute_spine(&Rules2026Projected, &inp).unwrap();
+        let f = form_6251(&Rules2026Projected, &inp, &ledger, &[]);
+        assert_eq!(f.amti, Usd::from_dollars(600_000));
+        // 50% of the 100,000 above 500,000 comes off the 90,100 exemption.
+        assert_eq!(f.exemption, Usd::from_dollars(40_100));
+        assert_eq!(f.taxable_excess, Usd::from_dollars(559_900));
+    }
+}
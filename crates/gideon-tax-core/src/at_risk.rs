@@ -0,0 +1,119 @@
+use crate::Usd;
+
+/// An owner's amount at risk in one activity (Form 6198).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AtRiskAmount {
+    /// Amount at risk at the start of the year.
+    pub amount: Usd,
+    /// Losses disallowed in prior years for lack of amounts at risk, carried
+    /// forward indefinitely (IRC §465(a)(2)).
+    pub suspended_losses: Usd,
+}
+
+/// The year's items that change the amount at risk, in the order Form 6198
+/// applies them. All amounts are positive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AtRiskAdjustments {
+    /// Income from the activity (Part I, lines 1–4, if positive).
+    pub income: Usd,
+    /// Money and adjusted basis of property contributed (line 16).
+    pub contributions: Usd,
+    /// Increases in amounts borrowed for which the owner is personally
+    /// liable, and qualified nonrecourse financing for real property
+    /// (line 17).
+    pub borrowed: Usd,
+    /// Money and property withdrawn, and decreases in amounts borrowed
+    /// (line 18).
+    pub withdrawals: Usd,
+    /// The year's loss from the activity (Part I, line 5).
+    pub losses: Usd,
+}
+
+/// Result of applying a year's items to an amount at risk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AtRiskLimitation {
+    /// Amount at risk available for the year's losses (line 19b).
+    pub available: Usd,
+    /// Current-year and carried-forward losses allowed (line 21). The
+    /// allowed loss is still subject to the passive activity rules.
+    pub allowed_losses: Usd,
+    /// Amount by which withdrawals took the amount at risk below zero,
+    /// included in income (IRC §465(e)).
+    pub recapture: Usd,
+    /// Amount at risk and suspended losses carried to next year.
+    pub ending: AtRiskAmount,
+}
+
+impl AtRiskAmount {
+    /// Applies a year's items: income, contributions, and borrowing increase
+    /// the amount at risk, withdrawals reduce it, and losses (current-year
+    /// plus suspended) are allowed up to what remains, with the rest
+    /// suspended.
+    ///
+    /// Recapture is not limited to losses allowed in prior years.
+    ///
+    /// See: <https://www.irs.gov/instructions/i6198>
+    pub fn apply(self, adj: &AtRiskAdjustments) -> AtRiskLimitation {
+        let before_withdrawals = self.amount + adj.income + adj.contributions + adj.borrowed;
+        let recapture = (adj.withdrawals - before_withdrawals).max(Usd::ZERO);
+        let available = (before_withdrawals - adj.withdrawals).max(Usd::ZERO);
+
+        let losses = adj.losses + self.suspended_losses;
+        let allowed_losses = losses.min(available);
+
+        AtRiskLimitation {
+            available,
+            allowed_losses,
+            recapture,
+            ending: AtRiskAmount {
+                amount: available - allowed_losses,
+                suspended_losses: losses - allowed_losses,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loss_beyond_amount_at_risk_suspended() {
+        let start = AtRiskAmount {
+            amount: Usd::from_dollars(10_000),
+            suspended_losses: Usd::from_dollars(2_000),
+        };
+        let r = start.apply(&AtRiskAdjustments {
+            contributions: Usd::from_dollars(3_000),
+            withdrawals: Usd::from_dollars(1_000),
+            losses: Usd::from_dollars(18_000),
+            ..Default::default()
+        });
+        assert_eq!(r.available, Usd::from_dollars(12_000));
+        assert_eq!(r.allowed_losses, Usd::from_dollars(12_000));
+        assert_eq!(r.ending.amount, Usd::ZERO);
+        assert_eq!(r.ending.suspended_losses, Usd::from_dollars(8_000));
+
+        // Next year's income frees the suspended losses.
+        let r = r.ending.apply(&AtRiskAdjustments {
+            income: Usd::from_dollars(5_000),
+            ..Default::default()
+        });
+        assert_eq!(r.allowed_losses, Usd::from_dollars(5_000));
+        assert_eq!(r.ending.suspended_losses, Usd::from_dollars(3_000));
+    }
+
+    #[test]
+    fn withdrawals_below_zero_recaptured() {
+        let start = AtRiskAmount {
+            amount: Usd::from_dollars(4_000),
+            ..Default::default()
+        };
+        let r = start.apply(&AtRiskAdjustments {
+            withdrawals: Usd::from_dollars(6_500),
+            ..Default::default()
+        });
+        assert_eq!(r.recapture, Usd::from_dollars(2_500));
+        assert_eq!(r.ending.amount, Usd::ZERO);
+    }
+}
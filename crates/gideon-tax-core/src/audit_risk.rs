@@ -0,0 +1,330 @@
+//! Advisory audit-risk heuristics for a computed return.
+//!
+//! The IRS selects returns with scoring formulas it does not publish. The
+//! heuristics here follow patterns practitioners commonly cite as drawing
+//! attention. A high score is not a finding that anything is wrong; it
+//! points at entries worth documenting well.
+
+use std::fmt;
+
+use crate::Usd;
+use crate::rules::TaxYearRules;
+use crate::spine::{Key, Ledger, ReturnInput};
+
+/// Schedule C loss as a percentage of gross receipts above which the loss
+/// is flagged.
+const LOSS_RATIO_FLAG_PERCENT: i64 = 50;
+
+/// W-2 wages at or above which a Schedule C loss is flagged as offsetting
+/// them.
+const LOSS_AGAINST_WAGES_FLAG: Usd = Usd::from_dollars(100_000);
+
+/// Gross receipts below which a home office deduction is flagged.
+const HOME_OFFICE_RECEIPTS_FLAG: Usd = Usd::from_dollars(25_000);
+
+/// Charitable contributions as a percentage of AGI above which they are
+/// flagged, and the higher percentage that scores as more unusual.
+const CHARITABLE_FLAG_PERCENT: i64 = 10;
+const CHARITABLE_HIGH_PERCENT: i64 = 30;
+
+/// Minimum entered amounts before round numbers are counted, and the share
+/// of them, in percent, that must be whole hundreds to be flagged.
+const ROUND_NUMBER_MIN_AMOUNTS: usize = 4;
+const ROUND_NUMBER_FLAG_PERCENT: usize = 75;
+
+/// A sole proprietorship's Schedule C totals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BusinessActivity {
+    pub name: String,
+    /// Line 1.
+    pub gross_receipts: Usd,
+    /// Line 28, before business use of the home.
+    pub expenses: Usd,
+    /// Line 30, business use of the home.
+    pub home_office: Usd,
+}
+
+impl BusinessActivity {
+    /// Line 31: net profit, or a loss if negative.
+    pub fn net_profit(&self) -> Usd {
+        self.gross_receipts - self.expenses - self.home_office
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RiskLevel {
+    Low,
+    Moderate,
+    High,
+}
+
+/// One heuristic the return matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RiskFinding {
+    /// Stable code, such as `"schedule_c.loss_ratio"`.
+    pub code: String,
+    /// Contribution to the score.
+    pub points: u32,
+    pub explanation: String,
+}
+
+/// A return's audit-risk score and the findings behind it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuditRiskReport {
+    /// Sum of the findings' points, at most 100.
+    pub score: u32,
+    pub level: RiskLevel,
+    /// Highest points first.
+    pub findings: Vec<RiskFinding>,
+}
+
+impl AuditRiskReport {
+    /// Scores the return `ledger` was computed from, with its Schedule C
+    /// `businesses`, which [`ReturnInput`] does not carry.
+    pub fn build(
+        rules: &dyn TaxYearRules,
+        input: &ReturnInput,
+        ledger: &Ledger,
+        businesses: &[BusinessActivity],
+    ) -> Self {
+        let agi = ledger.get(&Key::AGI).copied().unwrap_or(Usd::ZERO);
+        let mut findings = Vec::new();
+        for business in businesses {
+            schedule_c(&mut findings, input, business);
+        }
+        charitable(&mut findings, rules, input, agi);
+        round_numbers(&mut findings, input, businesses);
+        findings.sort_by_key(|f| std::cmp::Reverse(f.points));
+        let score = findings.iter().map(|f| f.points).sum::<u32>().min(100);
+        let level = match score {
+            0..20 => RiskLevel::Low,
+            20..40 => RiskLevel::Moderate,
+            _ => RiskLevel::High,
+        };
+        AuditRiskReport {
+            score,
+            level,
+            findings,
+        }
+    }
+}
+
+fn finding(code: &'static str, points: u32, explanation: String) -> RiskFinding {
+    RiskFinding {
+        code: code.to_string(),
+        points,
+        explanation,
+    }
+}
+
+fn schedule_c(out: &mut Vec<RiskFinding>, input: &ReturnInput, business: &BusinessActivity) {
+    let name = &business.name;
+    let receipts = business.gross_receipts;
+    let loss = -business.net_profit();
+    if loss > Usd::ZERO {
+        if receipts <= Usd::ZERO {
+            out.push(finding(
+                "schedule_c.loss_ratio",
+                20,
+                format!("{name} reports a {loss} loss with no gross receipts"),
+            ));
+        } else if loss * 100 > receipts * LOSS_RATIO_FLAG_PERCENT {
+            out.push(finding(
+                "schedule_c.loss_ratio",
+                15,
+                format!(
+                    "{name}'s {loss} loss is more than {LOSS_RATIO_FLAG_PERCENT}% of its \
+                     {receipts} gross receipts"
+                ),
+            ));
+        }
+        if input.w2_wages >= LOSS_AGAINST_WAGES_FLAG {
+            out.push(finding(
+                "schedule_c.loss_against_wages",
+                10,
+                format!(
+                    "{name}'s loss offsets {} of W-2 wages; losses from an activity not \
+                     engaged in for profit are not deductible (IRC §183)",
+                    input.w2_wages
+                ),
+            ));
+        }
+    }
+    if business.home_office > Usd::ZERO && receipts < HOME_OFFICE_RECEIPTS_FLAG {
+        let profit_before = receipts - business.expenses;
+        let explanation = if business.home_office > profit_before {
+            format!(
+                "{name} deducts a {} home office against {profit_before} of profit; the \
+                 deduction is limited to the business's gross income (IRC §280A(c)(5))",
+                business.home_office
+            )
+        } else {
+            format!(
+                "{name} deducts a {} home office on gross receipts of {receipts}",
+                business.home_office
+            )
+        };
+        out.push(finding(
+            "schedule_c.home_office_low_income",
+            10,
+            explanation,
+        ));
+    }
+}
+
+fn charitable(out: &mut Vec<RiskFinding>, rules: &dyn TaxYearRules, input: &ReturnInput, agi: Usd) {
+    let c = input.itemized_deductions.charitable_contributions;
+    let given = c.sixty + c.fifty + c.thirty + c.twenty;
+    if agi <= Usd::ZERO || given == Usd::ZERO || !input.itemizes(rules, agi) {
+        return;
+    }
+    let points = if given * 100 > agi * CHARITABLE_HIGH_PERCENT {
+        20
+    } else if given * 100 > agi * CHARITABLE_FLAG_PERCENT {
+        10
+    } else {
+        return;
+    };
+    out.push(finding(
+        "charitable.high_to_agi",
+        points,
+        format!(
+            "Charitable contributions of {given} are {}% of AGI {agi}; keep receipts and \
+             acknowledgments for each gift",
+            given.cents() * 100 / agi.cents()
+        ),
+    ));
+}
+
+fn round_numbers(out: &mut Vec<RiskFinding>, input: &ReturnInput, businesses: &[BusinessActivity]) {
+    let d = &input.itemized_deductions;
+    let c = d.charitable_contributions;
+    let amounts: Vec<Usd> = [
+        d.medical_expenses,
+        d.state_and_local_taxes,
+        d.real_estate_taxes,
+        d.personal_property_taxes,
+        d.mortgage_interest,
+        d.investment_interest,
+        c.sixty,
+        c.fifty,
+        c.thirty,
+        c.twenty,
+        d.other,
+    ]
+    .into_iter()
+    .chain(businesses.iter().flat_map(|b| [b.expenses, b.home_office]))
+    .filter(|a| *a != Usd::ZERO)
+    .collect();
+    if amounts.len() < ROUND_NUMBER_MIN_AMOUNTS {
+        return;
+    }
+    let round = amounts.iter().filter(|a| a.cents() % 10_000 == 0).count();
+    if round * 100 >= amounts.len() * ROUND_NUMBER_FLAG_PERCENT {
+        out.push(finding(
+            "round_numbers",
+            10,
+            format!(
+                "{round} of {} deduction and expense amounts are whole hundreds of dollars, \
+                 which suggests estimates rather than records",
+                amounts.len()
+            ),
+        ));
+    }
+}
+
+impl fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Moderate => "moderate",
+            RiskLevel::High => "high",
+        })
+    }
+}
+
+impl fmt::Display for AuditRiskReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Audit risk: {} ({}/100)", self.level, self.score)?;
+        for finding in &self.findings {
+            writeln!(
+                f,
+                "  +{:<3} [{}]: {}",
+                finding.points, finding.code, finding.explanation
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charitable::Contributions;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    fn codes(report: &AuditRiskReport) -> Vec<&str> {
+        report.findings.iter().map(|f| f.code.as_str()).collect()
+    }
+
+    #[test]
+    fn plain_wage_return_is_low_risk() {
+        let inp = input(60_000, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let report = AuditRiskReport::build(&Rules2025, &inp, &ledger, &[]);
+        assert_eq!(report.score, 0);
+        assert_eq!(report.level, RiskLevel::Low);
+        assert_eq!(report.to_string(), "Audit risk: low (0/100)\n");
+    }
+
+    #[test]
+    fn schedule_c_losses_and_home_office() {
+        let inp = input(150_000, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let business = BusinessActivity {
+            name: "Photography".to_string(),
+            gross_receipts: Usd::from_dollars(8_000),
+            expenses: Usd::from_dollars(14_250),
+            home_office: Usd::from_dollars(1_500),
+        };
+        let report = AuditRiskReport::build(&Rules2025, &inp, &ledger, &[business]);
+        assert_eq!(
+            codes(&report),
+            [
+                "schedule_c.loss_ratio",
+                "schedule_c.loss_against_wages",
+                "schedule_c.home_office_low_income",
+            ]
+        );
+        assert_eq!(report.score, 35);
+        assert_eq!(report.level, RiskLevel::Moderate);
+        assert!(report.findings[2].explanation.contains("§280A(c)(5)"));
+    }
+
+    #[test]
+    fn charitable_and_round_numbers() {
+        let mut inp = input(80_000, 0);
+        inp.itemized_deductions.state_and_local_taxes = Usd::from_dollars(4_000);
+        inp.itemized_deductions.real_estate_taxes = Usd::from_dollars(3_000);
+        inp.itemized_deductions.mortgage_interest = Usd::from_cents(912_345);
+        inp.itemized_deductions.charitable_contributions = Contributions {
+            sixty: Usd::from_dollars(25_000),
+            ..Default::default()
+        };
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let report = AuditRiskReport::build(&Rules2025, &inp, &ledger, &[]);
+        assert_eq!(codes(&report), ["charitable.high_to_agi", "round_numbers"]);
+        assert!(
+            report.findings[0]
+                .explanation
+                .starts_with("Charitable contributions of $25000.00 are 31% of AGI")
+        );
+        assert_eq!(report.level, RiskLevel::Moderate);
+    }
+}
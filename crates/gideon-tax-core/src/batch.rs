@@ -0,0 +1,59 @@
+use crate::error::Error;
+use crate::rules::TaxYearRules;
+use crate::spine::{Ledger, ReturnInput, compute_spine};
+
+/// Computes the spine for each of `inputs` under `rules`.
+///
+/// Results are in input order. An error in one return does not stop the
+/// others: each return's error is in its own slot. With the `rayon`
+/// feature, returns are computed in parallel on rayon's global thread pool;
+/// without it, one after another.
+pub fn compute_batch(
+    rules: &(dyn TaxYearRules + Sync),
+    inputs: &[ReturnInput],
+) -> Vec<Result<Ledger, Error>> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        inputs
+            .par_iter()
+            .map(|input| compute_spine(rules, input))
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        inputs
+            .iter()
+            .map(|input| compute_spine(rules, input))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn errors_stay_with_their_return() {
+        let inputs: Vec<ReturnInput> = (0..100)
+            .map(|i| ReturnInput {
+                tax_year: if i == 37 { 2024 } else { 2025 },
+                ..input(1_000 * i, 100 * i)
+            })
+            .collect();
+        let results = compute_batch(&Rules2025, &inputs);
+        assert_eq!(results.len(), inputs.len());
+        for (i, (result, inp)) in results.iter().zip(&inputs).enumerate() {
+            match result {
+                Ok(ledger) => assert_eq!(*ledger, compute_spine(&Rules2025, inp).unwrap()),
+                Err(e) => {
+                    assert_eq!(i, 37);
+                    assert_eq!(e.code(), "year_mismatch");
+                }
+            }
+        }
+        assert!(results[37].is_err());
+    }
+}
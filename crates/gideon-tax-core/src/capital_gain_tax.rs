@@ -0,0 +1,155 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::error::Error;
+use crate::rules::TaxYearRules;
+use crate::spine;
+use crate::{Rate, Usd};
+
+/// Rate on qualified dividends and net capital gain between the two
+/// [`capital_gain_rate_thresholds`](TaxYearRules::capital_gain_rate_thresholds).
+pub const MIDDLE_RATE: Rate = Rate::from_percent(15);
+
+/// Rate on qualified dividends and net capital gain above the upper
+/// threshold.
+pub const UPPER_RATE: Rate = Rate::from_percent(20);
+
+/// Qualified Dividends and Capital Gain Tax Worksheet—Line 16.
+///
+/// See: <https://www.irs.gov/instructions/i1040gi>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CapitalGainTax {
+    /// Line 1.
+    pub taxable_income: Usd,
+    /// Line 2.
+    pub qualified_dividends: Usd,
+    /// Line 3: the smaller of Schedule D, line 15 or 16, if both are gains.
+    pub net_capital_gain: Usd,
+    /// Line 5: taxable income other than qualified dividends and net
+    /// capital gain, taxed at ordinary rates.
+    pub ordinary_income: Usd,
+    /// Line 9: taxed at 0%.
+    pub zero_rate_amount: Usd,
+    /// Line 17: taxed at 15%.
+    pub middle_rate_amount: Usd,
+    /// Line 20: taxed at 20%.
+    pub upper_rate_amount: Usd,
+    /// Line 22.
+    pub tax_on_ordinary_income: Usd,
+    /// Line 23.
+    pub tax_with_preferential_rates: Usd,
+    /// Line 24.
+    pub tax_at_ordinary_rates: Usd,
+    /// Line 25, which goes on Form 1040, line 16.
+    pub tax: Usd,
+}
+
+impl CapitalGainTax {
+    /// Line 4.
+    pub fn preferential_income(&self) -> Usd {
+        self.qualified_dividends + self.net_capital_gain
+    }
+}
+
+/// Figures the tax on `taxable_income` with `qualified_dividends` and
+/// `net_capital_gain` taxed at the capital gain rates.
+///
+/// Returns [`Error::TaxCompute`] if the bracket lookup for line 22 or 24
+/// fails.
+pub fn compute(
+    rules: &dyn TaxYearRules,
+    status: FilingStatus,
+    taxable_income: Usd,
+    qualified_dividends: Usd,
+    net_capital_gain: Usd,
+) -> Result<CapitalGainTax, Error> {
+    let [zero_rate_top, middle_rate_top] = rules.capital_gain_rate_thresholds(status);
+    let preferential = qualified_dividends + net_capital_gain;
+    let ordinary_income = (taxable_income - preferential).max(Usd::ZERO);
+
+    let zero_rate_ceiling = taxable_income.min(zero_rate_top);
+    let zero_rate_amount = zero_rate_ceiling - ordinary_income.min(zero_rate_ceiling);
+    let taxed_preferential = taxable_income.min(preferential);
+    let middle_rate_room =
+        (taxable_income.min(middle_rate_top) - (ordinary_income + zero_rate_amount)).max(Usd::ZERO);
+    let middle_rate_amount = (taxed_preferential - zero_rate_amount).min(middle_rate_room);
+    let upper_rate_amount = taxed_preferential - zero_rate_amount - middle_rate_amount;
+
+    let tax_on_ordinary_income = spine::tax_on(rules, status, ordinary_income)?;
+    let tax_with_preferential_rates =
+        middle_rate_amount * MIDDLE_RATE + upper_rate_amount * UPPER_RATE + tax_on_ordinary_income;
+    let tax_at_ordinary_rates = spine::tax_on(rules, status, taxable_income)?;
+    Ok(CapitalGainTax {
+        taxable_income,
+        qualified_dividends,
+        net_capital_gain,
+        ordinary_income,
+        zero_rate_amount,
+        middle_rate_amount,
+        upper_rate_amount,
+        tax_on_ordinary_income,
+        tax_with_preferential_rates,
+        tax_at_ordinary_rates,
+        tax: tax_with_preferential_rates
+            .min(tax_at_ordinary_rates)
+            .irs_round(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    #[test]
+    fn dividends_split_across_zero_and_fifteen_percent() {
+        let w = compute(
+            &Rules2025,
+            FilingStatus::Single,
+            Usd::from_dollars(54_250),
+            Usd::from_dollars(10_000),
+            Usd::ZERO,
+        )
+        .unwrap();
+        assert_eq!(w.ordinary_income, Usd::from_dollars(44_250));
+        // 48,350 − 44,250 at 0%, the rest at 15%.
+        assert_eq!(w.zero_rate_amount, Usd::from_dollars(4_100));
+        assert_eq!(w.middle_rate_amount, Usd::from_dollars(5_900));
+        assert_eq!(w.upper_rate_amount, Usd::ZERO);
+        assert_eq!(
+            w.tax,
+            (w.tax_on_ordinary_income + Usd::from_dollars(885)).irs_round()
+        );
+        assert!(w.tax < w.tax_at_ordinary_rates);
+    }
+
+    #[test]
+    fn gain_above_the_top_threshold_taxed_at_twenty_percent() {
+        let w = compute(
+            &Rules2025,
+            FilingStatus::Single,
+            Usd::from_dollars(1_000_000),
+            Usd::ZERO,
+            Usd::from_dollars(900_000),
+        )
+        .unwrap();
+        assert_eq!(w.ordinary_income, Usd::from_dollars(100_000));
+        assert_eq!(w.zero_rate_amount, Usd::ZERO);
+        assert_eq!(w.middle_rate_amount, Usd::from_dollars(533_400 - 100_000));
+        assert_eq!(w.upper_rate_amount, Usd::from_dollars(1_000_000 - 533_400));
+    }
+
+    #[test]
+    fn preferential_income_above_taxable_income() {
+        let w = compute(
+            &Rules2025,
+            FilingStatus::Single,
+            Usd::from_dollars(20_000),
+            Usd::ZERO,
+            Usd::from_dollars(30_000),
+        )
+        .unwrap();
+        assert_eq!(w.ordinary_income, Usd::ZERO);
+        assert_eq!(w.zero_rate_amount, Usd::from_dollars(20_000));
+        assert_eq!(w.tax, Usd::ZERO);
+    }
+}
@@ -0,0 +1,152 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+
+/// Most net capital loss deductible against other income, or half for
+/// married filing separately (IRC §1211(b)).
+pub const CAPITAL_LOSS_LIMIT: Usd = Usd::from_dollars(3_000);
+
+/// Capital losses carried over from the prior year, as positive amounts
+/// (Schedule D, lines 6 and 14).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapitalLossCarryover {
+    pub short_term: Usd,
+    pub long_term: Usd,
+}
+
+/// Schedule D totals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScheduleD {
+    /// Net short-term gain or loss after the carryover (line 7).
+    pub net_short_term: Usd,
+    /// Net long-term gain or loss after the carryover (line 15).
+    pub net_long_term: Usd,
+    /// Gain, or loss up to the limit, reported on Form 1040, line 7 (line 16
+    /// or 21).
+    pub capital_gain_or_loss: Usd,
+}
+
+/// Combines short- and long-term gains and losses with the prior-year
+/// carryover.
+///
+/// See: <https://www.irs.gov/instructions/i1040sd>
+pub fn schedule_d(
+    short_term: Usd,
+    long_term: Usd,
+    carryover: &CapitalLossCarryover,
+    filing_status: FilingStatus,
+) -> ScheduleD {
+    let net_short_term = short_term - carryover.short_term;
+    let net_long_term = long_term - carryover.long_term;
+    let net = net_short_term + net_long_term;
+    ScheduleD {
+        net_short_term,
+        net_long_term,
+        capital_gain_or_loss: net.max(Usd::ZERO - loss_limit(filing_status)),
+    }
+}
+
+/// [`CAPITAL_LOSS_LIMIT`] for `filing_status`.
+pub fn loss_limit(filing_status: FilingStatus) -> Usd {
+    if filing_status == FilingStatus::MarriedFilingSeparately {
+        CAPITAL_LOSS_LIMIT.mul_div(1, 2)
+    } else {
+        CAPITAL_LOSS_LIMIT
+    }
+}
+
+impl ScheduleD {
+    /// Capital Loss Carryover Worksheet: the losses not used this year,
+    /// carried to next year's Schedule D. `taxable_income` is Form 1040,
+    /// line 15 before it is limited to zero.
+    pub fn carryover(&self, taxable_income: Usd) -> CapitalLossCarryover {
+        // Lines 2–4
+        let deducted = (Usd::ZERO - self.capital_gain_or_loss).max(Usd::ZERO);
+        let used = deducted.min((taxable_income + deducted).max(Usd::ZERO));
+        let short_term_loss = (Usd::ZERO - self.net_short_term).max(Usd::ZERO);
+        let long_term_loss = (Usd::ZERO - self.net_long_term).max(Usd::ZERO);
+        let short_term_gain = self.net_short_term.max(Usd::ZERO);
+        let long_term_gain = self.net_long_term.max(Usd::ZERO);
+        // Lines 5–8
+        let short_term = (short_term_loss - (used + long_term_gain)).max(Usd::ZERO);
+        // Lines 9–13
+        let used_against_long_term = (used - short_term_loss).max(Usd::ZERO);
+        let long_term =
+            (long_term_loss - (short_term_gain + used_against_long_term)).max(Usd::ZERO);
+        CapitalLossCarryover {
+            short_term,
+            long_term,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loss_limited_and_carried_over() {
+        let carryover = CapitalLossCarryover {
+            short_term: Usd::from_dollars(1_000),
+            long_term: Usd::from_dollars(0),
+        };
+        let s = schedule_d(
+            Usd::from_dollars(-2_000),
+            Usd::from_dollars(-6_000),
+            &carryover,
+            FilingStatus::Single,
+        );
+        assert_eq!(s.net_short_term, Usd::from_dollars(-3_000));
+        assert_eq!(s.capital_gain_or_loss, Usd::from_dollars(-3_000));
+        // The deducted loss comes from short-term losses first.
+        let next = s.carryover(Usd::from_dollars(40_000));
+        assert_eq!(next.short_term, Usd::ZERO);
+        assert_eq!(next.long_term, Usd::from_dollars(6_000));
+    }
+
+    #[test]
+    fn married_filing_separately_half_limit() {
+        let s = schedule_d(
+            Usd::from_dollars(0),
+            Usd::from_dollars(-5_000),
+            &CapitalLossCarryover::default(),
+            FilingStatus::MarriedFilingSeparately,
+        );
+        assert_eq!(s.capital_gain_or_loss, Usd::from_dollars(-1_500));
+    }
+
+    #[test]
+    fn negative_taxable_income_preserves_loss() {
+        let s = schedule_d(
+            Usd::from_dollars(-5_000),
+            Usd::from_dollars(0),
+            &CapitalLossCarryover::default(),
+            FilingStatus::Single,
+        );
+        // Only 1,000 of the 3,000 deduction offset income; the other 2,000
+        // carries over with the excess.
+        let next = s.carryover(Usd::from_dollars(-2_000));
+        assert_eq!(next.short_term, Usd::from_dollars(4_000));
+        assert_eq!(next.long_term, Usd::ZERO);
+    }
+
+    #[test]
+    fn gain_absorbs_carryover() {
+        let carryover = CapitalLossCarryover {
+            short_term: Usd::from_dollars(0),
+            long_term: Usd::from_dollars(4_000),
+        };
+        let s = schedule_d(
+            Usd::from_dollars(0),
+            Usd::from_dollars(10_000),
+            &carryover,
+            FilingStatus::Single,
+        );
+        assert_eq!(s.capital_gain_or_loss, Usd::from_dollars(6_000));
+        assert_eq!(
+            s.carryover(Usd::from_dollars(50_000)),
+            CapitalLossCarryover::default()
+        );
+    }
+}
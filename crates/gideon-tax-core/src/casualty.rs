@@ -0,0 +1,220 @@
+use crate::Usd;
+
+/// Reduction per personal casualty or theft event (IRC §165(h)(1)).
+pub const PER_EVENT_REDUCTION: Usd = Usd::from_dollars(100);
+
+/// One item of property damaged, destroyed, or stolen (Form 4684,
+/// lines 2–9 and 20–27).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CasualtyProperty {
+    pub description: String,
+    /// Cost or other basis (line 2 or 20).
+    pub basis: Usd,
+    /// Insurance or other reimbursement received or expected (line 3
+    /// or 21).
+    pub reimbursement: Usd,
+    /// Fair market value before the casualty or theft (line 5 or 23).
+    pub fmv_before: Usd,
+    /// Fair market value after the casualty or theft (line 6 or 24).
+    pub fmv_after: Usd,
+}
+
+impl CasualtyProperty {
+    /// Gain from reimbursement in excess of basis (line 4 or 22).
+    pub fn gain(&self) -> Usd {
+        (self.reimbursement - self.basis).max(Usd::ZERO)
+    }
+
+    /// Loss after reimbursement (line 9 or 27), using the smaller of basis and
+    /// the decline in value, or basis alone for business property that was
+    /// totally destroyed or stolen.
+    fn loss(&self, whole_basis: bool) -> Usd {
+        if self.gain() > Usd::ZERO {
+            return Usd::ZERO;
+        }
+        let decline = (self.fmv_before - self.fmv_after).max(Usd::ZERO);
+        let loss = if whole_basis {
+            self.basis
+        } else {
+            self.basis.min(decline)
+        };
+        (loss - self.reimbursement).max(Usd::ZERO)
+    }
+}
+
+/// One casualty or theft of personal-use property (Form 4684, Section A).
+/// Each event is reduced by $100 once, however many items it affected.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PersonalCasualty {
+    pub properties: Vec<CasualtyProperty>,
+    /// `true` if attributable to a federally declared disaster. Other
+    /// personal casualty losses are deductible only against personal
+    /// casualty gains (IRC §165(h)(5)).
+    pub federally_declared_disaster: bool,
+}
+
+/// Results of Form 4684, Section A.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PersonalCasualtyResult {
+    /// Gains from reimbursements (line 13).
+    pub gains: Usd,
+    /// Losses after the $100 reduction, limited to gains for losses not
+    /// attributable to a federally declared disaster (line 14).
+    pub losses: Usd,
+    /// Net gain reported on Schedule D (line 15).
+    pub net_gain: Usd,
+    /// Casualty and theft loss deduction after the 10%-of-AGI reduction,
+    /// reported on Schedule A, line 15 (line 18).
+    pub itemized_deduction: Usd,
+}
+
+/// Computes Form 4684, Section A for personal-use property.
+///
+/// Qualified disaster losses under special disaster legislation, which use
+/// a $500 reduction and no AGI floor, are not modeled.
+///
+/// See: <https://www.irs.gov/instructions/i4684>
+pub fn personal_casualty(events: &[PersonalCasualty], agi: Usd) -> PersonalCasualtyResult {
+    let mut gains = Usd::ZERO;
+    let mut disaster_losses = Usd::ZERO;
+    let mut other_losses = Usd::ZERO;
+    for event in events {
+        gains += event.properties.iter().map(CasualtyProperty::gain).sum();
+        // Lines 10–12
+        let loss: Usd = event.properties.iter().map(|p| p.loss(false)).sum();
+        let loss = (loss - PER_EVENT_REDUCTION).max(Usd::ZERO);
+        if event.federally_declared_disaster {
+            disaster_losses += loss;
+        } else {
+            other_losses += loss;
+        }
+    }
+
+    let losses = disaster_losses + other_losses.min(gains);
+    if gains >= losses {
+        return PersonalCasualtyResult {
+            gains,
+            losses,
+            net_gain: gains - losses,
+            itemized_deduction: Usd::ZERO,
+        };
+    }
+    // Lines 16–18
+    let floor = agi.max(Usd::ZERO).mul_div(10, 100);
+    PersonalCasualtyResult {
+        gains,
+        losses,
+        net_gain: Usd::ZERO,
+        itemized_deduction: (losses - gains - floor).max(Usd::ZERO),
+    }
+}
+
+/// Business or income-producing property damaged, destroyed, or stolen
+/// (Form 4684, Section B).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BusinessCasualty {
+    pub property: CasualtyProperty,
+    /// `true` if totally destroyed or stolen, in which case the loss is
+    /// figured from basis alone.
+    pub totally_destroyed: bool,
+    /// `true` if held more than one year (Part II of Section B).
+    pub held_more_than_one_year: bool,
+}
+
+/// Results of Form 4684, Section B, as carried to Form 4797.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BusinessCasualtyResult {
+    /// Net gain or loss from property held one year or less, reported on
+    /// Form 4797, line 14.
+    pub short_term: Usd,
+    /// Net gain or loss from property held more than one year. A net gain
+    /// is reported on Form 4797, line 3, and a net loss on line 14.
+    pub long_term: Usd,
+}
+
+/// Computes Form 4684, Section B for business and income-producing property.
+pub fn business_casualty(casualties: &[BusinessCasualty]) -> BusinessCasualtyResult {
+    let mut result = BusinessCasualtyResult::default();
+    for c in casualties {
+        let net = c.property.gain() - c.property.loss(c.totally_destroyed);
+        if c.held_more_than_one_year {
+            result.long_term += net;
+        } else {
+            result.short_term += net;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(
+        basis: i64,
+        reimbursement: i64,
+        fmv_before: i64,
+        fmv_after: i64,
+    ) -> CasualtyProperty {
+        CasualtyProperty {
+            description: String::new(),
+            basis: Usd::from_dollars(basis),
+            reimbursement: Usd::from_dollars(reimbursement),
+            fmv_before: Usd::from_dollars(fmv_before),
+            fmv_after: Usd::from_dollars(fmv_after),
+        }
+    }
+
+    #[test]
+    fn disaster_loss_reduced_by_100_and_ten_percent_of_agi() {
+        let events = [PersonalCasualty {
+            properties: vec![
+                property(200_000, 120_000, 250_000, 100_000),
+                property(8_000, 0, 5_000, 0),
+            ],
+            federally_declared_disaster: true,
+        }];
+        let r = personal_casualty(&events, Usd::from_dollars(80_000));
+        // (150,000 − 120,000) + 5,000 − 100 = 34,900; less 8,000.
+        assert_eq!(r.losses, Usd::from_dollars(34_900));
+        assert_eq!(r.itemized_deduction, Usd::from_dollars(26_900));
+        assert_eq!(r.net_gain, Usd::ZERO);
+    }
+
+    #[test]
+    fn other_losses_only_offset_gains() {
+        let theft = PersonalCasualty {
+            properties: vec![property(3_000, 0, 2_600, 0)],
+            federally_declared_disaster: false,
+        };
+        let r = personal_casualty(std::slice::from_ref(&theft), Usd::from_dollars(50_000));
+        assert_eq!(r.itemized_deduction, Usd::ZERO);
+
+        let insured = PersonalCasualty {
+            properties: vec![property(1_000, 4_000, 5_000, 0)],
+            federally_declared_disaster: false,
+        };
+        let r = personal_casualty(&[theft, insured], Usd::from_dollars(50_000));
+        assert_eq!(r.gains, Usd::from_dollars(3_000));
+        assert_eq!(r.losses, Usd::from_dollars(2_500));
+        assert_eq!(r.net_gain, Usd::from_dollars(500));
+    }
+
+    #[test]
+    fn destroyed_business_property_uses_basis() {
+        let r = business_casualty(&[
+            BusinessCasualty {
+                property: property(10_000, 2_000, 6_000, 0),
+                totally_destroyed: true,
+                held_more_than_one_year: true,
+            },
+            BusinessCasualty {
+                property: property(1_000, 1_500, 1_200, 0),
+                totally_destroyed: true,
+                held_more_than_one_year: false,
+            },
+        ]);
+        assert_eq!(r.long_term, Usd::from_dollars(-8_000));
+        assert_eq!(r.short_term, Usd::from_dollars(500));
+    }
+}
@@ -0,0 +1,218 @@
+use crate::Usd;
+
+/// Years an unused contribution can be carried forward (IRC §170(d)(1)).
+pub const CARRYOVER_YEARS: i32 = 5;
+
+/// AGI limit that applies to a contribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LimitCategory {
+    /// Cash to 50% limit organizations (IRC §170(b)(1)(G)).
+    Sixty,
+    /// Noncash property other than capital gain property to 50% limit
+    /// organizations.
+    Fifty,
+    /// Contributions to other qualified organizations, and capital gain
+    /// property to 50% limit organizations deducted at fair market value.
+    Thirty,
+    /// Capital gain property to organizations other than 50% limit
+    /// organizations.
+    Twenty,
+}
+
+/// An amount contributed in `year` that was not deductible because of the
+/// AGI limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharitableCarryover {
+    pub category: LimitCategory,
+    /// Tax year of the contribution.
+    pub year: i32,
+    pub amount: Usd,
+}
+
+/// This year's contributions by category.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Contributions {
+    pub sixty: Usd,
+    pub fifty: Usd,
+    pub thirty: Usd,
+    pub twenty: Usd,
+}
+
+impl Contributions {
+    fn get(&self, category: LimitCategory) -> Usd {
+        match category {
+            LimitCategory::Sixty => self.sixty,
+            LimitCategory::Fifty => self.fifty,
+            LimitCategory::Thirty => self.thirty,
+            LimitCategory::Twenty => self.twenty,
+        }
+    }
+}
+
+/// Result of applying the AGI limits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CharitableDeduction {
+    /// Deduction for Schedule A, line 14.
+    pub deduction: Usd,
+    /// Amounts carried to next year, oldest first within each category.
+    pub carryovers: Vec<CharitableCarryover>,
+    /// Carryovers from the fifth prior year that were not used and are
+    /// lost.
+    pub expired: Usd,
+}
+
+/// Applies the AGI limits to this year's contributions and prior
+/// carryovers.
+///
+/// Each category's limit is figured on its total including carryovers.
+/// The allowed amount is taken from this year's contributions first, then
+/// from carryovers oldest first. Contributions reducing the 30% and 20%
+/// limits are counted before the limits apply, as in Worksheet 2 of
+/// Pub. 526. Qualified conservation contributions and the election to
+/// deduct capital gain property at basis are not modeled.
+///
+/// See: <https://www.irs.gov/publications/p526>
+pub fn apply_limits(
+    agi: Usd,
+    tax_year: i32,
+    current: &Contributions,
+    carryovers: &[CharitableCarryover],
+) -> CharitableDeduction {
+    let pct = |p: i64| agi.max(Usd::ZERO).mul_div(p, 100);
+    let total = |category: LimitCategory| {
+        current.get(category)
+            + carryovers
+                .iter()
+                .filter(|c| c.category == category)
+                .map(|c| c.amount)
+                .sum::<Usd>()
+    };
+    let (t60, t50, t30, t20) = (
+        total(LimitCategory::Sixty),
+        total(LimitCategory::Fifty),
+        total(LimitCategory::Thirty),
+        total(LimitCategory::Twenty),
+    );
+    let a50 = t50.min(pct(50));
+    let a60 = t60.min((pct(60) - a50).max(Usd::ZERO));
+    let a30 = t30.min(pct(30)).min((pct(50) - t50 - t60).max(Usd::ZERO));
+    let a20 = t20
+        .min(pct(20))
+        .min((pct(30) - a30).max(Usd::ZERO))
+        .min((pct(50) - t50 - t60 - a30).max(Usd::ZERO));
+
+    let mut result = CharitableDeduction {
+        deduction: a60 + a50 + a30 + a20,
+        ..Default::default()
+    };
+    for (category, allowed) in [
+        (LimitCategory::Sixty, a60),
+        (LimitCategory::Fifty, a50),
+        (LimitCategory::Thirty, a30),
+        (LimitCategory::Twenty, a20),
+    ] {
+        let mut prior: Vec<_> = carryovers
+            .iter()
+            .filter(|c| c.category == category)
+            .copied()
+            .collect();
+        prior.sort_by_key(|c| c.year);
+        let this_year = CharitableCarryover {
+            category,
+            year: tax_year,
+            amount: current.get(category),
+        };
+        let mut left = allowed;
+        for mut c in std::iter::once(this_year).chain(prior) {
+            let used = c.amount.min(left);
+            left -= used;
+            c.amount -= used;
+            if c.amount == Usd::ZERO {
+                continue;
+            }
+            if tax_year - c.year >= CARRYOVER_YEARS {
+                result.expired += c.amount;
+            } else {
+                result.carryovers.push(c);
+            }
+        }
+    }
+    result.carryovers.sort_by_key(|c| (c.category, c.year));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cash_limited_to_sixty_percent() {
+        let current = Contributions {
+            sixty: Usd::from_dollars(70_000),
+            ..Default::default()
+        };
+        let r = apply_limits(Usd::from_dollars(100_000), 2025, &current, &[]);
+        assert_eq!(r.deduction, Usd::from_dollars(60_000));
+        assert_eq!(
+            r.carryovers,
+            vec![CharitableCarryover {
+                category: LimitCategory::Sixty,
+                year: 2025,
+                amount: Usd::from_dollars(10_000),
+            }]
+        );
+    }
+
+    #[test]
+    fn current_year_first_then_oldest_carryover() {
+        let current = Contributions {
+            thirty: Usd::from_dollars(20_000),
+            ..Default::default()
+        };
+        let carryovers = [
+            CharitableCarryover {
+                category: LimitCategory::Thirty,
+                year: 2022,
+                amount: Usd::from_dollars(5_000),
+            },
+            CharitableCarryover {
+                category: LimitCategory::Thirty,
+                year: 2020,
+                amount: Usd::from_dollars(4_000),
+            },
+        ];
+        let r = apply_limits(Usd::from_dollars(100_000), 2025, &current, &carryovers);
+        // All 29,000 fits under the 30,000 limit.
+        assert_eq!(r.deduction, Usd::from_dollars(29_000));
+        assert!(r.carryovers.is_empty());
+
+        let r = apply_limits(Usd::from_dollars(50_000), 2025, &current, &carryovers);
+        // 15,000 allowed, all from this year; the 2020 carryover expires.
+        assert_eq!(r.deduction, Usd::from_dollars(15_000));
+        assert_eq!(r.expired, Usd::from_dollars(4_000));
+        assert_eq!(
+            r.carryovers
+                .iter()
+                .map(|c| (c.year, c.amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (2022, Usd::from_dollars(5_000)),
+                (2025, Usd::from_dollars(5_000))
+            ]
+        );
+    }
+
+    #[test]
+    fn thirty_percent_reduced_by_fifty_percent_contributions() {
+        let current = Contributions {
+            sixty: Usd::from_dollars(40_000),
+            thirty: Usd::from_dollars(20_000),
+            ..Default::default()
+        };
+        let r = apply_limits(Usd::from_dollars(100_000), 2025, &current, &[]);
+        // 50,000 − 40,000 leaves 10,000 for the 30% category.
+        assert_eq!(r.deduction, Usd::from_dollars(50_000));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::Usd;
+use crate::error::Error;
+use crate::rules::TaxYearRules;
+use crate::spine::{self, Key, ReturnInput};
+
+/// How a filer spreads the same giving over several years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GivingStrategy {
+    /// The same gift every year.
+    Annual,
+    /// `years` years of gifts made together in the first of them, taking
+    /// the standard deduction in the years between.
+    Bunched { years: u32 },
+}
+
+/// One year under a strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GivingYear {
+    pub year: i32,
+    /// Cash contributions made this year.
+    pub contributions: Usd,
+    /// `true` if the return itemizes.
+    pub itemized: bool,
+    pub total_tax: Usd,
+}
+
+/// Total tax over the horizon under one strategy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrategyOutcome {
+    pub strategy: GivingStrategy,
+    pub years: Vec<GivingYear>,
+    pub total_tax: Usd,
+}
+
+/// Annual and bunched giving side by side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BunchingComparison {
+    pub annual: StrategyOutcome,
+    pub bunched: StrategyOutcome,
+    /// Tax saved over the horizon by bunching, negative if bunching costs
+    /// more.
+    pub savings: Usd,
+}
+
+/// Compares giving `annual_gift` in cash every year for `horizon` years
+/// with bunching `bunch_years` years of gifts into one.
+///
+/// Every year repeats `base` under `rules`, replacing its cash
+/// contributions with the strategy's gift, so the comparison is in base-year
+/// dollars. Charitable carryovers are threaded from year to year through
+/// Schedule A and aged one year at each step. When the horizon is not a
+/// multiple of `bunch_years`, the last bunch covers only the years left, so
+/// both strategies give the same total.
+pub fn compare_bunching(
+    rules: &dyn TaxYearRules,
+    base: &ReturnInput,
+    annual_gift: Usd,
+    bunch_years: u32,
+    horizon: u32,
+) -> Result<BunchingComparison, Error> {
+    let annual = outcome(
+        rules,
+        base,
+        GivingStrategy::Annual,
+        &vec![annual_gift; horizon as usize],
+    )?;
+    let bunch_years = bunch_years.max(1);
+    let gifts: Vec<Usd> = (0..horizon)
+        .map(|i| {
+            if i % bunch_years == 0 {
+                annual_gift.mul_div(i64::from(bunch_years.min(horizon - i)), 1)
+            } else {
+                Usd::ZERO
+            }
+        })
+        .collect();
+    let bunched = outcome(
+        rules,
+        base,
+        GivingStrategy::Bunched { years: bunch_years },
+        &gifts,
+    )?;
+    Ok(BunchingComparison {
+        savings: annual.total_tax - bunched.total_tax,
+        annual,
+        bunched,
+    })
+}
+
+fn outcome(
+    rules: &dyn TaxYearRules,
+    base: &ReturnInput,
+    strategy: GivingStrategy,
+    gifts: &[Usd],
+) -> Result<StrategyOutcome, Error> {
+    let first_year = rules.calendar_year();
+    let mut input = base.clone();
+    let mut years = Vec::new();
+    for (year, gift) in (first_year..).zip(gifts.iter().copied()) {
+        input.itemized_deductions.charitable_contributions.sixty = gift;
+        let ledger = spine::compute_spine(rules, &input)?;
+        let agi = ledger.get(&Key::AGI).copied().unwrap_or(Usd::ZERO);
+        years.push(GivingYear {
+            year,
+            contributions: gift,
+            itemized: input.itemizes(rules, agi),
+            total_tax: ledger.get(&Key::TotalTax).copied().unwrap_or(Usd::ZERO),
+        });
+        // Each year is computed as the base year, so carryovers age by
+        // moving their year back rather than the return's forward.
+        let mut carryovers = input.next_charitable_carryovers(rules, &ledger);
+        for c in &mut carryovers {
+            c.year -= 1;
+        }
+        input.itemized_deductions.charitable_carryovers = carryovers;
+    }
+    Ok(StrategyOutcome {
+        strategy,
+        total_tax: years.iter().map(|y| y.total_tax).sum(),
+        years,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+
+    fn base() -> ReturnInput {
+        let mut inp = input(100_000, 0);
+        inp.itemized_deductions.state_and_local_taxes = Usd::from_dollars(10_000);
+        inp.itemized_deductions.mortgage_interest = Usd::from_dollars(3_000);
+        inp
+    }
+
+    #[test]
+    fn bunching_beats_annual_near_standard_deduction() {
+        let c = compare_bunching(&Rules2025, &base(), Usd::from_dollars(5_000), 2, 2).unwrap();
+        // Annual giving itemizes 18,000 twice. Bunching itemizes 23,000 and
+        // then takes the 15,750 standard deduction: 2,750 more deductions,
+        // all at 22%.
+        assert!(c.annual.years.iter().all(|y| y.itemized));
+        assert_eq!(
+            c.bunched
+                .years
+                .iter()
+                .map(|y| y.itemized)
+                .collect::<Vec<_>>(),
+            vec![true, false]
+        );
+        assert_eq!(c.savings, Usd::from_dollars(605));
+        assert_eq!(
+            c.bunched.total_tax,
+            c.bunched.years.iter().map(|y| y.total_tax).sum::<Usd>()
+        );
+    }
+
+    #[test]
+    fn last_bunch_covers_remaining_years() {
+        let c = compare_bunching(&Rules2025, &base(), Usd::from_dollars(5_000), 2, 3).unwrap();
+        assert_eq!(
+            c.bunched
+                .years
+                .iter()
+                .map(|y| (y.year, y.contributions))
+                .collect::<Vec<_>>(),
+            vec![
+                (2025, Usd::from_dollars(10_000)),
+                (2026, Usd::ZERO),
+                (2027, Usd::from_dollars(5_000))
+            ]
+        );
+        assert_eq!(c.annual.years.len(), 3);
+    }
+
+    #[test]
+    fn excess_gift_carries_to_later_years() {
+        let mut inp = base();
+        inp.w2_wages = Usd::from_dollars(20_000);
+        // 30,000 given at once exceeds 60% of AGI; the rest is deducted in
+        // the following years as a carryover.
+        let c = compare_bunching(&Rules2025, &inp, Usd::from_dollars(10_000), 3, 3).unwrap();
+        assert!(c.bunched.years[1].itemized);
+    }
+}
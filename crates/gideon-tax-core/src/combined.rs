@@ -0,0 +1,255 @@
+use crate::Usd;
+use crate::error::Error;
+use crate::ledger::{Key, Ledger};
+use crate::rules::TaxYearRules;
+use crate::spine::{ReturnInput, compute_spine};
+use crate::state::local::{self, LocalInput, LocalKey, LocalLedger, Locality};
+use crate::state::{State, StateInput, StateKey, StateLedger, StateRules, compute_state_spine};
+
+/// A state return to compute alongside the federal one.
+#[derive(Clone, Copy)]
+pub struct StateFiling<'a> {
+    pub rules: &'a dyn StateRules,
+    pub input: &'a StateInput,
+}
+
+/// The federal return and the state and local returns filed with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CombinedReturn {
+    pub federal: Ledger,
+    /// One ledger per state, in the order filed.
+    pub states: Vec<(State, StateLedger)>,
+    /// One ledger per locality, by name, in the order added.
+    pub locals: Vec<(String, LocalLedger)>,
+}
+
+/// Totals across every jurisdiction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CombinedSummary {
+    pub total_tax: Usd,
+    pub total_withholding: Usd,
+    pub total_payments: Usd,
+    /// Refunds due, summed. A refund from one jurisdiction does not pay an
+    /// amount owed to another.
+    pub total_refund: Usd,
+    pub total_owed: Usd,
+}
+
+impl CombinedSummary {
+    /// Refunds less amounts owed.
+    pub fn net(&self) -> Usd {
+        self.total_refund - self.total_owed
+    }
+}
+
+impl CombinedReturn {
+    /// Computes the federal return, then each state return from it.
+    ///
+    /// State income tax withheld and paid in estimates during the year is
+    /// added to the federal Schedule A, line 5a, so `federal_input`'s
+    /// `state_and_local_taxes` should not already include it. The
+    /// deduction is for tax paid, not the year's state liability, so no
+    /// iteration between the returns is needed.
+    pub fn compute(
+        federal_rules: &dyn TaxYearRules,
+        federal_input: &ReturnInput,
+        states: &[StateFiling<'_>],
+    ) -> Result<Self, Error> {
+        let mut federal_input = federal_input.clone();
+        federal_input.itemized_deductions.state_and_local_taxes += states
+            .iter()
+            .map(|s| s.input.withholding + s.input.estimated_payments)
+            .sum();
+        let federal = compute_spine(federal_rules, &federal_input)?;
+        let states = states
+            .iter()
+            .map(|s| {
+                compute_state_spine(s.rules, &federal_input, &federal, s.input)
+                    .map(|ledger| (s.input.state, ledger))
+                    .map_err(|e| Error::InState {
+                        state: s.input.state,
+                        source: Box::new(e),
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(CombinedReturn {
+            federal,
+            states,
+            locals: Vec::new(),
+        })
+    }
+
+    /// Computes a local return and adds it. Local income tax withheld
+    /// should already be on the federal Schedule A, as
+    /// `SourceForms::apply_to` puts it there.
+    pub fn add_local(&mut self, locality: &Locality, input: &LocalInput) {
+        self.locals.push((
+            locality.name.clone(),
+            local::compute_local_tax(locality, input),
+        ));
+    }
+
+    /// The ledger for `state`, if one was filed.
+    pub fn state(&self, state: State) -> Option<&StateLedger> {
+        self.states
+            .iter()
+            .find(|(s, _)| *s == state)
+            .map(|(_, ledger)| ledger)
+    }
+
+    pub fn summary(&self) -> CombinedSummary {
+        let federal = |key| self.federal.get(&key).copied().unwrap_or(Usd::ZERO);
+        let state = |key| -> Usd {
+            self.states
+                .iter()
+                .filter_map(|(_, ledger)| ledger.get(&key))
+                .copied()
+                .sum()
+        };
+        let local = |key| -> Usd {
+            self.locals
+                .iter()
+                .filter_map(|(_, ledger)| ledger.get(&key))
+                .copied()
+                .sum()
+        };
+        CombinedSummary {
+            total_tax: federal(Key::TotalTax)
+                + state(StateKey::TotalTax)
+                + local(LocalKey::TotalTax),
+            total_withholding: federal(Key::Withholding)
+                + state(StateKey::Withholding)
+                + local(LocalKey::Withholding),
+            total_payments: federal(Key::TotalPayments)
+                + state(StateKey::TotalPayments)
+                + local(LocalKey::TotalPayments),
+            total_refund: federal(Key::Refund) + state(StateKey::Refund) + local(LocalKey::Refund),
+            total_owed: federal(Key::AmountOwed)
+                + state(StateKey::AmountOwed)
+                + local(LocalKey::AmountOwed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+    use crate::state::ca::California2025;
+    use crate::state::flat::flat_tax_state;
+    use crate::state::local::LocalityKind;
+    use crate::state::tests::state_input;
+    use crate::{Rate, TaxYear};
+
+    #[test]
+    fn state_tax_paid_feeds_schedule_a() {
+        let mut federal_input = input(60_000, 6_000);
+        federal_input.itemized_deductions.mortgage_interest = Usd::from_dollars(14_500);
+        let ca = state_input(State::CA, 2_000);
+        let combined = CombinedReturn::compute(
+            &Rules2025,
+            &federal_input,
+            &[StateFiling {
+                rules: &California2025,
+                input: &ca,
+            }],
+        )
+        .unwrap();
+        // 14,500 + 2,000 beats the 15,750 standard deduction.
+        assert_eq!(
+            combined.federal[&Key::Deductions],
+            Usd::from_dollars(16_500)
+        );
+        let ca = combined.state(State::CA).unwrap();
+        assert_eq!(ca[&StateKey::Refund], Usd::from_dollars(360));
+
+        let summary = combined.summary();
+        assert_eq!(
+            summary.total_tax,
+            combined.federal[&Key::TotalTax] + ca[&StateKey::TotalTax]
+        );
+        assert_eq!(summary.total_withholding, Usd::from_dollars(8_000));
+        assert_eq!(
+            summary.net(),
+            combined.federal[&Key::Refund] - combined.federal[&Key::AmountOwed]
+                + Usd::from_dollars(360)
+        );
+    }
+
+    #[test]
+    fn refunds_and_amounts_owed_do_not_offset() {
+        let federal_input = input(60_000, 10_000);
+        let il = state_input(State::IL, 0);
+        let combined = CombinedReturn::compute(
+            &Rules2025,
+            &federal_input,
+            &[StateFiling {
+                rules: flat_tax_state(State::IL, TaxYear::Y2025).unwrap(),
+                input: &il,
+            }],
+        )
+        .unwrap();
+        let summary = combined.summary();
+        assert_eq!(summary.total_refund, combined.federal[&Key::Refund]);
+        assert_eq!(summary.total_owed, Usd::from_dollars(2_829));
+    }
+
+    #[test]
+    fn local_returns_join_the_summary() {
+        let mut combined =
+            CombinedReturn::compute(&Rules2025, &input(60_000, 10_000), &[]).unwrap();
+        let before = combined.summary();
+        let city = Locality {
+            state: State::OH,
+            name: "Columbus".to_string(),
+            kind: LocalityKind::Municipality,
+            resident_rate: Rate::from_bps(250),
+            nonresident_rate: Rate::from_bps(250),
+            workplace_credit_share: Rate::from_percent(100),
+            workplace_credit_rate_limit: Rate::from_bps(250),
+        };
+        combined.add_local(
+            &city,
+            &LocalInput {
+                resident: true,
+                income: Usd::from_dollars(60_000),
+                withholding: Usd::from_dollars(1_000),
+                ..Default::default()
+            },
+        );
+        let summary = combined.summary();
+        assert_eq!(
+            summary.total_tax,
+            before.total_tax + Usd::from_dollars(1_500)
+        );
+        assert_eq!(
+            summary.total_withholding,
+            before.total_withholding + Usd::from_dollars(1_000)
+        );
+        assert_eq!(summary.total_owed, Usd::from_dollars(500));
+    }
+
+    #[test]
+    fn state_errors_name_the_state() {
+        let wrong_year = StateInput {
+            tax_year: TaxYear::Y2024,
+            ..state_input(State::CA, 0)
+        };
+        let err = CombinedReturn::compute(
+            &Rules2025,
+            &input(60_000, 0),
+            &[StateFiling {
+                rules: &California2025,
+                input: &wrong_year,
+            }],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            &err,
+            Error::InState { state: State::CA, source } if matches!(**source, Error::YearMismatch { .. })
+        ));
+        assert_eq!(err.code(), "year_mismatch");
+        assert!(err.to_string().starts_with("CA return: tax year mismatch"));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::diagnostics::Diagnostic;
+
+/// Years a credit is banned after a final determination of reckless or
+/// intentional disregard of the rules (IRC §32(k)(1)(B)(ii)).
+pub const RECKLESS_DISREGARD_BAN_YEARS: i32 = 2;
+
+/// Years a credit is banned after a final determination of fraud (IRC
+/// §32(k)(1)(B)(i)).
+pub const FRAUD_BAN_YEARS: i32 = 10;
+
+/// Credits that can't be claimed again after a disallowance without
+/// Form 8862 (IRC §§24(g), 25A(b)(4), 32(k)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisallowableCredit {
+    EarnedIncome,
+    /// Child tax credit, additional child tax credit, and credit for other
+    /// dependents.
+    ChildTax,
+    AmericanOpportunity,
+}
+
+impl DisallowableCredit {
+    fn name(self) -> &'static str {
+        match self {
+            DisallowableCredit::EarnedIncome => "earned income credit",
+            DisallowableCredit::ChildTax => "child tax credit",
+            DisallowableCredit::AmericanOpportunity => "American opportunity credit",
+        }
+    }
+}
+
+/// Why a credit was reduced or disallowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisallowanceReason {
+    /// A math or clerical error; Form 8862 is not required afterward.
+    MathOrClericalError,
+    /// Any other reason, such as failing to prove eligibility in an exam.
+    Other,
+    /// Reckless or intentional disregard of the rules.
+    RecklessDisregard,
+    Fraud,
+}
+
+/// A credit the IRS reduced or disallowed for a tax year after 1996 (or
+/// 2015 for the child tax and American opportunity credits).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorDisallowance {
+    pub credit: DisallowableCredit,
+    pub reason: DisallowanceReason,
+    /// Calendar year of the final determination; any ban covers the tax
+    /// years after it.
+    pub determination_year: i32,
+    /// `true` if the credit has since been allowed on a return filed with
+    /// Form 8862, so the form is not needed again.
+    pub recertified: bool,
+}
+
+/// Whether a credit can be claimed for a tax year.
+///
+/// Ordered from least to most restrictive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CreditEligibility {
+    Allowed,
+    /// Allowed only with Form 8862 attached.
+    RequiresForm8862,
+    /// Not allowed for any tax year through `through_year`.
+    Banned {
+        through_year: i32,
+    },
+}
+
+impl PriorDisallowance {
+    /// Eligibility for `tax_year` given only this disallowance.
+    pub fn eligibility(&self, tax_year: i32) -> CreditEligibility {
+        let ban_years = match self.reason {
+            DisallowanceReason::MathOrClericalError => return CreditEligibility::Allowed,
+            DisallowanceReason::Other => 0,
+            DisallowanceReason::RecklessDisregard => RECKLESS_DISREGARD_BAN_YEARS,
+            DisallowanceReason::Fraud => FRAUD_BAN_YEARS,
+        };
+        let through_year = self.determination_year + ban_years;
+        if tax_year <= through_year && ban_years > 0 {
+            CreditEligibility::Banned { through_year }
+        } else if self.recertified {
+            CreditEligibility::Allowed
+        } else {
+            CreditEligibility::RequiresForm8862
+        }
+    }
+}
+
+/// Most restrictive eligibility for `credit` in `tax_year` across all prior
+/// disallowances.
+///
+/// The exception for an earned income credit claimed without a qualifying
+/// child after a disallowance based only on the child is not modeled.
+///
+/// See: <https://www.irs.gov/instructions/i8862>
+pub fn eligibility(
+    history: &[PriorDisallowance],
+    credit: DisallowableCredit,
+    tax_year: i32,
+) -> CreditEligibility {
+    history
+        .iter()
+        .filter(|d| d.credit == credit)
+        .map(|d| d.eligibility(tax_year))
+        .max()
+        .unwrap_or(CreditEligibility::Allowed)
+}
+
+/// Flags credits claimed in `tax_year` that are banned or need Form 8862.
+pub fn diagnostics(
+    history: &[PriorDisallowance],
+    claimed: &[DisallowableCredit],
+    tax_year: i32,
+    form_8862_attached: bool,
+) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+    for &credit in claimed {
+        match eligibility(history, credit, tax_year) {
+            CreditEligibility::Allowed => {}
+            CreditEligibility::RequiresForm8862 if form_8862_attached => {}
+            CreditEligibility::RequiresForm8862 => out.push(Diagnostic::error(
+                "credit.form_8862_required",
+                format!(
+                    "the {} was disallowed before; attach Form 8862 to claim it",
+                    credit.name()
+                ),
+            )),
+            CreditEligibility::Banned { through_year } => out.push(Diagnostic::error(
+                "credit.banned",
+                format!(
+                    "the {} can't be claimed for tax years through {through_year}",
+                    credit.name()
+                ),
+            )),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disallowed(reason: DisallowanceReason) -> PriorDisallowance {
+        PriorDisallowance {
+            credit: DisallowableCredit::EarnedIncome,
+            reason,
+            determination_year: 2023,
+            recertified: false,
+        }
+    }
+
+    #[test]
+    fn reasons() {
+        use CreditEligibility::*;
+        let d = disallowed(DisallowanceReason::MathOrClericalError);
+        assert_eq!(d.eligibility(2025), Allowed);
+        let d = disallowed(DisallowanceReason::Other);
+        assert_eq!(d.eligibility(2025), RequiresForm8862);
+        let d = disallowed(DisallowanceReason::RecklessDisregard);
+        assert_eq!(d.eligibility(2025), Banned { through_year: 2025 });
+        assert_eq!(d.eligibility(2026), RequiresForm8862);
+        let d = PriorDisallowance {
+            recertified: true,
+            ..disallowed(DisallowanceReason::Fraud)
+        };
+        assert_eq!(d.eligibility(2033), Banned { through_year: 2033 });
+        assert_eq!(d.eligibility(2034), Allowed);
+    }
+
+    #[test]
+    fn most_restrictive_for_credit() {
+        let history = [
+            disallowed(DisallowanceReason::Other),
+            disallowed(DisallowanceReason::RecklessDisregard),
+        ];
+        assert_eq!(
+            eligibility(&history, DisallowableCredit::EarnedIncome, 2024),
+            CreditEligibility::Banned { through_year: 2025 }
+        );
+        assert_eq!(
+            eligibility(&history, DisallowableCredit::ChildTax, 2024),
+            CreditEligibility::Allowed
+        );
+        let claimed = [DisallowableCredit::EarnedIncome];
+        let out = diagnostics(&history[..1], &claimed, 2025, false);
+        assert_eq!(out[0].code, "credit.form_8862_required");
+        assert!(diagnostics(&history[..1], &claimed, 2025, true).is_empty());
+    }
+}
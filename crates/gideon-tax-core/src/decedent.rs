@@ -0,0 +1,249 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::diagnostics::Diagnostic;
+use crate::period::{ShortPeriodReason, TaxPeriod};
+use crate::{Date, Usd};
+
+/// Which filer on the return died during the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeceasedFiler {
+    Taxpayer,
+    Spouse,
+}
+
+/// Who signs a decedent's final return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FinalReturnSigner {
+    /// Surviving spouse filing a joint return with no personal
+    /// representative appointed.
+    SurvivingSpouse,
+    /// Court-appointed or certified executor or administrator.
+    PersonalRepresentative,
+    /// Anyone else claiming the decedent's refund; Form 1310 is required.
+    RefundClaimant,
+}
+
+/// Deceased-taxpayer metadata for a final return.
+///
+/// See: <https://www.irs.gov/publications/p559>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeceasedTaxpayer {
+    pub filer: DeceasedFiler,
+    /// Decedent's name as written in the return header.
+    pub name: String,
+    pub date_of_death: Date,
+    pub signer: FinalReturnSigner,
+    /// `true` if the personal representative elects to deduct medical
+    /// expenses paid from the estate within one year after death on the
+    /// final return instead of the estate's return (IRC §213(c)).
+    pub medical_expense_election: bool,
+}
+
+/// An amount paid or received on a given date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatedAmount {
+    pub date: Date,
+    pub amount: Usd,
+}
+
+/// A cash-basis decedent's income split at the date of death.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IncomeAttribution {
+    /// Received on or before the date of death; reported on the final
+    /// return.
+    pub final_return: Usd,
+    /// Received after death; income in respect of a decedent (IRC §691),
+    /// reported by the estate or beneficiary that receives it.
+    pub income_in_respect_of_decedent: Usd,
+}
+
+impl DeceasedTaxpayer {
+    /// The period the final return covers, or `None` for a full calendar
+    /// year.
+    ///
+    /// A joint return with a surviving spouse, or the surviving taxpayer's
+    /// own return, covers the full year; otherwise the final return ends on
+    /// the date of death.
+    pub fn final_period(&self, filing_status: FilingStatus) -> Option<TaxPeriod> {
+        if self.filer == DeceasedFiler::Spouse
+            || filing_status == FilingStatus::MarriedFilingJointly
+            || self.date_of_death == Date::last_of_year(self.date_of_death.year())
+        {
+            return None;
+        }
+        Some(TaxPeriod {
+            start: Date::first_of_year(self.date_of_death.year()),
+            end: self.date_of_death,
+            short_period: Some(ShortPeriodReason::Death),
+        })
+    }
+
+    /// Splits income items at the date of death.
+    pub fn attribute_income(&self, items: &[DatedAmount]) -> IncomeAttribution {
+        items
+            .iter()
+            .fold(IncomeAttribution::default(), |mut acc, item| {
+                if item.date <= self.date_of_death {
+                    acc.final_return += item.amount;
+                } else {
+                    acc.income_in_respect_of_decedent += item.amount;
+                }
+                acc
+            })
+    }
+
+    /// Medical expenses of the decedent paid after death that may be
+    /// deducted on the final return: those paid within one year after death,
+    /// when the election is made. Expenses paid before death are deducted
+    /// normally.
+    pub fn electable_medical_expenses(&self, payments: &[DatedAmount]) -> Usd {
+        if !self.medical_expense_election {
+            return Usd::ZERO;
+        }
+        let deadline = self.date_of_death.add_years(1);
+        payments
+            .iter()
+            .filter(|p| p.date > self.date_of_death && p.date <= deadline)
+            .map(|p| p.amount)
+            .sum()
+    }
+
+    /// Notation written across the top of Form 1040.
+    pub fn header_notation(&self) -> String {
+        format!("DECEASED {} {}", self.name, self.date_of_death)
+    }
+
+    /// Capacity written next to the signer's signature.
+    pub fn signature_capacity(&self) -> &'static str {
+        match self.signer {
+            FinalReturnSigner::SurvivingSpouse => "Filing as surviving spouse",
+            FinalReturnSigner::PersonalRepresentative => "Personal representative",
+            FinalReturnSigner::RefundClaimant => "Claimant",
+        }
+    }
+
+    /// `true` if Form 1310 must be attached to claim a refund.
+    pub fn requires_form_1310(&self, refund_due: bool) -> bool {
+        refund_due && self.signer == FinalReturnSigner::RefundClaimant
+    }
+
+    /// Flags final-return issues for review.
+    pub fn diagnostics(
+        &self,
+        attribution: &IncomeAttribution,
+        refund_due: bool,
+    ) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        if attribution.income_in_respect_of_decedent > Usd::ZERO {
+            out.push(Diagnostic::warning(
+                "decedent.income_in_respect_of_decedent",
+                format!(
+                    "{} received after {} is income in respect of a decedent; report it on \
+                     the return of the estate or beneficiary that received it, not the final return",
+                    attribution.income_in_respect_of_decedent, self.date_of_death
+                ),
+            ));
+        }
+        if self.requires_form_1310(refund_due) {
+            out.push(Diagnostic::info(
+                "decedent.form_1310_required",
+                "attach Form 1310 to claim the refund due the decedent",
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::new(y, m, day).unwrap()
+    }
+
+    fn decedent(signer: FinalReturnSigner) -> DeceasedTaxpayer {
+        DeceasedTaxpayer {
+            filer: DeceasedFiler::Taxpayer,
+            name: "Jane Doe".to_string(),
+            date_of_death: d(2025, 4, 10),
+            signer,
+            medical_expense_election: true,
+        }
+    }
+
+    fn dated(date: Date, dollars: i64) -> DatedAmount {
+        DatedAmount {
+            date,
+            amount: Usd::from_dollars(dollars),
+        }
+    }
+
+    #[test]
+    fn final_period_ends_on_date_of_death() {
+        let dec = decedent(FinalReturnSigner::PersonalRepresentative);
+        let period = dec.final_period(FilingStatus::Single).unwrap();
+        assert_eq!(period.start, d(2025, 1, 1));
+        assert_eq!(period.end, d(2025, 4, 10));
+        assert_eq!(period.validate(2025), Ok(()));
+    }
+
+    #[test]
+    fn joint_return_covers_full_year() {
+        let dec = decedent(FinalReturnSigner::SurvivingSpouse);
+        assert_eq!(dec.final_period(FilingStatus::MarriedFilingJointly), None);
+        let spouse = DeceasedTaxpayer {
+            filer: DeceasedFiler::Spouse,
+            ..dec
+        };
+        assert_eq!(
+            spouse.final_period(FilingStatus::MarriedFilingSeparately),
+            None
+        );
+    }
+
+    #[test]
+    fn income_split_at_death() {
+        let dec = decedent(FinalReturnSigner::PersonalRepresentative);
+        let split = dec.attribute_income(&[
+            dated(d(2025, 3, 31), 4_000),
+            dated(d(2025, 4, 10), 1_000),
+            dated(d(2025, 4, 30), 4_000),
+        ]);
+        assert_eq!(split.final_return, Usd::from_dollars(5_000));
+        assert_eq!(
+            split.income_in_respect_of_decedent,
+            Usd::from_dollars(4_000)
+        );
+        let diags = dec.diagnostics(&split, false);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].code, "decedent.income_in_respect_of_decedent");
+    }
+
+    #[test]
+    fn medical_expenses_within_one_year() {
+        let mut dec = decedent(FinalReturnSigner::PersonalRepresentative);
+        let payments = [
+            dated(d(2025, 2, 1), 500),
+            dated(d(2025, 6, 1), 2_000),
+            dated(d(2026, 4, 10), 300),
+            dated(d(2026, 4, 11), 700),
+        ];
+        assert_eq!(
+            dec.electable_medical_expenses(&payments),
+            Usd::from_dollars(2_300)
+        );
+        dec.medical_expense_election = false;
+        assert_eq!(dec.electable_medical_expenses(&payments), Usd::ZERO);
+    }
+
+    #[test]
+    fn header_and_signature() {
+        let dec = decedent(FinalReturnSigner::RefundClaimant);
+        assert_eq!(dec.header_notation(), "DECEASED Jane Doe 2025-04-10");
+        assert_eq!(dec.signature_capacity(), "Claimant");
+        assert!(dec.requires_form_1310(true));
+        assert!(!dec.requires_form_1310(false));
+        assert!(!decedent(FinalReturnSigner::SurvivingSpouse).requires_form_1310(true));
+    }
+}
@@ -0,0 +1,282 @@
+//! Assumptions and limitations of a computed return.
+//!
+//! The spine computes the core Form 1040 flow; several provisions are left
+//! to separate stages or not computed at all. [`limitations`] lists the
+//! ones a return may be missing, so an application can disclose what its
+//! result does and does not cover.
+
+use std::fmt;
+
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+use crate::rules::TaxYearRules;
+use crate::spine::{Key, Ledger, ReturnInput};
+
+/// A provision the engine did not evaluate for a return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Provision {
+    /// Form 6251.
+    AlternativeMinimumTax,
+    /// Form 8995-A, above the QBI threshold.
+    QbiDeductionAboveThreshold,
+    /// Form 8959.
+    AdditionalMedicareTax,
+    /// Form 8960.
+    NetInvestmentIncomeTax,
+    /// Child tax credit, education credits, foreign tax credit, and other
+    /// Schedule 3, Part I credits.
+    NonrefundableCredits,
+    /// Earned income credit, additional child tax credit, and refundable
+    /// American opportunity credit.
+    RefundableCredits,
+    /// Estimated tax payments and overpayments applied from the prior year.
+    EstimatedPayments,
+    /// Form 2210.
+    UnderpaymentPenalty,
+    StateAndLocalReturns,
+}
+
+impl Provision {
+    /// Stable machine-readable code, such as `"limitation.amt"`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Provision::AlternativeMinimumTax => "limitation.amt",
+            Provision::QbiDeductionAboveThreshold => "limitation.qbi_above_threshold",
+            Provision::AdditionalMedicareTax => "limitation.additional_medicare_tax",
+            Provision::NetInvestmentIncomeTax => "limitation.net_investment_income_tax",
+            Provision::NonrefundableCredits => "limitation.nonrefundable_credits",
+            Provision::RefundableCredits => "limitation.refundable_credits",
+            Provision::EstimatedPayments => "limitation.estimated_payments",
+            Provision::UnderpaymentPenalty => "limitation.underpayment_penalty",
+            Provision::StateAndLocalReturns => "limitation.state_and_local_returns",
+        }
+    }
+}
+
+/// Stages beyond the spine that the caller ran for the return.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stages {
+    /// [`amt::form_6251`](crate::amt::form_6251), with its AMT added to
+    /// the total tax. The spine leaves the AMT out of [`Key::TotalTax`].
+    pub amt: bool,
+    /// [`estimated_tax::safe_harbor`](crate::estimated_tax::safe_harbor).
+    pub underpayment_penalty: bool,
+    /// [`CombinedReturn`](crate::combined::CombinedReturn) with the
+    /// filer's state and local returns.
+    pub state_and_local: bool,
+}
+
+/// One provision the return does not cover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Limitation {
+    pub provision: Provision,
+    /// Plain-language statement of what was not done, and for this return,
+    /// why it may matter.
+    pub description: String,
+}
+
+impl fmt::Display for Limitation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.provision.code(), self.description)
+    }
+}
+
+/// Threshold for the additional Medicare tax (IRC §3101(b)(2)) and the net
+/// investment income tax (IRC §1411(b)), which are not indexed.
+fn surtax_threshold(status: FilingStatus) -> Usd {
+    match status {
+        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => {
+            Usd::from_dollars(250_000)
+        }
+        FilingStatus::MarriedFilingSeparately => Usd::from_dollars(125_000),
+        _ => Usd::from_dollars(200_000),
+    }
+}
+
+/// Lists the provisions the return in `ledger` does not cover, given the
+/// `stages` run beyond the spine, in [`Provision`] order.
+///
+/// Provisions that depend on particular income, such as the additional
+/// Medicare tax, are listed only when the return has that income. The
+/// rest are listed whenever they were not evaluated, because whether they
+/// apply turns on facts the engine does not collect.
+pub fn limitations(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    stages: Stages,
+) -> Vec<Limitation> {
+    let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let status = input.filing_status;
+    let mut list = Vec::new();
+    let mut push = |provision, description: String| {
+        list.push(Limitation {
+            provision,
+            description,
+        })
+    };
+
+    if !stages.amt {
+        push(
+            Provision::AlternativeMinimumTax,
+            "The alternative minimum tax was not checked, and total tax does not include \
+             any (Schedule 2, line 1)."
+                .into(),
+        );
+    }
+    let qbi: Usd = input
+        .passthroughs
+        .iter()
+        .map(|p| p.qualified_business_income)
+        .sum();
+    let threshold = rules.qbi_threshold(status);
+    if qbi != Usd::ZERO && get(Key::TaxableIncome) + get(Key::QbiDeduction) > threshold {
+        push(
+            Provision::QbiDeductionAboveThreshold,
+            format!(
+                "Taxable income is above the {threshold} QBI threshold; Form 8995-A was not \
+                 computed and no qualified business income deduction was taken."
+            ),
+        );
+    }
+    let self_employment: Usd = input
+        .passthroughs
+        .iter()
+        .map(|p| p.self_employment_earnings)
+        .sum();
+    let surtax_threshold = surtax_threshold(status);
+    if input.w2_wages + self_employment > surtax_threshold {
+        push(
+            Provision::AdditionalMedicareTax,
+            format!(
+                "Wages and self-employment income exceed {surtax_threshold}; the additional \
+                 Medicare tax was not computed."
+            ),
+        );
+    }
+    let investment_income = get(Key::TaxableInterest)
+        + get(Key::OrdinaryDividends)
+        + get(Key::CapitalGainOrLoss).max(Usd::ZERO);
+    if investment_income > Usd::ZERO && get(Key::AGI) > surtax_threshold {
+        push(
+            Provision::NetInvestmentIncomeTax,
+            format!(
+                "AGI exceeds {surtax_threshold} with investment income; the net investment \
+                 income tax was not computed."
+            ),
+        );
+    }
+    push(
+        Provision::NonrefundableCredits,
+        "No nonrefundable credits were computed, such as the child tax credit or education \
+         credits."
+            .into(),
+    );
+    push(
+        Provision::RefundableCredits,
+        "No refundable credits other than the premium tax credit were computed, such as the \
+         earned income credit."
+            .into(),
+    );
+    push(
+        Provision::EstimatedPayments,
+        "Estimated tax payments and prior-year overpayments are not included in payments.".into(),
+    );
+    if !stages.underpayment_penalty && get(Key::AmountOwed) > Usd::ZERO {
+        push(
+            Provision::UnderpaymentPenalty,
+            "Tax is owed and the estimated tax penalty was not checked.".into(),
+        );
+    }
+    if !stages.state_and_local {
+        push(
+            Provision::StateAndLocalReturns,
+            "Only the federal return was computed.".into(),
+        );
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Owner;
+    use crate::passthrough::{EntityKind, LossLimits, PassthroughItems};
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    fn provisions(inp: &ReturnInput, stages: Stages) -> Vec<Provision> {
+        let ledger = compute_spine(&Rules2025, inp).unwrap();
+        limitations(&Rules2025, inp, &ledger, stages)
+            .into_iter()
+            .map(|l| l.provision)
+            .collect()
+    }
+
+    #[test]
+    fn wage_earner_with_refund() {
+        let inp = input(60_000, 10_000);
+        assert_eq!(
+            provisions(&inp, Stages::default()),
+            vec![
+                Provision::AlternativeMinimumTax,
+                Provision::NonrefundableCredits,
+                Provision::RefundableCredits,
+                Provision::EstimatedPayments,
+                Provision::StateAndLocalReturns,
+            ]
+        );
+        let all = Stages {
+            amt: true,
+            underpayment_penalty: true,
+            state_and_local: true,
+        };
+        assert!(!provisions(&inp, all).contains(&Provision::AlternativeMinimumTax));
+    }
+
+    #[test]
+    fn high_earner_with_a_partnership() {
+        let mut inp = input(300_000, 0);
+        inp.passthroughs.push(PassthroughItems {
+            entity_name: "Partners LLC".into(),
+            entity_ein: "12-3456789".into(),
+            kind: EntityKind::Partnership,
+            owner: Owner::Taxpayer,
+            material_participation: true,
+            ordinary_income: Usd::from_dollars(40_000),
+            rental_income: Usd::ZERO,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: Usd::ZERO,
+            section_179_deduction: Usd::ZERO,
+            interest: Usd::from_dollars(1_000),
+            ordinary_dividends: Usd::from_dollars(2_000),
+            qualified_dividends: Usd::from_dollars(2_000),
+            net_short_term_capital_gain: Usd::ZERO,
+            net_long_term_capital_gain: Usd::ZERO,
+            self_employment_earnings: Usd::from_dollars(40_000),
+            qualified_business_income: Usd::from_dollars(40_000),
+            loss_limits: LossLimits::default(),
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let list = limitations(&Rules2025, &inp, &ledger, Stages::default());
+        let found: Vec<Provision> = list.iter().map(|l| l.provision).collect();
+        for p in [
+            Provision::QbiDeductionAboveThreshold,
+            Provision::AdditionalMedicareTax,
+            Provision::NetInvestmentIncomeTax,
+            Provision::UnderpaymentPenalty,
+        ] {
+            assert!(found.contains(&p), "{p:?} missing");
+        }
+        assert_eq!(
+            list[1].to_string(),
+            "limitation.qbi_above_threshold: Taxable income is above the $197300.00 QBI \
+             threshold; Form 8995-A was not computed and no qualified business income \
+             deduction was taken."
+        );
+    }
+}
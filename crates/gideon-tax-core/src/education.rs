@@ -0,0 +1,113 @@
+use crate::Usd;
+
+/// Which education credit expenses are being measured for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EducationCredit {
+    /// American opportunity credit: tuition, fees, and course materials.
+    AmericanOpportunity,
+    /// Lifetime learning credit: tuition and fees paid to the institution.
+    LifetimeLearning,
+}
+
+/// One student's education expenses and assistance for the year.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StudentExpenses {
+    /// Tuition and fees required for enrollment (Form 1098-T, box 1, plus any
+    /// amounts paid that the form does not report).
+    pub tuition_and_fees: Usd,
+    /// Books, supplies, and equipment needed for a course of study but not
+    /// paid to the institution.
+    pub course_materials: Usd,
+    /// Scholarships, grants, and other tax-free educational assistance
+    /// (Form 1098-T, box 5).
+    pub scholarships: Usd,
+    /// Portion of `scholarships` the student elects to include in income so
+    /// that the expenses it would have covered qualify for a credit.
+    pub scholarships_elected_taxable: Usd,
+}
+
+impl StudentExpenses {
+    /// Scholarships excluded from income under IRC §117: the part used for
+    /// tuition, fees, and course materials, less any amount elected taxable.
+    pub fn tax_free_scholarships(&self) -> Usd {
+        let excludable = (self.scholarships - self.scholarships_elected_taxable).max(Usd::ZERO);
+        excludable.min(self.tuition_and_fees + self.course_materials)
+    }
+
+    /// Scholarships included in income (Schedule 1, line 8r).
+    pub fn taxable_scholarships(&self) -> Usd {
+        (self.scholarships.max(Usd::ZERO) - self.tax_free_scholarships()).max(Usd::ZERO)
+    }
+
+    /// Qualified education expenses remaining after tax-free assistance
+    /// (Form 8863, lines 27 and 31).
+    ///
+    /// Tax-free scholarships are applied to tuition and fees first.
+    pub fn adjusted_qualified_expenses(&self, credit: EducationCredit) -> Usd {
+        let expenses = match credit {
+            EducationCredit::AmericanOpportunity => self.tuition_and_fees + self.course_materials,
+            EducationCredit::LifetimeLearning => self.tuition_and_fees,
+        };
+        (expenses - self.tax_free_scholarships()).max(Usd::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn student(tuition: i64, materials: i64, scholarships: i64) -> StudentExpenses {
+        StudentExpenses {
+            tuition_and_fees: Usd::from_dollars(tuition),
+            course_materials: Usd::from_dollars(materials),
+            scholarships: Usd::from_dollars(scholarships),
+            scholarships_elected_taxable: Usd::ZERO,
+        }
+    }
+
+    #[test]
+    fn no_scholarship() {
+        let s = student(8_000, 500, 0);
+        assert_eq!(s.taxable_scholarships(), Usd::ZERO);
+        assert_eq!(
+            s.adjusted_qualified_expenses(EducationCredit::AmericanOpportunity),
+            Usd::from_dollars(8_500)
+        );
+        assert_eq!(
+            s.adjusted_qualified_expenses(EducationCredit::LifetimeLearning),
+            Usd::from_dollars(8_000)
+        );
+    }
+
+    #[test]
+    fn scholarship_exceeding_expenses_is_taxable() {
+        let s = student(8_000, 500, 12_000);
+        assert_eq!(s.tax_free_scholarships(), Usd::from_dollars(8_500));
+        assert_eq!(s.taxable_scholarships(), Usd::from_dollars(3_500));
+        assert_eq!(
+            s.adjusted_qualified_expenses(EducationCredit::AmericanOpportunity),
+            Usd::ZERO
+        );
+    }
+
+    #[test]
+    fn partial_scholarship_reduces_expenses() {
+        let s = student(8_000, 0, 5_000);
+        assert_eq!(s.taxable_scholarships(), Usd::ZERO);
+        assert_eq!(
+            s.adjusted_qualified_expenses(EducationCredit::LifetimeLearning),
+            Usd::from_dollars(3_000)
+        );
+    }
+
+    #[test]
+    fn election_to_include_scholarship_frees_expenses() {
+        let mut s = student(8_000, 0, 8_000);
+        s.scholarships_elected_taxable = Usd::from_dollars(4_000);
+        assert_eq!(s.taxable_scholarships(), Usd::from_dollars(4_000));
+        assert_eq!(
+            s.adjusted_qualified_expenses(EducationCredit::AmericanOpportunity),
+            Usd::from_dollars(4_000)
+        );
+    }
+}
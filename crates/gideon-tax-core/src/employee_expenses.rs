@@ -0,0 +1,238 @@
+use crate::Usd;
+use crate::rules::TaxYearRules;
+
+/// Highest AGI, figured without the deduction, at which a performing
+/// artist qualifies (IRC §62(b)(1)(C)).
+pub const PERFORMING_ARTIST_AGI_LIMIT: Usd = Usd::from_dollars(16_000);
+
+/// Least an employer must pay a performing artist for the employer to
+/// count toward the two required (IRC §62(b)(2)).
+pub const PERFORMING_ARTIST_EMPLOYER_MINIMUM: Usd = Usd::from_dollars(200);
+
+/// Employees who may still deduct unreimbursed business expenses as an
+/// adjustment to income (IRC §62(a)(2)(B)–(E)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EligibleEmployee {
+    /// Member of a reserve component of the Armed Forces traveling more
+    /// than 100 miles from home for reserve service.
+    ArmedForcesReservist,
+    /// Performing artist, qualified if the conditions in
+    /// [`PerformingArtist::qualifies`] are met.
+    PerformingArtist(PerformingArtist),
+    /// State or local government official paid on a fee basis.
+    FeeBasisOfficial,
+}
+
+/// A performing artist's income from performing in the year.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PerformingArtist {
+    /// Wages from each employer for performing-arts services.
+    pub wages_by_employer: Vec<Usd>,
+}
+
+impl PerformingArtist {
+    /// `true` if two or more employers each paid at least $200, expenses
+    /// exceed 10% of performing-arts income, and AGI without the deduction
+    /// is $16,000 or less.
+    pub fn qualifies(&self, expenses: Usd, agi_before_deduction: Usd) -> bool {
+        let employers = self
+            .wages_by_employer
+            .iter()
+            .filter(|w| **w >= PERFORMING_ARTIST_EMPLOYER_MINIMUM)
+            .count();
+        let income: Usd = self.wages_by_employer.iter().copied().sum();
+        employers >= 2
+            && expenses > income.mul_div(10, 100)
+            && agi_before_deduction <= PERFORMING_ARTIST_AGI_LIMIT
+    }
+}
+
+/// Vehicle expenses (Form 2106, Part II).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VehicleExpenses {
+    /// Business miles (line 13).
+    pub business_miles: u32,
+    /// Total miles (line 12).
+    pub total_miles: u32,
+    /// Actual gasoline, repairs, insurance, depreciation, and other costs for
+    /// the year (lines 23–28), or `None` to use the standard mileage rate.
+    pub actual_expenses: Option<Usd>,
+}
+
+impl VehicleExpenses {
+    /// Vehicle expense (line 22 or 29): business miles at the standard
+    /// mileage rate, or actual expenses times the business use percentage.
+    pub fn expense(&self, rules: &dyn TaxYearRules) -> Usd {
+        match self.actual_expenses {
+            None => Usd::from_cents(rules.business_standard_mileage_rate_mills())
+                .mul_div(i64::from(self.business_miles), 10),
+            Some(_) if self.total_miles == 0 => Usd::ZERO,
+            Some(actual) => {
+                actual.mul_div(i64::from(self.business_miles), i64::from(self.total_miles))
+            }
+        }
+    }
+}
+
+/// Form 2106 inputs (Part I).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmployeeBusinessExpenses {
+    pub employee: EligibleEmployee,
+    pub vehicle: Option<VehicleExpenses>,
+    /// Parking fees, tolls, and transportation not involving overnight
+    /// travel or commuting (line 2).
+    pub parking_tolls_transportation: Usd,
+    /// Travel away from home overnight, not including meals (line 3).
+    pub travel: Usd,
+    /// Other business expenses (line 4).
+    pub other: Usd,
+    /// Meals (line 5).
+    pub meals: Usd,
+    /// Reimbursements not reported in Form W-2, box 1, for expenses other
+    /// than meals (line 7, column A).
+    pub reimbursements: Usd,
+    /// Reimbursements not reported in Form W-2, box 1, for meals (line 7,
+    /// column B).
+    pub meal_reimbursements: Usd,
+    /// `true` if subject to the Department of Transportation hours of
+    /// service limits, which raises the deductible share of meals to 80%.
+    pub hours_of_service_limits: bool,
+}
+
+/// Results of Form 2106.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Form2106 {
+    /// Vehicle expense (line 1).
+    pub vehicle_expense: Usd,
+    /// Expenses other than meals after reimbursements (line 8, column A).
+    pub expenses: Usd,
+    /// Meals after reimbursements (line 8, column B).
+    pub meals: Usd,
+    /// Deductible share of meals (line 9).
+    pub deductible_meals: Usd,
+    /// Deduction reported on Schedule 1, line 12, for
+    /// [`Adjustments::business_expenses_reservists`](crate::adjustments::Adjustments::business_expenses_reservists)
+    /// (line 10). Zero for a performing artist who does not qualify.
+    pub deduction: Usd,
+}
+
+impl EmployeeBusinessExpenses {
+    /// Computes Form 2106. `agi_before_deduction` is used only for a
+    /// performing artist.
+    ///
+    /// The per diem limit on a reservist's travel expenses is not applied.
+    ///
+    /// See: <https://www.irs.gov/instructions/i2106>
+    pub fn form_2106(&self, rules: &dyn TaxYearRules, agi_before_deduction: Usd) -> Form2106 {
+        let vehicle_expense = self.vehicle.map_or(Usd::ZERO, |v| v.expense(rules));
+        // Lines 6–8
+        let expenses =
+            (vehicle_expense + self.parking_tolls_transportation + self.travel + self.other
+                - self.reimbursements)
+                .max(Usd::ZERO);
+        let meals = (self.meals - self.meal_reimbursements).max(Usd::ZERO);
+        let deductible_meals = if self.hours_of_service_limits {
+            meals.mul_div(80, 100)
+        } else {
+            meals.mul_div(50, 100)
+        };
+        let total = expenses + deductible_meals;
+        let qualifies = match &self.employee {
+            EligibleEmployee::PerformingArtist(artist) => {
+                artist.qualifies(total, agi_before_deduction)
+            }
+            _ => true,
+        };
+        Form2106 {
+            vehicle_expense,
+            expenses,
+            meals,
+            deductible_meals,
+            deduction: if qualifies { total } else { Usd::ZERO },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2023::Rules2023;
+    use crate::rules::y2025::Rules2025;
+    use crate::rules::y2026::Rules2026Projected;
+
+    fn reservist() -> EmployeeBusinessExpenses {
+        EmployeeBusinessExpenses {
+            employee: EligibleEmployee::ArmedForcesReservist,
+            vehicle: Some(VehicleExpenses {
+                business_miles: 1_000,
+                total_miles: 12_000,
+                actual_expenses: None,
+            }),
+            parking_tolls_transportation: Usd::from_dollars(50),
+            travel: Usd::from_dollars(600),
+            other: Usd::ZERO,
+            meals: Usd::from_dollars(300),
+            reimbursements: Usd::from_dollars(100),
+            meal_reimbursements: Usd::ZERO,
+            hours_of_service_limits: false,
+        }
+    }
+
+    #[test]
+    fn standard_mileage_and_half_of_meals() {
+        let f = reservist().form_2106(&Rules2025, Usd::from_dollars(60_000));
+        // 1,000 miles × $0.70
+        assert_eq!(f.vehicle_expense, Usd::from_dollars(700));
+        assert_eq!(f.expenses, Usd::from_dollars(1_250));
+        assert_eq!(f.deductible_meals, Usd::from_dollars(150));
+        assert_eq!(f.deduction, Usd::from_dollars(1_400));
+    }
+
+    #[test]
+    fn half_cent_rates_round_the_product() {
+        let vehicle = VehicleExpenses {
+            business_miles: 1_001,
+            total_miles: 12_000,
+            actual_expenses: None,
+        };
+        // 1,001 × $0.655 = $655.655
+        assert_eq!(vehicle.expense(&Rules2023), Usd::from_cents(65_566));
+        // 1,001 × $0.725 = $725.725
+        assert_eq!(
+            vehicle.expense(&Rules2026Projected),
+            Usd::from_cents(72_573)
+        );
+    }
+
+    #[test]
+    fn actual_expenses_by_business_use() {
+        let vehicle = VehicleExpenses {
+            business_miles: 3_000,
+            total_miles: 12_000,
+            actual_expenses: Some(Usd::from_dollars(8_000)),
+        };
+        assert_eq!(vehicle.expense(&Rules2025), Usd::from_dollars(2_000));
+    }
+
+    #[test]
+    fn performing_artist_conditions() {
+        let artist = PerformingArtist {
+            wages_by_employer: vec![Usd::from_dollars(6_000), Usd::from_dollars(4_000)],
+        };
+        let expenses = EmployeeBusinessExpenses {
+            employee: EligibleEmployee::PerformingArtist(artist.clone()),
+            ..reservist()
+        };
+        let f = expenses.form_2106(&Rules2025, Usd::from_dollars(12_000));
+        assert_eq!(f.deduction, Usd::from_dollars(1_400));
+        let f = expenses.form_2106(&Rules2025, Usd::from_dollars(16_001));
+        assert_eq!(f.deduction, Usd::ZERO);
+        // Only one employer paid $200 or more.
+        let one_employer = PerformingArtist {
+            wages_by_employer: vec![Usd::from_dollars(14_000), Usd::from_dollars(150)],
+        };
+        assert!(!one_employer.qualifies(Usd::from_dollars(5_000), Usd::from_dollars(12_000)));
+        // 1,000 is not more than 10% of 10,000.
+        assert!(!artist.qualifies(Usd::from_dollars(1_000), Usd::from_dollars(12_000)));
+    }
+}
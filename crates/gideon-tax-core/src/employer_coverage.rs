@@ -0,0 +1,128 @@
+use crate::Usd;
+use crate::rules::TaxYearRules;
+
+/// An employer's offer of health coverage to an employee for one month, as
+/// reported on Form 1095-C, Part II.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmployerOffer {
+    /// `true` if the offer is minimum essential coverage providing minimum
+    /// value.
+    pub minimum_value: bool,
+    /// Employee share of the lowest-cost monthly premium for self-only
+    /// coverage (Form 1095-C, line 15).
+    pub employee_required_contribution: Usd,
+    /// `true` if the employee enrolled in the offered coverage.
+    pub enrolled: bool,
+}
+
+/// Premium tax credit eligibility for one month given any employer offer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PtcEligibility {
+    /// No offer, or the offer is unaffordable or lacks minimum value.
+    Eligible,
+    /// The employee enrolled in employer coverage.
+    EnrolledInEmployerCoverage,
+    /// The offer is affordable and provides minimum value, so the employee
+    /// is ineligible whether or not they enrolled.
+    AffordableOffer,
+}
+
+/// `true` if the annualized required contribution is no more than the
+/// year's applicable percentage of household income.
+///
+/// See: <https://www.irs.gov/instructions/i8962>
+pub fn is_affordable(
+    rules: &dyn TaxYearRules,
+    employee_required_contribution: Usd,
+    household_income: Usd,
+) -> bool {
+    employee_required_contribution * 12
+        <= household_income * rules.employer_coverage_affordability_rate()
+}
+
+/// Determines whether an employee can claim the premium tax credit for a
+/// month in which the employer made `offer`.
+///
+/// Affordability is tested on self-only coverage for the employee; family
+/// members are tested on the cost of family coverage, which Form 1095-C
+/// does not report.
+pub fn employee_ptc_eligibility(
+    rules: &dyn TaxYearRules,
+    offer: Option<&EmployerOffer>,
+    household_income: Usd,
+) -> PtcEligibility {
+    let Some(offer) = offer else {
+        return PtcEligibility::Eligible;
+    };
+    if offer.enrolled {
+        return PtcEligibility::EnrolledInEmployerCoverage;
+    }
+    if offer.minimum_value
+        && is_affordable(
+            rules,
+            offer.employee_required_contribution,
+            household_income,
+        )
+    {
+        return PtcEligibility::AffordableOffer;
+    }
+    PtcEligibility::Eligible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    fn offer(premium: i64) -> EmployerOffer {
+        EmployerOffer {
+            minimum_value: true,
+            employee_required_contribution: Usd::from_dollars(premium),
+            enrolled: false,
+        }
+    }
+
+    #[test]
+    fn affordability_threshold() {
+        // 9.02% of 40,000 = 3,608 per year
+        let income = Usd::from_dollars(40_000);
+        assert!(is_affordable(&Rules2025, Usd::from_cents(30_066), income));
+        assert!(!is_affordable(&Rules2025, Usd::from_cents(30_067), income));
+    }
+
+    #[test]
+    fn no_offer_eligible() {
+        let e = employee_ptc_eligibility(&Rules2025, None, Usd::from_dollars(30_000));
+        assert_eq!(e, PtcEligibility::Eligible);
+    }
+
+    #[test]
+    fn affordable_offer_blocks() {
+        let o = offer(150);
+        let e = employee_ptc_eligibility(&Rules2025, Some(&o), Usd::from_dollars(30_000));
+        assert_eq!(e, PtcEligibility::AffordableOffer);
+    }
+
+    #[test]
+    fn unaffordable_offer_eligible() {
+        let o = offer(300);
+        let e = employee_ptc_eligibility(&Rules2025, Some(&o), Usd::from_dollars(30_000));
+        assert_eq!(e, PtcEligibility::Eligible);
+    }
+
+    #[test]
+    fn no_minimum_value_eligible() {
+        let mut o = offer(50);
+        o.minimum_value = false;
+        let e = employee_ptc_eligibility(&Rules2025, Some(&o), Usd::from_dollars(30_000));
+        assert_eq!(e, PtcEligibility::Eligible);
+    }
+
+    #[test]
+    fn enrollment_blocks() {
+        let mut o = offer(500);
+        o.enrolled = true;
+        let e = employee_ptc_eligibility(&Rules2025, Some(&o), Usd::from_dollars(30_000));
+        assert_eq!(e, PtcEligibility::EnrolledInEmployerCoverage);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::error::Error;
+use crate::estimated_tax::{self, SafeHarborCheck};
+use crate::rules::TaxYearRules;
+use crate::spine::{self, Key, ReturnInput};
+use crate::{Rate, Usd};
+
+/// Optional flat rate for withholding on supplemental wages up to
+/// $1 million a year (Treas. Reg. §31.3402(g)-1(a)(7)(iii)(F)).
+pub const SUPPLEMENTAL_RATE: Rate = Rate::from_percent(22);
+
+/// Mandatory rate on supplemental wages above $1 million a year
+/// (Treas. Reg. §31.3402(g)-1(a)(2)).
+pub const MANDATORY_RATE: Rate = Rate::from_percent(37);
+
+/// Supplemental wages in a year above which the mandatory rate applies.
+pub const MANDATORY_THRESHOLD: Usd = Usd::from_dollars(1_000_000);
+
+/// Restricted stock units vesting on one date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RsuVest {
+    /// Fair market value of the shares on the vesting date, included in
+    /// wages.
+    pub value: Usd,
+    /// Federal income tax withheld on the vest, or `None` to assume
+    /// flat-rate supplemental withholding.
+    pub withheld: Option<Usd>,
+}
+
+/// Tax on a year's RSU vests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsuEstimate {
+    /// Value of all vests, added to wages.
+    pub income: Usd,
+    /// Federal income tax withheld on the vests.
+    pub withholding: Usd,
+    /// Increase in total tax from the vests.
+    pub tax: Usd,
+    /// `tax` per dollar of `income`.
+    pub effective_rate: Rate,
+    /// Tax on the vests not covered by their withholding, negative if
+    /// they are over-withheld.
+    pub shortfall: Usd,
+    /// Estimated tax safe harbors for the year with the vests.
+    pub safe_harbor: SafeHarborCheck,
+}
+
+/// Flat-rate withholding on each of `values`, paid in order, with the
+/// mandatory rate once the year's supplemental wages pass $1 million.
+/// Other supplemental wages paid in the year are not counted.
+pub fn supplemental_withholding(values: &[Usd]) -> Usd {
+    let mut paid = Usd::ZERO;
+    let mut withholding = Usd::ZERO;
+    for &value in values {
+        let at_flat_rate = value.min((MANDATORY_THRESHOLD - paid).max(Usd::ZERO));
+        withholding += at_flat_rate * SUPPLEMENTAL_RATE + (value - at_flat_rate) * MANDATORY_RATE;
+        paid += value;
+    }
+    withholding
+}
+
+/// Estimates tax on `vests` for the return in `input`, whose wages and
+/// withholding exclude them.
+///
+/// The vests are added to wages and their withholding to Form W-2
+/// withholding, and the return is recomputed. The prior year's tax and AGI
+/// feed the safe harbor check as in [`estimated_tax::safe_harbor`].
+pub fn estimate_rsu(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    vests: &[RsuVest],
+    prior_year_tax: Option<Usd>,
+    prior_year_agi: Option<Usd>,
+) -> Result<RsuEstimate, Error> {
+    let income: Usd = vests.iter().map(|v| v.value).sum();
+    let flat_rate: Vec<Usd> = vests
+        .iter()
+        .filter(|v| v.withheld.is_none())
+        .map(|v| v.value)
+        .collect();
+    let withholding =
+        supplemental_withholding(&flat_rate) + vests.iter().filter_map(|v| v.withheld).sum::<Usd>();
+
+    let total_tax =
+        |ledger: &spine::Ledger| ledger.get(&Key::TotalTax).copied().unwrap_or(Usd::ZERO);
+    let without = spine::compute_spine(rules, input)?;
+    let mut with_vests = input.clone();
+    with_vests.w2_wages += income;
+    with_vests.fed_withholding += withholding;
+    let ledger = spine::compute_spine(rules, &with_vests)?;
+
+    let tax = total_tax(&ledger) - total_tax(&without);
+    Ok(RsuEstimate {
+        income,
+        withholding,
+        tax,
+        effective_rate: if income > Usd::ZERO {
+            Rate::from_bps(tax.cents() * 10_000 / income.cents())
+        } else {
+            Rate::ZERO
+        },
+        shortfall: tax - withholding,
+        safe_harbor: estimated_tax::safe_harbor(
+            input.filing_status,
+            &ledger,
+            prior_year_tax,
+            prior_year_agi,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn flat_rate_under_withholds_in_higher_bracket() {
+        // Taxable income of 184,250 without the vest, in the 24% bracket;
+        // the vest stays within it.
+        let vest = RsuVest {
+            value: Usd::from_dollars(10_000),
+            withheld: None,
+        };
+        let estimate =
+            estimate_rsu(&Rules2025, &input(200_000, 40_000), &[vest], None, None).unwrap();
+        assert_eq!(estimate.withholding, Usd::from_dollars(2_200));
+        assert_eq!(estimate.tax, Usd::from_dollars(2_400));
+        assert_eq!(estimate.effective_rate, Rate::from_percent(24));
+        assert_eq!(estimate.shortfall, Usd::from_dollars(200));
+        assert!(!estimate.safe_harbor.penalty_expected);
+    }
+
+    #[test]
+    fn mandatory_rate_above_one_million() {
+        assert_eq!(
+            supplemental_withholding(&[Usd::from_dollars(900_000), Usd::from_dollars(200_000)]),
+            Usd::from_dollars(198_000 + 22_000 + 37_000)
+        );
+    }
+
+    #[test]
+    fn shortfall_feeds_safe_harbor() {
+        let vest = RsuVest {
+            value: Usd::from_dollars(100_000),
+            withheld: Some(Usd::from_dollars(10_000)),
+        };
+        let estimate = estimate_rsu(
+            &Rules2025,
+            &input(150_000, 25_000),
+            &[vest],
+            Some(Usd::from_dollars(40_000)),
+            Some(Usd::from_dollars(160_000)),
+        )
+        .unwrap();
+        assert!(estimate.shortfall > Usd::ZERO);
+        // 110% of the prior year's 40,000 is below 90% of this year's tax.
+        assert_eq!(
+            estimate.safe_harbor.required_annual_payment,
+            Usd::from_dollars(44_000)
+        );
+        assert_eq!(estimate.safe_harbor.shortfall, Usd::from_dollars(9_000));
+        assert!(estimate.safe_harbor.penalty_expected);
+    }
+}
@@ -0,0 +1,135 @@
+//! Errors from computing returns.
+//!
+//! Every fallible computation in the crate returns [`Error`]. Match on
+//! [`Error::code`] rather than the message to show a user-facing message:
+//! codes are stable across releases, while messages and variant fields may
+//! gain detail.
+
+use us_tax_brackets::TaxError;
+
+use crate::interview::Question;
+use crate::ledger::Key;
+use crate::period::PeriodError;
+use crate::state::State;
+
+/// `Result` with [`Error`] as the default error.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input is for a different tax year than the rules.
+    #[error("tax year mismatch: input={input}, rules={rules}")]
+    YearMismatch { input: i32, rules: i32 },
+    /// The input's tax period is not valid for the rules' year.
+    #[error("invalid tax period: {0}")]
+    InvalidPeriod(#[from] PeriodError),
+    /// The bracket lookup for a line failed.
+    #[error("tax computation error on {}: {source}", key.label())]
+    TaxCompute {
+        key: Key,
+        #[source]
+        source: TaxError,
+    },
+    /// A state input was passed to another state's rules.
+    #[error("state mismatch: input={input}, rules={rules}")]
+    StateMismatch { input: State, rules: State },
+    /// The federal ledger lacks a line a state return starts from.
+    #[error("federal return has no {}", .0.label())]
+    MissingFederalLine(Key),
+    /// Years of a portfolio must be added in order with no gaps.
+    #[error("expected tax year {expected}, got {got}")]
+    NotConsecutive { expected: i32, got: i32 },
+    /// An error computing one state's return.
+    #[error("{state} return: {source}")]
+    InState {
+        state: State,
+        #[source]
+        source: Box<Error>,
+    },
+    /// An interview answer is the wrong kind for its question, or is about
+    /// a person not added to the interview.
+    #[error("invalid answer to {0:?}")]
+    InvalidAnswer(Question),
+}
+
+impl Error {
+    /// Stable machine-readable code, such as `"year_mismatch"`. An error in
+    /// a state return has the code of the error inside it.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::YearMismatch { .. } => "year_mismatch",
+            Error::InvalidPeriod(e) => match e {
+                PeriodError::EndBeforeStart => "period.end_before_start",
+                PeriodError::WrongYear { .. } => "period.wrong_year",
+                PeriodError::NotTwelveMonths => "period.not_twelve_months",
+                PeriodError::ShortPeriodTooLong => "period.short_period_too_long",
+            },
+            Error::TaxCompute { .. } => "tax_compute",
+            Error::StateMismatch { .. } => "state_mismatch",
+            Error::MissingFederalLine(_) => "missing_federal_line",
+            Error::NotConsecutive { .. } => "not_consecutive",
+            Error::InState { source, .. } => source.code(),
+            Error::InvalidAnswer(_) => "interview.invalid_answer",
+        }
+    }
+
+    /// The ledger line involved, if any.
+    pub fn key(&self) -> Option<Key> {
+        match self {
+            Error::TaxCompute { key, .. } | Error::MissingFederalLine(key) => Some(*key),
+            Error::InState { source, .. } => source.key(),
+            _ => None,
+        }
+    }
+
+    /// The input field involved, if any, such as `"tax_year"`.
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            Error::YearMismatch { .. } | Error::NotConsecutive { .. } => Some("tax_year"),
+            Error::InvalidPeriod(_) => Some("period"),
+            Error::StateMismatch { .. } => Some("state"),
+            Error::InState { source, .. } => source.field(),
+            _ => None,
+        }
+    }
+
+    /// The state whose return failed, if the error came from one.
+    pub fn state(&self) -> Option<State> {
+        match self {
+            Error::InState { state, .. } => Some(*state),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_errors_keep_the_inner_code() {
+        let e = Error::InState {
+            state: State::CA,
+            source: Box::new(Error::MissingFederalLine(Key::AGI)),
+        };
+        assert_eq!(e.code(), "missing_federal_line");
+        assert_eq!(e.key(), Some(Key::AGI));
+        assert_eq!(e.state(), Some(State::CA));
+        assert_eq!(
+            e.to_string(),
+            "CA return: federal return has no Adjusted gross income"
+        );
+    }
+
+    #[test]
+    fn period_codes_name_the_problem() {
+        let e = Error::from(PeriodError::WrongYear {
+            period_year: 2024,
+            rules_year: 2025,
+        });
+        assert_eq!(e.code(), "period.wrong_year");
+        assert_eq!(e.field(), Some("period"));
+        assert!(std::error::Error::source(&e).is_some());
+    }
+}
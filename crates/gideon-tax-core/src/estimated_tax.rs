@@ -0,0 +1,112 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+use crate::ledger::{Key, Ledger};
+
+/// Tax owed after withholding below which no estimated tax penalty
+/// applies (IRC §6654(e)(1)).
+pub const PENALTY_THRESHOLD: Usd = Usd::from_dollars(1_000);
+
+/// Prior-year AGI above which the prior-year safe harbor is 110% of the
+/// prior year's tax, or half for married filing separately
+/// (IRC §6654(d)(1)(C)).
+pub const HIGH_INCOME_AGI: Usd = Usd::from_dollars(150_000);
+
+/// Whether a year's payments meet the estimated tax safe harbors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SafeHarborCheck {
+    /// Required annual payment: the smaller of 90% of this year's tax and
+    /// 100% or 110% of the prior year's.
+    pub required_annual_payment: Usd,
+    /// Withholding and estimated tax payments.
+    pub payments: Usd,
+    /// Required payment not yet made.
+    pub shortfall: Usd,
+    /// `true` if the shortfall would be penalized.
+    pub penalty_expected: bool,
+}
+
+/// Checks the return in `ledger` against the safe harbors of IRC §6654(d)
+/// and the $1,000 threshold of §6654(e)(1).
+///
+/// Pass `None` for the prior year when no return was filed for it or it
+/// was not a full 12 months, which removes the prior-year safe harbor.
+/// Payments are checked for the year as a whole; the required
+/// installments and their due dates are not.
+///
+/// See: <https://www.irs.gov/instructions/i2210>
+pub fn safe_harbor(
+    filing_status: FilingStatus,
+    ledger: &Ledger,
+    prior_year_tax: Option<Usd>,
+    prior_year_agi: Option<Usd>,
+) -> SafeHarborCheck {
+    let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let tax = get(Key::TotalTax).max(Usd::ZERO);
+    let current_year = tax.mul_div(90, 100);
+    let high_income = if filing_status == FilingStatus::MarriedFilingSeparately {
+        HIGH_INCOME_AGI.mul_div(1, 2)
+    } else {
+        HIGH_INCOME_AGI
+    };
+    let prior_year = prior_year_tax.map(|prior| {
+        if prior_year_agi.is_some_and(|agi| agi > high_income) {
+            prior.mul_div(110, 100)
+        } else {
+            prior
+        }
+    });
+    let required_annual_payment = prior_year.map_or(current_year, |p| p.min(current_year));
+    let withholding = get(Key::Withholding);
+    let payments = withholding + get(Key::EstimatedPayments);
+    let shortfall = (required_annual_payment - payments).max(Usd::ZERO);
+    SafeHarborCheck {
+        required_annual_payment,
+        payments,
+        shortfall,
+        penalty_expected: shortfall > Usd::ZERO && tax - withholding >= PENALTY_THRESHOLD,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(total_tax: i64, withholding: i64) -> Ledger {
+        let mut ledger = Ledger::new();
+        ledger.insert(Key::TotalTax, Usd::from_dollars(total_tax));
+        ledger.insert(Key::Withholding, Usd::from_dollars(withholding));
+        ledger
+    }
+
+    #[test]
+    fn prior_year_safe_harbor() {
+        let check = safe_harbor(
+            FilingStatus::Single,
+            &ledger(30_000, 20_000),
+            Some(Usd::from_dollars(20_000)),
+            Some(Usd::from_dollars(100_000)),
+        );
+        assert_eq!(check.required_annual_payment, Usd::from_dollars(20_000));
+        assert!(!check.penalty_expected);
+
+        // Above 150,000 of AGI, 110% of the prior year's tax is required.
+        let check = safe_harbor(
+            FilingStatus::Single,
+            &ledger(30_000, 20_000),
+            Some(Usd::from_dollars(20_000)),
+            Some(Usd::from_dollars(200_000)),
+        );
+        assert_eq!(check.required_annual_payment, Usd::from_dollars(22_000));
+        assert_eq!(check.shortfall, Usd::from_dollars(2_000));
+        assert!(check.penalty_expected);
+    }
+
+    #[test]
+    fn small_balance_not_penalized() {
+        let check = safe_harbor(FilingStatus::Single, &ledger(5_000, 4_100), None, None);
+        assert_eq!(check.required_annual_payment, Usd::from_dollars(4_500));
+        assert_eq!(check.shortfall, Usd::from_dollars(400));
+        assert!(!check.penalty_expected);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::ledger::{Key, Ledger};
+use crate::{Date, Usd};
+
+/// Failure-to-file penalty per month or part of a month, in basis points of
+/// the unpaid tax (IRC §6651(a)(1)).
+pub const FAILURE_TO_FILE_BPS_PER_MONTH: i64 = 500;
+
+/// Failure-to-pay penalty per month or part of a month, in basis points of
+/// the unpaid tax (IRC §6651(a)(2)).
+pub const FAILURE_TO_PAY_BPS_PER_MONTH: i64 = 50;
+
+/// Most months either penalty accrues: 5 months of failure to file and 50
+/// months of failure to pay, each reaching 25%.
+const MAX_FAILURE_TO_FILE_MONTHS: i64 = 5;
+const MAX_FAILURE_TO_PAY_MONTHS: i64 = 50;
+
+/// Share of the tax that must be paid by the original due date for the
+/// extension to also relieve the failure-to-pay penalty, in basis points
+/// (Treas. Reg. §301.6651-1(c)(3)).
+const EXTENSION_PAYMENT_SAFE_HARBOR_BPS: i64 = 9_000;
+
+/// Form 4868, Application for Automatic Extension of Time To File.
+///
+/// The estimate is a snapshot of the return as known when the extension is
+/// requested; the final return is computed later from complete input, with
+/// the payment entered as [`ReturnInput::extension_payment`](crate::spine::ReturnInput::extension_payment).
+///
+/// See: <https://www.irs.gov/forms-pubs/about-form-4868>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Form4868 {
+    /// Estimate of total tax liability (line 4).
+    pub estimated_total_tax: Usd,
+    /// Total payments (line 5).
+    pub total_payments: Usd,
+    /// Balance due (line 6).
+    pub balance_due: Usd,
+    /// Amount paying with the extension (line 7).
+    pub amount_paying: Usd,
+}
+
+impl Form4868 {
+    /// Fills in the form from the ledger of an estimated return.
+    pub fn from_ledger(estimate: &Ledger, amount_paying: Usd) -> Self {
+        let get = |key: Key| estimate.get(&key).copied().unwrap_or(Usd::ZERO);
+        let estimated_total_tax = get(Key::TotalTax).max(Usd::ZERO);
+        let total_payments = get(Key::TotalPayments) - get(Key::ExtensionPayment);
+        Form4868 {
+            estimated_total_tax,
+            total_payments,
+            balance_due: (estimated_total_tax - total_payments).max(Usd::ZERO),
+            amount_paying,
+        }
+    }
+}
+
+/// Original and extended due dates of a return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DueDates {
+    pub original: Date,
+    pub extended: Date,
+}
+
+impl DueDates {
+    /// April 15 and October 15 after the calendar year. Weekends and legal
+    /// holidays are not taken into account.
+    pub fn calendar_year(year: i32) -> Self {
+        DueDates {
+            original: Date::new(year + 1, 4, 15).expect("valid date"),
+            extended: Date::new(year + 1, 10, 15).expect("valid date"),
+        }
+    }
+}
+
+/// Penalties for filing or paying late.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatePenalties {
+    /// After the reduction for months the failure-to-pay penalty also
+    /// applies (IRC §6651(c)(1)).
+    pub failure_to_file: Usd,
+    pub failure_to_pay: Usd,
+}
+
+impl LatePenalties {
+    pub fn total(&self) -> Usd {
+        self.failure_to_file + self.failure_to_pay
+    }
+}
+
+/// Computes the failure-to-file and failure-to-pay penalties for the final
+/// return in `ledger`, filed on `filed` with the balance paid on `paid`.
+///
+/// With an extension, the failure-to-file penalty runs from the extended
+/// due date. The failure-to-pay penalty runs from the original due date,
+/// unless payments by then were at least 90% of the tax and the balance is
+/// paid by the extended due date. Payments on the return are assumed made
+/// by the original due date. The minimum penalty for returns more than 60
+/// days late and interest are not modeled.
+pub fn late_penalties(
+    dates: DueDates,
+    extension: Option<&Form4868>,
+    filed: Date,
+    paid: Date,
+    ledger: &Ledger,
+) -> LatePenalties {
+    let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let unpaid = get(Key::AmountOwed);
+    let total_tax = get(Key::TotalTax).max(Usd::ZERO);
+
+    let filing_deadline = if extension.is_some() {
+        dates.extended
+    } else {
+        dates.original
+    };
+    let safe_harbor = extension.is_some()
+        && get(Key::TotalPayments) >= total_tax.mul_div(EXTENSION_PAYMENT_SAFE_HARBOR_BPS, 10_000)
+        && paid <= dates.extended;
+    let payment_deadline = if safe_harbor {
+        dates.extended
+    } else {
+        dates.original
+    };
+
+    // Months counted from the original due date, as [start, end).
+    let window = |deadline: Date, done: Date, max: i64| {
+        let start = months_late(dates.original, deadline);
+        (start, start + months_late(deadline, done).min(max))
+    };
+    let (file_start, file_end) = window(filing_deadline, filed, MAX_FAILURE_TO_FILE_MONTHS);
+    let (pay_start, pay_end) = window(payment_deadline, paid, MAX_FAILURE_TO_PAY_MONTHS);
+    let overlap = (file_end.min(pay_end) - file_start.max(pay_start)).max(0);
+
+    let monthly = |bps: i64, months: i64| unpaid.mul_div(bps * months, 10_000);
+    LatePenalties {
+        failure_to_file: monthly(FAILURE_TO_FILE_BPS_PER_MONTH, file_end - file_start)
+            - monthly(FAILURE_TO_PAY_BPS_PER_MONTH, overlap),
+        failure_to_pay: monthly(FAILURE_TO_PAY_BPS_PER_MONTH, pay_end - pay_start),
+    }
+}
+
+/// Months or parts of a month from `due` to `date`; zero if `date` is on
+/// or before `due`.
+fn months_late(due: Date, date: Date) -> i64 {
+    if date <= due {
+        return 0;
+    }
+    let months =
+        i64::from(date.year() - due.year()) * 12 + i64::from(date.month()) - i64::from(due.month());
+    if date.day() > due.day() {
+        months + 1
+    } else {
+        months
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u8, day: u8) -> Date {
+        Date::new(2026, month, day).unwrap()
+    }
+
+    fn ledger(total_tax: i64, payments: i64) -> Ledger {
+        let mut ledger = Ledger::new();
+        ledger.insert(Key::TotalTax, Usd::from_dollars(total_tax));
+        ledger.insert(Key::TotalPayments, Usd::from_dollars(payments));
+        ledger.insert(
+            Key::AmountOwed,
+            Usd::from_dollars((total_tax - payments).max(0)),
+        );
+        ledger
+    }
+
+    #[test]
+    fn month_or_part_of_a_month() {
+        assert_eq!(months_late(date(4, 15), date(4, 15)), 0);
+        assert_eq!(months_late(date(4, 15), date(4, 16)), 1);
+        assert_eq!(months_late(date(4, 15), date(5, 15)), 1);
+        assert_eq!(months_late(date(4, 15), date(5, 16)), 2);
+    }
+
+    #[test]
+    fn late_without_extension() {
+        let dates = DueDates::calendar_year(2025);
+        // Filed and paid three months late: 3 × (5% − 0.5%) and 3 × 0.5%.
+        let p = late_penalties(dates, None, date(7, 1), date(7, 1), &ledger(12_000, 10_000));
+        assert_eq!(p.failure_to_file, Usd::from_dollars(270));
+        assert_eq!(p.failure_to_pay, Usd::from_dollars(30));
+    }
+
+    #[test]
+    fn extension_moves_filing_deadline() {
+        let dates = DueDates::calendar_year(2025);
+        let estimate = ledger(12_000, 10_000);
+        let extension = Form4868::from_ledger(&estimate, Usd::ZERO);
+        assert_eq!(extension.balance_due, Usd::from_dollars(2_000));
+
+        // Less than 90% paid: no failure-to-file penalty, but failure to
+        // pay from April 15.
+        let p = late_penalties(dates, Some(&extension), date(7, 1), date(7, 1), &estimate);
+        assert_eq!(p.failure_to_file, Usd::ZERO);
+        assert_eq!(p.failure_to_pay, Usd::from_dollars(30));
+
+        // At least 90% paid and the rest paid with the return.
+        let p = late_penalties(
+            dates,
+            Some(&extension),
+            date(7, 1),
+            date(7, 1),
+            &ledger(12_000, 11_000),
+        );
+        assert_eq!(p.total(), Usd::ZERO);
+    }
+}
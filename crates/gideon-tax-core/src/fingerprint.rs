@@ -0,0 +1,576 @@
+//! Canonical hash of a computed return.
+//!
+//! A [`Fingerprint`] identifies the inputs, the rules, the engine version,
+//! and the ledger of a computed return. Store it with the results: if the
+//! fingerprint of the same input computed by the current engine differs,
+//! the stored results are stale; if it matches, the stored ledger is the
+//! one those inputs produce.
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+
+use crate::adjustments::Adjustments;
+use crate::capital_loss::CapitalLossCarryover;
+use crate::casualty::{CasualtyProperty, PersonalCasualty};
+use crate::charitable::{CharitableCarryover, Contributions, LimitCategory};
+use crate::decedent::{DeceasedFiler, DeceasedTaxpayer, FinalReturnSigner};
+use crate::depreciation::{DepreciableAsset, RecoveryClass};
+use crate::education::StudentExpenses;
+use crate::home_sale::{HomeSale, SaleReason};
+use crate::hsa::HsaDistributions;
+use crate::nol::NolCarryover;
+use crate::parents_election::ChildInvestmentIncome;
+use crate::passthrough::{EntityKind, LossLimits, PassthroughItems};
+use crate::period::{ShortPeriodReason, TaxPeriod};
+use crate::premium_tax_credit::{CoverageMonth, MarketplaceCoverage, PovertyRegion};
+use crate::rental::{PropertyType, RentalExpenses, RentalProperty};
+use crate::rules::TaxYearRules;
+use crate::schedule_a::ItemizedDeductions;
+use crate::spine::{Ledger, ReturnInput};
+use crate::tips::{EmployerTips, UnreportedTips};
+use crate::withholding::{ReportedWithholding, WithholdingSource};
+use crate::{Date, Filer, FilingStatus, Owner, Usd};
+
+/// Names the encoding below; change it if the encoding changes.
+const DOMAIN: &[u8] = b"honest-tax/return/v2";
+
+/// SHA-256 over a computed return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fingerprint([u8; 32]);
+
+impl Fingerprint {
+    /// Fingerprints `input` as computed under `rules` into `ledger`.
+    ///
+    /// Covers this crate's version, the rules' calendar year and whether
+    /// they are a projection, every input field, and every ledger line.
+    /// Rules that override parameters of a year, such as an overlay, and the
+    /// `us_tax_brackets` tables are covered only through the ledger they
+    /// produce. The hash is the same on every platform, including wasm32,
+    /// for a given version of this crate.
+    pub fn new(rules: &dyn TaxYearRules, input: &ReturnInput, ledger: &Ledger) -> Self {
+        Self::with_version(env!("CARGO_PKG_VERSION"), rules, input, ledger)
+    }
+
+    fn with_version(
+        version: &str,
+        rules: &dyn TaxYearRules,
+        input: &ReturnInput,
+        ledger: &Ledger,
+    ) -> Self {
+        let mut h = Sha256::new();
+        h.update(DOMAIN);
+        version.encode(&mut h);
+        rules.calendar_year().encode(&mut h);
+        rules.is_projection().encode(&mut h);
+        input.encode(&mut h);
+        ledger.encode(&mut h);
+        Fingerprint(h.finalize().into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// Lowercase hex.
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a value's canonical bytes into the hash.
+///
+/// Integers are fixed-width little-endian, amounts are their cents as an
+/// `i64`, strings and sequences are prefixed with their length as a `u64`,
+/// and an `Option` with a `u8` 0 or 1. Enum variants are written as the
+/// tags listed below, not their declaration order or derived `Hash`.
+trait Encode {
+    fn encode(&self, h: &mut Sha256);
+}
+
+macro_rules! encode_int {
+    ($($t:ty),+) => {
+        $(
+            impl Encode for $t {
+                fn encode(&self, h: &mut Sha256) {
+                    h.update(self.to_le_bytes());
+                }
+            }
+        )+
+    };
+}
+
+encode_int!(u8, u32, u64, i32, i64);
+
+impl Encode for bool {
+    fn encode(&self, h: &mut Sha256) {
+        u8::from(*self).encode(h);
+    }
+}
+
+impl Encode for str {
+    fn encode(&self, h: &mut Sha256) {
+        (self.len() as u64).encode(h);
+        h.update(self.as_bytes());
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, h: &mut Sha256) {
+        self.as_str().encode(h);
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode(&self, h: &mut Sha256) {
+        match self {
+            None => 0u8.encode(h),
+            Some(value) => {
+                1u8.encode(h);
+                value.encode(h);
+            }
+        }
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, h: &mut Sha256) {
+        (self.len() as u64).encode(h);
+        for item in self {
+            item.encode(h);
+        }
+    }
+}
+
+/// Fixed length, so not prefixed.
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode(&self, h: &mut Sha256) {
+        for item in self {
+            item.encode(h);
+        }
+    }
+}
+
+impl Encode for Usd {
+    fn encode(&self, h: &mut Sha256) {
+        self.cents().encode(h);
+    }
+}
+
+impl Encode for Date {
+    fn encode(&self, h: &mut Sha256) {
+        self.year().encode(h);
+        self.month().encode(h);
+        self.day().encode(h);
+    }
+}
+
+/// Each line set, in form order, as its variant name and amount.
+impl Encode for Ledger {
+    fn encode(&self, h: &mut Sha256) {
+        (self.len() as u64).encode(h);
+        for (key, amount) in self.iter() {
+            format!("{key:?}").encode(h);
+            amount.encode(h);
+        }
+    }
+}
+
+/// Encodes a unit enum as the `u8` tag given for each variant.
+macro_rules! encode_enum {
+    ($($ty:ident { $($variant:ident = $tag:literal),+ $(,)? })+) => {
+        $(
+            impl Encode for $ty {
+                fn encode(&self, h: &mut Sha256) {
+                    let tag: u8 = match self {
+                        $($ty::$variant => $tag,)+
+                    };
+                    tag.encode(h);
+                }
+            }
+        )+
+    };
+}
+
+/// Encodes a struct's fields in the order listed. The struct is
+/// destructured without `..`, so a new field does not compile until it is
+/// added here.
+macro_rules! encode_struct {
+    ($($ty:ident { $($field:ident),+ $(,)? })+) => {
+        $(
+            impl Encode for $ty {
+                fn encode(&self, h: &mut Sha256) {
+                    let $ty { $($field),+ } = self;
+                    $($field.encode(h);)+
+                }
+            }
+        )+
+    };
+}
+
+encode_enum! {
+    FilingStatus {
+        Single = 0,
+        MarriedFilingJointly = 1,
+        MarriedFilingSeparately = 2,
+        HeadOfHousehold = 3,
+        QualifyingSurvivingSpouse = 4,
+    }
+    ShortPeriodReason {
+        Death = 0,
+        AccountingPeriodChange = 1,
+    }
+    DeceasedFiler {
+        Taxpayer = 0,
+        Spouse = 1,
+    }
+    FinalReturnSigner {
+        SurvivingSpouse = 0,
+        PersonalRepresentative = 1,
+        RefundClaimant = 2,
+    }
+    EntityKind {
+        Partnership = 0,
+        SCorporation = 1,
+        EstateOrTrust = 2,
+    }
+    Owner {
+        Taxpayer = 0,
+        Spouse = 1,
+    }
+    PropertyType {
+        SingleFamily = 0,
+        MultiFamily = 1,
+        VacationShortTerm = 2,
+        Commercial = 3,
+        Land = 4,
+        Royalties = 5,
+        SelfRental = 6,
+        Other = 7,
+    }
+    RecoveryClass {
+        ResidentialRental = 0,
+        Nonresidential = 1,
+    }
+    SaleReason {
+        Other = 0,
+        WorkRelated = 1,
+        HealthRelated = 2,
+        Unforeseen = 3,
+    }
+    LimitCategory {
+        Sixty = 0,
+        Fifty = 1,
+        Thirty = 2,
+        Twenty = 3,
+    }
+    PovertyRegion {
+        Contiguous = 0,
+        Alaska = 1,
+        Hawaii = 2,
+    }
+    WithholdingSource {
+        Form1099Int = 0,
+        Form1099Div = 1,
+        Form1099Nec = 2,
+        Form1099B = 3,
+        Form1099R = 4,
+        Form1099G = 5,
+        Ssa1099 = 6,
+    }
+}
+
+encode_struct! {
+    ReturnInput {
+        tax_year,
+        period,
+        deceased,
+        filing_status,
+        taxpayer,
+        spouse,
+        is_dependent,
+        is_dual_status_alien,
+        spouse_itemizes,
+        num_dependents,
+        mfs_lived_with_spouse,
+        w2_wages,
+        social_security_wages,
+        spouse_social_security_wages,
+        unreported_tips,
+        children_investment_income,
+        capital_loss_carryover,
+        nol_carryovers,
+        social_security_benefits,
+        hsa_distributions,
+        students,
+        passthroughs,
+        rental_properties,
+        home_sales,
+        adjustments,
+        itemized_deductions,
+        marketplace_coverage,
+        fed_withholding,
+        form_1099_withholding,
+        extension_payment,
+    }
+    TaxPeriod {
+        start,
+        end,
+        short_period,
+    }
+    DeceasedTaxpayer {
+        filer,
+        name,
+        date_of_death,
+        signer,
+        medical_expense_election,
+    }
+    Filer {
+        is_65_or_older,
+        is_blind,
+    }
+    UnreportedTips {
+        employers,
+        under_20_a_month,
+        social_security_wages_and_tips,
+    }
+    EmployerTips {
+        employer_name,
+        employer_ein,
+        tips_received,
+        tips_reported,
+    }
+    ChildInvestmentIncome {
+        child_name,
+        taxable_interest,
+        tax_exempt_interest,
+        ordinary_dividends,
+        qualified_dividends,
+        capital_gain_distributions,
+    }
+    CapitalLossCarryover {
+        short_term,
+        long_term,
+    }
+    NolCarryover {
+        year,
+        amount,
+    }
+    HsaDistributions {
+        gross_distributions,
+        rollovers_and_excess_withdrawn,
+        qualified_medical_expenses,
+        additional_tax_exception,
+    }
+    StudentExpenses {
+        tuition_and_fees,
+        course_materials,
+        scholarships,
+        scholarships_elected_taxable,
+    }
+    PassthroughItems {
+        entity_name,
+        entity_ein,
+        kind,
+        owner,
+        material_participation,
+        ordinary_income,
+        rental_income,
+        guaranteed_payments,
+        portfolio_income,
+        section_179_deduction,
+        interest,
+        ordinary_dividends,
+        qualified_dividends,
+        net_short_term_capital_gain,
+        net_long_term_capital_gain,
+        self_employment_earnings,
+        qualified_business_income,
+        loss_limits,
+    }
+    LossLimits {
+        basis,
+        at_risk,
+    }
+    RentalProperty {
+        address,
+        property_type,
+        fair_rental_days,
+        personal_use_days,
+        rents_received,
+        royalties_received,
+        expenses,
+        active_participation,
+        at_risk,
+        prior_year_unallowed,
+        disposed,
+        assets,
+    }
+    RentalExpenses {
+        advertising,
+        auto_and_travel,
+        cleaning_and_maintenance,
+        commissions,
+        insurance,
+        legal_and_professional_fees,
+        management_fees,
+        mortgage_interest,
+        other_interest,
+        repairs,
+        supplies,
+        taxes,
+        utilities,
+        depreciation,
+        other,
+    }
+    DepreciableAsset {
+        description,
+        placed_in_service,
+        cost,
+        land,
+        class,
+        disposed,
+    }
+    HomeSale {
+        date_acquired,
+        date_sold,
+        selling_price,
+        selling_expenses,
+        cost,
+        improvements,
+        depreciation,
+        ownership_days,
+        use_days,
+        spouse_use_days,
+        days_since_prior_exclusion,
+        nonqualified_use_days,
+        reason,
+    }
+    Adjustments {
+        business_expenses_reservists,
+        hsa_deduction,
+        moving_expenses,
+        ira_deduction,
+        section_67e_excess_deductions,
+    }
+    ItemizedDeductions {
+        medical_expenses,
+        state_and_local_taxes,
+        real_estate_taxes,
+        personal_property_taxes,
+        mortgage_interest,
+        investment_interest,
+        charitable_contributions,
+        charitable_carryovers,
+        casualties,
+        other,
+    }
+    Contributions {
+        sixty,
+        fifty,
+        thirty,
+        twenty,
+    }
+    CharitableCarryover {
+        category,
+        year,
+        amount,
+    }
+    PersonalCasualty {
+        properties,
+        federally_declared_disaster,
+    }
+    CasualtyProperty {
+        description,
+        basis,
+        reimbursement,
+        fmv_before,
+        fmv_after,
+    }
+    MarketplaceCoverage {
+        region,
+        months,
+    }
+    CoverageMonth {
+        enrollment_premium,
+        slcsp_premium,
+        advance_payment,
+    }
+    ReportedWithholding {
+        source,
+        payer_name,
+        payer_tin,
+        amount,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::rules::y2026::Rules2026Projected;
+    use crate::spine::tests::input;
+    use crate::spine::{Key, compute_spine};
+
+    fn fingerprint(inp: &ReturnInput) -> Fingerprint {
+        let ledger = compute_spine(&Rules2025, inp).unwrap();
+        Fingerprint::new(&Rules2025, inp, &ledger)
+    }
+
+    #[test]
+    fn same_return_same_fingerprint() {
+        let inp = input(60_000, 5_000);
+        let a = fingerprint(&inp);
+        assert_eq!(a, fingerprint(&inp.clone()));
+        assert_eq!(a.to_string().len(), 64);
+        assert!(a.to_string().bytes().all(|b| b.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn any_change_changes_it() {
+        let inp = input(60_000, 5_000);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let base = Fingerprint::new(&Rules2025, &inp, &ledger);
+
+        let mut withheld = inp.clone();
+        withheld.fed_withholding = Usd::from_dollars(5_001);
+        assert_ne!(fingerprint(&withheld), base);
+
+        // A stored ledger that does not match the inputs.
+        let mut edited = ledger.clone();
+        edited.insert(Key::Refund, Usd::from_dollars(1));
+        assert_ne!(Fingerprint::new(&Rules2025, &inp, &edited), base);
+
+        let inp = ReturnInput {
+            tax_year: 2026,
+            ..inp
+        };
+        assert_ne!(Fingerprint::new(&Rules2026Projected, &inp, &ledger), base);
+    }
+
+    /// Pins the encoding. Adding an input field changes the digest; any
+    /// other change to it needs a new [`DOMAIN`].
+    #[test]
+    fn golden_digest() {
+        let inp = ReturnInput {
+            w2_wages: Usd::from_dollars(60_000),
+            fed_withholding: Usd::from_dollars(5_000),
+            ..ReturnInput::new(2025, FilingStatus::Single)
+        };
+        let ledger: Ledger = [
+            (Key::AGI, Usd::from_dollars(60_000)),
+            (Key::TotalTax, Usd::from_dollars(5_000)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            Fingerprint::with_version("0.0.0", &Rules2025, &inp, &ledger).to_string(),
+            "a837d0e55b44538edb3b106d8d49d47cab5d6406dc92bf870850f8d01c1f705c"
+        );
+    }
+}
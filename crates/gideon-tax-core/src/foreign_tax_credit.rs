@@ -0,0 +1,225 @@
+use crate::Usd;
+
+/// Years unused foreign taxes can be carried forward (IRC §904(c)).
+pub const CARRYFORWARD_YEARS: i32 = 10;
+
+/// Separate limitation category of income (Form 1116, categories a–d).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Basket {
+    Section951A,
+    ForeignBranch,
+    Passive,
+    General,
+}
+
+/// Unused foreign taxes paid or accrued in `year`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FtcCarryover {
+    pub basket: Basket,
+    pub year: i32,
+    pub amount: Usd,
+}
+
+/// One basket's figures for the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BasketInput {
+    pub basket: Basket,
+    /// Foreign taxes paid or accrued this year (Form 1116, line 8).
+    pub foreign_taxes: Usd,
+    /// Form 1116, line 23: U.S. tax times foreign-source taxable income in
+    /// the basket over worldwide taxable income.
+    pub limitation: Usd,
+    /// Prior year's limitation in excess of the taxes credited in the
+    /// basket, which this year's excess can be carried back to.
+    pub prior_year_excess_limitation: Usd,
+}
+
+/// Form 1116, line 23 for one basket.
+pub fn limitation(us_tax: Usd, foreign_taxable_income: Usd, worldwide_taxable_income: Usd) -> Usd {
+    if worldwide_taxable_income <= Usd::ZERO || foreign_taxable_income <= Usd::ZERO {
+        return Usd::ZERO;
+    }
+    us_tax.mul_div(
+        foreign_taxable_income.min(worldwide_taxable_income).cents(),
+        worldwide_taxable_income.cents(),
+    )
+}
+
+/// Result of applying the limitation across baskets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForeignTaxCredit {
+    /// Credit reported on Schedule 3, line 1.
+    pub credit: Usd,
+    /// This year's excess carried back to the prior year, one entry per
+    /// basket, claimed on an amended prior-year return.
+    pub carrybacks: Vec<FtcCarryover>,
+    /// Amounts carried to next year, by basket and oldest first.
+    pub carryovers: Vec<FtcCarryover>,
+    /// Carryovers that reached the end of the carryforward period unused.
+    pub expired: Usd,
+}
+
+/// Applies each basket's limitation to this year's foreign taxes and then
+/// to carryovers from prior years, oldest first. This year's excess is
+/// carried back one year to the extent of the prior year's excess
+/// limitation, and the rest forward ten years.
+///
+/// See: <https://www.irs.gov/instructions/i1116>
+pub fn apply(
+    tax_year: i32,
+    baskets: &[BasketInput],
+    carryovers: &[FtcCarryover],
+) -> ForeignTaxCredit {
+    let mut result = ForeignTaxCredit::default();
+    for b in baskets {
+        let mut prior: Vec<_> = carryovers
+            .iter()
+            .filter(|c| c.basket == b.basket)
+            .copied()
+            .collect();
+        prior.sort_by_key(|c| c.year);
+
+        let current = b.foreign_taxes.min(b.limitation);
+        let mut limit_left = b.limitation - current;
+        result.credit += current;
+        for mut c in prior {
+            if tax_year - c.year > CARRYFORWARD_YEARS {
+                result.expired += c.amount;
+                continue;
+            }
+            let used = c.amount.min(limit_left);
+            limit_left -= used;
+            result.credit += used;
+            c.amount -= used;
+            if c.amount == Usd::ZERO {
+                continue;
+            }
+            if tax_year - c.year >= CARRYFORWARD_YEARS {
+                result.expired += c.amount;
+            } else {
+                result.carryovers.push(c);
+            }
+        }
+
+        let excess = b.foreign_taxes - current;
+        let carryback = excess.min(b.prior_year_excess_limitation);
+        if carryback > Usd::ZERO {
+            result.carrybacks.push(FtcCarryover {
+                basket: b.basket,
+                year: tax_year,
+                amount: carryback,
+            });
+        }
+        if excess > carryback {
+            result.carryovers.push(FtcCarryover {
+                basket: b.basket,
+                year: tax_year,
+                amount: excess - carryback,
+            });
+        }
+    }
+    // Carryovers in baskets with no activity this year pass through.
+    for c in carryovers {
+        if baskets.iter().any(|b| b.basket == c.basket) {
+            continue;
+        }
+        if tax_year - c.year >= CARRYFORWARD_YEARS {
+            result.expired += c.amount;
+        } else {
+            result.carryovers.push(*c);
+        }
+    }
+    result.carryovers.sort_by_key(|c| (c.basket, c.year));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passive(taxes: i64, limitation: i64, prior_excess: i64) -> BasketInput {
+        BasketInput {
+            basket: Basket::Passive,
+            foreign_taxes: Usd::from_dollars(taxes),
+            limitation: Usd::from_dollars(limitation),
+            prior_year_excess_limitation: Usd::from_dollars(prior_excess),
+        }
+    }
+
+    #[test]
+    fn limitation_by_foreign_share() {
+        assert_eq!(
+            limitation(
+                Usd::from_dollars(20_000),
+                Usd::from_dollars(10_000),
+                Usd::from_dollars(100_000)
+            ),
+            Usd::from_dollars(2_000)
+        );
+        assert_eq!(
+            limitation(
+                Usd::from_dollars(20_000),
+                Usd::from_dollars(-5_000),
+                Usd::from_dollars(100_000)
+            ),
+            Usd::ZERO
+        );
+    }
+
+    #[test]
+    fn excess_carried_back_then_forward() {
+        let r = apply(2025, &[passive(1_500, 1_000, 200)], &[]);
+        assert_eq!(r.credit, Usd::from_dollars(1_000));
+        assert_eq!(r.carrybacks[0].amount, Usd::from_dollars(200));
+        assert_eq!(
+            r.carryovers,
+            vec![FtcCarryover {
+                basket: Basket::Passive,
+                year: 2025,
+                amount: Usd::from_dollars(300),
+            }]
+        );
+    }
+
+    #[test]
+    fn carryovers_used_oldest_first_and_expire() {
+        let carryovers = [
+            FtcCarryover {
+                basket: Basket::Passive,
+                year: 2018,
+                amount: Usd::from_dollars(400),
+            },
+            FtcCarryover {
+                basket: Basket::Passive,
+                year: 2015,
+                amount: Usd::from_dollars(300),
+            },
+            FtcCarryover {
+                basket: Basket::Passive,
+                year: 2014,
+                amount: Usd::from_dollars(100),
+            },
+            FtcCarryover {
+                basket: Basket::General,
+                year: 2020,
+                amount: Usd::from_dollars(50),
+            },
+        ];
+        let r = apply(2025, &[passive(600, 1_000, 0)], &carryovers);
+        // 600 current, 300 from 2015, 100 of 2018; 2014 expired.
+        assert_eq!(r.credit, Usd::from_dollars(1_000));
+        assert_eq!(r.expired, Usd::from_dollars(100));
+        assert_eq!(
+            r.carryovers
+                .iter()
+                .map(|c| (c.basket, c.year, c.amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (Basket::Passive, 2018, Usd::from_dollars(300)),
+                (Basket::General, 2020, Usd::from_dollars(50)),
+            ]
+        );
+    }
+}
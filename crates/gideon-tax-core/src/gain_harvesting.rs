@@ -0,0 +1,137 @@
+use crate::Usd;
+use crate::capital_loss;
+use crate::rules::TaxYearRules;
+use crate::spine::{Key, Ledger, ReturnInput};
+
+/// How much gain or loss is worth realizing before year end.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HarvestPlan {
+    /// Long-term gain that can be realized without raising tax: gain that
+    /// absorbs losses above the deduction limit and, if no capital loss is
+    /// deducted, unused deductions and room below the 15% capital gain
+    /// threshold.
+    pub zero_rate_gain: Usd,
+    /// Loss that would reduce this year's taxable income: losses offsetting
+    /// this year's net gain, plus up to the $3,000 limit against other
+    /// income. Losses beyond this are carried forward.
+    pub deductible_loss: Usd,
+}
+
+/// Plans gain and loss harvesting for the return in `ledger`.
+///
+/// Each realized dollar is assumed to change taxable income dollar for
+/// dollar once it passes through Schedule D, so the taxable Social
+/// Security interaction and phase-outs are ignored. Gain is taxed at the
+/// rates of the Qualified Dividends and Capital Gain Tax Worksheet, as in
+/// the spine.
+///
+/// While a capital loss is deducted, gain first reduces that deduction,
+/// which raises tax at ordinary rates, so only the gain that absorbs
+/// losses above the limit is free.
+pub fn harvest_plan(rules: &dyn TaxYearRules, input: &ReturnInput, ledger: &Ledger) -> HarvestPlan {
+    let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let taxable_income =
+        get(Key::AGI) - get(Key::Deductions) - get(Key::Exemptions) - get(Key::QbiDeduction);
+    let schedule_d = input.schedule_d(rules);
+    let net = schedule_d.net_short_term + schedule_d.net_long_term;
+    let limit = capital_loss::loss_limit(input.filing_status);
+
+    // Gain offset by losses Schedule D did not deduct changes nothing.
+    let absorbed = (Usd::ZERO - limit - net).max(Usd::ZERO);
+    let [fifteen_percent, _] = rules.capital_gain_rate_thresholds(input.filing_status);
+    let room = if net < Usd::ZERO {
+        Usd::ZERO
+    } else {
+        (fifteen_percent - taxable_income).max(Usd::ZERO)
+    };
+
+    let deductible_loss = (net + limit)
+        .max(Usd::ZERO)
+        .min(taxable_income.max(Usd::ZERO));
+    HarvestPlan {
+        zero_rate_gain: absorbed + room,
+        deductible_loss,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Owner;
+    use crate::capital_loss::CapitalLossCarryover;
+    use crate::passthrough::{EntityKind, LossLimits, PassthroughItems};
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    fn plan(inp: &ReturnInput) -> HarvestPlan {
+        let ledger = compute_spine(&Rules2025, inp).unwrap();
+        harvest_plan(&Rules2025, inp, &ledger)
+    }
+
+    fn with_long_term_gain(inp: &ReturnInput, gain: Usd) -> ReturnInput {
+        let mut inp = inp.clone();
+        inp.passthroughs.push(PassthroughItems {
+            entity_name: "Acme LP".to_string(),
+            entity_ein: "12-3456789".to_string(),
+            kind: EntityKind::Partnership,
+            owner: Owner::Taxpayer,
+            material_participation: false,
+            ordinary_income: Usd::ZERO,
+            rental_income: Usd::ZERO,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: Usd::ZERO,
+            section_179_deduction: Usd::ZERO,
+            interest: Usd::ZERO,
+            ordinary_dividends: Usd::ZERO,
+            qualified_dividends: Usd::ZERO,
+            net_short_term_capital_gain: Usd::ZERO,
+            net_long_term_capital_gain: gain,
+            self_employment_earnings: Usd::ZERO,
+            qualified_business_income: Usd::ZERO,
+            loss_limits: LossLimits::default(),
+        });
+        inp
+    }
+
+    /// Asserts that realizing the planned zero-rate gain leaves the tax
+    /// unchanged and that a dollar more raises it.
+    fn assert_zero_rate_gain_is_free(inp: &ReturnInput) {
+        let tax = |inp: &ReturnInput| compute_spine(&Rules2025, inp).unwrap()[&Key::RegularTax];
+        let gain = plan(inp).zero_rate_gain;
+        assert_eq!(tax(&with_long_term_gain(inp, gain)), tax(inp));
+        assert!(tax(&with_long_term_gain(inp, gain + Usd::from_dollars(100))) > tax(inp));
+    }
+
+    #[test]
+    fn fills_zero_rate_bracket() {
+        // Taxable income of 40,000 leaves 8,350 below 48,350.
+        let p = plan(&input(55_750, 0));
+        assert_eq!(p.zero_rate_gain, Usd::from_dollars(8_350));
+        assert_eq!(p.deductible_loss, Usd::from_dollars(3_000));
+        assert_zero_rate_gain_is_free(&input(55_750, 0));
+    }
+
+    #[test]
+    fn undeducted_losses_absorb_gain_first() {
+        let mut inp = input(55_750, 0);
+        inp.capital_loss_carryover = CapitalLossCarryover {
+            short_term: Usd::from_dollars(10_000),
+            long_term: Usd::ZERO,
+        };
+        let p = plan(&inp);
+        // 7,000 of the carryover is not deductible this year. More gain
+        // would reduce the 3,000 deducted against wages.
+        assert_eq!(p.zero_rate_gain, Usd::from_dollars(7_000));
+        assert_eq!(p.deductible_loss, Usd::ZERO);
+        assert_zero_rate_gain_is_free(&inp);
+    }
+
+    #[test]
+    fn loss_offsets_gain_then_limit() {
+        let inp = with_long_term_gain(&input(55_750, 0), Usd::from_dollars(20_000));
+        let p = plan(&inp);
+        assert_eq!(p.zero_rate_gain, Usd::ZERO);
+        assert_eq!(p.deductible_loss, Usd::from_dollars(23_000));
+    }
+}
@@ -0,0 +1,210 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::rental::SPECIAL_ALLOWANCE_PHASEOUT_START;
+use crate::rules::TaxYearRules;
+use crate::spine::{Key, Ledger, ReturnInput};
+use crate::{Rate, Usd};
+
+/// A threshold the return is below.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// Start of the next ordinary income bracket.
+    OrdinaryBracket { rate: Rate },
+    /// Start of the next rate on net capital gain and qualified dividends.
+    CapitalGainRate { rate: Rate },
+    /// Threshold above which the qualified business income deduction is
+    /// limited by W-2 wages and property (IRC §199A(b)(3)).
+    QbiThreshold,
+    /// Start of the rental real estate special allowance phase-out
+    /// (IRC §469(i)(3)).
+    RentalAllowancePhaseout,
+    /// Start of the personal exemption phase-out (IRC §151(d)(3)).
+    ExemptionPhaseout,
+}
+
+/// The income figure a threshold is measured against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Measure {
+    TaxableIncome,
+    /// Taxable income figured without the qualified business income
+    /// deduction.
+    TaxableIncomeBeforeQbi,
+    ModifiedAgi,
+    Agi,
+}
+
+/// How far the return is from one threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Headroom {
+    pub boundary: Boundary,
+    pub measure: Measure,
+    pub threshold: Usd,
+    /// Additional income the filer can realize before reaching the
+    /// threshold.
+    pub headroom: Usd,
+}
+
+/// Lists each threshold from the year's rules that the return in `ledger`
+/// has not yet reached, nearest first.
+///
+/// Additional income is treated as raising every measure dollar for
+/// dollar, which ignores the taxable Social Security interaction and
+/// assumes no other item changes. Thresholds are only listed when they
+/// apply to the return: the QBI threshold with qualified business income,
+/// and the rental phase-out with an actively managed rental. Credits are
+/// not yet computed, so no credit phase-outs or cliffs are listed.
+pub fn headroom(rules: &dyn TaxYearRules, input: &ReturnInput, ledger: &Ledger) -> Vec<Headroom> {
+    let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let status = input.filing_status;
+    let taxable_income = get(Key::TaxableIncome);
+
+    let mut candidates = Vec::new();
+    if let Some(next) = rules
+        .ordinary_brackets(status)
+        .into_iter()
+        .find(|b| b.floor > taxable_income)
+    {
+        candidates.push((
+            Boundary::OrdinaryBracket { rate: next.rate },
+            Measure::TaxableIncome,
+            next.floor,
+            taxable_income,
+        ));
+    }
+    if let Some((threshold, rate)) = rules
+        .capital_gain_rate_thresholds(status)
+        .into_iter()
+        .zip([Rate::from_percent(15), Rate::from_percent(20)])
+        .find(|(t, _)| *t > taxable_income)
+    {
+        candidates.push((
+            Boundary::CapitalGainRate { rate },
+            Measure::TaxableIncome,
+            threshold,
+            taxable_income,
+        ));
+    }
+    if input
+        .passthroughs
+        .iter()
+        .any(|p| p.qualified_business_income != Usd::ZERO)
+    {
+        candidates.push((
+            Boundary::QbiThreshold,
+            Measure::TaxableIncomeBeforeQbi,
+            rules.qbi_threshold(status),
+            taxable_income + get(Key::QbiDeduction),
+        ));
+    }
+    let mfs = status == FilingStatus::MarriedFilingSeparately;
+    if input
+        .rental_properties
+        .iter()
+        .any(|p| p.active_participation)
+        && !(mfs && input.mfs_lived_with_spouse)
+    {
+        let start = if mfs {
+            SPECIAL_ALLOWANCE_PHASEOUT_START.mul_div(1, 2)
+        } else {
+            SPECIAL_ALLOWANCE_PHASEOUT_START
+        };
+        candidates.push((
+            Boundary::RentalAllowancePhaseout,
+            Measure::ModifiedAgi,
+            start,
+            input.rental_modified_agi(ledger),
+        ));
+    }
+    if let Some(threshold) = rules.exemption_phaseout_threshold(status) {
+        candidates.push((
+            Boundary::ExemptionPhaseout,
+            Measure::Agi,
+            threshold,
+            get(Key::AGI),
+        ));
+    }
+
+    let mut report: Vec<Headroom> = candidates
+        .into_iter()
+        .filter(|(_, _, threshold, current)| threshold > current)
+        .map(|(boundary, measure, threshold, current)| Headroom {
+            boundary,
+            measure,
+            threshold,
+            headroom: threshold - current,
+        })
+        .collect();
+    report.sort_by_key(|h| h.headroom);
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rental::RentalProperty;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn next_bracket_and_capital_gain_rate() {
+        // Taxable income of 40,000.
+        let inp = input(55_750, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let report = headroom(&Rules2025, &inp, &ledger);
+        assert_eq!(
+            report,
+            vec![
+                Headroom {
+                    boundary: Boundary::CapitalGainRate {
+                        rate: Rate::from_percent(15)
+                    },
+                    measure: Measure::TaxableIncome,
+                    threshold: Usd::from_dollars(48_350),
+                    headroom: Usd::from_dollars(8_350),
+                },
+                Headroom {
+                    boundary: Boundary::OrdinaryBracket {
+                        rate: Rate::from_percent(22)
+                    },
+                    measure: Measure::TaxableIncome,
+                    threshold: Usd::from_dollars(48_475),
+                    headroom: Usd::from_dollars(8_475),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rental_phaseout_listed_with_active_rental() {
+        let mut inp = input(90_000, 0);
+        inp.rental_properties.push(RentalProperty {
+            rents_received: Usd::from_dollars(5_000),
+            active_participation: true,
+            ..Default::default()
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let rental = headroom(&Rules2025, &inp, &ledger)
+            .into_iter()
+            .find(|h| h.boundary == Boundary::RentalAllowancePhaseout)
+            .unwrap();
+        // Modified AGI excludes the rental's own income.
+        assert_eq!(rental.headroom, Usd::from_dollars(10_000));
+
+        inp.mfs_lived_with_spouse = true;
+        inp.filing_status = FilingStatus::MarriedFilingSeparately;
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert!(
+            headroom(&Rules2025, &inp, &ledger)
+                .iter()
+                .all(|h| h.boundary != Boundary::RentalAllowancePhaseout)
+        );
+    }
+
+    #[test]
+    fn nothing_above_top_bracket() {
+        let inp = input(1_000_000, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert!(headroom(&Rules2025, &inp, &ledger).is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::{Date, Usd};
+
+/// Largest exclusion, doubled for a joint return when both spouses meet
+/// the use test (IRC §121(b)).
+pub const MAXIMUM_EXCLUSION: Usd = Usd::from_dollars(250_000);
+
+/// Days of ownership and use in the five years before the sale required
+/// for the full exclusion, and the denominator of a partial exclusion.
+pub const REQUIRED_DAYS: u32 = 730;
+
+/// Why a main home was sold before meeting the two-year tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SaleReason {
+    /// No reason that qualifies for a partial exclusion.
+    Other,
+    /// A change in place of employment.
+    WorkRelated,
+    /// Health.
+    HealthRelated,
+    /// Unforeseeable circumstances, such as death, divorce, or a disaster.
+    Unforeseen,
+}
+
+/// Sale of a main home (Publication 523).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HomeSale {
+    pub date_acquired: Date,
+    pub date_sold: Date,
+    pub selling_price: Usd,
+    /// Commissions, fees, and other selling expenses.
+    pub selling_expenses: Usd,
+    /// Purchase price plus settlement costs.
+    pub cost: Usd,
+    /// Capital improvements, less any later removed.
+    pub improvements: Usd,
+    /// Depreciation allowed or allowable after May 6, 1997, such as for a
+    /// home office or rental use.
+    pub depreciation: Usd,
+    /// Days owned during the five years before the sale.
+    pub ownership_days: u32,
+    /// Days used as the taxpayer's main home during the five years before
+    /// the sale.
+    pub use_days: u32,
+    /// Days the spouse used it as a main home, for a joint return.
+    pub spouse_use_days: u32,
+    /// Days since the sale of another home for which gain was excluded, or
+    /// `None` if none was in the two years before this sale.
+    pub days_since_prior_exclusion: Option<u32>,
+    /// Days after 2008 neither spouse used it as a main home, other than
+    /// after the last such use (IRC §121(b)(5)).
+    pub nonqualified_use_days: u32,
+    pub reason: SaleReason,
+}
+
+/// Publication 523 worksheets for one sale.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HomeSaleWorksheet {
+    pub amount_realized: Usd,
+    pub adjusted_basis: Usd,
+    /// Gain, or zero for a loss, which is not deductible.
+    pub gain: Usd,
+    /// Gain from depreciation, which cannot be excluded and is
+    /// unrecaptured section 1250 gain.
+    pub depreciation_gain: Usd,
+    /// Gain allocated to nonqualified use, which cannot be excluded.
+    pub nonqualified_use_gain: Usd,
+    /// Exclusion limit after any partial reduction.
+    pub maximum_exclusion: Usd,
+    pub exclusion: Usd,
+    /// Gain reported on Schedule D.
+    pub taxable_gain: Usd,
+    /// `true` if the home was held more than one year.
+    pub long_term: bool,
+}
+
+impl HomeSale {
+    /// Computes the gain, exclusion, and taxable gain.
+    ///
+    /// A partial exclusion uses the shortest of the ownership days, the
+    /// use days, and the days since a prior exclusion. A surviving spouse's
+    /// $500,000 limit and homes held in trust are not modeled.
+    ///
+    /// See: <https://www.irs.gov/publications/p523>
+    pub fn worksheet(&self, filing_status: FilingStatus) -> HomeSaleWorksheet {
+        let amount_realized = self.selling_price - self.selling_expenses;
+        let adjusted_basis = self.cost + self.improvements - self.depreciation;
+        let gain = (amount_realized - adjusted_basis).max(Usd::ZERO);
+        let depreciation_gain = gain.min(self.depreciation);
+
+        let owned_days = self.date_acquired.days_until(self.date_sold).max(1);
+        let nonqualified_use_gain = (gain - depreciation_gain).mul_div(
+            i64::from(self.nonqualified_use_days).min(owned_days),
+            owned_days,
+        );
+
+        let prior_exclusion_ok = self
+            .days_since_prior_exclusion
+            .is_none_or(|days| days >= REQUIRED_DAYS);
+        let qualifies = self.ownership_days >= REQUIRED_DAYS
+            && self.use_days >= REQUIRED_DAYS
+            && prior_exclusion_ok;
+        let joint = matches!(filing_status, FilingStatus::MarriedFilingJointly)
+            && self.spouse_use_days >= REQUIRED_DAYS;
+        let limit = if joint {
+            MAXIMUM_EXCLUSION.mul_div(2, 1)
+        } else {
+            MAXIMUM_EXCLUSION
+        };
+        let maximum_exclusion = if qualifies {
+            limit
+        } else if self.reason != SaleReason::Other {
+            let days = self
+                .ownership_days
+                .min(self.use_days)
+                .min(self.days_since_prior_exclusion.unwrap_or(u32::MAX))
+                .min(REQUIRED_DAYS);
+            limit.mul_div(i64::from(days), i64::from(REQUIRED_DAYS))
+        } else {
+            Usd::ZERO
+        };
+
+        let excludable = gain - depreciation_gain - nonqualified_use_gain;
+        let exclusion = excludable.min(maximum_exclusion);
+        HomeSaleWorksheet {
+            amount_realized,
+            adjusted_basis,
+            gain,
+            depreciation_gain,
+            nonqualified_use_gain,
+            maximum_exclusion,
+            exclusion,
+            taxable_gain: gain - exclusion,
+            long_term: self.date_sold > self.date_acquired.add_years(1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sale() -> HomeSale {
+        HomeSale {
+            date_acquired: Date::new(2015, 6, 1).unwrap(),
+            date_sold: Date::new(2025, 6, 1).unwrap(),
+            selling_price: Usd::from_dollars(900_000),
+            selling_expenses: Usd::from_dollars(50_000),
+            cost: Usd::from_dollars(300_000),
+            improvements: Usd::from_dollars(60_000),
+            depreciation: Usd::from_dollars(10_000),
+            ownership_days: 1_826,
+            use_days: 1_826,
+            spouse_use_days: 1_826,
+            days_since_prior_exclusion: None,
+            nonqualified_use_days: 0,
+            reason: SaleReason::Other,
+        }
+    }
+
+    #[test]
+    fn joint_exclusion_with_depreciation_recaptured() {
+        let w = sale().worksheet(FilingStatus::MarriedFilingJointly);
+        assert_eq!(w.amount_realized, Usd::from_dollars(850_000));
+        assert_eq!(w.adjusted_basis, Usd::from_dollars(350_000));
+        assert_eq!(w.gain, Usd::from_dollars(500_000));
+        assert_eq!(w.depreciation_gain, Usd::from_dollars(10_000));
+        assert_eq!(w.exclusion, Usd::from_dollars(490_000));
+        assert_eq!(w.taxable_gain, Usd::from_dollars(10_000));
+        assert!(w.long_term);
+
+        let single = sale().worksheet(FilingStatus::Single);
+        assert_eq!(single.taxable_gain, Usd::from_dollars(250_000));
+    }
+
+    #[test]
+    fn partial_exclusion_for_job_move() {
+        let mut s = sale();
+        s.ownership_days = 365;
+        s.use_days = 365;
+        s.reason = SaleReason::WorkRelated;
+        let w = s.worksheet(FilingStatus::Single);
+        assert_eq!(w.maximum_exclusion, Usd::from_dollars(125_000));
+        assert_eq!(w.taxable_gain, Usd::from_dollars(375_000));
+
+        s.reason = SaleReason::Other;
+        assert_eq!(s.worksheet(FilingStatus::Single).exclusion, Usd::ZERO);
+    }
+}
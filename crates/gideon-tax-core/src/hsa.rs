@@ -0,0 +1,131 @@
+use crate::Usd;
+use crate::rules::TaxYearRules;
+
+/// Additional contribution allowed at age 55 or older (IRC §223(b)(3)).
+pub const CATCH_UP_CONTRIBUTION: Usd = Usd::from_dollars(1_000);
+
+/// High-deductible health plan coverage type (Form 8889, line 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HsaCoverage {
+    SelfOnly,
+    Family,
+}
+
+/// Contribution limit for a full year of coverage (Form 8889, lines 3
+/// and 7), before employer contributions. Partial-year coverage and the
+/// last-month rule are not modeled.
+pub fn contribution_limit(
+    rules: &dyn TaxYearRules,
+    coverage: HsaCoverage,
+    age_55_or_older: bool,
+) -> Usd {
+    let catch_up = if age_55_or_older {
+        CATCH_UP_CONTRIBUTION
+    } else {
+        Usd::ZERO
+    };
+    rules.hsa_contribution_limit(coverage == HsaCoverage::Family) + catch_up
+}
+
+/// Form 8889, Part II inputs: distributions from a health savings account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HsaDistributions {
+    /// Total distributions received (Form 1099-SA, box 1; line 14a).
+    pub gross_distributions: Usd,
+    /// Distributions rolled over and excess contributions withdrawn by the
+    /// due date (line 14b).
+    pub rollovers_and_excess_withdrawn: Usd,
+    /// Unreimbursed qualified medical expenses paid with distributions
+    /// (line 15).
+    pub qualified_medical_expenses: Usd,
+    /// `true` if the account beneficiary was disabled, turned 65, or died
+    /// (line 17a). Waives the additional 20% tax.
+    pub additional_tax_exception: bool,
+}
+
+/// Results of Form 8889, Part II.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HsaDistributionTax {
+    /// Taxable HSA distributions (line 16), reported on Schedule 1, line 8f.
+    pub taxable_distributions: Usd,
+    /// Additional 20% tax (line 17b), reported on Schedule 2, line 17c.
+    pub additional_tax: Usd,
+}
+
+/// Computes the taxable portion of HSA distributions and the additional
+/// 20% tax on distributions not used for qualified medical expenses.
+///
+/// See: <https://www.irs.gov/instructions/i8889>
+pub fn distribution_tax(d: &HsaDistributions) -> HsaDistributionTax {
+    // Lines 14a–14c
+    let line14c = (d.gross_distributions - d.rollovers_and_excess_withdrawn).max(Usd::ZERO);
+    // Line 16
+    let taxable = (line14c - d.qualified_medical_expenses).max(Usd::ZERO);
+    // Line 17b
+    let additional_tax = if d.additional_tax_exception {
+        Usd::ZERO
+    } else {
+        taxable.mul_div(20, 100)
+    };
+
+    HsaDistributionTax {
+        taxable_distributions: taxable,
+        additional_tax,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    #[test]
+    fn contribution_limit_with_catch_up() {
+        assert_eq!(
+            contribution_limit(&Rules2025, HsaCoverage::SelfOnly, false),
+            Usd::from_dollars(4_300)
+        );
+        assert_eq!(
+            contribution_limit(&Rules2025, HsaCoverage::Family, true),
+            Usd::from_dollars(9_550)
+        );
+    }
+
+    #[test]
+    fn fully_qualified_no_tax() {
+        let d = HsaDistributions {
+            gross_distributions: Usd::from_dollars(3_000),
+            qualified_medical_expenses: Usd::from_dollars(3_500),
+            ..Default::default()
+        };
+        let t = distribution_tax(&d);
+        assert_eq!(t.taxable_distributions, Usd::ZERO);
+        assert_eq!(t.additional_tax, Usd::ZERO);
+    }
+
+    #[test]
+    fn nonqualified_portion_taxed_with_penalty() {
+        let d = HsaDistributions {
+            gross_distributions: Usd::from_dollars(5_000),
+            rollovers_and_excess_withdrawn: Usd::from_dollars(1_000),
+            qualified_medical_expenses: Usd::from_dollars(1_500),
+            additional_tax_exception: false,
+        };
+        // 5,000 - 1,000 - 1,500 = 2,500; 20% = 500
+        let t = distribution_tax(&d);
+        assert_eq!(t.taxable_distributions, Usd::from_dollars(2_500));
+        assert_eq!(t.additional_tax, Usd::from_dollars(500));
+    }
+
+    #[test]
+    fn exception_waives_additional_tax() {
+        let d = HsaDistributions {
+            gross_distributions: Usd::from_dollars(2_000),
+            additional_tax_exception: true,
+            ..Default::default()
+        };
+        let t = distribution_tax(&d);
+        assert_eq!(t.taxable_distributions, Usd::from_dollars(2_000));
+        assert_eq!(t.additional_tax, Usd::ZERO);
+    }
+}
@@ -0,0 +1,212 @@
+//! Fast path for refiguring a ledger after a payment input changes.
+//!
+//! Interactive tools refigure the return on every edit. Withholding and
+//! other payments only move the payment lines and the refund or amount
+//! owed. [`recompute`] refigures just those lines when only payment inputs
+//! change. Any other change reruns [`compute_spine`] in full.
+//!
+//! Lines through total tax are not split further. The NOL deduction is
+//! limited by taxable income figured with the deductions and exemptions.
+//! Taxable Social Security depends on the other income. The premium tax
+//! credit depends on AGI. Nearly every input therefore reaches every line
+//! from income to total tax.
+
+use crate::error::Error;
+use crate::rules::TaxYearRules;
+use crate::spine::{self, Key, Ledger, ReturnInput, compute_spine};
+
+/// A part of the spine that can be re-evaluated on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    /// Income through total tax (Form 1040, lines 1–24), which is always
+    /// refigured in full by [`compute_spine`].
+    Tax,
+    /// Withholding and other payments (lines 25–33).
+    Payments,
+    /// Refund or amount owed (lines 34–37).
+    Balance,
+}
+
+impl Stage {
+    /// Every stage, each after the stages it depends on.
+    pub const ALL: [Stage; 3] = [Stage::Tax, Stage::Payments, Stage::Balance];
+
+    /// Ledger lines the stage computes.
+    pub fn keys(self) -> &'static [Key] {
+        static KEYS: [Key; Key::ALL.len()] = Key::ALL;
+        match self {
+            Stage::Tax => &KEYS[..=Key::TotalTax as usize],
+            Stage::Payments => &KEYS[Key::Withholding as usize..=Key::TotalPayments as usize],
+            Stage::Balance => &KEYS[Key::Refund as usize..],
+        }
+    }
+
+    /// Stages whose lines this stage reads.
+    pub fn dependencies(self) -> &'static [Stage] {
+        match self {
+            Stage::Tax | Stage::Payments => &[],
+            Stage::Balance => &[Stage::Tax, Stage::Payments],
+        }
+    }
+}
+
+/// Stages to re-evaluate to go from `old` to `new`: those whose inputs
+/// differ, and every stage that depends on one of them, in
+/// [`Stage::ALL`] order.
+pub fn affected_stages(old: &ReturnInput, new: &ReturnInput) -> Vec<Stage> {
+    let mut affected: Vec<Stage> = Vec::new();
+    for stage in Stage::ALL {
+        if inputs_differ(stage, old, new)
+            || stage.dependencies().iter().any(|d| affected.contains(d))
+        {
+            affected.push(stage);
+        }
+    }
+    affected
+}
+
+/// `true` if an input `stage` reads directly differs between `old` and
+/// `new`.
+fn inputs_differ(stage: Stage, old: &ReturnInput, new: &ReturnInput) -> bool {
+    // Destructured so that a new input field must be assigned a stage.
+    let ReturnInput {
+        tax_year,
+        period,
+        deceased,
+        filing_status,
+        taxpayer,
+        spouse,
+        is_dependent,
+        is_dual_status_alien,
+        spouse_itemizes,
+        num_dependents,
+        mfs_lived_with_spouse,
+        w2_wages,
+        social_security_wages,
+        spouse_social_security_wages,
+        unreported_tips,
+        children_investment_income,
+        capital_loss_carryover,
+        nol_carryovers,
+        social_security_benefits,
+        hsa_distributions,
+        students,
+        passthroughs,
+        rental_properties,
+        home_sales,
+        adjustments,
+        itemized_deductions,
+        marketplace_coverage,
+        fed_withholding,
+        form_1099_withholding,
+        extension_payment,
+    } = old;
+    match stage {
+        Stage::Tax => {
+            *tax_year != new.tax_year
+                || *period != new.period
+                || *deceased != new.deceased
+                || *filing_status != new.filing_status
+                || *taxpayer != new.taxpayer
+                || *spouse != new.spouse
+                || *is_dependent != new.is_dependent
+                || *is_dual_status_alien != new.is_dual_status_alien
+                || *spouse_itemizes != new.spouse_itemizes
+                || *num_dependents != new.num_dependents
+                || *mfs_lived_with_spouse != new.mfs_lived_with_spouse
+                || *w2_wages != new.w2_wages
+                || *social_security_wages != new.social_security_wages
+                || *spouse_social_security_wages != new.spouse_social_security_wages
+                || *unreported_tips != new.unreported_tips
+                || *children_investment_income != new.children_investment_income
+                || *capital_loss_carryover != new.capital_loss_carryover
+                || *nol_carryovers != new.nol_carryovers
+                || *social_security_benefits != new.social_security_benefits
+                || *hsa_distributions != new.hsa_distributions
+                || *students != new.students
+                || *passthroughs != new.passthroughs
+                || *rental_properties != new.rental_properties
+                || *home_sales != new.home_sales
+                || *adjustments != new.adjustments
+                || *itemized_deductions != new.itemized_deductions
+                || *marketplace_coverage != new.marketplace_coverage
+        }
+        Stage::Payments => {
+            *fed_withholding != new.fed_withholding
+                || *form_1099_withholding != new.form_1099_withholding
+                || *extension_payment != new.extension_payment
+        }
+        Stage::Balance => false,
+    }
+}
+
+/// Recomputes `ledger`, which [`compute_spine`] returned for `old`, for
+/// `new`.
+///
+/// The result is the ledger `compute_spine(rules, new)` returns. If only
+/// payment inputs differ, the payment and balance lines are refigured in
+/// the existing ledger. Otherwise `compute_spine` is run.
+pub fn recompute(
+    rules: &dyn TaxYearRules,
+    old: &ReturnInput,
+    ledger: &Ledger,
+    new: &ReturnInput,
+) -> Result<Ledger, Error> {
+    let mut ledger = ledger.clone();
+    for stage in affected_stages(old, new) {
+        match stage {
+            Stage::Tax => return compute_spine(rules, new),
+            Stage::Payments => spine::insert_payments(&mut ledger, new),
+            Stage::Balance => spine::insert_balance(&mut ledger),
+        }
+    }
+    Ok(ledger)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Usd;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn stages_cover_every_key_once() {
+        let keys: Vec<Key> = Stage::ALL
+            .iter()
+            .flat_map(|s| s.keys().iter().copied())
+            .collect();
+        assert_eq!(keys, Key::ALL);
+        assert_eq!(Stage::Payments.keys()[0], Key::Withholding);
+        assert_eq!(Stage::Balance.keys()[0], Key::Refund);
+    }
+
+    #[test]
+    fn withholding_change_skips_the_tax_stage() {
+        let old = input(60_000, 5_000);
+        let ledger = compute_spine(&Rules2025, &old).unwrap();
+        let mut new = old.clone();
+        new.fed_withholding = Usd::from_dollars(9_000);
+        assert_eq!(
+            affected_stages(&old, &new),
+            vec![Stage::Payments, Stage::Balance]
+        );
+        let updated = recompute(&Rules2025, &old, &ledger, &new).unwrap();
+        assert_eq!(updated, compute_spine(&Rules2025, &new).unwrap());
+        assert_ne!(updated[&Key::Refund], ledger[&Key::Refund]);
+    }
+
+    #[test]
+    fn wage_change_recomputes_everything() {
+        let old = input(60_000, 5_000);
+        let ledger = compute_spine(&Rules2025, &old).unwrap();
+        let new = input(70_000, 5_000);
+        assert_eq!(
+            affected_stages(&old, &new),
+            vec![Stage::Tax, Stage::Balance]
+        );
+        let updated = recompute(&Rules2025, &old, &ledger, &new).unwrap();
+        assert_eq!(updated, compute_spine(&Rules2025, &new).unwrap());
+        assert!(affected_stages(&old, &old).is_empty());
+    }
+}
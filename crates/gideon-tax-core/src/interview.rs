@@ -0,0 +1,643 @@
+//! Eligibility interview following the IRS filing status and dependent
+//! flowcharts (Publication 501).
+//!
+//! An [`Interview`] records answers and, from them alone, works out the
+//! next [`Question`] or the [`Outcome`]. Save the interview to resume it
+//! later; change an earlier answer and the next step follows the new path,
+//! ignoring answers that no longer apply.
+
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+use crate::error::Error;
+use crate::rules::TaxYearRules;
+use crate::spine::ReturnInput;
+
+/// A question about the household or the person at `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Question {
+    ClaimedAsDependent,
+    MarriedAtYearEnd,
+    FileJointly,
+    SpouseAwayLastSixMonths,
+    ChildsMainHomeMoreThanHalfYear,
+    SpouseDiedInPriorTwoYears,
+    ChildLivedWithYouAllYear,
+    /// Answered with [`Answer::HomeCosts`]; every other question is yes or
+    /// no.
+    HomeCosts,
+    Person {
+        index: usize,
+        question: PersonQuestion,
+    },
+}
+
+impl Question {
+    /// Text to show the filer, with amounts for the year of `rules`.
+    pub fn prompt(self, rules: &dyn TaxYearRules) -> String {
+        let text = match self {
+            Question::ClaimedAsDependent => {
+                "Can anyone claim you (or your spouse, if filing jointly) as a dependent?"
+            }
+            Question::MarriedAtYearEnd => {
+                "Were you married on the last day of the year? Answer yes if your spouse died during the year."
+            }
+            Question::FileJointly => "Will you and your spouse file a joint return?",
+            Question::SpouseAwayLastSixMonths => {
+                "Did your spouse live outside your home for the last 6 months of the year?"
+            }
+            Question::ChildsMainHomeMoreThanHalfYear => {
+                "Was your home the main home of your child, stepchild, or foster child for more than half the year?"
+            }
+            Question::SpouseDiedInPriorTwoYears => {
+                "Did your spouse die in either of the two prior years, when you could have filed jointly, without you remarrying?"
+            }
+            Question::ChildLivedWithYouAllYear => {
+                "Did your son, daughter, or stepchild (not a foster child) whom you claim live with you all year?"
+            }
+            Question::HomeCosts => {
+                "What did it cost to keep up the home for the year, and how much of that did you pay? For a parent who is your qualifying person, use the parent's home."
+            }
+            Question::Person { question, .. } => return question.prompt(rules),
+        };
+        text.to_string()
+    }
+
+    /// `true` if answered with [`Answer::Yes`] or [`Answer::No`].
+    pub fn is_yes_no(self) -> bool {
+        self != Question::HomeCosts
+    }
+}
+
+/// A question about one person the filer may claim as a dependent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PersonQuestion {
+    CitizenOrResident,
+    FilesJointReturn,
+    LivedWithYouMoreThanHalfYear,
+    ChildRelationship,
+    UnderAgeLimit,
+    PermanentlyDisabled,
+    ProvidedOwnSupport,
+    QualifyingChildOfAnother,
+    IsParent,
+    OtherRelative,
+    MemberOfHouseholdAllYear,
+    GrossIncomeUnderLimit,
+    YouProvidedOverHalfSupport,
+}
+
+impl PersonQuestion {
+    /// Text to show the filer, with amounts for the year of `rules`.
+    pub fn prompt(self, rules: &dyn TaxYearRules) -> String {
+        let text = match self {
+            PersonQuestion::CitizenOrResident => {
+                "Is the person a U.S. citizen, U.S. national, or resident of the U.S., Canada, or Mexico?"
+            }
+            PersonQuestion::FilesJointReturn => {
+                "Is the person filing a joint return for the year, other than only to claim a refund?"
+            }
+            PersonQuestion::LivedWithYouMoreThanHalfYear => {
+                "Did the person live with you for more than half the year? Count temporary absences as time lived with you."
+            }
+            PersonQuestion::ChildRelationship => {
+                "Is the person your son, daughter, stepchild, foster child, brother, sister, half or step sibling, or a descendant of any of them?"
+            }
+            PersonQuestion::UnderAgeLimit => {
+                "At the end of the year, was the person younger than you (or your spouse, if filing jointly) and under 19, or under 24 and a full-time student?"
+            }
+            PersonQuestion::PermanentlyDisabled => {
+                "Was the person permanently and totally disabled at any time during the year?"
+            }
+            PersonQuestion::ProvidedOwnSupport => {
+                "Did the person provide more than half of their own support?"
+            }
+            PersonQuestion::QualifyingChildOfAnother => {
+                "Is the person the qualifying child of any other taxpayer?"
+            }
+            PersonQuestion::IsParent => {
+                "Is the person your father, mother, or an ancestor or stepparent of either?"
+            }
+            PersonQuestion::OtherRelative => {
+                "Is the person your grandparent, aunt, uncle, niece, nephew, or in-law?"
+            }
+            PersonQuestion::MemberOfHouseholdAllYear => {
+                "Did the person live with you all year as a member of your household?"
+            }
+            PersonQuestion::GrossIncomeUnderLimit => {
+                return format!(
+                    "Was the person's gross income for {} less than {}?",
+                    rules.calendar_year(),
+                    rules.dependent_gross_income_limit()
+                );
+            }
+            PersonQuestion::YouProvidedOverHalfSupport => {
+                "Did you provide more than half of the person's total support?"
+            }
+        };
+        text.to_string()
+    }
+}
+
+/// An answer to a [`Question`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Answer {
+    Yes,
+    No,
+    HomeCosts(HomeCosts),
+}
+
+impl From<bool> for Answer {
+    fn from(yes: bool) -> Self {
+        if yes { Answer::Yes } else { Answer::No }
+    }
+}
+
+/// One line of Publication 501, Worksheet 1.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HomeCost {
+    pub paid_by_you: Usd,
+    pub total: Usd,
+}
+
+/// Cost of keeping up a home (Publication 501, Worksheet 1).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HomeCosts {
+    pub property_taxes: HomeCost,
+    pub mortgage_interest: HomeCost,
+    pub rent: HomeCost,
+    pub utilities: HomeCost,
+    pub repairs: HomeCost,
+    pub property_insurance: HomeCost,
+    pub food_eaten_in_home: HomeCost,
+    pub other: HomeCost,
+}
+
+impl HomeCosts {
+    fn lines(&self) -> [HomeCost; 8] {
+        [
+            self.property_taxes,
+            self.mortgage_interest,
+            self.rent,
+            self.utilities,
+            self.repairs,
+            self.property_insurance,
+            self.food_eaten_in_home,
+            self.other,
+        ]
+    }
+
+    pub fn paid_by_you(&self) -> Usd {
+        self.lines().iter().map(|l| l.paid_by_you).sum()
+    }
+
+    pub fn total(&self) -> Usd {
+        self.lines().iter().map(|l| l.total).sum()
+    }
+
+    /// `true` if the filer paid more than half the cost of keeping up the
+    /// home.
+    pub fn more_than_half_paid(&self) -> bool {
+        self.paid_by_you() * 2 > self.total()
+    }
+}
+
+/// How a dependent qualifies (IRC §152(c) and (d)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DependentKind {
+    QualifyingChild,
+    QualifyingRelative,
+}
+
+/// A dependent's relationship to the filer, as the head of household
+/// rules group them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relationship {
+    /// Child, sibling, or a descendant of either (IRC §152(c)(2)).
+    Child,
+    /// Parent, ancestor of a parent, or stepparent.
+    Parent,
+    /// Grandparent, aunt, uncle, niece, nephew, or in-law.
+    OtherRelative,
+    /// Unrelated, living with the filer all year.
+    HouseholdMember,
+}
+
+/// A person the filer can claim as a dependent.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Dependent {
+    pub name: String,
+    pub kind: DependentKind,
+    pub relationship: Relationship,
+    pub lived_with_you_more_than_half_year: bool,
+}
+
+impl Dependent {
+    /// `true` if the dependent is a qualifying person for head of
+    /// household (Publication 501, Table 4). A parent need not live with
+    /// the filer.
+    pub fn qualifies_for_head_of_household(&self) -> bool {
+        match self.relationship {
+            Relationship::Parent => true,
+            Relationship::HouseholdMember => false,
+            Relationship::Child | Relationship::OtherRelative => {
+                self.lived_with_you_more_than_half_year
+            }
+        }
+    }
+}
+
+/// The typed inputs a finished interview produces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub filing_status: FilingStatus,
+    /// The filer can be claimed as someone else's dependent.
+    pub is_dependent: bool,
+    pub dependents: Vec<Dependent>,
+}
+
+impl Outcome {
+    /// Sets the filing status, dependency, and dependent count on `input`.
+    pub fn apply(&self, input: &mut ReturnInput) {
+        input.filing_status = self.filing_status;
+        input.is_dependent = self.is_dependent;
+        input.num_dependents = self.dependents.len() as u32;
+    }
+}
+
+/// The interview's next step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Ask(Question),
+    Done(Outcome),
+}
+
+/// Answers so far about the filer and the people they may claim.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interview {
+    /// Names of people the filer may claim, in the order asked about.
+    people: Vec<String>,
+    answers: Vec<(Question, Answer)>,
+}
+
+impl Interview {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a person the filer may claim and returns the index their
+    /// questions use.
+    pub fn add_person(&mut self, name: impl Into<String>) -> usize {
+        self.people.push(name.into());
+        self.people.len() - 1
+    }
+
+    pub fn people(&self) -> &[String] {
+        &self.people
+    }
+
+    /// Records `answer` to `question`, replacing any earlier answer.
+    ///
+    /// Returns [`Error::InvalidAnswer`] if the answer is the wrong kind
+    /// for the question, or the question is about a person not added.
+    pub fn answer(&mut self, question: Question, answer: Answer) -> Result<(), Error> {
+        let fits = question.is_yes_no() != matches!(answer, Answer::HomeCosts(_));
+        let known = match question {
+            Question::Person { index, .. } => index < self.people.len(),
+            _ => true,
+        };
+        if !fits || !known {
+            return Err(Error::InvalidAnswer(question));
+        }
+        match self.answers.iter_mut().find(|(q, _)| *q == question) {
+            Some((_, a)) => *a = answer,
+            None => self.answers.push((question, answer)),
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, question: Question) -> Option<Answer> {
+        self.answers
+            .iter()
+            .find(|(q, _)| *q == question)
+            .map(|(_, a)| *a)
+    }
+
+    /// The next unanswered question on the path the answers take, or the
+    /// outcome once there is none.
+    pub fn next(&self) -> Step {
+        match self.outcome() {
+            Ok(outcome) => Step::Done(outcome),
+            Err(question) => Step::Ask(question),
+        }
+    }
+
+    fn yes(&self, question: Question) -> Result<bool, Question> {
+        match self.get(question) {
+            Some(Answer::Yes) => Ok(true),
+            Some(Answer::No) => Ok(false),
+            _ => Err(question),
+        }
+    }
+
+    fn home_costs(&self) -> Result<HomeCosts, Question> {
+        match self.get(Question::HomeCosts) {
+            Some(Answer::HomeCosts(costs)) => Ok(costs),
+            _ => Err(Question::HomeCosts),
+        }
+    }
+
+    fn outcome(&self) -> Result<Outcome, Question> {
+        // A dependent cannot claim dependents (IRC §152(b)(1)).
+        let is_dependent = self.yes(Question::ClaimedAsDependent)?;
+        let mut dependents = Vec::new();
+        if !is_dependent {
+            for index in 0..self.people.len() {
+                dependents.extend(self.dependent(index)?);
+            }
+        }
+        Ok(Outcome {
+            filing_status: self.filing_status(&dependents)?,
+            is_dependent,
+            dependents,
+        })
+    }
+
+    /// Qualifying child, then qualifying relative tests for the person at
+    /// `index` (Publication 501, Table 5).
+    fn dependent(&self, index: usize) -> Result<Option<Dependent>, Question> {
+        let ask = |question| self.yes(Question::Person { index, question });
+        if !ask(PersonQuestion::CitizenOrResident)? || ask(PersonQuestion::FilesJointReturn)? {
+            return Ok(None);
+        }
+        let lived_with_you = ask(PersonQuestion::LivedWithYouMoreThanHalfYear)?;
+        let claim = |kind, relationship| {
+            Some(Dependent {
+                name: self.people[index].clone(),
+                kind,
+                relationship,
+                lived_with_you_more_than_half_year: lived_with_you,
+            })
+        };
+
+        let child = ask(PersonQuestion::ChildRelationship)?;
+        if child
+            && (ask(PersonQuestion::UnderAgeLimit)? || ask(PersonQuestion::PermanentlyDisabled)?)
+            && lived_with_you
+            && !ask(PersonQuestion::ProvidedOwnSupport)?
+        {
+            return Ok(claim(DependentKind::QualifyingChild, Relationship::Child));
+        }
+
+        if ask(PersonQuestion::QualifyingChildOfAnother)? {
+            return Ok(None);
+        }
+        let relationship = if child {
+            Relationship::Child
+        } else if ask(PersonQuestion::IsParent)? {
+            Relationship::Parent
+        } else if ask(PersonQuestion::OtherRelative)? {
+            Relationship::OtherRelative
+        } else if ask(PersonQuestion::MemberOfHouseholdAllYear)? {
+            Relationship::HouseholdMember
+        } else {
+            return Ok(None);
+        };
+        if ask(PersonQuestion::GrossIncomeUnderLimit)?
+            && ask(PersonQuestion::YouProvidedOverHalfSupport)?
+        {
+            Ok(claim(DependentKind::QualifyingRelative, relationship))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Filing status (Publication 501, Table 1 and Figure 1).
+    fn filing_status(&self, dependents: &[Dependent]) -> Result<FilingStatus, Question> {
+        if self.yes(Question::MarriedAtYearEnd)? {
+            if self.yes(Question::FileJointly)? {
+                return Ok(FilingStatus::MarriedFilingJointly);
+            }
+            // Considered unmarried (IRC §7703(b)).
+            let considered_unmarried = self.yes(Question::SpouseAwayLastSixMonths)?
+                && dependents
+                    .iter()
+                    .any(|d| d.kind == DependentKind::QualifyingChild)
+                && self.yes(Question::ChildsMainHomeMoreThanHalfYear)?
+                && self.home_costs()?.more_than_half_paid();
+            return Ok(if considered_unmarried {
+                FilingStatus::HeadOfHousehold
+            } else {
+                FilingStatus::MarriedFilingSeparately
+            });
+        }
+        if !dependents.is_empty()
+            && self.yes(Question::SpouseDiedInPriorTwoYears)?
+            && self.yes(Question::ChildLivedWithYouAllYear)?
+            && self.home_costs()?.more_than_half_paid()
+        {
+            return Ok(FilingStatus::QualifyingSurvivingSpouse);
+        }
+        if dependents
+            .iter()
+            .any(Dependent::qualifies_for_head_of_household)
+            && self.home_costs()?.more_than_half_paid()
+        {
+            return Ok(FilingStatus::HeadOfHousehold);
+        }
+        Ok(FilingStatus::Single)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2023::Rules2023;
+    use crate::rules::y2025::Rules2025;
+    use crate::rules::y2026::Rules2026Projected;
+    use crate::spine::tests::input;
+
+    fn costs(paid_by_you: i64, total: i64) -> Answer {
+        Answer::HomeCosts(HomeCosts {
+            rent: HomeCost {
+                paid_by_you: Usd::from_dollars(paid_by_you),
+                total: Usd::from_dollars(total),
+            },
+            ..Default::default()
+        })
+    }
+
+    /// Answers each question with `answer` until the interview is done,
+    /// returning the questions asked and the outcome.
+    fn run(
+        mut interview: Interview,
+        answer: impl Fn(Question) -> Answer,
+    ) -> (Vec<Question>, Outcome) {
+        let mut asked = Vec::new();
+        loop {
+            match interview.next() {
+                Step::Ask(q) => {
+                    asked.push(q);
+                    interview.answer(q, answer(q)).unwrap();
+                }
+                Step::Done(outcome) => return (asked, outcome),
+            }
+        }
+    }
+
+    fn person(question: PersonQuestion) -> Question {
+        Question::Person { index: 0, question }
+    }
+
+    #[test]
+    fn married_filing_jointly() {
+        let (asked, outcome) = run(Interview::new(), |q| {
+            Answer::from(matches!(
+                q,
+                Question::MarriedAtYearEnd | Question::FileJointly
+            ))
+        });
+        assert_eq!(
+            asked,
+            vec![
+                Question::ClaimedAsDependent,
+                Question::MarriedAtYearEnd,
+                Question::FileJointly
+            ]
+        );
+        assert_eq!(outcome.filing_status, FilingStatus::MarriedFilingJointly);
+        assert!(outcome.dependents.is_empty());
+    }
+
+    fn single_parent(paid_by_you: i64) -> (Vec<Question>, Outcome) {
+        let mut interview = Interview::new();
+        interview.add_person("Ada");
+        run(interview, |q| match q {
+            Question::HomeCosts => costs(paid_by_you, 20_000),
+            Question::Person { question, .. } => Answer::from(matches!(
+                question,
+                PersonQuestion::CitizenOrResident
+                    | PersonQuestion::LivedWithYouMoreThanHalfYear
+                    | PersonQuestion::ChildRelationship
+                    | PersonQuestion::UnderAgeLimit
+            )),
+            _ => Answer::No,
+        })
+    }
+
+    #[test]
+    fn single_parent_paying_most_costs_is_head_of_household() {
+        let (asked, outcome) = single_parent(12_000);
+        assert_eq!(outcome.filing_status, FilingStatus::HeadOfHousehold);
+        assert_eq!(
+            outcome.dependents,
+            vec![Dependent {
+                name: "Ada".into(),
+                kind: DependentKind::QualifyingChild,
+                relationship: Relationship::Child,
+                lived_with_you_more_than_half_year: true,
+            }]
+        );
+        assert!(!asked.contains(&person(PersonQuestion::PermanentlyDisabled)));
+        assert!(!asked.contains(&person(PersonQuestion::QualifyingChildOfAnother)));
+
+        let mut inp = input(50_000, 0);
+        outcome.apply(&mut inp);
+        assert_eq!(inp.filing_status, FilingStatus::HeadOfHousehold);
+        assert_eq!(inp.num_dependents, 1);
+    }
+
+    #[test]
+    fn paying_half_the_costs_is_not_enough() {
+        let (_, outcome) = single_parent(10_000);
+        assert_eq!(outcome.filing_status, FilingStatus::Single);
+        assert_eq!(outcome.dependents.len(), 1);
+    }
+
+    #[test]
+    fn adult_child_can_be_a_qualifying_relative() {
+        let mut interview = Interview::new();
+        interview.add_person("Sam");
+        let (_, outcome) = run(interview, |q| match q {
+            Question::HomeCosts => costs(15_000, 20_000),
+            Question::Person { question, .. } => Answer::from(matches!(
+                question,
+                PersonQuestion::CitizenOrResident
+                    | PersonQuestion::LivedWithYouMoreThanHalfYear
+                    | PersonQuestion::ChildRelationship
+                    | PersonQuestion::GrossIncomeUnderLimit
+                    | PersonQuestion::YouProvidedOverHalfSupport
+            )),
+            _ => Answer::No,
+        });
+        assert_eq!(
+            outcome.dependents[0].kind,
+            DependentKind::QualifyingRelative
+        );
+        assert_eq!(outcome.filing_status, FilingStatus::HeadOfHousehold);
+    }
+
+    #[test]
+    fn changing_an_answer_resumes_on_the_new_path() {
+        let mut interview = Interview::new();
+        interview
+            .answer(Question::ClaimedAsDependent, Answer::No)
+            .unwrap();
+        interview
+            .answer(Question::MarriedAtYearEnd, Answer::Yes)
+            .unwrap();
+        interview.answer(Question::FileJointly, Answer::No).unwrap();
+        assert_eq!(
+            interview.next(),
+            Step::Ask(Question::SpouseAwayLastSixMonths)
+        );
+
+        interview
+            .answer(Question::MarriedAtYearEnd, Answer::No)
+            .unwrap();
+        match interview.next() {
+            Step::Done(outcome) => assert_eq!(outcome.filing_status, FilingStatus::Single),
+            step => panic!("expected done, got {step:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_answers_that_do_not_fit() {
+        let mut interview = Interview::new();
+        let e = interview
+            .answer(Question::HomeCosts, Answer::Yes)
+            .unwrap_err();
+        assert_eq!(e.code(), "interview.invalid_answer");
+        assert!(
+            interview
+                .answer(Question::FileJointly, costs(1, 2))
+                .is_err()
+        );
+        assert!(
+            interview
+                .answer(person(PersonQuestion::IsParent), Answer::Yes)
+                .is_err()
+        );
+        assert_eq!(interview.get(Question::HomeCosts), None);
+    }
+
+    #[test]
+    fn gross_income_prompt_uses_the_year_limit() {
+        let q = person(PersonQuestion::GrossIncomeUnderLimit);
+        assert_eq!(
+            q.prompt(&Rules2025),
+            "Was the person's gross income for 2025 less than $5200.00?"
+        );
+        assert!(q.prompt(&Rules2023).contains("2023 less than $4700.00"));
+        assert!(
+            q.prompt(&Rules2026Projected)
+                .contains("2026 less than $5300.00")
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+use crate::rules::TaxYearRules;
+
+/// Coverage by a retirement plan at work (Form W-2, box 13), which
+/// limits the traditional IRA deduction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PlanCoverage {
+    #[default]
+    None,
+    /// The taxpayer is covered.
+    Covered,
+    /// Only the taxpayer's spouse is covered.
+    SpouseCovered,
+}
+
+/// Maximum combined traditional and Roth IRA contributions for the year:
+/// the annual limit, plus the catch-up contribution at age 50 or older,
+/// but no more than taxable compensation (IRC §219(b)(1), §408A(c)(2)).
+///
+/// For a spousal IRA on a joint return, `compensation` is the couple's
+/// combined compensation less the other spouse's IRA contributions.
+///
+/// See: <https://www.irs.gov/publications/p590a>
+pub fn contribution_limit(
+    rules: &dyn TaxYearRules,
+    age_50_or_older: bool,
+    compensation: Usd,
+) -> Usd {
+    let catch_up = if age_50_or_older {
+        rules.ira_catch_up_contribution()
+    } else {
+        Usd::ZERO
+    };
+    (rules.ira_contribution_limit() + catch_up).min(compensation.max(Usd::ZERO))
+}
+
+/// Most of `contribution_limit` that is deductible as a traditional IRA
+/// contribution, after the phase-out for workplace plan coverage.
+///
+/// The limit is reduced ratably over $10,000 of modified AGI, or $20,000
+/// for a covered taxpayer filing jointly, rounded up to the next $10,
+/// and is at least $200 until fully phased out (IRC §219(g)(2)).
+///
+/// See: <https://www.irs.gov/publications/p590a>
+pub fn deduction_limit(
+    rules: &dyn TaxYearRules,
+    status: FilingStatus,
+    coverage: PlanCoverage,
+    modified_agi: Usd,
+    contribution_limit: Usd,
+) -> Usd {
+    let start = match coverage {
+        PlanCoverage::None => return contribution_limit,
+        PlanCoverage::Covered => rules.ira_deduction_phaseout_start(status, false),
+        PlanCoverage::SpouseCovered => rules.ira_deduction_phaseout_start(status, true),
+    };
+    let joint = matches!(
+        status,
+        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse
+    );
+    let width = if joint && coverage == PlanCoverage::Covered {
+        Usd::from_dollars(20_000)
+    } else {
+        Usd::from_dollars(10_000)
+    };
+    let excess = (modified_agi - start).max(Usd::ZERO);
+    if excess >= width {
+        return Usd::ZERO;
+    }
+    let reduced = contribution_limit - contribution_limit.mul_div(excess.cents(), width.cents());
+    let rounded = Usd::from_dollars((reduced.cents() + 999) / 1_000 * 10);
+    rounded.max(Usd::from_dollars(200)).min(contribution_limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    #[test]
+    fn annual_limit_with_catch_up() {
+        let comp = Usd::from_dollars(80_000);
+        assert_eq!(
+            contribution_limit(&Rules2025, false, comp),
+            Usd::from_dollars(7_000)
+        );
+        assert_eq!(
+            contribution_limit(&Rules2025, true, comp),
+            Usd::from_dollars(8_000)
+        );
+    }
+
+    #[test]
+    fn deduction_phased_out_for_covered_taxpayer() {
+        let limit = Usd::from_dollars(7_000);
+        let deductible = |coverage, magi| {
+            deduction_limit(
+                &Rules2025,
+                FilingStatus::Single,
+                coverage,
+                Usd::from_dollars(magi),
+                limit,
+            )
+        };
+        assert_eq!(deductible(PlanCoverage::None, 200_000), limit);
+        assert_eq!(deductible(PlanCoverage::Covered, 79_000), limit);
+        // 7,000 − 7,000 × 2,345 / 10,000 = 5,358.50, rounded up to 5,360.
+        assert_eq!(
+            deductible(PlanCoverage::Covered, 81_345),
+            Usd::from_dollars(5_360)
+        );
+        assert_eq!(
+            deductible(PlanCoverage::Covered, 88_990),
+            Usd::from_dollars(200)
+        );
+        assert_eq!(deductible(PlanCoverage::Covered, 89_000), Usd::ZERO);
+    }
+
+    #[test]
+    fn limited_to_compensation() {
+        assert_eq!(
+            contribution_limit(&Rules2025, true, Usd::from_dollars(3_200)),
+            Usd::from_dollars(3_200)
+        );
+    }
+}
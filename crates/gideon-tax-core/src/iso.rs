@@ -0,0 +1,203 @@
+use crate::Usd;
+use crate::amt::{self, AmtAdjustment, Form6251};
+use crate::ledger::Ledger;
+use crate::minimum_tax_credit::AmtItem;
+use crate::rules::TaxYearRules;
+use crate::spine::ReturnInput;
+
+/// Exercise of incentive stock options held after exercise, so the
+/// bargain element is an AMT adjustment but not wages (IRC §56(b)(3)).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IsoExercise {
+    pub shares: u64,
+    /// Exercise price per share.
+    pub strike_price: Usd,
+    /// Fair market value per share on the exercise date.
+    pub fair_market_value: Usd,
+}
+
+impl IsoExercise {
+    /// Fair market value less the exercise price of all shares.
+    pub fn bargain_element(&self) -> Usd {
+        (self.fair_market_value - self.strike_price).mul_div(self.shares as i64, 1)
+    }
+
+    /// Shares acquired, with their regular and AMT bases for a later sale.
+    pub fn lot(&self) -> IsoLot {
+        IsoLot {
+            shares: self.shares,
+            regular_basis: self.strike_price.mul_div(self.shares as i64, 1),
+            amt_basis: self.fair_market_value.mul_div(self.shares as i64, 1),
+        }
+    }
+}
+
+/// Shares acquired by exercising incentive stock options. The AMT basis
+/// includes the bargain element, so a later sale reverses it as a Form
+/// 6251, line 2k, adjustment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IsoLot {
+    pub shares: u64,
+    pub regular_basis: Usd,
+    pub amt_basis: Usd,
+}
+
+impl IsoLot {
+    /// Form 6251, line 2k, adjustment for selling the lot: the regular
+    /// gain less the AMT gain, negative for a sale in a later year.
+    pub fn disposition_adjustment(&self) -> Usd {
+        self.regular_basis - self.amt_basis
+    }
+}
+
+/// Effect of a year's ISO exercises on the AMT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsoPlan {
+    /// Form 6251, line 2i.
+    pub bargain_element: Usd,
+    pub before: Form6251,
+    pub after: Form6251,
+    /// Additional AMT from the exercises.
+    pub amt_increase: Usd,
+    /// AMT for the year from deferral items, which becomes minimum tax
+    /// credit for later years (Form 8801).
+    pub credit_generated: Usd,
+    pub lots: Vec<IsoLot>,
+}
+
+/// Plans `exercises` for the return in `ledger`, which has the year's other
+/// AMT `adjustments`.
+///
+/// The spine does not include the AMT in [`Key::TotalTax`]; the year's
+/// total tax with the exercises is that line plus `after.amt` (Schedule 2,
+/// line 1).
+///
+/// [`Key::TotalTax`]: crate::ledger::Key::TotalTax
+pub fn plan_iso_exercise(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    adjustments: &[AmtAdjustment],
+    exercises: &[IsoExercise],
+) -> IsoPlan {
+    let bargain_element: Usd = exercises.iter().map(IsoExercise::bargain_element).sum();
+    let with_exercises = with_bargain_element(adjustments, bargain_element);
+    let before = amt::form_6251(rules, input, ledger, adjustments);
+    let after = amt::form_6251(rules, input, ledger, &with_exercises);
+    let exclusion_amt = amt::exclusion_items_amt(rules, input, ledger, &with_exercises);
+    IsoPlan {
+        bargain_element,
+        before,
+        after,
+        amt_increase: after.amt - before.amt,
+        credit_generated: (after.amt - exclusion_amt).max(Usd::ZERO),
+        lots: exercises.iter().map(IsoExercise::lot).collect(),
+    }
+}
+
+/// Largest bargain element that can be realized this year without owing
+/// AMT, to the cent.
+pub fn amt_free_bargain_element(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    adjustments: &[AmtAdjustment],
+) -> Usd {
+    let amt_with = |bargain: i64| {
+        amt::form_6251(
+            rules,
+            input,
+            ledger,
+            &with_bargain_element(adjustments, Usd::from_cents(bargain)),
+        )
+        .amt
+    };
+    if amt_with(0) > Usd::ZERO {
+        return Usd::ZERO;
+    }
+    // The AMT never falls as the bargain element grows, and beyond the
+    // exemption each dollar adds at least 26 cents of tentative minimum
+    // tax, so five times the regular tax plus the exemption bounds the
+    // search.
+    let mut low = 0;
+    let mut high = 1
+        + amt::form_6251(rules, input, ledger, adjustments)
+            .regular_tax
+            .cents()
+            * 5
+        + rules.amt_exemption(input.filing_status).cents() * 5;
+    while low + 1 < high {
+        let mid = low + (high - low) / 2;
+        if amt_with(mid) > Usd::ZERO {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    Usd::from_cents(low)
+}
+
+fn with_bargain_element(adjustments: &[AmtAdjustment], bargain_element: Usd) -> Vec<AmtAdjustment> {
+    let mut all = adjustments.to_vec();
+    all.push(AmtAdjustment {
+        item: AmtItem::IncentiveStockOptions,
+        amount: bargain_element,
+    });
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn exercise_creates_amt_and_credit() {
+        let inp = input(200_000, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let exercise = IsoExercise {
+            shares: 1_000,
+            strike_price: Usd::from_dollars(10),
+            fair_market_value: Usd::from_dollars(110),
+        };
+        let plan = plan_iso_exercise(&Rules2025, &inp, &ledger, &[], &[exercise]);
+        assert_eq!(plan.bargain_element, Usd::from_dollars(100_000));
+        assert_eq!(plan.before.amt, Usd::ZERO);
+        assert!(plan.amt_increase > Usd::ZERO);
+        // All of the AMT comes from the deferral item.
+        assert_eq!(plan.credit_generated, plan.after.amt);
+        assert_eq!(
+            plan.lots,
+            vec![IsoLot {
+                shares: 1_000,
+                regular_basis: Usd::from_dollars(10_000),
+                amt_basis: Usd::from_dollars(110_000),
+            }]
+        );
+        assert_eq!(
+            plan.lots[0].disposition_adjustment(),
+            Usd::from_dollars(-100_000)
+        );
+    }
+
+    #[test]
+    fn sizes_exercise_to_amt_crossover() {
+        let inp = input(200_000, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let limit = amt_free_bargain_element(&Rules2025, &inp, &ledger, &[]);
+        assert!(limit > Usd::ZERO);
+        let amt_at = |bargain: Usd| {
+            amt::form_6251(
+                &Rules2025,
+                &inp,
+                &ledger,
+                &with_bargain_element(&[], bargain),
+            )
+            .amt
+        };
+        assert_eq!(amt_at(limit), Usd::ZERO);
+        assert!(amt_at(limit + Usd::from_cents(1)) > Usd::ZERO);
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt;
+use std::ops::Index;
+
+use crate::Usd;
+
+/// A line computed by the spine, in Form 1040 order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Key {
+    TaxableInterest,
+    OrdinaryDividends,
+    TaxableSocialSecurity,
+    CapitalGainOrLoss,
+    SupplementalIncome,
+    TaxableHsaDistributions,
+    TaxableScholarships,
+    ChildInvestmentIncome,
+    NolDeduction,
+    TotalIncome,
+    SelfEmploymentTaxDeduction,
+    Adjustments,
+    AGI,
+    Deductions,
+    QbiDeduction,
+    Exemptions,
+    TaxableIncome,
+    RegularTax,
+    ChildInvestmentTax,
+    SelfEmploymentTax,
+    HsaAdditionalTax,
+    UnreportedTipsTax,
+    ExcessAdvancePremiumTaxCredit,
+    AdditionalTax,
+    TotalTaxPreCredits,
+    NonRefundableCredits,
+    TaxAfterNonRefundableCredits,
+    NetPremiumTaxCredit,
+    RefundableCredits,
+    TotalTax,
+    Withholding,
+    EstimatedPayments,
+    ExtensionPayment,
+    TotalPayments,
+    Refund,
+    AmountOwed,
+}
+
+impl Key {
+    /// Every key, in form order.
+    pub const ALL: [Key; 36] = [
+        Key::TaxableInterest,
+        Key::OrdinaryDividends,
+        Key::TaxableSocialSecurity,
+        Key::CapitalGainOrLoss,
+        Key::SupplementalIncome,
+        Key::TaxableHsaDistributions,
+        Key::TaxableScholarships,
+        Key::ChildInvestmentIncome,
+        Key::NolDeduction,
+        Key::TotalIncome,
+        Key::SelfEmploymentTaxDeduction,
+        Key::Adjustments,
+        Key::AGI,
+        Key::Deductions,
+        Key::QbiDeduction,
+        Key::Exemptions,
+        Key::TaxableIncome,
+        Key::RegularTax,
+        Key::ChildInvestmentTax,
+        Key::SelfEmploymentTax,
+        Key::HsaAdditionalTax,
+        Key::UnreportedTipsTax,
+        Key::ExcessAdvancePremiumTaxCredit,
+        Key::AdditionalTax,
+        Key::TotalTaxPreCredits,
+        Key::NonRefundableCredits,
+        Key::TaxAfterNonRefundableCredits,
+        Key::NetPremiumTaxCredit,
+        Key::RefundableCredits,
+        Key::TotalTax,
+        Key::Withholding,
+        Key::EstimatedPayments,
+        Key::ExtensionPayment,
+        Key::TotalPayments,
+        Key::Refund,
+        Key::AmountOwed,
+    ];
+
+    /// Plain-language name of the line.
+    pub fn label(self) -> &'static str {
+        match self {
+            Key::TaxableInterest => "Taxable interest",
+            Key::OrdinaryDividends => "Ordinary dividends",
+            Key::TaxableSocialSecurity => "Taxable social security benefits",
+            Key::CapitalGainOrLoss => "Capital gain or loss",
+            Key::SupplementalIncome => "Supplemental income (Schedule E)",
+            Key::TaxableHsaDistributions => "Taxable HSA distributions",
+            Key::TaxableScholarships => "Taxable scholarships",
+            Key::ChildInvestmentIncome => "Child's interest and dividends (Form 8814)",
+            Key::NolDeduction => "Net operating loss deduction",
+            Key::TotalIncome => "Total income",
+            Key::SelfEmploymentTaxDeduction => "Deductible part of self-employment tax",
+            Key::Adjustments => "Adjustments to income",
+            Key::AGI => "Adjusted gross income",
+            Key::Deductions => "Standard or itemized deduction",
+            Key::QbiDeduction => "Qualified business income deduction",
+            Key::Exemptions => "Exemptions",
+            Key::TaxableIncome => "Taxable income",
+            Key::RegularTax => "Tax",
+            Key::ChildInvestmentTax => "Tax on child's interest and dividends (Form 8814)",
+            Key::SelfEmploymentTax => "Self-employment tax",
+            Key::HsaAdditionalTax => "Additional tax on HSA distributions",
+            Key::UnreportedTipsTax => "Social security and Medicare tax on unreported tips",
+            Key::ExcessAdvancePremiumTaxCredit => "Excess advance premium tax credit repayment",
+            Key::AdditionalTax => "Additional taxes",
+            Key::TotalTaxPreCredits => "Tax before credits",
+            Key::NonRefundableCredits => "Nonrefundable credits",
+            Key::TaxAfterNonRefundableCredits => "Tax after nonrefundable credits",
+            Key::NetPremiumTaxCredit => "Net premium tax credit",
+            Key::RefundableCredits => "Refundable credits",
+            Key::TotalTax => "Total tax",
+            Key::Withholding => "Federal income tax withheld",
+            Key::EstimatedPayments => "Estimated tax payments",
+            Key::ExtensionPayment => "Amount paid with extension request",
+            Key::TotalPayments => "Total payments",
+            Key::Refund => "Refund",
+            Key::AmountOwed => "Amount owed",
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Amounts computed by the spine, keyed by [`Key`].
+///
+/// Stored inline in a fixed array indexed by key, so building a ledger does
+/// not allocate. Iteration is in form order, like the `BTreeMap` this
+/// replaces.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Ledger {
+    values: [Option<Usd>; Key::ALL.len()],
+}
+
+impl Ledger {
+    /// An empty ledger.
+    pub const fn new() -> Self {
+        Ledger {
+            values: [None; Key::ALL.len()],
+        }
+    }
+
+    /// Sets `key` to `value`, returning the previous value if any.
+    pub fn insert(&mut self, key: Key, value: Usd) -> Option<Usd> {
+        self.values[key.index()].replace(value)
+    }
+
+    /// Clears `key`, returning its value if any.
+    pub fn remove(&mut self, key: &Key) -> Option<Usd> {
+        self.values[key.index()].take()
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Usd> {
+        self.values[key.index()].as_ref()
+    }
+
+    pub fn contains_key(&self, key: &Key) -> bool {
+        self.values[key.index()].is_some()
+    }
+
+    /// Number of keys set.
+    pub fn len(&self) -> usize {
+        self.values.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(Option::is_none)
+    }
+
+    /// Set keys and their amounts, in form order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &Usd)> {
+        Key::ALL
+            .iter()
+            .zip(&self.values)
+            .filter_map(|(k, v)| v.as_ref().map(|v| (k, v)))
+    }
+
+    /// Set keys, in form order.
+    pub fn keys(&self) -> impl Iterator<Item = &Key> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Amounts, in form order of their keys.
+    pub fn values(&self) -> impl Iterator<Item = &Usd> {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+impl Default for Ledger {
+    fn default() -> Self {
+        Ledger::new()
+    }
+}
+
+impl Index<&Key> for Ledger {
+    type Output = Usd;
+
+    /// Panics if `key` is not set.
+    fn index(&self, key: &Key) -> &Usd {
+        self.get(key)
+            .unwrap_or_else(|| panic!("ledger has no value for {key:?}"))
+    }
+}
+
+impl FromIterator<(Key, Usd)> for Ledger {
+    fn from_iter<I: IntoIterator<Item = (Key, Usd)>>(iter: I) -> Self {
+        let mut ledger = Ledger::new();
+        ledger.extend(iter);
+        ledger
+    }
+}
+
+impl Extend<(Key, Usd)> for Ledger {
+    fn extend<I: IntoIterator<Item = (Key, Usd)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl fmt::Debug for Ledger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Serializes as a map in form order, the same shape as a `BTreeMap`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Ledger {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Ledger {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = <std::collections::BTreeMap<Key, Usd> as serde::Deserialize>::deserialize(
+            deserializer,
+        )?;
+        Ok(map.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_is_in_declaration_order() {
+        for (i, key) in Key::ALL.iter().enumerate() {
+            assert_eq!(key.index(), i, "{key:?}");
+        }
+    }
+
+    #[test]
+    fn map_like_api() {
+        let mut ledger = Ledger::new();
+        assert!(ledger.is_empty());
+        assert_eq!(ledger.insert(Key::AGI, Usd::from_dollars(10)), None);
+        assert_eq!(
+            ledger.insert(Key::AGI, Usd::from_dollars(20)),
+            Some(Usd::from_dollars(10))
+        );
+        ledger.insert(Key::TaxableInterest, Usd::from_dollars(5));
+        assert!(ledger.contains_key(&Key::AGI));
+        assert!(!ledger.contains_key(&Key::Refund));
+        assert_eq!(ledger.get(&Key::Refund), None);
+        assert_eq!(ledger[&Key::AGI], Usd::from_dollars(20));
+        assert_eq!(ledger.len(), 2);
+        assert_eq!(ledger.remove(&Key::AGI), Some(Usd::from_dollars(20)));
+        assert_eq!(ledger.len(), 1);
+    }
+
+    #[test]
+    fn iterates_in_form_order() {
+        let ledger: Ledger = [
+            (Key::AmountOwed, Usd::ZERO),
+            (Key::AGI, Usd::from_dollars(1)),
+            (Key::TaxableInterest, Usd::from_dollars(2)),
+        ]
+        .into_iter()
+        .collect();
+        let keys: Vec<Key> = ledger.keys().copied().collect();
+        assert_eq!(keys, [Key::TaxableInterest, Key::AGI, Key::AmountOwed]);
+        assert_eq!(
+            format!("{ledger:?}"),
+            "{TaxableInterest: Usd(200), AGI: Usd(100), AmountOwed: Usd(0)}"
+        );
+    }
+}
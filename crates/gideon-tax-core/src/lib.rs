@@ -1,6 +1,72 @@
+pub mod adjustments;
+pub mod amt;
+pub mod at_risk;
+pub mod audit_risk;
+pub mod batch;
+pub mod capital_gain_tax;
+pub mod capital_loss;
+pub mod casualty;
+pub mod charitable;
+pub mod charitable_bunching;
+pub mod combined;
+pub mod credit_disallowance;
+pub mod decedent;
+pub mod depreciation;
+pub mod diagnostics;
+pub mod disclosure;
+pub mod education;
+pub mod employee_expenses;
+pub mod employer_coverage;
+pub mod equity_compensation;
+pub mod error;
+pub mod estimated_tax;
+pub mod extension;
+pub mod fingerprint;
+pub mod foreign_tax_credit;
+pub mod gain_harvesting;
+pub mod headroom;
+pub mod home_sale;
+pub mod hsa;
+pub mod incremental;
+pub mod interview;
+pub mod ira;
+pub mod iso;
+pub mod ledger;
+pub mod minimum_tax_credit;
+pub mod moving;
+pub mod nol;
+pub mod parents_election;
+pub mod passive_loss;
+pub mod passthrough;
+pub mod period;
+pub mod portfolio;
+pub mod premium_tax_credit;
+pub mod projection;
+pub mod qbi;
+pub mod refund_change;
+pub mod rental;
+pub mod report;
+pub mod retirement_optimizer;
+pub mod review;
 pub mod rules;
+pub mod schedule_a;
+pub mod self_employment;
+pub mod social_security;
 pub mod spine;
+pub mod state;
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategies;
+pub mod tips;
+pub mod trace;
 pub mod types;
+pub mod w4;
+pub mod withholding;
+pub mod worksheet;
 
+pub use error::{Error, Result};
+pub use types::Date;
 pub use types::Filer;
+pub use types::Owner;
+pub use types::Rate;
 pub use types::Usd;
+pub use us_tax_brackets::{FilingStatus, TaxYear};
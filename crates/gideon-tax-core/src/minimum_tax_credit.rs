@@ -0,0 +1,139 @@
+use crate::Usd;
+
+/// Whether an AMT adjustment or preference permanently excludes income from
+/// regular tax or only changes when it is taxed. Only AMT from deferral
+/// items generates the minimum tax credit (IRC §53(d)(1)(B)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreferenceKind {
+    Exclusion,
+    Deferral,
+}
+
+/// Adjustments and preferences on Form 6251, Part I.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmtItem {
+    /// Line 2a: taxes deducted on Schedule A, or the standard deduction.
+    TaxesOrStandardDeduction,
+    /// Line 2c: investment interest expense.
+    InvestmentInterest,
+    /// Line 2d: depletion.
+    Depletion,
+    /// Line 2f: net operating loss deduction.
+    NetOperatingLoss,
+    /// Line 2g: interest from specified private activity bonds.
+    PrivateActivityBondInterest,
+    /// Line 2h: qualified small business stock exclusion.
+    SmallBusinessStock,
+    /// Line 2i: exercise of incentive stock options.
+    IncentiveStockOptions,
+    /// Line 2k: disposition of property.
+    PropertyDisposition,
+    /// Line 2l: depreciation on assets placed in service after 1986.
+    Depreciation,
+    /// Line 2m: passive activities.
+    PassiveActivities,
+    /// Line 2n: loss limitations.
+    LossLimitations,
+    /// Line 2t: intangible drilling costs.
+    IntangibleDrillingCosts,
+}
+
+impl AmtItem {
+    /// Classification used for Form 8801, Part I (line 2).
+    pub fn kind(self) -> PreferenceKind {
+        match self {
+            AmtItem::TaxesOrStandardDeduction
+            | AmtItem::InvestmentInterest
+            | AmtItem::Depletion
+            | AmtItem::PrivateActivityBondInterest
+            | AmtItem::SmallBusinessStock => PreferenceKind::Exclusion,
+            AmtItem::NetOperatingLoss
+            | AmtItem::IncentiveStockOptions
+            | AmtItem::PropertyDisposition
+            | AmtItem::Depreciation
+            | AmtItem::PassiveActivities
+            | AmtItem::LossLimitations
+            | AmtItem::IntangibleDrillingCosts => PreferenceKind::Deferral,
+        }
+    }
+}
+
+/// Form 8801 inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MinimumTaxCreditInput {
+    /// Prior-year AMT (prior-year Form 6251, line 11) (line 19).
+    pub prior_year_amt: Usd,
+    /// Prior-year minimum tax figured on exclusion items only (line 20).
+    pub prior_year_exclusion_amt: Usd,
+    /// Credit carried forward from the prior year's Form 8801.
+    pub carryforward: Usd,
+    /// This year's regular tax less nonrefundable credits other than the
+    /// minimum tax credit.
+    pub regular_tax_less_credits: Usd,
+    /// This year's tentative minimum tax (Form 6251, line 9).
+    pub tentative_minimum_tax: Usd,
+}
+
+/// Results of Form 8801, Part II.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MinimumTaxCredit {
+    /// Credit available: deferral-item AMT plus the carryforward.
+    pub available: Usd,
+    /// Credit allowed, reported on Schedule 3, line 6b.
+    pub credit: Usd,
+    /// Credit carried forward to next year, as
+    /// [`MinimumTaxCreditInput::carryforward`] on that year's return.
+    pub carryforward: Usd,
+}
+
+/// Computes the credit for prior-year minimum tax.
+///
+/// The AMT itself is not computed in this crate, so the prior-year AMT,
+/// its exclusion-item portion, and this year's tentative minimum tax are
+/// inputs. The refundable portion for long-term unused credits no longer
+/// applies and is not modeled.
+///
+/// See: <https://www.irs.gov/instructions/i8801>
+pub fn minimum_tax_credit(input: &MinimumTaxCreditInput) -> MinimumTaxCredit {
+    let deferral_amt = (input.prior_year_amt - input.prior_year_exclusion_amt).max(Usd::ZERO);
+    let available = deferral_amt + input.carryforward;
+    let limit = (input.regular_tax_less_credits - input.tentative_minimum_tax).max(Usd::ZERO);
+    let credit = available.min(limit);
+    MinimumTaxCredit {
+        available,
+        credit,
+        carryforward: available - credit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deferral_items_classified() {
+        assert_eq!(
+            AmtItem::IncentiveStockOptions.kind(),
+            PreferenceKind::Deferral
+        );
+        assert_eq!(
+            AmtItem::TaxesOrStandardDeduction.kind(),
+            PreferenceKind::Exclusion
+        );
+    }
+
+    #[test]
+    fn credit_limited_to_regular_tax_over_tentative_minimum() {
+        let input = MinimumTaxCreditInput {
+            prior_year_amt: Usd::from_dollars(12_000),
+            prior_year_exclusion_amt: Usd::from_dollars(2_000),
+            carryforward: Usd::from_dollars(1_000),
+            regular_tax_less_credits: Usd::from_dollars(30_000),
+            tentative_minimum_tax: Usd::from_dollars(26_000),
+        };
+        let c = minimum_tax_credit(&input);
+        assert_eq!(c.available, Usd::from_dollars(11_000));
+        assert_eq!(c.credit, Usd::from_dollars(4_000));
+        assert_eq!(c.carryforward, Usd::from_dollars(7_000));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::Usd;
+use crate::spine::ReturnInput;
+
+/// Form 3903 inputs: a move by a member of the Armed Forces.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MilitaryMove {
+    /// `true` if the taxpayer (or spouse) is on active duty and moved under
+    /// a military order incident to a permanent change of station
+    /// (IRC §217(g)). Since 2018 no one else may deduct moving expenses
+    /// (IRC §217(k)), and the One Big Beautiful Bill Act made that
+    /// permanent. From 2026 it also allows members of the intelligence
+    /// community moving under a change of assignment, which this does not
+    /// cover.
+    pub permanent_change_of_station: bool,
+    /// Transportation and storage of household goods and personal effects
+    /// (line 1).
+    pub transportation_and_storage: Usd,
+    /// Travel and lodging from the old home to the new home, not including
+    /// meals (line 2).
+    pub travel_and_lodging: Usd,
+    /// Reimbursements not included in wages, from Form W-2, box 12, code P
+    /// (line 4).
+    pub reimbursements: Usd,
+}
+
+/// Results of Form 3903.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MovingExpenses {
+    /// Deduction reported on Schedule 1, line 14 (line 5).
+    pub deduction: Usd,
+    /// Reimbursements in excess of expenses, included in income on
+    /// Form 1040, line 1h.
+    pub excess_reimbursement: Usd,
+}
+
+impl MilitaryMove {
+    /// Computes Form 3903. Returns zero for a move that does not qualify.
+    ///
+    /// See: <https://www.irs.gov/instructions/i3903>
+    pub fn expenses(&self) -> MovingExpenses {
+        if !self.permanent_change_of_station {
+            return MovingExpenses::default();
+        }
+        // Line 3
+        let expenses = self.transportation_and_storage + self.travel_and_lodging;
+        MovingExpenses {
+            deduction: (expenses - self.reimbursements).max(Usd::ZERO),
+            excess_reimbursement: (self.reimbursements - expenses).max(Usd::ZERO),
+        }
+    }
+
+    /// Sets the moving expense adjustment on `input` and adds any excess
+    /// reimbursement to wages. Leaves `input` unchanged for a move that does
+    /// not qualify.
+    pub fn apply_to(&self, input: &mut ReturnInput) {
+        if !self.permanent_change_of_station {
+            return;
+        }
+        let result = self.expenses();
+        input.adjustments.moving_expenses = result.deduction;
+        input.w2_wages += result.excess_reimbursement;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn military_move(pcs: bool, reimbursements: i64) -> MilitaryMove {
+        MilitaryMove {
+            permanent_change_of_station: pcs,
+            transportation_and_storage: Usd::from_dollars(3_000),
+            travel_and_lodging: Usd::from_dollars(800),
+            reimbursements: Usd::from_dollars(reimbursements),
+        }
+    }
+
+    #[test]
+    fn unreimbursed_expenses_deducted() {
+        let r = military_move(true, 2_500).expenses();
+        assert_eq!(r.deduction, Usd::from_dollars(1_300));
+        assert_eq!(r.excess_reimbursement, Usd::ZERO);
+
+        let r = military_move(true, 4_000).expenses();
+        assert_eq!(r.deduction, Usd::ZERO);
+        assert_eq!(r.excess_reimbursement, Usd::from_dollars(200));
+    }
+
+    #[test]
+    fn only_permanent_change_of_station_qualifies() {
+        assert_eq!(
+            military_move(false, 0).expenses(),
+            MovingExpenses::default()
+        );
+
+        let mut input = crate::spine::tests::input(40_000, 0);
+        military_move(false, 0).apply_to(&mut input);
+        assert_eq!(input.adjustments.moving_expenses, Usd::ZERO);
+        military_move(true, 0).apply_to(&mut input);
+        assert_eq!(input.adjustments.moving_expenses, Usd::from_dollars(3_800));
+    }
+}
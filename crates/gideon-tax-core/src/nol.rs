@@ -0,0 +1,234 @@
+use crate::Usd;
+
+/// Share of taxable income, figured without the deduction, that losses
+/// arising after 2017 can offset, in percent (IRC §172(a)(2)).
+pub const POST_2017_LIMIT_PERCENT: i64 = 80;
+
+/// Years a loss arising before 2018 can be carried forward (IRC
+/// §172(b)(1)(A)(ii), before amendment by P.L. 115-97).
+pub const PRE_2018_CARRYFORWARD_YEARS: i32 = 20;
+
+/// Figures from a loss year's return, for the NOL worksheet (Form 172,
+/// Part I).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LossYear {
+    /// Form 1040, line 15 figured without limiting it to zero (line 1).
+    pub taxable_income: Usd,
+    /// Nonbusiness capital losses before the $3,000 limit (line 2).
+    pub nonbusiness_capital_losses: Usd,
+    /// Line 3.
+    pub nonbusiness_capital_gains: Usd,
+    /// Nonbusiness deductions, including the standard deduction (line 6).
+    pub nonbusiness_deductions: Usd,
+    /// Nonbusiness income other than capital gains, such as interest and
+    /// dividends (line 7).
+    pub nonbusiness_income: Usd,
+    /// Business capital losses before the $3,000 limit (line 11).
+    pub business_capital_losses: Usd,
+    /// Line 12.
+    pub business_capital_gains: Usd,
+    /// Net loss from Schedule D, line 16, as a positive amount (line 16).
+    pub schedule_d_loss: Usd,
+    /// Loss deducted on Schedule D, line 21, as a positive amount (line 19).
+    pub capital_loss_deduction: Usd,
+    /// NOL deduction for losses from other years.
+    pub nol_deduction: Usd,
+    /// Qualified business income deduction.
+    pub qbi_deduction: Usd,
+    /// Personal exemptions, for years before 2018.
+    pub exemptions: Usd,
+}
+
+impl LossYear {
+    /// The net operating loss, as a positive amount, or zero if there is
+    /// none. The section 1202 exclusion is not modeled.
+    ///
+    /// See: <https://www.irs.gov/forms-pubs/about-form-172>
+    pub fn net_operating_loss(&self) -> Usd {
+        let excess = |a: Usd, b: Usd| (a - b).max(Usd::ZERO);
+        // Lines 4–5
+        let nonbusiness_capital_loss = excess(
+            self.nonbusiness_capital_losses,
+            self.nonbusiness_capital_gains,
+        );
+        let nonbusiness_capital_gain = excess(
+            self.nonbusiness_capital_gains,
+            self.nonbusiness_capital_losses,
+        );
+        // Lines 8–10
+        let nonbusiness_income = nonbusiness_capital_gain + self.nonbusiness_income;
+        let excess_nonbusiness_deductions = excess(self.nonbusiness_deductions, nonbusiness_income);
+        let gain_left =
+            excess(nonbusiness_income, self.nonbusiness_deductions).min(nonbusiness_capital_gain);
+        // Lines 13–15
+        let business_capital_loss = excess(
+            self.business_capital_losses,
+            gain_left + self.business_capital_gains,
+        );
+        let capital_losses = nonbusiness_capital_loss + business_capital_loss;
+        // Lines 20–22
+        let unused_loss = excess(self.schedule_d_loss, self.capital_loss_deduction);
+        let deducted_over_loss = excess(self.capital_loss_deduction, self.schedule_d_loss);
+        let disallowed_capital_loss = excess(capital_losses, unused_loss);
+        let result = self.taxable_income
+            + excess_nonbusiness_deductions
+            + deducted_over_loss
+            + disallowed_capital_loss
+            + self.nol_deduction
+            + self.qbi_deduction
+            + self.exemptions;
+        (Usd::ZERO - result).max(Usd::ZERO)
+    }
+}
+
+/// An unused net operating loss from `year`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NolCarryover {
+    /// Tax year the loss arose.
+    pub year: i32,
+    pub amount: Usd,
+}
+
+/// Result of applying carryovers in a later year.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NolDeduction {
+    /// Deduction reported on Schedule 1, line 8a.
+    pub deduction: Usd,
+    /// Carryovers left for next year, oldest first.
+    pub carryovers: Vec<NolCarryover>,
+    /// Losses from before 2018 that reached the end of their carryforward
+    /// period unused.
+    pub expired: Usd,
+}
+
+/// Applies carryovers against `taxable_income`, figured without the NOL
+/// deduction and the qualified business income deduction.
+///
+/// Losses from before 2018 offset all of taxable income; later losses
+/// offset 80% of what remains. Carrybacks, including the two-year farming
+/// loss carryback, are not modeled.
+pub fn nol_deduction(
+    carryovers: &[NolCarryover],
+    taxable_income: Usd,
+    tax_year: i32,
+) -> NolDeduction {
+    let available = |pre_2018: bool| {
+        carryovers
+            .iter()
+            .filter(|c| (c.year < 2018) == pre_2018 && !expired(c, tax_year))
+            .map(|c| c.amount)
+            .sum::<Usd>()
+    };
+    let taxable_income = taxable_income.max(Usd::ZERO);
+    let pre_2018 = available(true).min(taxable_income);
+    let post_2017 =
+        available(false).min((taxable_income - pre_2018).mul_div(POST_2017_LIMIT_PERCENT, 100));
+    carry_forward(carryovers, pre_2018 + post_2017, tax_year)
+}
+
+/// Takes `deduction` from `carryovers` oldest first and returns what is
+/// left for next year.
+pub fn carry_forward(carryovers: &[NolCarryover], deduction: Usd, tax_year: i32) -> NolDeduction {
+    let mut sorted = carryovers.to_vec();
+    sorted.sort_by_key(|c| c.year);
+
+    let mut result = NolDeduction {
+        deduction,
+        ..Default::default()
+    };
+    let mut left = deduction;
+    for mut c in sorted {
+        if expired(&c, tax_year) {
+            result.expired += c.amount;
+            continue;
+        }
+        let used = c.amount.min(left);
+        left -= used;
+        c.amount -= used;
+        if c.amount == Usd::ZERO {
+            continue;
+        }
+        if expired(&c, tax_year + 1) {
+            result.expired += c.amount;
+        } else {
+            result.carryovers.push(c);
+        }
+    }
+    result
+}
+
+/// `true` if a loss can no longer be carried to `tax_year`.
+fn expired(c: &NolCarryover, tax_year: i32) -> bool {
+    c.year < 2018 && tax_year - c.year > PRE_2018_CARRYFORWARD_YEARS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonbusiness_deductions_added_back() {
+        // A business loss of 50,000, 2,000 of interest, and the standard
+        // deduction of 15,000: only the business loss is an NOL.
+        let year = LossYear {
+            taxable_income: Usd::from_dollars(2_000 - 50_000 - 15_000),
+            nonbusiness_deductions: Usd::from_dollars(15_000),
+            nonbusiness_income: Usd::from_dollars(2_000),
+            ..Default::default()
+        };
+        assert_eq!(year.net_operating_loss(), Usd::from_dollars(50_000));
+    }
+
+    #[test]
+    fn capital_loss_deduction_added_back() {
+        let year = LossYear {
+            taxable_income: Usd::from_dollars(-23_000),
+            nonbusiness_capital_losses: Usd::from_dollars(5_000),
+            schedule_d_loss: Usd::from_dollars(5_000),
+            capital_loss_deduction: Usd::from_dollars(3_000),
+            ..Default::default()
+        };
+        assert_eq!(year.net_operating_loss(), Usd::from_dollars(20_000));
+    }
+
+    #[test]
+    fn post_2017_losses_limited_to_eighty_percent() {
+        let carryovers = [NolCarryover {
+            year: 2022,
+            amount: Usd::from_dollars(100_000),
+        }];
+        let r = nol_deduction(&carryovers, Usd::from_dollars(50_000), 2025);
+        assert_eq!(r.deduction, Usd::from_dollars(40_000));
+        assert_eq!(
+            r.carryovers,
+            vec![NolCarryover {
+                year: 2022,
+                amount: Usd::from_dollars(60_000),
+            }]
+        );
+    }
+
+    #[test]
+    fn pre_2018_losses_first_and_expire() {
+        let carryovers = [
+            NolCarryover {
+                year: 2020,
+                amount: Usd::from_dollars(40_000),
+            },
+            NolCarryover {
+                year: 2016,
+                amount: Usd::from_dollars(10_000),
+            },
+            NolCarryover {
+                year: 2004,
+                amount: Usd::from_dollars(5_000),
+            },
+        ];
+        let r = nol_deduction(&carryovers, Usd::from_dollars(50_000), 2025);
+        // 10,000 from 2016, then 80% of the remaining 40,000.
+        assert_eq!(r.deduction, Usd::from_dollars(42_000));
+        assert_eq!(r.expired, Usd::from_dollars(5_000));
+        assert_eq!(r.carryovers[0].amount, Usd::from_dollars(8_000));
+    }
+}
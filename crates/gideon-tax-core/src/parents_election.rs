@@ -0,0 +1,132 @@
+use crate::Usd;
+use crate::rules::TaxYearRules;
+
+/// Tax rate on the child's income between the amount not taxed and the base
+/// amount, in basis points (IRC §1(g)(7)(B)(ii)).
+pub const CHILD_TAX_BPS: i64 = 1_000;
+
+/// A child's interest and dividends reported on a parent's return
+/// (Form 8814, lines 1a–3).
+///
+/// Eligibility other than the gross income limit (the child's age, filing
+/// requirement, and absence of withholding or estimated payments) is the
+/// caller's to check.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChildInvestmentIncome {
+    pub child_name: String,
+    /// Line 1a.
+    pub taxable_interest: Usd,
+    /// Reported on the parent's Form 1040, line 2a (line 1b).
+    pub tax_exempt_interest: Usd,
+    /// Including Alaska Permanent Fund dividends (line 2a).
+    pub ordinary_dividends: Usd,
+    /// Line 2b.
+    pub qualified_dividends: Usd,
+    /// Line 3.
+    pub capital_gain_distributions: Usd,
+}
+
+/// Results of Form 8814.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Form8814 {
+    /// Line 4.
+    pub gross_income: Usd,
+    /// Amount included in the parent's income (line 6).
+    pub included: Usd,
+    /// Part of the included amount reported as qualified dividends on
+    /// Form 1040, lines 3a and 3b (line 9).
+    pub qualified_dividends: Usd,
+    /// Part of the included amount reported as capital gain distributions
+    /// (line 10).
+    pub capital_gain_distributions: Usd,
+    /// Part of the included amount reported on Schedule 1, line 8z
+    /// (line 12).
+    pub other_income: Usd,
+    /// Tax added to Form 1040, line 16 (line 15).
+    pub tax: Usd,
+}
+
+impl ChildInvestmentIncome {
+    /// Line 4.
+    pub fn gross_income(&self) -> Usd {
+        self.taxable_interest + self.ordinary_dividends + self.capital_gain_distributions
+    }
+
+    /// `true` if the child's gross income is less than ten times the amount
+    /// not taxed ($13,500 for 2025).
+    pub fn qualifies(&self, rules: &dyn TaxYearRules) -> bool {
+        self.gross_income() < rules.dependent_minimum_deduction() * 10
+    }
+
+    /// Computes Form 8814. The amount not taxed is the minimum standard
+    /// deduction for a dependent, and the base amount twice that.
+    ///
+    /// See: <https://www.irs.gov/instructions/i8814>
+    pub fn form_8814(&self, rules: &dyn TaxYearRules) -> Form8814 {
+        let not_taxed = rules.dependent_minimum_deduction();
+        let gross_income = self.gross_income();
+        let included = (gross_income - not_taxed * 2).max(Usd::ZERO);
+        // Lines 7–11
+        let share = |amount: Usd| {
+            if gross_income > Usd::ZERO {
+                included.mul_div(amount.cents(), gross_income.cents())
+            } else {
+                Usd::ZERO
+            }
+        };
+        let qualified_dividends = share(self.qualified_dividends);
+        let capital_gain_distributions = share(self.capital_gain_distributions);
+        // Lines 13–15
+        let taxed_at_child_rate = (gross_income - not_taxed).max(Usd::ZERO).min(not_taxed);
+        Form8814 {
+            gross_income,
+            included,
+            qualified_dividends,
+            capital_gain_distributions,
+            other_income: included - qualified_dividends - capital_gain_distributions,
+            tax: taxed_at_child_rate.mul_div(CHILD_TAX_BPS, 10_000),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    fn child(interest: i64, dividends: i64, qualified: i64) -> ChildInvestmentIncome {
+        ChildInvestmentIncome {
+            taxable_interest: Usd::from_dollars(interest),
+            ordinary_dividends: Usd::from_dollars(dividends),
+            qualified_dividends: Usd::from_dollars(qualified),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn excess_over_base_amount_included() {
+        let f = child(1_700, 3_000, 2_000).form_8814(&Rules2025);
+        assert_eq!(f.gross_income, Usd::from_dollars(4_700));
+        assert_eq!(f.included, Usd::from_dollars(2_000));
+        // 2,000 × 2,000 / 4,700
+        assert_eq!(f.qualified_dividends, Usd::from_cents(85_106));
+        assert_eq!(f.other_income, Usd::from_cents(114_894));
+        assert_eq!(f.tax, Usd::from_dollars(135));
+    }
+
+    #[test]
+    fn below_base_amount_only_tax() {
+        let f = child(2_000, 0, 0).form_8814(&Rules2025);
+        assert_eq!(f.included, Usd::ZERO);
+        // 10% of 2,000 − 1,350
+        assert_eq!(f.tax, Usd::from_dollars(65));
+        assert_eq!(child(1_000, 0, 0).form_8814(&Rules2025).tax, Usd::ZERO);
+    }
+
+    #[test]
+    fn gross_income_limit() {
+        assert!(child(13_499, 0, 0).qualifies(&Rules2025));
+        assert!(!child(10_000, 3_500, 0).qualifies(&Rules2025));
+    }
+}
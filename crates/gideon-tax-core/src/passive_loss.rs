@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use crate::Usd;
+use crate::rental::{RentalProperty, ScheduleEPart1};
+
+/// Passive losses disallowed in prior years, as positive amounts, keyed by
+/// activity. Rental properties are keyed by their address.
+///
+/// Form 8582 reads the store before the year's computation and writes it
+/// back afterward, so the store carries losses from year to year until
+/// they are allowed or the activity is disposed of.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SuspendedLosses {
+    activities: BTreeMap<String, Usd>,
+}
+
+impl SuspendedLosses {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The loss suspended for `activity`, or zero.
+    pub fn get(&self, activity: &str) -> Usd {
+        self.activities.get(activity).copied().unwrap_or(Usd::ZERO)
+    }
+
+    /// Replaces the loss suspended for `activity`. A zero amount removes
+    /// the entry.
+    pub fn set(&mut self, activity: &str, amount: Usd) {
+        if amount > Usd::ZERO {
+            self.activities.insert(activity.to_string(), amount);
+        } else {
+            self.activities.remove(activity);
+        }
+    }
+
+    /// Removes `activity` and returns its suspended loss, which becomes
+    /// fully deductible in the year of a complete disposition (IRC
+    /// §469(g)).
+    pub fn release(&mut self, activity: &str) -> Usd {
+        self.activities.remove(activity).unwrap_or(Usd::ZERO)
+    }
+
+    /// Total suspended across all activities.
+    pub fn total(&self) -> Usd {
+        self.activities.values().copied().sum()
+    }
+
+    /// Activities and their suspended losses, in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Usd)> {
+        self.activities.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+
+    /// Sets each property's prior-year unallowed loss from the store.
+    pub fn apply_to(&self, properties: &mut [RentalProperty]) {
+        for p in properties {
+            p.prior_year_unallowed = self.get(&p.address);
+        }
+    }
+
+    /// Records the losses still suspended after this year's Schedule E,
+    /// Part I. Disposed properties are released; their losses were
+    /// allowed in full.
+    pub fn record(&mut self, properties: &[RentalProperty], part1: &ScheduleEPart1) {
+        for (p, r) in properties.iter().zip(&part1.properties) {
+            if p.disposed {
+                self.release(&p.address);
+            } else {
+                self.set(&p.address, r.suspended_loss);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use us_tax_brackets::FilingStatus;
+
+    use super::*;
+    use crate::rental::{RentalExpenses, RentalParams, schedule_e_part_1};
+
+    fn property(address: &str, rents: i64, repairs: i64) -> RentalProperty {
+        RentalProperty {
+            address: address.to_string(),
+            rents_received: Usd::from_dollars(rents),
+            expenses: RentalExpenses {
+                repairs: Usd::from_dollars(repairs),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn params() -> RentalParams {
+        RentalParams {
+            filing_status: FilingStatus::Single,
+            mfs_lived_with_spouse: false,
+            modified_agi: Usd::from_dollars(200_000),
+            tax_year: 2025,
+        }
+    }
+
+    #[test]
+    fn set_removes_zero() {
+        let mut store = SuspendedLosses::new();
+        store.set("1 Main St", Usd::from_dollars(500));
+        store.set("2 Oak Ave", Usd::from_dollars(300));
+        assert_eq!(store.total(), Usd::from_dollars(800));
+        store.set("1 Main St", Usd::ZERO);
+        assert_eq!(
+            store.iter().collect::<Vec<_>>(),
+            vec![("2 Oak Ave", Usd::from_dollars(300))]
+        );
+    }
+
+    #[test]
+    fn loss_carried_then_used_against_income() {
+        let mut store = SuspendedLosses::new();
+        let mut properties = [property("1 Main St", 10_000, 14_000)];
+        let part1 = schedule_e_part_1(&properties, &params());
+        store.record(&properties, &part1);
+        assert_eq!(store.get("1 Main St"), Usd::from_dollars(4_000));
+
+        // Next year the property earns 3,000, which absorbs 3,000 of the
+        // suspended loss.
+        properties[0].expenses.repairs = Usd::from_dollars(7_000);
+        store.apply_to(&mut properties);
+        let part1 = schedule_e_part_1(&properties, &params());
+        assert_eq!(part1.total(), Usd::ZERO);
+        store.record(&properties, &part1);
+        assert_eq!(store.get("1 Main St"), Usd::from_dollars(1_000));
+    }
+
+    #[test]
+    fn disposition_releases_loss() {
+        let mut store = SuspendedLosses::new();
+        store.set("1 Main St", Usd::from_dollars(6_000));
+        let mut properties = [RentalProperty {
+            disposed: true,
+            ..property("1 Main St", 5_000, 6_000)
+        }];
+        store.apply_to(&mut properties);
+        let part1 = schedule_e_part_1(&properties, &params());
+        assert_eq!(part1.losses, Usd::from_dollars(7_000));
+        assert_eq!(part1.properties[0].suspended_loss, Usd::ZERO);
+        store.record(&properties, &part1);
+        assert_eq!(store.total(), Usd::ZERO);
+    }
+}
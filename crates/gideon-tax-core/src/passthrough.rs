@@ -0,0 +1,407 @@
+use crate::{Owner, Usd};
+
+/// Type of pass-through entity that issued a Schedule K-1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityKind {
+    /// Form 1065 partnership.
+    Partnership,
+    /// Form 1120-S S corporation.
+    SCorporation,
+    /// Form 1041 estate or trust.
+    EstateOrTrust,
+}
+
+/// One pass-through entity's items for an owner, in the form they flow to
+/// the owner's return.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PassthroughItems {
+    pub entity_name: String,
+    pub entity_ein: String,
+    pub kind: EntityKind,
+    /// The filer the K-1 was issued to, whose Schedule SE its
+    /// self-employment earnings go on.
+    pub owner: Owner,
+    /// `true` if the owner materially participates in the trade or business
+    /// (IRC §469(h)). Rental activities are passive regardless.
+    pub material_participation: bool,
+    /// Ordinary business income or loss.
+    pub ordinary_income: Usd,
+    /// Net rental real estate and other rental income or loss.
+    pub rental_income: Usd,
+    /// Guaranteed payments to a partner, always nonpassive.
+    pub guaranteed_payments: Usd,
+    /// Portfolio and nonbusiness income from an estate or trust, always
+    /// nonpassive.
+    pub portfolio_income: Usd,
+    /// Section 179 deduction passed through (Schedule E, line 28(j)).
+    pub section_179_deduction: Usd,
+    /// Interest income (Schedule B, part I).
+    pub interest: Usd,
+    /// Ordinary dividends (Schedule B, part II).
+    pub ordinary_dividends: Usd,
+    /// Portion of `ordinary_dividends` that is qualified.
+    pub qualified_dividends: Usd,
+    /// Net short-term capital gain or loss (Schedule D, line 5).
+    pub net_short_term_capital_gain: Usd,
+    /// Net long-term capital gain or loss (Schedule D, line 12).
+    pub net_long_term_capital_gain: Usd,
+    /// Net earnings from self-employment (Schedule SE, line 2).
+    pub self_employment_earnings: Usd,
+    /// Qualified business income or loss for the §199A deduction.
+    pub qualified_business_income: Usd,
+    /// Basis and at-risk amounts that limit this year's losses.
+    pub loss_limits: LossLimits,
+}
+
+/// Amounts available to absorb an owner's losses from one entity, after the
+/// year's income, distributions, and nondeductible expenses. `None` means the
+/// limitation is not tracked or was already applied, as it is for
+/// S corporation K-1s converted with the shareholder's basis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LossLimits {
+    /// Partner's outside basis (IRC §704(d)) or shareholder's stock and debt
+    /// basis (IRC §1366(d)).
+    pub basis: Option<Usd>,
+    /// Amount at risk (IRC §465), from
+    /// [`AtRiskAmount::apply`](crate::at_risk::AtRiskAmount::apply).
+    pub at_risk: Option<Usd>,
+}
+
+impl PassthroughItems {
+    /// Nonpassive income or loss (Schedule E, line 28(i)–(k)), net of the
+    /// section 179 deduction.
+    pub fn nonpassive_income(&self) -> Usd {
+        let business = if self.material_participation {
+            self.ordinary_income - self.section_179_deduction
+        } else {
+            Usd::ZERO
+        };
+        self.guaranteed_payments + self.portfolio_income + business
+    }
+
+    /// Passive income or loss (Schedule E, line 28(g)–(h)), before the
+    /// passive activity loss limitation.
+    pub fn passive_income(&self) -> Usd {
+        let business = if self.material_participation {
+            Usd::ZERO
+        } else {
+            self.ordinary_income - self.section_179_deduction
+        };
+        self.rental_income + business
+    }
+}
+
+/// One entity's line 28 amounts, with the losses each limitation disallowed.
+/// All amounts are positive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PassthroughRow {
+    /// Line 28(g).
+    pub passive_loss_allowed: Usd,
+    /// Line 28(h).
+    pub passive_income: Usd,
+    /// Line 28(i), including the section 179 deduction.
+    pub nonpassive_loss_allowed: Usd,
+    /// Line 28(k).
+    pub nonpassive_income: Usd,
+    /// Losses in excess of basis, carried forward.
+    pub basis_suspended: Usd,
+    /// Losses in excess of the amount at risk, carried forward.
+    pub at_risk_suspended: Usd,
+    /// Passive losses in excess of passive income, carried forward.
+    pub passive_suspended: Usd,
+}
+
+/// Schedule E, Part II.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScheduleEPart2 {
+    /// One row per entity, in input order.
+    pub rows: Vec<PassthroughRow>,
+}
+
+impl ScheduleEPart2 {
+    /// Line 30: columns (h) and (k).
+    pub fn income(&self) -> Usd {
+        self.rows
+            .iter()
+            .map(|r| r.passive_income + r.nonpassive_income)
+            .sum()
+    }
+
+    /// Line 31: columns (g) and (i), as a positive amount.
+    pub fn losses(&self) -> Usd {
+        self.rows
+            .iter()
+            .map(|r| r.passive_loss_allowed + r.nonpassive_loss_allowed)
+            .sum()
+    }
+
+    /// Line 32.
+    pub fn total(&self) -> Usd {
+        self.income() - self.losses()
+    }
+}
+
+/// Aggregates partnership, S corporation, estate, and trust items into
+/// Schedule E, Part II.
+///
+/// Each entity's losses are limited in the order the Code applies them:
+/// first to basis, then to the amount at risk, then by the passive activity
+/// rules. A loss cut back by basis or at-risk is shared pro rata between the
+/// entity's passive and nonpassive losses. The passive losses that remain
+/// are allowed up to the passive income from all entities, pro rata.
+/// TODO: Form 8582 (rental real estate allowance, dispositions, carryovers).
+pub fn schedule_e_part_2(items: &[PassthroughItems]) -> ScheduleEPart2 {
+    let positive = |a: Usd| a.max(Usd::ZERO);
+    let negative = |a: Usd| (Usd::ZERO - a).max(Usd::ZERO);
+
+    let mut rows: Vec<PassthroughRow> = items
+        .iter()
+        .map(|p| {
+            let (passive, nonpassive) = (p.passive_income(), p.nonpassive_income());
+            let loss = negative(passive) + negative(nonpassive);
+            let after_basis = p.loss_limits.basis.map_or(loss, |b| loss.min(positive(b)));
+            let after_at_risk = p
+                .loss_limits
+                .at_risk
+                .map_or(after_basis, |a| after_basis.min(positive(a)));
+            let passive_loss = share(after_at_risk, negative(passive), loss);
+            PassthroughRow {
+                passive_loss_allowed: passive_loss,
+                passive_income: positive(passive),
+                nonpassive_loss_allowed: after_at_risk - passive_loss,
+                nonpassive_income: positive(nonpassive),
+                basis_suspended: loss - after_basis,
+                at_risk_suspended: after_basis - after_at_risk,
+                passive_suspended: Usd::ZERO,
+            }
+        })
+        .collect();
+
+    let passive_income: Usd = rows.iter().map(|r| r.passive_income).sum();
+    let passive_loss: Usd = rows.iter().map(|r| r.passive_loss_allowed).sum();
+    let allowed = passive_loss.min(passive_income);
+    for r in &mut rows {
+        let limited = share(allowed, r.passive_loss_allowed, passive_loss);
+        r.passive_suspended = r.passive_loss_allowed - limited;
+        r.passive_loss_allowed = limited;
+    }
+    ScheduleEPart2 { rows }
+}
+
+/// Total partnership, S corporation, estate, and trust income or loss
+/// (Schedule E, lines 32 and 37).
+pub fn schedule_e_passthrough_total(items: &[PassthroughItems]) -> Usd {
+    schedule_e_part_2(items).total()
+}
+
+/// `part`'s pro rata share of `amount`.
+fn share(amount: Usd, part: Usd, whole: Usd) -> Usd {
+    if whole > Usd::ZERO {
+        amount.mul_div(part.cents(), whole.cents())
+    } else {
+        Usd::ZERO
+    }
+}
+
+/// A shareholder's basis in S corporation stock and loans (Form 7203).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShareholderBasis {
+    pub stock: Usd,
+    pub debt: Usd,
+    /// Losses and deductions disallowed in prior years for lack of basis,
+    /// carried forward indefinitely (IRC §1366(d)(2)).
+    pub suspended_losses: Usd,
+}
+
+/// The year's items that change a shareholder's basis, in the order
+/// Form 7203 applies them. All amounts are positive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BasisAdjustments {
+    /// Income and gain items, including tax-exempt income.
+    pub income: Usd,
+    /// Nondividend distributions.
+    pub distributions: Usd,
+    /// Nondeductible expenses.
+    pub nondeductible_expenses: Usd,
+    /// Current-year loss and deduction items.
+    pub losses: Usd,
+}
+
+/// Result of applying a year's items to a shareholder's basis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BasisLimitation {
+    /// Current-year and carried-forward losses allowed (Form 7203, line 47).
+    pub allowed_losses: Usd,
+    /// Distributions in excess of stock basis, taxed as capital gain.
+    pub excess_distributions: Usd,
+    /// Basis and suspended losses carried to next year.
+    pub ending: ShareholderBasis,
+}
+
+impl ShareholderBasis {
+    /// Applies a year's items to basis: income increases stock basis,
+    /// distributions and nondeductible expenses reduce it, and losses
+    /// (current-year plus suspended) are allowed against stock basis and
+    /// then debt basis, with the rest suspended.
+    ///
+    /// Restoration of debt basis by later income is not modeled.
+    ///
+    /// See: <https://www.irs.gov/instructions/i7203>
+    pub fn apply(self, adj: &BasisAdjustments) -> BasisLimitation {
+        let stock = self.stock + adj.income;
+        let excess_distributions = (adj.distributions - stock).max(Usd::ZERO);
+        let stock = (stock - adj.distributions).max(Usd::ZERO);
+        let stock = (stock - adj.nondeductible_expenses).max(Usd::ZERO);
+
+        let losses = adj.losses + self.suspended_losses;
+        let against_stock = losses.min(stock);
+        let against_debt = (losses - against_stock).min(self.debt);
+        let allowed_losses = against_stock + against_debt;
+
+        BasisLimitation {
+            allowed_losses,
+            excess_distributions,
+            ending: ShareholderBasis {
+                stock: stock - against_stock,
+                debt: self.debt - against_debt,
+                suspended_losses: losses - allowed_losses,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partnership(ordinary: i64, material: bool) -> PassthroughItems {
+        PassthroughItems {
+            entity_name: "Acme LP".to_string(),
+            entity_ein: "12-3456789".to_string(),
+            kind: EntityKind::Partnership,
+            owner: Owner::Taxpayer,
+            material_participation: material,
+            ordinary_income: Usd::from_dollars(ordinary),
+            rental_income: Usd::ZERO,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: Usd::ZERO,
+            section_179_deduction: Usd::ZERO,
+            interest: Usd::ZERO,
+            ordinary_dividends: Usd::ZERO,
+            qualified_dividends: Usd::ZERO,
+            net_short_term_capital_gain: Usd::ZERO,
+            net_long_term_capital_gain: Usd::ZERO,
+            self_employment_earnings: Usd::ZERO,
+            qualified_business_income: Usd::ZERO,
+            loss_limits: LossLimits::default(),
+        }
+    }
+
+    #[test]
+    fn material_participation_is_nonpassive() {
+        let mut p = partnership(20_000, true);
+        p.guaranteed_payments = Usd::from_dollars(10_000);
+        p.section_179_deduction = Usd::from_dollars(2_000);
+        p.rental_income = Usd::from_dollars(1_000);
+        assert_eq!(p.nonpassive_income(), Usd::from_dollars(28_000));
+        assert_eq!(p.passive_income(), Usd::from_dollars(1_000));
+    }
+
+    #[test]
+    fn passive_loss_offsets_passive_income_only() {
+        let items = [
+            partnership(30_000, true),
+            partnership(5_000, false),
+            partnership(-12_000, false),
+        ];
+        // Net passive loss of 7,000 is suspended.
+        assert_eq!(
+            schedule_e_passthrough_total(&items),
+            Usd::from_dollars(30_000)
+        );
+
+        let items = [partnership(-4_000, true), partnership(6_000, false)];
+        assert_eq!(
+            schedule_e_passthrough_total(&items),
+            Usd::from_dollars(2_000)
+        );
+    }
+
+    #[test]
+    fn losses_limited_by_basis_then_at_risk_then_passive() {
+        let mut limited = partnership(-20_000, false);
+        limited.guaranteed_payments = Usd::from_dollars(5_000);
+        limited.loss_limits = LossLimits {
+            basis: Some(Usd::from_dollars(15_000)),
+            at_risk: Some(Usd::from_dollars(12_000)),
+        };
+        let items = [
+            limited,
+            partnership(-10_000, true),
+            partnership(4_000, false),
+        ];
+        let part2 = schedule_e_part_2(&items);
+        let row = part2.rows[0];
+        assert_eq!(row.basis_suspended, Usd::from_dollars(5_000));
+        assert_eq!(row.at_risk_suspended, Usd::from_dollars(3_000));
+        // 12,000 of passive loss left, allowed up to 4,000 of passive income.
+        assert_eq!(row.passive_loss_allowed, Usd::from_dollars(4_000));
+        assert_eq!(row.passive_suspended, Usd::from_dollars(8_000));
+        assert_eq!(row.nonpassive_income, Usd::from_dollars(5_000));
+        assert_eq!(
+            part2.rows[1].nonpassive_loss_allowed,
+            Usd::from_dollars(10_000)
+        );
+        assert_eq!(part2.income(), Usd::from_dollars(9_000));
+        assert_eq!(part2.losses(), Usd::from_dollars(14_000));
+        assert_eq!(part2.total(), Usd::from_dollars(-5_000));
+    }
+
+    fn adjustments(income: i64, distributions: i64, losses: i64) -> BasisAdjustments {
+        BasisAdjustments {
+            income: Usd::from_dollars(income),
+            distributions: Usd::from_dollars(distributions),
+            nondeductible_expenses: Usd::ZERO,
+            losses: Usd::from_dollars(losses),
+        }
+    }
+
+    #[test]
+    fn losses_within_basis_allowed() {
+        let basis = ShareholderBasis {
+            stock: Usd::from_dollars(10_000),
+            ..Default::default()
+        };
+        let r = basis.apply(&adjustments(0, 0, 8_000));
+        assert_eq!(r.allowed_losses, Usd::from_dollars(8_000));
+        assert_eq!(r.ending.stock, Usd::from_dollars(2_000));
+        assert_eq!(r.ending.suspended_losses, Usd::ZERO);
+    }
+
+    #[test]
+    fn losses_beyond_stock_and_debt_suspended() {
+        let basis = ShareholderBasis {
+            stock: Usd::from_dollars(5_000),
+            debt: Usd::from_dollars(3_000),
+            suspended_losses: Usd::from_dollars(1_000),
+        };
+        let r = basis.apply(&adjustments(0, 0, 12_000));
+        assert_eq!(r.allowed_losses, Usd::from_dollars(8_000));
+        assert_eq!(r.ending.stock, Usd::ZERO);
+        assert_eq!(r.ending.debt, Usd::ZERO);
+        assert_eq!(r.ending.suspended_losses, Usd::from_dollars(5_000));
+    }
+
+    #[test]
+    fn distributions_reduce_basis_before_losses() {
+        let basis = ShareholderBasis {
+            stock: Usd::from_dollars(4_000),
+            ..Default::default()
+        };
+        let r = basis.apply(&adjustments(2_000, 7_000, 3_000));
+        assert_eq!(r.excess_distributions, Usd::from_dollars(1_000));
+        assert_eq!(r.allowed_losses, Usd::ZERO);
+        assert_eq!(r.ending.suspended_losses, Usd::from_dollars(3_000));
+    }
+}
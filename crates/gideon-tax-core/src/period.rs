@@ -0,0 +1,173 @@
+use crate::Date;
+
+/// Why a return covers less than twelve months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShortPeriodReason {
+    /// The taxpayer died; the final return ends on the date of death.
+    /// Income is not annualized and the full standard deduction and
+    /// exemptions are allowed.
+    Death,
+    /// Change in annual accounting period (IRC §443). Income is annualized,
+    /// the standard deduction is not allowed, and exemptions are prorated.
+    AccountingPeriodChange,
+}
+
+/// The period a return covers when it is not the calendar year.
+///
+/// A fiscal year is twelve consecutive months ending on the last day of any
+/// month other than December, and takes the rules of the year in which it
+/// begins. 52–53-week years are not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaxPeriod {
+    pub start: Date,
+    pub end: Date,
+    /// `Some` for a period shorter than twelve months.
+    pub short_period: Option<ShortPeriodReason>,
+}
+
+/// Why a [`TaxPeriod`] is not valid for a set of rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum PeriodError {
+    #[error("period ends before it starts")]
+    EndBeforeStart,
+    /// The period must begin in the rules' tax year.
+    #[error("period begins in {period_year} but rules are for {rules_year}")]
+    WrongYear { period_year: i32, rules_year: i32 },
+    /// A full-year period must span exactly twelve months ending on the last
+    /// day of a month.
+    #[error("a full tax year must be twelve months ending on the last day of a month")]
+    NotTwelveMonths,
+    /// A short period must be shorter than twelve months.
+    #[error("a short period must be shorter than twelve months")]
+    ShortPeriodTooLong,
+}
+
+impl TaxPeriod {
+    /// The calendar year `year`.
+    pub const fn calendar(year: i32) -> Self {
+        TaxPeriod {
+            start: Date::first_of_year(year),
+            end: Date::last_of_year(year),
+            short_period: None,
+        }
+    }
+
+    /// Number of calendar months the period touches, counting partial
+    /// months as whole months.
+    pub fn months(&self) -> i64 {
+        let index = |d: Date| d.year() as i64 * 12 + d.month() as i64;
+        index(self.end) - index(self.start) + 1
+    }
+
+    /// `true` if income must be annualized (IRC §443(b)).
+    pub fn annualizes(&self) -> bool {
+        self.short_period == Some(ShortPeriodReason::AccountingPeriodChange)
+    }
+
+    /// Checks that the period is well-formed and begins in `rules_year`.
+    pub fn validate(&self, rules_year: i32) -> Result<(), PeriodError> {
+        if self.end < self.start {
+            return Err(PeriodError::EndBeforeStart);
+        }
+        if self.start.year() != rules_year {
+            return Err(PeriodError::WrongYear {
+                period_year: self.start.year(),
+                rules_year,
+            });
+        }
+        let full_year =
+            self.start.day() == 1 && self.end == self.end.end_of_month() && self.months() == 12;
+        match self.short_period {
+            None if !full_year => Err(PeriodError::NotTwelveMonths),
+            Some(_) if full_year || self.months() > 12 => Err(PeriodError::ShortPeriodTooLong),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::new(y, m, day).unwrap()
+    }
+
+    fn period(start: Date, end: Date, short: Option<ShortPeriodReason>) -> TaxPeriod {
+        TaxPeriod {
+            start,
+            end,
+            short_period: short,
+        }
+    }
+
+    #[test]
+    fn calendar_year_valid() {
+        let p = TaxPeriod::calendar(2025);
+        assert_eq!(p.months(), 12);
+        assert_eq!(p.validate(2025), Ok(()));
+        assert!(!p.annualizes());
+    }
+
+    #[test]
+    fn fiscal_year_valid() {
+        let p = period(d(2025, 7, 1), d(2026, 6, 30), None);
+        assert_eq!(p.months(), 12);
+        assert_eq!(p.validate(2025), Ok(()));
+    }
+
+    #[test]
+    fn fiscal_year_wrong_rules_year() {
+        let p = period(d(2025, 7, 1), d(2026, 6, 30), None);
+        assert_eq!(
+            p.validate(2026),
+            Err(PeriodError::WrongYear {
+                period_year: 2025,
+                rules_year: 2026
+            })
+        );
+    }
+
+    #[test]
+    fn partial_year_without_reason_rejected() {
+        let p = period(d(2025, 1, 1), d(2025, 6, 30), None);
+        assert_eq!(p.validate(2025), Err(PeriodError::NotTwelveMonths));
+    }
+
+    #[test]
+    fn short_period_for_death() {
+        let p = period(
+            d(2025, 1, 1),
+            d(2025, 3, 14),
+            Some(ShortPeriodReason::Death),
+        );
+        assert_eq!(p.months(), 3);
+        assert_eq!(p.validate(2025), Ok(()));
+        assert!(!p.annualizes());
+    }
+
+    #[test]
+    fn short_period_for_accounting_change() {
+        let p = period(
+            d(2025, 1, 1),
+            d(2025, 9, 30),
+            Some(ShortPeriodReason::AccountingPeriodChange),
+        );
+        assert_eq!(p.months(), 9);
+        assert!(p.annualizes());
+        assert_eq!(p.validate(2025), Ok(()));
+    }
+
+    #[test]
+    fn short_period_covering_full_year_rejected() {
+        let mut p = TaxPeriod::calendar(2025);
+        p.short_period = Some(ShortPeriodReason::Death);
+        assert_eq!(p.validate(2025), Err(PeriodError::ShortPeriodTooLong));
+    }
+
+    #[test]
+    fn end_before_start_rejected() {
+        let p = period(d(2025, 5, 1), d(2025, 4, 1), Some(ShortPeriodReason::Death));
+        assert_eq!(p.validate(2025), Err(PeriodError::EndBeforeStart));
+    }
+}
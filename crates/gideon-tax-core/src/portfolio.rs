@@ -0,0 +1,234 @@
+use crate::Usd;
+use crate::capital_loss::CapitalLossCarryover;
+use crate::charitable::CharitableCarryover;
+use crate::error::Error;
+use crate::nol::NolCarryover;
+use crate::passive_loss::SuspendedLosses;
+use crate::rules::TaxYearRules;
+use crate::spine::{self, Key, Ledger, ReturnInput};
+
+/// Amounts one year's return passes to the next.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Carryovers {
+    /// Schedule D, lines 6 and 14.
+    pub capital_loss: CapitalLossCarryover,
+    /// Net operating losses, for Schedule 1, line 8a.
+    pub nol: Vec<NolCarryover>,
+    /// Charitable contributions not deducted because of the AGI limits.
+    pub charitable: Vec<CharitableCarryover>,
+    /// Minimum tax credit carryforward (Form 8801, line 26).
+    pub minimum_tax_credit: Usd,
+    /// Passive losses suspended by Form 8582, by activity.
+    pub passive_losses: SuspendedLosses,
+    /// Prior-year AGI, for the estimated tax safe harbor (IRC §6654(d)).
+    pub prior_year_agi: Option<Usd>,
+    /// Prior-year total tax, for the estimated tax safe harbor.
+    pub prior_year_tax: Option<Usd>,
+}
+
+/// One year's return in a [`ReturnPortfolio`].
+#[derive(Debug, Clone)]
+pub struct PortfolioYear {
+    /// The calendar year of the rules the year was computed with.
+    pub tax_year: i32,
+    /// The input as computed, with carryovers from the prior year filled
+    /// in.
+    pub input: ReturnInput,
+    pub ledger: Ledger,
+    /// Carryovers into this year.
+    pub carryovers_in: Carryovers,
+    /// Carryovers to next year.
+    pub carryovers_out: Carryovers,
+}
+
+/// Consecutive years of returns, each computed with the carryovers the
+/// year before it left.
+///
+/// Capital loss, net operating loss, charitable contribution, and passive
+/// loss carryovers flow through the spine. The minimum tax credit is not
+/// yet claimed by the spine, so it passes through unused.
+#[derive(Debug, Clone, Default)]
+pub struct ReturnPortfolio {
+    /// Carryovers into the first year.
+    opening: Carryovers,
+    years: Vec<PortfolioYear>,
+}
+
+impl ReturnPortfolio {
+    /// A portfolio whose first year starts with `opening` carryovers.
+    pub fn new(opening: Carryovers) -> Self {
+        ReturnPortfolio {
+            opening,
+            years: Vec::new(),
+        }
+    }
+
+    /// Fills `input`'s carryovers from the last year, computes it, and
+    /// appends it. Carryover fields already set on `input` are replaced.
+    pub fn push(
+        &mut self,
+        rules: &dyn TaxYearRules,
+        mut input: ReturnInput,
+    ) -> Result<&PortfolioYear, Error> {
+        let tax_year = rules.calendar_year();
+        if let Some(last) = self.years.last() {
+            let expected = last.tax_year + 1;
+            if tax_year != expected {
+                return Err(Error::NotConsecutive {
+                    expected,
+                    got: tax_year,
+                });
+            }
+        }
+        let carryovers_in = self.next_carryovers().clone();
+        input.capital_loss_carryover = carryovers_in.capital_loss;
+        input.nol_carryovers = carryovers_in.nol.clone();
+        input.itemized_deductions.charitable_carryovers = carryovers_in.charitable.clone();
+        carryovers_in
+            .passive_losses
+            .apply_to(&mut input.rental_properties);
+
+        let ledger = spine::compute_spine(rules, &input)?;
+        let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+
+        let mut passive_losses = carryovers_in.passive_losses.clone();
+        passive_losses.record(
+            &input.rental_properties,
+            &input.schedule_e_part_1(rules, &ledger),
+        );
+        let carryovers_out = Carryovers {
+            capital_loss: input.next_capital_loss_carryover(rules, &ledger),
+            nol: input.next_nol_carryovers(rules, &ledger),
+            charitable: input.next_charitable_carryovers(rules, &ledger),
+            minimum_tax_credit: carryovers_in.minimum_tax_credit,
+            passive_losses,
+            prior_year_agi: Some(get(Key::AGI)),
+            prior_year_tax: Some(get(Key::TotalTax)),
+        };
+        self.years.push(PortfolioYear {
+            tax_year,
+            input,
+            ledger,
+            carryovers_in,
+            carryovers_out,
+        });
+        Ok(self.years.last().expect("just pushed"))
+    }
+
+    /// Carryovers into the next year to be pushed.
+    pub fn next_carryovers(&self) -> &Carryovers {
+        self.years
+            .last()
+            .map_or(&self.opening, |y| &y.carryovers_out)
+    }
+
+    /// The year for `tax_year`, if present.
+    pub fn year(&self, tax_year: i32) -> Option<&PortfolioYear> {
+        self.years.iter().find(|y| y.tax_year == tax_year)
+    }
+
+    /// Years in order.
+    pub fn years(&self) -> &[PortfolioYear] {
+        &self.years
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charitable::LimitCategory;
+    use crate::rental::{RentalExpenses, RentalProperty};
+    use crate::rules::y2025::Rules2025;
+    use crate::rules::y2026::Rules2026Projected;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn carryovers_threaded_into_input() {
+        let mut passive_losses = SuspendedLosses::new();
+        passive_losses.set("1 Main St", Usd::from_dollars(2_000));
+        let mut portfolio = ReturnPortfolio::new(Carryovers {
+            capital_loss: CapitalLossCarryover {
+                short_term: Usd::from_dollars(5_000),
+                long_term: Usd::ZERO,
+            },
+            passive_losses,
+            ..Default::default()
+        });
+        let mut inp = input(80_000, 0);
+        inp.rental_properties.push(RentalProperty {
+            address: "1 Main St".to_string(),
+            rents_received: Usd::from_dollars(12_000),
+            expenses: RentalExpenses {
+                repairs: Usd::from_dollars(11_000),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let year = portfolio.push(&Rules2025, inp).unwrap();
+
+        // 3,000 of the capital loss is deducted; the 1,000 of rental income
+        // frees half of the suspended loss.
+        assert_eq!(
+            year.ledger[&Key::CapitalGainOrLoss],
+            Usd::from_dollars(-3_000)
+        );
+        assert_eq!(year.ledger[&Key::SupplementalIncome], Usd::ZERO);
+        let out = &year.carryovers_out;
+        assert_eq!(out.capital_loss.short_term, Usd::from_dollars(2_000));
+        assert_eq!(
+            out.passive_losses.get("1 Main St"),
+            Usd::from_dollars(1_000)
+        );
+        assert_eq!(out.prior_year_agi, Some(Usd::from_dollars(77_000)));
+    }
+
+    #[test]
+    fn expired_charitable_carryovers_dropped() {
+        let carryover = |year, amount| CharitableCarryover {
+            category: LimitCategory::Sixty,
+            year,
+            amount: Usd::from_dollars(amount),
+        };
+        let mut portfolio = ReturnPortfolio::new(Carryovers {
+            charitable: vec![carryover(2020, 1_000), carryover(2023, 40_000)],
+            minimum_tax_credit: Usd::from_dollars(500),
+            ..Default::default()
+        });
+        portfolio.push(&Rules2025, input(50_000, 0)).unwrap();
+        let next = portfolio.next_carryovers();
+        // The standard deduction is taken, but carryovers are still used,
+        // oldest first, up to 60% of the 50,000 AGI.
+        assert_eq!(next.charitable, vec![carryover(2023, 11_000)]);
+        assert_eq!(next.minimum_tax_credit, Usd::from_dollars(500));
+        assert!(portfolio.year(2025).is_some());
+    }
+
+    #[test]
+    fn years_must_be_consecutive() {
+        let mut portfolio = ReturnPortfolio::default();
+        portfolio.push(&Rules2025, input(50_000, 0)).unwrap();
+        let err = portfolio.push(&Rules2025, input(50_000, 0)).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::NotConsecutive {
+                expected: 2026,
+                got: 2025
+            }
+        ));
+        // The 2026 projection follows 2025 and takes a 2026 input.
+        let inp = ReturnInput {
+            tax_year: 2026,
+            ..input(50_000, 0)
+        };
+        portfolio.push(&Rules2026Projected, inp).unwrap();
+        assert!(portfolio.year(2026).is_some());
+        let inp = ReturnInput {
+            tax_year: 2024,
+            ..input(50_000, 0)
+        };
+        assert!(matches!(
+            ReturnPortfolio::default().push(&Rules2025, inp),
+            Err(Error::YearMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,273 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::rules::TaxYearRules;
+use crate::{Rate, Usd};
+
+/// Region whose federal poverty guidelines apply to the tax family.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PovertyRegion {
+    /// The 48 contiguous states and the District of Columbia.
+    #[default]
+    Contiguous,
+    Alaska,
+    Hawaii,
+}
+
+/// One month of marketplace coverage, as reported on Form 1095-A, Part III
+/// and carried to Form 8962, lines 12–23.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoverageMonth {
+    /// Monthly enrollment premium (column A).
+    pub enrollment_premium: Usd,
+    /// Monthly second lowest cost silver plan (SLCSP) premium (column B).
+    pub slcsp_premium: Usd,
+    /// Monthly advance payment of the premium tax credit (column C).
+    pub advance_payment: Usd,
+}
+
+/// Marketplace coverage for the tax family, summed across policies.
+///
+/// Months are indexed January (0) through December (11).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MarketplaceCoverage {
+    pub region: PovertyRegion,
+    pub months: [CoverageMonth; 12],
+}
+
+impl MarketplaceCoverage {
+    /// Adds one policy's monthly amounts (already allocated to this tax
+    /// family, if shared).
+    pub fn add_policy(&mut self, months: &[CoverageMonth; 12]) {
+        for (total, month) in self.months.iter_mut().zip(months) {
+            total.enrollment_premium += month.enrollment_premium;
+            total.slcsp_premium += month.slcsp_premium;
+            total.advance_payment += month.advance_payment;
+        }
+    }
+}
+
+/// One band of the applicable percentage table (IRC §36B(b)(3)(A)).
+///
+/// Within a band the applicable percentage rises linearly from `initial`
+/// to `final_` as household income rises from the previous band's upper
+/// bound to `up_to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApplicablePercentageBand {
+    /// Upper bound of household income as a percentage of the federal
+    /// poverty line (exclusive), or `None` for the top band.
+    pub up_to: Option<i64>,
+    pub initial: Rate,
+    pub final_: Rate,
+}
+
+/// Looks up the applicable figure (Form 8962, line 7) for household income
+/// at `fpl_percent` of the poverty line, interpolating within the band and
+/// rounding to the nearest basis point.
+pub fn interpolate_applicable_figure(bands: &[ApplicablePercentageBand], fpl_percent: i64) -> Rate {
+    let mut low = 0;
+    for band in bands {
+        match band.up_to {
+            Some(high) if fpl_percent >= high => low = high,
+            Some(high) => {
+                let span = band.final_.bps() - band.initial.bps();
+                let width = high - low;
+                let step = (span * (fpl_percent - low) + width / 2) / width;
+                return Rate::from_bps(band.initial.bps() + step);
+            }
+            None => return band.final_,
+        }
+    }
+    bands.last().map_or(Rate::ZERO, |b| b.final_)
+}
+
+/// Input to [`compute`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PtcParams {
+    pub filing_status: FilingStatus,
+    /// Number of individuals in the tax family (line 1).
+    pub family_size: u32,
+    /// Household income: modified AGI of the taxpayer, spouse, and
+    /// dependents required to file (line 3).
+    pub household_income: Usd,
+    pub coverage: MarketplaceCoverage,
+}
+
+/// Results of Form 8962.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PremiumTaxCredit {
+    /// Household income as a percentage of the federal poverty line, rounded
+    /// down (line 5).
+    pub fpl_percent: i64,
+    /// Applicable figure (line 7).
+    pub applicable_figure: Rate,
+    /// Annual contribution for health care (line 8a).
+    pub annual_contribution: Usd,
+    /// Monthly premium tax credit allowed (lines 12–23, column (e)).
+    pub monthly_credit: [Usd; 12],
+    /// Total premium tax credit (line 24).
+    pub credit: Usd,
+    /// Total advance payments (line 25).
+    pub advance_payments: Usd,
+    /// Net premium tax credit (line 26), reported on Schedule 3, line 9.
+    pub net_credit: Usd,
+    /// Excess advance payment repayment after the limitation (line 29),
+    /// reported on Schedule 2, line 1a.
+    pub excess_advance_repayment: Usd,
+}
+
+/// Computes the premium tax credit and reconciles it with advance payments
+/// on Form 8962.
+///
+/// The credit for each covered month is the enrollment premium, limited to
+/// the SLCSP premium less the monthly contribution. A family with income
+/// below the poverty line is allowed the credit only if advance payments
+/// were made. Married taxpayers filing separately get no credit; the
+/// domestic abuse and abandonment relief is not modeled.
+///
+/// See: <https://www.irs.gov/instructions/i8962>
+pub fn compute(rules: &dyn TaxYearRules, params: &PtcParams) -> PremiumTaxCredit {
+    let coverage = &params.coverage;
+    let poverty_line = rules.poverty_line(coverage.region, params.family_size);
+    let fpl_percent = if poverty_line > Usd::ZERO {
+        params.household_income.cents() * 100 / poverty_line.cents()
+    } else {
+        0
+    };
+    let advance_payments: Usd = coverage.months.iter().map(|m| m.advance_payment).sum();
+
+    let eligible = params.filing_status != FilingStatus::MarriedFilingSeparately
+        && (fpl_percent >= 100 || advance_payments > Usd::ZERO);
+    let applicable_figure = rules.ptc_applicable_figure(fpl_percent.max(0));
+    let annual_contribution = params.household_income.max(Usd::ZERO) * applicable_figure;
+    let monthly_contribution = annual_contribution.mul_div(1, 12);
+    let monthly_credit = coverage.months.map(|m| {
+        if !eligible || m.enrollment_premium <= Usd::ZERO {
+            return Usd::ZERO;
+        }
+        let max_credit = (m.slcsp_premium - monthly_contribution).max(Usd::ZERO);
+        m.enrollment_premium.min(max_credit)
+    });
+    let credit: Usd = monthly_credit.iter().copied().sum();
+
+    let net_credit = (credit - advance_payments).max(Usd::ZERO);
+    let excess = (advance_payments - credit).max(Usd::ZERO);
+    let excess_advance_repayment =
+        match rules.ptc_repayment_limit(params.filing_status, fpl_percent) {
+            Some(limit) => excess.min(limit),
+            None => excess,
+        };
+
+    PremiumTaxCredit {
+        fpl_percent,
+        applicable_figure,
+        annual_contribution,
+        monthly_credit,
+        credit,
+        advance_payments,
+        net_credit,
+        excess_advance_repayment,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    fn coverage(premium: i64, slcsp: i64, advance: i64) -> MarketplaceCoverage {
+        MarketplaceCoverage {
+            region: PovertyRegion::Contiguous,
+            months: [CoverageMonth {
+                enrollment_premium: Usd::from_dollars(premium),
+                slcsp_premium: Usd::from_dollars(slcsp),
+                advance_payment: Usd::from_dollars(advance),
+            }; 12],
+        }
+    }
+
+    fn params(income: i64, coverage: MarketplaceCoverage) -> PtcParams {
+        PtcParams {
+            filing_status: FilingStatus::Single,
+            family_size: 1,
+            household_income: Usd::from_dollars(income),
+            coverage,
+        }
+    }
+
+    #[test]
+    fn interpolates_within_band() {
+        let bands = [
+            ApplicablePercentageBand {
+                up_to: Some(200),
+                initial: Rate::ZERO,
+                final_: Rate::from_percent(2),
+            },
+            ApplicablePercentageBand {
+                up_to: None,
+                initial: Rate::from_percent(2),
+                final_: Rate::from_percent(2),
+            },
+        ];
+        assert_eq!(interpolate_applicable_figure(&bands, 0), Rate::ZERO);
+        assert_eq!(
+            interpolate_applicable_figure(&bands, 150),
+            Rate::from_bps(150)
+        );
+        assert_eq!(
+            interpolate_applicable_figure(&bands, 450),
+            Rate::from_percent(2)
+        );
+    }
+
+    #[test]
+    fn credit_reconciled_with_advance_payments() {
+        // 2024 guidelines: 15,060 for one person; 30,120 is 200%, figure 2%.
+        let ptc = compute(&Rules2025, &params(30_120, coverage(500, 450, 300)));
+        assert_eq!(ptc.fpl_percent, 200);
+        assert_eq!(ptc.applicable_figure, Rate::from_percent(2));
+        assert_eq!(ptc.annual_contribution, Usd::from_cents(60_240));
+        // min(500, 450 - 50.20) = 399.80 per month
+        assert_eq!(ptc.monthly_credit[0], Usd::from_cents(39_980));
+        assert_eq!(ptc.credit, Usd::from_cents(479_760));
+        assert_eq!(ptc.net_credit, Usd::from_cents(119_760));
+        assert_eq!(ptc.excess_advance_repayment, Usd::ZERO);
+    }
+
+    #[test]
+    fn excess_advance_repayment_limited() {
+        // 45,180 is 300%, figure 6%: contribution 2,710.80, 225.90/month.
+        let ptc = compute(&Rules2025, &params(45_180, coverage(400, 400, 350)));
+        assert_eq!(ptc.applicable_figure, Rate::from_percent(6));
+        assert_eq!(ptc.credit, Usd::from_cents(208_920));
+        // Excess of 2,110.80 limited for 300%–400% of the poverty line.
+        assert_eq!(ptc.excess_advance_repayment, Usd::from_dollars(1_625));
+        assert_eq!(ptc.net_credit, Usd::ZERO);
+    }
+
+    #[test]
+    fn no_credit_below_poverty_line_without_advance_payments() {
+        let ptc = compute(&Rules2025, &params(10_000, coverage(400, 400, 0)));
+        assert_eq!(ptc.credit, Usd::ZERO);
+        let ptc = compute(&Rules2025, &params(10_000, coverage(400, 400, 100)));
+        assert_eq!(ptc.credit, Usd::from_dollars(4_800));
+    }
+
+    #[test]
+    fn married_filing_separately_repays_advance() {
+        let mut p = params(45_180, coverage(400, 400, 100));
+        p.filing_status = FilingStatus::MarriedFilingSeparately;
+        let ptc = compute(&Rules2025, &p);
+        assert_eq!(ptc.credit, Usd::ZERO);
+        assert_eq!(ptc.excess_advance_repayment, Usd::from_dollars(1_200));
+    }
+
+    #[test]
+    fn add_policy_sums_months() {
+        let mut total = MarketplaceCoverage::default();
+        total.add_policy(&coverage(100, 200, 50).months);
+        total.add_policy(&coverage(300, 200, 25).months);
+        assert_eq!(total.months[11].enrollment_premium, Usd::from_dollars(400));
+        assert_eq!(total.months[11].advance_payment, Usd::from_dollars(75));
+    }
+}
@@ -0,0 +1,226 @@
+use crate::error::Error;
+use crate::rules::TaxYearRules;
+use crate::rules::indexing::CostOfLivingAdjustment;
+use crate::rules::sunset::TcjaSunset;
+use crate::spine::{self, Ledger, ReturnInput};
+use crate::{Rate, Usd};
+
+/// Price index of the base year, in millionths.
+const BASE_INDEX: i64 = 1_000_000;
+
+/// Annual rates a projection compounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ProjectionAssumptions {
+    /// Nominal growth in wages each year.
+    pub wage_growth: Rate,
+    /// Inflation each year, by which the rules' dollar amounts are indexed.
+    pub inflation: Rate,
+    /// If set, years after 2025 are computed as if TCJA's individual
+    /// provisions had expired, with 2017 amounts indexed by this adjustment
+    /// to the base year's dollars. See [`TcjaSunset`].
+    pub tcja_sunset: Option<CostOfLivingAdjustment>,
+}
+
+/// A change in circumstances that applies from its year onward.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LifeEvent {
+    /// A child is born and claimed as a dependent.
+    ChildBorn,
+    /// The mortgage on the rental property at `address` is paid off, so it
+    /// has no more mortgage interest.
+    MortgagePaidOff { address: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduledEvent {
+    pub year: i32,
+    pub event: LifeEvent,
+}
+
+/// One projected year, in that year's dollars.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectedYear {
+    pub year: i32,
+    pub w2_wages: Usd,
+    pub ledger: Ledger,
+}
+
+/// Rolls `base` forward `years` years after its own, returning the base
+/// year followed by each projected year.
+///
+/// Wages and withholding grow at the wage growth rate. Every other amount
+/// in the input, and every dollar amount in the rules, is assumed to keep
+/// pace with inflation. Each year is therefore computed with `rules` in
+/// base-year dollars, with wages deflated by the price index, and the
+/// ledger is then inflated back to the year's dollars. Bracket rounding
+/// differs slightly from the published tables of later years. Carryovers
+/// are not threaded between projected years; see
+/// [`ReturnPortfolio`](crate::portfolio::ReturnPortfolio) for filed years.
+pub fn project(
+    rules: &dyn TaxYearRules,
+    base: &ReturnInput,
+    years: u32,
+    assumptions: &ProjectionAssumptions,
+    events: &[ScheduledEvent],
+) -> Result<Vec<ProjectedYear>, Error> {
+    let base_year = rules.calendar_year();
+    let grow = |amount: Usd, rate: Rate| amount + amount * rate;
+
+    let mut input = base.clone();
+    let mut wages = base.w2_wages;
+    let mut index = BASE_INDEX;
+    let mut result = Vec::new();
+    for year in base_year..=base_year + years as i32 {
+        if year > base_year {
+            wages = grow(wages, assumptions.wage_growth);
+            index = Usd::from_cents(index)
+                .mul_div(10_000 + assumptions.inflation.bps(), 10_000)
+                .cents();
+        }
+        for e in events.iter().filter(|e| e.year == year) {
+            apply(&mut input, &e.event);
+        }
+        let deflate = |amount: Usd| amount.mul_div(BASE_INDEX, index);
+        input.w2_wages = deflate(wages);
+        input.fed_withholding = deflate(
+            base.fed_withholding
+                .mul_div(wages.cents(), base.w2_wages.cents().max(1)),
+        );
+        let sunset = assumptions
+            .tcja_sunset
+            .filter(|_| year > 2025)
+            .map(|adjustment| TcjaSunset::new(rules, adjustment));
+        let year_rules = sunset.as_ref().map_or(rules, |s| s as &dyn TaxYearRules);
+        let ledger = spine::compute_spine(year_rules, &input)?
+            .iter()
+            .map(|(k, v)| (*k, v.mul_div(index, BASE_INDEX)))
+            .collect();
+        result.push(ProjectedYear {
+            year,
+            w2_wages: wages,
+            ledger,
+        });
+    }
+    Ok(result)
+}
+
+fn apply(input: &mut ReturnInput, event: &LifeEvent) {
+    match event {
+        LifeEvent::ChildBorn => input.num_dependents += 1,
+        LifeEvent::MortgagePaidOff { address } => {
+            for p in input
+                .rental_properties
+                .iter_mut()
+                .filter(|p| &p.address == address)
+            {
+                p.expenses.mortgage_interest = Usd::ZERO;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rental::{RentalExpenses, RentalProperty};
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::Key;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn wages_outpacing_inflation_raise_real_income() {
+        let assumptions = ProjectionAssumptions {
+            wage_growth: Rate::from_percent(5),
+            inflation: Rate::from_percent(2),
+            ..Default::default()
+        };
+        let years = project(&Rules2025, &input(60_000, 6_000), 2, &assumptions, &[]).unwrap();
+        assert_eq!(
+            years.iter().map(|y| y.year).collect::<Vec<_>>(),
+            vec![2025, 2026, 2027]
+        );
+        assert_eq!(
+            years[0].ledger[&Key::TotalIncome],
+            Usd::from_dollars(60_000)
+        );
+        assert_eq!(years[2].w2_wages, Usd::from_dollars(66_150));
+        // Deflating and reinflating wages can lose a cent.
+        let off = |key: Key, expected: Usd| (years[2].ledger[&key] - expected).cents().abs();
+        assert!(off(Key::TotalIncome, Usd::from_dollars(66_150)) <= 1);
+        assert!(off(Key::Withholding, Usd::from_dollars(6_615)) <= 1);
+        // The standard deduction is indexed: 15,750 × 1.02².
+        assert_eq!(off(Key::Deductions, Usd::from_cents(1_638_630)), 0);
+    }
+
+    #[test]
+    fn inflation_only_keeps_real_tax_constant() {
+        let assumptions = ProjectionAssumptions {
+            wage_growth: Rate::from_percent(3),
+            inflation: Rate::from_percent(3),
+            ..Default::default()
+        };
+        let years = project(&Rules2025, &input(60_000, 0), 1, &assumptions, &[]).unwrap();
+        let tax = years[0].ledger[&Key::RegularTax];
+        assert_eq!(years[1].ledger[&Key::RegularTax], tax.mul_div(103, 100));
+    }
+
+    #[test]
+    fn events_apply_from_their_year() {
+        let mut base = input(60_000, 0);
+        base.rental_properties.push(RentalProperty {
+            address: "1 Main St".to_string(),
+            rents_received: Usd::from_dollars(20_000),
+            expenses: RentalExpenses {
+                mortgage_interest: Usd::from_dollars(8_000),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let events = [ScheduledEvent {
+            year: 2026,
+            event: LifeEvent::MortgagePaidOff {
+                address: "1 Main St".to_string(),
+            },
+        }];
+        let years = project(
+            &Rules2025,
+            &base,
+            1,
+            &ProjectionAssumptions::default(),
+            &events,
+        )
+        .unwrap();
+        assert_eq!(
+            years[0].ledger[&Key::SupplementalIncome],
+            Usd::from_dollars(12_000)
+        );
+        assert_eq!(
+            years[1].ledger[&Key::SupplementalIncome],
+            Usd::from_dollars(20_000)
+        );
+    }
+
+    #[test]
+    fn sunset_applies_after_2025() {
+        let assumptions = ProjectionAssumptions {
+            tcja_sunset: Some(CostOfLivingAdjustment {
+                index: 1,
+                base_index: 1,
+            }),
+            ..Default::default()
+        };
+        let current = project(&Rules2025, &input(60_000, 0), 1, &Default::default(), &[]).unwrap();
+        let sunset = project(&Rules2025, &input(60_000, 0), 1, &assumptions, &[]).unwrap();
+        assert_eq!(sunset[0].ledger, current[0].ledger);
+        assert_eq!(
+            sunset[1].ledger[&Key::TaxableIncome],
+            Usd::from_dollars(49_600)
+        );
+        assert!(sunset[1].ledger[&Key::TotalTax] > current[1].ledger[&Key::TotalTax]);
+    }
+}
@@ -0,0 +1,94 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::rules::TaxYearRules;
+use crate::{Rate, Usd};
+
+/// Deduction rate for qualified business income (IRC §199A(b)(2)).
+const QBI_RATE: Rate = Rate::from_percent(20);
+
+/// Inputs to Form 8995.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QbiParams {
+    /// Total qualified business income or loss (line 4).
+    pub qualified_business_income: Usd,
+    /// Taxable income before the QBI deduction (line 11).
+    pub taxable_income_before_qbi: Usd,
+    /// Net capital gain, including qualified dividends (line 12).
+    pub net_capital_gain: Usd,
+}
+
+/// Computes the qualified business income deduction using Form 8995.
+///
+/// Returns `None` when taxable income exceeds the year's threshold and
+/// Form 8995-A (W-2 wage and property limits) is required instead. REIT
+/// dividends, PTP income, and loss carryforwards are not modeled.
+///
+/// See: <https://www.irs.gov/instructions/i8995>
+pub fn simplified_deduction(
+    rules: &dyn TaxYearRules,
+    filing_status: FilingStatus,
+    params: &QbiParams,
+) -> Option<Usd> {
+    if params.taxable_income_before_qbi > rules.qbi_threshold(filing_status) {
+        return None;
+    }
+    // Lines 4–5; a net loss carries forward and allows no deduction.
+    let component = params.qualified_business_income.max(Usd::ZERO) * QBI_RATE;
+    // Lines 11–14
+    let income_limit =
+        (params.taxable_income_before_qbi - params.net_capital_gain).max(Usd::ZERO) * QBI_RATE;
+    // Line 15
+    Some(component.min(income_limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    fn params(qbi: i64, taxable: i64, cap_gain: i64) -> QbiParams {
+        QbiParams {
+            qualified_business_income: Usd::from_dollars(qbi),
+            taxable_income_before_qbi: Usd::from_dollars(taxable),
+            net_capital_gain: Usd::from_dollars(cap_gain),
+        }
+    }
+
+    #[test]
+    fn limited_by_qbi_component() {
+        let d = simplified_deduction(&Rules2025, FilingStatus::Single, &params(50_000, 80_000, 0));
+        assert_eq!(d, Some(Usd::from_dollars(10_000)));
+    }
+
+    #[test]
+    fn limited_by_taxable_income() {
+        let d = simplified_deduction(
+            &Rules2025,
+            FilingStatus::Single,
+            &params(50_000, 40_000, 5_000),
+        );
+        assert_eq!(d, Some(Usd::from_dollars(7_000)));
+    }
+
+    #[test]
+    fn net_loss_no_deduction() {
+        let d = simplified_deduction(&Rules2025, FilingStatus::Single, &params(-5_000, 60_000, 0));
+        assert_eq!(d, Some(Usd::ZERO));
+    }
+
+    #[test]
+    fn above_threshold_requires_8995_a() {
+        let d = simplified_deduction(
+            &Rules2025,
+            FilingStatus::Single,
+            &params(50_000, 200_000, 0),
+        );
+        assert_eq!(d, None);
+        let d = simplified_deduction(
+            &Rules2025,
+            FilingStatus::MarriedFilingJointly,
+            &params(50_000, 200_000, 0),
+        );
+        assert_eq!(d, Some(Usd::from_dollars(10_000)));
+    }
+}
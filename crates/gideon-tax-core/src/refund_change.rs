@@ -0,0 +1,308 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+use crate::error::Error;
+use crate::ledger::{Key, Ledger};
+use crate::rules::TaxYearRules;
+use crate::spine::ReturnInput;
+use crate::trace::{self, ParameterValue, Trace};
+
+/// A return for one year: its input and the rules it is computed under.
+#[derive(Clone, Copy)]
+pub struct YearReturn<'a> {
+    pub rules: &'a dyn TaxYearRules,
+    pub input: &'a ReturnInput,
+}
+
+/// What moved the refund between two years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CauseKind {
+    /// Year parameters and bracket tables.
+    TaxLaw,
+    /// Filing status, dependents, and age and blindness.
+    Household,
+    Income,
+    Adjustments,
+    /// Itemized deductions and charitable carryovers.
+    ItemizedDeductions,
+    MarketplaceCoverage,
+    /// Withholding and payments made with an extension request.
+    Withholding,
+}
+
+/// A year parameter whose value differs between two traces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParameterChange {
+    pub name: &'static str,
+    pub filing_status: Option<FilingStatus>,
+    pub prior: ParameterValue,
+    pub current: ParameterValue,
+}
+
+/// One cause of the change, with its share of the change in dollars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefundCause {
+    pub kind: CauseKind,
+    /// Effect on the net refund; negative if it made the refund smaller or
+    /// the amount owed larger.
+    pub amount: Usd,
+    pub text: String,
+    /// For [`CauseKind::TaxLaw`], the year parameters that changed.
+    pub parameter_changes: Vec<ParameterChange>,
+}
+
+/// Why the refund changed from one year to the next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefundChange {
+    /// Refund less amount owed for the prior year.
+    pub prior_net_refund: Usd,
+    /// Refund less amount owed for the current year.
+    pub current_net_refund: Usd,
+    pub change: Usd,
+    /// Causes with a nonzero effect, largest first. The amounts add up to
+    /// `change`.
+    pub causes: Vec<RefundCause>,
+    pub prior: Ledger,
+    pub current: Ledger,
+}
+
+/// Explains the change in net refund from `prior` to `current`.
+///
+/// Starting from the prior return, each group of inputs is switched to its
+/// current-year value in turn (tax law, household, income, adjustments,
+/// itemized deductions, marketplace coverage, withholding) and the return is recomputed. A
+/// cause's amount is the change in net refund at its step, so the amounts
+/// add up to the total change. Interactions between causes are credited to
+/// the later step.
+pub fn explain_refund_change(
+    prior: YearReturn<'_>,
+    current: YearReturn<'_>,
+) -> Result<RefundChange, Error> {
+    let (prior_ledger, prior_trace) = trace::compute_spine_traced(prior.rules, prior.input)?;
+    let (current_ledger, current_trace) =
+        trace::compute_spine_traced(current.rules, current.input)?;
+
+    let mut step = prior.input.clone();
+    let mut before = net_refund(&prior_ledger);
+    let mut causes = Vec::new();
+    for kind in [
+        CauseKind::TaxLaw,
+        CauseKind::Household,
+        CauseKind::Income,
+        CauseKind::Adjustments,
+        CauseKind::ItemizedDeductions,
+        CauseKind::MarketplaceCoverage,
+        CauseKind::Withholding,
+    ] {
+        switch(kind, &mut step, current.input);
+        let after = net_refund(&crate::spine::compute_spine(current.rules, &step)?);
+        let amount = after - before;
+        before = after;
+        if amount == Usd::ZERO {
+            continue;
+        }
+        let parameter_changes = match kind {
+            CauseKind::TaxLaw => diff_parameters(&prior_trace, &current_trace),
+            _ => Vec::new(),
+        };
+        causes.push(RefundCause {
+            kind,
+            amount,
+            text: describe(
+                kind,
+                amount,
+                &prior_ledger,
+                &current_ledger,
+                &parameter_changes,
+            ),
+            parameter_changes,
+        });
+    }
+    causes.sort_by_key(|c| std::cmp::Reverse(c.amount.cents().abs()));
+
+    let prior_net_refund = net_refund(&prior_ledger);
+    let current_net_refund = net_refund(&current_ledger);
+    Ok(RefundChange {
+        prior_net_refund,
+        current_net_refund,
+        change: current_net_refund - prior_net_refund,
+        causes,
+        prior: prior_ledger,
+        current: current_ledger,
+    })
+}
+
+/// Year parameters consulted by both computations whose values differ, in
+/// the order `current` first used them.
+pub fn diff_parameters(prior: &Trace, current: &Trace) -> Vec<ParameterChange> {
+    current
+        .parameters
+        .iter()
+        .filter_map(|c| {
+            let p = prior
+                .parameters
+                .iter()
+                .find(|p| p.name == c.name && p.filing_status == c.filing_status)?;
+            (p.value != c.value).then_some(ParameterChange {
+                name: c.name,
+                filing_status: c.filing_status,
+                prior: p.value,
+                current: c.value,
+            })
+        })
+        .collect()
+}
+
+fn net_refund(ledger: &Ledger) -> Usd {
+    let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    get(Key::Refund) - get(Key::AmountOwed)
+}
+
+/// Copies the inputs for `kind` from `current` into `step`.
+fn switch(kind: CauseKind, step: &mut ReturnInput, current: &ReturnInput) {
+    match kind {
+        CauseKind::TaxLaw => {
+            step.tax_year = current.tax_year;
+            step.period = current.period;
+            step.deceased = current.deceased.clone();
+        }
+        CauseKind::Household => {
+            step.filing_status = current.filing_status;
+            step.taxpayer = current.taxpayer;
+            step.spouse = current.spouse;
+            step.is_dependent = current.is_dependent;
+            step.is_dual_status_alien = current.is_dual_status_alien;
+            step.spouse_itemizes = current.spouse_itemizes;
+            step.num_dependents = current.num_dependents;
+            step.mfs_lived_with_spouse = current.mfs_lived_with_spouse;
+        }
+        CauseKind::Income => {
+            step.w2_wages = current.w2_wages;
+            step.unreported_tips = current.unreported_tips.clone();
+            step.children_investment_income = current.children_investment_income.clone();
+            step.capital_loss_carryover = current.capital_loss_carryover;
+            step.nol_carryovers = current.nol_carryovers.clone();
+            step.social_security_benefits = current.social_security_benefits;
+            step.hsa_distributions = current.hsa_distributions;
+            step.students = current.students.clone();
+            step.passthroughs = current.passthroughs.clone();
+            step.rental_properties = current.rental_properties.clone();
+            step.home_sales = current.home_sales.clone();
+        }
+        CauseKind::Adjustments => step.adjustments = current.adjustments,
+        CauseKind::ItemizedDeductions => {
+            step.itemized_deductions = current.itemized_deductions.clone();
+        }
+        CauseKind::MarketplaceCoverage => step.marketplace_coverage = current.marketplace_coverage,
+        CauseKind::Withholding => {
+            step.fed_withholding = current.fed_withholding;
+            step.form_1099_withholding = current.form_1099_withholding.clone();
+            step.extension_payment = current.extension_payment;
+        }
+    }
+}
+
+fn describe(
+    kind: CauseKind,
+    amount: Usd,
+    prior: &Ledger,
+    current: &Ledger,
+    parameter_changes: &[ParameterChange],
+) -> String {
+    let effect = if amount > Usd::ZERO {
+        format!("raised your refund by {amount}")
+    } else {
+        format!("lowered your refund by {}", Usd::ZERO - amount)
+    };
+    let moved = |key: Key| {
+        let get = |l: &Ledger| l.get(&key).copied().unwrap_or(Usd::ZERO);
+        format!(
+            "{} went from {} to {}",
+            key.label().to_lowercase(),
+            get(prior),
+            get(current)
+        )
+    };
+    match kind {
+        CauseKind::TaxLaw if parameter_changes.is_empty() => {
+            format!("Tax rates and brackets for the new year {effect}.")
+        }
+        CauseKind::TaxLaw => format!(
+            "Tax law changes {effect}; {} year amounts changed, including {}.",
+            parameter_changes.len(),
+            parameter_changes[0].name.replace('_', " ")
+        ),
+        CauseKind::Household => {
+            format!("Changes to your filing status, dependents, or age and blindness {effect}.")
+        }
+        CauseKind::Income => format!("Your {} and this {effect}.", moved(Key::TotalIncome)),
+        CauseKind::Adjustments => format!("Your {} and this {effect}.", moved(Key::Adjustments)),
+        CauseKind::ItemizedDeductions => {
+            format!("Your {} and this {effect}.", moved(Key::Deductions))
+        }
+        CauseKind::MarketplaceCoverage => {
+            format!("Changes to your marketplace health coverage {effect}.")
+        }
+        CauseKind::Withholding => format!("Your {} and this {effect}.", moved(Key::Withholding)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+    use crate::trace::ParameterUse;
+
+    #[test]
+    fn causes_ranked_and_add_up() {
+        let prior = input(60_000, 9_000);
+        let current = input(62_000, 7_000);
+        let year = |input| YearReturn {
+            rules: &Rules2025,
+            input,
+        };
+        let change = explain_refund_change(year(&prior), year(&current)).unwrap();
+
+        assert!(change.change < Usd::ZERO);
+        let kinds: Vec<CauseKind> = change.causes.iter().map(|c| c.kind).collect();
+        assert_eq!(kinds, [CauseKind::Withholding, CauseKind::Income]);
+        let total: Usd = change.causes.iter().map(|c| c.amount).sum();
+        assert_eq!(total, change.change);
+        assert_eq!(change.causes[0].amount, Usd::from_dollars(-2_000));
+        assert_eq!(
+            change.causes[0].text,
+            "Your federal income tax withheld went from $9000.00 to $7000.00 and this \
+             lowered your refund by $2000.00."
+        );
+    }
+
+    #[test]
+    fn parameter_diff() {
+        let trace = |deduction: i64| Trace {
+            year: 2025,
+            parameters: vec![
+                ParameterUse {
+                    name: "single_mfs_typical_standard_deduction",
+                    filing_status: None,
+                    value: ParameterValue::Usd(Usd::from_dollars(deduction)),
+                },
+                ParameterUse {
+                    name: "dependent_minimum_deduction",
+                    filing_status: None,
+                    value: ParameterValue::Usd(Usd::from_dollars(1_350)),
+                },
+            ],
+        };
+        let changes = diff_parameters(&trace(14_600), &trace(15_750));
+        assert_eq!(
+            changes,
+            [ParameterChange {
+                name: "single_mfs_typical_standard_deduction",
+                filing_status: None,
+                prior: ParameterValue::Usd(Usd::from_dollars(14_600)),
+                current: ParameterValue::Usd(Usd::from_dollars(15_750)),
+            }]
+        );
+    }
+}
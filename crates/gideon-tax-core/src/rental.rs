@@ -0,0 +1,467 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+use crate::depreciation::{self, DepreciableAsset};
+
+/// Special allowance for rental real estate with active participation
+/// (IRC §469(i)(2)); half for married filing separately and living apart.
+pub const SPECIAL_ALLOWANCE: Usd = Usd::from_dollars(25_000);
+
+/// Modified AGI above which the special allowance is reduced by 50% of the
+/// excess (IRC §469(i)(3)); half for married filing separately.
+pub const SPECIAL_ALLOWANCE_PHASEOUT_START: Usd = Usd::from_dollars(100_000);
+
+/// Type of property (Schedule E, line 1b).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PropertyType {
+    #[default]
+    SingleFamily,
+    MultiFamily,
+    VacationShortTerm,
+    Commercial,
+    Land,
+    Royalties,
+    SelfRental,
+    Other,
+}
+
+impl PropertyType {
+    pub const ALL: [PropertyType; 8] = [
+        PropertyType::SingleFamily,
+        PropertyType::MultiFamily,
+        PropertyType::VacationShortTerm,
+        PropertyType::Commercial,
+        PropertyType::Land,
+        PropertyType::Royalties,
+        PropertyType::SelfRental,
+        PropertyType::Other,
+    ];
+
+    /// The type code entered on line 1b.
+    pub fn code(self) -> u8 {
+        match self {
+            PropertyType::SingleFamily => 1,
+            PropertyType::MultiFamily => 2,
+            PropertyType::VacationShortTerm => 3,
+            PropertyType::Commercial => 4,
+            PropertyType::Land => 5,
+            PropertyType::Royalties => 6,
+            PropertyType::SelfRental => 7,
+            PropertyType::Other => 8,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.code() == code)
+    }
+}
+
+/// Expenses of one property (Schedule E, lines 5–19).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RentalExpenses {
+    /// Line 5.
+    pub advertising: Usd,
+    /// Line 6.
+    pub auto_and_travel: Usd,
+    /// Line 7.
+    pub cleaning_and_maintenance: Usd,
+    /// Line 8.
+    pub commissions: Usd,
+    /// Line 9.
+    pub insurance: Usd,
+    /// Line 10.
+    pub legal_and_professional_fees: Usd,
+    /// Line 11.
+    pub management_fees: Usd,
+    /// Line 12.
+    pub mortgage_interest: Usd,
+    /// Line 13.
+    pub other_interest: Usd,
+    /// Line 14.
+    pub repairs: Usd,
+    /// Line 15.
+    pub supplies: Usd,
+    /// Line 16.
+    pub taxes: Usd,
+    /// Line 17.
+    pub utilities: Usd,
+    /// Line 18, from Form 4562, other than depreciation of the property's
+    /// [`assets`](RentalProperty::assets).
+    pub depreciation: Usd,
+    /// Line 19.
+    pub other: Usd,
+}
+
+impl RentalExpenses {
+    /// Total expenses (line 20).
+    pub fn total(&self) -> Usd {
+        self.advertising
+            + self.auto_and_travel
+            + self.cleaning_and_maintenance
+            + self.commissions
+            + self.insurance
+            + self.legal_and_professional_fees
+            + self.management_fees
+            + self.mortgage_interest
+            + self.other_interest
+            + self.repairs
+            + self.supplies
+            + self.taxes
+            + self.utilities
+            + self.depreciation
+            + self.other
+    }
+}
+
+/// One rental real estate or royalty property (Schedule E, Part I).
+///
+/// Personal use of a dwelling unit (IRC §280A) is not modeled; expenses
+/// should already be limited to the rental portion.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RentalProperty {
+    /// Line 1a.
+    pub address: String,
+    /// Line 1b.
+    pub property_type: PropertyType,
+    /// Line 2, fair rental days.
+    pub fair_rental_days: u32,
+    /// Line 2, personal use days.
+    pub personal_use_days: u32,
+    /// Line 3.
+    pub rents_received: Usd,
+    /// Line 4.
+    pub royalties_received: Usd,
+    pub expenses: RentalExpenses,
+    /// `true` if the owner actively participated (IRC §469(i)(6)), which
+    /// qualifies a rental loss for the special allowance.
+    pub active_participation: bool,
+    /// Amount at risk available for this year's loss, from
+    /// [`AtRiskAmount::apply`](crate::at_risk::AtRiskAmount::apply); `None`
+    /// if the loss is not limited.
+    pub at_risk: Option<Usd>,
+    /// Passive losses disallowed in prior years, from
+    /// [`SuspendedLosses`](crate::passive_loss::SuspendedLosses) (Form 8582,
+    /// Worksheet 1, column (c)).
+    pub prior_year_unallowed: Usd,
+    /// `true` if the entire interest was disposed of this year in a fully
+    /// taxable transaction to an unrelated party, which frees current and
+    /// prior-year losses from the passive activity rules (IRC §469(g)).
+    pub disposed: bool,
+    /// Buildings and improvements whose depreciation is added to line 18.
+    pub assets: Vec<DepreciableAsset>,
+}
+
+impl RentalProperty {
+    /// Depreciation for `year` (line 18).
+    pub fn depreciation(&self, year: i32) -> Usd {
+        self.expenses.depreciation + depreciation::total_depreciation(&self.assets, year)
+    }
+
+    /// Depreciation of the property's assets through the end of `year`,
+    /// the most gain a sale can treat as unrecaptured section 1250 gain.
+    pub fn accumulated_depreciation(&self, year: i32) -> Usd {
+        self.assets
+            .iter()
+            .map(|a| a.accumulated_depreciation(year))
+            .sum()
+    }
+
+    /// Income or loss before the passive activity loss limitation for
+    /// `year` (line 21).
+    pub fn income_or_loss(&self, year: i32) -> Usd {
+        self.rents_received + self.royalties_received
+            - self.expenses.total()
+            - depreciation::total_depreciation(&self.assets, year)
+    }
+
+    /// Royalties are not a passive activity; every other property type is
+    /// a rental activity, until it is disposed of.
+    fn is_passive(&self) -> bool {
+        self.property_type != PropertyType::Royalties && !self.disposed
+    }
+}
+
+/// Input to [`schedule_e_part_1`] besides the properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RentalParams {
+    pub filing_status: FilingStatus,
+    /// `true` if filing separately and the taxpayer lived with their spouse
+    /// at any time during the year, which eliminates the special allowance.
+    pub mfs_lived_with_spouse: bool,
+    /// AGI figured without passive activity losses, taxable Social
+    /// Security, and the IRA deduction (Form 8582, line 6).
+    pub modified_agi: Usd,
+    /// Calendar year of the return, for depreciation.
+    pub tax_year: i32,
+}
+
+/// One property's result.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PropertyResult {
+    /// Line 21.
+    pub income_or_loss: Usd,
+    /// Deductible loss after the passive activity loss limitation, as a
+    /// positive amount (line 22).
+    pub allowed_loss: Usd,
+    /// Loss in excess of the amount at risk, carried forward as a positive
+    /// amount.
+    pub at_risk_suspended: Usd,
+    /// Loss disallowed by the passive activity rules, including prior-year
+    /// unallowed losses still not allowed, carried forward as a positive
+    /// amount.
+    pub suspended_loss: Usd,
+}
+
+/// Results of Schedule E, Part I.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleEPart1 {
+    /// One entry per property, in input order.
+    pub properties: Vec<PropertyResult>,
+    /// Special allowance after the modified AGI phase-out (Form 8582,
+    /// line 9).
+    pub special_allowance: Usd,
+    /// Line 24.
+    pub income: Usd,
+    /// Line 25, as a positive amount.
+    pub losses: Usd,
+}
+
+impl ScheduleEPart1 {
+    /// Rental real estate and royalty income or loss carried to line 26.
+    pub fn total(&self) -> Usd {
+        self.income - self.losses
+    }
+}
+
+/// Special allowance after the phase-out (Form 8582, Part II).
+pub fn special_allowance(params: &RentalParams) -> Usd {
+    let (allowance, phaseout_start) = match params.filing_status {
+        FilingStatus::MarriedFilingSeparately if params.mfs_lived_with_spouse => {
+            return Usd::ZERO;
+        }
+        FilingStatus::MarriedFilingSeparately => (
+            SPECIAL_ALLOWANCE.mul_div(1, 2),
+            SPECIAL_ALLOWANCE_PHASEOUT_START.mul_div(1, 2),
+        ),
+        _ => (SPECIAL_ALLOWANCE, SPECIAL_ALLOWANCE_PHASEOUT_START),
+    };
+    let reduction = (params.modified_agi - phaseout_start)
+        .max(Usd::ZERO)
+        .mul_div(1, 2);
+    (allowance - reduction).max(Usd::ZERO)
+}
+
+/// Computes Schedule E, Part I, limiting rental losses under the at-risk
+/// and passive activity rules.
+///
+/// Each property's loss is first limited to its amount at risk. Rental
+/// losses are then allowed against rental income from other properties,
+/// then against the special allowance for properties with active
+/// participation. Each property's allowed loss is its pro rata share
+/// (Form 8582, Worksheets 4 and 5). Prior-year unallowed losses join the
+/// year's passive loss. Royalty losses, and all losses of a property
+/// disposed of this year, are not passive.
+///
+/// Passive income from other activities is not considered.
+pub fn schedule_e_part_1(properties: &[RentalProperty], params: &RentalParams) -> ScheduleEPart1 {
+    let results: Vec<Usd> = properties
+        .iter()
+        .map(|p| p.income_or_loss(params.tax_year))
+        .collect();
+    let gross_loss = |i: usize| (Usd::ZERO - results[i]).max(Usd::ZERO);
+    let loss = |i: usize| {
+        properties[i]
+            .at_risk
+            .map_or(gross_loss(i), |a| gross_loss(i).min(a.max(Usd::ZERO)))
+    };
+    let total_loss = |i: usize| loss(i) + properties[i].prior_year_unallowed;
+    let passive_loss = |i: usize| {
+        if properties[i].is_passive() {
+            total_loss(i)
+        } else {
+            Usd::ZERO
+        }
+    };
+
+    let passive_income: Usd = properties
+        .iter()
+        .zip(&results)
+        .filter(|(p, r)| p.is_passive() && **r > Usd::ZERO)
+        .map(|(_, r)| *r)
+        .sum();
+    let total_passive_loss: Usd = (0..properties.len()).map(passive_loss).sum();
+
+    // Losses allowed against passive income, shared by every loss property.
+    let against_income = total_passive_loss.min(passive_income);
+    let mut allowed: Vec<Usd> = (0..properties.len())
+        .map(|i| share(against_income, passive_loss(i), total_passive_loss))
+        .collect();
+
+    // The special allowance, shared by active participation properties.
+    let allowance = special_allowance(params);
+    let remaining = |i: usize, allowed: &[Usd]| {
+        if properties[i].active_participation {
+            passive_loss(i) - allowed[i]
+        } else {
+            Usd::ZERO
+        }
+    };
+    let total_remaining: Usd = (0..properties.len()).map(|i| remaining(i, &allowed)).sum();
+    let from_allowance = total_remaining.min(allowance);
+    let extra: Vec<Usd> = (0..properties.len())
+        .map(|i| share(from_allowance, remaining(i, &allowed), total_remaining))
+        .collect();
+    for (a, e) in allowed.iter_mut().zip(extra) {
+        *a += e;
+    }
+
+    let properties: Vec<PropertyResult> = (0..properties.len())
+        .map(|i| {
+            let allowed_loss = if properties[i].is_passive() {
+                allowed[i]
+            } else {
+                total_loss(i)
+            };
+            PropertyResult {
+                income_or_loss: results[i],
+                allowed_loss,
+                at_risk_suspended: gross_loss(i) - loss(i),
+                suspended_loss: total_loss(i) - allowed_loss,
+            }
+        })
+        .collect();
+    ScheduleEPart1 {
+        income: results.iter().map(|r| (*r).max(Usd::ZERO)).sum(),
+        losses: properties.iter().map(|p| p.allowed_loss).sum(),
+        properties,
+        special_allowance: allowance,
+    }
+}
+
+/// `part`'s pro rata share of `amount`.
+fn share(amount: Usd, part: Usd, whole: Usd) -> Usd {
+    if whole > Usd::ZERO {
+        amount.mul_div(part.cents(), whole.cents())
+    } else {
+        Usd::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(rents: i64, expenses: i64, active: bool) -> RentalProperty {
+        RentalProperty {
+            rents_received: Usd::from_dollars(rents),
+            expenses: RentalExpenses {
+                other: Usd::from_dollars(expenses),
+                ..Default::default()
+            },
+            active_participation: active,
+            ..Default::default()
+        }
+    }
+
+    fn params(magi: i64) -> RentalParams {
+        RentalParams {
+            filing_status: FilingStatus::Single,
+            mfs_lived_with_spouse: false,
+            modified_agi: Usd::from_dollars(magi),
+            tax_year: 2025,
+        }
+    }
+
+    #[test]
+    fn asset_depreciation_on_line_18() {
+        let property = RentalProperty {
+            rents_received: Usd::from_dollars(24_000),
+            expenses: RentalExpenses {
+                taxes: Usd::from_dollars(4_000),
+                ..Default::default()
+            },
+            assets: vec![DepreciableAsset {
+                description: "House".to_string(),
+                placed_in_service: crate::Date::new(2018, 1, 1).unwrap(),
+                cost: Usd::from_dollars(300_000),
+                land: Usd::from_dollars(25_000),
+                class: depreciation::RecoveryClass::ResidentialRental,
+                disposed: None,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(property.depreciation(2025), Usd::from_dollars(10_000));
+        assert_eq!(property.income_or_loss(2025), Usd::from_dollars(10_000));
+        let part1 = schedule_e_part_1(&[property], &params(60_000));
+        assert_eq!(part1.total(), Usd::from_dollars(10_000));
+    }
+
+    #[test]
+    fn allowance_phases_out() {
+        assert_eq!(special_allowance(&params(90_000)), SPECIAL_ALLOWANCE);
+        assert_eq!(
+            special_allowance(&params(120_000)),
+            Usd::from_dollars(15_000)
+        );
+        assert_eq!(special_allowance(&params(150_000)), Usd::ZERO);
+        let mfs = RentalParams {
+            filing_status: FilingStatus::MarriedFilingSeparately,
+            ..params(60_000)
+        };
+        assert_eq!(special_allowance(&mfs), Usd::from_dollars(7_500));
+        let together = RentalParams {
+            mfs_lived_with_spouse: true,
+            ..mfs
+        };
+        assert_eq!(special_allowance(&together), Usd::ZERO);
+    }
+
+    #[test]
+    fn loss_within_allowance_fully_allowed() {
+        let part1 = schedule_e_part_1(&[property(12_000, 20_000, true)], &params(80_000));
+        assert_eq!(part1.properties[0].allowed_loss, Usd::from_dollars(8_000));
+        assert_eq!(part1.total(), Usd::from_dollars(-8_000));
+    }
+
+    #[test]
+    fn losses_limited_and_shared_pro_rata() {
+        // 10,000 of income absorbs 10,000 of the 40,000 in losses; the
+        // 15,000 allowance at 120,000 MAGI goes only to the active property.
+        let properties = [
+            property(30_000, 20_000, true),
+            property(10_000, 30_000, true),
+            property(10_000, 30_000, false),
+        ];
+        let part1 = schedule_e_part_1(&properties, &params(120_000));
+        assert_eq!(part1.special_allowance, Usd::from_dollars(15_000));
+        let p = &part1.properties;
+        assert_eq!(p[0].allowed_loss, Usd::ZERO);
+        assert_eq!(p[1].allowed_loss, Usd::from_dollars(20_000));
+        assert_eq!(p[2].allowed_loss, Usd::from_dollars(5_000));
+        assert_eq!(p[2].suspended_loss, Usd::from_dollars(15_000));
+        assert_eq!(part1.total(), Usd::from_dollars(-15_000));
+    }
+
+    #[test]
+    fn at_risk_limited_before_allowance() {
+        let limited = RentalProperty {
+            at_risk: Some(Usd::from_dollars(6_000)),
+            ..property(0, 10_000, true)
+        };
+        let part1 = schedule_e_part_1(&[limited], &params(80_000));
+        let p = part1.properties[0];
+        assert_eq!(p.at_risk_suspended, Usd::from_dollars(4_000));
+        assert_eq!(p.allowed_loss, Usd::from_dollars(6_000));
+        assert_eq!(p.suspended_loss, Usd::ZERO);
+    }
+
+    #[test]
+    fn royalty_loss_not_limited() {
+        let royalty = RentalProperty {
+            property_type: PropertyType::Royalties,
+            ..property(1_000, 4_000, false)
+        };
+        let part1 = schedule_e_part_1(&[royalty], &params(200_000));
+        assert_eq!(part1.total(), Usd::from_dollars(-3_000));
+    }
+}
@@ -0,0 +1,427 @@
+use std::fmt::Write;
+
+use crate::combined::CombinedReturn;
+use crate::ledger::Key;
+use crate::rules::TaxYearRules;
+use crate::spine::ReturnInput;
+use crate::state::StateKey;
+use crate::state::local::LocalKey;
+use crate::{Rate, Usd};
+
+/// Income lines listed as sources, in form order. Wages are not in the
+/// ledger and come first, from the input.
+const INCOME_SOURCES: [Key; 9] = [
+    Key::TaxableInterest,
+    Key::OrdinaryDividends,
+    Key::TaxableSocialSecurity,
+    Key::CapitalGainOrLoss,
+    Key::SupplementalIncome,
+    Key::TaxableHsaDistributions,
+    Key::TaxableScholarships,
+    Key::ChildInvestmentIncome,
+    Key::NolDeduction,
+];
+
+/// One line of a report section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportRow {
+    pub label: String,
+    pub amount: Usd,
+    /// Printed in bold as the section's result.
+    pub total: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportSection {
+    pub heading: String,
+    pub rows: Vec<ReportRow>,
+    /// A sentence printed below the rows.
+    pub note: Option<String>,
+}
+
+/// A plain-language summary of a computed return for the client: income
+/// sources, the deduction taken, credits, the effective rate, and the
+/// refund or amount owed for each jurisdiction.
+///
+/// Amounts are rounded to whole dollars when rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReturnReport {
+    pub title: String,
+    pub sections: Vec<ReportSection>,
+}
+
+impl ReturnReport {
+    /// Summarizes `combined`, which was computed from `input`.
+    ///
+    /// Lines that are zero are left out, except totals. The deduction is
+    /// reported as itemized when it exceeds the standard deduction.
+    pub fn build(rules: &dyn TaxYearRules, input: &ReturnInput, combined: &CombinedReturn) -> Self {
+        let federal = &combined.federal;
+        let line = |key: Key| federal.get(&key).copied().unwrap_or(Usd::ZERO);
+        let mut sections = Vec::new();
+
+        let mut income = Section::new("Income");
+        income.row(
+            "Wages, salaries, and tips",
+            input.w2_wages + input.unreported_tips.total(),
+        );
+        for key in INCOME_SOURCES {
+            let amount = line(key);
+            if key == Key::NolDeduction {
+                income.row(key.label(), Usd::ZERO - amount);
+            } else {
+                income.row(key.label(), amount);
+            }
+        }
+        income.total(Key::TotalIncome.label(), line(Key::TotalIncome));
+        income.row(Key::Adjustments.label(), Usd::ZERO - line(Key::Adjustments));
+        income.total(Key::AGI.label(), line(Key::AGI));
+        sections.push(income.0);
+
+        let mut deductions = Section::new("Deductions");
+        let standard = input.standard_deduction(rules);
+        let itemized = line(Key::Deductions) > standard;
+        deductions.row(
+            if itemized {
+                "Itemized deductions"
+            } else {
+                "Standard deduction"
+            },
+            line(Key::Deductions),
+        );
+        deductions.row(Key::QbiDeduction.label(), line(Key::QbiDeduction));
+        deductions.total(Key::TaxableIncome.label(), line(Key::TaxableIncome));
+        if itemized {
+            deductions.0.note = Some(format!(
+                "Itemizing saves {} of deductions over the {} standard deduction.",
+                money(line(Key::Deductions) - standard),
+                money(standard)
+            ));
+        }
+        sections.push(deductions.0);
+
+        let mut tax = Section::new("Tax and credits");
+        tax.row(
+            "Income tax",
+            line(Key::RegularTax) + line(Key::ChildInvestmentTax),
+        );
+        tax.row(Key::AdditionalTax.label(), line(Key::AdditionalTax));
+        tax.row(
+            Key::NonRefundableCredits.label(),
+            Usd::ZERO - line(Key::NonRefundableCredits),
+        );
+        tax.row(
+            Key::NetPremiumTaxCredit.label(),
+            Usd::ZERO - line(Key::NetPremiumTaxCredit),
+        );
+        tax.total(Key::TotalTax.label(), line(Key::TotalTax));
+        tax.0.note = Some(format!(
+            "Effective federal rate: {} of adjusted gross income.",
+            effective_rate(line(Key::TotalTax), line(Key::AGI))
+        ));
+        sections.push(tax.0);
+
+        let mut result = Section::new("Federal refund or amount owed");
+        result.row(Key::Withholding.label(), line(Key::Withholding));
+        result.row(Key::EstimatedPayments.label(), line(Key::EstimatedPayments));
+        result.row(Key::ExtensionPayment.label(), line(Key::ExtensionPayment));
+        result.total(Key::TotalPayments.label(), line(Key::TotalPayments));
+        result.outcome(line(Key::Refund), line(Key::AmountOwed));
+        sections.push(result.0);
+
+        for (state, ledger) in &combined.states {
+            let line = |key: StateKey| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+            let mut section = Section::new(&format!("{state} return"));
+            section.row(StateKey::StateAgi.label(), line(StateKey::StateAgi));
+            section.row(StateKey::Deductions.label(), line(StateKey::Deductions));
+            section.row(
+                StateKey::TaxableIncome.label(),
+                line(StateKey::TaxableIncome),
+            );
+            section.row(
+                StateKey::NonRefundableCredits.label(),
+                Usd::ZERO - line(StateKey::NonRefundableCredits),
+            );
+            section.total(StateKey::TotalTax.label(), line(StateKey::TotalTax));
+            section.row(
+                StateKey::TotalPayments.label(),
+                line(StateKey::TotalPayments),
+            );
+            section.outcome(line(StateKey::Refund), line(StateKey::AmountOwed));
+            sections.push(section.0);
+        }
+        for (name, ledger) in &combined.locals {
+            let line = |key: LocalKey| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+            let mut section = Section::new(&format!("{name} return"));
+            section.row(LocalKey::Income.label(), line(LocalKey::Income));
+            section.total(LocalKey::TotalTax.label(), line(LocalKey::TotalTax));
+            section.row(
+                LocalKey::TotalPayments.label(),
+                line(LocalKey::TotalPayments),
+            );
+            section.outcome(line(LocalKey::Refund), line(LocalKey::AmountOwed));
+            sections.push(section.0);
+        }
+        if !combined.states.is_empty() || !combined.locals.is_empty() {
+            let summary = combined.summary();
+            let mut all = Section::new("All returns");
+            all.total("Total tax", summary.total_tax);
+            all.row("Total payments", summary.total_payments);
+            all.row("Refunds", summary.total_refund);
+            all.row("Amounts owed", summary.total_owed);
+            all.0.note = Some(if summary.net() >= Usd::ZERO {
+                format!("Net refund across all returns: {}.", money(summary.net()))
+            } else {
+                format!(
+                    "Net amount owed across all returns: {}.",
+                    money(Usd::ZERO - summary.net())
+                )
+            });
+            sections.push(all.0);
+        }
+
+        ReturnReport {
+            title: format!("{} Tax Return Summary", rules.calendar_year()),
+            sections,
+        }
+    }
+
+    /// The report as Markdown, one table per section.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {}\n", self.title);
+        for section in &self.sections {
+            write!(
+                out,
+                "\n## {}\n\n| | Amount |\n|---|---:|\n",
+                section.heading
+            )
+            .unwrap();
+            for row in &section.rows {
+                if row.total {
+                    writeln!(out, "| **{}** | **{}** |", row.label, money(row.amount)).unwrap();
+                } else {
+                    writeln!(out, "| {} | {} |", row.label, money(row.amount)).unwrap();
+                }
+            }
+            if let Some(note) = &section.note {
+                write!(out, "\n{note}\n").unwrap();
+            }
+        }
+        out
+    }
+
+    /// The report as an HTML fragment, for embedding in a page.
+    pub fn to_html(&self) -> String {
+        let mut out = format!(
+            "<article class=\"return-summary\">\n<h1>{}</h1>\n",
+            escape(&self.title)
+        );
+        for section in &self.sections {
+            write!(
+                out,
+                "<section>\n<h2>{}</h2>\n<table>\n",
+                escape(&section.heading)
+            )
+            .unwrap();
+            for row in &section.rows {
+                let (open, close) = if row.total {
+                    ("<tr class=\"total\">", "</tr>")
+                } else {
+                    ("<tr>", "</tr>")
+                };
+                writeln!(
+                    out,
+                    "{open}<th scope=\"row\">{}</th><td>{}</td>{close}",
+                    escape(&row.label),
+                    money(row.amount)
+                )
+                .unwrap();
+            }
+            out.push_str("</table>\n");
+            if let Some(note) = &section.note {
+                writeln!(out, "<p>{}</p>", escape(note)).unwrap();
+            }
+            out.push_str("</section>\n");
+        }
+        out.push_str("</article>\n");
+        out
+    }
+}
+
+/// Builds a section, skipping zero rows.
+struct Section(ReportSection);
+
+impl Section {
+    fn new(heading: &str) -> Self {
+        Section(ReportSection {
+            heading: heading.to_string(),
+            rows: Vec::new(),
+            note: None,
+        })
+    }
+
+    fn row(&mut self, label: &str, amount: Usd) {
+        if amount.irs_round() != Usd::ZERO {
+            self.push(label, amount, false);
+        }
+    }
+
+    fn total(&mut self, label: &str, amount: Usd) {
+        self.push(label, amount, true);
+    }
+
+    /// The refund, or the amount owed when there is no refund.
+    fn outcome(&mut self, refund: Usd, owed: Usd) {
+        if owed > Usd::ZERO {
+            self.total("Amount owed", owed);
+        } else {
+            self.total("Refund", refund);
+        }
+    }
+
+    fn push(&mut self, label: &str, amount: Usd, total: bool) {
+        self.0.rows.push(ReportRow {
+            label: label.to_string(),
+            amount,
+            total,
+        });
+    }
+}
+
+/// Whole dollars with thousands separators, such as `-$1,235`.
+fn money(amount: Usd) -> String {
+    let dollars = amount.irs_round().cents() / 100;
+    let digits = dollars.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let sign = if dollars < 0 { "-" } else { "" };
+    format!("{sign}${grouped}")
+}
+
+/// Total tax as a share of AGI, or zero without AGI.
+fn effective_rate(total_tax: Usd, agi: Usd) -> Rate {
+    if agi > Usd::ZERO {
+        Rate::from_bps(total_tax.max(Usd::ZERO).cents() * 10_000 / agi.cents())
+    } else {
+        Rate::ZERO
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combined::StateFiling;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+    use crate::state::State;
+    use crate::state::ca::California2025;
+    use crate::state::tests::state_input;
+
+    fn report(input: &ReturnInput, states: &[StateFiling<'_>]) -> ReturnReport {
+        let combined = CombinedReturn::compute(&Rules2025, input, states).unwrap();
+        ReturnReport::build(&Rules2025, input, &combined)
+    }
+
+    #[test]
+    fn markdown_summarizes_a_wage_earner() {
+        let input = input(60_000, 9_000);
+        let report = report(&input, &[]);
+        let markdown = report.to_markdown();
+        assert!(
+            markdown.starts_with("# 2025 Tax Return Summary\n"),
+            "{markdown}"
+        );
+        assert!(
+            markdown.contains("| Wages, salaries, and tips | $60,000 |"),
+            "{markdown}"
+        );
+        assert!(
+            markdown.contains("| Standard deduction | $15,750 |"),
+            "{markdown}"
+        );
+        assert!(
+            markdown.contains("| **Taxable income** | **$44,250** |"),
+            "{markdown}"
+        );
+        assert!(markdown.contains("| **Refund** |"), "{markdown}");
+        assert!(markdown.contains("Effective federal rate: "), "{markdown}");
+        assert!(!markdown.contains("Taxable interest"), "{markdown}");
+        assert!(!markdown.contains("All returns"), "{markdown}");
+    }
+
+    #[test]
+    fn itemized_deduction_and_state_return() {
+        let mut input = input(60_000, 6_000);
+        input.itemized_deductions.mortgage_interest = Usd::from_dollars(14_500);
+        let ca = state_input(State::CA, 2_000);
+        let report = report(
+            &input,
+            &[StateFiling {
+                rules: &California2025,
+                input: &ca,
+            }],
+        );
+        let headings = report
+            .sections
+            .iter()
+            .map(|s| s.heading.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            headings,
+            [
+                "Income",
+                "Deductions",
+                "Tax and credits",
+                "Federal refund or amount owed",
+                "CA return",
+                "All returns"
+            ]
+        );
+        assert_eq!(report.sections[1].rows[0].label, "Itemized deductions");
+        assert_eq!(
+            report.sections[1].note.as_deref(),
+            Some("Itemizing saves $750 of deductions over the $15,750 standard deduction.")
+        );
+        let ca = &report.sections[4];
+        assert_eq!(ca.rows.last().unwrap().label, "Refund");
+        assert_eq!(ca.rows.last().unwrap().amount, Usd::from_dollars(360));
+    }
+
+    #[test]
+    fn html_escapes_and_marks_totals() {
+        let report = ReturnReport {
+            title: "Summary".to_string(),
+            sections: vec![ReportSection {
+                heading: "Tax & credits".to_string(),
+                rows: vec![ReportRow {
+                    label: "Total tax".to_string(),
+                    amount: Usd::from_cents(123_456_789),
+                    total: true,
+                }],
+                note: None,
+            }],
+        };
+        let html = report.to_html();
+        assert!(html.contains("<h2>Tax &amp; credits</h2>"), "{html}");
+        assert!(
+            html.contains(
+                "<tr class=\"total\"><th scope=\"row\">Total tax</th><td>$1,234,568</td></tr>"
+            ),
+            "{html}"
+        );
+        assert_eq!(money(Usd::from_dollars(-999)), "-$999");
+        assert_eq!(money(Usd::ZERO), "$0");
+    }
+}
@@ -0,0 +1,179 @@
+use crate::error::Error;
+use crate::hsa::{self, HsaCoverage};
+use crate::ira::{self, PlanCoverage};
+use crate::rules::TaxYearRules;
+use crate::spine::{self, Key, ReturnInput};
+use crate::{Rate, Usd};
+
+/// An account that reduces income tax on contributions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetirementAccount {
+    /// Elective deferrals to a traditional 401(k), 403(b), or governmental
+    /// 457(b) plan, which reduce wages.
+    Traditional401k,
+    /// HSA contributions, deducted on Schedule 1, line 13.
+    Hsa,
+    /// Traditional IRA contributions, deducted on Schedule 1, line 20.
+    TraditionalIra,
+}
+
+/// Contributions already made for the year and what limits them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetirementSituation {
+    /// Age at the end of the year.
+    pub age: u32,
+    /// Elective deferrals already made (Form W-2, box 12, codes D, E,
+    /// and G).
+    pub elective_deferrals: Usd,
+    /// High-deductible health plan coverage for the full year, or `None`
+    /// if not eligible to contribute to an HSA.
+    pub hsa_coverage: Option<HsaCoverage>,
+    /// HSA contributions already made, including the employer's.
+    pub hsa_contributions: Usd,
+    /// Traditional and Roth IRA contributions already made.
+    pub ira_contributions: Usd,
+    pub plan_coverage: PlanCoverage,
+}
+
+/// Tax saved by filling one account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContributionOption {
+    pub account: RetirementAccount,
+    /// Additional contributions allowed, or for an IRA, additional
+    /// deductible contributions.
+    pub room: Usd,
+    /// Reduction in total tax from contributing all of `room`.
+    pub tax_saved: Usd,
+    /// `tax_saved` per dollar contributed.
+    pub saved_per_dollar: Rate,
+}
+
+/// Ranks the accounts with room left by federal income tax saved per
+/// dollar, best first.
+///
+/// Each account is evaluated alone by recomputing the return with its
+/// room filled, so interactions between accounts, such as a 401(k)
+/// deferral lowering the modified AGI for the IRA phase-out, are not
+/// captured. Payroll taxes saved by deferrals made through payroll are not
+/// counted.
+pub fn rank_contributions(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    situation: &RetirementSituation,
+) -> Result<Vec<ContributionOption>, Error> {
+    let base = spine::compute_spine(rules, input)?;
+    let total_tax = |ledger: &spine::Ledger| ledger.get(&Key::TotalTax).copied();
+
+    let deferral_room = (rules.elective_deferral_limit()
+        + rules.elective_deferral_catch_up(situation.age)
+        - situation.elective_deferrals)
+        .min(input.w2_wages);
+    let hsa_room = situation.hsa_coverage.map_or(Usd::ZERO, |coverage| {
+        hsa::contribution_limit(rules, coverage, situation.age >= 55) - situation.hsa_contributions
+    });
+    let ira_room = {
+        let limit = ira::contribution_limit(rules, situation.age >= 50, input.w2_wages);
+        let modified_agi =
+            base.get(&Key::AGI).copied().unwrap_or(Usd::ZERO) + input.adjustments.ira_deduction;
+        ira::deduction_limit(
+            rules,
+            input.filing_status,
+            situation.plan_coverage,
+            modified_agi,
+            limit,
+        )
+        .min(limit - situation.ira_contributions)
+    };
+
+    let mut options = Vec::new();
+    for (account, room) in [
+        (RetirementAccount::Traditional401k, deferral_room),
+        (RetirementAccount::Hsa, hsa_room),
+        (RetirementAccount::TraditionalIra, ira_room),
+    ] {
+        if room <= Usd::ZERO {
+            continue;
+        }
+        let mut changed = input.clone();
+        match account {
+            RetirementAccount::Traditional401k => changed.w2_wages -= room,
+            RetirementAccount::Hsa => changed.adjustments.hsa_deduction += room,
+            RetirementAccount::TraditionalIra => changed.adjustments.ira_deduction += room,
+        }
+        let ledger = spine::compute_spine(rules, &changed)?;
+        let tax_saved =
+            total_tax(&base).unwrap_or(Usd::ZERO) - total_tax(&ledger).unwrap_or(Usd::ZERO);
+        options.push(ContributionOption {
+            account,
+            room,
+            tax_saved,
+            saved_per_dollar: Rate::from_bps(tax_saved.cents() * 10_000 / room.cents()),
+        });
+    }
+    options.sort_by_key(|o| {
+        (
+            std::cmp::Reverse(o.saved_per_dollar),
+            std::cmp::Reverse(o.room),
+        )
+    });
+    Ok(options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn ranks_by_savings_per_dollar() {
+        let situation = RetirementSituation {
+            age: 40,
+            elective_deferrals: Usd::from_dollars(20_000),
+            hsa_coverage: Some(HsaCoverage::SelfOnly),
+            hsa_contributions: Usd::from_dollars(1_300),
+            plan_coverage: PlanCoverage::Covered,
+            ..Default::default()
+        };
+        // Taxable income of 150,000, well inside the 24% bracket; the IRA
+        // deduction is fully phased out.
+        let options = rank_contributions(&Rules2025, &input(165_750, 0), &situation).unwrap();
+        let summary: Vec<_> = options
+            .iter()
+            .map(|o| (o.account, o.room, o.tax_saved, o.saved_per_dollar))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    RetirementAccount::Traditional401k,
+                    Usd::from_dollars(3_500),
+                    Usd::from_dollars(840),
+                    Rate::from_percent(24)
+                ),
+                (
+                    RetirementAccount::Hsa,
+                    Usd::from_dollars(3_000),
+                    Usd::from_dollars(720),
+                    Rate::from_percent(24)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn ira_room_less_prior_contributions() {
+        let situation = RetirementSituation {
+            age: 55,
+            elective_deferrals: Usd::from_dollars(31_000),
+            ira_contributions: Usd::from_dollars(1_000),
+            ..Default::default()
+        };
+        let options = rank_contributions(&Rules2025, &input(60_000, 0), &situation).unwrap();
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].account, RetirementAccount::TraditionalIra);
+        // 8,000 with the catch-up, less 1,000 already contributed.
+        assert_eq!(options[0].room, Usd::from_dollars(7_000));
+        assert_eq!(options[0].saved_per_dollar, Rate::from_percent(12));
+    }
+}
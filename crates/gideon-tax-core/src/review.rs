@@ -0,0 +1,269 @@
+use std::fmt;
+
+use crate::diagnostics::{Diagnostic, Severity};
+use crate::spine::{Key, Ledger, ReturnInput};
+use crate::{Date, Usd};
+
+/// Effective rate (total tax / AGI) above which a return is flagged, in
+/// percent. Above the top bracket rate, something is likely double counted.
+const EFFECTIVE_RATE_FLAG_PERCENT: i64 = 37;
+
+/// Withholding as a multiple of total tax above which a return is flagged.
+const WITHHOLDING_MULTIPLE_FLAG: i64 = 3;
+
+/// Minimum wages for which having no withholding is flagged.
+const UNWITHHELD_WAGES_FLAG: Usd = Usd::from_dollars(20_000);
+
+/// Why an item is on the review checklist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ItemSource {
+    /// A diagnostic raised while preparing the return.
+    Diagnostic,
+    /// A choice the engine made on the filer's behalf.
+    Election,
+    /// A ratio between ledger amounts outside the usual range.
+    UnusualRatio,
+}
+
+/// A reviewer's sign-off on a checklist item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignOff {
+    pub reviewer: String,
+    pub date: Date,
+    pub note: Option<String>,
+}
+
+/// One item for a second preparer to confirm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChecklistItem {
+    pub source: ItemSource,
+    pub severity: Severity,
+    /// Stable code; a diagnostic's own code, or `"election.*"` /
+    /// `"ratio.*"`.
+    pub code: String,
+    pub description: String,
+    /// `None` until a reviewer signs off.
+    pub sign_off: Option<SignOff>,
+}
+
+/// Checklist for a second-preparer review of a computed return.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReviewChecklist {
+    /// Ordered by severity (errors first), then source.
+    pub items: Vec<ChecklistItem>,
+}
+
+impl ReviewChecklist {
+    /// Builds a checklist from open warnings and errors, the engine's
+    /// elections, and unusual ratios in `ledger`.
+    pub fn build(input: &ReturnInput, ledger: &Ledger, diagnostics: &[Diagnostic]) -> Self {
+        let mut items: Vec<ChecklistItem> = diagnostics
+            .iter()
+            .filter(|d| d.severity >= Severity::Warning)
+            .map(|d| item(ItemSource::Diagnostic, d.severity, d.code, &d.message))
+            .collect();
+        items.extend(elections(input, ledger));
+        items.extend(unusual_ratios(input, ledger));
+        items.sort_by_key(|i| (std::cmp::Reverse(i.severity), i.source as u8));
+        ReviewChecklist { items }
+    }
+
+    /// `true` once every item has been signed off.
+    pub fn is_complete(&self) -> bool {
+        self.items.iter().all(|i| i.sign_off.is_some())
+    }
+
+    /// Signs off the first open item with `code`. Returns `false` if there
+    /// is none.
+    pub fn sign_off(&mut self, code: &str, sign_off: SignOff) -> bool {
+        match self
+            .items
+            .iter_mut()
+            .find(|i| i.code == code && i.sign_off.is_none())
+        {
+            Some(i) => {
+                i.sign_off = Some(sign_off);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn item(source: ItemSource, severity: Severity, code: &str, description: &str) -> ChecklistItem {
+    ChecklistItem {
+        source,
+        severity,
+        code: code.to_string(),
+        description: description.to_string(),
+        sign_off: None,
+    }
+}
+
+fn elections(input: &ReturnInput, ledger: &Ledger) -> Vec<ChecklistItem> {
+    let mut out = Vec::new();
+    let election =
+        |code, description: &str| item(ItemSource::Election, Severity::Info, code, description);
+    if ledger.get(&Key::Deductions).is_some_and(|d| *d > Usd::ZERO) {
+        out.push(election(
+            "election.standard_deduction",
+            "Standard deduction taken; itemized deductions were not compared",
+        ));
+    }
+    if input
+        .students
+        .iter()
+        .any(|s| s.scholarships_elected_taxable > Usd::ZERO)
+    {
+        out.push(election(
+            "election.scholarship_included_in_income",
+            "Scholarships elected as taxable income to free expenses for an education credit",
+        ));
+    }
+    if input.effective_period().is_some_and(|p| p.annualizes()) {
+        out.push(election(
+            "election.short_period_annualized",
+            "Short-period income annualized for a change in accounting period",
+        ));
+    }
+    if input
+        .deceased
+        .as_ref()
+        .is_some_and(|d| d.medical_expense_election)
+    {
+        out.push(election(
+            "election.decedent_medical_expenses",
+            "Decedent's medical expenses paid after death deducted on the final return",
+        ));
+    }
+    let qbi_income: Usd = input
+        .passthroughs
+        .iter()
+        .map(|p| p.qualified_business_income)
+        .sum();
+    if qbi_income > Usd::ZERO && ledger.get(&Key::QbiDeduction) == Some(&Usd::ZERO) {
+        out.push(item(
+            ItemSource::Election,
+            Severity::Warning,
+            "election.qbi_deduction_not_taken",
+            "Qualified business income reported but no QBI deduction taken",
+        ));
+    }
+    out
+}
+
+fn unusual_ratios(input: &ReturnInput, ledger: &Ledger) -> Vec<ChecklistItem> {
+    let get = |key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let (agi, total_tax, withholding) = (get(Key::AGI), get(Key::TotalTax), get(Key::Withholding));
+    let mut out = Vec::new();
+    let ratio = |code, description: String| {
+        item(
+            ItemSource::UnusualRatio,
+            Severity::Warning,
+            code,
+            &description,
+        )
+    };
+    if agi > Usd::ZERO && total_tax * 100 > agi * EFFECTIVE_RATE_FLAG_PERCENT {
+        out.push(ratio(
+            "ratio.effective_rate",
+            format!("Total tax {total_tax} exceeds {EFFECTIVE_RATE_FLAG_PERCENT}% of AGI {agi}"),
+        ));
+    }
+    if total_tax > Usd::ZERO && withholding > total_tax * WITHHOLDING_MULTIPLE_FLAG {
+        out.push(ratio(
+            "ratio.withholding_to_tax",
+            format!(
+                "Withholding {withholding} is more than {WITHHOLDING_MULTIPLE_FLAG}× total tax {total_tax}"
+            ),
+        ));
+    }
+    if input.w2_wages >= UNWITHHELD_WAGES_FLAG && input.fed_withholding == Usd::ZERO {
+        out.push(ratio(
+            "ratio.wages_without_withholding",
+            format!(
+                "W-2 wages of {} with no federal withholding",
+                input.w2_wages
+            ),
+        ));
+    }
+    out
+}
+
+impl fmt::Display for ReviewChecklist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in &self.items {
+            let mark = if i.sign_off.is_some() { "x" } else { " " };
+            writeln!(f, "[{mark}] {} [{}]: {}", i.severity, i.code, i.description)?;
+            match &i.sign_off {
+                Some(s) => writeln!(f, "    Reviewed by {} on {}", s.reviewer, s.date)?,
+                None => writeln!(f, "    Reviewer: ________  Date: ________")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    fn codes(c: &ReviewChecklist) -> Vec<&str> {
+        c.items.iter().map(|i| i.code.as_str()).collect()
+    }
+
+    #[test]
+    fn diagnostics_and_elections_listed() {
+        let inp = input(60_000, 6_000);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let diags = [
+            Diagnostic::info("w2.note", "ignored"),
+            Diagnostic::error("w2.medicare_wages_gap", "gap"),
+        ];
+        let c = ReviewChecklist::build(&inp, &ledger, &diags);
+        assert_eq!(
+            codes(&c),
+            ["w2.medicare_wages_gap", "election.standard_deduction"]
+        );
+        assert!(!c.is_complete());
+    }
+
+    #[test]
+    fn unusual_ratios_flagged() {
+        let inp = input(60_000, 0);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let c = ReviewChecklist::build(&inp, &ledger, &[]);
+        assert!(codes(&c).contains(&"ratio.wages_without_withholding"));
+
+        let inp = input(30_000, 20_000);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let c = ReviewChecklist::build(&inp, &ledger, &[]);
+        assert!(codes(&c).contains(&"ratio.withholding_to_tax"));
+    }
+
+    #[test]
+    fn sign_off_completes_checklist() {
+        let inp = input(60_000, 6_000);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        let mut c = ReviewChecklist::build(&inp, &ledger, &[]);
+        let sign_off = SignOff {
+            reviewer: "A. Reviewer".to_string(),
+            date: Date::new(2026, 3, 1).unwrap(),
+            note: None,
+        };
+        assert!(c.sign_off("election.standard_deduction", sign_off.clone()));
+        assert!(!c.sign_off("election.standard_deduction", sign_off));
+        assert!(c.is_complete());
+        assert!(
+            c.to_string()
+                .contains("[x] info [election.standard_deduction]")
+        );
+    }
+}
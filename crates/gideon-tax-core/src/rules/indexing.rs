@@ -0,0 +1,194 @@
+use crate::Usd;
+use crate::rules::Bracket;
+
+/// Cost-of-living adjustment under IRC §1(f)(3): the chained CPI-U for
+/// the year before the tax year over the chained CPI-U for the
+/// provision's base year.
+///
+/// Each index is the average of the 12 months ending August 31 of its
+/// year (IRC §1(f)(4)), given in thousandths so published values like
+/// 147.579 can be entered exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CostOfLivingAdjustment {
+    pub index: i64,
+    pub base_index: i64,
+}
+
+impl CostOfLivingAdjustment {
+    /// Base-year `amount` adjusted and rounded as `rounding` requires.
+    pub fn apply(self, amount: Usd, rounding: Rounding) -> Usd {
+        let scaled = i128::from(amount.cents()) * i128::from(self.index);
+        let base = i128::from(self.base_index.max(1));
+        let multiple = i128::from(rounding.multiple().cents().max(1));
+        let steps = match rounding {
+            // Floor so adjustments below a whole multiple are dropped.
+            Rounding::Down(_) => scaled.div_euclid(base * multiple),
+            Rounding::Nearest(_) => (2 * scaled + base * multiple).div_euclid(2 * base * multiple),
+        };
+        Usd::from_cents((steps * multiple) as i64)
+    }
+}
+
+/// How an adjusted amount is rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// To the next lowest multiple.
+    Down(Usd),
+    /// To the nearest multiple, halves rounding up.
+    Nearest(Usd),
+}
+
+impl Rounding {
+    fn multiple(self) -> Usd {
+        match self {
+            Rounding::Down(m) | Rounding::Nearest(m) => m,
+        }
+    }
+}
+
+/// An inflation-indexed amount, named by the provision that indexes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Provision {
+    /// Bracket floors (IRC §1(f)(7)(A)). Married filing separately uses
+    /// half the multiple (IRC §1(f)(7)(B)).
+    OrdinaryBrackets { married_filing_separately: bool },
+    /// 15% and 20% capital gain thresholds (IRC §1(j)(5)(C)).
+    CapitalGainThresholds { married_filing_separately: bool },
+    /// Basic standard deduction (IRC §63(c)(4)).
+    StandardDeduction,
+    /// Additional standard deduction for age or blindness (IRC §63(c)(4)).
+    AdditionalStandardDeduction,
+    /// AMT exemption and phase-out threshold (IRC §55(d)(4)(B)).
+    AmtExemption,
+    /// IRA contribution limit (IRC §219(b)(5)(C)).
+    IraContributionLimit,
+    /// IRA deduction phase-out ranges (IRC §219(g)(8)).
+    IraDeductionPhaseout,
+    /// HSA contribution limits (IRC §223(g)).
+    HsaContributionLimit,
+}
+
+impl Provision {
+    /// The provision's rounding convention.
+    pub fn rounding(self) -> Rounding {
+        let down = |dollars| Rounding::Down(Usd::from_dollars(dollars));
+        match self {
+            Provision::OrdinaryBrackets {
+                married_filing_separately: true,
+            }
+            | Provision::CapitalGainThresholds {
+                married_filing_separately: true,
+            } => Rounding::Down(Usd::from_cents(1_250)),
+            Provision::OrdinaryBrackets { .. } | Provision::CapitalGainThresholds { .. } => {
+                down(25)
+            }
+            Provision::StandardDeduction
+            | Provision::AdditionalStandardDeduction
+            | Provision::HsaContributionLimit => down(50),
+            Provision::AmtExemption => down(100),
+            Provision::IraContributionLimit => down(500),
+            Provision::IraDeductionPhaseout => down(1_000),
+        }
+    }
+
+    /// Base-year `amount` indexed by `adjustment` under this provision.
+    pub fn index(self, amount: Usd, adjustment: CostOfLivingAdjustment) -> Usd {
+        adjustment.apply(amount, self.rounding())
+    }
+}
+
+/// Base-year bracket floors indexed by `adjustment`; rates are unchanged.
+pub fn index_brackets(
+    base: [Bracket; 7],
+    adjustment: CostOfLivingAdjustment,
+    married_filing_separately: bool,
+) -> [Bracket; 7] {
+    let provision = Provision::OrdinaryBrackets {
+        married_filing_separately,
+    };
+    base.map(|b| Bracket {
+        floor: provision.index(b.floor, adjustment),
+        rate: b.rate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use us_tax_brackets::FilingStatus;
+
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::rules::{BRACKET_RATES, TaxYearRules};
+
+    /// Not the published ratio: a factor of 1.25274 falls within the range
+    /// that reproduces the 2025 single amounts from their 2018 bases.
+    const TO_2025: CostOfLivingAdjustment = CostOfLivingAdjustment {
+        index: 125_274,
+        base_index: 100_000,
+    };
+
+    #[test]
+    fn reproduces_2025_single_brackets() {
+        let base_2018 = [0, 9_525, 38_700, 82_500, 157_500, 200_000, 500_000];
+        let base = std::array::from_fn(|i| Bracket {
+            floor: Usd::from_dollars(base_2018[i]),
+            rate: BRACKET_RATES[i],
+        });
+        assert_eq!(
+            index_brackets(base, TO_2025, false),
+            Rules2025.ordinary_brackets(FilingStatus::Single)
+        );
+    }
+
+    #[test]
+    fn rounding_by_provision() {
+        // 12,000 × 1.25274 = 15,032.88: down to 15,000 at $50.
+        assert_eq!(
+            Provision::StandardDeduction.index(Usd::from_dollars(12_000), TO_2025),
+            Usd::from_dollars(15_000)
+        );
+        // 38,600 × 1.25274 = 48,355.76: down to 48,350 at $25.
+        let cap_gains = Provision::CapitalGainThresholds {
+            married_filing_separately: false,
+        };
+        assert_eq!(
+            cap_gains.index(Usd::from_dollars(38_600), TO_2025),
+            Usd::from_dollars(48_350)
+        );
+        // 1,000 × 1.25274 = 1,252.74: 1,250 at $12.50, 1,200 at $100.
+        let mfs = Provision::OrdinaryBrackets {
+            married_filing_separately: true,
+        };
+        assert_eq!(
+            mfs.index(Usd::from_dollars(1_000), TO_2025),
+            Usd::from_dollars(1_250)
+        );
+        assert_eq!(
+            Provision::AmtExemption.index(Usd::from_dollars(1_000), TO_2025),
+            Usd::from_dollars(1_200)
+        );
+    }
+
+    #[test]
+    fn nearest_rounds_half_up() {
+        let half = CostOfLivingAdjustment {
+            index: 3,
+            base_index: 2,
+        };
+        let nearest = Rounding::Nearest(Usd::from_dollars(10));
+        // 15 × 1.5 = 22.50 → 20; 25 × 1.5 = 37.50 → 40.
+        assert_eq!(
+            half.apply(Usd::from_dollars(15), nearest),
+            Usd::from_dollars(20)
+        );
+        assert_eq!(
+            half.apply(Usd::from_dollars(25), nearest),
+            Usd::from_dollars(40)
+        );
+        assert_eq!(
+            half.apply(Usd::from_dollars(15), Rounding::Down(Usd::from_dollars(10))),
+            Usd::from_dollars(20)
+        );
+    }
+}
@@ -1,17 +1,44 @@
+pub mod indexing;
+pub mod overlay;
+pub mod sunset;
+pub mod y2022;
+pub mod y2023;
 pub mod y2025;
+pub mod y2026;
+
+use std::collections::HashMap;
 
 use us_tax_brackets::{FilingStatus, TaxYear};
 
-use crate::Usd;
+use crate::premium_tax_credit::{ApplicablePercentageBand, PovertyRegion};
 use crate::types::Filer;
+use crate::{Rate, Usd};
 
 /// Year-specific tax parameters consumed by [`crate::spine::compute_spine`].
 ///
 /// Each tax year gets its own implementation that supplies the IRS-published
 /// dollar amounts. The [`standard_deduction`](TaxYearRules::standard_deduction)
-/// algorithm is a provided method that combines them.
+/// and [`exemptions`](TaxYearRules::exemptions) algorithms are provided
+/// methods that combine them.
 pub trait TaxYearRules {
-    fn year(&self) -> TaxYear;
+    /// The calendar year the rules are for, which a
+    /// [`ReturnInput`](crate::spine::ReturnInput) must match.
+    fn calendar_year(&self) -> i32;
+
+    /// `true` if the amounts are projected from announced or expected
+    /// inflation adjustments rather than taken from final IRS guidance.
+    fn is_projection(&self) -> bool {
+        false
+    }
+
+    /// `true` if the spine taxes returns with [`rate_schedule_tax`] on the
+    /// [`ordinary_brackets`](TaxYearRules::ordinary_brackets) instead of the
+    /// year's Tax Table and Tax Computation Worksheet. By default this is
+    /// every projection and every year `us_tax_brackets` has no tables for
+    /// (see [`tax_year`]).
+    fn uses_rate_schedule(&self) -> bool {
+        self.is_projection() || tax_year(self.calendar_year()).is_none()
+    }
 
     /// Base standard deduction for Single or MFS filers.
     fn single_mfs_typical_standard_deduction(&self) -> Usd;
@@ -34,6 +61,175 @@ pub trait TaxYearRules {
     /// Minimum standard deduction for a dependent filer.
     fn dependent_minimum_deduction(&self) -> Usd;
 
+    /// Ordinary income tax brackets for `status`, lowest first
+    /// (IRC §1(j)(2)). When [`uses_rate_schedule`] is `true` the spine taxes
+    /// income on these with [`rate_schedule_tax`]; otherwise the tax comes
+    /// from the published tables in `us_tax_brackets`.
+    ///
+    /// [`uses_rate_schedule`]: TaxYearRules::uses_rate_schedule
+    fn ordinary_brackets(&self, status: FilingStatus) -> [Bracket; 7];
+
+    /// Taxable income at which the 15% and 20% rates on net capital gain
+    /// and qualified dividends begin (IRC §1(j)(5)(B)).
+    fn capital_gain_rate_thresholds(&self, status: FilingStatus) -> [Usd; 2];
+
+    /// Alternative minimum tax exemption amount (IRC §55(d)(1)).
+    fn amt_exemption(&self, status: FilingStatus) -> Usd;
+
+    /// Alternative minimum taxable income above which the AMT exemption
+    /// phases out (IRC §55(d)(2)).
+    fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd;
+
+    /// Share of alternative minimum taxable income above
+    /// [`amt_exemption_phaseout_start`](TaxYearRules::amt_exemption_phaseout_start)
+    /// that reduces the exemption (IRC §55(d)(3)).
+    fn amt_exemption_phaseout_rate(&self) -> Rate;
+
+    /// Taxable excess above which the 28% AMT rate applies
+    /// (IRC §55(b)(1)(A)).
+    fn amt_rate_threshold(&self, status: FilingStatus) -> Usd;
+
+    /// Child tax credit per qualifying child (IRC §24(h)(2)).
+    fn child_tax_credit_amount(&self) -> Usd;
+
+    /// Most of the child tax credit per qualifying child that is
+    /// refundable as the additional child tax credit (IRC §24(h)(5)).
+    fn additional_child_tax_credit_limit(&self) -> Usd;
+
+    /// Earned income credit amounts for `qualifying_children`, three or
+    /// more counted as three (IRC §32(b)).
+    fn eitc(&self, qualifying_children: u32) -> EitcParameters;
+
+    /// Investment income above which no earned income credit is allowed
+    /// (IRC §32(i)).
+    fn eitc_investment_income_limit(&self) -> Usd;
+
+    /// Maximum share of household income an employee's required contribution
+    /// for self-only employer coverage may be for the offer to be affordable
+    /// for premium tax credit purposes (IRC §36B(c)(2)(C)).
+    fn employer_coverage_affordability_rate(&self) -> Rate;
+
+    /// Taxable income at or below which the qualified business income
+    /// deduction is computed on Form 8995 without the W-2 wage and property
+    /// limits (IRC §199A(e)(2)).
+    fn qbi_threshold(&self, status: FilingStatus) -> Usd;
+
+    /// Width of the range above [`qbi_threshold`](TaxYearRules::qbi_threshold)
+    /// over which the W-2 wage and property limits and the exclusion of
+    /// specified service businesses phase in (IRC §199A(b)(3)(B),
+    /// (d)(3)(A)).
+    fn qbi_phase_in_range(&self, status: FilingStatus) -> Usd;
+
+    /// Limit on the itemized deduction for state and local taxes, or half
+    /// for married filing separately, or `None` if the deduction is not
+    /// limited (IRC §164(b)(6)).
+    fn salt_cap(&self) -> Option<Usd>;
+
+    /// Modified AGI above which the SALT cap is reduced by 30% of the
+    /// excess, or half for married filing separately, or `None` if the cap
+    /// is not reduced (IRC §164(b)(7)).
+    fn salt_phasedown_start(&self) -> Option<Usd>;
+
+    /// Amount the SALT cap is never reduced below, or half for married
+    /// filing separately. Defaults to the unindexed $10,000 of IRC
+    /// §164(b)(7)(B).
+    fn salt_cap_floor(&self) -> Usd {
+        Usd::from_dollars(10_000)
+    }
+
+    /// Annual limit on traditional and Roth IRA contributions combined
+    /// (IRC §219(b)(5)(A)).
+    fn ira_contribution_limit(&self) -> Usd;
+
+    /// Additional IRA contribution allowed at age 50 or older
+    /// (IRC §219(b)(5)(B)).
+    fn ira_catch_up_contribution(&self) -> Usd;
+
+    /// AGI at which the traditional IRA deduction begins to phase out for a
+    /// taxpayer covered by a workplace retirement plan, or, if
+    /// `spouse_covered_only`, for one whose spouse is covered
+    /// (IRC §219(g)(3)(B), (7)).
+    fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_covered_only: bool) -> Usd;
+
+    /// Limit on elective deferrals to 401(k), 403(b), and governmental 457(b)
+    /// plans (IRC §402(g)(1)(B)).
+    fn elective_deferral_limit(&self) -> Usd;
+
+    /// Additional elective deferrals allowed at `age` at the end of the
+    /// year (IRC §414(v)(2)(B)), including the higher amount at ages 60–63.
+    fn elective_deferral_catch_up(&self, age: u32) -> Usd;
+
+    /// Annual limit on HSA contributions for self-only or family
+    /// high-deductible coverage (IRC §223(b)(2)).
+    fn hsa_contribution_limit(&self, family: bool) -> Usd;
+
+    /// Standard mileage rate for business use of a vehicle, in tenths of a
+    /// cent per mile. Published rates such as 65.5 cents are not whole
+    /// cents, so the rate is applied to the miles before rounding.
+    fn business_standard_mileage_rate_mills(&self) -> i64;
+
+    /// Maximum wages and tips subject to Social Security tax
+    /// (IRC §3121(a)(1)).
+    fn social_security_wage_base(&self) -> Usd;
+
+    /// Standard deduction the year's Publication 15-T percentage method
+    /// tables are built on, which can differ from the year's own when the
+    /// law changes after the tables are published.
+    fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd;
+
+    /// Publication 15-T, Worksheet 1A, line 1g: subtracted from annual
+    /// wages when the Form W-4, Step 2, box is not checked.
+    fn withholding_wage_adjustment(&self, status: FilingStatus) -> Usd;
+
+    /// Federal poverty line for a family of `family_size` used for the
+    /// year's premium tax credit: the HHS guidelines published in the prior
+    /// year.
+    fn poverty_line(&self, region: PovertyRegion, family_size: u32) -> Usd;
+
+    /// Applicable figure (Form 8962, line 7) for household income at
+    /// `fpl_percent` of the poverty line (IRC §36B(b)(3)(A)).
+    fn ptc_applicable_figure(&self, fpl_percent: i64) -> Rate;
+
+    /// Limit on repaying excess advance premium tax credit payments, or
+    /// `None` if repayment is not limited at `fpl_percent` of the poverty
+    /// line (IRC §36B(f)(2)(B)).
+    fn ptc_repayment_limit(&self, status: FilingStatus, fpl_percent: i64) -> Option<Usd>;
+
+    /// Amount of each personal or dependent exemption.
+    ///
+    /// Zero for tax years in which the exemption is suspended (2018–2025
+    /// under TCJA), which is the default.
+    fn personal_exemption_amount(&self) -> Usd {
+        Usd::ZERO
+    }
+
+    /// Gross income at or above which a person cannot be a qualifying
+    /// relative (IRC §152(d)(1)(B)).
+    ///
+    /// This is the exemption amount, which §151(d)(5)(B) keeps indexing
+    /// for this test while the exemption itself is zero. The default is
+    /// [`personal_exemption_amount`](TaxYearRules::personal_exemption_amount),
+    /// for years with an exemption.
+    fn dependent_gross_income_limit(&self) -> Usd {
+        self.personal_exemption_amount()
+    }
+
+    /// AGI above which exemptions begin to phase out, or `None` if there is
+    /// no phase-out.
+    fn exemption_phaseout_threshold(&self, _status: FilingStatus) -> Option<Usd> {
+        None
+    }
+
+    /// Modified AGI above which the child tax credit phases out
+    /// (IRC §24(b)(2)). Defaults to the amounts set from 2018 on, which
+    /// are not indexed.
+    fn child_tax_credit_phaseout_start(&self, status: FilingStatus) -> Usd {
+        Usd::from_dollars(match status {
+            FilingStatus::MarriedFilingJointly => 400_000,
+            _ => 200_000,
+        })
+    }
+
     /// Base standard deduction before any age/blindness additions.
     fn typical_standard_deduction(&self, status: FilingStatus) -> Usd {
         use FilingStatus::*;
@@ -90,6 +286,229 @@ pub trait TaxYearRules {
             base + additional
         }
     }
+
+    /// Computes the deduction for exemptions.
+    ///
+    /// Multiplies the per-person amount by the number of exemptions, then
+    /// reduces it by 2% for each $2,500 ($1,250 if MFS), or part thereof, by
+    /// which AGI exceeds the phase-out threshold (IRC §151(d)(3)).
+    fn exemptions(&self, params: &ExemptionParams) -> Usd {
+        let full = self.personal_exemption_amount() * params.count;
+        if full == Usd::ZERO {
+            return Usd::ZERO;
+        }
+        let Some(threshold) = self.exemption_phaseout_threshold(params.filing_status) else {
+            return full;
+        };
+        if params.agi <= threshold {
+            return full;
+        }
+
+        let step = if params.filing_status == FilingStatus::MarriedFilingSeparately {
+            Usd::from_dollars(1_250)
+        } else {
+            Usd::from_dollars(2_500)
+        };
+        let excess = (params.agi - threshold).cents();
+        // Each step or fraction of a step removes 2%; 50 steps removes all.
+        let steps = (excess + step.cents() - 1) / step.cents();
+        let reduction_pct = (steps * 2).min(100);
+        full - full.mul_div(reduction_pct, 100)
+    }
+}
+
+/// A tax bracket: the rate on taxable income from `floor` up to the next
+/// bracket's floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bracket {
+    pub floor: Usd,
+    pub rate: Rate,
+}
+
+/// Applicable percentages for 2021–2025 (IRC §36B(b)(3)(A)(iii)).
+const APPLICABLE_PERCENTAGES: [ApplicablePercentageBand; 6] = [
+    ApplicablePercentageBand {
+        up_to: Some(150),
+        initial: Rate::ZERO,
+        final_: Rate::ZERO,
+    },
+    ApplicablePercentageBand {
+        up_to: Some(200),
+        initial: Rate::ZERO,
+        final_: Rate::from_percent(2),
+    },
+    ApplicablePercentageBand {
+        up_to: Some(250),
+        initial: Rate::from_percent(2),
+        final_: Rate::from_percent(4),
+    },
+    ApplicablePercentageBand {
+        up_to: Some(300),
+        initial: Rate::from_percent(4),
+        final_: Rate::from_percent(6),
+    },
+    ApplicablePercentageBand {
+        up_to: Some(400),
+        initial: Rate::from_percent(6),
+        final_: Rate::from_bps(850),
+    },
+    ApplicablePercentageBand {
+        up_to: None,
+        initial: Rate::from_bps(850),
+        final_: Rate::from_bps(850),
+    },
+];
+
+/// Rates of the seven brackets for 2018–2025 (IRC §1(j)(2)).
+const BRACKET_RATES: [Rate; 7] = [
+    Rate::from_percent(10),
+    Rate::from_percent(12),
+    Rate::from_percent(22),
+    Rate::from_percent(24),
+    Rate::from_percent(32),
+    Rate::from_percent(35),
+    Rate::from_percent(37),
+];
+
+/// Earned income credit parameters for one number of qualifying
+/// children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EitcParameters {
+    pub credit_rate: Rate,
+    pub phaseout_rate: Rate,
+    /// Earned income at which the maximum credit is reached.
+    pub earned_income_amount: Usd,
+    pub maximum_credit: Usd,
+    /// Earned income or AGI, whichever is greater, above which the credit
+    /// phases out.
+    pub phaseout_start: Usd,
+    /// `phaseout_start` when married filing jointly.
+    pub phaseout_start_joint: Usd,
+}
+
+impl EitcParameters {
+    /// Parameters from a year's indexed amounts and the statutory rates
+    /// (IRC §32(b)(1)). The maximum credit is the earned income amount at
+    /// the credit rate, rounded to the dollar as in the published tables.
+    pub fn new(
+        qualifying_children: u32,
+        earned_income_amount: Usd,
+        phaseout_start: Usd,
+        phaseout_start_joint: Usd,
+    ) -> Self {
+        let (credit_bps, phaseout_bps) = match qualifying_children {
+            0 => (765, 765),
+            1 => (3_400, 1_598),
+            2 => (4_000, 2_106),
+            _ => (4_500, 2_106),
+        };
+        let credit_rate = Rate::from_bps(credit_bps);
+        EitcParameters {
+            credit_rate,
+            phaseout_rate: Rate::from_bps(phaseout_bps),
+            earned_income_amount,
+            maximum_credit: (earned_income_amount * credit_rate).irs_round(),
+            phaseout_start,
+            phaseout_start_joint,
+        }
+    }
+}
+
+/// Tax on `taxable_income` under the rate schedule given by `brackets`,
+/// rounded to the cent. Returns above the tax table's range use the
+/// schedule; the table instead taxes the midpoint of each row.
+pub fn rate_schedule_tax(brackets: &[Bracket], taxable_income: Usd) -> Usd {
+    let mut tax = Usd::ZERO;
+    for (i, bracket) in brackets.iter().enumerate() {
+        if taxable_income <= bracket.floor {
+            break;
+        }
+        let top = brackets
+            .get(i + 1)
+            .map_or(taxable_income, |next| next.floor.min(taxable_income));
+        tax += (top - bracket.floor) * bracket.rate;
+    }
+    tax
+}
+
+/// The calendar year a [`TaxYear`] names.
+pub const fn calendar_year(year: TaxYear) -> i32 {
+    match year {
+        TaxYear::Y2023 => 2023,
+        TaxYear::Y2024 => 2024,
+        TaxYear::Y2025 => 2025,
+    }
+}
+
+/// The [`TaxYear`] whose tables `us_tax_brackets` publishes for calendar
+/// year `year`, or `None` if it has none.
+pub const fn tax_year(year: i32) -> Option<TaxYear> {
+    match year {
+        2023 => Some(TaxYear::Y2023),
+        2024 => Some(TaxYear::Y2024),
+        2025 => Some(TaxYear::Y2025),
+        _ => None,
+    }
+}
+
+/// The rules this crate ships for `year`, or `None` if it has none.
+pub fn rules_for(year: TaxYear) -> Option<&'static dyn TaxYearRules> {
+    match year {
+        TaxYear::Y2023 => Some(&y2023::Rules2023),
+        TaxYear::Y2024 => None,
+        TaxYear::Y2025 => Some(&y2025::Rules2025),
+    }
+}
+
+/// The rules this crate ships for calendar year `year`, including 2022,
+/// which `us_tax_brackets` has no [`TaxYear`] for, and the 2026
+/// projection, or `None` if it has none.
+pub fn rules_for_calendar_year(year: i32) -> Option<&'static dyn TaxYearRules> {
+    match year {
+        2022 => Some(&y2022::Rules2022),
+        2023 => rules_for(TaxYear::Y2023),
+        2025 => rules_for(TaxYear::Y2025),
+        2026 => Some(&y2026::Rules2026Projected),
+        _ => None,
+    }
+}
+
+/// Rules by calendar year: the built-in rules from
+/// [`rules_for_calendar_year`], overridden or supplemented by registered
+/// ones.
+#[derive(Default)]
+pub struct RulesRegistry {
+    registered: HashMap<i32, Box<dyn TaxYearRules>>,
+}
+
+impl RulesRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `rules` for their [`calendar_year`](TaxYearRules::calendar_year),
+    /// returning any rules previously registered for it. Built-in rules are
+    /// shadowed, not returned.
+    pub fn register(&mut self, rules: Box<dyn TaxYearRules>) -> Option<Box<dyn TaxYearRules>> {
+        self.registered.insert(rules.calendar_year(), rules)
+    }
+
+    /// The registered rules for calendar year `year`, else the built-in
+    /// ones.
+    pub fn get(&self, year: i32) -> Option<&dyn TaxYearRules> {
+        match self.registered.get(&year) {
+            Some(rules) => Some(rules.as_ref()),
+            None => rules_for_calendar_year(year),
+        }
+    }
+}
+
+/// Input to [`TaxYearRules::exemptions`].
+pub struct ExemptionParams {
+    pub filing_status: FilingStatus,
+    pub agi: Usd,
+    /// Number of personal and dependent exemptions claimed.
+    pub count: i64,
 }
 
 /// Input to [`TaxYearRules::standard_deduction`].
@@ -108,3 +527,335 @@ pub struct DeductionParams {
     pub spouse_itemizes: bool,
     pub earned_income: Usd,
 }
+
+/// Generates the standard-deduction test matrix for a [`TaxYearRules`]
+/// implementation from a table of expected amounts.
+///
+/// For each filing status, `boxes` lists the expected deduction with 0, 1,
+/// 2, ... age/blindness boxes checked (the taxpayer's two boxes first, then
+/// the spouse's), and `dependent` the same for a dependent filer with
+/// `dependent_earned_income`. One test is generated per status.
+///
+/// ```ignore
+/// standard_deduction_matrix! {
+///     rules: Rules2025,
+///     dependent_earned_income: 3_000,
+///     Single => { boxes: [15_750, 17_750, 19_750], dependent: [3_450, 5_450, 7_450] },
+/// }
+/// ```
+#[cfg(test)]
+macro_rules! standard_deduction_matrix {
+    (
+        rules: $rules:expr,
+        dependent_earned_income: $earned:expr,
+        $(
+            $status:ident => {
+                boxes: [$($amount:expr),+ $(,)?],
+                dependent: [$($dependent:expr),+ $(,)?] $(,)?
+            }
+        ),+ $(,)?
+    ) => {
+        #[allow(non_snake_case)]
+        mod standard_deduction_matrix {
+            use super::*;
+            use $crate::rules::{DeductionParams, TaxYearRules};
+            use $crate::types::Filer;
+
+            fn filer(boxes: usize) -> Filer {
+                Filer {
+                    is_65_or_older: boxes >= 1,
+                    is_blind: boxes >= 2,
+                }
+            }
+
+            fn params(status: FilingStatus, boxes: usize, is_dependent: bool) -> DeductionParams {
+                DeductionParams {
+                    filing_status: status,
+                    taxpayer: filer(boxes.min(2)),
+                    spouse: Some(filer(boxes.saturating_sub(2))),
+                    is_dependent,
+                    is_dual_status_alien: false,
+                    spouse_itemizes: false,
+                    earned_income: Usd::from_dollars($earned),
+                }
+            }
+
+            $(
+                #[test]
+                fn $status() {
+                    let status = FilingStatus::$status;
+                    let cases = [(false, vec![$($amount),+]), (true, vec![$($dependent),+])];
+                    for (is_dependent, amounts) in cases {
+                        for (boxes, expected) in amounts.into_iter().enumerate() {
+                            assert_eq!(
+                                $rules.standard_deduction(&params(status, boxes, is_dependent)),
+                                Usd::from_dollars(expected),
+                                "{status:?}, {boxes} boxes, dependent: {is_dependent}",
+                            );
+                        }
+                    }
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use standard_deduction_matrix;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pre-TCJA (2017) parameters; only the exemptions are tested.
+    struct Pre2018Style;
+
+    impl TaxYearRules for Pre2018Style {
+        fn calendar_year(&self) -> i32 {
+            2025
+        }
+        fn single_mfs_typical_standard_deduction(&self) -> Usd {
+            Usd::from_dollars(6_350)
+        }
+        fn mfj_qss_typical_standard_deduction(&self) -> Usd {
+            Usd::from_dollars(12_700)
+        }
+        fn hoh_typical_standard_deduction(&self) -> Usd {
+            Usd::from_dollars(9_350)
+        }
+        fn additional_deduction_unmarried(&self) -> Usd {
+            Usd::from_dollars(1_550)
+        }
+        fn additional_deduction_married(&self) -> Usd {
+            Usd::from_dollars(1_250)
+        }
+        fn dependent_earned_income_addition(&self) -> Usd {
+            Usd::from_dollars(350)
+        }
+        fn dependent_minimum_deduction(&self) -> Usd {
+            Usd::from_dollars(1_050)
+        }
+        fn ordinary_brackets(&self, _status: FilingStatus) -> [Bracket; 7] {
+            [
+                (0, 1_000),
+                (9_325, 1_500),
+                (37_950, 2_500),
+                (91_900, 2_800),
+                (191_650, 3_300),
+                (416_700, 3_500),
+                (418_400, 3_960),
+            ]
+            .map(|(floor, bps)| Bracket {
+                floor: Usd::from_dollars(floor),
+                rate: Rate::from_bps(bps),
+            })
+        }
+        fn capital_gain_rate_thresholds(&self, _status: FilingStatus) -> [Usd; 2] {
+            [Usd::from_dollars(37_950), Usd::from_dollars(418_400)]
+        }
+        fn amt_exemption(&self, status: FilingStatus) -> Usd {
+            use FilingStatus::*;
+            Usd::from_dollars(match status {
+                Single | HeadOfHousehold => 54_300,
+                MarriedFilingJointly | QualifyingSurvivingSpouse => 84_500,
+                MarriedFilingSeparately => 42_250,
+            })
+        }
+        fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd {
+            use FilingStatus::*;
+            Usd::from_dollars(match status {
+                Single | HeadOfHousehold => 120_700,
+                MarriedFilingJointly | QualifyingSurvivingSpouse => 160_900,
+                MarriedFilingSeparately => 80_450,
+            })
+        }
+        fn amt_exemption_phaseout_rate(&self) -> Rate {
+            Rate::from_percent(25)
+        }
+        fn amt_rate_threshold(&self, _status: FilingStatus) -> Usd {
+            Usd::from_dollars(187_800)
+        }
+        fn child_tax_credit_amount(&self) -> Usd {
+            Usd::from_dollars(1_000)
+        }
+        fn additional_child_tax_credit_limit(&self) -> Usd {
+            Usd::from_dollars(1_000)
+        }
+        fn child_tax_credit_phaseout_start(&self, status: FilingStatus) -> Usd {
+            use FilingStatus::*;
+            Usd::from_dollars(match status {
+                MarriedFilingJointly => 110_000,
+                MarriedFilingSeparately => 55_000,
+                _ => 75_000,
+            })
+        }
+        fn eitc(&self, qualifying_children: u32) -> EitcParameters {
+            let (earned, start, joint) = match qualifying_children {
+                0 => (6_670, 8_340, 13_930),
+                1 => (10_000, 18_340, 23_930),
+                _ => (14_040, 18_340, 23_930),
+            };
+            EitcParameters::new(
+                qualifying_children,
+                Usd::from_dollars(earned),
+                Usd::from_dollars(start),
+                Usd::from_dollars(joint),
+            )
+        }
+        fn eitc_investment_income_limit(&self) -> Usd {
+            Usd::from_dollars(3_450)
+        }
+        fn employer_coverage_affordability_rate(&self) -> Rate {
+            Rate::from_bps(969)
+        }
+        fn qbi_threshold(&self, _status: FilingStatus) -> Usd {
+            Usd::ZERO
+        }
+        fn qbi_phase_in_range(&self, _status: FilingStatus) -> Usd {
+            Usd::ZERO
+        }
+        fn salt_cap(&self) -> Option<Usd> {
+            None
+        }
+        fn salt_phasedown_start(&self) -> Option<Usd> {
+            None
+        }
+        fn ira_contribution_limit(&self) -> Usd {
+            Usd::from_dollars(5_500)
+        }
+        fn ira_catch_up_contribution(&self) -> Usd {
+            Usd::from_dollars(1_000)
+        }
+        fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_only: bool) -> Usd {
+            use FilingStatus::*;
+            Usd::from_dollars(match status {
+                MarriedFilingSeparately => 0,
+                _ if spouse_only => 186_000,
+                MarriedFilingJointly | QualifyingSurvivingSpouse => 99_000,
+                Single | HeadOfHousehold => 62_000,
+            })
+        }
+        fn elective_deferral_limit(&self) -> Usd {
+            Usd::from_dollars(18_000)
+        }
+        fn elective_deferral_catch_up(&self, age: u32) -> Usd {
+            Usd::from_dollars(if age >= 50 { 6_000 } else { 0 })
+        }
+        fn hsa_contribution_limit(&self, family: bool) -> Usd {
+            Usd::from_dollars(if family { 6_750 } else { 3_400 })
+        }
+        fn business_standard_mileage_rate_mills(&self) -> i64 {
+            0
+        }
+        fn social_security_wage_base(&self) -> Usd {
+            Usd::from_dollars(127_200)
+        }
+        fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd {
+            self.typical_standard_deduction(status)
+        }
+        fn withholding_wage_adjustment(&self, _status: FilingStatus) -> Usd {
+            Usd::ZERO
+        }
+        fn poverty_line(&self, _region: PovertyRegion, _family_size: u32) -> Usd {
+            Usd::ZERO
+        }
+        fn ptc_applicable_figure(&self, _fpl_percent: i64) -> Rate {
+            Rate::ZERO
+        }
+        fn ptc_repayment_limit(&self, _status: FilingStatus, _fpl_percent: i64) -> Option<Usd> {
+            None
+        }
+        fn personal_exemption_amount(&self) -> Usd {
+            Usd::from_dollars(4_050)
+        }
+        fn exemption_phaseout_threshold(&self, status: FilingStatus) -> Option<Usd> {
+            use FilingStatus::*;
+            Some(Usd::from_dollars(match status {
+                Single => 261_500,
+                MarriedFilingJointly | QualifyingSurvivingSpouse => 313_800,
+                MarriedFilingSeparately => 156_900,
+                HeadOfHousehold => 287_650,
+            }))
+        }
+    }
+
+    fn exemption_params(status: FilingStatus, agi: i64, count: i64) -> ExemptionParams {
+        ExemptionParams {
+            filing_status: status,
+            agi: Usd::from_dollars(agi),
+            count,
+        }
+    }
+
+    #[test]
+    fn exemptions_below_threshold_full() {
+        let p = exemption_params(FilingStatus::MarriedFilingJointly, 200_000, 4);
+        assert_eq!(Pre2018Style.exemptions(&p), Usd::from_dollars(16_200));
+    }
+
+    #[test]
+    fn exemptions_partial_step_rounds_up() {
+        // 1 dollar over → 1 step → 2% of 4,050 = 81
+        let p = exemption_params(FilingStatus::Single, 261_501, 1);
+        assert_eq!(Pre2018Style.exemptions(&p), Usd::from_dollars(3_969));
+    }
+
+    #[test]
+    fn exemptions_mfs_uses_half_step() {
+        // 2,500 over at $1,250 steps → 2 steps → 4%
+        let p = exemption_params(FilingStatus::MarriedFilingSeparately, 159_400, 1);
+        assert_eq!(Pre2018Style.exemptions(&p), Usd::from_cents(388_800));
+    }
+
+    #[test]
+    fn built_in_rules_by_year() {
+        assert_eq!(rules_for(TaxYear::Y2023).unwrap().calendar_year(), 2023);
+        assert_eq!(rules_for(TaxYear::Y2025).unwrap().calendar_year(), 2025);
+        assert!(rules_for(TaxYear::Y2024).is_none());
+        assert_eq!(rules_for_calendar_year(2022).unwrap().calendar_year(), 2022);
+        assert_eq!(rules_for_calendar_year(2026).unwrap().calendar_year(), 2026);
+        assert!(rules_for_calendar_year(2024).is_none());
+    }
+
+    #[test]
+    fn registered_rules_shadow_built_in() {
+        let mut registry = RulesRegistry::new();
+        let exemption = |r: &RulesRegistry| r.get(2025).unwrap().personal_exemption_amount();
+        assert_eq!(exemption(&registry), Usd::ZERO);
+        assert!(registry.register(Box::new(Pre2018Style)).is_none());
+        assert_eq!(exemption(&registry), Usd::from_dollars(4_050));
+        assert!(registry.register(Box::new(Pre2018Style)).is_some());
+        assert!(registry.get(2024).is_none());
+    }
+
+    #[test]
+    fn projection_registered_under_its_own_year() {
+        let mut registry = RulesRegistry::new();
+        assert!(registry.register(Box::new(Pre2018Style)).is_none());
+        assert!(
+            registry
+                .register(Box::new(y2026::Rules2026Projected))
+                .is_none()
+        );
+        assert_eq!(
+            registry.get(2025).unwrap().personal_exemption_amount(),
+            Usd::from_dollars(4_050)
+        );
+        assert!(registry.get(2026).unwrap().is_projection());
+    }
+
+    #[test]
+    fn rate_schedule_matches_tables_above_their_range() {
+        let brackets = y2025::Rules2025.ordinary_brackets(FilingStatus::Single);
+        let tax = rate_schedule_tax(&brackets, Usd::from_dollars(150_000));
+        let table = us_tax_brackets::compute_tax(TaxYear::Y2025, FilingStatus::Single, 150_000);
+        assert_eq!(tax, Usd::from_dollars(table.unwrap()));
+        assert_eq!(rate_schedule_tax(&brackets, Usd::ZERO), Usd::ZERO);
+    }
+
+    #[test]
+    fn exemptions_fully_phased_out() {
+        let p = exemption_params(FilingStatus::Single, 261_500 + 122_501, 2);
+        assert_eq!(Pre2018Style.exemptions(&p), Usd::ZERO);
+    }
+}
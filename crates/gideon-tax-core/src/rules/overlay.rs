@@ -0,0 +1,436 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::premium_tax_credit::PovertyRegion;
+use crate::rules::{Bracket, EitcParameters, TaxYearRules};
+use crate::{Rate, Usd};
+
+/// A parameter replaced by a [`RulesOverlay`]. Variants with a `status`
+/// apply to that filing status only, or to every status if `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Override {
+    SingleMfsStandardDeduction(Usd),
+    MfjQssStandardDeduction(Usd),
+    HohStandardDeduction(Usd),
+    AdditionalDeductionUnmarried(Usd),
+    AdditionalDeductionMarried(Usd),
+    OrdinaryBrackets {
+        status: Option<FilingStatus>,
+        brackets: [Bracket; 7],
+    },
+    CapitalGainRateThresholds {
+        status: Option<FilingStatus>,
+        thresholds: [Usd; 2],
+    },
+    AmtExemption {
+        status: Option<FilingStatus>,
+        amount: Usd,
+    },
+    AmtExemptionPhaseoutStart {
+        status: Option<FilingStatus>,
+        amount: Usd,
+    },
+    ChildTaxCreditAmount(Usd),
+    AdditionalChildTaxCreditLimit(Usd),
+    ChildTaxCreditPhaseoutStart {
+        status: Option<FilingStatus>,
+        amount: Usd,
+    },
+    QbiThreshold {
+        status: Option<FilingStatus>,
+        amount: Usd,
+    },
+    /// `None` removes the cap.
+    SaltCap(Option<Usd>),
+    /// `None` removes the phase-down.
+    SaltPhasedownStart(Option<Usd>),
+    SaltCapFloor(Usd),
+    IraContributionLimit(Usd),
+    ElectiveDeferralLimit(Usd),
+    HsaContributionLimit {
+        family: bool,
+        amount: Usd,
+    },
+    SocialSecurityWageBase(Usd),
+    PersonalExemptionAmount(Usd),
+}
+
+/// Rules that replace selected parameters of a base year, for modeling
+/// proposed law against real returns.
+///
+/// Parameters without an override come from the base rules, and the
+/// provided algorithms run against the overlay. When the ordinary brackets
+/// are overridden, the spine taxes returns on the new rate schedule rather
+/// than the base year's tables; otherwise the base year's tables still
+/// apply. An overlay is a projection only if its base is.
+pub struct RulesOverlay<'a> {
+    base: &'a dyn TaxYearRules,
+    overrides: Vec<Override>,
+}
+
+impl<'a> RulesOverlay<'a> {
+    pub fn new(base: &'a dyn TaxYearRules) -> Self {
+        RulesOverlay {
+            base,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Adds `o`, which takes precedence over earlier overrides of the same
+    /// parameter.
+    pub fn with(mut self, o: Override) -> Self {
+        self.overrides.push(o);
+        self
+    }
+
+    pub fn overrides(&self) -> &[Override] {
+        &self.overrides
+    }
+
+    fn find<T>(&self, f: impl Fn(&Override) -> Option<T>) -> Option<T> {
+        self.overrides.iter().rev().find_map(f)
+    }
+}
+
+fn applies(to: Option<FilingStatus>, status: FilingStatus) -> bool {
+    to.is_none_or(|s| s == status)
+}
+
+impl TaxYearRules for RulesOverlay<'_> {
+    fn calendar_year(&self) -> i32 {
+        self.base.calendar_year()
+    }
+
+    fn is_projection(&self) -> bool {
+        self.base.is_projection()
+    }
+
+    fn uses_rate_schedule(&self) -> bool {
+        self.base.uses_rate_schedule()
+            || self
+                .overrides
+                .iter()
+                .any(|o| matches!(o, Override::OrdinaryBrackets { .. }))
+    }
+
+    fn single_mfs_typical_standard_deduction(&self) -> Usd {
+        self.find(|o| match o {
+            Override::SingleMfsStandardDeduction(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.single_mfs_typical_standard_deduction())
+    }
+
+    fn mfj_qss_typical_standard_deduction(&self) -> Usd {
+        self.find(|o| match o {
+            Override::MfjQssStandardDeduction(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.mfj_qss_typical_standard_deduction())
+    }
+
+    fn hoh_typical_standard_deduction(&self) -> Usd {
+        self.find(|o| match o {
+            Override::HohStandardDeduction(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.hoh_typical_standard_deduction())
+    }
+
+    fn additional_deduction_unmarried(&self) -> Usd {
+        self.find(|o| match o {
+            Override::AdditionalDeductionUnmarried(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.additional_deduction_unmarried())
+    }
+
+    fn additional_deduction_married(&self) -> Usd {
+        self.find(|o| match o {
+            Override::AdditionalDeductionMarried(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.additional_deduction_married())
+    }
+
+    fn dependent_earned_income_addition(&self) -> Usd {
+        self.base.dependent_earned_income_addition()
+    }
+
+    fn dependent_minimum_deduction(&self) -> Usd {
+        self.base.dependent_minimum_deduction()
+    }
+
+    fn ordinary_brackets(&self, status: FilingStatus) -> [Bracket; 7] {
+        self.find(|o| match o {
+            Override::OrdinaryBrackets {
+                status: to,
+                brackets,
+            } if applies(*to, status) => Some(*brackets),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.ordinary_brackets(status))
+    }
+
+    fn capital_gain_rate_thresholds(&self, status: FilingStatus) -> [Usd; 2] {
+        self.find(|o| match o {
+            Override::CapitalGainRateThresholds {
+                status: to,
+                thresholds,
+            } if applies(*to, status) => Some(*thresholds),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.capital_gain_rate_thresholds(status))
+    }
+
+    fn amt_exemption(&self, status: FilingStatus) -> Usd {
+        self.find(|o| match o {
+            Override::AmtExemption { status: to, amount } if applies(*to, status) => Some(*amount),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.amt_exemption(status))
+    }
+
+    fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd {
+        self.find(|o| match o {
+            Override::AmtExemptionPhaseoutStart { status: to, amount } if applies(*to, status) => {
+                Some(*amount)
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.amt_exemption_phaseout_start(status))
+    }
+
+    fn amt_exemption_phaseout_rate(&self) -> Rate {
+        self.base.amt_exemption_phaseout_rate()
+    }
+
+    fn amt_rate_threshold(&self, status: FilingStatus) -> Usd {
+        self.base.amt_rate_threshold(status)
+    }
+
+    fn child_tax_credit_amount(&self) -> Usd {
+        self.find(|o| match o {
+            Override::ChildTaxCreditAmount(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.child_tax_credit_amount())
+    }
+
+    fn additional_child_tax_credit_limit(&self) -> Usd {
+        self.find(|o| match o {
+            Override::AdditionalChildTaxCreditLimit(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.additional_child_tax_credit_limit())
+    }
+
+    fn child_tax_credit_phaseout_start(&self, status: FilingStatus) -> Usd {
+        self.find(|o| match o {
+            Override::ChildTaxCreditPhaseoutStart { status: to, amount }
+                if applies(*to, status) =>
+            {
+                Some(*amount)
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.child_tax_credit_phaseout_start(status))
+    }
+
+    fn eitc(&self, qualifying_children: u32) -> EitcParameters {
+        self.base.eitc(qualifying_children)
+    }
+
+    fn eitc_investment_income_limit(&self) -> Usd {
+        self.base.eitc_investment_income_limit()
+    }
+
+    fn employer_coverage_affordability_rate(&self) -> Rate {
+        self.base.employer_coverage_affordability_rate()
+    }
+
+    fn qbi_threshold(&self, status: FilingStatus) -> Usd {
+        self.find(|o| match o {
+            Override::QbiThreshold { status: to, amount } if applies(*to, status) => Some(*amount),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.qbi_threshold(status))
+    }
+
+    fn qbi_phase_in_range(&self, status: FilingStatus) -> Usd {
+        self.base.qbi_phase_in_range(status)
+    }
+
+    fn salt_cap(&self) -> Option<Usd> {
+        self.find(|o| match o {
+            Override::SaltCap(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.salt_cap())
+    }
+
+    fn salt_phasedown_start(&self) -> Option<Usd> {
+        self.find(|o| match o {
+            Override::SaltPhasedownStart(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.salt_phasedown_start())
+    }
+
+    fn salt_cap_floor(&self) -> Usd {
+        self.find(|o| match o {
+            Override::SaltCapFloor(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.salt_cap_floor())
+    }
+
+    fn ira_contribution_limit(&self) -> Usd {
+        self.find(|o| match o {
+            Override::IraContributionLimit(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.ira_contribution_limit())
+    }
+
+    fn ira_catch_up_contribution(&self) -> Usd {
+        self.base.ira_catch_up_contribution()
+    }
+
+    fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_covered_only: bool) -> Usd {
+        self.base
+            .ira_deduction_phaseout_start(status, spouse_covered_only)
+    }
+
+    fn elective_deferral_limit(&self) -> Usd {
+        self.find(|o| match o {
+            Override::ElectiveDeferralLimit(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.elective_deferral_limit())
+    }
+
+    fn elective_deferral_catch_up(&self, age: u32) -> Usd {
+        self.base.elective_deferral_catch_up(age)
+    }
+
+    fn hsa_contribution_limit(&self, family: bool) -> Usd {
+        self.find(|o| match o {
+            Override::HsaContributionLimit { family: to, amount } if *to == family => Some(*amount),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.hsa_contribution_limit(family))
+    }
+
+    fn business_standard_mileage_rate_mills(&self) -> i64 {
+        self.base.business_standard_mileage_rate_mills()
+    }
+
+    fn social_security_wage_base(&self) -> Usd {
+        self.find(|o| match o {
+            Override::SocialSecurityWageBase(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.social_security_wage_base())
+    }
+
+    fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd {
+        self.base.withholding_standard_deduction(status)
+    }
+
+    fn withholding_wage_adjustment(&self, status: FilingStatus) -> Usd {
+        self.base.withholding_wage_adjustment(status)
+    }
+
+    fn poverty_line(&self, region: PovertyRegion, family_size: u32) -> Usd {
+        self.base.poverty_line(region, family_size)
+    }
+
+    fn ptc_applicable_figure(&self, fpl_percent: i64) -> Rate {
+        self.base.ptc_applicable_figure(fpl_percent)
+    }
+
+    fn ptc_repayment_limit(&self, status: FilingStatus, fpl_percent: i64) -> Option<Usd> {
+        self.base.ptc_repayment_limit(status, fpl_percent)
+    }
+
+    fn personal_exemption_amount(&self) -> Usd {
+        self.find(|o| match o {
+            Override::PersonalExemptionAmount(v) => Some(*v),
+            _ => None,
+        })
+        .unwrap_or_else(|| self.base.personal_exemption_amount())
+    }
+
+    fn dependent_gross_income_limit(&self) -> Usd {
+        self.base.dependent_gross_income_limit()
+    }
+
+    fn exemption_phaseout_threshold(&self, status: FilingStatus) -> Option<Usd> {
+        self.base.exemption_phaseout_threshold(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2023::Rules2023;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+    use crate::spine::{Key, ReturnInput, compute_spine};
+
+    #[test]
+    fn salt_cap_override_changes_tax() {
+        let mut inp = ReturnInput {
+            tax_year: 2023,
+            ..input(150_000, 0)
+        };
+        inp.itemized_deductions.state_and_local_taxes = Usd::from_dollars(25_000);
+        let current = compute_spine(&Rules2023, &inp).unwrap();
+        let proposed =
+            RulesOverlay::new(&Rules2023).with(Override::SaltCap(Some(Usd::from_dollars(40_000))));
+        let overlaid = compute_spine(&proposed, &inp).unwrap();
+        // Itemizing 25,000 instead of the 13,850 standard deduction.
+        assert_eq!(
+            current[&Key::TaxableIncome] - overlaid[&Key::TaxableIncome],
+            Usd::from_dollars(11_150)
+        );
+        assert!(!proposed.uses_rate_schedule());
+    }
+
+    #[test]
+    fn later_overrides_win_and_status_filters_apply() {
+        let overlay = RulesOverlay::new(&Rules2025)
+            .with(Override::ChildTaxCreditAmount(Usd::from_dollars(3_000)))
+            .with(Override::ChildTaxCreditAmount(Usd::from_dollars(3_600)))
+            .with(Override::QbiThreshold {
+                status: Some(FilingStatus::Single),
+                amount: Usd::from_dollars(250_000),
+            });
+        assert_eq!(overlay.child_tax_credit_amount(), Usd::from_dollars(3_600));
+        assert_eq!(
+            overlay.qbi_threshold(FilingStatus::Single),
+            Usd::from_dollars(250_000)
+        );
+        assert_eq!(
+            overlay.qbi_threshold(FilingStatus::MarriedFilingJointly),
+            Usd::from_dollars(394_600)
+        );
+        assert_eq!(overlay.salt_cap(), Some(Usd::from_dollars(40_000)));
+    }
+
+    #[test]
+    fn bracket_override_taxes_on_schedule() {
+        let flat = [Bracket {
+            floor: Usd::ZERO,
+            rate: Rate::from_percent(10),
+        }; 7];
+        let overlay = RulesOverlay::new(&Rules2025).with(Override::OrdinaryBrackets {
+            status: None,
+            brackets: flat,
+        });
+        assert!(overlay.uses_rate_schedule());
+        assert!(!overlay.is_projection());
+        let ledger = compute_spine(&overlay, &input(60_000, 0)).unwrap();
+        assert_eq!(ledger[&Key::TotalTax], Usd::from_dollars(4_425));
+    }
+}
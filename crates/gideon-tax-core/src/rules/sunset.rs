@@ -0,0 +1,315 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::premium_tax_credit::PovertyRegion;
+use crate::rules::indexing::{CostOfLivingAdjustment, Provision};
+use crate::rules::{Bracket, EitcParameters, TaxYearRules};
+use crate::{Rate, Usd};
+
+/// Rates of the seven brackets before 2018 (IRC §1(a)–(d)).
+const PRE_TCJA_RATES: [Rate; 7] = [
+    Rate::from_percent(10),
+    Rate::from_percent(15),
+    Rate::from_percent(25),
+    Rate::from_percent(28),
+    Rate::from_percent(33),
+    Rate::from_percent(35),
+    Rate::from_bps(3_960),
+];
+
+/// The individual income tax as it stood before the Tax Cuts and Jobs Act:
+/// 2017 amounts indexed by `adjustment`, with every parameter TCJA left
+/// alone taken from `base`.
+///
+/// TCJA's individual provisions were scheduled to expire after 2025; the
+/// One Big Beautiful Bill Act made them permanent instead. This rule set
+/// models that expiry, or a later repeal, for comparison.
+///
+/// Brackets return to seven rates topping out at 39.6%, with the capital
+/// gain thresholds at the bracket boundaries they followed. Personal
+/// exemptions return with their phase-out, the child tax credit falls to
+/// $1,000, there is no SALT cap, and no QBI deduction. The limitation on
+/// itemized deductions (IRC §68) and miscellaneous itemized deductions are
+/// not modeled. Brackets differ from the base year's tables, so this
+/// reports itself as a projection and the spine taxes returns on the rate
+/// schedule.
+pub struct TcjaSunset<'a> {
+    base: &'a dyn TaxYearRules,
+    adjustment: CostOfLivingAdjustment,
+}
+
+impl<'a> TcjaSunset<'a> {
+    /// `adjustment` takes 2017 amounts to the dollars `base` is in.
+    pub fn new(base: &'a dyn TaxYearRules, adjustment: CostOfLivingAdjustment) -> Self {
+        TcjaSunset { base, adjustment }
+    }
+
+    fn index(&self, provision: Provision, dollars: i64) -> Usd {
+        provision.index(Usd::from_dollars(dollars), self.adjustment)
+    }
+}
+
+impl TaxYearRules for TcjaSunset<'_> {
+    fn calendar_year(&self) -> i32 {
+        self.base.calendar_year()
+    }
+
+    fn is_projection(&self) -> bool {
+        true
+    }
+
+    fn single_mfs_typical_standard_deduction(&self) -> Usd {
+        self.index(Provision::StandardDeduction, 6_350)
+    }
+
+    fn mfj_qss_typical_standard_deduction(&self) -> Usd {
+        self.index(Provision::StandardDeduction, 12_700)
+    }
+
+    fn hoh_typical_standard_deduction(&self) -> Usd {
+        self.index(Provision::StandardDeduction, 9_350)
+    }
+
+    fn additional_deduction_unmarried(&self) -> Usd {
+        self.index(Provision::AdditionalStandardDeduction, 1_550)
+    }
+
+    fn additional_deduction_married(&self) -> Usd {
+        self.index(Provision::AdditionalStandardDeduction, 1_250)
+    }
+
+    fn dependent_earned_income_addition(&self) -> Usd {
+        self.index(Provision::StandardDeduction, 350)
+    }
+
+    fn dependent_minimum_deduction(&self) -> Usd {
+        self.index(Provision::StandardDeduction, 1_050)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-16-55.pdf>
+    fn ordinary_brackets(&self, status: FilingStatus) -> [Bracket; 7] {
+        use FilingStatus::*;
+        let floors = match status {
+            Single => [0, 9_325, 37_950, 91_900, 191_650, 416_700, 418_400],
+            MarriedFilingJointly | QualifyingSurvivingSpouse => {
+                [0, 18_650, 75_900, 153_100, 233_350, 416_700, 470_700]
+            }
+            MarriedFilingSeparately => [0, 9_325, 37_950, 76_550, 116_675, 208_350, 235_350],
+            HeadOfHousehold => [0, 13_350, 50_800, 131_200, 212_500, 416_700, 444_550],
+        };
+        let provision = Provision::OrdinaryBrackets {
+            married_filing_separately: status == MarriedFilingSeparately,
+        };
+        std::array::from_fn(|i| Bracket {
+            floor: self.index(provision, floors[i]),
+            rate: PRE_TCJA_RATES[i],
+        })
+    }
+
+    /// The 15% rate began above the 15% bracket and the 20% rate in the
+    /// 39.6% bracket (IRC §1(h)(1) before 2018).
+    fn capital_gain_rate_thresholds(&self, status: FilingStatus) -> [Usd; 2] {
+        let brackets = self.ordinary_brackets(status);
+        [brackets[2].floor, brackets[6].floor]
+    }
+
+    fn amt_exemption(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        let dollars = match status {
+            Single | HeadOfHousehold => 54_300,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 84_500,
+            MarriedFilingSeparately => 42_250,
+        };
+        self.index(Provision::AmtExemption, dollars)
+    }
+
+    fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        let dollars = match status {
+            Single | HeadOfHousehold => 120_700,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 160_900,
+            MarriedFilingSeparately => 80_450,
+        };
+        self.index(Provision::AmtExemption, dollars)
+    }
+
+    fn amt_exemption_phaseout_rate(&self) -> Rate {
+        Rate::from_percent(25)
+    }
+
+    fn amt_rate_threshold(&self, status: FilingStatus) -> Usd {
+        let dollars = if status == FilingStatus::MarriedFilingSeparately {
+            93_900
+        } else {
+            187_800
+        };
+        self.index(Provision::AmtExemption, dollars)
+    }
+
+    /// Not indexed (IRC §24(a)).
+    fn child_tax_credit_amount(&self) -> Usd {
+        Usd::from_dollars(1_000)
+    }
+
+    fn additional_child_tax_credit_limit(&self) -> Usd {
+        Usd::from_dollars(1_000)
+    }
+
+    /// Not indexed (IRC §24(b)(2)).
+    fn child_tax_credit_phaseout_start(&self, status: FilingStatus) -> Usd {
+        Usd::from_dollars(match status {
+            FilingStatus::MarriedFilingJointly => 110_000,
+            FilingStatus::MarriedFilingSeparately => 55_000,
+            _ => 75_000,
+        })
+    }
+
+    fn eitc(&self, qualifying_children: u32) -> EitcParameters {
+        self.base.eitc(qualifying_children)
+    }
+
+    fn eitc_investment_income_limit(&self) -> Usd {
+        self.base.eitc_investment_income_limit()
+    }
+
+    fn employer_coverage_affordability_rate(&self) -> Rate {
+        self.base.employer_coverage_affordability_rate()
+    }
+
+    /// There is no QBI deduction, so the simplified computation never
+    /// applies.
+    fn qbi_threshold(&self, _status: FilingStatus) -> Usd {
+        Usd::ZERO
+    }
+
+    fn qbi_phase_in_range(&self, _status: FilingStatus) -> Usd {
+        Usd::ZERO
+    }
+
+    fn salt_cap(&self) -> Option<Usd> {
+        None
+    }
+
+    fn salt_phasedown_start(&self) -> Option<Usd> {
+        None
+    }
+
+    fn ira_contribution_limit(&self) -> Usd {
+        self.base.ira_contribution_limit()
+    }
+
+    fn ira_catch_up_contribution(&self) -> Usd {
+        self.base.ira_catch_up_contribution()
+    }
+
+    fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_covered_only: bool) -> Usd {
+        self.base
+            .ira_deduction_phaseout_start(status, spouse_covered_only)
+    }
+
+    fn elective_deferral_limit(&self) -> Usd {
+        self.base.elective_deferral_limit()
+    }
+
+    fn elective_deferral_catch_up(&self, age: u32) -> Usd {
+        self.base.elective_deferral_catch_up(age)
+    }
+
+    fn hsa_contribution_limit(&self, family: bool) -> Usd {
+        self.base.hsa_contribution_limit(family)
+    }
+
+    fn business_standard_mileage_rate_mills(&self) -> i64 {
+        self.base.business_standard_mileage_rate_mills()
+    }
+
+    fn social_security_wage_base(&self) -> Usd {
+        self.base.social_security_wage_base()
+    }
+
+    fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd {
+        self.typical_standard_deduction(status)
+    }
+
+    fn withholding_wage_adjustment(&self, status: FilingStatus) -> Usd {
+        self.base.withholding_wage_adjustment(status)
+    }
+
+    fn poverty_line(&self, region: PovertyRegion, family_size: u32) -> Usd {
+        self.base.poverty_line(region, family_size)
+    }
+
+    fn ptc_applicable_figure(&self, fpl_percent: i64) -> Rate {
+        self.base.ptc_applicable_figure(fpl_percent)
+    }
+
+    fn ptc_repayment_limit(&self, status: FilingStatus, fpl_percent: i64) -> Option<Usd> {
+        self.base.ptc_repayment_limit(status, fpl_percent)
+    }
+
+    fn personal_exemption_amount(&self) -> Usd {
+        self.index(Provision::StandardDeduction, 4_050)
+    }
+
+    fn exemption_phaseout_threshold(&self, status: FilingStatus) -> Option<Usd> {
+        use FilingStatus::*;
+        let dollars = match status {
+            Single => 261_500,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 313_800,
+            MarriedFilingSeparately => 156_900,
+            HeadOfHousehold => 287_650,
+        };
+        Some(self.index(Provision::StandardDeduction, dollars))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+    use crate::spine::{Key, compute_spine};
+
+    /// Unindexed, so the 2017 amounts come through unchanged.
+    const NONE: CostOfLivingAdjustment = CostOfLivingAdjustment {
+        index: 1,
+        base_index: 1,
+    };
+
+    #[test]
+    fn pre_tcja_parameters() {
+        let sunset = TcjaSunset::new(&Rules2025, NONE);
+        assert_eq!(
+            sunset.capital_gain_rate_thresholds(FilingStatus::Single),
+            [Usd::from_dollars(37_950), Usd::from_dollars(418_400)]
+        );
+        assert_eq!(sunset.salt_cap(), None);
+        assert_eq!(sunset.child_tax_credit_amount(), Usd::from_dollars(1_000));
+        // Unchanged by TCJA.
+        assert_eq!(
+            sunset.hsa_contribution_limit(true),
+            Usd::from_dollars(8_550)
+        );
+        let indexed = TcjaSunset::new(
+            &Rules2025,
+            CostOfLivingAdjustment {
+                index: 110,
+                base_index: 100,
+            },
+        );
+        // 4,050 × 1.1 = 4,455, down to 4,450.
+        assert_eq!(
+            indexed.personal_exemption_amount(),
+            Usd::from_dollars(4_450)
+        );
+    }
+
+    #[test]
+    fn exemption_and_smaller_deduction_raise_tax() {
+        let sunset = TcjaSunset::new(&Rules2025, NONE);
+        let ledger = compute_spine(&sunset, &input(60_000, 0)).unwrap();
+        // 60,000 - 6,350 - 4,050 = 49,600: 932.50 + 4,293.75 + 25% of
+        // 11,650 is 8,138.75, rounded to the dollar.
+        assert_eq!(ledger[&Key::TaxableIncome], Usd::from_dollars(49_600));
+        assert_eq!(ledger[&Key::TotalTax], Usd::from_dollars(8_139));
+    }
+}
@@ -0,0 +1,464 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::premium_tax_credit::{self, PovertyRegion};
+use crate::rules::{APPLICABLE_PERCENTAGES, BRACKET_RATES, Bracket, EitcParameters, TaxYearRules};
+use crate::{Rate, Usd};
+
+/// IRS-published parameters for tax year 2022 (filed in 2023).
+///
+/// `us_tax_brackets` has no 2022 tables, so the spine taxes 2022 returns
+/// with the rate schedule, and returns under $100,000 differ from the
+/// published tax table by a few dollars.
+///
+/// See: <https://www.irs.gov/pub/irs-prior/i1040gi--2022.pdf>
+pub struct Rules2022;
+
+impl TaxYearRules for Rules2022 {
+    fn calendar_year(&self) -> i32 {
+        2022
+    }
+
+    fn single_mfs_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(12_950)
+    }
+
+    fn mfj_qss_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(25_900)
+    }
+
+    fn hoh_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(19_400)
+    }
+
+    fn additional_deduction_unmarried(&self) -> Usd {
+        Usd::from_dollars(1_750)
+    }
+
+    fn additional_deduction_married(&self) -> Usd {
+        Usd::from_dollars(1_400)
+    }
+
+    fn dependent_earned_income_addition(&self) -> Usd {
+        Usd::from_dollars(400)
+    }
+
+    fn dependent_minimum_deduction(&self) -> Usd {
+        Usd::from_dollars(1_150)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn ordinary_brackets(&self, status: FilingStatus) -> [Bracket; 7] {
+        use FilingStatus::*;
+        let floors = match status {
+            Single => [0, 10_275, 41_775, 89_075, 170_050, 215_950, 539_900],
+            MarriedFilingJointly | QualifyingSurvivingSpouse => {
+                [0, 20_550, 83_550, 178_150, 340_100, 431_900, 647_850]
+            }
+            MarriedFilingSeparately => [0, 10_275, 41_775, 89_075, 170_050, 215_950, 323_925],
+            HeadOfHousehold => [0, 14_650, 55_900, 89_050, 170_050, 215_950, 539_900],
+        };
+        std::array::from_fn(|i| Bracket {
+            floor: Usd::from_dollars(floors[i]),
+            rate: BRACKET_RATES[i],
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn capital_gain_rate_thresholds(&self, status: FilingStatus) -> [Usd; 2] {
+        use FilingStatus::*;
+        let (fifteen, twenty) = match status {
+            Single => (41_675, 459_750),
+            MarriedFilingJointly | QualifyingSurvivingSpouse => (83_350, 517_200),
+            MarriedFilingSeparately => (41_675, 258_600),
+            HeadOfHousehold => (55_800, 488_500),
+        };
+        [Usd::from_dollars(fifteen), Usd::from_dollars(twenty)]
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn amt_exemption(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | HeadOfHousehold => 75_900,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 118_100,
+            MarriedFilingSeparately => 59_050,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 1_079_800,
+            _ => 539_900,
+        })
+    }
+
+    fn amt_exemption_phaseout_rate(&self) -> Rate {
+        Rate::from_percent(25)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn amt_rate_threshold(&self, status: FilingStatus) -> Usd {
+        if status == FilingStatus::MarriedFilingSeparately {
+            Usd::from_dollars(103_050)
+        } else {
+            Usd::from_dollars(206_100)
+        }
+    }
+
+    fn child_tax_credit_amount(&self) -> Usd {
+        Usd::from_dollars(2_000)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn additional_child_tax_credit_limit(&self) -> Usd {
+        Usd::from_dollars(1_500)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn eitc(&self, qualifying_children: u32) -> EitcParameters {
+        let (earned, start, joint) = match qualifying_children {
+            0 => (7_320, 9_160, 15_290),
+            1 => (10_980, 20_130, 26_260),
+            _ => (15_410, 20_130, 26_260),
+        };
+        EitcParameters::new(
+            qualifying_children,
+            Usd::from_dollars(earned),
+            Usd::from_dollars(start),
+            Usd::from_dollars(joint),
+        )
+    }
+
+    fn eitc_investment_income_limit(&self) -> Usd {
+        Usd::from_dollars(10_300)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-36.pdf>
+    fn employer_coverage_affordability_rate(&self) -> Rate {
+        Rate::from_bps(961)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn qbi_threshold(&self, status: FilingStatus) -> Usd {
+        match status {
+            FilingStatus::MarriedFilingJointly => Usd::from_dollars(340_100),
+            _ => Usd::from_dollars(170_050),
+        }
+    }
+
+    fn qbi_phase_in_range(&self, status: FilingStatus) -> Usd {
+        match status {
+            FilingStatus::MarriedFilingJointly => Usd::from_dollars(100_000),
+            _ => Usd::from_dollars(50_000),
+        }
+    }
+
+    fn salt_cap(&self) -> Option<Usd> {
+        Some(Usd::from_dollars(10_000))
+    }
+
+    fn salt_phasedown_start(&self) -> Option<Usd> {
+        None
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-21-61.pdf>
+    fn ira_contribution_limit(&self) -> Usd {
+        Usd::from_dollars(6_000)
+    }
+
+    fn ira_catch_up_contribution(&self) -> Usd {
+        Usd::from_dollars(1_000)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-21-61.pdf>
+    fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_covered_only: bool) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingSeparately => 0,
+            _ if spouse_covered_only => 204_000,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 109_000,
+            Single | HeadOfHousehold => 68_000,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-21-61.pdf>
+    fn elective_deferral_limit(&self) -> Usd {
+        Usd::from_dollars(20_500)
+    }
+
+    /// The higher catch-up at ages 60–63 starts in 2025.
+    fn elective_deferral_catch_up(&self, age: u32) -> Usd {
+        Usd::from_dollars(if age >= 50 { 6_500 } else { 0 })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-25.pdf>
+    fn hsa_contribution_limit(&self, family: bool) -> Usd {
+        Usd::from_dollars(if family { 7_300 } else { 3_650 })
+    }
+
+    /// 58.5 cents, the rate for the first half of the year. The 62.5 cents
+    /// that applied from July 1 is not modelled.
+    ///
+    /// See: <https://www.irs.gov/pub/irs-drop/n-22-03.pdf>
+    fn business_standard_mileage_rate_mills(&self) -> i64 {
+        585
+    }
+
+    fn social_security_wage_base(&self) -> Usd {
+        Usd::from_dollars(147_000)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-prior/p15t--2022.pdf>
+    fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | MarriedFilingSeparately => 12_950,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 25_900,
+            HeadOfHousehold => 19_400,
+        })
+    }
+
+    fn withholding_wage_adjustment(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 12_900,
+            _ => 8_600,
+        })
+    }
+
+    /// 2021 HHS poverty guidelines.
+    ///
+    /// See: <https://www.irs.gov/pub/irs-prior/i8962--2022.pdf>
+    fn poverty_line(&self, region: PovertyRegion, family_size: u32) -> Usd {
+        let (first, each_additional) = match region {
+            PovertyRegion::Contiguous => (12_880, 4_540),
+            PovertyRegion::Alaska => (16_090, 5_680),
+            PovertyRegion::Hawaii => (14_820, 5_220),
+        };
+        let additional = i64::from(family_size.max(1) - 1);
+        Usd::from_dollars(first + each_additional * additional)
+    }
+
+    fn ptc_applicable_figure(&self, fpl_percent: i64) -> Rate {
+        premium_tax_credit::interpolate_applicable_figure(&APPLICABLE_PERCENTAGES, fpl_percent)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-prior/i8962--2022.pdf>
+    fn ptc_repayment_limit(&self, status: FilingStatus, fpl_percent: i64) -> Option<Usd> {
+        let (single, other) = match fpl_percent {
+            ..200 => (325, 650),
+            200..300 => (825, 1_650),
+            300..400 => (1_400, 2_800),
+            _ => return None,
+        };
+        let limit = if status == FilingStatus::Single {
+            single
+        } else {
+            other
+        };
+        Some(Usd::from_dollars(limit))
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-21-45.pdf>
+    fn dependent_gross_income_limit(&self) -> Usd {
+        Usd::from_dollars(4_400)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{DeductionParams, ExemptionParams};
+    use crate::spine::tests::input;
+    use crate::spine::{Key, ReturnInput, compute_spine};
+    use crate::types::Filer;
+
+    const SENIOR: Filer = Filer {
+        is_65_or_older: true,
+        is_blind: false,
+    };
+    const SENIOR_BLIND: Filer = Filer {
+        is_65_or_older: true,
+        is_blind: true,
+    };
+
+    fn params(status: FilingStatus) -> DeductionParams {
+        DeductionParams {
+            filing_status: status,
+            taxpayer: Filer::default(),
+            spouse: None,
+            is_dependent: false,
+            is_dual_status_alien: false,
+            spouse_itemizes: false,
+            earned_income: Usd::ZERO,
+        }
+    }
+
+    // ── Status × box count × dependent matrix ───────────────────────
+
+    crate::rules::standard_deduction_matrix! {
+        rules: Rules2022,
+        dependent_earned_income: 3_000,
+        Single => {
+            boxes: [12_950, 14_700, 16_450],
+            dependent: [3_400, 5_150, 6_900],
+        },
+        HeadOfHousehold => {
+            boxes: [19_400, 21_150, 22_900],
+            dependent: [3_400, 5_150, 6_900],
+        },
+        MarriedFilingJointly => {
+            boxes: [25_900, 27_300, 28_700, 30_100, 31_500],
+            dependent: [3_400, 4_800, 6_200, 7_600, 9_000],
+        },
+        MarriedFilingSeparately => {
+            boxes: [12_950, 14_350, 15_750, 17_150, 18_550],
+            dependent: [3_400, 4_800, 6_200, 7_600, 9_000],
+        },
+        QualifyingSurvivingSpouse => {
+            boxes: [25_900, 27_300, 28_700],
+            dependent: [3_400, 4_800, 6_200],
+        },
+    }
+
+    // ── Dependent: earned income formula ────────────────────────────
+
+    #[test]
+    fn dependent_zero_earned_income_hits_floor() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dependent = true;
+        p.earned_income = Usd::ZERO;
+        // max(0 + 400, 1,150) = 1,150; min(1,150, 12,950) = 1,150
+        assert_eq!(Rules2022.standard_deduction(&p), Usd::from_dollars(1_150));
+    }
+
+    #[test]
+    fn dependent_low_earned_income_hits_floor() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dependent = true;
+        p.earned_income = Usd::from_dollars(500);
+        // max(500 + 400, 1,150) = 1,150; min(1,150, 12,950) = 1,150
+        assert_eq!(Rules2022.standard_deduction(&p), Usd::from_dollars(1_150));
+    }
+
+    #[test]
+    fn dependent_mid_earned_income_uses_formula() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dependent = true;
+        p.earned_income = Usd::from_dollars(5_000);
+        // max(5,000 + 400, 1,150) = 5,400; min(5,400, 12,950) = 5,400
+        assert_eq!(Rules2022.standard_deduction(&p), Usd::from_dollars(5_400));
+    }
+
+    #[test]
+    fn dependent_high_earned_income_capped_at_base() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dependent = true;
+        p.earned_income = Usd::from_dollars(20_000);
+        // max(20,000 + 400, 1,150) = 20,400; min(20,400, 12,950) = 12,950
+        assert_eq!(Rules2022.standard_deduction(&p), Usd::from_dollars(12_950));
+    }
+
+    #[test]
+    fn dependent_with_boxes() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dependent = true;
+        p.taxpayer = SENIOR_BLIND;
+        p.earned_income = Usd::from_dollars(3_000);
+        // base portion: max(3,000 + 400, 1,150) = 3,400
+        // additional: 2 * 1,750 = 3,500
+        assert_eq!(Rules2022.standard_deduction(&p), Usd::from_dollars(6_900));
+    }
+
+    // ── Zero-deduction overrides ────────────────────────────────────
+
+    #[test]
+    fn dual_status_alien_is_zero() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dual_status_alien = true;
+        p.taxpayer = SENIOR;
+        assert_eq!(Rules2022.standard_deduction(&p), Usd::ZERO);
+    }
+
+    #[test]
+    fn mfs_spouse_itemizes_is_zero() {
+        let mut p = params(FilingStatus::MarriedFilingSeparately);
+        p.spouse_itemizes = true;
+        assert_eq!(Rules2022.standard_deduction(&p), Usd::ZERO);
+    }
+
+    #[test]
+    fn no_personal_exemptions() {
+        let p = ExemptionParams {
+            filing_status: FilingStatus::MarriedFilingJointly,
+            agi: Usd::from_dollars(80_000),
+            count: 4,
+        };
+        assert_eq!(Rules2022.exemptions(&p), Usd::ZERO);
+    }
+
+    #[test]
+    fn poverty_line() {
+        assert_eq!(
+            Rules2022.poverty_line(PovertyRegion::Contiguous, 4),
+            Usd::from_dollars(26_500)
+        );
+        assert_eq!(
+            Rules2022.poverty_line(PovertyRegion::Alaska, 1),
+            Usd::from_dollars(16_090)
+        );
+    }
+
+    #[test]
+    fn ptc_repayment_limit() {
+        assert_eq!(
+            Rules2022.ptc_repayment_limit(FilingStatus::HeadOfHousehold, 250),
+            Some(Usd::from_dollars(1_650))
+        );
+        assert_eq!(
+            Rules2022.ptc_repayment_limit(FilingStatus::Single, 150),
+            Some(Usd::from_dollars(325))
+        );
+        assert_eq!(
+            Rules2022.ptc_repayment_limit(FilingStatus::Single, 400),
+            None
+        );
+    }
+
+    #[test]
+    fn qbi_threshold() {
+        assert_eq!(
+            Rules2022.qbi_threshold(FilingStatus::MarriedFilingJointly),
+            Usd::from_dollars(340_100)
+        );
+        assert_eq!(
+            Rules2022.qbi_threshold(FilingStatus::HeadOfHousehold),
+            Usd::from_dollars(170_050)
+        );
+    }
+
+    #[test]
+    fn computes_2022_return_on_rate_schedule() {
+        assert!(Rules2022.uses_rate_schedule());
+        let inp = ReturnInput {
+            tax_year: 2022,
+            ..input(60_000, 6_000)
+        };
+        let ledger = compute_spine(&Rules2022, &inp).unwrap();
+        // 60,000 - 12,950 = 47,050 taxable: 10% of 10,275, 12% of 31,500
+        // and 22% of 5,275.
+        assert_eq!(ledger[&Key::TaxableIncome], Usd::from_dollars(47_050));
+        assert_eq!(ledger[&Key::TotalTax], Usd::from_dollars(5_968));
+    }
+
+    #[test]
+    fn eitc_maximum_credits() {
+        let maximums: Vec<Usd> = (0..=3).map(|n| Rules2022.eitc(n).maximum_credit).collect();
+        assert_eq!(
+            maximums,
+            [560, 3_733, 6_164, 6_935].map(Usd::from_dollars).to_vec()
+        );
+    }
+}
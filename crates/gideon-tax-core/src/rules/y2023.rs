@@ -0,0 +1,409 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::premium_tax_credit::{self, PovertyRegion};
+use crate::rules::{APPLICABLE_PERCENTAGES, BRACKET_RATES, Bracket, EitcParameters, TaxYearRules};
+use crate::{Rate, Usd};
+
+/// IRS-published parameters for tax year 2023 (filed in 2024).
+///
+/// See: <https://www.irs.gov/pub/irs-prior/i1040gi--2023.pdf>
+pub struct Rules2023;
+
+impl TaxYearRules for Rules2023 {
+    fn calendar_year(&self) -> i32 {
+        2023
+    }
+
+    fn single_mfs_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(13_850)
+    }
+
+    fn mfj_qss_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(27_700)
+    }
+
+    fn hoh_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(20_800)
+    }
+
+    fn additional_deduction_unmarried(&self) -> Usd {
+        Usd::from_dollars(1_850)
+    }
+
+    fn additional_deduction_married(&self) -> Usd {
+        Usd::from_dollars(1_500)
+    }
+
+    fn dependent_earned_income_addition(&self) -> Usd {
+        Usd::from_dollars(400)
+    }
+
+    fn dependent_minimum_deduction(&self) -> Usd {
+        Usd::from_dollars(1_250)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn ordinary_brackets(&self, status: FilingStatus) -> [Bracket; 7] {
+        use FilingStatus::*;
+        let floors = match status {
+            Single => [0, 11_000, 44_725, 95_375, 182_100, 231_250, 578_125],
+            MarriedFilingJointly | QualifyingSurvivingSpouse => {
+                [0, 22_000, 89_450, 190_750, 364_200, 462_500, 693_750]
+            }
+            MarriedFilingSeparately => [0, 11_000, 44_725, 95_375, 182_100, 231_250, 346_875],
+            HeadOfHousehold => [0, 15_700, 59_850, 95_350, 182_100, 231_250, 578_100],
+        };
+        std::array::from_fn(|i| Bracket {
+            floor: Usd::from_dollars(floors[i]),
+            rate: BRACKET_RATES[i],
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn capital_gain_rate_thresholds(&self, status: FilingStatus) -> [Usd; 2] {
+        use FilingStatus::*;
+        let (fifteen, twenty) = match status {
+            Single => (44_625, 492_300),
+            MarriedFilingJointly | QualifyingSurvivingSpouse => (89_250, 553_850),
+            MarriedFilingSeparately => (44_625, 276_900),
+            HeadOfHousehold => (59_750, 523_050),
+        };
+        [Usd::from_dollars(fifteen), Usd::from_dollars(twenty)]
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn amt_exemption(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | HeadOfHousehold => 81_300,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 126_500,
+            MarriedFilingSeparately => 63_250,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 1_156_300,
+            _ => 578_150,
+        })
+    }
+
+    fn amt_exemption_phaseout_rate(&self) -> Rate {
+        Rate::from_percent(25)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn amt_rate_threshold(&self, status: FilingStatus) -> Usd {
+        if status == FilingStatus::MarriedFilingSeparately {
+            Usd::from_dollars(110_350)
+        } else {
+            Usd::from_dollars(220_700)
+        }
+    }
+
+    fn child_tax_credit_amount(&self) -> Usd {
+        Usd::from_dollars(2_000)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn additional_child_tax_credit_limit(&self) -> Usd {
+        Usd::from_dollars(1_600)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn eitc(&self, qualifying_children: u32) -> EitcParameters {
+        let (earned, start, joint) = match qualifying_children {
+            0 => (7_840, 9_800, 16_370),
+            1 => (11_750, 21_560, 28_120),
+            _ => (16_510, 21_560, 28_120),
+        };
+        EitcParameters::new(
+            qualifying_children,
+            Usd::from_dollars(earned),
+            Usd::from_dollars(start),
+            Usd::from_dollars(joint),
+        )
+    }
+
+    fn eitc_investment_income_limit(&self) -> Usd {
+        Usd::from_dollars(11_000)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-34.pdf>
+    fn employer_coverage_affordability_rate(&self) -> Rate {
+        Rate::from_bps(912)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn qbi_threshold(&self, status: FilingStatus) -> Usd {
+        match status {
+            FilingStatus::MarriedFilingJointly => Usd::from_dollars(364_200),
+            _ => Usd::from_dollars(182_100),
+        }
+    }
+
+    fn qbi_phase_in_range(&self, status: FilingStatus) -> Usd {
+        match status {
+            FilingStatus::MarriedFilingJointly => Usd::from_dollars(100_000),
+            _ => Usd::from_dollars(50_000),
+        }
+    }
+
+    fn salt_cap(&self) -> Option<Usd> {
+        Some(Usd::from_dollars(10_000))
+    }
+
+    fn salt_phasedown_start(&self) -> Option<Usd> {
+        None
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-22-55.pdf>
+    fn ira_contribution_limit(&self) -> Usd {
+        Usd::from_dollars(6_500)
+    }
+
+    fn ira_catch_up_contribution(&self) -> Usd {
+        Usd::from_dollars(1_000)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-22-55.pdf>
+    fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_covered_only: bool) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingSeparately => 0,
+            _ if spouse_covered_only => 218_000,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 116_000,
+            Single | HeadOfHousehold => 73_000,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-22-55.pdf>
+    fn elective_deferral_limit(&self) -> Usd {
+        Usd::from_dollars(22_500)
+    }
+
+    /// The higher catch-up at ages 60–63 starts in 2025.
+    fn elective_deferral_catch_up(&self, age: u32) -> Usd {
+        Usd::from_dollars(if age >= 50 { 7_500 } else { 0 })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-24.pdf>
+    fn hsa_contribution_limit(&self, family: bool) -> Usd {
+        Usd::from_dollars(if family { 7_750 } else { 3_850 })
+    }
+
+    /// 65.5 cents.
+    ///
+    /// See: <https://www.irs.gov/pub/irs-drop/n-23-03.pdf>
+    fn business_standard_mileage_rate_mills(&self) -> i64 {
+        655
+    }
+
+    fn social_security_wage_base(&self) -> Usd {
+        Usd::from_dollars(160_200)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-prior/p15t--2023.pdf>
+    fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | MarriedFilingSeparately => 13_850,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 27_700,
+            HeadOfHousehold => 20_800,
+        })
+    }
+
+    fn withholding_wage_adjustment(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 12_900,
+            _ => 8_600,
+        })
+    }
+
+    /// 2022 HHS poverty guidelines.
+    ///
+    /// See: <https://www.irs.gov/pub/irs-prior/i8962--2023.pdf>
+    fn poverty_line(&self, region: PovertyRegion, family_size: u32) -> Usd {
+        let (first, each_additional) = match region {
+            PovertyRegion::Contiguous => (13_590, 4_720),
+            PovertyRegion::Alaska => (16_990, 5_900),
+            PovertyRegion::Hawaii => (15_630, 5_430),
+        };
+        let additional = i64::from(family_size.max(1) - 1);
+        Usd::from_dollars(first + each_additional * additional)
+    }
+
+    fn ptc_applicable_figure(&self, fpl_percent: i64) -> Rate {
+        premium_tax_credit::interpolate_applicable_figure(&APPLICABLE_PERCENTAGES, fpl_percent)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-34.pdf>
+    fn ptc_repayment_limit(&self, status: FilingStatus, fpl_percent: i64) -> Option<Usd> {
+        let (single, other) = match fpl_percent {
+            ..200 => (350, 700),
+            200..300 => (900, 1_800),
+            300..400 => (1_500, 3_000),
+            _ => return None,
+        };
+        let limit = if status == FilingStatus::Single {
+            single
+        } else {
+            other
+        };
+        Some(Usd::from_dollars(limit))
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-22-38.pdf>
+    fn dependent_gross_income_limit(&self) -> Usd {
+        Usd::from_dollars(4_700)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::DeductionParams;
+    use crate::spine::tests::input;
+    use crate::spine::{Key, ReturnInput, compute_spine};
+    use crate::types::Filer;
+
+    const SENIOR_BLIND: Filer = Filer {
+        is_65_or_older: true,
+        is_blind: true,
+    };
+
+    fn params(status: FilingStatus) -> DeductionParams {
+        DeductionParams {
+            filing_status: status,
+            taxpayer: Filer::default(),
+            spouse: None,
+            is_dependent: false,
+            is_dual_status_alien: false,
+            spouse_itemizes: false,
+            earned_income: Usd::ZERO,
+        }
+    }
+
+    // ── Status × box count × dependent matrix ───────────────────────
+
+    crate::rules::standard_deduction_matrix! {
+        rules: Rules2023,
+        dependent_earned_income: 3_000,
+        Single => {
+            boxes: [13_850, 15_700, 17_550],
+            dependent: [3_400, 5_250, 7_100],
+        },
+        HeadOfHousehold => {
+            boxes: [20_800, 22_650, 24_500],
+            dependent: [3_400, 5_250, 7_100],
+        },
+        MarriedFilingJointly => {
+            boxes: [27_700, 29_200, 30_700, 32_200, 33_700],
+            dependent: [3_400, 4_900, 6_400, 7_900, 9_400],
+        },
+        MarriedFilingSeparately => {
+            boxes: [13_850, 15_350, 16_850, 18_350, 19_850],
+            dependent: [3_400, 4_900, 6_400, 7_900, 9_400],
+        },
+        QualifyingSurvivingSpouse => {
+            boxes: [27_700, 29_200, 30_700],
+            dependent: [3_400, 4_900, 6_400],
+        },
+    }
+
+    // ── Dependent: earned income formula ────────────────────────────
+
+    #[test]
+    fn dependent_low_earned_income_hits_floor() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dependent = true;
+        p.earned_income = Usd::from_dollars(500);
+        // max(500 + 400, 1,250) = 1,250; min(1,250, 13,850) = 1,250
+        assert_eq!(Rules2023.standard_deduction(&p), Usd::from_dollars(1_250));
+    }
+
+    #[test]
+    fn dependent_high_earned_income_capped_at_base() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dependent = true;
+        p.earned_income = Usd::from_dollars(20_000);
+        // max(20,000 + 400, 1,250) = 20,400; min(20,400, 13,850) = 13,850
+        assert_eq!(Rules2023.standard_deduction(&p), Usd::from_dollars(13_850));
+    }
+
+    #[test]
+    fn dependent_with_boxes() {
+        let mut p = params(FilingStatus::Single);
+        p.is_dependent = true;
+        p.taxpayer = SENIOR_BLIND;
+        p.earned_income = Usd::from_dollars(3_000);
+        // base portion: max(3,000 + 400, 1,250) = 3,400
+        // additional: 2 * 1,850 = 3,700
+        assert_eq!(Rules2023.standard_deduction(&p), Usd::from_dollars(7_100));
+    }
+
+    #[test]
+    fn poverty_line() {
+        assert_eq!(
+            Rules2023.poverty_line(PovertyRegion::Contiguous, 4),
+            Usd::from_dollars(27_750)
+        );
+        assert_eq!(
+            Rules2023.poverty_line(PovertyRegion::Alaska, 1),
+            Usd::from_dollars(16_990)
+        );
+    }
+
+    #[test]
+    fn ptc_repayment_limit() {
+        assert_eq!(
+            Rules2023.ptc_repayment_limit(FilingStatus::HeadOfHousehold, 250),
+            Some(Usd::from_dollars(1_800))
+        );
+        assert_eq!(
+            Rules2023.ptc_repayment_limit(FilingStatus::Single, 150),
+            Some(Usd::from_dollars(350))
+        );
+        assert_eq!(
+            Rules2023.ptc_repayment_limit(FilingStatus::Single, 400),
+            None
+        );
+    }
+
+    #[test]
+    fn qbi_threshold() {
+        assert_eq!(
+            Rules2023.qbi_threshold(FilingStatus::MarriedFilingJointly),
+            Usd::from_dollars(364_200)
+        );
+        assert_eq!(
+            Rules2023.qbi_threshold(FilingStatus::HeadOfHousehold),
+            Usd::from_dollars(182_100)
+        );
+    }
+
+    #[test]
+    fn computes_2023_return() {
+        let inp = ReturnInput {
+            tax_year: 2023,
+            ..input(60_000, 6_000)
+        };
+        let ledger = compute_spine(&Rules2023, &inp).unwrap();
+        // 60,000 - 13,850 = 46,150 taxable, taxed at the 46,175 midpoint of
+        // its tax table row: 1,100 + 4,047 + 22% of 1,450.
+        assert_eq!(ledger[&Key::TaxableIncome], Usd::from_dollars(46_150));
+        assert_eq!(ledger[&Key::TotalTax], Usd::from_dollars(5_466));
+    }
+
+    #[test]
+    fn eitc_maximum_credits() {
+        let maximums: Vec<Usd> = (0..=3).map(|n| Rules2023.eitc(n).maximum_credit).collect();
+        assert_eq!(
+            maximums,
+            [600, 3_995, 6_604, 7_430].map(Usd::from_dollars).to_vec()
+        );
+    }
+}
@@ -1,7 +1,8 @@
-use us_tax_brackets::TaxYear;
+use us_tax_brackets::FilingStatus;
 
-use crate::Usd;
-use crate::rules::TaxYearRules;
+use crate::premium_tax_credit::{self, PovertyRegion};
+use crate::rules::{APPLICABLE_PERCENTAGES, BRACKET_RATES, Bracket, EitcParameters, TaxYearRules};
+use crate::{Rate, Usd};
 
 /// IRS-published parameters for tax year 2025 (filed in 2026).
 ///
@@ -9,8 +10,8 @@ use crate::rules::TaxYearRules;
 pub struct Rules2025;
 
 impl TaxYearRules for Rules2025 {
-    fn year(&self) -> TaxYear {
-        TaxYear::Y2025
+    fn calendar_year(&self) -> i32 {
+        2025
     }
 
     fn single_mfs_typical_standard_deduction(&self) -> Usd {
@@ -40,20 +41,237 @@ impl TaxYearRules for Rules2025 {
     fn dependent_minimum_deduction(&self) -> Usd {
         Usd::from_dollars(1_350)
     }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn ordinary_brackets(&self, status: FilingStatus) -> [Bracket; 7] {
+        use FilingStatus::*;
+        let floors = match status {
+            Single => [0, 11_925, 48_475, 103_350, 197_300, 250_525, 626_350],
+            MarriedFilingJointly | QualifyingSurvivingSpouse => {
+                [0, 23_850, 96_950, 206_700, 394_600, 501_050, 751_600]
+            }
+            MarriedFilingSeparately => [0, 11_925, 48_475, 103_350, 197_300, 250_525, 375_800],
+            HeadOfHousehold => [0, 17_000, 64_850, 103_350, 197_300, 250_500, 626_350],
+        };
+        std::array::from_fn(|i| Bracket {
+            floor: Usd::from_dollars(floors[i]),
+            rate: BRACKET_RATES[i],
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn capital_gain_rate_thresholds(&self, status: FilingStatus) -> [Usd; 2] {
+        use FilingStatus::*;
+        let (fifteen, twenty) = match status {
+            Single => (48_350, 533_400),
+            MarriedFilingJointly | QualifyingSurvivingSpouse => (96_700, 600_050),
+            MarriedFilingSeparately => (48_350, 300_000),
+            HeadOfHousehold => (64_750, 566_700),
+        };
+        [Usd::from_dollars(fifteen), Usd::from_dollars(twenty)]
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn amt_exemption(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | HeadOfHousehold => 88_100,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 137_000,
+            MarriedFilingSeparately => 68_500,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 1_252_700,
+            _ => 626_350,
+        })
+    }
+
+    fn amt_exemption_phaseout_rate(&self) -> Rate {
+        Rate::from_percent(25)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn amt_rate_threshold(&self, status: FilingStatus) -> Usd {
+        if status == FilingStatus::MarriedFilingSeparately {
+            Usd::from_dollars(119_550)
+        } else {
+            Usd::from_dollars(239_100)
+        }
+    }
+
+    fn child_tax_credit_amount(&self) -> Usd {
+        Usd::from_dollars(2_200)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn additional_child_tax_credit_limit(&self) -> Usd {
+        Usd::from_dollars(1_700)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn eitc(&self, qualifying_children: u32) -> EitcParameters {
+        let (earned, start, joint) = match qualifying_children {
+            0 => (8_490, 10_620, 17_730),
+            1 => (12_730, 23_350, 30_470),
+            _ => (17_880, 23_350, 30_470),
+        };
+        EitcParameters::new(
+            qualifying_children,
+            Usd::from_dollars(earned),
+            Usd::from_dollars(start),
+            Usd::from_dollars(joint),
+        )
+    }
+
+    fn eitc_investment_income_limit(&self) -> Usd {
+        Usd::from_dollars(11_950)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-35.pdf>
+    fn employer_coverage_affordability_rate(&self) -> Rate {
+        Rate::from_bps(902)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn qbi_threshold(&self, status: FilingStatus) -> Usd {
+        match status {
+            FilingStatus::MarriedFilingJointly => Usd::from_dollars(394_600),
+            _ => Usd::from_dollars(197_300),
+        }
+    }
+
+    fn qbi_phase_in_range(&self, status: FilingStatus) -> Usd {
+        match status {
+            FilingStatus::MarriedFilingJointly => Usd::from_dollars(100_000),
+            _ => Usd::from_dollars(50_000),
+        }
+    }
+
+    /// Raised from $10,000 by the One Big Beautiful Bill Act.
+    fn salt_cap(&self) -> Option<Usd> {
+        Some(Usd::from_dollars(40_000))
+    }
+
+    fn salt_phasedown_start(&self) -> Option<Usd> {
+        Some(Usd::from_dollars(500_000))
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-24-80.pdf>
+    fn ira_contribution_limit(&self) -> Usd {
+        Usd::from_dollars(7_000)
+    }
+
+    fn ira_catch_up_contribution(&self) -> Usd {
+        Usd::from_dollars(1_000)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-24-80.pdf>
+    fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_covered_only: bool) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingSeparately => 0,
+            _ if spouse_covered_only => 236_000,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 126_000,
+            Single | HeadOfHousehold => 79_000,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/n-24-80.pdf>
+    fn elective_deferral_limit(&self) -> Usd {
+        Usd::from_dollars(23_500)
+    }
+
+    fn elective_deferral_catch_up(&self, age: u32) -> Usd {
+        Usd::from_dollars(match age {
+            60..=63 => 11_250,
+            50.. => 7_500,
+            _ => 0,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-25.pdf>
+    fn hsa_contribution_limit(&self, family: bool) -> Usd {
+        Usd::from_dollars(if family { 8_550 } else { 4_300 })
+    }
+
+    fn business_standard_mileage_rate_mills(&self) -> i64 {
+        700
+    }
+
+    fn social_security_wage_base(&self) -> Usd {
+        Usd::from_dollars(176_100)
+    }
+
+    /// Publication 15-T was issued before the One Big Beautiful Bill Act
+    /// raised the 2025 standard deduction, and the tables were not revised.
+    ///
+    /// See: <https://www.irs.gov/pub/irs-pdf/p15t.pdf>
+    fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | MarriedFilingSeparately => 15_000,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 30_000,
+            HeadOfHousehold => 22_500,
+        })
+    }
+
+    fn withholding_wage_adjustment(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 12_900,
+            _ => 8_600,
+        })
+    }
+
+    /// 2024 HHS poverty guidelines.
+    ///
+    /// See: <https://www.irs.gov/instructions/i8962>
+    fn poverty_line(&self, region: PovertyRegion, family_size: u32) -> Usd {
+        let (first, each_additional) = match region {
+            PovertyRegion::Contiguous => (15_060, 5_380),
+            PovertyRegion::Alaska => (18_810, 6_730),
+            PovertyRegion::Hawaii => (17_310, 6_190),
+        };
+        let additional = i64::from(family_size.max(1) - 1);
+        Usd::from_dollars(first + each_additional * additional)
+    }
+
+    fn ptc_applicable_figure(&self, fpl_percent: i64) -> Rate {
+        premium_tax_credit::interpolate_applicable_figure(&APPLICABLE_PERCENTAGES, fpl_percent)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-35.pdf>
+    fn ptc_repayment_limit(&self, status: FilingStatus, fpl_percent: i64) -> Option<Usd> {
+        let (single, other) = match fpl_percent {
+            ..200 => (375, 750),
+            200..300 => (975, 1_950),
+            300..400 => (1_625, 3_250),
+            _ => return None,
+        };
+        let limit = if status == FilingStatus::Single {
+            single
+        } else {
+            other
+        };
+        Some(Usd::from_dollars(limit))
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-24-40.pdf>
+    fn dependent_gross_income_limit(&self) -> Usd {
+        Usd::from_dollars(5_200)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use us_tax_brackets::FilingStatus;
-
     use super::*;
-    use crate::rules::DeductionParams;
+    use crate::rules::{DeductionParams, ExemptionParams};
     use crate::types::Filer;
 
-    const BLIND: Filer = Filer {
-        is_65_or_older: false,
-        is_blind: true,
-    };
     const SENIOR: Filer = Filer {
         is_65_or_older: true,
         is_blind: false,
@@ -75,80 +293,31 @@ mod tests {
         }
     }
 
-    // ── Non-dependent, no boxes ─────────────────────────────────────
-
-    #[test]
-    fn base_single() {
-        let d = Rules2025.standard_deduction(&params(FilingStatus::Single));
-        assert_eq!(d, Usd::from_dollars(15_750));
-    }
-
-    #[test]
-    fn base_mfj() {
-        let d = Rules2025.standard_deduction(&params(FilingStatus::MarriedFilingJointly));
-        assert_eq!(d, Usd::from_dollars(31_500));
-    }
-
-    #[test]
-    fn base_hoh() {
-        let d = Rules2025.standard_deduction(&params(FilingStatus::HeadOfHousehold));
-        assert_eq!(d, Usd::from_dollars(23_625));
-    }
-
-    #[test]
-    fn base_mfs() {
-        let d = Rules2025.standard_deduction(&params(FilingStatus::MarriedFilingSeparately));
-        assert_eq!(d, Usd::from_dollars(15_750));
-    }
-
-    #[test]
-    fn base_qss() {
-        let d = Rules2025.standard_deduction(&params(FilingStatus::QualifyingSurvivingSpouse));
-        assert_eq!(d, Usd::from_dollars(31_500));
-    }
-
-    // ── Non-dependent, with boxes ───────────────────────────────────
-
-    #[test]
-    fn single_senior() {
-        let mut p = params(FilingStatus::Single);
-        p.taxpayer = SENIOR;
-        // 15,750 + 1 * 2,000 = 17,750
-        assert_eq!(Rules2025.standard_deduction(&p), Usd::from_dollars(17_750));
-    }
-
-    #[test]
-    fn single_senior_blind() {
-        let mut p = params(FilingStatus::Single);
-        p.taxpayer = SENIOR_BLIND;
-        // 15,750 + 2 * 2,000 = 19,750
-        assert_eq!(Rules2025.standard_deduction(&p), Usd::from_dollars(19_750));
-    }
-
-    #[test]
-    fn mfj_both_senior() {
-        let mut p = params(FilingStatus::MarriedFilingJointly);
-        p.taxpayer = SENIOR;
-        p.spouse = Some(SENIOR);
-        // 31,500 + 2 * 1,600 = 34,700
-        assert_eq!(Rules2025.standard_deduction(&p), Usd::from_dollars(34_700));
-    }
-
-    #[test]
-    fn mfj_all_four_boxes() {
-        let mut p = params(FilingStatus::MarriedFilingJointly);
-        p.taxpayer = SENIOR_BLIND;
-        p.spouse = Some(SENIOR_BLIND);
-        // 31,500 + 4 * 1,600 = 37,900
-        assert_eq!(Rules2025.standard_deduction(&p), Usd::from_dollars(37_900));
-    }
-
-    #[test]
-    fn hoh_blind() {
-        let mut p = params(FilingStatus::HeadOfHousehold);
-        p.taxpayer = BLIND;
-        // 23,625 + 1 * 2,000 = 25,625
-        assert_eq!(Rules2025.standard_deduction(&p), Usd::from_dollars(25_625));
+    // ── Status × box count × dependent matrix ───────────────────────
+
+    crate::rules::standard_deduction_matrix! {
+        rules: Rules2025,
+        dependent_earned_income: 3_000,
+        Single => {
+            boxes: [15_750, 17_750, 19_750],
+            dependent: [3_450, 5_450, 7_450],
+        },
+        HeadOfHousehold => {
+            boxes: [23_625, 25_625, 27_625],
+            dependent: [3_450, 5_450, 7_450],
+        },
+        MarriedFilingJointly => {
+            boxes: [31_500, 33_100, 34_700, 36_300, 37_900],
+            dependent: [3_450, 5_050, 6_650, 8_250, 9_850],
+        },
+        MarriedFilingSeparately => {
+            boxes: [15_750, 17_350, 18_950, 20_550, 22_150],
+            dependent: [3_450, 5_050, 6_650, 8_250, 9_850],
+        },
+        QualifyingSurvivingSpouse => {
+            boxes: [31_500, 33_100, 34_700],
+            dependent: [3_450, 5_050, 6_650],
+        },
     }
 
     // ── Dependent: earned income formula ────────────────────────────
@@ -217,4 +386,70 @@ mod tests {
         p.spouse_itemizes = true;
         assert_eq!(Rules2025.standard_deduction(&p), Usd::ZERO);
     }
+
+    // ── Exemptions (suspended through 2025) ─────────────────────────
+
+    #[test]
+    fn no_personal_exemptions() {
+        let p = ExemptionParams {
+            filing_status: FilingStatus::MarriedFilingJointly,
+            agi: Usd::from_dollars(80_000),
+            count: 4,
+        };
+        assert_eq!(Rules2025.exemptions(&p), Usd::ZERO);
+    }
+
+    #[test]
+    fn poverty_line() {
+        assert_eq!(
+            Rules2025.poverty_line(PovertyRegion::Contiguous, 4),
+            Usd::from_dollars(31_200)
+        );
+        assert_eq!(
+            Rules2025.poverty_line(PovertyRegion::Alaska, 1),
+            Usd::from_dollars(18_810)
+        );
+    }
+
+    #[test]
+    fn ptc_applicable_figure() {
+        assert_eq!(Rules2025.ptc_applicable_figure(140), Rate::ZERO);
+        assert_eq!(Rules2025.ptc_applicable_figure(175), Rate::from_percent(1));
+        assert_eq!(Rules2025.ptc_applicable_figure(350), Rate::from_bps(725));
+        assert_eq!(Rules2025.ptc_applicable_figure(500), Rate::from_bps(850));
+    }
+
+    #[test]
+    fn ptc_repayment_limit() {
+        assert_eq!(
+            Rules2025.ptc_repayment_limit(FilingStatus::HeadOfHousehold, 250),
+            Some(Usd::from_dollars(1_950))
+        );
+        assert_eq!(
+            Rules2025.ptc_repayment_limit(FilingStatus::Single, 400),
+            None
+        );
+    }
+
+    #[test]
+    fn qbi_threshold() {
+        assert_eq!(
+            Rules2025.qbi_threshold(FilingStatus::MarriedFilingJointly),
+            Usd::from_dollars(394_600)
+        );
+        assert_eq!(
+            Rules2025.qbi_threshold(FilingStatus::QualifyingSurvivingSpouse),
+            Usd::from_dollars(197_300)
+        );
+    }
+
+    #[test]
+    fn eitc_maximum_credits() {
+        let maximums: Vec<Usd> = (0..=3).map(|n| Rules2025.eitc(n).maximum_credit).collect();
+        assert_eq!(
+            maximums,
+            [649, 4_328, 7_152, 8_046].map(Usd::from_dollars).to_vec()
+        );
+        assert_eq!(Rules2025.eitc(5).phaseout_rate, Rate::from_bps(2_106));
+    }
 }
@@ -0,0 +1,449 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::premium_tax_credit::{self, ApplicablePercentageBand, PovertyRegion};
+use crate::rules::indexing::CostOfLivingAdjustment;
+use crate::rules::sunset::TcjaSunset;
+use crate::rules::{BRACKET_RATES, Bracket, EitcParameters, TaxYearRules};
+use crate::{Rate, Usd};
+
+/// Applicable percentages for 2026, after the temporary 2021–2025 table
+/// expired (Rev. Proc. 2025-25).
+///
+/// Above 400% of the poverty line no credit is allowed. The top band's
+/// 100% makes the required contribution the whole household income, so
+/// no premium is left for the credit.
+const APPLICABLE_PERCENTAGES: [ApplicablePercentageBand; 7] = [
+    ApplicablePercentageBand {
+        up_to: Some(133),
+        initial: Rate::from_bps(210),
+        final_: Rate::from_bps(210),
+    },
+    ApplicablePercentageBand {
+        up_to: Some(150),
+        initial: Rate::from_bps(314),
+        final_: Rate::from_bps(419),
+    },
+    ApplicablePercentageBand {
+        up_to: Some(200),
+        initial: Rate::from_bps(419),
+        final_: Rate::from_bps(660),
+    },
+    ApplicablePercentageBand {
+        up_to: Some(250),
+        initial: Rate::from_bps(660),
+        final_: Rate::from_bps(844),
+    },
+    ApplicablePercentageBand {
+        up_to: Some(300),
+        initial: Rate::from_bps(844),
+        final_: Rate::from_bps(996),
+    },
+    ApplicablePercentageBand {
+        up_to: Some(400),
+        initial: Rate::from_bps(996),
+        final_: Rate::from_bps(996),
+    },
+    ApplicablePercentageBand {
+        up_to: None,
+        initial: Rate::from_percent(100),
+        final_: Rate::from_percent(100),
+    },
+];
+
+/// Parameters for tax year 2026 (filed in 2027) as announced ahead of the
+/// year, for planning before final forms and instructions are published.
+///
+/// `us_tax_brackets` has no 2026 tables, so the spine taxes it with the
+/// 2026 rate schedule instead of the tax table, and returns under $100,000
+/// differ from the eventual table by a few dollars.
+///
+/// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+pub struct Rules2026Projected;
+
+impl TaxYearRules for Rules2026Projected {
+    fn calendar_year(&self) -> i32 {
+        2026
+    }
+
+    fn is_projection(&self) -> bool {
+        true
+    }
+
+    fn single_mfs_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(16_100)
+    }
+
+    fn mfj_qss_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(32_200)
+    }
+
+    fn hoh_typical_standard_deduction(&self) -> Usd {
+        Usd::from_dollars(24_150)
+    }
+
+    fn additional_deduction_unmarried(&self) -> Usd {
+        Usd::from_dollars(2_050)
+    }
+
+    fn additional_deduction_married(&self) -> Usd {
+        Usd::from_dollars(1_650)
+    }
+
+    fn dependent_earned_income_addition(&self) -> Usd {
+        Usd::from_dollars(450)
+    }
+
+    fn dependent_minimum_deduction(&self) -> Usd {
+        Usd::from_dollars(1_350)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn ordinary_brackets(&self, status: FilingStatus) -> [Bracket; 7] {
+        use FilingStatus::*;
+        let floors = match status {
+            Single => [0, 12_400, 50_400, 105_700, 201_775, 256_225, 640_600],
+            MarriedFilingJointly | QualifyingSurvivingSpouse => {
+                [0, 24_800, 100_800, 211_400, 403_550, 512_450, 768_700]
+            }
+            MarriedFilingSeparately => [0, 12_400, 50_400, 105_700, 201_775, 256_225, 384_350],
+            HeadOfHousehold => [0, 17_700, 67_450, 105_700, 201_750, 256_200, 640_600],
+        };
+        std::array::from_fn(|i| Bracket {
+            floor: Usd::from_dollars(floors[i]),
+            rate: BRACKET_RATES[i],
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn capital_gain_rate_thresholds(&self, status: FilingStatus) -> [Usd; 2] {
+        use FilingStatus::*;
+        let (fifteen, twenty) = match status {
+            Single => (49_450, 545_500),
+            MarriedFilingJointly | QualifyingSurvivingSpouse => (98_900, 613_700),
+            MarriedFilingSeparately => (49_450, 306_850),
+            HeadOfHousehold => (66_200, 579_600),
+        };
+        [Usd::from_dollars(fifteen), Usd::from_dollars(twenty)]
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn amt_exemption(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | HeadOfHousehold => 90_100,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 140_200,
+            MarriedFilingSeparately => 70_100,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 1_000_000,
+            _ => 500_000,
+        })
+    }
+
+    /// Doubled from 25% by the One Big Beautiful Bill Act, §70107.
+    fn amt_exemption_phaseout_rate(&self) -> Rate {
+        Rate::from_percent(50)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn amt_rate_threshold(&self, status: FilingStatus) -> Usd {
+        if status == FilingStatus::MarriedFilingSeparately {
+            Usd::from_dollars(122_250)
+        } else {
+            Usd::from_dollars(244_500)
+        }
+    }
+
+    fn child_tax_credit_amount(&self) -> Usd {
+        Usd::from_dollars(2_200)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn additional_child_tax_credit_limit(&self) -> Usd {
+        Usd::from_dollars(1_700)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn eitc(&self, qualifying_children: u32) -> EitcParameters {
+        let (earned, start, joint) = match qualifying_children {
+            0 => (8_680, 10_860, 18_140),
+            1 => (13_020, 23_890, 31_160),
+            _ => (18_290, 23_890, 31_160),
+        };
+        EitcParameters::new(
+            qualifying_children,
+            Usd::from_dollars(earned),
+            Usd::from_dollars(start),
+            Usd::from_dollars(joint),
+        )
+    }
+
+    fn eitc_investment_income_limit(&self) -> Usd {
+        Usd::from_dollars(12_200)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-25.pdf>
+    fn employer_coverage_affordability_rate(&self) -> Rate {
+        Rate::from_bps(996)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn qbi_threshold(&self, status: FilingStatus) -> Usd {
+        match status {
+            FilingStatus::MarriedFilingJointly => Usd::from_dollars(403_500),
+            _ => Usd::from_dollars(201_750),
+        }
+    }
+
+    fn qbi_phase_in_range(&self, status: FilingStatus) -> Usd {
+        match status {
+            FilingStatus::MarriedFilingJointly => Usd::from_dollars(150_000),
+            _ => Usd::from_dollars(75_000),
+        }
+    }
+
+    /// The 2025 amounts increased by 1% (IRC §164(b)(7)).
+    fn salt_cap(&self) -> Option<Usd> {
+        Some(Usd::from_dollars(40_400))
+    }
+
+    fn salt_phasedown_start(&self) -> Option<Usd> {
+        Some(Usd::from_dollars(505_000))
+    }
+
+    fn ira_contribution_limit(&self) -> Usd {
+        Usd::from_dollars(7_500)
+    }
+
+    /// Indexed for inflation from 2024 (IRC §219(b)(5)(C)(iii)).
+    fn ira_catch_up_contribution(&self) -> Usd {
+        Usd::from_dollars(1_100)
+    }
+
+    fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_covered_only: bool) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingSeparately => 0,
+            _ if spouse_covered_only => 242_000,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 129_000,
+            Single | HeadOfHousehold => 81_000,
+        })
+    }
+
+    fn elective_deferral_limit(&self) -> Usd {
+        Usd::from_dollars(24_500)
+    }
+
+    fn elective_deferral_catch_up(&self, age: u32) -> Usd {
+        Usd::from_dollars(match age {
+            60..=63 => 11_250,
+            50.. => 8_000,
+            _ => 0,
+        })
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-19.pdf>
+    fn hsa_contribution_limit(&self, family: bool) -> Usd {
+        Usd::from_dollars(if family { 8_750 } else { 4_400 })
+    }
+
+    /// The announced rate is 72.5 cents.
+    fn business_standard_mileage_rate_mills(&self) -> i64 {
+        725
+    }
+
+    fn social_security_wage_base(&self) -> Usd {
+        Usd::from_dollars(184_500)
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-dft/p15t--dft.pdf>
+    fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | MarriedFilingSeparately => 16_100,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 32_200,
+            HeadOfHousehold => 24_150,
+        })
+    }
+
+    fn withholding_wage_adjustment(&self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 12_900,
+            _ => 8_600,
+        })
+    }
+
+    /// 2025 HHS poverty guidelines.
+    fn poverty_line(&self, region: PovertyRegion, family_size: u32) -> Usd {
+        let (first, each_additional) = match region {
+            PovertyRegion::Contiguous => (15_650, 5_500),
+            PovertyRegion::Alaska => (19_550, 6_880),
+            PovertyRegion::Hawaii => (17_990, 6_330),
+        };
+        let additional = i64::from(family_size.max(1) - 1);
+        Usd::from_dollars(first + each_additional * additional)
+    }
+
+    fn ptc_applicable_figure(&self, fpl_percent: i64) -> Rate {
+        premium_tax_credit::interpolate_applicable_figure(&APPLICABLE_PERCENTAGES, fpl_percent)
+    }
+
+    /// The One Big Beautiful Bill Act repealed the repayment limits for
+    /// years after 2025, so every excess advance payment is repaid.
+    fn ptc_repayment_limit(&self, _status: FilingStatus, _fpl_percent: i64) -> Option<Usd> {
+        None
+    }
+
+    /// See: <https://www.irs.gov/pub/irs-drop/rp-25-32.pdf>
+    fn dependent_gross_income_limit(&self) -> Usd {
+        Usd::from_dollars(5_300)
+    }
+}
+
+/// Which law 2026 is taxed under in [`rules_2026`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tcja2026 {
+    /// The Tax Cuts and Jobs Act's individual provisions continue, as the
+    /// One Big Beautiful Bill Act made them permanent.
+    Extended,
+    /// They expire after 2025 as originally scheduled, with 2017 amounts
+    /// indexed to 2026 by the adjustment.
+    Sunset(CostOfLivingAdjustment),
+}
+
+/// Rules for 2026 under `scenario`: [`Rules2026Projected`], or
+/// [`TcjaSunset`] over it.
+pub fn rules_2026(scenario: Tcja2026) -> Box<dyn TaxYearRules> {
+    match scenario {
+        Tcja2026::Extended => Box::new(Rules2026Projected),
+        Tcja2026::Sunset(adjustment) => Box::new(TcjaSunset::new(&Rules2026Projected, adjustment)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::period::{PeriodError, TaxPeriod};
+    use crate::spine::{Key, ReturnInput, compute_spine};
+
+    fn input(wages: i64, withholding: i64) -> ReturnInput {
+        ReturnInput {
+            tax_year: 2026,
+            ..crate::spine::tests::input(wages, withholding)
+        }
+    }
+
+    // ── Status × box count × dependent matrix ───────────────────────
+
+    crate::rules::standard_deduction_matrix! {
+        rules: Rules2026Projected,
+        dependent_earned_income: 3_000,
+        Single => {
+            boxes: [16_100, 18_150, 20_200],
+            dependent: [3_450, 5_500, 7_550],
+        },
+        HeadOfHousehold => {
+            boxes: [24_150, 26_200, 28_250],
+            dependent: [3_450, 5_500, 7_550],
+        },
+        MarriedFilingJointly => {
+            boxes: [32_200, 33_850, 35_500, 37_150, 38_800],
+            dependent: [3_450, 5_100, 6_750, 8_400, 10_050],
+        },
+        MarriedFilingSeparately => {
+            boxes: [16_100, 17_750, 19_400, 21_050, 22_700],
+            dependent: [3_450, 5_100, 6_750, 8_400, 10_050],
+        },
+        QualifyingSurvivingSpouse => {
+            boxes: [32_200, 33_850, 35_500],
+            dependent: [3_450, 5_100, 6_750],
+        },
+    }
+
+    #[test]
+    fn projection_taxed_on_rate_schedule() {
+        assert!(Rules2026Projected.is_projection());
+        let ledger = compute_spine(&Rules2026Projected, &input(60_000, 0)).unwrap();
+        // 60,000 - 16,100 = 43,900 taxable: 10% of 12,400 plus 12% of
+        // 31,500, with no tax table midpoint.
+        assert_eq!(ledger[&Key::TaxableIncome], Usd::from_dollars(43_900));
+        assert_eq!(ledger[&Key::TotalTax], Usd::from_dollars(5_020));
+    }
+
+    #[test]
+    fn scenarios_branch_on_tcja() {
+        let extended = rules_2026(Tcja2026::Extended);
+        let sunset = rules_2026(Tcja2026::Sunset(CostOfLivingAdjustment {
+            index: 1,
+            base_index: 1,
+        }));
+        assert_eq!(sunset.calendar_year(), 2026);
+        assert_eq!(extended.personal_exemption_amount(), Usd::ZERO);
+        assert_eq!(sunset.personal_exemption_amount(), Usd::from_dollars(4_050));
+        assert_eq!(
+            sunset.ordinary_brackets(FilingStatus::Single)[6].rate,
+            Rate::from_bps(3_960)
+        );
+
+        let inp = input(60_000, 0);
+        let extended = compute_spine(extended.as_ref(), &inp).unwrap();
+        let sunset = compute_spine(sunset.as_ref(), &inp).unwrap();
+        assert_eq!(extended[&Key::TaxableIncome], Usd::from_dollars(43_900));
+        // 60,000 - 6,350 - 4,050 in unindexed 2017 amounts.
+        assert_eq!(sunset[&Key::TaxableIncome], Usd::from_dollars(49_600));
+        assert!(sunset[&Key::TotalTax] > extended[&Key::TotalTax]);
+    }
+
+    #[test]
+    fn periods_begin_in_2026() {
+        let mut inp = input(60_000, 0);
+        inp.period = Some(TaxPeriod::calendar(2026));
+        assert!(compute_spine(&Rules2026Projected, &inp).is_ok());
+        inp.period = Some(TaxPeriod::calendar(2025));
+        assert!(matches!(
+            compute_spine(&Rules2026Projected, &inp).unwrap_err(),
+            Error::InvalidPeriod(PeriodError::WrongYear {
+                rules_year: 2026,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn ptc_cliff_returns_above_400_percent() {
+        assert_eq!(
+            Rules2026Projected.ptc_applicable_figure(120),
+            Rate::from_bps(210)
+        );
+        assert_eq!(
+            Rules2026Projected.ptc_applicable_figure(200),
+            Rate::from_bps(660)
+        );
+        assert_eq!(
+            Rules2026Projected.ptc_applicable_figure(401),
+            Rate::from_percent(100)
+        );
+        assert_eq!(
+            Rules2026Projected.ptc_repayment_limit(FilingStatus::Single, 150),
+            None
+        );
+    }
+
+    #[test]
+    fn eitc_maximum_credits() {
+        let maximums: Vec<Usd> = (0..=3)
+            .map(|n| Rules2026Projected.eitc(n).maximum_credit)
+            .collect();
+        assert_eq!(
+            maximums,
+            [664, 4_427, 7_316, 8_231].map(Usd::from_dollars).to_vec()
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+use crate::casualty::{self, PersonalCasualty};
+use crate::charitable::{self, CharitableCarryover, CharitableDeduction, Contributions};
+use crate::rules::TaxYearRules;
+
+/// Share of AGI medical and dental expenses must exceed, in tenths of a
+/// percent (IRC §213(a)).
+pub const MEDICAL_FLOOR_PERMILLE: i64 = 75;
+
+/// Itemized deduction inputs (Schedule A).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ItemizedDeductions {
+    /// Medical and dental expenses (line 1).
+    pub medical_expenses: Usd,
+    /// State and local income taxes, or general sales taxes (line 5a).
+    pub state_and_local_taxes: Usd,
+    /// State and local real estate taxes (line 5b).
+    pub real_estate_taxes: Usd,
+    /// State and local personal property taxes (line 5c).
+    pub personal_property_taxes: Usd,
+    /// Home mortgage interest and points, limited to interest on qualified
+    /// home acquisition debt (lines 8a–8c).
+    pub mortgage_interest: Usd,
+    /// Investment interest from Form 4952 (line 9).
+    pub investment_interest: Usd,
+    /// Gifts to charity this year, by AGI limit category.
+    pub charitable_contributions: Contributions,
+    /// Charitable contributions carried over from the prior five years.
+    pub charitable_carryovers: Vec<CharitableCarryover>,
+    /// Personal casualty and theft events (Form 4684, Section A).
+    pub casualties: Vec<PersonalCasualty>,
+    /// Other itemized deductions (line 16).
+    pub other: Usd,
+}
+
+/// Schedule A results.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScheduleA {
+    /// Line 4.
+    pub medical: Usd,
+    /// Line 7, after the SALT cap.
+    pub taxes: Usd,
+    /// Line 10.
+    pub interest: Usd,
+    /// Line 14, with the carryovers to next year.
+    pub charitable: CharitableDeduction,
+    /// Line 15.
+    pub casualty: Usd,
+    /// Line 16.
+    pub other: Usd,
+    /// Line 17.
+    pub total: Usd,
+}
+
+impl ItemizedDeductions {
+    /// Computes Schedule A for a return with `agi` (Form 1040, line 11).
+    ///
+    /// Modified AGI for the SALT cap is taken to be AGI; the foreign income
+    /// exclusions that increase it are not modeled. Net casualty gains
+    /// belong on Schedule D and are not carried there.
+    ///
+    /// See: <https://www.irs.gov/instructions/i1040sca>
+    pub fn schedule_a(
+        &self,
+        rules: &dyn TaxYearRules,
+        filing_status: FilingStatus,
+        agi: Usd,
+        tax_year: i32,
+    ) -> ScheduleA {
+        let medical_floor = agi.max(Usd::ZERO).mul_div(MEDICAL_FLOOR_PERMILLE, 1_000);
+        let medical = (self.medical_expenses - medical_floor).max(Usd::ZERO);
+        let taxes =
+            self.state_and_local_taxes + self.real_estate_taxes + self.personal_property_taxes;
+        let taxes = salt_cap(rules, filing_status, agi).map_or(taxes, |cap| taxes.min(cap));
+        let interest = self.mortgage_interest + self.investment_interest;
+        let charitable = charitable::apply_limits(
+            agi,
+            tax_year,
+            &self.charitable_contributions,
+            &self.charitable_carryovers,
+        );
+        let casualty = casualty::personal_casualty(&self.casualties, agi).itemized_deduction;
+        let total = medical + taxes + interest + charitable.deduction + casualty + self.other;
+        ScheduleA {
+            medical,
+            taxes,
+            interest,
+            charitable,
+            casualty,
+            other: self.other,
+            total,
+        }
+    }
+}
+
+/// SALT cap under `rules` after the phase-down for `modified_agi`, or
+/// `None` if the year has no cap.
+pub fn salt_cap(
+    rules: &dyn TaxYearRules,
+    filing_status: FilingStatus,
+    modified_agi: Usd,
+) -> Option<Usd> {
+    let half = |usd: Usd| {
+        if filing_status == FilingStatus::MarriedFilingSeparately {
+            usd.mul_div(1, 2)
+        } else {
+            usd
+        }
+    };
+    let cap = half(rules.salt_cap()?);
+    let Some(start) = rules.salt_phasedown_start() else {
+        return Some(cap);
+    };
+    let excess = (modified_agi - half(start)).max(Usd::ZERO);
+    Some((cap - excess.mul_div(30, 100)).max(half(rules.salt_cap_floor())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2023::Rules2023;
+    use crate::rules::y2025::Rules2025;
+    use crate::rules::y2026::Rules2026Projected;
+
+    #[test]
+    fn floors_and_caps() {
+        let itemized = ItemizedDeductions {
+            medical_expenses: Usd::from_dollars(10_000),
+            state_and_local_taxes: Usd::from_dollars(30_000),
+            real_estate_taxes: Usd::from_dollars(15_000),
+            mortgage_interest: Usd::from_dollars(12_000),
+            charitable_contributions: Contributions {
+                sixty: Usd::from_dollars(5_000),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let a = itemized.schedule_a(
+            &Rules2025,
+            FilingStatus::Single,
+            Usd::from_dollars(100_000),
+            2025,
+        );
+        assert_eq!(a.medical, Usd::from_dollars(2_500));
+        assert_eq!(a.taxes, Usd::from_dollars(40_000));
+        assert_eq!(a.total, Usd::from_dollars(2_500 + 40_000 + 12_000 + 5_000));
+    }
+
+    #[test]
+    fn salt_cap_phases_down_to_floor() {
+        let cap = |status, magi| salt_cap(&Rules2025, status, Usd::from_dollars(magi)).unwrap();
+        assert_eq!(
+            cap(FilingStatus::Single, 500_000),
+            Usd::from_dollars(40_000)
+        );
+        // 30% of 50,000 over the threshold.
+        assert_eq!(
+            cap(FilingStatus::MarriedFilingJointly, 550_000),
+            Usd::from_dollars(25_000)
+        );
+        assert_eq!(
+            cap(FilingStatus::Single, 700_000),
+            Usd::from_dollars(10_000)
+        );
+        assert_eq!(
+            cap(FilingStatus::MarriedFilingSeparately, 100_000),
+            Usd::from_dollars(20_000)
+        );
+    }
+
+    #[test]
+    fn salt_cap_by_year() {
+        // 2023 has a flat cap with no phase-down.
+        assert_eq!(
+            salt_cap(&Rules2023, FilingStatus::Single, Usd::from_dollars(700_000)),
+            Some(Usd::from_dollars(10_000))
+        );
+        assert_eq!(
+            salt_cap(
+                &Rules2026Projected,
+                FilingStatus::Single,
+                Usd::from_dollars(505_000)
+            ),
+            Some(Usd::from_dollars(40_400))
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use crate::Usd;
+use crate::rules::TaxYearRules;
+
+/// Share of self-employment earnings that are net earnings subject to the
+/// tax, in basis points (Schedule SE, line 4a; IRC §1402(a)(12)).
+pub const NET_EARNINGS_BPS: i64 = 9_235;
+
+/// Social Security part of the self-employment tax rate, in basis points
+/// (IRC §1401(a)).
+pub const SOCIAL_SECURITY_TAX_BPS: i64 = 1_240;
+
+/// Medicare part of the self-employment tax rate, in basis points
+/// (IRC §1401(b)(1)).
+pub const MEDICARE_TAX_BPS: i64 = 290;
+
+/// Net earnings below which no self-employment tax is due
+/// (IRC §1402(b)(2)).
+pub const MINIMUM_NET_EARNINGS: Usd = Usd::from_dollars(400);
+
+/// Results of Schedule SE, Part I.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScheduleSe {
+    /// Net earnings from self-employment (line 6), or zero below the
+    /// $400 minimum.
+    pub net_earnings: Usd,
+    /// Line 10.
+    pub social_security_tax: Usd,
+    /// Line 11.
+    pub medicare_tax: Usd,
+}
+
+impl ScheduleSe {
+    /// Self-employment tax, reported on Schedule 2, line 4 (line 12).
+    pub fn tax(&self) -> Usd {
+        self.social_security_tax + self.medicare_tax
+    }
+
+    /// Deduction for half of the tax, reported on Schedule 1, line 15
+    /// (line 13).
+    pub fn deduction(&self) -> Usd {
+        self.tax().mul_div(1, 2)
+    }
+}
+
+/// Computes Schedule SE, Part I, for net profit from self-employment
+/// (line 3) of `earnings`. Social Security wages and tips of
+/// `social_security_wages` (lines 8a–8d) use up the wage base first.
+///
+/// See: <https://www.irs.gov/instructions/i1040sse>
+pub fn schedule_se(
+    rules: &dyn TaxYearRules,
+    earnings: Usd,
+    social_security_wages: Usd,
+) -> ScheduleSe {
+    let net_earnings = earnings.mul_div(NET_EARNINGS_BPS, 10_000);
+    if net_earnings < MINIMUM_NET_EARNINGS {
+        return ScheduleSe::default();
+    }
+    // Lines 7–9
+    let wage_base_left = (rules.social_security_wage_base() - social_security_wages).max(Usd::ZERO);
+    ScheduleSe {
+        net_earnings,
+        social_security_tax: net_earnings
+            .min(wage_base_left)
+            .mul_div(SOCIAL_SECURITY_TAX_BPS, 10_000),
+        medicare_tax: net_earnings.mul_div(MEDICARE_TAX_BPS, 10_000),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    #[test]
+    fn tax_and_deduction() {
+        let se = schedule_se(&Rules2025, Usd::from_dollars(60_000), Usd::ZERO);
+        assert_eq!(se.net_earnings, Usd::from_dollars(55_410));
+        assert_eq!(se.social_security_tax, Usd::from_cents(687_084));
+        assert_eq!(se.medicare_tax, Usd::from_cents(160_689));
+        assert_eq!(se.tax(), Usd::from_cents(847_773));
+        assert_eq!(se.deduction(), Usd::from_cents(423_887));
+    }
+
+    #[test]
+    fn wages_use_up_the_wage_base() {
+        let se = schedule_se(
+            &Rules2025,
+            Usd::from_dollars(60_000),
+            Usd::from_dollars(170_000),
+        );
+        assert_eq!(
+            se.social_security_tax,
+            Usd::from_dollars(6_100).mul_div(1_240, 10_000)
+        );
+        assert_eq!(se.medicare_tax, Usd::from_cents(160_689));
+    }
+
+    #[test]
+    fn no_tax_below_four_hundred_dollars() {
+        assert_eq!(
+            schedule_se(&Rules2025, Usd::from_dollars(433), Usd::ZERO),
+            ScheduleSe::default()
+        );
+        assert_eq!(
+            schedule_se(&Rules2025, Usd::from_dollars(-5_000), Usd::ZERO),
+            ScheduleSe::default()
+        );
+        assert!(schedule_se(&Rules2025, Usd::from_dollars(434), Usd::ZERO).tax() > Usd::ZERO);
+    }
+}
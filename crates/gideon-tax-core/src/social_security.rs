@@ -0,0 +1,169 @@
+use us_tax_brackets::FilingStatus;
+
+use crate::Usd;
+
+// ---------------------------------------------------------------------------
+// Statutory thresholds (IRC §86, not inflation-indexed)
+// ---------------------------------------------------------------------------
+
+const BASE_AMOUNT_MFJ: Usd = Usd::from_dollars(32_000);
+const BASE_AMOUNT_OTHER: Usd = Usd::from_dollars(25_000);
+const ADJUSTED_BASE_EXCESS_MFJ: Usd = Usd::from_dollars(12_000);
+const ADJUSTED_BASE_EXCESS_OTHER: Usd = Usd::from_dollars(9_000);
+
+/// Input to [`taxable_benefits`], mirroring the lines of the Social Security
+/// Benefits Worksheet in the Form 1040 instructions.
+pub struct BenefitsWorksheetParams {
+    pub filing_status: FilingStatus,
+    /// `true` if filing MFS and the taxpayer lived with their spouse at any
+    /// time during the year (worksheet line 8 special case).
+    pub mfs_lived_with_spouse: bool,
+    /// Sum of box 5 of all Forms SSA-1099 and RRB-1099 (Form 1040, line 6a).
+    pub net_benefits: Usd,
+    /// Income reported on Form 1040 other than Social Security benefits
+    /// (lines 1z, 2b, 3b, 4b, 5b, 7, and 8).
+    pub other_income: Usd,
+    /// Tax-exempt interest (Form 1040, line 2a).
+    pub tax_exempt_interest: Usd,
+    /// Schedule 1, lines 11 through 20, 23, and 25.
+    pub adjustments: Usd,
+}
+
+/// Computes the taxable portion of Social Security benefits (Form 1040,
+/// line 6b).
+///
+/// Follows the Social Security Benefits Worksheet: up to 50% of benefits
+/// are taxable once provisional income exceeds the base amount, and up to
+/// 85% once it exceeds the adjusted base amount. MFS filers who lived with
+/// their spouse have a base amount of zero.
+///
+/// See: <https://www.irs.gov/instructions/i1040gi>
+pub fn taxable_benefits(params: &BenefitsWorksheetParams) -> Usd {
+    use FilingStatus::*;
+
+    // Lines 1–2
+    let benefits = params.net_benefits.max(Usd::ZERO);
+    if benefits == Usd::ZERO {
+        return Usd::ZERO;
+    }
+    let half_benefits = benefits.mul_div(50, 100);
+
+    // Lines 3–7: provisional income less adjustments
+    let combined = half_benefits + params.other_income + params.tax_exempt_interest;
+    if params.adjustments >= combined {
+        return Usd::ZERO;
+    }
+    let line7 = combined - params.adjustments;
+
+    // Line 16 (MFS, lived with spouse): 85% of line 7, no base amount
+    let line16 = if params.filing_status == MarriedFilingSeparately && params.mfs_lived_with_spouse
+    {
+        line7.mul_div(85, 100)
+    } else {
+        // Lines 8–9
+        let (base, excess) = match params.filing_status {
+            MarriedFilingJointly => (BASE_AMOUNT_MFJ, ADJUSTED_BASE_EXCESS_MFJ),
+            Single | HeadOfHousehold | QualifyingSurvivingSpouse | MarriedFilingSeparately => {
+                (BASE_AMOUNT_OTHER, ADJUSTED_BASE_EXCESS_OTHER)
+            }
+        };
+        if base >= line7 {
+            return Usd::ZERO;
+        }
+        let line9 = line7 - base;
+
+        // Lines 10–15
+        let line11 = (line9 - excess).max(Usd::ZERO);
+        let line12 = line9.min(excess);
+        let line13 = line12.mul_div(50, 100);
+        let line14 = half_benefits.min(line13);
+        let line15 = line11.mul_div(85, 100);
+        line14 + line15
+    };
+
+    // Lines 17–18
+    line16.min(benefits.mul_div(85, 100))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(status: FilingStatus, benefits: i64, other: i64) -> BenefitsWorksheetParams {
+        BenefitsWorksheetParams {
+            filing_status: status,
+            mfs_lived_with_spouse: false,
+            net_benefits: Usd::from_dollars(benefits),
+            other_income: Usd::from_dollars(other),
+            tax_exempt_interest: Usd::ZERO,
+            adjustments: Usd::ZERO,
+        }
+    }
+
+    #[test]
+    fn no_benefits_none_taxable() {
+        let p = params(FilingStatus::Single, 0, 100_000);
+        assert_eq!(taxable_benefits(&p), Usd::ZERO);
+    }
+
+    #[test]
+    fn below_base_none_taxable() {
+        // line 7 = 10,000 + 14,000 = 24,000 ≤ 25,000
+        let p = params(FilingStatus::Single, 20_000, 14_000);
+        assert_eq!(taxable_benefits(&p), Usd::ZERO);
+    }
+
+    #[test]
+    fn single_within_first_tier() {
+        // line 7 = 10,000 + 20,000 = 30,000; line 9 = 5,000
+        // line 12 = 5,000; line 13 = 2,500; line 14 = min(10,000, 2,500)
+        let p = params(FilingStatus::Single, 20_000, 20_000);
+        assert_eq!(taxable_benefits(&p), Usd::from_dollars(2_500));
+    }
+
+    #[test]
+    fn single_second_tier() {
+        // line 7 = 10,000 + 30,000 = 40,000; line 9 = 15,000
+        // line 11 = 6,000; line 13 = 4,500; line 14 = 4,500
+        // line 15 = 5,100; line 16 = 9,600; line 17 = 17,000
+        let p = params(FilingStatus::Single, 20_000, 30_000);
+        assert_eq!(taxable_benefits(&p), Usd::from_dollars(9_600));
+    }
+
+    #[test]
+    fn capped_at_85_percent() {
+        let p = params(FilingStatus::Single, 20_000, 200_000);
+        assert_eq!(taxable_benefits(&p), Usd::from_dollars(17_000));
+    }
+
+    #[test]
+    fn mfj_uses_higher_base() {
+        // line 7 = 15,000 + 20,000 = 35,000; line 9 = 3,000
+        // line 13 = 1,500
+        let p = params(FilingStatus::MarriedFilingJointly, 30_000, 20_000);
+        assert_eq!(taxable_benefits(&p), Usd::from_dollars(1_500));
+    }
+
+    #[test]
+    fn mfs_lived_with_spouse_has_no_base() {
+        let mut p = params(FilingStatus::MarriedFilingSeparately, 10_000, 2_000);
+        p.mfs_lived_with_spouse = true;
+        // line 7 = 5,000 + 2,000 = 7,000; 85% = 5,950; cap = 8,500
+        assert_eq!(taxable_benefits(&p), Usd::from_dollars(5_950));
+    }
+
+    #[test]
+    fn tax_exempt_interest_counts() {
+        let mut p = params(FilingStatus::Single, 20_000, 14_000);
+        p.tax_exempt_interest = Usd::from_dollars(3_000);
+        // line 7 = 27,000; line 9 = 2,000; line 13 = 1,000
+        assert_eq!(taxable_benefits(&p), Usd::from_dollars(1_000));
+    }
+
+    #[test]
+    fn adjustments_exceeding_income_none_taxable() {
+        let mut p = params(FilingStatus::Single, 20_000, 30_000);
+        p.adjustments = Usd::from_dollars(40_000);
+        assert_eq!(taxable_benefits(&p), Usd::ZERO);
+    }
+}
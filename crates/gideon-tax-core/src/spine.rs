@@ -1,57 +1,157 @@
-use std::collections::BTreeMap;
-use std::fmt;
-
-use us_tax_brackets::{self, FilingStatus, TaxYear};
+use us_tax_brackets::{self, FilingStatus};
 
 use crate::Usd;
-use crate::rules::{DeductionParams, TaxYearRules};
-use crate::types::Filer;
-
-// ---------------------------------------------------------------------------
-// Ledger keys
-// ---------------------------------------------------------------------------
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Key {
-    TotalIncome,
-    Adjustments,
-    AGI,
-    Deductions,
-    TaxableIncome,
-    RegularTax,
-    AdditionalTax,
-    TotalTaxPreCredits,
-    NonRefundableCredits,
-    TaxAfterNonRefundableCredits,
-    RefundableCredits,
-    TotalTax,
-    Withholding,
-    EstimatedPayments,
-    TotalPayments,
-    Refund,
-    AmountOwed,
-}
-
-pub type Ledger = BTreeMap<Key, Usd>;
+use crate::adjustments::Adjustments;
+use crate::capital_gain_tax;
+use crate::capital_loss::{self, CapitalLossCarryover, ScheduleD};
+use crate::charitable::{self, CharitableCarryover, Contributions};
+use crate::decedent::DeceasedTaxpayer;
+use crate::education::StudentExpenses;
+use crate::error::Error;
+use crate::home_sale::HomeSale;
+use crate::hsa::{self, HsaDistributions};
+pub use crate::ledger::{Key, Ledger};
+use crate::nol::{self, NolCarryover};
+use crate::parents_election::{ChildInvestmentIncome, Form8814};
+use crate::passthrough::{self, PassthroughItems};
+use crate::period::TaxPeriod;
+use crate::premium_tax_credit::{self, MarketplaceCoverage, PtcParams};
+use crate::qbi::{self, QbiParams};
+use crate::rental::{self, RentalParams, RentalProperty, ScheduleEPart1};
+use crate::rules::{self, DeductionParams, ExemptionParams, TaxYearRules};
+use crate::schedule_a::{ItemizedDeductions, ScheduleA};
+use crate::self_employment::{self, ScheduleSe};
+use crate::social_security::{self, BenefitsWorksheetParams};
+use crate::tips::UnreportedTips;
+use crate::types::{Filer, Owner};
+use crate::withholding::{self, ReportedWithholding};
 
 // ---------------------------------------------------------------------------
 // Input
 // ---------------------------------------------------------------------------
 
+#[derive(Debug, Clone, Hash)]
 pub struct ReturnInput {
-    pub tax_year: TaxYear,
+    /// Calendar year of the return, which the rules computing it must be
+    /// for.
+    pub tax_year: i32,
+    /// Fiscal or short tax period; `None` for the calendar year, or for a
+    /// final return, the period implied by `deceased`.
+    pub period: Option<TaxPeriod>,
+    /// Set when this is a final return for a taxpayer or spouse who died
+    /// during the year.
+    pub deceased: Option<DeceasedTaxpayer>,
     pub filing_status: FilingStatus,
     pub taxpayer: Filer,
     pub spouse: Option<Filer>,
     pub is_dependent: bool,
     pub is_dual_status_alien: bool,
     pub spouse_itemizes: bool,
+    /// Number of dependents claimed.
+    pub num_dependents: u32,
+    /// `true` if filing MFS and the taxpayer lived with their spouse at any
+    /// time during the year.
+    pub mfs_lived_with_spouse: bool,
     pub w2_wages: Usd,
+    /// The taxpayer's Social Security wages and tips from Forms W-2,
+    /// boxes 3 and 7, which use up the wage base before self-employment
+    /// earnings (Schedule SE, line 8a).
+    pub social_security_wages: Usd,
+    /// The same for the spouse on a joint return.
+    pub spouse_social_security_wages: Usd,
+    /// Tips not reported to employers (Form 4137).
+    pub unreported_tips: UnreportedTips,
+    /// Children's interest and dividends the parent elects to report
+    /// (Form 8814), one entry per child.
+    pub children_investment_income: Vec<ChildInvestmentIncome>,
+    /// Capital losses carried over from the prior year's Schedule D.
+    pub capital_loss_carryover: CapitalLossCarryover,
+    /// Net operating losses carried forward from prior years.
+    pub nol_carryovers: Vec<NolCarryover>,
+    /// Net Social Security and tier 1 railroad retirement benefits
+    /// (Form 1040, line 6a).
+    pub social_security_benefits: Usd,
+    /// HSA distributions (Form 8889, Part II).
+    pub hsa_distributions: HsaDistributions,
+    /// Education expenses and scholarships, one entry per student.
+    pub students: Vec<StudentExpenses>,
+    /// Items from partnership, S corporation, and estate or trust
+    /// Schedules K-1.
+    pub passthroughs: Vec<PassthroughItems>,
+    /// Rental real estate and royalty properties (Schedule E, Part I).
+    pub rental_properties: Vec<RentalProperty>,
+    /// Sales of a main home, whose gain after the exclusion goes on
+    /// Schedule D.
+    pub home_sales: Vec<HomeSale>,
+    /// Adjustments to income (Schedule 1, Part II).
+    pub adjustments: Adjustments,
+    /// Itemized deductions (Schedule A), taken when they exceed the
+    /// standard deduction.
+    pub itemized_deductions: ItemizedDeductions,
+    /// Marketplace coverage from Forms 1095-A, or `None` if no one in the
+    /// tax family enrolled.
+    pub marketplace_coverage: Option<MarketplaceCoverage>,
+    /// Federal income tax withheld from Forms W-2 (Form 1040, line 25a).
     pub fed_withholding: Usd,
+    /// Federal income tax withheld from Forms 1099 and SSA-1099, one entry
+    /// per form (Form 1040, line 25b).
+    pub form_1099_withholding: Vec<ReportedWithholding>,
+    /// Amount paid with a request for an extension of time to file
+    /// (Form 4868, line 7; Schedule 3, line 10).
+    pub extension_payment: Usd,
 }
 
 impl ReturnInput {
-    fn deduction_params(&self) -> DeductionParams {
+    /// A calendar-year return for `tax_year` with no income, deductions,
+    /// credits, or payments. The taxpayer is under 65 and not blind, and no
+    /// spouse or dependents are claimed. Set the lines that apply with
+    /// struct update syntax.
+    pub fn new(tax_year: i32, filing_status: FilingStatus) -> Self {
+        ReturnInput {
+            tax_year,
+            period: None,
+            deceased: None,
+            filing_status,
+            taxpayer: Filer::default(),
+            spouse: None,
+            is_dependent: false,
+            is_dual_status_alien: false,
+            spouse_itemizes: false,
+            num_dependents: 0,
+            mfs_lived_with_spouse: false,
+            w2_wages: Usd::ZERO,
+            social_security_wages: Usd::ZERO,
+            spouse_social_security_wages: Usd::ZERO,
+            unreported_tips: UnreportedTips::default(),
+            children_investment_income: Vec::new(),
+            capital_loss_carryover: CapitalLossCarryover::default(),
+            nol_carryovers: Vec::new(),
+            social_security_benefits: Usd::ZERO,
+            hsa_distributions: HsaDistributions::default(),
+            students: Vec::new(),
+            passthroughs: Vec::new(),
+            rental_properties: Vec::new(),
+            home_sales: Vec::new(),
+            adjustments: Adjustments::default(),
+            itemized_deductions: ItemizedDeductions::default(),
+            marketplace_coverage: None,
+            fed_withholding: Usd::ZERO,
+            form_1099_withholding: Vec::new(),
+            extension_payment: Usd::ZERO,
+        }
+    }
+
+    /// The period the return covers, or `None` for the calendar year.
+    pub fn effective_period(&self) -> Option<TaxPeriod> {
+        self.period.or_else(|| {
+            self.deceased
+                .as_ref()
+                .and_then(|d| d.final_period(self.filing_status))
+        })
+    }
+
+    pub(crate) fn deduction_params(&self, rules: &dyn TaxYearRules) -> DeductionParams {
+        let schedule_se = self.schedule_se(rules);
         DeductionParams {
             filing_status: self.filing_status,
             taxpayer: self.taxpayer,
@@ -59,46 +159,225 @@ impl ReturnInput {
             is_dependent: self.is_dependent,
             is_dual_status_alien: self.is_dual_status_alien,
             spouse_itemizes: self.spouse_itemizes,
-            // TODO: include other earned income sources (self-employment, etc.)
-            earned_income: self.w2_wages,
+            earned_income: self.w2_wages
+                + self.unreported_tips.total()
+                + self.taxable_scholarships()
+                + schedule_se
+                    .iter()
+                    .map(|se| se.net_earnings - se.deduction())
+                    .sum::<Usd>(),
         }
     }
-}
 
-// ---------------------------------------------------------------------------
-// Error
-// ---------------------------------------------------------------------------
+    /// Schedule SE for the taxpayer and the spouse, each from the
+    /// self-employment earnings on their own K-1s and their own Social
+    /// Security wages. Unreported tips from Form 4137 count toward the
+    /// taxpayer's.
+    pub fn schedule_se(&self, rules: &dyn TaxYearRules) -> [ScheduleSe; 2] {
+        let earnings = |owner: Owner| -> Usd {
+            self.passthroughs
+                .iter()
+                .filter(|p| p.owner == owner)
+                .map(|p| p.self_employment_earnings)
+                .sum()
+        };
+        let tips = self.unreported_tips.form_4137(rules).social_security_tips;
+        [
+            self_employment::schedule_se(
+                rules,
+                earnings(Owner::Taxpayer),
+                self.social_security_wages + tips,
+            ),
+            self_employment::schedule_se(
+                rules,
+                earnings(Owner::Spouse),
+                self.spouse_social_security_wages,
+            ),
+        ]
+    }
 
-#[derive(Debug)]
-pub enum SpineError {
-    YearMismatch { input: TaxYear, rules: TaxYear },
-    TaxComputeError(us_tax_brackets::TaxError),
-}
+    /// Standard deduction, or zero for a short period annualized under
+    /// IRC §443 (§63(c)(6)(C)).
+    pub(crate) fn standard_deduction(&self, rules: &dyn TaxYearRules) -> Usd {
+        if self.effective_period().is_some_and(|p| p.annualizes()) {
+            Usd::ZERO
+        } else {
+            rules.standard_deduction(&self.deduction_params(rules))
+        }
+    }
 
-impl From<us_tax_brackets::TaxError> for SpineError {
-    fn from(e: us_tax_brackets::TaxError) -> Self {
-        SpineError::TaxComputeError(e)
+    /// Schedule A for a return with `agi`.
+    pub fn schedule_a(&self, rules: &dyn TaxYearRules, agi: Usd) -> ScheduleA {
+        self.itemized_deductions
+            .schedule_a(rules, self.filing_status, agi, rules.calendar_year())
+    }
+
+    /// `true` if itemized deductions exceed the standard deduction at `agi`.
+    pub fn itemizes(&self, rules: &dyn TaxYearRules, agi: Usd) -> bool {
+        self.schedule_a(rules, agi).total > self.standard_deduction(rules)
     }
-}
 
-impl fmt::Display for SpineError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            SpineError::YearMismatch { input, rules } => {
-                write!(f, "tax year mismatch: input={input}, rules={rules}")
+    /// Standard or itemized deductions at `agi` (Form 1040, line 12).
+    fn deductions(&self, rules: &dyn TaxYearRules, agi: Usd) -> Usd {
+        self.schedule_a(rules, agi)
+            .total
+            .max(self.standard_deduction(rules))
+    }
+
+    /// Charitable contributions to carry to next year's Schedule A, given
+    /// the ledger [`compute_spine`] returned for this input.
+    ///
+    /// In a year the standard deduction is taken, carryovers are still used
+    /// up as if the return had itemized (Treas. Reg. §1.170A-10(a)(2)), and
+    /// the year's own contributions do not carry over.
+    pub fn next_charitable_carryovers(
+        &self,
+        rules: &dyn TaxYearRules,
+        ledger: &Ledger,
+    ) -> Vec<CharitableCarryover> {
+        let agi = ledger.get(&Key::AGI).copied().unwrap_or(Usd::ZERO);
+        if self.itemizes(rules, agi) {
+            return self.schedule_a(rules, agi).charitable.carryovers;
+        }
+        charitable::apply_limits(
+            agi,
+            rules.calendar_year(),
+            &Contributions::default(),
+            &self.itemized_deductions.charitable_carryovers,
+        )
+        .carryovers
+    }
+
+    fn passthrough_total(&self, item: fn(&PassthroughItems) -> Usd) -> Usd {
+        self.passthroughs.iter().map(item).sum()
+    }
+
+    /// Schedule D from K-1 capital gains, children's capital gain
+    /// distributions reported on Form 8814, taxable gain on home sales, and
+    /// the prior-year carryover.
+    pub fn schedule_d(&self, rules: &dyn TaxYearRules) -> ScheduleD {
+        // TODO: Form 8949 sales and capital gain distributions from Forms 1099-DIV
+        let child_distributions: Usd = self
+            .children_investment_income
+            .iter()
+            .map(|c| c.form_8814(rules).capital_gain_distributions)
+            .sum();
+        let (mut home_short_term, mut home_long_term) = (Usd::ZERO, Usd::ZERO);
+        for sale in &self.home_sales {
+            let w = sale.worksheet(self.filing_status);
+            if w.long_term {
+                home_long_term += w.taxable_gain;
+            } else {
+                home_short_term += w.taxable_gain;
             }
-            SpineError::TaxComputeError(e) => write!(f, "tax computation error: {e}"),
         }
+        capital_loss::schedule_d(
+            self.passthrough_total(|p| p.net_short_term_capital_gain) + home_short_term,
+            self.passthrough_total(|p| p.net_long_term_capital_gain)
+                + child_distributions
+                + home_long_term,
+            &self.capital_loss_carryover,
+            self.filing_status,
+        )
+    }
+
+    /// Qualified dividends, from K-1s and children's Forms 8814, and net
+    /// capital gain from Schedule D: the income the Qualified Dividends and
+    /// Capital Gain Tax Worksheet taxes at preferential rates.
+    pub(crate) fn preferential_income(&self, rules: &dyn TaxYearRules) -> (Usd, Usd) {
+        let qualified_dividends = self.passthrough_total(|p| p.qualified_dividends)
+            + self
+                .children_investment_income
+                .iter()
+                .map(|c| c.form_8814(rules).qualified_dividends)
+                .sum::<Usd>();
+        let schedule_d = self.schedule_d(rules);
+        let net_capital_gain = schedule_d
+            .net_long_term
+            .min(schedule_d.net_short_term + schedule_d.net_long_term)
+            .max(Usd::ZERO);
+        (qualified_dividends, net_capital_gain)
+    }
+
+    /// Capital losses to carry to next year's
+    /// [`capital_loss_carryover`](Self::capital_loss_carryover), given the
+    /// ledger [`compute_spine`] returned for this input.
+    pub fn next_capital_loss_carryover(
+        &self,
+        rules: &dyn TaxYearRules,
+        ledger: &Ledger,
+    ) -> CapitalLossCarryover {
+        let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+        let taxable_income =
+            get(Key::AGI) - get(Key::Deductions) - get(Key::Exemptions) - get(Key::QbiDeduction);
+        self.schedule_d(rules).carryover(taxable_income)
     }
-}
 
-impl std::error::Error for SpineError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match self {
-            SpineError::TaxComputeError(e) => Some(e),
-            _ => None,
+    /// Net operating losses left to carry to next year's
+    /// [`nol_carryovers`](Self::nol_carryovers), given the ledger
+    /// [`compute_spine`] returned for this input.
+    pub fn next_nol_carryovers(
+        &self,
+        rules: &dyn TaxYearRules,
+        ledger: &Ledger,
+    ) -> Vec<NolCarryover> {
+        let deduction = ledger.get(&Key::NolDeduction).copied().unwrap_or(Usd::ZERO);
+        nol::carry_forward(&self.nol_carryovers, deduction, rules.calendar_year()).carryovers
+    }
+
+    /// Schedule E, Part I as figured for the ledger [`compute_spine`]
+    /// returned for this input, whose results carry suspended passive
+    /// losses to next year.
+    pub fn schedule_e_part_1(&self, rules: &dyn TaxYearRules, ledger: &Ledger) -> ScheduleEPart1 {
+        rental::schedule_e_part_1(
+            &self.rental_properties,
+            &self.rental_params(rules, self.rental_modified_agi(ledger)),
+        )
+    }
+
+    /// Modified AGI for the rental special allowance (Form 8582, line 6),
+    /// given the ledger [`compute_spine`] returned for this input.
+    pub fn rental_modified_agi(&self, ledger: &Ledger) -> Usd {
+        let get = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+        let rentals = get(Key::SupplementalIncome)
+            - passthrough::schedule_e_passthrough_total(&self.passthroughs);
+        get(Key::AGI) + get(Key::NolDeduction) - get(Key::TaxableSocialSecurity) - rentals
+            + self.adjustments.ira_deduction
+    }
+
+    fn rental_params(&self, rules: &dyn TaxYearRules, modified_agi: Usd) -> RentalParams {
+        RentalParams {
+            filing_status: self.filing_status,
+            mfs_lived_with_spouse: self.mfs_lived_with_spouse,
+            modified_agi,
+            tax_year: rules.calendar_year(),
         }
     }
+
+    /// Scholarships and grants not excluded under IRC §117.
+    fn taxable_scholarships(&self) -> Usd {
+        self.students.iter().map(|s| s.taxable_scholarships()).sum()
+    }
+
+    /// Individuals in the tax family: the taxpayer, a spouse filing jointly,
+    /// and dependents (Form 8962, line 1).
+    fn family_size(&self) -> u32 {
+        let spouse = u32::from(self.filing_status == FilingStatus::MarriedFilingJointly);
+        1 + spouse + self.num_dependents
+    }
+
+    /// Personal exemptions for the taxpayer (unless claimable as a
+    /// dependent) and spouse, plus one per dependent.
+    fn exemption_count(&self) -> i64 {
+        let taxpayer = i64::from(!self.is_dependent);
+        let spouse = match self.filing_status {
+            FilingStatus::MarriedFilingJointly => 1,
+            // MFS may claim a spouse with no income who is not a dependent.
+            FilingStatus::MarriedFilingSeparately => i64::from(self.spouse.is_some()),
+            _ => 0,
+        };
+        taxpayer + spouse + i64::from(self.num_dependents)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -112,74 +391,290 @@ impl std::error::Error for SpineError {
 /// Additional Tax → Credits (nonrefundable / refundable) → Payments →
 /// Refund or Amount Owed.
 ///
-/// Returns [`SpineError::YearMismatch`] if `input.tax_year` differs from
-/// `rules.year()`, or [`SpineError::TaxComputeError`] if the underlying
+/// A short period for a change in accounting period is annualized under IRC
+/// §443: no standard deduction, exemptions prorated by months, and tax
+/// computed on annualized income then scaled back to the period.
+///
+/// Returns [`Error::YearMismatch`] if `input.tax_year` differs from
+/// `rules.calendar_year()`, [`Error::InvalidPeriod`] if `input.period` is
+/// not valid for the rules year, or [`Error::TaxCompute`] if the underlying
 /// bracket lookup fails.
-pub fn compute_spine(rules: &dyn TaxYearRules, input: &ReturnInput) -> Result<Ledger, SpineError> {
-    if input.tax_year != rules.year() {
-        return Err(SpineError::YearMismatch {
+pub fn compute_spine(rules: &dyn TaxYearRules, input: &ReturnInput) -> Result<Ledger, Error> {
+    if input.tax_year != rules.calendar_year() {
+        return Err(Error::YearMismatch {
             input: input.tax_year,
-            rules: rules.year(),
+            rules: rules.calendar_year(),
         });
     }
+    let period = input.effective_period();
+    if let Some(period) = &period {
+        period.validate(rules.calendar_year())?;
+    }
+    let annualize_months = period.filter(TaxPeriod::annualizes).map(|p| p.months());
+
+    let hsa_tax = hsa::distribution_tax(&input.hsa_distributions);
 
-    // TODO: sum all income sources (interest, dividends, business, capital gains, etc.)
-    let total_income = input.w2_wages;
-    // TODO: Schedule 1 adjustments (educator expenses, HSA, IRA, student loan interest, etc.)
-    let adjustments = Usd::ZERO;
+    // TODO: sum all income sources (Forms 1099-INT/DIV, business, capital gains, etc.)
+    let taxable_interest = input.passthrough_total(|p| p.interest);
+    let ordinary_dividends = input.passthrough_total(|p| p.ordinary_dividends);
+    let passthrough_income = passthrough::schedule_e_passthrough_total(&input.passthroughs);
+    let taxable_scholarships = input.taxable_scholarships();
+    let tips = input.unreported_tips.form_4137(rules);
+    let children: Vec<Form8814> = input
+        .children_investment_income
+        .iter()
+        .map(|c| c.form_8814(rules))
+        .collect();
+    // Form 8814, line 9 goes on Form 1040, line 3b, line 10 on Schedule D,
+    // line 13, and line 12 on Schedule 1, line 8z.
+    let child_dividends: Usd = children.iter().map(|c| c.qualified_dividends).sum();
+    let child_income: Usd = children.iter().map(|c| c.other_income).sum();
+    let child_tax: Usd = children.iter().map(|c| c.tax).sum();
+    let child_tax_exempt_interest: Usd = input
+        .children_investment_income
+        .iter()
+        .map(|c| c.tax_exempt_interest)
+        .sum();
+    let ordinary_dividends = ordinary_dividends + child_dividends;
+    let capital_gains = input.schedule_d(rules);
+    let income_before_rentals = input.w2_wages
+        + tips.unreported_tips
+        + taxable_interest
+        + ordinary_dividends
+        + capital_gains.capital_gain_or_loss
+        + child_income
+        + passthrough_income
+        + hsa_tax.taxable_distributions
+        + taxable_scholarships;
+    let schedule_se = input.schedule_se(rules);
+    let se_tax_deduction: Usd = schedule_se.iter().map(ScheduleSe::deduction).sum();
+    // TODO: remaining Schedule 1 adjustments (educator expenses, student loan interest, etc.)
+    let adjustments = input.adjustments.total() + se_tax_deduction;
+
+    let rentals = rental::schedule_e_part_1(
+        &input.rental_properties,
+        // Form 8582, line 6: without taxable Social Security or the IRA deduction.
+        &input.rental_params(
+            rules,
+            income_before_rentals - adjustments + input.adjustments.ira_deduction,
+        ),
+    );
+    let supplemental_income = rentals.total() + passthrough_income;
+    let income_before_nol = income_before_rentals + rentals.total();
+
+    let taxable_benefits = |other_income: Usd| {
+        social_security::taxable_benefits(&BenefitsWorksheetParams {
+            filing_status: input.filing_status,
+            mfs_lived_with_spouse: input.mfs_lived_with_spouse,
+            net_benefits: input.social_security_benefits,
+            other_income,
+            // TODO: the taxpayer's own tax-exempt interest (Form 1040, line 2a)
+            tax_exempt_interest: child_tax_exempt_interest,
+            adjustments,
+        })
+    };
+    let exemptions_for = |agi: Usd| {
+        let exemptions = rules.exemptions(&ExemptionParams {
+            filing_status: input.filing_status,
+            agi,
+            count: input.exemption_count(),
+        });
+        match annualize_months {
+            // §443(c)
+            Some(months) => exemptions.mul_div(months, 12),
+            None => exemptions,
+        }
+    };
+
+    // Schedule 1, line 8a, limited by taxable income figured without it.
+    let agi_before_nol = income_before_nol + taxable_benefits(income_before_nol) - adjustments;
+    let nol = nol::nol_deduction(
+        &input.nol_carryovers,
+        agi_before_nol - input.deductions(rules, agi_before_nol) - exemptions_for(agi_before_nol),
+        rules.calendar_year(),
+    );
+    let other_income = income_before_nol - nol.deduction;
+
+    let taxable_social_security = taxable_benefits(other_income);
+    let total_income = other_income + taxable_social_security;
     let agi = total_income - adjustments;
+    let deductions = input.deductions(rules, agi);
+    let exemptions = exemptions_for(agi);
+    let taxable_income_before_qbi = (agi - deductions - exemptions).max(Usd::ZERO);
+    let (qualified_dividends, net_capital_gain) = input.preferential_income(rules);
+    // TODO: Form 8995-A above the threshold; until then no deduction is taken.
+    // TODO: reduce QBI by the deductible part of self-employment tax on it.
+    let qbi_deduction = qbi::simplified_deduction(
+        rules,
+        input.filing_status,
+        &QbiParams {
+            qualified_business_income: input.passthrough_total(|p| p.qualified_business_income),
+            taxable_income_before_qbi,
+            net_capital_gain: qualified_dividends + net_capital_gain,
+        },
+    )
+    .unwrap_or(Usd::ZERO);
+    let taxable_income = taxable_income_before_qbi - qbi_deduction;
 
-    // TODO: choose between standard and itemized deductions (Schedule A)
-    let deductions = rules.standard_deduction(&input.deduction_params());
-    let taxable_income = (agi - deductions).max(Usd::ZERO);
+    let regular_tax = match annualize_months {
+        Some(months) => {
+            let annualize = |amount: Usd| amount.mul_div(12, months);
+            regular_tax(
+                rules,
+                input.filing_status,
+                annualize(taxable_income),
+                annualize(qualified_dividends),
+                annualize(net_capital_gain),
+            )?
+            .mul_div(months, 12)
+        }
+        None => regular_tax(
+            rules,
+            input.filing_status,
+            taxable_income,
+            qualified_dividends,
+            net_capital_gain,
+        )?,
+    };
 
-    // compute_tax expects whole dollars; convert via IRS rounding.
-    let taxable_whole_dollars: i64 = taxable_income.irs_round().cents() / 100;
-    let regular_tax_whole_dollars =
-        us_tax_brackets::compute_tax(input.tax_year, input.filing_status, taxable_whole_dollars)?;
-    let regular_tax = Usd::from_dollars(regular_tax_whole_dollars);
+    let ptc = input.marketplace_coverage.map(|coverage| {
+        premium_tax_credit::compute(
+            rules,
+            &PtcParams {
+                filing_status: input.filing_status,
+                family_size: input.family_size(),
+                // TODO: add tax-exempt interest and excluded foreign income
+                household_income: agi + child_tax_exempt_interest + input.social_security_benefits
+                    - taxable_social_security,
+                coverage,
+            },
+        )
+    });
+    let excess_advance_ptc = ptc.map_or(Usd::ZERO, |p| p.excess_advance_repayment);
+    let net_ptc = ptc.map_or(Usd::ZERO, |p| p.net_credit);
 
-    // TODO: AMT, self-employment tax, additional Medicare, net investment income tax, etc.
-    let additional_tax = Usd::ZERO;
-    let total_tax_pre_credits = regular_tax + additional_tax;
+    // TODO: additional Medicare, net investment income tax, etc. The AMT
+    // (Schedule 2, line 1) needs the Form 6251 adjustments, which the input
+    // does not carry; callers add amt::form_6251 to the total tax.
+    let se_tax: Usd = schedule_se.iter().map(ScheduleSe::tax).sum();
+    let tips_tax = tips.tax();
+    let additional_tax = se_tax + hsa_tax.additional_tax + tips_tax + excess_advance_ptc;
+    let total_tax_pre_credits = regular_tax + child_tax + additional_tax;
 
     // TODO: child tax credit, education credits, foreign tax credit, etc.
     let nonrefundable_credits = Usd::ZERO;
     let tax_after_nonrefundable = (total_tax_pre_credits - nonrefundable_credits).max(Usd::ZERO);
 
     // TODO: EIC, additional child tax credit, American opportunity credit, etc.
-    let refundable_credits = Usd::ZERO;
+    let refundable_credits = net_ptc;
     let total_tax = tax_after_nonrefundable - refundable_credits;
 
-    let withholding = input.fed_withholding;
-    // TODO: estimated tax payments, amount applied from prior year, extension payments, etc.
-    let estimated_payments = Usd::ZERO;
-    let total_payments = withholding + estimated_payments;
-
-    let net = total_payments - total_tax;
-    let refund = net.max(Usd::ZERO);
-    let owed = (Usd::ZERO - net).max(Usd::ZERO);
-
     let mut ledger = Ledger::new();
+    ledger.insert(Key::TaxableInterest, taxable_interest);
+    ledger.insert(Key::OrdinaryDividends, ordinary_dividends);
+    ledger.insert(Key::TaxableSocialSecurity, taxable_social_security);
+    ledger.insert(Key::CapitalGainOrLoss, capital_gains.capital_gain_or_loss);
+    ledger.insert(Key::SupplementalIncome, supplemental_income);
+    ledger.insert(Key::TaxableHsaDistributions, hsa_tax.taxable_distributions);
+    ledger.insert(Key::TaxableScholarships, taxable_scholarships);
+    ledger.insert(Key::ChildInvestmentIncome, child_income);
+    ledger.insert(Key::NolDeduction, nol.deduction);
     ledger.insert(Key::TotalIncome, total_income);
+    ledger.insert(Key::SelfEmploymentTaxDeduction, se_tax_deduction);
     ledger.insert(Key::Adjustments, adjustments);
     ledger.insert(Key::AGI, agi);
     ledger.insert(Key::Deductions, deductions);
+    ledger.insert(Key::QbiDeduction, qbi_deduction);
+    ledger.insert(Key::Exemptions, exemptions);
     ledger.insert(Key::TaxableIncome, taxable_income);
     ledger.insert(Key::RegularTax, regular_tax);
+    ledger.insert(Key::ChildInvestmentTax, child_tax);
+    ledger.insert(Key::SelfEmploymentTax, se_tax);
+    ledger.insert(Key::HsaAdditionalTax, hsa_tax.additional_tax);
+    ledger.insert(Key::UnreportedTipsTax, tips_tax);
+    ledger.insert(Key::ExcessAdvancePremiumTaxCredit, excess_advance_ptc);
     ledger.insert(Key::AdditionalTax, additional_tax);
     ledger.insert(Key::TotalTaxPreCredits, total_tax_pre_credits);
     ledger.insert(Key::NonRefundableCredits, nonrefundable_credits);
     ledger.insert(Key::TaxAfterNonRefundableCredits, tax_after_nonrefundable);
+    ledger.insert(Key::NetPremiumTaxCredit, net_ptc);
     ledger.insert(Key::RefundableCredits, refundable_credits);
     ledger.insert(Key::TotalTax, total_tax);
+    insert_payments(&mut ledger, input);
+    insert_balance(&mut ledger);
+
+    Ok(ledger)
+}
+
+/// Sets the payment lines (Form 1040, lines 25–33) from `input`. They do
+/// not depend on any other line.
+pub(crate) fn insert_payments(ledger: &mut Ledger, input: &ReturnInput) {
+    let withholding =
+        input.fed_withholding + withholding::reported_total(&input.form_1099_withholding);
+    // TODO: estimated tax payments, amount applied from prior year, etc.
+    let estimated_payments = Usd::ZERO;
+    let total_payments = withholding + estimated_payments + input.extension_payment;
+
     ledger.insert(Key::Withholding, withholding);
     ledger.insert(Key::EstimatedPayments, estimated_payments);
+    ledger.insert(Key::ExtensionPayment, input.extension_payment);
     ledger.insert(Key::TotalPayments, total_payments);
-    ledger.insert(Key::Refund, refund);
-    ledger.insert(Key::AmountOwed, owed);
+}
 
-    Ok(ledger)
+/// Sets the refund or amount owed from the total tax and total payments
+/// already in `ledger`.
+pub(crate) fn insert_balance(ledger: &mut Ledger) {
+    let net = ledger[&Key::TotalPayments] - ledger[&Key::TotalTax];
+    ledger.insert(Key::Refund, net.max(Usd::ZERO));
+    ledger.insert(Key::AmountOwed, (Usd::ZERO - net).max(Usd::ZERO));
+}
+
+/// Tax on `taxable_income` (Form 1040, line 16): from the Qualified
+/// Dividends and Capital Gain Tax Worksheet if there are qualified
+/// dividends or net capital gain, otherwise from [`tax_on`].
+fn regular_tax(
+    rules: &dyn TaxYearRules,
+    filing_status: FilingStatus,
+    taxable_income: Usd,
+    qualified_dividends: Usd,
+    net_capital_gain: Usd,
+) -> Result<Usd, Error> {
+    if qualified_dividends + net_capital_gain > Usd::ZERO {
+        capital_gain_tax::compute(
+            rules,
+            filing_status,
+            taxable_income,
+            qualified_dividends,
+            net_capital_gain,
+        )
+        .map(|w| w.tax)
+    } else {
+        tax_on(rules, filing_status, taxable_income)
+    }
+}
+
+/// Tax on `taxable` income from the Tax Table or Tax Computation Worksheet,
+/// or for rules that [use the rate schedule](TaxYearRules::uses_rate_schedule),
+/// the rate schedule.
+pub(crate) fn tax_on(
+    rules: &dyn TaxYearRules,
+    filing_status: FilingStatus,
+    taxable: Usd,
+) -> Result<Usd, Error> {
+    let table_year = rules::tax_year(rules.calendar_year());
+    let Some(table_year) = table_year.filter(|_| !rules.uses_rate_schedule()) else {
+        let brackets = rules.ordinary_brackets(filing_status);
+        return Ok(rules::rate_schedule_tax(&brackets, taxable.irs_round()).irs_round());
+    };
+    // compute_tax expects whole dollars; convert via IRS rounding.
+    let whole_dollars = taxable.irs_round().cents() / 100;
+    let tax = us_tax_brackets::compute_tax(table_year, filing_status, whole_dollars).map_err(
+        |source| Error::TaxCompute {
+            key: Key::RegularTax,
+            source,
+        },
+    )?;
+    Ok(Usd::from_dollars(tax))
 }
 
 // ---------------------------------------------------------------------------
@@ -187,45 +682,43 @@ pub fn compute_spine(rules: &dyn TaxYearRules, input: &ReturnInput) -> Result<Le
 // ---------------------------------------------------------------------------
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
+    use us_tax_brackets::TaxYear;
+
     use super::*;
+    use crate::Date;
+    use crate::decedent::{DeceasedFiler, FinalReturnSigner};
+    use crate::home_sale::SaleReason;
+    use crate::period::{PeriodError, ShortPeriodReason};
     use crate::rules::y2025::Rules2025;
+    use crate::rules::y2026::Rules2026Projected;
+    use crate::withholding::WithholdingSource;
 
-    fn input(wages: i64, withholding: i64) -> ReturnInput {
+    /// A single 2025 return with only wages and withholding.
+    pub(crate) fn input(wages: i64, withholding: i64) -> ReturnInput {
         ReturnInput {
-            tax_year: TaxYear::Y2025,
-            filing_status: FilingStatus::Single,
-            taxpayer: Filer::default(),
-            spouse: None,
-            is_dependent: false,
-            is_dual_status_alien: false,
-            spouse_itemizes: false,
             w2_wages: Usd::from_dollars(wages),
             fed_withholding: Usd::from_dollars(withholding),
+            ..ReturnInput::new(2025, FilingStatus::Single)
         }
     }
 
     #[test]
     fn year_mismatch() {
         let inp = ReturnInput {
-            tax_year: TaxYear::Y2024,
-            filing_status: FilingStatus::Single,
-            taxpayer: Filer::default(),
-            spouse: None,
-            is_dependent: false,
-            is_dual_status_alien: false,
-            spouse_itemizes: false,
-            w2_wages: Usd::from_dollars(50_000),
-            fed_withholding: Usd::ZERO,
+            tax_year: 2024,
+            ..input(50_000, 0)
         };
         let err = compute_spine(&Rules2025, &inp).unwrap_err();
         assert!(matches!(
             err,
-            SpineError::YearMismatch {
-                input: TaxYear::Y2024,
-                rules: TaxYear::Y2025,
+            Error::YearMismatch {
+                input: 2024,
+                rules: 2025,
             }
         ));
+        let err = compute_spine(&Rules2026Projected, &input(50_000, 0)).unwrap_err();
+        assert_eq!(err.to_string(), "tax year mismatch: input=2025, rules=2026");
     }
 
     #[test]
@@ -263,20 +756,39 @@ mod tests {
     fn ledger_has_all_keys() {
         let ledger = compute_spine(&Rules2025, &input(50_000, 5_000)).unwrap();
         let expected = [
+            Key::TaxableInterest,
+            Key::OrdinaryDividends,
+            Key::TaxableSocialSecurity,
+            Key::CapitalGainOrLoss,
+            Key::SupplementalIncome,
+            Key::TaxableHsaDistributions,
+            Key::TaxableScholarships,
+            Key::ChildInvestmentIncome,
+            Key::NolDeduction,
             Key::TotalIncome,
+            Key::SelfEmploymentTaxDeduction,
             Key::Adjustments,
             Key::AGI,
             Key::Deductions,
+            Key::QbiDeduction,
+            Key::Exemptions,
             Key::TaxableIncome,
             Key::RegularTax,
+            Key::ChildInvestmentTax,
+            Key::SelfEmploymentTax,
+            Key::HsaAdditionalTax,
+            Key::UnreportedTipsTax,
+            Key::ExcessAdvancePremiumTaxCredit,
             Key::AdditionalTax,
             Key::TotalTaxPreCredits,
             Key::NonRefundableCredits,
             Key::TaxAfterNonRefundableCredits,
+            Key::NetPremiumTaxCredit,
             Key::RefundableCredits,
             Key::TotalTax,
             Key::Withholding,
             Key::EstimatedPayments,
+            Key::ExtensionPayment,
             Key::TotalPayments,
             Key::Refund,
             Key::AmountOwed,
@@ -286,6 +798,419 @@ mod tests {
         }
     }
 
+    #[test]
+    fn social_security_partially_taxable() {
+        let mut inp = input(30_000, 0);
+        inp.social_security_benefits = Usd::from_dollars(20_000);
+        inp.form_1099_withholding.push(ReportedWithholding {
+            source: WithholdingSource::Ssa1099,
+            payer_name: String::new(),
+            payer_tin: String::new(),
+            amount: Usd::from_dollars(1_000),
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        // line 7 = 10,000 + 30,000 = 40,000 → 9,600 taxable (see worksheet tests)
+        assert_eq!(
+            ledger[&Key::TaxableSocialSecurity],
+            Usd::from_dollars(9_600)
+        );
+        assert_eq!(ledger[&Key::TotalIncome], Usd::from_dollars(39_600));
+        assert_eq!(ledger[&Key::Withholding], Usd::from_dollars(1_000));
+    }
+
+    #[test]
+    fn nonqualified_hsa_distribution_taxed() {
+        let mut inp = input(50_000, 0);
+        inp.hsa_distributions.gross_distributions = Usd::from_dollars(2_000);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(
+            ledger[&Key::TaxableHsaDistributions],
+            Usd::from_dollars(2_000)
+        );
+        assert_eq!(ledger[&Key::TotalIncome], Usd::from_dollars(52_000));
+        assert_eq!(ledger[&Key::HsaAdditionalTax], Usd::from_dollars(400));
+        assert_eq!(
+            ledger[&Key::TotalTaxPreCredits],
+            ledger[&Key::RegularTax] + Usd::from_dollars(400)
+        );
+    }
+
+    #[test]
+    fn excess_scholarship_is_income() {
+        let mut inp = input(0, 0);
+        inp.students.push(StudentExpenses {
+            tuition_and_fees: Usd::from_dollars(10_000),
+            scholarships: Usd::from_dollars(14_000),
+            ..Default::default()
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::TaxableScholarships], Usd::from_dollars(4_000));
+        assert_eq!(ledger[&Key::TotalIncome], Usd::from_dollars(4_000));
+    }
+
+    #[test]
+    fn partnership_income_and_qbi_deduction() {
+        let mut inp = input(0, 0);
+        inp.passthroughs.push(PassthroughItems {
+            entity_name: "Acme LP".to_string(),
+            entity_ein: "12-3456789".to_string(),
+            kind: passthrough::EntityKind::Partnership,
+            owner: Owner::Taxpayer,
+            material_participation: true,
+            ordinary_income: Usd::from_dollars(60_000),
+            rental_income: Usd::ZERO,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: Usd::ZERO,
+            section_179_deduction: Usd::ZERO,
+            interest: Usd::from_dollars(1_000),
+            ordinary_dividends: Usd::from_dollars(2_000),
+            qualified_dividends: Usd::from_dollars(1_500),
+            net_short_term_capital_gain: Usd::ZERO,
+            net_long_term_capital_gain: Usd::ZERO,
+            self_employment_earnings: Usd::from_dollars(60_000),
+            qualified_business_income: Usd::from_dollars(60_000),
+            loss_limits: passthrough::LossLimits::default(),
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::TaxableInterest], Usd::from_dollars(1_000));
+        assert_eq!(ledger[&Key::OrdinaryDividends], Usd::from_dollars(2_000));
+        assert_eq!(ledger[&Key::SupplementalIncome], Usd::from_dollars(60_000));
+        assert_eq!(ledger[&Key::TotalIncome], Usd::from_dollars(63_000));
+        // 15.3% of 92.35% of 60,000, half of it deductible.
+        assert_eq!(ledger[&Key::SelfEmploymentTax], Usd::from_cents(847_773));
+        assert_eq!(
+            ledger[&Key::SelfEmploymentTaxDeduction],
+            Usd::from_cents(423_887)
+        );
+        assert_eq!(ledger[&Key::AGI], Usd::from_cents(5_876_113));
+        // min(20% × 60,000, 20% × (43,011.13 − 1,500)) = 8,302.23
+        assert_eq!(ledger[&Key::QbiDeduction], Usd::from_cents(830_223));
+        assert_eq!(ledger[&Key::TaxableIncome], Usd::from_cents(3_470_890));
+        assert_eq!(ledger[&Key::AdditionalTax], ledger[&Key::SelfEmploymentTax]);
+    }
+
+    /// A partnership K-1 whose ordinary income is all self-employment
+    /// earnings.
+    fn self_employment_k1(owner: Owner, earnings: i64) -> PassthroughItems {
+        PassthroughItems {
+            entity_name: "Acme LP".to_string(),
+            entity_ein: "12-3456789".to_string(),
+            kind: passthrough::EntityKind::Partnership,
+            owner,
+            material_participation: true,
+            ordinary_income: Usd::from_dollars(earnings),
+            rental_income: Usd::ZERO,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: Usd::ZERO,
+            section_179_deduction: Usd::ZERO,
+            interest: Usd::ZERO,
+            ordinary_dividends: Usd::ZERO,
+            qualified_dividends: Usd::ZERO,
+            net_short_term_capital_gain: Usd::ZERO,
+            net_long_term_capital_gain: Usd::ZERO,
+            self_employment_earnings: Usd::from_dollars(earnings),
+            qualified_business_income: Usd::ZERO,
+            loss_limits: passthrough::LossLimits::default(),
+        }
+    }
+
+    #[test]
+    fn each_spouse_has_their_own_wage_base() {
+        let mut inp = ReturnInput {
+            filing_status: FilingStatus::MarriedFilingJointly,
+            spouse: Some(Filer::default()),
+            social_security_wages: Usd::from_dollars(176_100),
+            ..input(176_100, 0)
+        };
+        inp.passthroughs
+            .push(self_employment_k1(Owner::Taxpayer, 60_000));
+        inp.passthroughs
+            .push(self_employment_k1(Owner::Spouse, 60_000));
+        let [taxpayer, spouse] = inp.schedule_se(&Rules2025);
+        // The taxpayer's wages use up their wage base, leaving only the
+        // Medicare part; the spouse's earnings are taxed in full.
+        assert_eq!(taxpayer.social_security_tax, Usd::ZERO);
+        assert_eq!(taxpayer.medicare_tax, Usd::from_cents(160_689));
+        assert_eq!(spouse.tax(), Usd::from_cents(847_773));
+
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::SelfEmploymentTax], Usd::from_cents(1_008_462));
+        assert_eq!(
+            ledger[&Key::SelfEmploymentTaxDeduction],
+            Usd::from_cents(80_345 + 423_887)
+        );
+    }
+
+    #[test]
+    fn self_employment_is_earned_income_for_a_dependent() {
+        let mut inp = ReturnInput {
+            is_dependent: true,
+            ..input(0, 0)
+        };
+        inp.passthroughs
+            .push(self_employment_k1(Owner::Taxpayer, 10_000));
+        // Net earnings of 9,235 less the 706.48 deduction, plus 450.
+        assert_eq!(inp.standard_deduction(&Rules2025), Usd::from_cents(897_852));
+    }
+
+    #[test]
+    fn extension_payment_counts_toward_payments() {
+        let mut inp = input(60_000, 4_000);
+        inp.extension_payment = Usd::from_dollars(1_500);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::ExtensionPayment], Usd::from_dollars(1_500));
+        assert_eq!(ledger[&Key::TotalPayments], Usd::from_dollars(5_500));
+    }
+
+    #[test]
+    fn home_sale_gain_over_exclusion_on_schedule_d() {
+        let mut inp = input(60_000, 0);
+        inp.home_sales.push(HomeSale {
+            date_acquired: Date::new(2020, 1, 10).unwrap(),
+            date_sold: Date::new(2025, 3, 1).unwrap(),
+            selling_price: Usd::from_dollars(700_000),
+            selling_expenses: Usd::from_dollars(40_000),
+            cost: Usd::from_dollars(400_000),
+            improvements: Usd::ZERO,
+            depreciation: Usd::ZERO,
+            ownership_days: 1_800,
+            use_days: 1_800,
+            spouse_use_days: 0,
+            days_since_prior_exclusion: None,
+            nonqualified_use_days: 0,
+            reason: SaleReason::Other,
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::CapitalGainOrLoss], Usd::from_dollars(10_000));
+        // Of taxable income of 54,250, the gain is taxed 4,100 at 0% and
+        // 5,900 at 15%.
+        let ordinary = tax_on(&Rules2025, FilingStatus::Single, Usd::from_dollars(44_250)).unwrap();
+        assert_eq!(ledger[&Key::RegularTax], ordinary + Usd::from_dollars(885));
+    }
+
+    #[test]
+    fn capital_loss_carryover_used_and_carried_forward() {
+        let base = compute_spine(&Rules2025, &input(60_000, 0)).unwrap();
+        let mut inp = input(60_000, 0);
+        inp.capital_loss_carryover = CapitalLossCarryover {
+            short_term: Usd::from_dollars(1_000),
+            long_term: Usd::from_dollars(7_000),
+        };
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::CapitalGainOrLoss], Usd::from_dollars(-3_000));
+        assert_eq!(
+            ledger[&Key::AGI],
+            base[&Key::AGI] - Usd::from_dollars(3_000)
+        );
+        assert_eq!(
+            inp.next_capital_loss_carryover(&Rules2025, &ledger),
+            CapitalLossCarryover {
+                short_term: Usd::ZERO,
+                long_term: Usd::from_dollars(5_000),
+            }
+        );
+    }
+
+    #[test]
+    fn nol_deduction_limited_to_eighty_percent() {
+        let mut inp = input(65_750, 0);
+        inp.nol_carryovers.push(NolCarryover {
+            year: 2023,
+            amount: Usd::from_dollars(100_000),
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        // 80% of 65,750 − 15,750
+        assert_eq!(ledger[&Key::NolDeduction], Usd::from_dollars(40_000));
+        assert_eq!(ledger[&Key::AGI], Usd::from_dollars(25_750));
+        assert_eq!(
+            inp.next_nol_carryovers(&Rules2025, &ledger),
+            vec![NolCarryover {
+                year: 2023,
+                amount: Usd::from_dollars(60_000),
+            }]
+        );
+    }
+
+    #[test]
+    fn child_interest_and_dividends_on_parent_return() {
+        let base = compute_spine(&Rules2025, &input(60_000, 0)).unwrap();
+        let mut inp = input(60_000, 0);
+        inp.children_investment_income.push(ChildInvestmentIncome {
+            taxable_interest: Usd::from_dollars(1_000),
+            ordinary_dividends: Usd::from_dollars(3_000),
+            qualified_dividends: Usd::from_dollars(2_000),
+            ..Default::default()
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        // 4,000 − 2,700 included: 650 as qualified dividends, 650 other.
+        assert_eq!(ledger[&Key::OrdinaryDividends], Usd::from_dollars(650));
+        assert_eq!(ledger[&Key::ChildInvestmentIncome], Usd::from_dollars(650));
+        assert_eq!(
+            ledger[&Key::AGI],
+            base[&Key::AGI] + Usd::from_dollars(1_300)
+        );
+        assert_eq!(ledger[&Key::ChildInvestmentTax], Usd::from_dollars(135));
+        assert_eq!(
+            ledger[&Key::TotalTaxPreCredits],
+            ledger[&Key::RegularTax] + Usd::from_dollars(135)
+        );
+    }
+
+    #[test]
+    fn unreported_tips_taxed_as_wages() {
+        let mut inp = input(30_000, 0);
+        inp.unreported_tips
+            .employers
+            .push(crate::tips::EmployerTips {
+                tips_received: Usd::from_dollars(2_000),
+                ..Default::default()
+            });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::TotalIncome], Usd::from_dollars(32_000));
+        // 7.65% of 2,000
+        assert_eq!(ledger[&Key::UnreportedTipsTax], Usd::from_dollars(153));
+    }
+
+    #[test]
+    fn rental_loss_limited_by_phased_out_allowance() {
+        let mut inp = input(130_000, 0);
+        inp.rental_properties.push(RentalProperty {
+            rents_received: Usd::from_dollars(12_000),
+            expenses: rental::RentalExpenses {
+                mortgage_interest: Usd::from_dollars(30_000),
+                depreciation: Usd::from_dollars(12_000),
+                ..Default::default()
+            },
+            active_participation: true,
+            ..Default::default()
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        // 25,000 − 50% × (130,000 − 100,000) = 10,000 of the 30,000 loss.
+        assert_eq!(ledger[&Key::SupplementalIncome], Usd::from_dollars(-10_000));
+        assert_eq!(ledger[&Key::TotalIncome], Usd::from_dollars(120_000));
+    }
+
+    #[test]
+    fn premium_tax_credit_reconciled() {
+        let month = |advance| premium_tax_credit::CoverageMonth {
+            enrollment_premium: Usd::from_dollars(500),
+            slcsp_premium: Usd::from_dollars(450),
+            advance_payment: Usd::from_dollars(advance),
+        };
+        let mut inp = input(30_120, 0);
+        inp.marketplace_coverage = Some(MarketplaceCoverage {
+            months: [month(300); 12],
+            ..Default::default()
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::NetPremiumTaxCredit], Usd::from_cents(119_760));
+        assert_eq!(ledger[&Key::RefundableCredits], Usd::from_cents(119_760));
+
+        inp.marketplace_coverage = Some(MarketplaceCoverage {
+            months: [month(450); 12],
+            ..Default::default()
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::NetPremiumTaxCredit], Usd::ZERO);
+        // Excess of 602.40 is within the 975 limit at 200% of the poverty line.
+        assert_eq!(
+            ledger[&Key::ExcessAdvancePremiumTaxCredit],
+            Usd::from_cents(60_240)
+        );
+        assert_eq!(
+            ledger[&Key::TotalTaxPreCredits],
+            ledger[&Key::RegularTax] + Usd::from_cents(60_240)
+        );
+    }
+
+    #[test]
+    fn exemption_count() {
+        let mut inp = input(0, 0);
+        inp.num_dependents = 2;
+        assert_eq!(inp.exemption_count(), 3);
+        inp.filing_status = FilingStatus::MarriedFilingJointly;
+        assert_eq!(inp.exemption_count(), 4);
+        inp.is_dependent = true;
+        assert_eq!(inp.exemption_count(), 3);
+    }
+
+    #[test]
+    fn fiscal_year_uses_rules_for_starting_year() {
+        let mut inp = input(50_000, 0);
+        inp.period = Some(TaxPeriod {
+            start: Date::new(2025, 7, 1).unwrap(),
+            end: Date::new(2026, 6, 30).unwrap(),
+            short_period: None,
+        });
+        let fiscal = compute_spine(&Rules2025, &inp).unwrap();
+        let calendar = compute_spine(&Rules2025, &input(50_000, 0)).unwrap();
+        assert_eq!(fiscal, calendar);
+    }
+
+    #[test]
+    fn invalid_period_rejected() {
+        let mut inp = input(50_000, 0);
+        inp.period = Some(TaxPeriod::calendar(2024));
+        let err = compute_spine(&Rules2025, &inp).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidPeriod(PeriodError::WrongYear { .. })
+        ));
+    }
+
+    #[test]
+    fn short_period_for_death_not_annualized() {
+        let mut inp = input(50_000, 0);
+        inp.period = Some(TaxPeriod {
+            start: Date::first_of_year(2025),
+            end: Date::new(2025, 5, 31).unwrap(),
+            short_period: Some(ShortPeriodReason::Death),
+        });
+        let short = compute_spine(&Rules2025, &inp).unwrap();
+        let calendar = compute_spine(&Rules2025, &input(50_000, 0)).unwrap();
+        assert_eq!(short, calendar);
+    }
+
+    #[test]
+    fn final_return_period_from_decedent() {
+        let mut inp = input(50_000, 0);
+        inp.deceased = Some(DeceasedTaxpayer {
+            filer: DeceasedFiler::Taxpayer,
+            name: "Jane Doe".to_string(),
+            date_of_death: Date::new(2025, 4, 10).unwrap(),
+            signer: FinalReturnSigner::PersonalRepresentative,
+            medical_expense_election: false,
+        });
+        let period = inp.effective_period().unwrap();
+        assert_eq!(period.end, Date::new(2025, 4, 10).unwrap());
+        assert!(compute_spine(&Rules2025, &inp).is_ok());
+
+        inp.deceased.as_mut().unwrap().date_of_death = Date::new(2024, 12, 1).unwrap();
+        assert!(matches!(
+            compute_spine(&Rules2025, &inp).unwrap_err(),
+            Error::InvalidPeriod(PeriodError::WrongYear { .. })
+        ));
+    }
+
+    #[test]
+    fn accounting_period_change_annualized() {
+        let mut inp = input(30_000, 0);
+        inp.period = Some(TaxPeriod {
+            start: Date::first_of_year(2025),
+            end: Date::new(2025, 6, 30).unwrap(),
+            short_period: Some(ShortPeriodReason::AccountingPeriodChange),
+        });
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::Deductions], Usd::ZERO);
+        assert_eq!(ledger[&Key::TaxableIncome], Usd::from_dollars(30_000));
+        // Half the tax on 60,000 annualized.
+        let annual = us_tax_brackets::compute_tax(TaxYear::Y2025, FilingStatus::Single, 60_000);
+        assert_eq!(
+            ledger[&Key::RegularTax],
+            Usd::from_dollars(annual.unwrap()).mul_div(6, 12)
+        );
+    }
+
     #[test]
     fn zero_wages_zero_withholding() {
         let ledger = compute_spine(&Rules2025, &input(0, 0)).unwrap();
@@ -0,0 +1,169 @@
+use crate::state::State;
+use crate::{Date, Usd};
+
+/// Days of the year the taxpayer was domiciled in `state`, both ends
+/// included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResidencyPeriod {
+    pub state: State,
+    pub start: Date,
+    pub end: Date,
+}
+
+impl ResidencyPeriod {
+    /// Days of the period that fall in `year`.
+    fn days_in(self, year: i32) -> i64 {
+        let start = self.start.max(Date::first_of_year(year));
+        let end = self.end.min(Date::last_of_year(year));
+        (start.days_until(end) + 1).max(0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IncomeKind {
+    Wages,
+    Interest,
+    Dividends,
+    Business,
+    Other,
+}
+
+/// One item of income to allocate among states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncomeItem {
+    pub kind: IncomeKind,
+    pub amount: Usd,
+    /// Where the income was earned, for wages from work in a state or
+    /// business income from property or activity there. `None` for income
+    /// with no source apart from the owner's domicile, such as interest
+    /// and dividends.
+    pub source: Option<State>,
+}
+
+/// The income a state may tax, by kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StateAllocation {
+    pub wages: Usd,
+    pub interest: Usd,
+    pub dividends: Usd,
+    pub business: Usd,
+    pub other: Usd,
+}
+
+impl StateAllocation {
+    pub fn total(&self) -> Usd {
+        self.wages + self.interest + self.dividends + self.business + self.other
+    }
+
+    fn add(&mut self, kind: IncomeKind, amount: Usd) {
+        let field = match kind {
+            IncomeKind::Wages => &mut self.wages,
+            IncomeKind::Interest => &mut self.interest,
+            IncomeKind::Dividends => &mut self.dividends,
+            IncomeKind::Business => &mut self.business,
+            IncomeKind::Other => &mut self.other,
+        };
+        *field += amount;
+    }
+}
+
+/// Allocates `items` to `state` for `year`.
+///
+/// A state taxes its residents on all income received while resident, and
+/// nonresidents on income sourced there. Each item is taken to be received
+/// evenly through the year: the state gets the share of days the taxpayer
+/// lived there, plus, for income sourced in the state, the rest. Days not
+/// covered by any period count as nonresident.
+pub fn allocate(
+    state: State,
+    year: i32,
+    residency: &[ResidencyPeriod],
+    items: &[IncomeItem],
+) -> StateAllocation {
+    let year_days = Date::first_of_year(year).days_until(Date::last_of_year(year)) + 1;
+    let resident_days: i64 = residency
+        .iter()
+        .filter(|p| p.state == state)
+        .map(|p| p.days_in(year))
+        .sum::<i64>()
+        .min(year_days);
+    let mut allocation = StateAllocation::default();
+    for item in items {
+        let days = if item.source == Some(state) {
+            year_days
+        } else {
+            resident_days
+        };
+        allocation.add(item.kind, item.amount.mul_div(days, year_days));
+    }
+    allocation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u8, day: u8) -> Date {
+        Date::new(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn part_year_residents_split_by_days() {
+        let residency = [
+            ResidencyPeriod {
+                state: State::IL,
+                start: date(1, 1),
+                end: date(6, 30),
+            },
+            ResidencyPeriod {
+                state: State::CA,
+                start: date(7, 1),
+                end: date(12, 31),
+            },
+        ];
+        let items = [
+            IncomeItem {
+                kind: IncomeKind::Wages,
+                amount: Usd::from_dollars(73_000),
+                source: None,
+            },
+            IncomeItem {
+                kind: IncomeKind::Interest,
+                amount: Usd::from_dollars(365),
+                source: None,
+            },
+        ];
+        // 181 days in Illinois, 184 in California.
+        let il = allocate(State::IL, 2025, &residency, &items);
+        let ca = allocate(State::CA, 2025, &residency, &items);
+        assert_eq!(il.wages, Usd::from_dollars(36_200));
+        assert_eq!(ca.wages, Usd::from_dollars(36_800));
+        assert_eq!(il.interest + ca.interest, Usd::from_dollars(365));
+    }
+
+    #[test]
+    fn source_state_taxes_nonresidents() {
+        let residency = [ResidencyPeriod {
+            state: State::NV,
+            start: date(1, 1),
+            end: date(12, 31),
+        }];
+        let items = [
+            IncomeItem {
+                kind: IncomeKind::Business,
+                amount: Usd::from_dollars(80_000),
+                source: Some(State::CA),
+            },
+            IncomeItem {
+                kind: IncomeKind::Dividends,
+                amount: Usd::from_dollars(5_000),
+                source: None,
+            },
+        ];
+        let ca = allocate(State::CA, 2025, &residency, &items);
+        assert_eq!(ca.business, Usd::from_dollars(80_000));
+        assert_eq!(ca.total(), Usd::from_dollars(80_000));
+        let nv = allocate(State::NV, 2025, &residency, &items);
+        assert_eq!(nv.total(), Usd::from_dollars(85_000));
+    }
+}
@@ -0,0 +1,259 @@
+use us_tax_brackets::{FilingStatus, TaxYear};
+
+use crate::ledger::Key;
+use crate::rules::Bracket;
+use crate::state::{State, StateReturn, StateRules};
+use crate::{Rate, Usd};
+
+/// Rates of the nine brackets (Cal. Rev. & Tax. Code §17041).
+const RATES: [Rate; 9] = [
+    Rate::from_percent(1),
+    Rate::from_percent(2),
+    Rate::from_percent(4),
+    Rate::from_percent(6),
+    Rate::from_percent(8),
+    Rate::from_bps(930),
+    Rate::from_bps(1_030),
+    Rate::from_bps(1_130),
+    Rate::from_bps(1_230),
+];
+
+/// California resident income tax for 2025 (Form 540).
+///
+/// Tax is figured on the rate schedules; the tax table FTB publishes for
+/// taxable income up to $100,000 taxes the midpoint of each row and can
+/// differ by a dollar.
+pub struct California2025;
+
+impl California2025 {
+    /// Federal AGI above which exemption credits and itemized deductions
+    /// are reduced.
+    fn phaseout_threshold(status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match status {
+            Single | MarriedFilingSeparately => 252_203,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => 504_411,
+            HeadOfHousehold => 378_310,
+        })
+    }
+
+    /// Exemption credits (Form 540, lines 7–11), each reduced by $6 for
+    /// every $2,500 ($1,250 if married filing separately), or part of it,
+    /// of federal AGI over the threshold.
+    // TODO: the worksheet for taxpayers someone else can claim as a dependent
+    pub fn exemption_credits(&self, ret: &StateReturn<'_>) -> Usd {
+        let status = ret.filing_status();
+        let input = ret.federal_input;
+        let joint = matches!(
+            status,
+            FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse
+        );
+        let spouse = if status == FilingStatus::MarriedFilingJointly {
+            input.spouse.unwrap_or_default().checked_boxes()
+        } else {
+            0
+        };
+        let personal = 1 + i64::from(joint) + input.taxpayer.checked_boxes() + spouse;
+        let dependents = i64::from(input.num_dependents);
+
+        let excess = (ret.federal_agi - Self::phaseout_threshold(status)).max(Usd::ZERO);
+        let step = if status == FilingStatus::MarriedFilingSeparately {
+            125_000
+        } else {
+            250_000
+        };
+        let reduction = 6 * ((excess.cents() + step - 1) / step);
+        let credits =
+            |count: i64, amount: i64| Usd::from_dollars(count * (amount - reduction).max(0));
+        credits(personal, 153) + credits(dependents, 475)
+    }
+
+    /// Renter's credit: $60, or $120 if married filing jointly, head of
+    /// household, or qualifying surviving spouse, when California AGI is at
+    /// most $53,994 ($107,988).
+    pub fn renters_credit(&self, ret: &StateReturn<'_>, state_agi: Usd) -> Usd {
+        if !ret.input.rented_principal_residence {
+            return Usd::ZERO;
+        }
+        let (credit, limit) = match ret.filing_status() {
+            FilingStatus::Single | FilingStatus::MarriedFilingSeparately => (60, 53_994),
+            _ => (120, 107_988),
+        };
+        if state_agi <= Usd::from_dollars(limit) {
+            Usd::from_dollars(credit)
+        } else {
+            Usd::ZERO
+        }
+    }
+}
+
+impl StateRules for California2025 {
+    fn state(&self) -> State {
+        State::CA
+    }
+
+    fn year(&self) -> TaxYear {
+        TaxYear::Y2025
+    }
+
+    /// Schedule CA, Part I, column C: California does not recognize HSAs,
+    /// so the HSA deduction is added back.
+    fn additions(&self, ret: &StateReturn<'_>) -> Usd {
+        ret.input.additions + ret.federal_input.adjustments.hsa_deduction
+    }
+
+    /// Schedule CA, Part I, column B: Social Security benefits are exempt.
+    fn subtractions(&self, ret: &StateReturn<'_>) -> Usd {
+        let social_security = ret
+            .federal
+            .get(&Key::TaxableSocialSecurity)
+            .copied()
+            .unwrap_or(Usd::ZERO);
+        ret.input.subtractions + social_security
+    }
+
+    // TODO: the limited standard deduction for dependents
+    fn standard_deduction(&self, ret: &StateReturn<'_>) -> Usd {
+        use FilingStatus::*;
+        Usd::from_dollars(match ret.filing_status() {
+            Single | MarriedFilingSeparately => 5_706,
+            MarriedFilingJointly | QualifyingSurvivingSpouse | HeadOfHousehold => 11_412,
+        })
+    }
+
+    /// Itemized deductions are reduced by the lesser of 6% of federal AGI
+    /// over the threshold or 80% of the deductions (Schedule CA, Part II,
+    /// line 29). The 80% limit is taken on all itemized deductions,
+    /// including those the law exempts from it.
+    fn deduction(&self, ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+        let itemized = ret.input.itemized_deductions;
+        let excess =
+            (ret.federal_agi - Self::phaseout_threshold(ret.filing_status())).max(Usd::ZERO);
+        let reduction = (excess * Rate::from_percent(6)).min(itemized * Rate::from_percent(80));
+        self.standard_deduction(ret).max(itemized - reduction)
+    }
+
+    fn brackets(&self, status: FilingStatus) -> Vec<Bracket> {
+        use FilingStatus::*;
+        let single = [
+            0, 11_079, 26_264, 41_452, 57_542, 72_724, 371_479, 445_771, 742_953,
+        ];
+        let floors = match status {
+            Single | MarriedFilingSeparately => single,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => single.map(|f| 2 * f),
+            HeadOfHousehold => [
+                0, 22_173, 52_530, 67_716, 83_805, 98_990, 505_208, 606_251, 1_010_417,
+            ],
+        };
+        floors
+            .iter()
+            .zip(RATES)
+            .map(|(&floor, rate)| Bracket {
+                floor: Usd::from_dollars(floor),
+                rate,
+            })
+            .collect()
+    }
+
+    /// Mental Health Services Tax: 1% of taxable income over $1,000,000
+    /// (Form 540, line 62). Not indexed.
+    fn additional_tax(&self, _ret: &StateReturn<'_>, taxable_income: Usd) -> Usd {
+        ((taxable_income - Usd::from_dollars(1_000_000)).max(Usd::ZERO) * Rate::from_percent(1))
+            .irs_round()
+    }
+
+    fn nonrefundable_credits(&self, ret: &StateReturn<'_>, state_agi: Usd) -> Usd {
+        self.exemption_credits(ret) + self.renters_credit(ret, state_agi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+    use crate::spine::{ReturnInput, compute_spine};
+    use crate::state::tests::state_input;
+    use crate::state::{StateInput, StateKey, StateLedger, compute_state_spine};
+
+    fn run(federal_input: &ReturnInput, state: &StateInput) -> StateLedger {
+        let federal = compute_spine(&Rules2025, federal_input).unwrap();
+        compute_state_spine(&California2025, federal_input, &federal, state).unwrap()
+    }
+
+    #[test]
+    fn single_wage_earner() {
+        let ledger = run(&input(60_000, 0), &state_input(State::CA, 2_000));
+        // 60,000 - 5,706 = 54,294: 110.79 + 303.70 + 607.52 + 770.52.
+        assert_eq!(ledger[&StateKey::TaxableIncome], Usd::from_dollars(54_294));
+        assert_eq!(ledger[&StateKey::Tax], Usd::from_dollars(1_793));
+        // One personal exemption credit; too much income for the renter's
+        // credit.
+        assert_eq!(
+            ledger[&StateKey::NonRefundableCredits],
+            Usd::from_dollars(153)
+        );
+        assert_eq!(ledger[&StateKey::Refund], Usd::from_dollars(360));
+    }
+
+    #[test]
+    fn joint_renters_with_dependents() {
+        let federal_input = ReturnInput {
+            filing_status: FilingStatus::MarriedFilingJointly,
+            num_dependents: 2,
+            ..input(100_000, 0)
+        };
+        let state = StateInput {
+            rented_principal_residence: true,
+            ..state_input(State::CA, 0)
+        };
+        let ledger = run(&federal_input, &state);
+        // 100,000 - 11,412 = 88,588: 221.58 + 607.40 + 1,215.04 + 341.04.
+        assert_eq!(ledger[&StateKey::Tax], Usd::from_dollars(2_385));
+        // 2 × 153 + 2 × 475 + 120.
+        assert_eq!(
+            ledger[&StateKey::NonRefundableCredits],
+            Usd::from_dollars(1_376)
+        );
+        assert_eq!(ledger[&StateKey::TotalTax], Usd::from_dollars(1_009));
+    }
+
+    #[test]
+    fn high_income_phaseouts_and_mental_health_tax() {
+        let ledger = run(&input(300_000, 0), &state_input(State::CA, 0));
+        // 47,797 over the threshold is 20 steps of 2,500: 153 - 120.
+        assert_eq!(
+            ledger[&StateKey::NonRefundableCredits],
+            Usd::from_dollars(33)
+        );
+        assert_eq!(ledger[&StateKey::AdditionalTax], Usd::ZERO);
+
+        let ledger = run(&input(1_200_000, 0), &state_input(State::CA, 0));
+        assert_eq!(ledger[&StateKey::NonRefundableCredits], Usd::ZERO);
+        // 1% of 1,194,294 - 1,000,000.
+        assert_eq!(ledger[&StateKey::AdditionalTax], Usd::from_dollars(1_943));
+    }
+
+    #[test]
+    fn social_security_and_hsa_adjustments() {
+        let mut federal_input = input(40_000, 0);
+        federal_input.social_security_benefits = Usd::from_dollars(20_000);
+        federal_input.adjustments.hsa_deduction = Usd::from_dollars(3_000);
+        let federal = compute_spine(&Rules2025, &federal_input).unwrap();
+        let ledger = compute_state_spine(
+            &California2025,
+            &federal_input,
+            &federal,
+            &state_input(State::CA, 0),
+        )
+        .unwrap();
+        assert_eq!(ledger[&StateKey::Additions], Usd::from_dollars(3_000));
+        assert_eq!(
+            ledger[&StateKey::Subtractions],
+            federal[&Key::TaxableSocialSecurity]
+        );
+        // Federal AGI with the HSA deduction added back and benefits
+        // taken out.
+        assert_eq!(ledger[&StateKey::StateAgi], Usd::from_dollars(40_000));
+    }
+}
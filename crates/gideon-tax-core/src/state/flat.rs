@@ -0,0 +1,357 @@
+use us_tax_brackets::{FilingStatus, TaxYear};
+
+use crate::ledger::Key;
+use crate::rules::Bracket;
+use crate::state::{State, StateReturn, StateRules};
+use crate::{Rate, Usd};
+
+/// Where a state's computation picks up the federal return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartingPoint {
+    /// Federal adjusted gross income; the state's own deductions follow.
+    FederalAgi,
+    /// Federal taxable income: the federal standard or itemized deduction
+    /// and QBI deduction carry over as the state deduction.
+    FederalTaxableIncome,
+}
+
+/// How a state allows personal and dependent exemptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExemptionForm {
+    /// Subtracted from income.
+    Deduction,
+    /// Subtracted from tax, as a nonrefundable credit.
+    Credit,
+}
+
+/// An amount that depends on filing status. Married filing separately
+/// uses the single amount and qualifying surviving spouse the joint
+/// amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusAmounts {
+    pub single: Usd,
+    pub married_filing_jointly: Usd,
+    pub head_of_household: Usd,
+}
+
+impl StatusAmounts {
+    /// The same amount for every filing status.
+    pub const fn all(amount: Usd) -> Self {
+        StatusAmounts {
+            single: amount,
+            married_filing_jointly: amount,
+            head_of_household: amount,
+        }
+    }
+
+    pub fn get(self, status: FilingStatus) -> Usd {
+        use FilingStatus::*;
+        match status {
+            Single | MarriedFilingSeparately => self.single,
+            MarriedFilingJointly | QualifyingSurvivingSpouse => self.married_filing_jointly,
+            HeadOfHousehold => self.head_of_household,
+        }
+    }
+}
+
+/// A state that taxes a modified federal base at one rate, described by
+/// data rather than code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlatTaxState {
+    pub state: State,
+    pub year: TaxYear,
+    pub rate: Rate,
+    pub starting_point: StartingPoint,
+    /// Zero for states without one. Ignored when starting from federal
+    /// taxable income.
+    pub standard_deduction: StatusAmounts,
+    /// For the taxpayer, and the spouse on a joint return.
+    pub personal_exemption: Usd,
+    pub dependent_exemption: Usd,
+    pub exemption_form: ExemptionForm,
+    /// Federal AGI above which no exemptions are allowed.
+    pub exemption_agi_limit: Option<StatusAmounts>,
+    /// Federal ledger lines the state does not tax, subtracted from the
+    /// starting point.
+    pub excluded_income: &'static [Key],
+}
+
+impl FlatTaxState {
+    fn exemption_total(&self, ret: &StateReturn<'_>) -> Usd {
+        let status = ret.filing_status();
+        if let Some(limit) = self.exemption_agi_limit
+            && ret.federal_agi > limit.get(status)
+        {
+            return Usd::ZERO;
+        }
+        let personal = 1 + i64::from(status == FilingStatus::MarriedFilingJointly);
+        let dependents = i64::from(ret.federal_input.num_dependents);
+        self.personal_exemption * personal + self.dependent_exemption * dependents
+    }
+
+    fn exemptions_as(&self, form: ExemptionForm, ret: &StateReturn<'_>) -> Usd {
+        if self.exemption_form == form {
+            self.exemption_total(ret)
+        } else {
+            Usd::ZERO
+        }
+    }
+}
+
+impl StateRules for FlatTaxState {
+    fn state(&self) -> State {
+        self.state
+    }
+
+    fn year(&self) -> TaxYear {
+        self.year
+    }
+
+    fn subtractions(&self, ret: &StateReturn<'_>) -> Usd {
+        let excluded: Usd = self
+            .excluded_income
+            .iter()
+            .filter_map(|key| ret.federal.get(key))
+            .copied()
+            .sum();
+        ret.input.subtractions + excluded
+    }
+
+    fn standard_deduction(&self, ret: &StateReturn<'_>) -> Usd {
+        self.standard_deduction.get(ret.filing_status())
+    }
+
+    fn deduction(&self, ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+        match self.starting_point {
+            StartingPoint::FederalAgi => self
+                .standard_deduction(ret)
+                .max(ret.input.itemized_deductions),
+            StartingPoint::FederalTaxableIncome => {
+                // Federal exemptions are left out; the state allows its own.
+                let line = |key| ret.federal.get(&key).copied().unwrap_or(Usd::ZERO);
+                line(Key::Deductions) + line(Key::QbiDeduction)
+            }
+        }
+    }
+
+    fn exemptions(&self, ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+        self.exemptions_as(ExemptionForm::Deduction, ret)
+    }
+
+    fn brackets(&self, _status: FilingStatus) -> Vec<Bracket> {
+        vec![Bracket {
+            floor: Usd::ZERO,
+            rate: self.rate,
+        }]
+    }
+
+    fn nonrefundable_credits(&self, ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+        self.exemptions_as(ExemptionForm::Credit, ret)
+    }
+}
+
+const fn d(dollars: i64) -> Usd {
+    Usd::from_dollars(dollars)
+}
+
+const NO_DEDUCTION: StatusAmounts = StatusAmounts::all(Usd::ZERO);
+
+/// Flat-tax states for 2025, by state.
+///
+/// Each entry models the base most residents use. Retirement income
+/// exclusions, age and blindness exemptions, and credits other than
+/// exemption credits are not modeled.
+pub const FLAT_TAX_STATES: [FlatTaxState; 9] = [
+    FlatTaxState {
+        state: State::CO,
+        year: TaxYear::Y2025,
+        rate: Rate::from_bps(440),
+        starting_point: StartingPoint::FederalTaxableIncome,
+        standard_deduction: NO_DEDUCTION,
+        personal_exemption: Usd::ZERO,
+        dependent_exemption: Usd::ZERO,
+        exemption_form: ExemptionForm::Deduction,
+        exemption_agi_limit: None,
+        excluded_income: &[],
+    },
+    FlatTaxState {
+        state: State::GA,
+        year: TaxYear::Y2025,
+        rate: Rate::from_bps(519),
+        starting_point: StartingPoint::FederalAgi,
+        standard_deduction: StatusAmounts {
+            single: d(12_000),
+            married_filing_jointly: d(24_000),
+            head_of_household: d(12_000),
+        },
+        personal_exemption: Usd::ZERO,
+        dependent_exemption: d(4_000),
+        exemption_form: ExemptionForm::Deduction,
+        exemption_agi_limit: None,
+        excluded_income: &[Key::TaxableSocialSecurity],
+    },
+    FlatTaxState {
+        state: State::IA,
+        year: TaxYear::Y2025,
+        rate: Rate::from_bps(380),
+        starting_point: StartingPoint::FederalTaxableIncome,
+        standard_deduction: NO_DEDUCTION,
+        personal_exemption: d(40),
+        dependent_exemption: d(40),
+        exemption_form: ExemptionForm::Credit,
+        exemption_agi_limit: None,
+        excluded_income: &[Key::TaxableSocialSecurity],
+    },
+    FlatTaxState {
+        state: State::IL,
+        year: TaxYear::Y2025,
+        rate: Rate::from_bps(495),
+        starting_point: StartingPoint::FederalAgi,
+        standard_deduction: NO_DEDUCTION,
+        personal_exemption: d(2_850),
+        dependent_exemption: d(2_850),
+        exemption_form: ExemptionForm::Deduction,
+        exemption_agi_limit: Some(StatusAmounts {
+            single: d(250_000),
+            married_filing_jointly: d(500_000),
+            head_of_household: d(250_000),
+        }),
+        excluded_income: &[Key::TaxableSocialSecurity],
+    },
+    // $1,000 per exemption plus $1,500 more per dependent child; every
+    // dependent is taken to be a child.
+    FlatTaxState {
+        state: State::IN,
+        year: TaxYear::Y2025,
+        rate: Rate::from_percent(3),
+        starting_point: StartingPoint::FederalAgi,
+        standard_deduction: NO_DEDUCTION,
+        personal_exemption: d(1_000),
+        dependent_exemption: d(2_500),
+        exemption_form: ExemptionForm::Deduction,
+        exemption_agi_limit: None,
+        excluded_income: &[Key::TaxableSocialSecurity],
+    },
+    FlatTaxState {
+        state: State::KY,
+        year: TaxYear::Y2025,
+        rate: Rate::from_percent(4),
+        starting_point: StartingPoint::FederalAgi,
+        standard_deduction: StatusAmounts::all(d(3_270)),
+        personal_exemption: Usd::ZERO,
+        dependent_exemption: Usd::ZERO,
+        exemption_form: ExemptionForm::Deduction,
+        exemption_agi_limit: None,
+        excluded_income: &[Key::TaxableSocialSecurity],
+    },
+    FlatTaxState {
+        state: State::MI,
+        year: TaxYear::Y2025,
+        rate: Rate::from_bps(425),
+        starting_point: StartingPoint::FederalAgi,
+        standard_deduction: NO_DEDUCTION,
+        personal_exemption: d(5_800),
+        dependent_exemption: d(5_800),
+        exemption_form: ExemptionForm::Deduction,
+        exemption_agi_limit: None,
+        excluded_income: &[Key::TaxableSocialSecurity],
+    },
+    FlatTaxState {
+        state: State::NC,
+        year: TaxYear::Y2025,
+        rate: Rate::from_bps(425),
+        starting_point: StartingPoint::FederalAgi,
+        standard_deduction: StatusAmounts {
+            single: d(12_750),
+            married_filing_jointly: d(25_500),
+            head_of_household: d(19_125),
+        },
+        personal_exemption: Usd::ZERO,
+        dependent_exemption: Usd::ZERO,
+        exemption_form: ExemptionForm::Deduction,
+        exemption_agi_limit: None,
+        excluded_income: &[Key::TaxableSocialSecurity],
+    },
+    // Pennsylvania taxes eight classes of income rather than federal AGI;
+    // starting from AGI approximates the common case of wages, interest,
+    // and dividends.
+    FlatTaxState {
+        state: State::PA,
+        year: TaxYear::Y2025,
+        rate: Rate::from_bps(307),
+        starting_point: StartingPoint::FederalAgi,
+        standard_deduction: NO_DEDUCTION,
+        personal_exemption: Usd::ZERO,
+        dependent_exemption: Usd::ZERO,
+        exemption_form: ExemptionForm::Deduction,
+        exemption_agi_limit: None,
+        excluded_income: &[Key::TaxableSocialSecurity],
+    },
+];
+
+/// The flat-tax rules for `state` in `year`, or `None` if it is not one.
+pub fn flat_tax_state(state: State, year: TaxYear) -> Option<&'static FlatTaxState> {
+    FLAT_TAX_STATES
+        .iter()
+        .find(|s| s.state == state && s.year == year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::tests::input;
+    use crate::spine::{ReturnInput, compute_spine};
+    use crate::state::tests::state_input;
+    use crate::state::{StateKey, StateLedger, compute_state_spine};
+
+    fn run(state: State, federal_input: &ReturnInput) -> StateLedger {
+        let rules = flat_tax_state(state, TaxYear::Y2025).unwrap();
+        let federal = compute_spine(&Rules2025, federal_input).unwrap();
+        compute_state_spine(rules, federal_input, &federal, &state_input(state, 0)).unwrap()
+    }
+
+    #[test]
+    fn exemptions_as_deductions() {
+        let ledger = run(State::IL, &input(60_000, 0));
+        // 4.95% of 60,000 - 2,850 = 2,828.93.
+        assert_eq!(ledger[&StateKey::TaxableIncome], d(57_150));
+        assert_eq!(ledger[&StateKey::TotalTax], d(2_829));
+
+        // No exemption above the limit.
+        let ledger = run(State::IL, &input(260_000, 0));
+        assert_eq!(ledger[&StateKey::Exemptions], Usd::ZERO);
+    }
+
+    #[test]
+    fn federal_taxable_income_start_with_exemption_credits() {
+        let federal_input = ReturnInput {
+            filing_status: FilingStatus::MarriedFilingJointly,
+            num_dependents: 1,
+            ..input(100_000, 0)
+        };
+        let federal = compute_spine(&Rules2025, &federal_input).unwrap();
+        let ledger = run(State::IA, &federal_input);
+        assert_eq!(
+            ledger[&StateKey::TaxableIncome],
+            federal[&Key::TaxableIncome]
+        );
+        // 3.8% of 68,500 = 2,603, less three $40 credits.
+        assert_eq!(ledger[&StateKey::Tax], d(2_603));
+        assert_eq!(ledger[&StateKey::TotalTax], d(2_483));
+    }
+
+    #[test]
+    fn excluded_income_and_lookup() {
+        let mut federal_input = input(30_000, 0);
+        federal_input.social_security_benefits = d(24_000);
+        let federal = compute_spine(&Rules2025, &federal_input).unwrap();
+        let ledger = run(State::PA, &federal_input);
+        assert_eq!(
+            ledger[&StateKey::Subtractions],
+            federal[&Key::TaxableSocialSecurity]
+        );
+        assert_eq!(ledger[&StateKey::StateAgi], d(30_000));
+        assert!(flat_tax_state(State::CA, TaxYear::Y2025).is_none());
+    }
+}
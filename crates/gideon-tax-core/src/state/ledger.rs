@@ -0,0 +1,184 @@
+use std::fmt;
+use std::ops::Index;
+
+use crate::Usd;
+
+/// A line computed by the state spine, in the order most state resident
+/// returns follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StateKey {
+    FederalAgi,
+    Additions,
+    Subtractions,
+    StateAgi,
+    StateSourceIncome,
+    Deductions,
+    Exemptions,
+    TaxableIncome,
+    Tax,
+    AdditionalTax,
+    NonRefundableCredits,
+    TaxAfterNonRefundableCredits,
+    RefundableCredits,
+    TotalTax,
+    Withholding,
+    EstimatedPayments,
+    TotalPayments,
+    Refund,
+    AmountOwed,
+}
+
+impl StateKey {
+    /// Every key, in form order.
+    pub const ALL: [StateKey; 19] = [
+        StateKey::FederalAgi,
+        StateKey::Additions,
+        StateKey::Subtractions,
+        StateKey::StateAgi,
+        StateKey::StateSourceIncome,
+        StateKey::Deductions,
+        StateKey::Exemptions,
+        StateKey::TaxableIncome,
+        StateKey::Tax,
+        StateKey::AdditionalTax,
+        StateKey::NonRefundableCredits,
+        StateKey::TaxAfterNonRefundableCredits,
+        StateKey::RefundableCredits,
+        StateKey::TotalTax,
+        StateKey::Withholding,
+        StateKey::EstimatedPayments,
+        StateKey::TotalPayments,
+        StateKey::Refund,
+        StateKey::AmountOwed,
+    ];
+
+    /// Plain-language name of the line.
+    pub fn label(self) -> &'static str {
+        match self {
+            StateKey::FederalAgi => "Federal adjusted gross income",
+            StateKey::Additions => "Additions to federal income",
+            StateKey::Subtractions => "Subtractions from federal income",
+            StateKey::StateAgi => "State adjusted gross income",
+            StateKey::StateSourceIncome => "Income taxed by the state (part-year or nonresident)",
+            StateKey::Deductions => "Standard or itemized deduction",
+            StateKey::Exemptions => "Exemptions",
+            StateKey::TaxableIncome => "Taxable income",
+            StateKey::Tax => "Tax",
+            StateKey::AdditionalTax => "Additional taxes",
+            StateKey::NonRefundableCredits => "Nonrefundable credits",
+            StateKey::TaxAfterNonRefundableCredits => "Tax after nonrefundable credits",
+            StateKey::RefundableCredits => "Refundable credits",
+            StateKey::TotalTax => "Total tax",
+            StateKey::Withholding => "State income tax withheld",
+            StateKey::EstimatedPayments => "Estimated tax payments",
+            StateKey::TotalPayments => "Total payments",
+            StateKey::Refund => "Refund",
+            StateKey::AmountOwed => "Amount owed",
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Amounts computed by the state spine, keyed by [`StateKey`].
+///
+/// Stored inline like the federal [`Ledger`](crate::ledger::Ledger).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StateLedger {
+    values: [Option<Usd>; StateKey::ALL.len()],
+}
+
+impl StateLedger {
+    /// An empty ledger.
+    pub const fn new() -> Self {
+        StateLedger {
+            values: [None; StateKey::ALL.len()],
+        }
+    }
+
+    /// Sets `key` to `value`, returning the previous value if any.
+    pub fn insert(&mut self, key: StateKey, value: Usd) -> Option<Usd> {
+        self.values[key.index()].replace(value)
+    }
+
+    pub fn get(&self, key: &StateKey) -> Option<&Usd> {
+        self.values[key.index()].as_ref()
+    }
+
+    pub fn contains_key(&self, key: &StateKey) -> bool {
+        self.values[key.index()].is_some()
+    }
+
+    /// Set keys and their amounts, in form order.
+    pub fn iter(&self) -> impl Iterator<Item = (&StateKey, &Usd)> {
+        StateKey::ALL
+            .iter()
+            .zip(&self.values)
+            .filter_map(|(k, v)| v.as_ref().map(|v| (k, v)))
+    }
+}
+
+impl Default for StateLedger {
+    fn default() -> Self {
+        StateLedger::new()
+    }
+}
+
+impl Index<&StateKey> for StateLedger {
+    type Output = Usd;
+
+    /// Panics if `key` is not set.
+    fn index(&self, key: &StateKey) -> &Usd {
+        self.get(key)
+            .unwrap_or_else(|| panic!("state ledger has no value for {key:?}"))
+    }
+}
+
+impl FromIterator<(StateKey, Usd)> for StateLedger {
+    fn from_iter<I: IntoIterator<Item = (StateKey, Usd)>>(iter: I) -> Self {
+        let mut ledger = StateLedger::new();
+        for (key, value) in iter {
+            ledger.insert(key, value);
+        }
+        ledger
+    }
+}
+
+impl fmt::Debug for StateLedger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Serializes as a map in form order, like the federal ledger.
+#[cfg(feature = "serde")]
+impl serde::Serialize for StateLedger {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StateLedger {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = <std::collections::BTreeMap<StateKey, Usd> as serde::Deserialize>::deserialize(
+            deserializer,
+        )?;
+        Ok(map.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_is_in_declaration_order() {
+        for (i, key) in StateKey::ALL.iter().enumerate() {
+            assert_eq!(key.index(), i, "{key:?}");
+        }
+    }
+}
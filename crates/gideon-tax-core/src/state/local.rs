@@ -0,0 +1,273 @@
+//! City and school district income taxes, such as Ohio municipal and
+//! school district taxes and Pennsylvania local earned income taxes.
+
+use std::fmt;
+use std::ops::Index;
+
+use crate::state::State;
+use crate::{Rate, Usd};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalityKind {
+    Municipality,
+    SchoolDistrict,
+}
+
+/// A locality's flat income tax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locality {
+    pub state: State,
+    /// As entered in box 20 of Form W-2.
+    pub name: String,
+    pub kind: LocalityKind,
+    pub resident_rate: Rate,
+    pub nonresident_rate: Rate,
+    /// Share of tax paid to a workplace locality that residents may credit.
+    pub workplace_credit_share: Rate,
+    /// The credit for each workplace is figured on no more than this rate
+    /// times the wages earned there.
+    pub workplace_credit_rate_limit: Rate,
+}
+
+/// Wages earned in another locality and the tax paid to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkplaceIncome {
+    pub locality: String,
+    pub wages: Usd,
+    pub tax_paid: Usd,
+}
+
+/// A local return.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocalInput {
+    pub resident: bool,
+    /// Income in the locality's base: earned income for most cities and
+    /// school districts on the earned income base, or state taxable income
+    /// for Ohio school districts on the traditional base. Nonresidents
+    /// enter only income earned in the locality.
+    pub income: Usd,
+    /// For residents, wages taxed by the localities they worked in.
+    pub workplace_income: Vec<WorkplaceIncome>,
+    /// Tax withheld for this locality (Form W-2, box 19).
+    pub withholding: Usd,
+    pub estimated_payments: Usd,
+}
+
+/// A line of a local return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LocalKey {
+    Income,
+    Tax,
+    WorkplaceCredit,
+    TotalTax,
+    Withholding,
+    EstimatedPayments,
+    TotalPayments,
+    Refund,
+    AmountOwed,
+}
+
+impl LocalKey {
+    /// Every key, in form order.
+    pub const ALL: [LocalKey; 9] = [
+        LocalKey::Income,
+        LocalKey::Tax,
+        LocalKey::WorkplaceCredit,
+        LocalKey::TotalTax,
+        LocalKey::Withholding,
+        LocalKey::EstimatedPayments,
+        LocalKey::TotalPayments,
+        LocalKey::Refund,
+        LocalKey::AmountOwed,
+    ];
+
+    /// Plain-language name of the line.
+    pub fn label(self) -> &'static str {
+        match self {
+            LocalKey::Income => "Taxable income",
+            LocalKey::Tax => "Tax",
+            LocalKey::WorkplaceCredit => "Credit for tax paid to other localities",
+            LocalKey::TotalTax => "Total tax",
+            LocalKey::Withholding => "Local income tax withheld",
+            LocalKey::EstimatedPayments => "Estimated tax payments",
+            LocalKey::TotalPayments => "Total payments",
+            LocalKey::Refund => "Refund",
+            LocalKey::AmountOwed => "Amount owed",
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Amounts computed for a local return, keyed by [`LocalKey`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LocalLedger {
+    values: [Option<Usd>; LocalKey::ALL.len()],
+}
+
+impl LocalLedger {
+    pub const fn new() -> Self {
+        LocalLedger {
+            values: [None; LocalKey::ALL.len()],
+        }
+    }
+
+    /// Sets `key` to `value`, returning the previous value if any.
+    pub fn insert(&mut self, key: LocalKey, value: Usd) -> Option<Usd> {
+        self.values[key.index()].replace(value)
+    }
+
+    pub fn get(&self, key: &LocalKey) -> Option<&Usd> {
+        self.values[key.index()].as_ref()
+    }
+
+    /// Set keys and their amounts, in form order.
+    pub fn iter(&self) -> impl Iterator<Item = (&LocalKey, &Usd)> {
+        LocalKey::ALL
+            .iter()
+            .zip(&self.values)
+            .filter_map(|(k, v)| v.as_ref().map(|v| (k, v)))
+    }
+}
+
+impl Default for LocalLedger {
+    fn default() -> Self {
+        LocalLedger::new()
+    }
+}
+
+impl Index<&LocalKey> for LocalLedger {
+    type Output = Usd;
+
+    /// Panics if `key` is not set.
+    fn index(&self, key: &LocalKey) -> &Usd {
+        self.get(key)
+            .unwrap_or_else(|| panic!("local ledger has no value for {key:?}"))
+    }
+}
+
+impl fmt::Debug for LocalLedger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LocalLedger {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// Credit against a resident's tax for tax paid to the localities they
+/// worked in: for each, the credited share of the lesser of the tax paid
+/// and the rate limit times the wages earned there.
+pub fn workplace_credit(locality: &Locality, workplaces: &[WorkplaceIncome]) -> Usd {
+    workplaces
+        .iter()
+        .map(|w| {
+            w.tax_paid
+                .min(w.wages * locality.workplace_credit_rate_limit)
+                .max(Usd::ZERO)
+                * locality.workplace_credit_share
+        })
+        .sum()
+}
+
+/// Computes a local return and returns a [`LocalLedger`] of results.
+pub fn compute_local_tax(locality: &Locality, input: &LocalInput) -> LocalLedger {
+    let rate = if input.resident {
+        locality.resident_rate
+    } else {
+        locality.nonresident_rate
+    };
+    let tax = (input.income.max(Usd::ZERO) * rate).irs_round();
+    let credit = if input.resident {
+        workplace_credit(locality, &input.workplace_income)
+            .irs_round()
+            .min(tax)
+    } else {
+        Usd::ZERO
+    };
+    let total_tax = tax - credit;
+    let total_payments = input.withholding + input.estimated_payments;
+    let net = total_payments - total_tax;
+
+    let mut ledger = LocalLedger::new();
+    ledger.insert(LocalKey::Income, input.income);
+    ledger.insert(LocalKey::Tax, tax);
+    ledger.insert(LocalKey::WorkplaceCredit, credit);
+    ledger.insert(LocalKey::TotalTax, total_tax);
+    ledger.insert(LocalKey::Withholding, input.withholding);
+    ledger.insert(LocalKey::EstimatedPayments, input.estimated_payments);
+    ledger.insert(LocalKey::TotalPayments, total_payments);
+    ledger.insert(LocalKey::Refund, net.max(Usd::ZERO));
+    ledger.insert(LocalKey::AmountOwed, (Usd::ZERO - net).max(Usd::ZERO));
+    ledger
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An Ohio city taxing at 2.5% with full credit up to 2.5%.
+    fn city() -> Locality {
+        Locality {
+            state: State::OH,
+            name: "Columbus".to_string(),
+            kind: LocalityKind::Municipality,
+            resident_rate: Rate::from_bps(250),
+            nonresident_rate: Rate::from_bps(250),
+            workplace_credit_share: Rate::from_percent(100),
+            workplace_credit_rate_limit: Rate::from_bps(250),
+        }
+    }
+
+    fn workplace(wages: i64, tax_paid: i64) -> WorkplaceIncome {
+        WorkplaceIncome {
+            locality: "Dublin".to_string(),
+            wages: Usd::from_dollars(wages),
+            tax_paid: Usd::from_dollars(tax_paid),
+        }
+    }
+
+    #[test]
+    fn resident_credit_for_workplace_tax() {
+        let input = LocalInput {
+            resident: true,
+            income: Usd::from_dollars(80_000),
+            // 2% paid where the taxpayer works.
+            workplace_income: vec![workplace(60_000, 1_200)],
+            withholding: Usd::from_dollars(500),
+            ..Default::default()
+        };
+        let ledger = compute_local_tax(&city(), &input);
+        assert_eq!(ledger[&LocalKey::Tax], Usd::from_dollars(2_000));
+        assert_eq!(ledger[&LocalKey::WorkplaceCredit], Usd::from_dollars(1_200));
+        assert_eq!(ledger[&LocalKey::AmountOwed], Usd::from_dollars(300));
+    }
+
+    #[test]
+    fn credit_limited_by_rate_and_share() {
+        let locality = Locality {
+            workplace_credit_share: Rate::from_percent(50),
+            ..city()
+        };
+        // 3% paid, credited at 2.5% and then half: 50% of 1,500.
+        assert_eq!(
+            workplace_credit(&locality, &[workplace(60_000, 1_800)]),
+            Usd::from_dollars(750)
+        );
+        let nonresident = LocalInput {
+            income: Usd::from_dollars(60_000),
+            workplace_income: vec![workplace(60_000, 1_800)],
+            ..Default::default()
+        };
+        let ledger = compute_local_tax(&locality, &nonresident);
+        assert_eq!(ledger[&LocalKey::WorkplaceCredit], Usd::ZERO);
+        assert_eq!(ledger[&LocalKey::TotalTax], Usd::from_dollars(1_500));
+    }
+}
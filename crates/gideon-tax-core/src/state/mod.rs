@@ -0,0 +1,501 @@
+//! State individual income tax, computed from a finished federal return.
+//!
+//! Most states start from federal AGI, add and subtract items the state
+//! treats differently, subtract a state deduction and exemptions, and tax
+//! the result on the state's own schedule. [`compute_state_spine`] runs
+//! that flow for any [`StateRules`].
+
+pub mod allocation;
+pub mod ca;
+pub mod flat;
+pub mod ledger;
+pub mod local;
+
+use std::fmt;
+
+use us_tax_brackets::{FilingStatus, TaxYear};
+
+use crate::Usd;
+use crate::error::Error;
+use crate::ledger::{Key, Ledger};
+use crate::rules::{self, Bracket};
+use crate::spine::ReturnInput;
+pub use ledger::{StateKey, StateLedger};
+
+/// A state, or the District of Columbia, by postal abbreviation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum State {
+    AL,
+    AK,
+    AZ,
+    AR,
+    CA,
+    CO,
+    CT,
+    DE,
+    DC,
+    FL,
+    GA,
+    HI,
+    ID,
+    IL,
+    IN,
+    IA,
+    KS,
+    KY,
+    LA,
+    ME,
+    MD,
+    MA,
+    MI,
+    MN,
+    MS,
+    MO,
+    MT,
+    NE,
+    NV,
+    NH,
+    NJ,
+    NM,
+    NY,
+    NC,
+    ND,
+    OH,
+    OK,
+    OR,
+    PA,
+    RI,
+    SC,
+    SD,
+    TN,
+    TX,
+    UT,
+    VT,
+    VA,
+    WA,
+    WV,
+    WI,
+    WY,
+}
+
+impl State {
+    /// Every state, alphabetical by name.
+    pub const ALL: [State; 51] = {
+        use State::*;
+        [
+            AL, AK, AZ, AR, CA, CO, CT, DE, DC, FL, GA, HI, ID, IL, IN, IA, KS, KY, LA, ME, MD, MA,
+            MI, MN, MS, MO, MT, NE, NV, NH, NJ, NM, NY, NC, ND, OH, OK, OR, PA, RI, SC, SD, TN, TX,
+            UT, VT, VA, WA, WV, WI, WY,
+        ]
+    };
+
+    /// The two-letter postal abbreviation, as entered in box 15 of Form W-2.
+    pub fn code(self) -> &'static str {
+        use State::*;
+        match self {
+            AL => "AL",
+            AK => "AK",
+            AZ => "AZ",
+            AR => "AR",
+            CA => "CA",
+            CO => "CO",
+            CT => "CT",
+            DE => "DE",
+            DC => "DC",
+            FL => "FL",
+            GA => "GA",
+            HI => "HI",
+            ID => "ID",
+            IL => "IL",
+            IN => "IN",
+            IA => "IA",
+            KS => "KS",
+            KY => "KY",
+            LA => "LA",
+            ME => "ME",
+            MD => "MD",
+            MA => "MA",
+            MI => "MI",
+            MN => "MN",
+            MS => "MS",
+            MO => "MO",
+            MT => "MT",
+            NE => "NE",
+            NV => "NV",
+            NH => "NH",
+            NJ => "NJ",
+            NM => "NM",
+            NY => "NY",
+            NC => "NC",
+            ND => "ND",
+            OH => "OH",
+            OK => "OK",
+            OR => "OR",
+            PA => "PA",
+            RI => "RI",
+            SC => "SC",
+            SD => "SD",
+            TN => "TN",
+            TX => "TX",
+            UT => "UT",
+            VT => "VT",
+            VA => "VA",
+            WA => "WA",
+            WV => "WV",
+            WI => "WI",
+            WY => "WY",
+        }
+    }
+
+    /// Parses a postal abbreviation (case-insensitive, surrounding
+    /// whitespace ignored).
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|s| s.code().eq_ignore_ascii_case(code))
+    }
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Input
+// ---------------------------------------------------------------------------
+
+/// What a state return needs beyond the federal return.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateInput {
+    pub tax_year: TaxYear,
+    pub state: State,
+    /// Income the state taxes that federal AGI leaves out, such as interest
+    /// on other states' bonds.
+    pub additions: Usd,
+    /// Federal AGI the state does not tax, such as interest on U.S.
+    /// obligations.
+    pub subtractions: Usd,
+    /// Itemized deductions as the state allows them, taken when they
+    /// exceed the state standard deduction.
+    pub itemized_deductions: Usd,
+    /// For part-year residents and nonresidents, the income the state
+    /// taxes, as [`allocation::allocate`] apportions it. Tax is figured on
+    /// all income as if a full-year resident, then prorated by this
+    /// income's share of state AGI. `None` for full-year residents.
+    pub state_source_income: Option<Usd>,
+    /// State income tax withheld.
+    pub withholding: Usd,
+    /// Paid rent for at least half the year on a principal residence in
+    /// the state, for renter's credits.
+    pub rented_principal_residence: bool,
+    /// State estimated tax payments, including any overpayment applied
+    /// from the prior year.
+    pub estimated_payments: Usd,
+}
+
+impl StateInput {
+    /// A full-year resident's return for `state` with no state adjustments,
+    /// deductions, or payments.
+    pub fn new(tax_year: TaxYear, state: State) -> Self {
+        StateInput {
+            tax_year,
+            state,
+            additions: Usd::ZERO,
+            subtractions: Usd::ZERO,
+            itemized_deductions: Usd::ZERO,
+            state_source_income: None,
+            withholding: Usd::ZERO,
+            rented_principal_residence: false,
+            estimated_payments: Usd::ZERO,
+        }
+    }
+}
+
+/// The federal return and state input a [`StateRules`] computes from.
+#[derive(Debug, Clone, Copy)]
+pub struct StateReturn<'a> {
+    pub federal_input: &'a ReturnInput,
+    pub federal: &'a Ledger,
+    pub federal_agi: Usd,
+    pub input: &'a StateInput,
+}
+
+impl StateReturn<'_> {
+    pub fn filing_status(&self) -> FilingStatus {
+        self.federal_input.filing_status
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Rules
+// ---------------------------------------------------------------------------
+
+/// One state's income tax for one year.
+///
+/// Provided methods follow the common pattern; states override the steps
+/// they do differently.
+pub trait StateRules {
+    fn state(&self) -> State;
+
+    fn year(&self) -> TaxYear;
+
+    /// Additions to federal AGI.
+    fn additions(&self, ret: &StateReturn<'_>) -> Usd {
+        ret.input.additions
+    }
+
+    /// Subtractions from federal AGI.
+    fn subtractions(&self, ret: &StateReturn<'_>) -> Usd {
+        ret.input.subtractions
+    }
+
+    fn standard_deduction(&self, ret: &StateReturn<'_>) -> Usd;
+
+    /// The larger of the standard deduction and state itemized deductions.
+    fn deduction(&self, ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+        self.standard_deduction(ret)
+            .max(ret.input.itemized_deductions)
+    }
+
+    /// Exemptions subtracted from income. States that allow exemptions as
+    /// credits instead return zero and count them in
+    /// [`nonrefundable_credits`](Self::nonrefundable_credits).
+    fn exemptions(&self, _ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+        Usd::ZERO
+    }
+
+    /// The rate schedule, lowest bracket first.
+    fn brackets(&self, status: FilingStatus) -> Vec<Bracket>;
+
+    /// Tax on `taxable_income`, rounded to the dollar.
+    fn tax(&self, ret: &StateReturn<'_>, taxable_income: Usd) -> Usd {
+        rules::rate_schedule_tax(&self.brackets(ret.filing_status()), taxable_income).irs_round()
+    }
+
+    /// Taxes added to the tax on taxable income, such as surtaxes on high
+    /// incomes.
+    fn additional_tax(&self, _ret: &StateReturn<'_>, _taxable_income: Usd) -> Usd {
+        Usd::ZERO
+    }
+
+    /// Credits limited to tax.
+    fn nonrefundable_credits(&self, _ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+        Usd::ZERO
+    }
+
+    fn refundable_credits(&self, _ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+        Usd::ZERO
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Spine
+// ---------------------------------------------------------------------------
+
+/// Computes a state resident return from the federal return and returns a
+/// [`StateLedger`] of results.
+///
+/// Follows the common state flow:
+/// federal AGI → additions/subtractions → state AGI → deductions/exemptions
+/// → taxable income → tax → credits → payments → refund/owed
+pub fn compute_state_spine(
+    rules: &dyn StateRules,
+    federal_input: &ReturnInput,
+    federal: &Ledger,
+    input: &StateInput,
+) -> Result<StateLedger, Error> {
+    if input.tax_year != rules.year() {
+        return Err(Error::YearMismatch {
+            input: rules::calendar_year(input.tax_year),
+            rules: rules::calendar_year(rules.year()),
+        });
+    }
+    if input.state != rules.state() {
+        return Err(Error::StateMismatch {
+            input: input.state,
+            rules: rules.state(),
+        });
+    }
+    let federal_agi = *federal
+        .get(&Key::AGI)
+        .ok_or(Error::MissingFederalLine(Key::AGI))?;
+    let ret = StateReturn {
+        federal_input,
+        federal,
+        federal_agi,
+        input,
+    };
+
+    let additions = rules.additions(&ret);
+    let subtractions = rules.subtractions(&ret);
+    let state_agi = federal_agi + additions - subtractions;
+    let deductions = rules.deduction(&ret, state_agi);
+    let exemptions = rules.exemptions(&ret, state_agi);
+    let taxable_income = (state_agi - deductions - exemptions).max(Usd::ZERO);
+
+    let tax = rules.tax(&ret, taxable_income);
+    let additional_tax = rules.additional_tax(&ret, taxable_income);
+    let nonrefundable_credits = rules.nonrefundable_credits(&ret, state_agi);
+    let full_year_tax = (tax + additional_tax - nonrefundable_credits).max(Usd::ZERO);
+    let tax_after_nonrefundable = match input.state_source_income {
+        Some(source) if state_agi > Usd::ZERO => {
+            let source = source.clamp(Usd::ZERO, state_agi);
+            full_year_tax
+                .mul_div(source.cents(), state_agi.cents())
+                .irs_round()
+        }
+        _ => full_year_tax,
+    };
+    let refundable_credits = rules.refundable_credits(&ret, state_agi);
+    let total_tax = tax_after_nonrefundable - refundable_credits;
+
+    let total_payments = input.withholding + input.estimated_payments;
+    let net = total_payments - total_tax;
+    let refund = net.max(Usd::ZERO);
+    let owed = (Usd::ZERO - net).max(Usd::ZERO);
+
+    let mut ledger = StateLedger::new();
+    ledger.insert(StateKey::FederalAgi, federal_agi);
+    ledger.insert(StateKey::Additions, additions);
+    ledger.insert(StateKey::Subtractions, subtractions);
+    ledger.insert(StateKey::StateAgi, state_agi);
+    if let Some(source) = input.state_source_income {
+        ledger.insert(StateKey::StateSourceIncome, source);
+    }
+    ledger.insert(StateKey::Deductions, deductions);
+    ledger.insert(StateKey::Exemptions, exemptions);
+    ledger.insert(StateKey::TaxableIncome, taxable_income);
+    ledger.insert(StateKey::Tax, tax);
+    ledger.insert(StateKey::AdditionalTax, additional_tax);
+    ledger.insert(StateKey::NonRefundableCredits, nonrefundable_credits);
+    ledger.insert(
+        StateKey::TaxAfterNonRefundableCredits,
+        tax_after_nonrefundable,
+    );
+    ledger.insert(StateKey::RefundableCredits, refundable_credits);
+    ledger.insert(StateKey::TotalTax, total_tax);
+    ledger.insert(StateKey::Withholding, input.withholding);
+    ledger.insert(StateKey::EstimatedPayments, input.estimated_payments);
+    ledger.insert(StateKey::TotalPayments, total_payments);
+    ledger.insert(StateKey::Refund, refund);
+    ledger.insert(StateKey::AmountOwed, owed);
+
+    Ok(ledger)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::Rate;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    /// 5% over a $2,000 deduction, with a $100 exemption credit.
+    struct Flat5;
+
+    impl StateRules for Flat5 {
+        fn state(&self) -> State {
+            State::CO
+        }
+
+        fn year(&self) -> TaxYear {
+            TaxYear::Y2025
+        }
+
+        fn standard_deduction(&self, _ret: &StateReturn<'_>) -> Usd {
+            Usd::from_dollars(2_000)
+        }
+
+        fn brackets(&self, _status: FilingStatus) -> Vec<Bracket> {
+            vec![Bracket {
+                floor: Usd::ZERO,
+                rate: Rate::from_percent(5),
+            }]
+        }
+
+        fn nonrefundable_credits(&self, _ret: &StateReturn<'_>, _state_agi: Usd) -> Usd {
+            Usd::from_dollars(100)
+        }
+    }
+
+    pub(crate) fn state_input(state: State, withholding: i64) -> StateInput {
+        StateInput {
+            withholding: Usd::from_dollars(withholding),
+            ..StateInput::new(TaxYear::Y2025, state)
+        }
+    }
+
+    #[test]
+    fn starts_from_federal_agi() {
+        let federal_input = input(60_000, 0);
+        let federal = compute_spine(&Rules2025, &federal_input).unwrap();
+        let state = StateInput {
+            additions: Usd::from_dollars(500),
+            subtractions: Usd::from_dollars(1_500),
+            ..state_input(State::CO, 2_500)
+        };
+        let ledger = compute_state_spine(&Flat5, &federal_input, &federal, &state).unwrap();
+        assert_eq!(ledger[&StateKey::StateAgi], Usd::from_dollars(59_000));
+        assert_eq!(ledger[&StateKey::TaxableIncome], Usd::from_dollars(57_000));
+        // 5% of 57,000 = 2,850, less the 100 credit.
+        assert_eq!(ledger[&StateKey::Tax], Usd::from_dollars(2_850));
+        assert_eq!(ledger[&StateKey::TotalTax], Usd::from_dollars(2_750));
+        assert_eq!(ledger[&StateKey::AmountOwed], Usd::from_dollars(250));
+        assert_eq!(ledger[&StateKey::Refund], Usd::ZERO);
+    }
+
+    #[test]
+    fn rejects_mismatched_state_and_missing_agi() {
+        let federal_input = input(60_000, 0);
+        let federal = compute_spine(&Rules2025, &federal_input).unwrap();
+        let err = compute_state_spine(&Flat5, &federal_input, &federal, &state_input(State::IL, 0))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::StateMismatch {
+                input: State::IL,
+                rules: State::CO,
+            }
+        ));
+        let err = compute_state_spine(
+            &Flat5,
+            &federal_input,
+            &Ledger::new(),
+            &state_input(State::CO, 0),
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::MissingFederalLine(Key::AGI)));
+    }
+
+    #[test]
+    fn nonresident_tax_prorated_by_state_income() {
+        let federal_input = input(60_000, 0);
+        let federal = compute_spine(&Rules2025, &federal_input).unwrap();
+        let state = StateInput {
+            state_source_income: Some(Usd::from_dollars(30_000)),
+            ..state_input(State::CO, 0)
+        };
+        let ledger = compute_state_spine(&Flat5, &federal_input, &federal, &state).unwrap();
+        // 5% of 58,000 less the credit is 2,800, half of it from the state.
+        assert_eq!(ledger[&StateKey::Tax], Usd::from_dollars(2_900));
+        assert_eq!(
+            ledger[&StateKey::StateSourceIncome],
+            Usd::from_dollars(30_000)
+        );
+        assert_eq!(ledger[&StateKey::TotalTax], Usd::from_dollars(1_400));
+    }
+
+    #[test]
+    fn state_codes_round_trip() {
+        for state in State::ALL {
+            assert_eq!(State::from_code(state.code()), Some(state));
+        }
+        assert_eq!(State::from_code(" ca "), Some(State::CA));
+        assert_eq!(State::from_code("PR"), None);
+    }
+}
@@ -0,0 +1,193 @@
+//! Proptest strategies that generate valid inputs in realistic ranges.
+//!
+//! Enabled by the `proptest` feature, so that downstream crates can fuzz
+//! their integrations with the engine. Generated returns have wages,
+//! withholding, Social Security benefits, Schedule 1 adjustments, and
+//! Schedule A deductions; the other inputs are left empty.
+
+use std::ops::RangeInclusive;
+
+use proptest::prelude::*;
+use us_tax_brackets::FilingStatus;
+
+use crate::adjustments::Adjustments;
+use crate::schedule_a::ItemizedDeductions;
+use crate::spine::ReturnInput;
+use crate::{Filer, Usd};
+
+/// Whole-dollar amounts in `range`.
+pub fn dollars(range: RangeInclusive<i64>) -> impl Strategy<Value = Usd> {
+    range.prop_map(Usd::from_dollars)
+}
+
+/// Zero three times in four, otherwise whole dollars in `range`. Most
+/// returns leave most lines empty.
+pub fn sometimes(range: RangeInclusive<i64>) -> impl Strategy<Value = Usd> {
+    prop_oneof![3 => Just(Usd::ZERO), 1 => dollars(range)]
+}
+
+pub fn filing_status() -> impl Strategy<Value = FilingStatus> {
+    prop_oneof![
+        Just(FilingStatus::Single),
+        Just(FilingStatus::MarriedFilingJointly),
+        Just(FilingStatus::MarriedFilingSeparately),
+        Just(FilingStatus::HeadOfHousehold),
+        Just(FilingStatus::QualifyingSurvivingSpouse),
+    ]
+}
+
+pub fn filer() -> impl Strategy<Value = Filer> {
+    (prop::bool::weighted(0.2), prop::bool::weighted(0.02)).prop_map(
+        |(is_65_or_older, is_blind)| Filer {
+            is_65_or_older,
+            is_blind,
+        },
+    )
+}
+
+/// Schedule 1, Part II adjustments, each within its annual limit.
+pub fn adjustments() -> impl Strategy<Value = Adjustments> {
+    (
+        sometimes(0..=5_000),
+        sometimes(0..=8_550),
+        sometimes(0..=10_000),
+        sometimes(0..=7_000),
+        sometimes(0..=5_000),
+    )
+        .prop_map(
+            |(
+                business_expenses_reservists,
+                hsa_deduction,
+                moving_expenses,
+                ira_deduction,
+                section_67e_excess_deductions,
+            )| Adjustments {
+                business_expenses_reservists,
+                hsa_deduction,
+                moving_expenses,
+                ira_deduction,
+                section_67e_excess_deductions,
+            },
+        )
+}
+
+/// Schedule A deductions, without charitable gifts or casualties.
+pub fn itemized_deductions() -> impl Strategy<Value = ItemizedDeductions> {
+    (
+        sometimes(0..=30_000),
+        sometimes(0..=25_000),
+        sometimes(0..=15_000),
+        sometimes(0..=2_000),
+        sometimes(0..=40_000),
+    )
+        .prop_map(
+            |(
+                medical_expenses,
+                state_and_local_taxes,
+                real_estate_taxes,
+                personal_property_taxes,
+                mortgage_interest,
+            )| ItemizedDeductions {
+                medical_expenses,
+                state_and_local_taxes,
+                real_estate_taxes,
+                personal_property_taxes,
+                mortgage_interest,
+                ..ItemizedDeductions::default()
+            },
+        )
+}
+
+/// A return for `tax_year` that passes [`compute_spine`]'s checks.
+///
+/// The spouse is present exactly when married, head of household and
+/// qualifying surviving spouse returns have a dependent, and only single
+/// filers are claimed as a dependent themselves. Withholding is at most 30%
+/// of wages.
+///
+/// [`compute_spine`]: crate::spine::compute_spine
+pub fn return_input(tax_year: i32) -> impl Strategy<Value = ReturnInput> {
+    (
+        filing_status(),
+        (filer(), filer()),
+        prop::bool::weighted(0.05),
+        0u32..=4,
+        dollars(0..=400_000),
+        0i64..=30,
+        sometimes(0..=50_000),
+        adjustments(),
+        itemized_deductions(),
+        sometimes(0..=10_000),
+    )
+        .prop_map(
+            move |(
+                filing_status,
+                (taxpayer, spouse),
+                is_dependent,
+                num_dependents,
+                w2_wages,
+                withholding_percent,
+                social_security_benefits,
+                adjustments,
+                itemized_deductions,
+                extension_payment,
+            )| {
+                let married = matches!(
+                    filing_status,
+                    FilingStatus::MarriedFilingJointly | FilingStatus::MarriedFilingSeparately
+                );
+                let is_dependent = is_dependent && filing_status == FilingStatus::Single;
+                let num_dependents = match filing_status {
+                    _ if is_dependent => 0,
+                    FilingStatus::HeadOfHousehold | FilingStatus::QualifyingSurvivingSpouse => {
+                        num_dependents.max(1)
+                    }
+                    _ => num_dependents,
+                };
+                ReturnInput {
+                    taxpayer,
+                    spouse: married.then_some(spouse),
+                    is_dependent,
+                    num_dependents,
+                    w2_wages,
+                    social_security_benefits,
+                    adjustments,
+                    itemized_deductions,
+                    fed_withholding: w2_wages.mul_div(withholding_percent, 100),
+                    extension_payment,
+                    ..ReturnInput::new(tax_year, filing_status)
+                }
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::{Key, compute_spine};
+
+    proptest! {
+        #[test]
+        fn ledger_invariants(input in return_input(2025)) {
+            let ledger = compute_spine(&Rules2025, &input).unwrap();
+            let refund = ledger[&Key::Refund];
+            let owed = ledger[&Key::AmountOwed];
+            prop_assert!(refund == Usd::ZERO || owed == Usd::ZERO);
+            prop_assert!(refund >= Usd::ZERO && owed >= Usd::ZERO);
+            prop_assert_eq!(
+                refund - owed,
+                ledger[&Key::TotalPayments] - ledger[&Key::TotalTax]
+            );
+            prop_assert!(ledger[&Key::TaxableIncome] >= Usd::ZERO);
+            prop_assert!(ledger[&Key::TaxableIncome] <= ledger[&Key::AGI].max(Usd::ZERO));
+            prop_assert!(ledger[&Key::TotalTax] >= Usd::ZERO);
+            prop_assert_eq!(
+                ledger[&Key::TotalPayments],
+                ledger[&Key::Withholding]
+                    + ledger[&Key::EstimatedPayments]
+                    + ledger[&Key::ExtensionPayment]
+            );
+        }
+    }
+}
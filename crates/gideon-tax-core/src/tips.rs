@@ -0,0 +1,122 @@
+use crate::Usd;
+use crate::rules::TaxYearRules;
+
+/// Employee Social Security tax rate, in basis points (IRC §3101(a)).
+pub const SOCIAL_SECURITY_TAX_BPS: i64 = 620;
+
+/// Employee Medicare tax rate, in basis points (IRC §3101(b)(1)).
+pub const MEDICARE_TAX_BPS: i64 = 145;
+
+/// Tips from one employer (Form 4137, line 1).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct EmployerTips {
+    /// Column (a).
+    pub employer_name: String,
+    /// Column (b).
+    pub employer_ein: String,
+    /// Cash and charge tips received, including allocated tips from
+    /// Form W-2, box 8 that were not reported to the employer (column (c)).
+    pub tips_received: Usd,
+    /// Tips reported to the employer (column (d)).
+    pub tips_reported: Usd,
+}
+
+/// Tips not reported to employers, for Form 4137.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct UnreportedTips {
+    pub employers: Vec<EmployerTips>,
+    /// Tips of less than $20 in a month from one employer, which did not
+    /// have to be reported and are not subject to Social Security and
+    /// Medicare tax (line 5).
+    pub under_20_a_month: Usd,
+    /// Social Security wages and tips from Forms W-2, boxes 3 and 7, and
+    /// RRTA compensation (line 8).
+    pub social_security_wages_and_tips: Usd,
+}
+
+/// Results of Form 4137.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Form4137 {
+    /// Unreported tips, included in wages on Form 1040, line 1c (line 4).
+    pub unreported_tips: Usd,
+    /// Unreported tips subject to Medicare tax (line 6).
+    pub medicare_tips: Usd,
+    /// Unreported tips subject to Social Security tax, after the wage base
+    /// (line 10).
+    pub social_security_tips: Usd,
+    /// Line 11.
+    pub social_security_tax: Usd,
+    /// Line 12.
+    pub medicare_tax: Usd,
+}
+
+impl Form4137 {
+    /// Tax reported on Schedule 2, line 5 (line 13).
+    pub fn tax(&self) -> Usd {
+        self.social_security_tax + self.medicare_tax
+    }
+}
+
+impl UnreportedTips {
+    /// Tips received less tips reported to employers (lines 2–4).
+    pub fn total(&self) -> Usd {
+        self.employers
+            .iter()
+            .map(|e| (e.tips_received - e.tips_reported).max(Usd::ZERO))
+            .sum()
+    }
+
+    /// Computes Form 4137.
+    ///
+    /// See: <https://www.irs.gov/instructions/i4137>
+    pub fn form_4137(&self, rules: &dyn TaxYearRules) -> Form4137 {
+        let unreported_tips = self.total();
+        let medicare_tips = (unreported_tips - self.under_20_a_month).max(Usd::ZERO);
+        // Lines 7–10
+        let wage_base_left = (rules.social_security_wage_base()
+            - self.social_security_wages_and_tips)
+            .max(Usd::ZERO);
+        let social_security_tips = medicare_tips.min(wage_base_left);
+        Form4137 {
+            unreported_tips,
+            medicare_tips,
+            social_security_tips,
+            social_security_tax: social_security_tips.mul_div(SOCIAL_SECURITY_TAX_BPS, 10_000),
+            medicare_tax: medicare_tips.mul_div(MEDICARE_TAX_BPS, 10_000),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::y2025::Rules2025;
+
+    fn tips(received: i64, reported: i64, ss_wages: i64) -> UnreportedTips {
+        UnreportedTips {
+            employers: vec![EmployerTips {
+                tips_received: Usd::from_dollars(received),
+                tips_reported: Usd::from_dollars(reported),
+                ..Default::default()
+            }],
+            under_20_a_month: Usd::ZERO,
+            social_security_wages_and_tips: Usd::from_dollars(ss_wages),
+        }
+    }
+
+    #[test]
+    fn tax_on_unreported_tips() {
+        let f = tips(9_000, 5_000, 30_000).form_4137(&Rules2025);
+        assert_eq!(f.unreported_tips, Usd::from_dollars(4_000));
+        assert_eq!(f.social_security_tax, Usd::from_dollars(248));
+        assert_eq!(f.medicare_tax, Usd::from_dollars(58));
+        assert_eq!(f.tax(), Usd::from_dollars(306));
+    }
+
+    #[test]
+    fn social_security_limited_to_wage_base() {
+        let f = tips(5_000, 0, 174_100).form_4137(&Rules2025);
+        assert_eq!(f.social_security_tips, Usd::from_dollars(2_000));
+        assert_eq!(f.medicare_tips, Usd::from_dollars(5_000));
+    }
+}
@@ -0,0 +1,507 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use us_tax_brackets::FilingStatus;
+
+use crate::error::Error;
+use crate::premium_tax_credit::PovertyRegion;
+use crate::rules::{Bracket, EitcParameters, TaxYearRules};
+use crate::spine::{self, Ledger, ReturnInput};
+use crate::{Rate, Usd};
+
+/// Value of a year parameter consulted during a computation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterValue {
+    Usd(Usd),
+    Rate(Rate),
+    /// Tenths of a cent, for the standard mileage rate.
+    Mills(i64),
+    /// A threshold that does not apply in this year.
+    NotApplicable,
+}
+
+/// One year parameter consulted during a computation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParameterUse {
+    /// Name of the [`TaxYearRules`] accessor, e.g.
+    /// `"single_mfs_typical_standard_deduction"`.
+    pub name: &'static str,
+    /// Filing status the parameter was looked up for, if it varies by
+    /// status.
+    pub filing_status: Option<FilingStatus>,
+    pub value: ParameterValue,
+}
+
+/// Artifacts recorded alongside a computed [`Ledger`] so a reviewer can
+/// verify how it was produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    /// Calendar year of the rules.
+    pub year: i32,
+    /// Every year parameter consulted, in order of first use.
+    pub parameters: Vec<ParameterUse>,
+}
+
+/// Computes the spine like [`spine::compute_spine`] and records every
+/// [`TaxYearRules`] parameter it consulted.
+///
+/// Tax tables come from `us_tax_brackets` and are identified by the
+/// trace's `year`. Rules that
+/// [use the rate schedule](TaxYearRules::uses_rate_schedule) tax on their
+/// ordinary brackets, which are not recorded.
+pub fn compute_spine_traced(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+) -> Result<(Ledger, Trace), Error> {
+    let recorder = RecordingRules {
+        inner: rules,
+        used: RefCell::new(Vec::new()),
+    };
+    let ledger = spine::compute_spine(&recorder, input)?;
+    let trace = Trace {
+        year: rules.calendar_year(),
+        parameters: recorder.used.into_inner(),
+    };
+    Ok((ledger, trace))
+}
+
+/// Delegates every parameter accessor to `inner`, recording each value.
+///
+/// The provided algorithms (`standard_deduction`, `exemptions`, ...) are
+/// not delegated, so they run against the recorded accessors.
+struct RecordingRules<'a> {
+    inner: &'a dyn TaxYearRules,
+    used: RefCell<Vec<ParameterUse>>,
+}
+
+impl RecordingRules<'_> {
+    fn record(
+        &self,
+        name: &'static str,
+        filing_status: Option<FilingStatus>,
+        value: ParameterValue,
+    ) {
+        let mut used = self.used.borrow_mut();
+        if !used
+            .iter()
+            .any(|u| u.name == name && u.filing_status == filing_status)
+        {
+            used.push(ParameterUse {
+                name,
+                filing_status,
+                value,
+            });
+        }
+    }
+
+    fn usd(&self, name: &'static str, value: Usd) -> Usd {
+        self.record(name, None, ParameterValue::Usd(value));
+        value
+    }
+
+    fn optional(&self, name: &'static str, value: Option<Usd>) {
+        self.record(
+            name,
+            None,
+            value.map_or(ParameterValue::NotApplicable, ParameterValue::Usd),
+        );
+    }
+
+    fn by_status(&self, name: &'static str, status: FilingStatus, value: Usd) -> Usd {
+        self.record(name, Some(status), ParameterValue::Usd(value));
+        value
+    }
+}
+
+impl TaxYearRules for RecordingRules<'_> {
+    fn calendar_year(&self) -> i32 {
+        self.inner.calendar_year()
+    }
+
+    fn is_projection(&self) -> bool {
+        self.inner.is_projection()
+    }
+
+    fn uses_rate_schedule(&self) -> bool {
+        self.inner.uses_rate_schedule()
+    }
+
+    fn single_mfs_typical_standard_deduction(&self) -> Usd {
+        self.usd(
+            "single_mfs_typical_standard_deduction",
+            self.inner.single_mfs_typical_standard_deduction(),
+        )
+    }
+
+    fn mfj_qss_typical_standard_deduction(&self) -> Usd {
+        self.usd(
+            "mfj_qss_typical_standard_deduction",
+            self.inner.mfj_qss_typical_standard_deduction(),
+        )
+    }
+
+    fn hoh_typical_standard_deduction(&self) -> Usd {
+        self.usd(
+            "hoh_typical_standard_deduction",
+            self.inner.hoh_typical_standard_deduction(),
+        )
+    }
+
+    fn additional_deduction_unmarried(&self) -> Usd {
+        self.usd(
+            "additional_deduction_unmarried",
+            self.inner.additional_deduction_unmarried(),
+        )
+    }
+
+    fn additional_deduction_married(&self) -> Usd {
+        self.usd(
+            "additional_deduction_married",
+            self.inner.additional_deduction_married(),
+        )
+    }
+
+    fn dependent_earned_income_addition(&self) -> Usd {
+        self.usd(
+            "dependent_earned_income_addition",
+            self.inner.dependent_earned_income_addition(),
+        )
+    }
+
+    fn dependent_minimum_deduction(&self) -> Usd {
+        self.usd(
+            "dependent_minimum_deduction",
+            self.inner.dependent_minimum_deduction(),
+        )
+    }
+
+    // Bracket tables are identified by the trace's year rather than
+    // recorded.
+    fn ordinary_brackets(&self, status: FilingStatus) -> [Bracket; 7] {
+        self.inner.ordinary_brackets(status)
+    }
+
+    fn capital_gain_rate_thresholds(&self, status: FilingStatus) -> [Usd; 2] {
+        self.inner.capital_gain_rate_thresholds(status)
+    }
+
+    fn amt_exemption(&self, status: FilingStatus) -> Usd {
+        self.by_status("amt_exemption", status, self.inner.amt_exemption(status))
+    }
+
+    fn amt_exemption_phaseout_start(&self, status: FilingStatus) -> Usd {
+        self.by_status(
+            "amt_exemption_phaseout_start",
+            status,
+            self.inner.amt_exemption_phaseout_start(status),
+        )
+    }
+
+    fn amt_exemption_phaseout_rate(&self) -> Rate {
+        let rate = self.inner.amt_exemption_phaseout_rate();
+        self.record(
+            "amt_exemption_phaseout_rate",
+            None,
+            ParameterValue::Rate(rate),
+        );
+        rate
+    }
+
+    fn amt_rate_threshold(&self, status: FilingStatus) -> Usd {
+        self.by_status(
+            "amt_rate_threshold",
+            status,
+            self.inner.amt_rate_threshold(status),
+        )
+    }
+
+    fn child_tax_credit_amount(&self) -> Usd {
+        self.usd(
+            "child_tax_credit_amount",
+            self.inner.child_tax_credit_amount(),
+        )
+    }
+
+    fn additional_child_tax_credit_limit(&self) -> Usd {
+        self.usd(
+            "additional_child_tax_credit_limit",
+            self.inner.additional_child_tax_credit_limit(),
+        )
+    }
+
+    fn child_tax_credit_phaseout_start(&self, status: FilingStatus) -> Usd {
+        self.by_status(
+            "child_tax_credit_phaseout_start",
+            status,
+            self.inner.child_tax_credit_phaseout_start(status),
+        )
+    }
+
+    // Rates are statutory; the indexed amounts are recorded.
+    fn eitc(&self, qualifying_children: u32) -> EitcParameters {
+        let eitc = self.inner.eitc(qualifying_children);
+        self.usd("eitc_earned_income_amount", eitc.earned_income_amount);
+        self.usd("eitc_phaseout_start", eitc.phaseout_start);
+        self.usd("eitc_phaseout_start_joint", eitc.phaseout_start_joint);
+        eitc
+    }
+
+    fn eitc_investment_income_limit(&self) -> Usd {
+        self.usd(
+            "eitc_investment_income_limit",
+            self.inner.eitc_investment_income_limit(),
+        )
+    }
+
+    fn employer_coverage_affordability_rate(&self) -> Rate {
+        let rate = self.inner.employer_coverage_affordability_rate();
+        self.record(
+            "employer_coverage_affordability_rate",
+            None,
+            ParameterValue::Rate(rate),
+        );
+        rate
+    }
+
+    fn qbi_threshold(&self, status: FilingStatus) -> Usd {
+        self.by_status("qbi_threshold", status, self.inner.qbi_threshold(status))
+    }
+
+    fn qbi_phase_in_range(&self, status: FilingStatus) -> Usd {
+        self.by_status(
+            "qbi_phase_in_range",
+            status,
+            self.inner.qbi_phase_in_range(status),
+        )
+    }
+
+    fn salt_cap(&self) -> Option<Usd> {
+        let value = self.inner.salt_cap();
+        self.optional("salt_cap", value);
+        value
+    }
+
+    fn salt_phasedown_start(&self) -> Option<Usd> {
+        let value = self.inner.salt_phasedown_start();
+        self.optional("salt_phasedown_start", value);
+        value
+    }
+
+    fn salt_cap_floor(&self) -> Usd {
+        self.usd("salt_cap_floor", self.inner.salt_cap_floor())
+    }
+
+    fn ira_contribution_limit(&self) -> Usd {
+        self.usd(
+            "ira_contribution_limit",
+            self.inner.ira_contribution_limit(),
+        )
+    }
+
+    fn ira_catch_up_contribution(&self) -> Usd {
+        self.usd(
+            "ira_catch_up_contribution",
+            self.inner.ira_catch_up_contribution(),
+        )
+    }
+
+    fn ira_deduction_phaseout_start(&self, status: FilingStatus, spouse_covered_only: bool) -> Usd {
+        let value = self
+            .inner
+            .ira_deduction_phaseout_start(status, spouse_covered_only);
+        self.record(
+            "ira_deduction_phaseout_start",
+            Some(status),
+            ParameterValue::Usd(value),
+        );
+        value
+    }
+
+    fn elective_deferral_limit(&self) -> Usd {
+        self.usd(
+            "elective_deferral_limit",
+            self.inner.elective_deferral_limit(),
+        )
+    }
+
+    fn elective_deferral_catch_up(&self, age: u32) -> Usd {
+        self.usd(
+            "elective_deferral_catch_up",
+            self.inner.elective_deferral_catch_up(age),
+        )
+    }
+
+    fn hsa_contribution_limit(&self, family: bool) -> Usd {
+        self.usd(
+            "hsa_contribution_limit",
+            self.inner.hsa_contribution_limit(family),
+        )
+    }
+
+    fn business_standard_mileage_rate_mills(&self) -> i64 {
+        let mills = self.inner.business_standard_mileage_rate_mills();
+        self.record(
+            "business_standard_mileage_rate_mills",
+            None,
+            ParameterValue::Mills(mills),
+        );
+        mills
+    }
+
+    fn social_security_wage_base(&self) -> Usd {
+        self.usd(
+            "social_security_wage_base",
+            self.inner.social_security_wage_base(),
+        )
+    }
+
+    fn withholding_standard_deduction(&self, status: FilingStatus) -> Usd {
+        self.by_status(
+            "withholding_standard_deduction",
+            status,
+            self.inner.withholding_standard_deduction(status),
+        )
+    }
+
+    fn withholding_wage_adjustment(&self, status: FilingStatus) -> Usd {
+        self.by_status(
+            "withholding_wage_adjustment",
+            status,
+            self.inner.withholding_wage_adjustment(status),
+        )
+    }
+
+    fn poverty_line(&self, region: PovertyRegion, family_size: u32) -> Usd {
+        self.usd("poverty_line", self.inner.poverty_line(region, family_size))
+    }
+
+    fn ptc_applicable_figure(&self, fpl_percent: i64) -> Rate {
+        let rate = self.inner.ptc_applicable_figure(fpl_percent);
+        self.record("ptc_applicable_figure", None, ParameterValue::Rate(rate));
+        rate
+    }
+
+    fn ptc_repayment_limit(&self, status: FilingStatus, fpl_percent: i64) -> Option<Usd> {
+        let value = self.inner.ptc_repayment_limit(status, fpl_percent);
+        self.record(
+            "ptc_repayment_limit",
+            Some(status),
+            value.map_or(ParameterValue::NotApplicable, ParameterValue::Usd),
+        );
+        value
+    }
+
+    fn personal_exemption_amount(&self) -> Usd {
+        self.usd(
+            "personal_exemption_amount",
+            self.inner.personal_exemption_amount(),
+        )
+    }
+
+    fn dependent_gross_income_limit(&self) -> Usd {
+        self.usd(
+            "dependent_gross_income_limit",
+            self.inner.dependent_gross_income_limit(),
+        )
+    }
+
+    fn exemption_phaseout_threshold(&self, status: FilingStatus) -> Option<Usd> {
+        let value = self.inner.exemption_phaseout_threshold(status);
+        self.record(
+            "exemption_phaseout_threshold",
+            Some(status),
+            value.map_or(ParameterValue::NotApplicable, ParameterValue::Usd),
+        );
+        value
+    }
+}
+
+impl fmt::Display for ParameterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParameterValue::Usd(v) => write!(f, "{v}"),
+            ParameterValue::Rate(r) => write!(f, "{r}"),
+            ParameterValue::Mills(m) => write!(f, "{}.{}¢", m / 10, m % 10),
+            ParameterValue::NotApplicable => write!(f, "n/a"),
+        }
+    }
+}
+
+impl fmt::Display for ParameterUse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.filing_status {
+            Some(status) => write!(f, "{} ({status}) = {}", self.name, self.value),
+            None => write!(f, "{} = {}", self.name, self.value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::Filer;
+    use crate::rules::y2025::Rules2025;
+
+    fn input(status: FilingStatus, taxpayer: Filer) -> ReturnInput {
+        ReturnInput {
+            taxpayer,
+            w2_wages: Usd::from_dollars(60_000),
+            ..ReturnInput::new(2025, status)
+        }
+    }
+
+    fn find<'a>(trace: &'a Trace, name: &str) -> Option<&'a ParameterUse> {
+        trace.parameters.iter().find(|p| p.name == name)
+    }
+
+    #[test]
+    fn ledger_matches_untraced() {
+        let inp = input(FilingStatus::Single, Filer::default());
+        let (ledger, trace) = compute_spine_traced(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger, spine::compute_spine(&Rules2025, &inp).unwrap());
+        assert_eq!(trace.year, 2025);
+    }
+
+    #[test]
+    fn records_parameters_consulted() {
+        let senior = Filer {
+            is_65_or_older: true,
+            is_blind: false,
+        };
+        let inp = input(FilingStatus::MarriedFilingJointly, senior);
+        let (_, trace) = compute_spine_traced(&Rules2025, &inp).unwrap();
+        assert_eq!(
+            find(&trace, "mfj_qss_typical_standard_deduction")
+                .unwrap()
+                .value,
+            ParameterValue::Usd(Usd::from_dollars(31_500))
+        );
+        assert_eq!(
+            find(&trace, "additional_deduction_married").unwrap().value,
+            ParameterValue::Usd(Usd::from_dollars(1_600))
+        );
+        let qbi = find(&trace, "qbi_threshold").unwrap();
+        assert_eq!(qbi.filing_status, Some(FilingStatus::MarriedFilingJointly));
+        assert_eq!(
+            qbi.to_string(),
+            "qbi_threshold (Married Filing Jointly) = $394600.00"
+        );
+        // Not consulted for this return.
+        assert!(find(&trace, "single_mfs_typical_standard_deduction").is_none());
+        assert!(find(&trace, "dependent_minimum_deduction").is_none());
+    }
+
+    #[test]
+    fn records_each_parameter_once() {
+        let inp = input(FilingStatus::Single, Filer::default());
+        let (_, trace) = compute_spine_traced(&Rules2025, &inp).unwrap();
+        let unique: HashSet<_> = trace
+            .parameters
+            .iter()
+            .map(|p| (p.name, p.filing_status))
+            .collect();
+        assert_eq!(unique.len(), trace.parameters.len());
+    }
+}
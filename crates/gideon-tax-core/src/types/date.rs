@@ -0,0 +1,249 @@
+use core::fmt;
+use core::str::FromStr;
+
+/// A calendar date (proleptic Gregorian), with no time or time zone.
+///
+/// # Examples
+///
+/// ```
+/// use gideon_tax_core::Date;
+///
+/// let d = Date::new(2025, 4, 15).unwrap();
+/// assert_eq!(d.to_string(), "2025-04-15");
+/// assert_eq!("2025-04-15".parse::<Date>().unwrap(), d);
+/// assert_eq!(d.ordinal(), 105);
+/// assert!(Date::new(2025, 2, 29).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date, returning `None` if the month or day is out of range.
+    pub const fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// January 1 of `year`.
+    pub const fn first_of_year(year: i32) -> Self {
+        Date {
+            year,
+            month: 1,
+            day: 1,
+        }
+    }
+
+    /// December 31 of `year`.
+    pub const fn last_of_year(year: i32) -> Self {
+        Date {
+            year,
+            month: 12,
+            day: 31,
+        }
+    }
+
+    /// Day of the year, starting at 1 for January 1.
+    pub const fn ordinal(self) -> u16 {
+        let mut days = self.day as u16;
+        let mut m = 1;
+        while m < self.month {
+            days += days_in_month(self.year, m) as u16;
+            m += 1;
+        }
+        days
+    }
+
+    /// Number of days from `self` to `other` (negative if `other` is
+    /// earlier).
+    pub const fn days_until(self, other: Date) -> i64 {
+        other.days_from_epoch() - self.days_from_epoch()
+    }
+
+    /// The last day of this date's month.
+    pub const fn end_of_month(self) -> Self {
+        Date {
+            day: days_in_month(self.year, self.month),
+            ..self
+        }
+    }
+
+    /// The same month and day `years` later; February 29 becomes February 28
+    /// in a non-leap year.
+    pub const fn add_years(self, years: i32) -> Self {
+        let year = self.year + years;
+        let max = days_in_month(year, self.month);
+        Date {
+            year,
+            day: if self.day > max { max } else { self.day },
+            ..self
+        }
+    }
+
+    /// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`).
+    const fn days_from_epoch(self) -> i64 {
+        let y = if self.month <= 2 {
+            self.year as i64 - 1
+        } else {
+            self.year as i64
+        };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let m = self.month as i64;
+        let mp = if m > 2 { m - 3 } else { m + 9 };
+        let doy = (153 * mp + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+}
+
+/// `true` if `year` is a Gregorian leap year.
+pub const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in `month` (1–12) of `year`; 0 for an invalid month.
+pub const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Error returned when parsing a [`Date`] from a string that is not a valid
+/// `YYYY-MM-DD` date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDateError;
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a valid date in YYYY-MM-DD format")
+    }
+}
+
+impl std::error::Error for ParseDateError {}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or(ParseDateError);
+        let year = next()?.parse().map_err(|_| ParseDateError)?;
+        let month = next()?.parse().map_err(|_| ParseDateError)?;
+        let day = next()?.parse().map_err(|_| ParseDateError)?;
+        Date::new(year, month, day).ok_or(ParseDateError)
+    }
+}
+
+/// Serialized as a `YYYY-MM-DD` string.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::new(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn validation() {
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2025, 2, 29).is_none());
+        assert!(Date::new(2025, 13, 1).is_none());
+        assert!(Date::new(2025, 4, 31).is_none());
+        assert!(Date::new(2025, 1, 0).is_none());
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2025));
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+    }
+
+    #[test]
+    fn ordinal() {
+        assert_eq!(d(2025, 1, 1).ordinal(), 1);
+        assert_eq!(d(2025, 12, 31).ordinal(), 365);
+        assert_eq!(d(2024, 12, 31).ordinal(), 366);
+        assert_eq!(d(2024, 3, 1).ordinal(), 61);
+    }
+
+    #[test]
+    fn days_until() {
+        assert_eq!(d(2025, 1, 1).days_until(d(2025, 12, 31)), 364);
+        assert_eq!(d(2024, 1, 1).days_until(d(2025, 1, 1)), 366);
+        assert_eq!(d(2025, 3, 1).days_until(d(2025, 2, 28)), -1);
+        assert_eq!(d(1970, 1, 1).days_from_epoch(), 0);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(d(2025, 1, 31) < d(2025, 2, 1));
+        assert!(d(2024, 12, 31) < d(2025, 1, 1));
+    }
+
+    #[test]
+    fn end_of_month() {
+        assert_eq!(d(2024, 2, 10).end_of_month(), d(2024, 2, 29));
+        assert_eq!(d(2025, 6, 1).end_of_month(), d(2025, 6, 30));
+    }
+
+    #[test]
+    fn add_years() {
+        assert_eq!(d(2025, 3, 14).add_years(1), d(2026, 3, 14));
+        assert_eq!(d(2024, 2, 29).add_years(1), d(2025, 2, 28));
+        assert_eq!(d(2024, 2, 29).add_years(4), d(2028, 2, 29));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!("2025-06-30".parse::<Date>(), Ok(d(2025, 6, 30)));
+        assert_eq!(d(2025, 6, 3).to_string(), "2025-06-03");
+        assert!("2025-02-30".parse::<Date>().is_err());
+        assert!("2025/06/30".parse::<Date>().is_err());
+        assert!("2025-06".parse::<Date>().is_err());
+    }
+}
@@ -1,6 +1,7 @@
 /// A filer (taxpayer or spouse) for purposes of the additional standard
 /// deduction and other age/blindness rules.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Filer {
     pub is_65_or_older: bool,
     pub is_blind: bool,
@@ -13,3 +14,13 @@ impl Filer {
         self.is_65_or_older as i64 + self.is_blind as i64
     }
 }
+
+/// Which filer on the return an item belongs to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Owner {
+    #[default]
+    Taxpayer,
+    /// The spouse on a joint return.
+    Spouse,
+}
@@ -0,0 +1,41 @@
+//! Serde for [`FilingStatus`], which `us_tax_brackets` does not derive.
+//!
+//! Use with `#[serde(with = "gideon_tax_core::types::filing_status_serde")]`.
+//! The status is written as its variant name, such as `"HeadOfHousehold"`.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::FilingStatus;
+
+/// Every status and its name.
+const NAMES: [(FilingStatus, &str); 5] = [
+    (FilingStatus::Single, "Single"),
+    (FilingStatus::MarriedFilingJointly, "MarriedFilingJointly"),
+    (
+        FilingStatus::MarriedFilingSeparately,
+        "MarriedFilingSeparately",
+    ),
+    (FilingStatus::HeadOfHousehold, "HeadOfHousehold"),
+    (
+        FilingStatus::QualifyingSurvivingSpouse,
+        "QualifyingSurvivingSpouse",
+    ),
+];
+
+pub fn serialize<S: Serializer>(status: &FilingStatus, serializer: S) -> Result<S::Ok, S::Error> {
+    let (_, name) = NAMES
+        .iter()
+        .find(|(s, _)| s == status)
+        .expect("every filing status is named");
+    serializer.serialize_str(name)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<FilingStatus, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    NAMES
+        .iter()
+        .find(|(_, n)| *n == name)
+        .map(|(s, _)| *s)
+        .ok_or_else(|| D::Error::custom(format!("unknown filing status: {name}")))
+}
@@ -1,5 +1,11 @@
+mod date;
 mod filer;
+#[cfg(feature = "serde")]
+pub mod filing_status_serde;
+mod rate;
 mod usd;
 
-pub use filer::Filer;
+pub use date::{Date, ParseDateError, days_in_month, is_leap_year};
+pub use filer::{Filer, Owner};
+pub use rate::Rate;
 pub use usd::Usd;
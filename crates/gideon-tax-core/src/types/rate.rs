@@ -0,0 +1,96 @@
+use core::fmt;
+use core::ops::Mul;
+
+use super::Usd;
+
+/// A percentage rate stored as whole basis points (hundredths of a percent).
+///
+/// Every rate the IRS publishes for individual returns (bracket rates,
+/// FICA rates, ACA applicable percentages) is a whole number of basis points.
+///
+/// # Examples
+///
+/// ```
+/// use gideon_tax_core::{Rate, Usd};
+///
+/// let rate = Rate::from_bps(902);
+/// assert_eq!(rate.to_string(), "9.02%");
+/// assert_eq!(Usd::from_dollars(50_000) * rate, Usd::from_dollars(4_510));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rate(i64);
+
+impl Rate {
+    /// Zero percent.
+    pub const ZERO: Self = Rate(0);
+
+    /// Creates a rate from basis points (`902` is 9.02%).
+    pub const fn from_bps(bps: i64) -> Self {
+        Rate(bps)
+    }
+
+    /// Creates a rate from a whole percentage (`22` is 22%).
+    pub const fn from_percent(percent: i64) -> Self {
+        Rate(percent * 100)
+    }
+
+    /// Returns the rate in basis points.
+    pub const fn bps(self) -> i64 {
+        self.0
+    }
+}
+
+impl Mul<Rate> for Usd {
+    type Output = Usd;
+
+    /// Applies the rate, rounding half away from zero to the nearest cent.
+    fn mul(self, rhs: Rate) -> Usd {
+        self.mul_div(rhs.0, 10_000)
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        if abs.is_multiple_of(100) {
+            write!(f, "{}{}%", sign, abs / 100)
+        } else {
+            let frac = format!("{:02}", abs % 100);
+            write!(f, "{}{}.{}%", sign, abs / 100, frac.trim_end_matches('0'))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Rate::from_percent(22).bps(), 2_200);
+        assert_eq!(Rate::from_bps(145).bps(), 145);
+    }
+
+    #[test]
+    fn apply_to_usd() {
+        assert_eq!(
+            Usd::from_dollars(1_000) * Rate::from_bps(765),
+            Usd::from_dollars(76) + Usd::from_cents(50)
+        );
+        assert_eq!(
+            Usd::from_cents(1) * Rate::from_percent(50),
+            Usd::from_cents(1)
+        );
+        assert_eq!(Usd::from_dollars(100) * Rate::ZERO, Usd::ZERO);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Rate::from_percent(22).to_string(), "22%");
+        assert_eq!(Rate::from_bps(902).to_string(), "9.02%");
+        assert_eq!(Rate::from_bps(90).to_string(), "0.9%");
+        assert_eq!(Rate::from_bps(1_530).to_string(), "15.3%");
+    }
+}
@@ -1,6 +1,6 @@
 use core::fmt;
 use core::iter::Sum;
-use core::ops::{Add, Mul, Neg, Sub};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 /// US dollar amount stored as whole cents for exact arithmetic.
 ///
@@ -24,6 +24,8 @@ use core::ops::{Add, Mul, Neg, Sub};
 /// assert_eq!(amount.irs_round(), Usd::from_dollars(11));
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Usd(i64);
 
 impl Usd {
@@ -113,6 +115,46 @@ impl Usd {
         }
     }
 
+    /// Multiplies by the fraction `numerator / denominator`, rounding the
+    /// result half away from zero to the nearest cent.
+    ///
+    /// Used for the percentage steps of IRS worksheets (e.g. "multiply line 1
+    /// by 85%" is `mul_div(85, 100)`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gideon_tax_core::Usd;
+    ///
+    /// assert_eq!(Usd::from_dollars(1_000).mul_div(85, 100), Usd::from_dollars(850));
+    /// assert_eq!(Usd::from_cents(101).mul_div(1, 2), Usd::from_cents(51));
+    /// ```
+    pub const fn mul_div(self, numerator: i64, denominator: i64) -> Self {
+        let product = self.0 as i128 * numerator as i128;
+        let den = denominator as i128;
+        let quotient = product / den;
+        let rem = product % den;
+        let rounded = if rem.unsigned_abs() * 2 >= den.unsigned_abs() {
+            if (product < 0) != (den < 0) {
+                quotient - 1
+            } else {
+                quotient + 1
+            }
+        } else {
+            quotient
+        };
+        Usd(rounded as i64)
+    }
+
+    /// Returns the absolute value.
+    pub const fn abs(self) -> Self {
+        Usd(self.0.abs())
+    }
+
     /// Returns the larger of two `Usd` values.
     pub const fn max(self, other: Self) -> Self {
         if self.0 >= other.0 { self } else { other }
@@ -140,6 +182,18 @@ impl Sub for Usd {
     }
 }
 
+impl AddAssign for Usd {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Usd {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
 impl Neg for Usd {
     type Output = Self;
 
@@ -207,6 +261,28 @@ mod tests {
         assert_eq!(Usd::from_cents(33) * 2, Usd::from_cents(66));
     }
 
+    #[test]
+    fn add_assign_sub_assign() {
+        let mut a = Usd::from_dollars(10);
+        a += Usd::from_cents(550);
+        assert_eq!(a, Usd::from_cents(1550));
+        a -= Usd::from_dollars(20);
+        assert_eq!(a, Usd::from_cents(-450));
+    }
+
+    #[test]
+    fn mul_div() {
+        assert_eq!(
+            Usd::from_dollars(100).mul_div(85, 100),
+            Usd::from_dollars(85)
+        );
+        assert_eq!(Usd::from_cents(3).mul_div(1, 2), Usd::from_cents(2));
+        assert_eq!(Usd::from_cents(-3).mul_div(1, 2), Usd::from_cents(-2));
+        assert_eq!(Usd::from_cents(1).mul_div(1, 3), Usd::ZERO);
+        assert_eq!(Usd::from_cents(2).mul_div(1, 3), Usd::from_cents(1));
+        assert_eq!(Usd::from_cents(5).mul_div(-1, 2), Usd::from_cents(-3));
+    }
+
     #[test]
     fn round_up_positive() {
         assert_eq!(Usd::from_cents(100).round_up(), Usd::from_dollars(1));
@@ -282,6 +358,12 @@ mod tests {
         assert_eq!(-Usd::ZERO, Usd::ZERO);
     }
 
+    #[test]
+    fn abs() {
+        assert_eq!(Usd::from_cents(-150).abs(), Usd::from_cents(150));
+        assert_eq!(Usd::from_cents(150).abs(), Usd::from_cents(150));
+    }
+
     #[test]
     fn sum() {
         let amounts = vec![
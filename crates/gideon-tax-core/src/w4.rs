@@ -0,0 +1,179 @@
+use crate::Usd;
+use crate::ledger::{Key, Ledger};
+
+/// How often wages are paid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PayFrequency {
+    Daily,
+    Weekly,
+    Biweekly,
+    Semimonthly,
+    Monthly,
+    Quarterly,
+    Semiannual,
+    Annual,
+}
+
+impl PayFrequency {
+    /// Pay periods in a year, as in Publication 15-T, Worksheet 1A.
+    pub fn periods_per_year(self) -> u32 {
+        match self {
+            PayFrequency::Daily => 260,
+            PayFrequency::Weekly => 52,
+            PayFrequency::Biweekly => 26,
+            PayFrequency::Semimonthly => 24,
+            PayFrequency::Monthly => 12,
+            PayFrequency::Quarterly => 4,
+            PayFrequency::Semiannual => 2,
+            PayFrequency::Annual => 1,
+        }
+    }
+}
+
+/// Withholding from one job under its current Form W-4.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaySchedule {
+    pub frequency: PayFrequency,
+    /// Federal income tax withheld so far this year.
+    pub withheld_to_date: Usd,
+    /// Federal income tax withheld each paycheck.
+    pub withholding_per_period: Usd,
+    /// Paychecks left in the year.
+    pub remaining_periods: u32,
+}
+
+/// Refund the filer is aiming for. A negative bound is a balance due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefundBand {
+    pub min: Usd,
+    pub max: Usd,
+}
+
+/// Changes to Form W-4 that bring the refund into the target band.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct W4Recommendation {
+    /// Withholding to date plus the remaining paychecks unchanged.
+    pub projected_withholding: Usd,
+    /// Refund with the current Form W-4, negative if tax is owed.
+    pub projected_refund: Usd,
+    /// Change in withholding for the rest of the year, negative to
+    /// withhold less.
+    pub withholding_change: Usd,
+    /// Step 3 amount that lowers withholding by `-withholding_change`.
+    pub step_3_credits: Usd,
+    /// Step 4(c) extra withholding per paycheck.
+    pub step_4c_extra_withholding: Usd,
+    /// Refund with the recommended Form W-4.
+    pub recommended_refund: Usd,
+}
+
+/// Recommends Form W-4 entries so the return in `projection`, a ledger
+/// of projected full-year liability and payments other than withholding,
+/// ends with a refund inside `band`.
+///
+/// The recommendation aims for the middle of the band. Under Publication
+/// 15-T, a Step 3 amount reduces annual withholding dollar for dollar
+/// across all of the year's paychecks, and a Step 4(c) amount is added to
+/// each paycheck, so both are exact without the withholding tables. W-4
+/// entries are whole dollars: extra withholding is rounded up and Step 3
+/// rounded down, erring toward a larger refund. Withholding cannot go
+/// below zero, so a band may be out of reach.
+pub fn recommend_w4(projection: &Ledger, pay: &PaySchedule, band: RefundBand) -> W4Recommendation {
+    let get = |key: Key| projection.get(&key).copied().unwrap_or(Usd::ZERO);
+    let other_payments = get(Key::TotalPayments) - get(Key::Withholding);
+    let remaining = i64::from(pay.remaining_periods);
+    let projected_withholding =
+        pay.withheld_to_date + pay.withholding_per_period.mul_div(remaining, 1);
+    let projected_refund = projected_withholding + other_payments - get(Key::TotalTax);
+
+    let mut rec = W4Recommendation {
+        projected_withholding,
+        projected_refund,
+        recommended_refund: projected_refund,
+        ..Default::default()
+    };
+    if remaining == 0 || (band.min..=band.max).contains(&projected_refund) {
+        return rec;
+    }
+    let target = band.min + (band.max - band.min).mul_div(1, 2);
+    let change = target - projected_refund;
+    let periods = i64::from(pay.frequency.periods_per_year());
+    if change > Usd::ZERO {
+        rec.step_4c_extra_withholding = change.mul_div(1, remaining).round_up();
+        rec.withholding_change = rec.step_4c_extra_withholding.mul_div(remaining, 1);
+    } else {
+        // Step 3 is spread over every paycheck in the year, so only the
+        // remaining share of it lowers this year's withholding.
+        let reduction = (Usd::ZERO - change).min(pay.withholding_per_period.mul_div(remaining, 1));
+        rec.step_3_credits = reduction.mul_div(periods, remaining).round_down();
+        rec.withholding_change = Usd::ZERO - rec.step_3_credits.mul_div(remaining, periods);
+    }
+    rec.recommended_refund = projected_refund + rec.withholding_change;
+    rec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projection(total_tax: i64) -> Ledger {
+        let mut ledger = Ledger::new();
+        ledger.insert(Key::TotalTax, Usd::from_dollars(total_tax));
+        ledger
+    }
+
+    fn pay(withheld_to_date: i64, per_period: i64, remaining_periods: u32) -> PaySchedule {
+        PaySchedule {
+            frequency: PayFrequency::Biweekly,
+            withheld_to_date: Usd::from_dollars(withheld_to_date),
+            withholding_per_period: Usd::from_dollars(per_period),
+            remaining_periods,
+        }
+    }
+
+    #[test]
+    fn extra_withholding_covers_shortfall() {
+        // 6,000 + 10 × 300 = 9,000 withheld against 10,000 of tax.
+        let band = RefundBand {
+            min: Usd::ZERO,
+            max: Usd::from_dollars(500),
+        };
+        let rec = recommend_w4(&projection(10_000), &pay(6_000, 300, 10), band);
+        assert_eq!(rec.projected_refund, Usd::from_dollars(-1_000));
+        assert_eq!(rec.step_4c_extra_withholding, Usd::from_dollars(125));
+        assert_eq!(rec.step_3_credits, Usd::ZERO);
+        assert_eq!(rec.recommended_refund, Usd::from_dollars(250));
+    }
+
+    #[test]
+    fn step_3_reduces_overwithholding() {
+        // 13 of 26 paychecks left; 3,000 too much withheld.
+        let band = RefundBand {
+            min: Usd::ZERO,
+            max: Usd::from_dollars(1_000),
+        };
+        let rec = recommend_w4(&projection(10_000), &pay(6_500, 500, 13), band);
+        assert_eq!(rec.projected_refund, Usd::from_dollars(3_000));
+        assert_eq!(rec.step_3_credits, Usd::from_dollars(5_000));
+        assert_eq!(rec.withholding_change, Usd::from_dollars(-2_500));
+        assert_eq!(rec.recommended_refund, Usd::from_dollars(500));
+    }
+
+    #[test]
+    fn no_change_inside_band() {
+        let band = RefundBand {
+            min: Usd::from_dollars(-100),
+            max: Usd::from_dollars(100),
+        };
+        let rec = recommend_w4(&projection(10_000), &pay(5_000, 500, 10), band);
+        assert_eq!(rec.recommended_refund, Usd::ZERO);
+        assert_eq!(
+            rec,
+            W4Recommendation {
+                projected_withholding: Usd::from_dollars(10_000),
+                ..Default::default()
+            }
+        );
+    }
+}
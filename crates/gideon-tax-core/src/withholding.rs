@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+
+use us_tax_brackets::FilingStatus;
+
+use crate::rules::TaxYearRules;
+use crate::w4::PayFrequency;
+use crate::{Rate, Usd};
+
+/// Form W-4, Step 1(c) filing status.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum W4FilingStatus {
+    #[default]
+    SingleOrMarriedFilingSeparately,
+    /// Married filing jointly or qualifying surviving spouse.
+    MarriedFilingJointly,
+    HeadOfHousehold,
+}
+
+impl W4FilingStatus {
+    /// Filing status whose brackets and standard deduction the percentage
+    /// method tables follow.
+    fn filing_status(self) -> FilingStatus {
+        match self {
+            W4FilingStatus::SingleOrMarriedFilingSeparately => FilingStatus::Single,
+            W4FilingStatus::MarriedFilingJointly => FilingStatus::MarriedFilingJointly,
+            W4FilingStatus::HeadOfHousehold => FilingStatus::HeadOfHousehold,
+        }
+    }
+}
+
+/// Form W-4 for 2020 or later.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormW4 {
+    /// Step 1(c).
+    pub filing_status: W4FilingStatus,
+    /// Step 2(c): multiple jobs or spouse works.
+    pub multiple_jobs: bool,
+    /// Step 3: claim dependents and other credits, annual.
+    pub credits: Usd,
+    /// Step 4(a): other income, annual.
+    pub other_income: Usd,
+    /// Step 4(b): deductions, annual.
+    pub deductions: Usd,
+    /// Step 4(c): extra withholding per pay period.
+    pub extra_withholding: Usd,
+}
+
+/// One row of an annual percentage method table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableRow {
+    /// Adjusted annual wage at least this amount.
+    pub floor: Usd,
+    /// Tentative withholding at `floor`.
+    pub base: Usd,
+    /// Rate on the excess over `floor`.
+    pub rate: Rate,
+}
+
+/// Worksheet 1A results for one pay period.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeriodWithholding {
+    /// Line 1i.
+    pub adjusted_annual_wage: Usd,
+    /// Line 2g.
+    pub tentative_annual_withholding: Usd,
+    /// Line 2h.
+    pub tentative_withholding: Usd,
+    /// Line 4b.
+    pub withholding: Usd,
+}
+
+/// Annual percentage method table for automated payroll systems: the
+/// standard withholding rate schedule, or with `multiple_jobs`, the Form
+/// W-4, Step 2, checkbox schedule.
+///
+/// Rows are the year's ordinary brackets offset by the part of the
+/// standard deduction not already subtracted on line 1g. The checkbox
+/// schedule halves the brackets and the standard deduction.
+///
+/// See: <https://www.irs.gov/publications/p15t>
+pub fn annual_table(
+    rules: &dyn TaxYearRules,
+    status: W4FilingStatus,
+    multiple_jobs: bool,
+) -> [TableRow; 7] {
+    let status = status.filing_status();
+    let brackets = rules.ordinary_brackets(status);
+    let standard_deduction = rules.withholding_standard_deduction(status);
+    let (offset, scale) = if multiple_jobs {
+        (standard_deduction.mul_div(1, 2), 2)
+    } else {
+        (
+            standard_deduction - rules.withholding_wage_adjustment(status),
+            1,
+        )
+    };
+    let mut base = Usd::ZERO;
+    std::array::from_fn(|i| {
+        if i > 0 {
+            let width = brackets[i].floor - brackets[i - 1].floor;
+            base += width.mul_div(1, scale) * brackets[i - 1].rate;
+        }
+        TableRow {
+            floor: offset + brackets[i].floor.mul_div(1, scale),
+            base,
+            rate: brackets[i].rate,
+        }
+    })
+}
+
+/// Federal income tax to withhold from one paycheck of `wages` under
+/// Publication 15-T, Worksheet 1A.
+pub fn withholding(
+    rules: &dyn TaxYearRules,
+    w4: &FormW4,
+    frequency: PayFrequency,
+    wages: Usd,
+) -> PeriodWithholding {
+    let periods = i64::from(frequency.periods_per_year());
+    let line_1e = wages.mul_div(periods, 1) + w4.other_income;
+    let line_1g = if w4.multiple_jobs {
+        Usd::ZERO
+    } else {
+        rules.withholding_wage_adjustment(w4.filing_status.filing_status())
+    };
+    let adjusted_annual_wage = (line_1e - w4.deductions - line_1g).max(Usd::ZERO);
+
+    let table = annual_table(rules, w4.filing_status, w4.multiple_jobs);
+    let tentative_annual_withholding = table
+        .iter()
+        .rev()
+        .find(|row| row.floor <= adjusted_annual_wage)
+        .map_or(Usd::ZERO, |row| {
+            row.base + (adjusted_annual_wage - row.floor) * row.rate
+        });
+    let tentative_withholding = tentative_annual_withholding.mul_div(1, periods);
+    let after_credits = (tentative_withholding - w4.credits.mul_div(1, periods)).max(Usd::ZERO);
+    PeriodWithholding {
+        adjusted_annual_wage,
+        tentative_annual_withholding,
+        tentative_withholding,
+        withholding: after_credits + w4.extra_withholding,
+    }
+}
+
+/// Information return reporting federal income tax withheld.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WithholdingSource {
+    /// Form 1099-INT, box 4.
+    Form1099Int,
+    /// Form 1099-DIV, box 4.
+    Form1099Div,
+    /// Form 1099-NEC, box 4.
+    Form1099Nec,
+    /// Form 1099-B, box 4.
+    Form1099B,
+    /// Form 1099-R, box 4.
+    Form1099R,
+    /// Form 1099-G, box 4.
+    Form1099G,
+    /// Form SSA-1099, box 6.
+    Ssa1099,
+}
+
+/// Federal income tax withheld on one information return (Form 1040,
+/// line 25b). On Forms 1099-INT, DIV, NEC, and B this is usually backup
+/// withholding (IRC §3406); on the others it is voluntary.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportedWithholding {
+    pub source: WithholdingSource,
+    pub payer_name: String,
+    pub payer_tin: String,
+    pub amount: Usd,
+}
+
+/// Total withheld on `reported`.
+pub fn reported_total(reported: &[ReportedWithholding]) -> Usd {
+    reported.iter().map(|r| r.amount).sum()
+}
+
+/// Withholding on `reported` by kind of information return.
+pub fn by_source(reported: &[ReportedWithholding]) -> BTreeMap<WithholdingSource, Usd> {
+    let mut totals = BTreeMap::new();
+    for r in reported {
+        *totals.entry(r.source).or_insert(Usd::ZERO) += r.amount;
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::Key;
+    use crate::rules::y2023::Rules2023;
+    use crate::rules::y2025::Rules2025;
+    use crate::rules::y2026::Rules2026Projected;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    #[test]
+    fn tables_match_publication() {
+        let single = annual_table(
+            &Rules2025,
+            W4FilingStatus::SingleOrMarriedFilingSeparately,
+            false,
+        );
+        assert_eq!(single[0].floor, Usd::from_dollars(6_400));
+        assert_eq!(single[1].floor, Usd::from_dollars(18_325));
+        assert_eq!(single[1].base, Usd::from_cents(119_250));
+        assert_eq!(single[2].base, Usd::from_cents(557_850));
+        let joint_checkbox = annual_table(&Rules2025, W4FilingStatus::MarriedFilingJointly, true);
+        assert_eq!(joint_checkbox[0].floor, Usd::from_dollars(15_000));
+        assert_eq!(joint_checkbox[1].floor, Usd::from_dollars(26_925));
+        assert_eq!(joint_checkbox[0].rate, Rate::from_percent(10));
+    }
+
+    #[test]
+    fn tables_follow_the_year() {
+        let single = |rules: &dyn TaxYearRules| {
+            annual_table(
+                rules,
+                W4FilingStatus::SingleOrMarriedFilingSeparately,
+                false,
+            )[0]
+            .floor
+        };
+        // Each year's standard deduction less the 8,600 on line 1g.
+        assert_eq!(single(&Rules2023), Usd::from_dollars(5_250));
+        assert_eq!(single(&Rules2026Projected), Usd::from_dollars(7_500));
+        let joint_checkbox = annual_table(
+            &Rules2026Projected,
+            W4FilingStatus::MarriedFilingJointly,
+            true,
+        );
+        assert_eq!(joint_checkbox[0].floor, Usd::from_dollars(16_100));
+    }
+
+    #[test]
+    fn cross_checks_with_annual_liability() {
+        let w4 = FormW4::default();
+        let per_period = withholding(
+            &Rules2025,
+            &w4,
+            PayFrequency::Monthly,
+            Usd::from_dollars(5_000),
+        );
+        assert_eq!(per_period.adjusted_annual_wage, Usd::from_dollars(51_400));
+        assert_eq!(
+            per_period.tentative_annual_withholding,
+            Usd::from_cents(516_150)
+        );
+        assert_eq!(per_period.withholding, Usd::from_cents(43_013));
+
+        // The tables predate the higher 2025 standard deduction, so a single
+        // job withholds 12% of the 750 difference more than the tax, less
+        // the 3.50 the tax table adds by taxing its row's midpoint.
+        let ledger = compute_spine(&Rules2025, &input(60_000, 0)).unwrap();
+        assert_eq!(
+            per_period.tentative_annual_withholding - ledger[&Key::TotalTax],
+            Usd::from_cents(8_650)
+        );
+    }
+
+    #[test]
+    fn credits_floor_at_zero_before_extra_withholding() {
+        let w4 = FormW4 {
+            filing_status: W4FilingStatus::MarriedFilingJointly,
+            credits: Usd::from_dollars(4_000),
+            extra_withholding: Usd::from_dollars(50),
+            ..Default::default()
+        };
+        let per_period = withholding(
+            &Rules2025,
+            &w4,
+            PayFrequency::Monthly,
+            Usd::from_dollars(4_000),
+        );
+        assert_eq!(per_period.adjusted_annual_wage, Usd::from_dollars(35_100));
+        assert_eq!(per_period.withholding, Usd::from_dollars(50));
+    }
+
+    #[test]
+    fn reported_withholding_by_source() {
+        let reported = |source, amount| ReportedWithholding {
+            source,
+            payer_name: String::new(),
+            payer_tin: String::new(),
+            amount: Usd::from_dollars(amount),
+        };
+        let items = [
+            reported(WithholdingSource::Form1099Int, 24),
+            reported(WithholdingSource::Form1099Nec, 1_200),
+            reported(WithholdingSource::Form1099Int, 6),
+        ];
+        assert_eq!(reported_total(&items), Usd::from_dollars(1_230));
+        assert_eq!(
+            by_source(&items).into_iter().collect::<Vec<_>>(),
+            vec![
+                (WithholdingSource::Form1099Int, Usd::from_dollars(30)),
+                (WithholdingSource::Form1099Nec, Usd::from_dollars(1_200)),
+            ]
+        );
+    }
+}
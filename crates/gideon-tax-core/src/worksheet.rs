@@ -0,0 +1,495 @@
+//! IRS worksheets behind ledger lines.
+//!
+//! [`explain`] lays out how a ledger line was figured as the worksheet the
+//! Form 1040 instructions give for it, line by line and with the
+//! instructions cited, so a reviewer can check the return against the
+//! paper worksheet.
+
+use std::fmt;
+
+use us_tax_brackets::FilingStatus;
+
+use crate::capital_gain_tax::{self, CapitalGainTax};
+use crate::rules::TaxYearRules;
+use crate::spine::{self, Key, Ledger, ReturnInput};
+use crate::{Rate, Usd};
+
+/// One line of a worksheet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorksheetLine {
+    /// The line's number on the worksheet, such as `"5a"`, or empty for a
+    /// chart row.
+    pub number: &'static str,
+    pub text: String,
+    /// `None` for a line that gives a rate or instruction rather than an
+    /// amount.
+    pub amount: Option<Usd>,
+}
+
+/// A ledger line figured on the worksheet the IRS instructions give for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Worksheet {
+    pub key: Key,
+    /// The worksheet's name in the instructions, such as
+    /// `"Standard Deduction Worksheet for Dependents"`.
+    pub title: String,
+    /// Where the worksheet is, such as
+    /// `"2025 Instructions for Form 1040, line 12"`.
+    pub citation: String,
+    pub lines: Vec<WorksheetLine>,
+    /// Where the ledger departs from the worksheet, or how its result was
+    /// used.
+    pub notes: Vec<String>,
+}
+
+impl Worksheet {
+    fn new(key: Key, title: impl Into<String>, citation: String) -> Self {
+        Worksheet {
+            key,
+            title: title.into(),
+            citation,
+            lines: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    fn line(&mut self, number: &'static str, text: impl Into<String>, amount: Usd) -> Usd {
+        self.lines.push(WorksheetLine {
+            number,
+            text: text.into(),
+            amount: Some(amount),
+        });
+        amount
+    }
+
+    fn text(&mut self, number: &'static str, text: impl Into<String>) {
+        self.lines.push(WorksheetLine {
+            number,
+            text: text.into(),
+            amount: None,
+        });
+    }
+
+    /// The amount on line `number`, if the worksheet has it.
+    pub fn amount(&self, number: &str) -> Option<Usd> {
+        self.lines
+            .iter()
+            .find(|l| l.number == number)
+            .and_then(|l| l.amount)
+    }
+}
+
+/// Lays out how `key` was figured for the return `ledger` was computed
+/// from, or `None` if the instructions give no worksheet for it.
+///
+/// Explains [`Key::Deductions`] with the Standard Deduction Worksheet for
+/// Dependents or the standard deduction chart, and [`Key::RegularTax`] with
+/// the Qualified Dividends and Capital Gain Tax Worksheet, the Tax
+/// Computation Worksheet, or the Tax Table, whichever arrives at the
+/// ledger's line 16. The tax is not explained for a short period annualized
+/// under IRC §443.
+pub fn explain(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    key: Key,
+) -> Option<Worksheet> {
+    let year = rules.calendar_year();
+    let citation = |line: &str| format!("{year} Instructions for Form 1040, {line}");
+    match key {
+        Key::Deductions => Some(deductions(rules, input, ledger, citation("line 12"))),
+        Key::RegularTax => {
+            if input.effective_period().is_some_and(|p| p.annualizes()) {
+                return None;
+            }
+            regular_tax(rules, input, ledger, citation("line 16"))
+        }
+        _ => None,
+    }
+}
+
+fn deductions(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    citation: String,
+) -> Worksheet {
+    use FilingStatus::*;
+
+    let params = input.deduction_params(rules);
+    let status = params.filing_status;
+    let born_before = rules.calendar_year() - 64;
+    let boxes = match status {
+        Single | HeadOfHousehold => params.taxpayer.checked_boxes(),
+        _ => params.taxpayer.checked_boxes() + params.spouse.map_or(0, |s| s.checked_boxes()),
+    };
+    let per_box = match status {
+        Single | HeadOfHousehold => rules.additional_deduction_unmarried(),
+        _ => rules.additional_deduction_married(),
+    };
+    let boxes_text = format!(
+        "{per_box} for each box checked for being born before January 2, {born_before}, or \
+         blind ({boxes})"
+    );
+    let basic = rules.typical_standard_deduction(status);
+
+    let mut w = if params.is_dependent {
+        let mut w = Worksheet::new(
+            Key::Deductions,
+            "Standard Deduction Worksheet for Dependents",
+            citation,
+        );
+        let addition = rules.dependent_earned_income_addition();
+        let l1 = w.line(
+            "1",
+            format!("Earned income plus {addition}"),
+            params.earned_income + addition,
+        );
+        let l2 = w.line(
+            "2",
+            "Minimum standard deduction",
+            rules.dependent_minimum_deduction(),
+        );
+        let l3 = w.line("3", "Larger of line 1 or line 2", l1.max(l2));
+        let l4 = w.line("4", format!("Basic standard deduction for {status}"), basic);
+        let l5a = w.line("5a", "Smaller of line 3 or line 4", l3.min(l4));
+        let l5b = w.line("5b", boxes_text, per_box * boxes);
+        w.line("5c", "Standard deduction: add lines 5a and 5b", l5a + l5b);
+        w
+    } else if boxes > 0 {
+        let mut w = Worksheet::new(
+            Key::Deductions,
+            format!(
+                "Standard Deduction Chart for People Who Were Born Before January 2, \
+                 {born_before}, or Were Blind"
+            ),
+            citation,
+        );
+        w.line("", format!("Basic standard deduction for {status}"), basic);
+        w.line("", boxes_text, per_box * boxes);
+        w.line("", "Standard deduction", basic + per_box * boxes);
+        w
+    } else {
+        let mut w = Worksheet::new(
+            Key::Deductions,
+            "Standard Deduction Chart for Most People",
+            citation,
+        );
+        w.line("", format!("Standard deduction for {status}"), basic);
+        w
+    };
+
+    let standard = input.standard_deduction(rules);
+    if params.is_dual_status_alien {
+        w.notes
+            .push("A dual-status alien gets no standard deduction.".to_string());
+    } else if status == MarriedFilingSeparately && params.spouse_itemizes {
+        w.notes.push(
+            "The spouse itemizes on a separate return, so the standard deduction is zero."
+                .to_string(),
+        );
+    } else if standard == Usd::ZERO {
+        w.notes.push(
+            "A short period annualized under IRC §443 gets no standard deduction.".to_string(),
+        );
+    }
+    let agi = ledger.get(&Key::AGI).copied().unwrap_or(Usd::ZERO);
+    let itemized = input.schedule_a(rules, agi).total;
+    if itemized > standard {
+        w.notes.push(format!(
+            "Itemized deductions of {itemized} (Schedule A, line 17) are larger, so they go \
+             on line 12 instead."
+        ));
+    } else if itemized > Usd::ZERO {
+        w.notes.push(format!(
+            "Itemized deductions of {itemized} (Schedule A, line 17) are not larger, so the \
+             standard deduction of {standard} goes on line 12."
+        ));
+    }
+    w
+}
+
+fn regular_tax(
+    rules: &dyn TaxYearRules,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    citation: String,
+) -> Option<Worksheet> {
+    let status = input.filing_status;
+    let taxable_income = ledger.get(&Key::TaxableIncome).copied()?;
+    let (qualified_dividends, net_capital_gain) = input.preferential_income(rules);
+
+    let w = if qualified_dividends + net_capital_gain > Usd::ZERO {
+        let computed = capital_gain_tax::compute(
+            rules,
+            status,
+            taxable_income,
+            qualified_dividends,
+            net_capital_gain,
+        )
+        .ok()?;
+        qdcgt(rules, status, &computed, citation)
+    } else if taxable_income < Usd::from_dollars(100_000) && !rules.uses_rate_schedule() {
+        let mut w = Worksheet::new(Key::RegularTax, "Tax Table", citation);
+        w.line("", "Taxable income (Form 1040, line 15)", taxable_income);
+        w.line(
+            "",
+            format!("Tax in the {status} column of the row for line 15"),
+            spine::tax_on(rules, status, taxable_income).ok()?,
+        );
+        w
+    } else {
+        tax_computation(rules, status, taxable_income, citation)?
+    };
+
+    // A worksheet that does not arrive at the ledger's line 16 would not
+    // explain it.
+    let result = w.lines.last().and_then(|l| l.amount)?;
+    (Some(result) == ledger.get(&Key::RegularTax).copied()).then_some(w)
+}
+
+/// Qualified Dividends and Capital Gain Tax Worksheet—Line 16, laid out
+/// from the `computed` worksheet.
+fn qdcgt(
+    rules: &dyn TaxYearRules,
+    status: FilingStatus,
+    computed: &CapitalGainTax,
+    citation: String,
+) -> Worksheet {
+    let [zero_rate_top, middle_rate_top] = rules.capital_gain_rate_thresholds(status);
+    let mut w = Worksheet::new(
+        Key::RegularTax,
+        "Qualified Dividends and Capital Gain Tax Worksheet—Line 16",
+        citation,
+    );
+    let l1 = w.line(
+        "1",
+        "Taxable income (Form 1040, line 15)",
+        computed.taxable_income,
+    );
+    w.line(
+        "2",
+        "Qualified dividends (Form 1040, line 3a)",
+        computed.qualified_dividends,
+    );
+    w.line(
+        "3",
+        "Smaller of Schedule D, line 15 or 16, if both are gains",
+        computed.net_capital_gain,
+    );
+    let l4 = w.line("4", "Add lines 2 and 3", computed.preferential_income());
+    let l5 = w.line("5", "Subtract line 4 from line 1", computed.ordinary_income);
+    let l6 = w.line(
+        "6",
+        format!("Top of the 0% rate for {status}"),
+        zero_rate_top,
+    );
+    let l7 = w.line("7", "Smaller of line 1 or line 6", l1.min(l6));
+    w.line("8", "Smaller of line 5 or line 7", l5.min(l7));
+    let l9 = w.line(
+        "9",
+        "Subtract line 8 from line 7, taxed at 0%",
+        computed.zero_rate_amount,
+    );
+    let l10 = w.line("10", "Smaller of line 1 or line 4", l1.min(l4));
+    w.line("11", "Amount from line 9", l9);
+    w.line("12", "Subtract line 11 from line 10", l10 - l9);
+    let l13 = w.line(
+        "13",
+        format!("Top of the 15% rate for {status}"),
+        middle_rate_top,
+    );
+    let l14 = w.line("14", "Smaller of line 1 or line 13", l1.min(l13));
+    let l15 = w.line("15", "Add lines 5 and 9", l5 + l9);
+    w.line(
+        "16",
+        "Subtract line 15 from line 14",
+        (l14 - l15).max(Usd::ZERO),
+    );
+    let l17 = w.line(
+        "17",
+        "Smaller of line 12 or line 16",
+        computed.middle_rate_amount,
+    );
+    w.line(
+        "18",
+        "Multiply line 17 by 15%",
+        l17 * capital_gain_tax::MIDDLE_RATE,
+    );
+    w.line("19", "Add lines 9 and 17", l9 + l17);
+    let l20 = w.line(
+        "20",
+        "Subtract line 19 from line 10",
+        computed.upper_rate_amount,
+    );
+    w.line(
+        "21",
+        "Multiply line 20 by 20%",
+        l20 * capital_gain_tax::UPPER_RATE,
+    );
+    w.line(
+        "22",
+        "Tax on line 5 (Tax Table or Tax Computation Worksheet)",
+        computed.tax_on_ordinary_income,
+    );
+    w.line(
+        "23",
+        "Add lines 18, 21, and 22",
+        computed.tax_with_preferential_rates,
+    );
+    w.line(
+        "24",
+        "Tax on line 1 (Tax Table or Tax Computation Worksheet)",
+        computed.tax_at_ordinary_rates,
+    );
+    w.line(
+        "25",
+        "Tax on all taxable income: smaller of line 23 or line 24",
+        computed.tax,
+    );
+    w
+}
+
+/// Tax Computation Worksheet—Line 16, for taxable income of $100,000 or
+/// more.
+fn tax_computation(
+    rules: &dyn TaxYearRules,
+    status: FilingStatus,
+    taxable_income: Usd,
+    citation: String,
+) -> Option<Worksheet> {
+    use FilingStatus::*;
+
+    let section = match status {
+        Single => "A",
+        MarriedFilingJointly | QualifyingSurvivingSpouse => "B",
+        MarriedFilingSeparately => "C",
+        HeadOfHousehold => "D",
+    };
+    let rate = rules
+        .ordinary_brackets(status)
+        .iter()
+        .rev()
+        .find(|b| b.floor < taxable_income)
+        .map_or(Rate::ZERO, |b| b.rate);
+    let tax = spine::tax_on(rules, status, taxable_income).ok()?;
+    let mut w = Worksheet::new(
+        Key::RegularTax,
+        format!("Tax Computation Worksheet—Line 16, Section {section}"),
+        citation,
+    );
+    w.line("(a)", "Taxable income (Form 1040, line 15)", taxable_income);
+    w.text("(b)", format!("Multiplication amount: {rate}"));
+    let c = w.line("(c)", "Multiply (a) by (b)", taxable_income * rate);
+    w.line("(d)", "Subtraction amount", c - tax);
+    w.line("", "Tax: subtract (d) from (c)", tax);
+    Some(w)
+}
+
+impl fmt::Display for Worksheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.title)?;
+        writeln!(f, "{}", self.citation)?;
+        for line in &self.lines {
+            match line.amount {
+                Some(amount) => writeln!(
+                    f,
+                    "  {:<5}{:<64}{:>14}",
+                    line.number,
+                    line.text,
+                    amount.to_string()
+                )?,
+                None => writeln!(f, "  {:<5}{}", line.number, line.text)?,
+            }
+        }
+        for note in &self.notes {
+            writeln!(f, "  Note: {note}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Owner;
+    use crate::passthrough::{EntityKind, LossLimits, PassthroughItems};
+    use crate::rules::y2025::Rules2025;
+    use crate::spine::compute_spine;
+    use crate::spine::tests::input;
+
+    fn explained(inp: &ReturnInput, key: Key) -> (Ledger, Worksheet) {
+        let ledger = compute_spine(&Rules2025, inp).unwrap();
+        let worksheet = explain(&Rules2025, inp, &ledger, key).unwrap();
+        (ledger, worksheet)
+    }
+
+    #[test]
+    fn dependent_worksheet() {
+        let mut inp = input(5_000, 0);
+        inp.is_dependent = true;
+        let (ledger, w) = explained(&inp, Key::Deductions);
+        assert_eq!(w.title, "Standard Deduction Worksheet for Dependents");
+        assert_eq!(w.citation, "2025 Instructions for Form 1040, line 12");
+        assert_eq!(w.amount("1"), Some(Usd::from_dollars(5_450)));
+        assert_eq!(w.amount("4"), Some(Usd::from_dollars(15_750)));
+        assert_eq!(w.amount("5c"), Some(ledger[&Key::Deductions]));
+        assert!(w.to_string().starts_with(
+            "Standard Deduction Worksheet for Dependents\n\
+             2025 Instructions for Form 1040, line 12\n  \
+             1    Earned income plus $450.00"
+        ));
+        assert!(explain(&Rules2025, &inp, &ledger, Key::AGI).is_none());
+    }
+
+    #[test]
+    fn tax_computation_worksheet_matches_ledger() {
+        let (ledger, w) = explained(&input(175_750, 0), Key::RegularTax);
+        assert_eq!(w.title, "Tax Computation Worksheet—Line 16, Section A");
+        assert_eq!(w.amount("(a)"), Some(Usd::from_dollars(160_000)));
+        assert_eq!(w.amount("(c)"), Some(Usd::from_dollars(38_400)));
+        assert_eq!(
+            w.lines.last().unwrap().amount,
+            Some(ledger[&Key::RegularTax])
+        );
+        assert!(w.notes.is_empty());
+    }
+
+    #[test]
+    fn qualified_dividends_worksheet() {
+        let mut inp = input(60_000, 0);
+        inp.passthroughs.push(PassthroughItems {
+            entity_name: "Acme LP".to_string(),
+            entity_ein: "12-3456789".to_string(),
+            kind: EntityKind::Partnership,
+            owner: Owner::Taxpayer,
+            material_participation: false,
+            ordinary_income: Usd::ZERO,
+            rental_income: Usd::ZERO,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: Usd::ZERO,
+            section_179_deduction: Usd::ZERO,
+            interest: Usd::ZERO,
+            ordinary_dividends: Usd::from_dollars(10_000),
+            qualified_dividends: Usd::from_dollars(10_000),
+            net_short_term_capital_gain: Usd::ZERO,
+            net_long_term_capital_gain: Usd::ZERO,
+            self_employment_earnings: Usd::ZERO,
+            qualified_business_income: Usd::ZERO,
+            loss_limits: LossLimits::default(),
+        });
+        let (ledger, w) = explained(&inp, Key::RegularTax);
+        assert_eq!(w.amount("1"), Some(Usd::from_dollars(54_250)));
+        assert_eq!(w.amount("5"), Some(Usd::from_dollars(44_250)));
+        // 48,350 − 44,250 of the dividends at 0%, the rest at 15%.
+        assert_eq!(w.amount("9"), Some(Usd::from_dollars(4_100)));
+        assert_eq!(w.amount("18"), Some(Usd::from_dollars(885)));
+        assert_eq!(
+            w.amount("23"),
+            Some(w.amount("22").unwrap() + Usd::from_dollars(885))
+        );
+        assert!(w.amount("23") < w.amount("24"));
+        assert_eq!(w.amount("25"), Some(ledger[&Key::RegularTax]));
+        assert!(w.notes.is_empty());
+    }
+}
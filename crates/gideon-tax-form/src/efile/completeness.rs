@@ -0,0 +1,370 @@
+//! Required fields a return bundle is missing for the way it will be filed.
+
+use std::fmt;
+
+use gideon_tax_core::FilingStatus;
+
+use crate::y2025::federal::{Form1040Person, ReturnBundle};
+
+/// How the return reaches the IRS.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TransmissionPath {
+    /// MeF, as written by [`form_1040_xml`](super::form_1040_xml) and
+    /// signed with self-select PINs.
+    #[default]
+    Efile,
+    /// Printed, signed by hand, and mailed with Copy B of each W-2.
+    Paper,
+}
+
+/// A required field that is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingField {
+    /// The bundle field, such as `source_forms.w2[0].employer_ein`.
+    pub path: String,
+    /// The form the field belongs to, such as `"Form W-2"`.
+    pub form: &'static str,
+    /// The MeF element when e-filing, such as `EmployerEIN`, or the line
+    /// on the printed form.
+    pub element: &'static str,
+}
+
+impl fmt::Display for MissingField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}: {} is empty", self.form, self.element, self.path)
+    }
+}
+
+/// Lists every field `bundle` needs filled in before it can be filed by
+/// `path`, in bundle order.
+///
+/// W-2s are checked only when e-filing; a paper return attaches the W-2s
+/// themselves. Signature PINs and dates are needed only when e-filing, as
+/// a paper return is signed by hand.
+pub fn missing_fields(bundle: &ReturnBundle, path: TransmissionPath) -> Vec<MissingField> {
+    let efile = path == TransmissionPath::Efile;
+    let mut out = Vec::new();
+    let mut need = |value: &str, field: String, form, element, line| {
+        if value.trim().is_empty() {
+            out.push(MissingField {
+                path: field,
+                form,
+                element: if efile { element } else { line },
+            });
+        }
+    };
+
+    let identity = &bundle.identity;
+    person(&mut need, "identity.taxpayer", &identity.taxpayer, false);
+    let spouse_required = matches!(
+        identity.filing_status,
+        FilingStatus::MarriedFilingJointly | FilingStatus::MarriedFilingSeparately
+    );
+    match &identity.spouse {
+        Some(spouse) => person(&mut need, "identity.spouse", spouse, true),
+        None if spouse_required => need(
+            "",
+            "identity.spouse".to_string(),
+            "Form 1040",
+            "SpouseSSN",
+            "Spouse's social security number",
+        ),
+        None => {}
+    }
+
+    let address = &identity.address;
+    need(
+        &address.street,
+        "identity.address.street".to_string(),
+        "Form 1040",
+        "AddressLine1Txt",
+        "Home address",
+    );
+    need(
+        &address.city,
+        "identity.address.city".to_string(),
+        "Form 1040",
+        "CityNm",
+        "City, town, or post office",
+    );
+    if address.foreign_country.is_some() {
+        need(
+            address.foreign_country.as_deref().unwrap_or(""),
+            "identity.address.foreign_country".to_string(),
+            "Form 1040",
+            "CountryCd",
+            "Foreign country name",
+        );
+    } else {
+        need(
+            &address.state,
+            "identity.address.state".to_string(),
+            "Form 1040",
+            "StateAbbreviationCd",
+            "State",
+        );
+        need(
+            &address.zip,
+            "identity.address.zip".to_string(),
+            "Form 1040",
+            "ZIPCd",
+            "ZIP code",
+        );
+    }
+
+    for (i, dependent) in identity.dependents.iter().enumerate() {
+        let field = |name: &str| format!("identity.dependents[{i}].{name}");
+        need(
+            &dependent.first_name,
+            field("first_name"),
+            "Form 1040",
+            "DependentFirstNm",
+            "Dependents, column (1)",
+        );
+        need(
+            &dependent.last_name,
+            field("last_name"),
+            "Form 1040",
+            "DependentLastNm",
+            "Dependents, column (1)",
+        );
+        need(
+            &dependent.ssn,
+            field("ssn"),
+            "Form 1040",
+            "DependentSSN",
+            "Dependents, column (2)",
+        );
+        need(
+            &dependent.relationship,
+            field("relationship"),
+            "Form 1040",
+            "DependentRelationshipCd",
+            "Dependents, column (3)",
+        );
+    }
+
+    if efile {
+        for (i, w2) in bundle.source_forms.w2.iter().enumerate() {
+            let field = |name: &str| format!("source_forms.w2[{i}].{name}");
+            need(
+                &w2.employee_ssn,
+                field("employee_ssn"),
+                "Form W-2",
+                "EmployeeSSN",
+                "box a",
+            );
+            need(
+                &w2.employer_ein,
+                field("employer_ein"),
+                "Form W-2",
+                "EmployerEIN",
+                "box b",
+            );
+            need(
+                w2.employer_name_address.lines().next().unwrap_or(""),
+                field("employer_name_address"),
+                "Form W-2",
+                "BusinessNameLine1Txt",
+                "box c",
+            );
+            need(
+                &w2.employee_name,
+                field("employee_name"),
+                "Form W-2",
+                "EmployeeNm",
+                "box e",
+            );
+        }
+    }
+
+    if let Some(deposit) = &bundle.filing.direct_deposit {
+        need(
+            &deposit.routing_number,
+            "filing.direct_deposit.routing_number".to_string(),
+            "Form 1040",
+            "RoutingTransitNum",
+            "line 35b",
+        );
+        need(
+            &deposit.account_number,
+            "filing.direct_deposit.account_number".to_string(),
+            "Form 1040",
+            "DepositorAccountNum",
+            "line 35d",
+        );
+    }
+
+    if efile {
+        let signature = &bundle.filing.signature;
+        let date = |d: Option<_>| if d.is_some() { "set" } else { "" };
+        need(
+            signature.self_select_pin.as_deref().unwrap_or(""),
+            "filing.signature.self_select_pin".to_string(),
+            "Form 1040",
+            "PrimarySignaturePIN",
+            "",
+        );
+        need(
+            date(signature.date),
+            "filing.signature.date".to_string(),
+            "Form 1040",
+            "PrimaryDateSignedDt",
+            "",
+        );
+        if identity.filing_status == FilingStatus::MarriedFilingJointly {
+            need(
+                signature.spouse_self_select_pin.as_deref().unwrap_or(""),
+                "filing.signature.spouse_self_select_pin".to_string(),
+                "Form 1040",
+                "SpouseSignaturePIN",
+                "",
+            );
+            need(
+                date(signature.spouse_date),
+                "filing.signature.spouse_date".to_string(),
+                "Form 1040",
+                "SpouseDateSignedDt",
+                "",
+            );
+        }
+    }
+    out
+}
+
+type Need<'a> = dyn FnMut(&str, String, &'static str, &'static str, &'static str) + 'a;
+
+fn person(need: &mut Need<'_>, path: &str, person: &Form1040Person, spouse: bool) {
+    let (ssn, ssn_line, name_control) = if spouse {
+        (
+            "SpouseSSN",
+            "Spouse's social security number",
+            "SpouseNameControlTxt",
+        )
+    } else {
+        (
+            "PrimarySSN",
+            "Your social security number",
+            "PrimaryNameControlTxt",
+        )
+    };
+    need(
+        &person.first_name,
+        format!("{path}.first_name"),
+        "Form 1040",
+        "NameLine1Txt",
+        "First name and middle initial",
+    );
+    need(
+        &person.last_name,
+        format!("{path}.last_name"),
+        "Form 1040",
+        name_control,
+        "Last name",
+    );
+    need(
+        &person.ssn,
+        format!("{path}.ssn"),
+        "Form 1040",
+        ssn,
+        ssn_line,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2025::federal::{
+        DirectDeposit, Filing, Form1040Address, Form1040Dependent, Form1040Identity, IrsW2,
+        SourceForms,
+    };
+
+    fn paths(missing: &[MissingField]) -> Vec<&str> {
+        missing.iter().map(|m| m.path.as_str()).collect()
+    }
+
+    fn bundle() -> ReturnBundle {
+        ReturnBundle {
+            identity: Form1040Identity {
+                filing_status: FilingStatus::MarriedFilingJointly,
+                taxpayer: Form1040Person {
+                    first_name: "Pat".to_string(),
+                    last_name: "Doe".to_string(),
+                    ssn: "400-12-0001".to_string(),
+                    ..Default::default()
+                },
+                address: Form1040Address {
+                    street: "1 Main St".to_string(),
+                    city: "Springfield".to_string(),
+                    state: "IL".to_string(),
+                    ..Default::default()
+                },
+                dependents: vec![Form1040Dependent {
+                    first_name: "Sam".to_string(),
+                    last_name: "Doe".to_string(),
+                    relationship: "Son".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            source_forms: SourceForms {
+                w2: vec![IrsW2 {
+                    employee_ssn: "400-12-0001".to_string(),
+                    employer_name_address: "Acme\n1 Main St".to_string(),
+                    employee_name: "Pat Doe".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            filing: Filing {
+                direct_deposit: Some(DirectDeposit {
+                    routing_number: "011000015".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn efile_needs_w2s_and_signatures() {
+        let missing = missing_fields(&bundle(), TransmissionPath::Efile);
+        assert_eq!(
+            paths(&missing),
+            [
+                "identity.spouse",
+                "identity.address.zip",
+                "identity.dependents[0].ssn",
+                "source_forms.w2[0].employer_ein",
+                "filing.direct_deposit.account_number",
+                "filing.signature.self_select_pin",
+                "filing.signature.date",
+                "filing.signature.spouse_self_select_pin",
+                "filing.signature.spouse_date",
+            ]
+        );
+        assert_eq!(
+            missing[3].to_string(),
+            "Form W-2, EmployerEIN: source_forms.w2[0].employer_ein is empty"
+        );
+    }
+
+    #[test]
+    fn paper_names_form_lines() {
+        let missing = missing_fields(&bundle(), TransmissionPath::Paper);
+        let lines: Vec<_> = missing
+            .iter()
+            .map(|m| (m.path.as_str(), m.element))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                ("identity.spouse", "Spouse's social security number"),
+                ("identity.address.zip", "ZIP code"),
+                ("identity.dependents[0].ssn", "Dependents, column (2)"),
+                ("filing.direct_deposit.account_number", "line 35d"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,600 @@
+use gideon_tax_core::{Date, FilingStatus, TaxYear, Usd};
+
+use super::xml::XmlWriter;
+use super::{EfileError, Originator, ReturnHeader, schema_version};
+use crate::y2025::federal::{Form1040Identity, Form1040Person, IrsForm1040, IrsW2};
+
+/// Writes `form` and the `w2s` attached to it as an MeF 1040 return: the
+/// return header, `IRS1040`, Schedules 1 through 3 when lines 8, 10, 17,
+/// 20, 23, or 31 carry an amount, and one `IRSW2` per form.
+///
+/// Amounts are rounded to whole dollars and zero amounts are left out, as
+/// MeF expects. `IrsForm1040` holds only the totals carried from each
+/// schedule, so the schedules carry their total lines and no detail.
+/// Employer and employee addresses are free text on [`IrsW2`] and are not
+/// written.
+pub fn form_1040_xml(
+    header: &ReturnHeader,
+    form: &IrsForm1040,
+    w2s: &[IrsW2],
+) -> Result<String, EfileError> {
+    let signature = &form.signature;
+    let signatures = Signatures::of(form)?;
+
+    let schedule_1 = form.additional_income != Usd::ZERO || form.adjustments_to_income != Usd::ZERO;
+    let schedule_2 = form.schedule_2_part_1_tax != Usd::ZERO || form.other_taxes != Usd::ZERO;
+    let schedule_3 = form.schedule_3_credits != Usd::ZERO || form.schedule_3_payments != Usd::ZERO;
+    let document_count =
+        1 + schedule_1 as usize + schedule_2 as usize + schedule_3 as usize + w2s.len();
+
+    let mut w = XmlWriter::new();
+    w.start(
+        "Return",
+        &[
+            ("xmlns", "http://www.irs.gov/efile"),
+            ("xmlns:efile", "http://www.irs.gov/efile"),
+            ("returnVersion", schema_version(TaxYear::Y2025)),
+        ],
+    );
+
+    w.start("ReturnHeader", &[("binaryAttachmentCnt", "0")]);
+    w.text("ReturnTs", &header.timestamp);
+    w.text("TaxYr", "2025");
+    w.text("TaxPeriodBeginDt", "2025-01-01");
+    w.text("TaxPeriodEndDt", "2025-12-31");
+    w.text("SoftwareId", &header.software_id);
+    w.start("OriginatorGrp", &[]);
+    w.text("EFIN", &header.efin);
+    w.text("OriginatorTypeCd", header.originator.type_code());
+    w.end();
+    w.text("PINTypeCd", header.originator.pin_type_code());
+    w.text("JuratDisclosureCd", header.originator.jurat_code());
+    signatures.write(&mut w, header.originator);
+    w.text("ReturnTypeCd", "1040");
+    write_filer(&mut w, &form.identity);
+    if let Some(preparer) = &form.paid_preparer {
+        w.start("PaidPreparerInformationGrp", &[]);
+        w.text("PreparerPersonNm", &preparer.name);
+        w.text("PTIN", &preparer.ptin);
+        w.opt_text(
+            "PreparationDt",
+            preparer.date.map(|d| d.to_string()).as_deref(),
+        );
+        w.text("PreparerFirmName", &preparer.firm_name);
+        w.text("PreparerFirmEIN", &digits(&preparer.firm_ein));
+        w.text("PhoneNum", &digits(&preparer.phone));
+        w.checkbox("SelfEmployedInd", preparer.self_employed);
+        w.end();
+    }
+    w.text("PrimaryOccupationTxt", &signature.occupation);
+    w.opt_text(
+        "SpouseOccupationTxt",
+        signature.spouse_occupation.as_deref(),
+    );
+    w.opt_text(
+        "IdentityProtectionPIN",
+        signature.identity_protection_pin.as_deref(),
+    );
+    w.opt_text(
+        "SpouseIdentityProtectionPIN",
+        signature.spouse_identity_protection_pin.as_deref(),
+    );
+    w.opt_text(
+        "PhoneNum",
+        signature.phone.as_deref().map(digits).as_deref(),
+    );
+    w.opt_text("EmailAddressTxt", signature.email.as_deref());
+    w.end();
+
+    w.start(
+        "ReturnData",
+        &[("documentCnt", &document_count.to_string())],
+    );
+    write_1040(&mut w, form);
+    if schedule_1 {
+        w.start("IRS1040Schedule1", &[("documentId", "IRS1040Schedule1")]);
+        w.amount("TotalAdditionalIncomeAmt", form.additional_income);
+        w.amount("TotalAdjustmentsAmt", form.adjustments_to_income);
+        w.end();
+    }
+    if schedule_2 {
+        w.start("IRS1040Schedule2", &[("documentId", "IRS1040Schedule2")]);
+        w.amount("TotalAdditionalTaxAmt", form.schedule_2_part_1_tax);
+        w.amount("TotalOtherTaxesAmt", form.other_taxes);
+        w.end();
+    }
+    if schedule_3 {
+        w.start("IRS1040Schedule3", &[("documentId", "IRS1040Schedule3")]);
+        w.amount("TotalNonrefundableCreditsAmt", form.schedule_3_credits);
+        w.amount("TotalOtherPaymentsRfdblCrAmt", form.schedule_3_payments);
+        w.end();
+    }
+    for (i, w2) in w2s.iter().enumerate() {
+        write_w2(&mut w, w2, &format!("IRSW2-{}", i + 1));
+    }
+    w.end();
+
+    w.end();
+    Ok(w.finish())
+}
+
+/// Self-select PINs and signature dates from a signed return.
+pub(super) struct Signatures<'a> {
+    primary: (&'a str, Date),
+    spouse: Option<(&'a str, Date)>,
+}
+
+impl<'a> Signatures<'a> {
+    /// Checks that `form` identifies the taxpayer, and the spouse on a
+    /// joint return, and that each has signed.
+    pub(super) fn of(form: &'a IrsForm1040) -> Result<Self, EfileError> {
+        let identity = &form.identity;
+        let signature = &form.signature;
+        require(&identity.taxpayer.ssn, "the taxpayer's SSN")?;
+        require(&identity.taxpayer.last_name, "the taxpayer's last name")?;
+        let pin = signature
+            .self_select_pin
+            .as_deref()
+            .ok_or(EfileError::MissingField("the taxpayer's self-select PIN"))?;
+        let signed = signature
+            .date
+            .ok_or(EfileError::MissingField("the taxpayer's signature date"))?;
+        let spouse = if identity.filing_status == FilingStatus::MarriedFilingJointly {
+            let spouse = identity
+                .spouse
+                .as_ref()
+                .ok_or(EfileError::MissingField("the spouse on a joint return"))?;
+            require(&spouse.ssn, "the spouse's SSN")?;
+            let pin = signature
+                .spouse_self_select_pin
+                .as_deref()
+                .ok_or(EfileError::MissingField("the spouse's self-select PIN"))?;
+            let signed = signature
+                .spouse_date
+                .ok_or(EfileError::MissingField("the spouse's signature date"))?;
+            Some((pin, signed))
+        } else {
+            None
+        };
+        Ok(Signatures {
+            primary: (pin, signed),
+            spouse,
+        })
+    }
+
+    /// The PIN and date elements of a return header.
+    pub(super) fn write(&self, w: &mut XmlWriter, originator: Originator) {
+        w.text("PrimaryPINEnteredByCd", originator.pin_entered_by());
+        if self.spouse.is_some() {
+            w.text("SpousePINEnteredByCd", originator.pin_entered_by());
+        }
+        w.text("PrimarySignaturePIN", self.primary.0);
+        if let Some((pin, _)) = self.spouse {
+            w.text("SpouseSignaturePIN", pin);
+        }
+        w.text("PrimaryDateSignedDt", &self.primary.1.to_string());
+        if let Some((_, signed)) = self.spouse {
+            w.text("SpouseDateSignedDt", &signed.to_string());
+        }
+    }
+}
+
+/// The `Filer` group of a return header: SSNs, name line, name controls,
+/// and address.
+pub(super) fn write_filer(w: &mut XmlWriter, identity: &Form1040Identity) {
+    let joint = identity.filing_status == FilingStatus::MarriedFilingJointly;
+    w.start("Filer", &[]);
+    w.text("PrimarySSN", &digits(&identity.taxpayer.ssn));
+    if let Some(spouse) = &identity.spouse {
+        w.text("SpouseSSN", &digits(&spouse.ssn));
+    }
+    w.text(
+        "NameLine1Txt",
+        &name_line(
+            &identity.taxpayer,
+            identity.spouse.as_ref().filter(|_| joint),
+        ),
+    );
+    w.text(
+        "PrimaryNameControlTxt",
+        &name_control(&identity.taxpayer.last_name),
+    );
+    if let Some(spouse) = &identity.spouse {
+        w.text("SpouseNameControlTxt", &name_control(&spouse.last_name));
+    }
+    let address = &identity.address;
+    let street = match &address.apartment {
+        Some(apartment) => format!("{} {apartment}", address.street),
+        None => address.street.clone(),
+    };
+    if let Some(country) = &address.foreign_country {
+        w.start("ForeignAddress", &[]);
+        w.text("AddressLine1Txt", &street);
+        w.text("CityNm", &address.city);
+        w.opt_text("ProvinceOrStateNm", address.foreign_province.as_deref());
+        w.text("CountryCd", country);
+        w.opt_text("ForeignPostalCd", address.foreign_postal_code.as_deref());
+    } else {
+        w.start("USAddress", &[]);
+        w.text("AddressLine1Txt", &street);
+        w.text("CityNm", &address.city);
+        w.text("StateAbbreviationCd", &address.state);
+        w.text("ZIPCd", &digits(&address.zip));
+    }
+    w.end();
+    w.end();
+}
+
+fn write_1040(w: &mut XmlWriter, form: &IrsForm1040) {
+    let identity = &form.identity;
+    w.start("IRS1040", &[("documentId", "IRS1040")]);
+    w.text(
+        "IndividualReturnFilingStatusCd",
+        filing_status_code(identity.filing_status),
+    );
+    let status_name = match identity.filing_status {
+        FilingStatus::MarriedFilingSeparately => "SpouseNm",
+        FilingStatus::HeadOfHousehold => "QualifyingHOHNm",
+        FilingStatus::QualifyingSurvivingSpouse => "QualifyingPersonNm",
+        _ => "",
+    };
+    if !status_name.is_empty() {
+        w.opt_text(status_name, identity.filing_status_name.as_deref());
+    }
+    w.text(
+        "VirtualCurAcquiredDurTYInd",
+        if identity.digital_assets {
+            "true"
+        } else {
+            "false"
+        },
+    );
+    w.checkbox("PECPrimaryInd", identity.presidential_campaign);
+    w.checkbox("PECSpouseInd", identity.spouse_presidential_campaign);
+    w.checkbox(
+        "PrimaryClaimAsDependentInd",
+        identity.can_be_claimed_as_dependent,
+    );
+    w.checkbox(
+        "SpouseClaimAsDependentInd",
+        identity.spouse_can_be_claimed_as_dependent,
+    );
+    w.checkbox("MustItemizeInd", identity.spouse_itemizes_or_dual_status);
+    w.checkbox(
+        "Primary65OrOlderInd",
+        identity.taxpayer.filer.is_65_or_older,
+    );
+    w.checkbox("PrimaryBlindInd", identity.taxpayer.filer.is_blind);
+    if let Some(spouse) = &identity.spouse {
+        w.checkbox("Spouse65OrOlderInd", spouse.filer.is_65_or_older);
+        w.checkbox("SpouseBlindInd", spouse.filer.is_blind);
+    }
+    for dependent in &identity.dependents {
+        w.start("DependentDetail", &[]);
+        w.text("DependentFirstNm", &dependent.first_name);
+        w.text("DependentLastNm", &dependent.last_name);
+        w.text("DependentSSN", &digits(&dependent.ssn));
+        w.text(
+            "DependentRelationshipCd",
+            &dependent.relationship.to_uppercase(),
+        );
+        w.checkbox("EligibleForChildTaxCreditInd", dependent.child_tax_credit);
+        w.checkbox("EligibleForODCInd", dependent.credit_for_other_dependents);
+        w.end();
+    }
+    w.amount("WagesAmt", form.w2_wages);
+    w.amount("HouseholdEmployeeWagesAmt", form.household_employee_wages);
+    w.amount("TipIncomeAmt", form.unreported_tips);
+    w.amount(
+        "MedicaidWaiverPymtNotRptW2Amt",
+        form.medicaid_waiver_payments,
+    );
+    w.amount("TaxableBenefitsAmt", form.dependent_care_benefits);
+    w.amount("EmployerProvidedAdoptionBnftAmt", form.adoption_benefits);
+    w.amount("WagesNotShownForm8919Amt", form.form_8919_wages);
+    w.amount("OtherEarnedIncomeAmt", form.other_earned_income);
+    w.amount("NontaxableCombatPayElectionAmt", form.nontaxable_combat_pay);
+    w.amount("WagesSalariesAndTipsAmt", form.total_wages);
+    w.amount("TaxExemptInterestAmt", form.tax_exempt_interest);
+    w.amount("TaxableInterestAmt", form.taxable_interest);
+    w.amount("QualifiedDividendsAmt", form.qualified_dividends);
+    w.amount("OrdinaryDividendsAmt", form.ordinary_dividends);
+    w.amount("IRADistributionsAmt", form.ira_distributions);
+    w.amount("TaxableIRAAmt", form.taxable_ira_distributions);
+    w.amount("PensionsAnnuitiesAmt", form.pensions_and_annuities);
+    w.amount(
+        "TotalTaxablePensionsAmt",
+        form.taxable_pensions_and_annuities,
+    );
+    w.amount("SocSecBnftAmt", form.social_security_benefits);
+    w.amount("TaxableSocSecAmt", form.taxable_social_security);
+    w.checkbox("LumpSumElectionMethodInd", form.lump_sum_election);
+    w.amount("CapitalGainLossAmt", form.capital_gain_or_loss);
+    w.checkbox("CapitalDistributionInd", form.schedule_d_not_required);
+    w.amount("TotalAdditionalIncomeAmt", form.additional_income);
+    w.amount("TotalIncomeAmt", form.total_income);
+    w.amount("TotalAdjustmentsAmt", form.adjustments_to_income);
+    w.amount("AdjustedGrossIncomeAmt", form.adjusted_gross_income);
+    w.amount(
+        "TotalItemizedOrStandardDedAmt",
+        form.standard_or_itemized_deduction,
+    );
+    w.amount("QualifiedBusinessIncomeDedAmt", form.qbi_deduction);
+    w.amount("Schedule1ADeductionsAmt", form.schedule_1a_deductions);
+    w.amount("TotalDeductionsAmt", form.total_deductions);
+    w.amount("TaxableIncomeAmt", form.taxable_income);
+    w.checkbox("Form8814Ind", form.tax_from_forms.form_8814);
+    w.checkbox("Form4972Ind", form.tax_from_forms.form_4972);
+    w.opt_text("OtherTaxFormNum", form.tax_from_forms.other_form.as_deref());
+    w.amount("TaxAmt", form.tax);
+    w.amount("AdditionalTaxAmt", form.schedule_2_part_1_tax);
+    w.amount("TotalTaxBeforeCrAndOthTaxesAmt", form.tax_before_credits);
+    w.amount("CTCODCAmt", form.child_tax_credit);
+    w.amount("TotalNonrefundableCreditsAmt", form.schedule_3_credits);
+    w.amount("TotalCreditsAmt", form.total_credits);
+    w.amount("TaxLessCreditsAmt", form.tax_after_credits);
+    w.amount("OtherTaxesAmt", form.other_taxes);
+    w.amount("TotalTaxAmt", form.total_tax);
+    w.amount("FormW2WithheldTaxAmt", form.w2_withholding);
+    w.amount("Form1099WithheldTaxAmt", form.form_1099_withholding);
+    w.amount("TaxWithheldOtherAmt", form.other_withholding);
+    w.amount("WithholdingTaxAmt", form.total_withholding);
+    w.amount("EstimatedTaxPaymentsAmt", form.estimated_tax_payments);
+    w.amount("EarnedIncomeCreditAmt", form.earned_income_credit);
+    w.amount(
+        "AdditionalChildTaxCreditAmt",
+        form.additional_child_tax_credit,
+    );
+    w.amount("RefundableAOCCreditAmt", form.american_opportunity_credit);
+    w.amount("RefundableCreditsAmt", form.schedule_3_payments);
+    w.amount("TotalOtherPaymentsRfdblCrAmt", form.total_other_payments);
+    w.amount("TotalPaymentsAmt", form.total_payments);
+    w.amount("OverpaidAmt", form.overpaid);
+    w.amount("RefundAmt", form.refund);
+    if let Some(deposit) = &form.direct_deposit {
+        w.text("RoutingTransitNum", &digits(&deposit.routing_number));
+        w.text("BankAccountTypeCd", if deposit.savings { "2" } else { "1" });
+        w.text("DepositorAccountNum", &deposit.account_number);
+    }
+    w.amount("AppliedToEsTaxAmt", form.applied_to_next_year);
+    w.amount("OwedAmt", form.amount_owed);
+    w.amount("EsPenaltyAmt", form.estimated_tax_penalty);
+    if let Some(designee) = &form.third_party_designee {
+        w.text("ThirdPartyDesigneeInd", "true");
+        w.text("ThirdPartyDesigneeNm", &designee.name);
+        w.text("ThirdPartyDesigneePhoneNum", &digits(&designee.phone));
+        w.text("ThirdPartyDesigneePIN", &designee.pin);
+    }
+    w.end();
+}
+
+fn write_w2(w: &mut XmlWriter, form: &IrsW2, document_id: &str) {
+    let employer = form.employer_name_address.lines().next().unwrap_or("");
+    w.start("IRSW2", &[("documentId", document_id)]);
+    w.text("EmployeeSSN", &digits(&form.employee_ssn));
+    w.text("EmployerEIN", &digits(&form.employer_ein));
+    w.text("EmployerNameControlTxt", &name_control(employer));
+    w.start("EmployerName", &[]);
+    w.text("BusinessNameLine1Txt", employer);
+    w.end();
+    w.opt_text("ControlNum", form.control_number.as_deref());
+    w.text("EmployeeNm", &form.employee_name);
+    w.amount("WagesAmt", form.wages_tips_other_comp);
+    w.amount("WithholdingAmt", form.federal_income_tax_withheld);
+    w.amount("SocialSecurityWagesAmt", form.social_security_wages);
+    w.amount("SocialSecurityTaxAmt", form.social_security_tax_withheld);
+    w.amount("MedicareWagesAndTipsAmt", form.medicare_wages_and_tips);
+    w.amount("MedicareTaxWithheldAmt", form.medicare_tax_withheld);
+    w.amount("SocialSecurityTipsAmt", form.social_security_tips);
+    w.amount("AllocatedTipsAmt", form.allocated_tips);
+    w.amount("DependentCareBenefitsAmt", form.dependent_care_benefits);
+    w.amount("NonqualifiedPlansAmt", form.nonqualified_plans);
+    for entry in &form.box_12 {
+        w.start("EmployersUseGrp", &[]);
+        w.text("EmployersUseCd", entry.code.code());
+        w.amount("EmployersUseAmt", entry.amount);
+        w.end();
+    }
+    w.checkbox("StatutoryEmployeeInd", form.statutory_employee);
+    w.checkbox("RetirementPlanInd", form.retirement_plan);
+    w.checkbox("ThirdPartySickPayInd", form.third_party_sick_pay);
+    if !form.state_tax.is_empty() {
+        w.start("W2StateLocalTaxGrp", &[]);
+        for state in &form.state_tax {
+            w.start("W2StateTaxGrp", &[]);
+            w.text("StateAbbreviationCd", &state.state);
+            w.opt_text("EmployerStateIdNum", state.employer_state_id.as_deref());
+            w.amount("StateWagesAmt", state.state_wages);
+            w.amount("StateIncomeTaxAmt", state.state_income_tax);
+            for local in &state.local_tax {
+                w.start("W2LocalTaxGrp", &[]);
+                w.amount("LocalWagesAndTipsAmt", local.local_wages);
+                w.amount("LocalIncomeTaxAmt", local.local_income_tax);
+                w.text("LocalityNm", &local.locality_name);
+                w.end();
+            }
+            w.end();
+        }
+        w.end();
+    }
+    w.text("StandardOrNonStandardCd", "S");
+    w.end();
+}
+
+fn require(value: &str, field: &'static str) -> Result<(), EfileError> {
+    if value.trim().is_empty() {
+        Err(EfileError::MissingField(field))
+    } else {
+        Ok(())
+    }
+}
+
+pub(super) fn filing_status_code(status: FilingStatus) -> &'static str {
+    match status {
+        FilingStatus::Single => "1",
+        FilingStatus::MarriedFilingJointly => "2",
+        FilingStatus::MarriedFilingSeparately => "3",
+        FilingStatus::HeadOfHousehold => "4",
+        FilingStatus::QualifyingSurvivingSpouse => "5",
+    }
+}
+
+/// SSNs, EINs, routing, phone, and ZIP numbers without separators.
+pub(super) fn digits(text: &str) -> String {
+    text.chars().filter(char::is_ascii_digit).collect()
+}
+
+/// The first four letters, digits, or hyphens of a name, uppercased, as
+/// the IRS matches names to taxpayer identification numbers.
+pub(super) fn name_control(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .take(4)
+        .collect::<String>()
+        .to_ascii_uppercase()
+}
+
+/// `JOHN<DOE<`, `JOHN & JANE<DOE<`, or `JOHN<DOE<& JANE SMITH` for joint
+/// filers with different last names.
+fn name_line(taxpayer: &Form1040Person, spouse: Option<&Form1040Person>) -> String {
+    let first = taxpayer.first_name.trim();
+    let last = taxpayer.last_name.trim();
+    let line = match spouse {
+        None => format!("{first}<{last}<"),
+        Some(spouse) if spouse.last_name.trim().eq_ignore_ascii_case(last) => {
+            format!("{first} & {}<{last}<", spouse.first_name.trim())
+        }
+        Some(spouse) => format!(
+            "{first}<{last}<& {} {}",
+            spouse.first_name.trim(),
+            spouse.last_name.trim()
+        ),
+    };
+    line.to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::compute_spine;
+
+    use super::*;
+    use crate::y2025::federal::test_input as input;
+    use crate::y2025::federal::{
+        Box12Code, Box12Entry, Form1040Signature, W2LocalTaxGrp, W2StateTaxGrp,
+    };
+
+    fn person(first: &str, last: &str, ssn: &str) -> Form1040Person {
+        Form1040Person {
+            first_name: first.to_string(),
+            last_name: last.to_string(),
+            ssn: ssn.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn w2() -> IrsW2 {
+        IrsW2 {
+            employee_ssn: "400-00-0001".to_string(),
+            employer_ein: "12-3456789".to_string(),
+            employer_name_address: "Acme & Co\n1 Main St".to_string(),
+            employee_name: "Pat Doe".to_string(),
+            wages_tips_other_comp: Usd::from_dollars(60_000),
+            federal_income_tax_withheld: Usd::from_dollars(9_000),
+            box_12: vec![Box12Entry {
+                code: Box12Code::DD,
+                amount: Usd::from_dollars(8_000),
+            }],
+            state_tax: vec![W2StateTaxGrp {
+                state: "OH".to_string(),
+                state_wages: Usd::from_dollars(60_000),
+                state_income_tax: Usd::from_dollars(1_500),
+                local_tax: vec![W2LocalTaxGrp {
+                    local_wages: Usd::from_dollars(60_000),
+                    local_income_tax: Usd::from_dollars(1_200),
+                    locality_name: "COLUMBUS".to_string(),
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn form() -> IrsForm1040 {
+        let mut input = input();
+        input.w2_wages = Usd::from_dollars(60_000);
+        input.fed_withholding = Usd::from_dollars(9_000);
+        let ledger = compute_spine(&Rules2025, &input).unwrap();
+        let identity = Form1040Identity {
+            taxpayer: person("Pat", "Doe", "400-00-0001"),
+            ..Default::default()
+        };
+        let mut form = IrsForm1040::from_ledger(identity, &input, &ledger);
+        form.signature = Form1040Signature {
+            date: Date::new(2026, 2, 14),
+            occupation: "Engineer".to_string(),
+            self_select_pin: Some("12345".to_string()),
+            ..Default::default()
+        };
+        form
+    }
+
+    #[test]
+    fn writes_header_1040_and_w2() {
+        let header = ReturnHeader {
+            timestamp: "2026-02-14T09:30:00-05:00".to_string(),
+            software_id: "12345678".to_string(),
+            efin: "123456".to_string(),
+            ..Default::default()
+        };
+        let form = form();
+        let xml = form_1040_xml(&header, &form, &[w2()]).unwrap();
+        assert!(xml.contains("returnVersion=\"2025v5.0\""));
+        assert!(xml.contains("<ReturnData documentCnt=\"2\">"));
+        assert!(xml.contains("<PINTypeCd>Self-Select On-Line</PINTypeCd>"));
+        assert!(xml.contains("<PrimaryDateSignedDt>2026-02-14</PrimaryDateSignedDt>"));
+        assert!(xml.contains("<PrimarySSN>400000001</PrimarySSN>"));
+        assert!(xml.contains("<NameLine1Txt>PAT&lt;DOE&lt;</NameLine1Txt>"));
+        assert!(xml.contains("<PrimaryNameControlTxt>DOE</PrimaryNameControlTxt>"));
+        assert!(xml.contains("<IndividualReturnFilingStatusCd>1</IndividualReturnFilingStatusCd>"));
+        assert!(xml.contains("<WagesAmt>60000</WagesAmt>"));
+        let taxable = form.taxable_income.cents() / 100;
+        assert!(xml.contains(&format!("<TaxableIncomeAmt>{taxable}</TaxableIncomeAmt>")));
+        assert!(!xml.contains("IRS1040Schedule"));
+        assert!(xml.contains("<IRSW2 documentId=\"IRSW2-1\">"));
+        assert!(xml.contains("<BusinessNameLine1Txt>Acme &amp; Co</BusinessNameLine1Txt>"));
+        assert!(xml.contains("<EmployersUseCd>DD</EmployersUseCd>"));
+        assert!(xml.contains("<LocalityNm>COLUMBUS</LocalityNm>"));
+    }
+
+    #[test]
+    fn schedules_and_joint_signatures() {
+        let mut form = form();
+        form.identity.filing_status = FilingStatus::MarriedFilingJointly;
+        form.identity.spouse = Some(person("Sam", "Roe", "400-00-0002"));
+        form.other_taxes = Usd::from_dollars(250);
+        assert_eq!(
+            form_1040_xml(&ReturnHeader::default(), &form, &[]),
+            Err(EfileError::MissingField("the spouse's self-select PIN"))
+        );
+        form.signature.spouse_self_select_pin = Some("54321".to_string());
+        form.signature.spouse_date = Date::new(2026, 2, 15);
+        let xml = form_1040_xml(&ReturnHeader::default(), &form, &[]).unwrap();
+        assert!(xml.contains("<ReturnData documentCnt=\"2\">"));
+        assert!(xml.contains("<IRS1040Schedule2 documentId=\"IRS1040Schedule2\">"));
+        assert!(xml.contains("<TotalOtherTaxesAmt>250</TotalOtherTaxesAmt>"));
+        assert!(xml.contains("<SpouseSignaturePIN>54321</SpouseSignaturePIN>"));
+        assert!(xml.contains("<NameLine1Txt>PAT&lt;DOE&lt;&amp; SAM ROE</NameLine1Txt>"));
+    }
+
+    #[test]
+    fn requires_a_signature() {
+        let mut form = form();
+        form.signature.self_select_pin = None;
+        let err = form_1040_xml(&ReturnHeader::default(), &form, &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "e-file requires the taxpayer's self-select PIN"
+        );
+    }
+}
@@ -0,0 +1,586 @@
+use gideon_tax_core::spine::ReturnInput;
+use gideon_tax_core::{Date, FilingStatus, Usd};
+
+use super::xml::{self, Element, invalid};
+use super::{EfileError, Originator, ReturnHeader};
+use crate::y2025::federal::{
+    Box12Code, Box12Entry, DirectDeposit, Form1040Dependent, Form1040Person, IrsForm1040, IrsW2,
+    PaidPreparer, SourceForms, ThirdPartyDesignee, W2LocalTaxGrp, W2StateTaxGrp,
+};
+
+/// A 1040 submission read back from MeF XML.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MefReturn {
+    pub header: ReturnHeader,
+    pub form_1040: IrsForm1040,
+    pub w2s: Vec<IrsW2>,
+}
+
+impl MefReturn {
+    /// Sets the filing status, filers, and dependents from the 1040, then
+    /// adds the Forms W-2 and line 6a benefits to `input`.
+    ///
+    /// Other income is on forms a submission does not carry, such as Forms
+    /// 1099, so it is not recovered. Recompute the return from `input` and
+    /// compare the result with [`form_1040`](MefReturn::form_1040) to find
+    /// what is missing.
+    pub fn apply_to(&self, input: &mut ReturnInput) {
+        let identity = &self.form_1040.identity;
+        input.filing_status = identity.filing_status;
+        input.taxpayer = identity.taxpayer.filer;
+        input.spouse = identity.spouse.as_ref().map(|s| s.filer);
+        input.is_dependent = identity.can_be_claimed_as_dependent;
+        input.spouse_itemizes = identity.spouse_itemizes_or_dual_status;
+        input.num_dependents = identity.dependents.len() as u32;
+        SourceForms {
+            w2: self.w2s.clone(),
+            ..Default::default()
+        }
+        .apply_to(input);
+        input.social_security_benefits += self.form_1040.social_security_benefits;
+    }
+}
+
+/// Reads an MeF 1040 submission, such as one written by
+/// [`form_1040_xml`](super::form_1040_xml) or other software.
+///
+/// Elements this crate does not write are ignored.
+pub fn parse_return(xml: &str) -> Result<MefReturn, EfileError> {
+    let root = xml::parse(xml)?;
+    if root.name != "Return" {
+        return Err(EfileError::MissingElement("Return"));
+    }
+    let header = root
+        .child("ReturnHeader")
+        .ok_or(EfileError::MissingElement("ReturnHeader"))?;
+    let year = header
+        .text_of("TaxYr")
+        .ok_or(EfileError::MissingElement("TaxYr"))?;
+    if year != "2025" {
+        return Err(EfileError::UnsupportedTaxYear(year.to_string()));
+    }
+    let data = root
+        .child("ReturnData")
+        .ok_or(EfileError::MissingElement("ReturnData"))?;
+    let mut form = parse_1040(
+        data.child("IRS1040")
+            .ok_or(EfileError::MissingElement("IRS1040"))?,
+    )?;
+    read_header(header, &mut form)?;
+    let originator = header.child("OriginatorGrp");
+    Ok(MefReturn {
+        header: ReturnHeader {
+            timestamp: header.text_of("ReturnTs").unwrap_or("").to_string(),
+            software_id: header.text_of("SoftwareId").unwrap_or("").to_string(),
+            efin: originator
+                .and_then(|o| o.text_of("EFIN"))
+                .unwrap_or("")
+                .to_string(),
+            originator: match originator.and_then(|o| o.text_of("OriginatorTypeCd")) {
+                Some("ERO") => Originator::Ero,
+                _ => Originator::OnlineFiler,
+            },
+        },
+        form_1040: form,
+        w2s: data
+            .children_named("IRSW2")
+            .map(w2_from_element)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Reads a single `IRSW2` document, as found in a submission's
+/// `ReturnData`.
+pub fn parse_w2(xml: &str) -> Result<IrsW2, EfileError> {
+    let root = xml::parse(xml)?;
+    if root.name != "IRSW2" {
+        return Err(EfileError::MissingElement("IRSW2"));
+    }
+    w2_from_element(&root)
+}
+
+fn text(element: &Element, name: &str) -> String {
+    element.text_of(name).unwrap_or("").to_string()
+}
+
+fn opt_text(element: &Element, name: &str) -> Option<String> {
+    element.text_of(name).map(str::to_string)
+}
+
+fn date(element: &Element, name: &str) -> Result<Option<Date>, EfileError> {
+    element
+        .text_of(name)
+        .map(|text| text.parse().map_err(|_| invalid(name, text)))
+        .transpose()
+}
+
+/// Fills the identity, signature, and preparer from the return header.
+fn read_header(header: &Element, form: &mut IrsForm1040) -> Result<(), EfileError> {
+    let filer = header
+        .child("Filer")
+        .ok_or(EfileError::MissingElement("Filer"))?;
+    let identity = &mut form.identity;
+    identity.taxpayer.ssn = filer
+        .text_of("PrimarySSN")
+        .ok_or(EfileError::MissingElement("PrimarySSN"))?
+        .to_string();
+    if let Some(ssn) = filer.text_of("SpouseSSN") {
+        let spouse = identity.spouse.get_or_insert_with(Default::default);
+        spouse.ssn = ssn.to_string();
+    }
+    let names = parse_name_line(filer.text_of("NameLine1Txt").unwrap_or(""));
+    identity.taxpayer.first_name = names.first;
+    identity.taxpayer.last_name = names.last;
+    if let (Some(spouse), Some((first, last))) = (&mut identity.spouse, names.spouse) {
+        spouse.first_name = first;
+        spouse.last_name = last;
+    }
+    if let Some(address) = filer.child("USAddress") {
+        identity.address.street = text(address, "AddressLine1Txt");
+        identity.address.city = text(address, "CityNm");
+        identity.address.state = text(address, "StateAbbreviationCd");
+        identity.address.zip = text(address, "ZIPCd");
+    } else if let Some(address) = filer.child("ForeignAddress") {
+        identity.address.street = text(address, "AddressLine1Txt");
+        identity.address.city = text(address, "CityNm");
+        identity.address.foreign_province = opt_text(address, "ProvinceOrStateNm");
+        identity.address.foreign_country = opt_text(address, "CountryCd");
+        identity.address.foreign_postal_code = opt_text(address, "ForeignPostalCd");
+    }
+
+    let signature = &mut form.signature;
+    signature.self_select_pin = opt_text(header, "PrimarySignaturePIN");
+    signature.spouse_self_select_pin = opt_text(header, "SpouseSignaturePIN");
+    signature.date = date(header, "PrimaryDateSignedDt")?;
+    signature.spouse_date = date(header, "SpouseDateSignedDt")?;
+    signature.occupation = text(header, "PrimaryOccupationTxt");
+    signature.spouse_occupation = opt_text(header, "SpouseOccupationTxt");
+    signature.identity_protection_pin = opt_text(header, "IdentityProtectionPIN");
+    signature.spouse_identity_protection_pin = opt_text(header, "SpouseIdentityProtectionPIN");
+    signature.phone = opt_text(header, "PhoneNum");
+    signature.email = opt_text(header, "EmailAddressTxt");
+
+    if let Some(preparer) = header.child("PaidPreparerInformationGrp") {
+        form.paid_preparer = Some(PaidPreparer {
+            name: text(preparer, "PreparerPersonNm"),
+            date: date(preparer, "PreparationDt")?,
+            ptin: text(preparer, "PTIN"),
+            self_employed: preparer.checkbox_of("SelfEmployedInd"),
+            firm_name: text(preparer, "PreparerFirmName"),
+            firm_address: String::new(),
+            firm_ein: text(preparer, "PreparerFirmEIN"),
+            phone: text(preparer, "PhoneNum"),
+        });
+    }
+    Ok(())
+}
+
+struct NameLine {
+    first: String,
+    last: String,
+    spouse: Option<(String, String)>,
+}
+
+/// Splits `JOHN<DOE<`, `JOHN & JANE<DOE<`, or `JOHN<DOE<& JANE SMITH`.
+fn parse_name_line(line: &str) -> NameLine {
+    let mut parts = line.split('<');
+    let firsts = parts.next().unwrap_or("").trim();
+    let last = parts.next().unwrap_or("").trim().to_string();
+    let suffix = parts.next().unwrap_or("").trim();
+    let (first, spouse) = match firsts.split_once('&') {
+        Some((first, spouse_first)) => (
+            first.trim(),
+            Some((spouse_first.trim().to_string(), last.clone())),
+        ),
+        None => (firsts, None),
+    };
+    let spouse = match suffix.strip_prefix('&') {
+        Some(name) => {
+            let name = name.trim();
+            let (first, last) = name.rsplit_once(' ').unwrap_or(("", name));
+            Some((first.trim().to_string(), last.to_string()))
+        }
+        None => spouse,
+    };
+    NameLine {
+        first: first.to_string(),
+        last,
+        spouse,
+    }
+}
+
+/// The `IRS1040` amount elements and the lines they fill.
+fn amounts_1040(form: &mut IrsForm1040) -> Vec<(&'static str, &mut Usd)> {
+    vec![
+        ("WagesAmt", &mut form.w2_wages),
+        (
+            "HouseholdEmployeeWagesAmt",
+            &mut form.household_employee_wages,
+        ),
+        ("TipIncomeAmt", &mut form.unreported_tips),
+        (
+            "MedicaidWaiverPymtNotRptW2Amt",
+            &mut form.medicaid_waiver_payments,
+        ),
+        ("TaxableBenefitsAmt", &mut form.dependent_care_benefits),
+        (
+            "EmployerProvidedAdoptionBnftAmt",
+            &mut form.adoption_benefits,
+        ),
+        ("WagesNotShownForm8919Amt", &mut form.form_8919_wages),
+        ("OtherEarnedIncomeAmt", &mut form.other_earned_income),
+        (
+            "NontaxableCombatPayElectionAmt",
+            &mut form.nontaxable_combat_pay,
+        ),
+        ("WagesSalariesAndTipsAmt", &mut form.total_wages),
+        ("TaxExemptInterestAmt", &mut form.tax_exempt_interest),
+        ("TaxableInterestAmt", &mut form.taxable_interest),
+        ("QualifiedDividendsAmt", &mut form.qualified_dividends),
+        ("OrdinaryDividendsAmt", &mut form.ordinary_dividends),
+        ("IRADistributionsAmt", &mut form.ira_distributions),
+        ("TaxableIRAAmt", &mut form.taxable_ira_distributions),
+        ("PensionsAnnuitiesAmt", &mut form.pensions_and_annuities),
+        (
+            "TotalTaxablePensionsAmt",
+            &mut form.taxable_pensions_and_annuities,
+        ),
+        ("SocSecBnftAmt", &mut form.social_security_benefits),
+        ("TaxableSocSecAmt", &mut form.taxable_social_security),
+        ("CapitalGainLossAmt", &mut form.capital_gain_or_loss),
+        ("TotalAdditionalIncomeAmt", &mut form.additional_income),
+        ("TotalIncomeAmt", &mut form.total_income),
+        ("TotalAdjustmentsAmt", &mut form.adjustments_to_income),
+        ("AdjustedGrossIncomeAmt", &mut form.adjusted_gross_income),
+        (
+            "TotalItemizedOrStandardDedAmt",
+            &mut form.standard_or_itemized_deduction,
+        ),
+        ("QualifiedBusinessIncomeDedAmt", &mut form.qbi_deduction),
+        ("Schedule1ADeductionsAmt", &mut form.schedule_1a_deductions),
+        ("TotalDeductionsAmt", &mut form.total_deductions),
+        ("TaxableIncomeAmt", &mut form.taxable_income),
+        ("TaxAmt", &mut form.tax),
+        ("AdditionalTaxAmt", &mut form.schedule_2_part_1_tax),
+        (
+            "TotalTaxBeforeCrAndOthTaxesAmt",
+            &mut form.tax_before_credits,
+        ),
+        ("CTCODCAmt", &mut form.child_tax_credit),
+        ("TotalNonrefundableCreditsAmt", &mut form.schedule_3_credits),
+        ("TotalCreditsAmt", &mut form.total_credits),
+        ("TaxLessCreditsAmt", &mut form.tax_after_credits),
+        ("OtherTaxesAmt", &mut form.other_taxes),
+        ("TotalTaxAmt", &mut form.total_tax),
+        ("FormW2WithheldTaxAmt", &mut form.w2_withholding),
+        ("Form1099WithheldTaxAmt", &mut form.form_1099_withholding),
+        ("TaxWithheldOtherAmt", &mut form.other_withholding),
+        ("WithholdingTaxAmt", &mut form.total_withholding),
+        ("EstimatedTaxPaymentsAmt", &mut form.estimated_tax_payments),
+        ("EarnedIncomeCreditAmt", &mut form.earned_income_credit),
+        (
+            "AdditionalChildTaxCreditAmt",
+            &mut form.additional_child_tax_credit,
+        ),
+        (
+            "RefundableAOCCreditAmt",
+            &mut form.american_opportunity_credit,
+        ),
+        ("RefundableCreditsAmt", &mut form.schedule_3_payments),
+        (
+            "TotalOtherPaymentsRfdblCrAmt",
+            &mut form.total_other_payments,
+        ),
+        ("TotalPaymentsAmt", &mut form.total_payments),
+        ("OverpaidAmt", &mut form.overpaid),
+        ("RefundAmt", &mut form.refund),
+        ("AppliedToEsTaxAmt", &mut form.applied_to_next_year),
+        ("OwedAmt", &mut form.amount_owed),
+        ("EsPenaltyAmt", &mut form.estimated_tax_penalty),
+    ]
+}
+
+fn parse_1040(element: &Element) -> Result<IrsForm1040, EfileError> {
+    let mut form = IrsForm1040::default();
+    for (name, line) in amounts_1040(&mut form) {
+        *line = element.amount_of(name)?;
+    }
+    let identity = &mut form.identity;
+    let status = element
+        .text_of("IndividualReturnFilingStatusCd")
+        .ok_or(EfileError::MissingElement("IndividualReturnFilingStatusCd"))?;
+    identity.filing_status = match status {
+        "1" => FilingStatus::Single,
+        "2" => FilingStatus::MarriedFilingJointly,
+        "3" => FilingStatus::MarriedFilingSeparately,
+        "4" => FilingStatus::HeadOfHousehold,
+        "5" => FilingStatus::QualifyingSurvivingSpouse,
+        _ => return Err(invalid("IndividualReturnFilingStatusCd", status)),
+    };
+    identity.filing_status_name = ["SpouseNm", "QualifyingHOHNm", "QualifyingPersonNm"]
+        .into_iter()
+        .find_map(|name| opt_text(element, name));
+    identity.digital_assets = element.checkbox_of("VirtualCurAcquiredDurTYInd");
+    identity.presidential_campaign = element.checkbox_of("PECPrimaryInd");
+    identity.spouse_presidential_campaign = element.checkbox_of("PECSpouseInd");
+    identity.can_be_claimed_as_dependent = element.checkbox_of("PrimaryClaimAsDependentInd");
+    identity.spouse_can_be_claimed_as_dependent = element.checkbox_of("SpouseClaimAsDependentInd");
+    identity.spouse_itemizes_or_dual_status = element.checkbox_of("MustItemizeInd");
+    identity.taxpayer.filer.is_65_or_older = element.checkbox_of("Primary65OrOlderInd");
+    identity.taxpayer.filer.is_blind = element.checkbox_of("PrimaryBlindInd");
+    if identity.filing_status == FilingStatus::MarriedFilingJointly {
+        let spouse = identity.spouse.get_or_insert_with(Form1040Person::default);
+        spouse.filer.is_65_or_older = element.checkbox_of("Spouse65OrOlderInd");
+        spouse.filer.is_blind = element.checkbox_of("SpouseBlindInd");
+    }
+    identity.dependents = element
+        .children_named("DependentDetail")
+        .map(|d| Form1040Dependent {
+            first_name: text(d, "DependentFirstNm"),
+            last_name: text(d, "DependentLastNm"),
+            ssn: text(d, "DependentSSN"),
+            relationship: text(d, "DependentRelationshipCd"),
+            child_tax_credit: d.checkbox_of("EligibleForChildTaxCreditInd"),
+            credit_for_other_dependents: d.checkbox_of("EligibleForODCInd"),
+            ..Default::default()
+        })
+        .collect();
+    form.lump_sum_election = element.checkbox_of("LumpSumElectionMethodInd");
+    form.schedule_d_not_required = element.checkbox_of("CapitalDistributionInd");
+    form.tax_from_forms.form_8814 = element.checkbox_of("Form8814Ind");
+    form.tax_from_forms.form_4972 = element.checkbox_of("Form4972Ind");
+    form.tax_from_forms.other_form = opt_text(element, "OtherTaxFormNum");
+    if let Some(routing_number) = element.text_of("RoutingTransitNum") {
+        form.direct_deposit = Some(DirectDeposit {
+            routing_number: routing_number.to_string(),
+            savings: element.text_of("BankAccountTypeCd") == Some("2"),
+            account_number: text(element, "DepositorAccountNum"),
+        });
+    }
+    if element.checkbox_of("ThirdPartyDesigneeInd") {
+        form.third_party_designee = Some(ThirdPartyDesignee {
+            name: text(element, "ThirdPartyDesigneeNm"),
+            phone: text(element, "ThirdPartyDesigneePhoneNum"),
+            pin: text(element, "ThirdPartyDesigneePIN"),
+        });
+    }
+    Ok(form)
+}
+
+/// The `IRSW2` amount elements and the boxes they fill.
+fn amounts_w2(form: &mut IrsW2) -> [(&'static str, &mut Usd); 10] {
+    [
+        ("WagesAmt", &mut form.wages_tips_other_comp),
+        ("WithholdingAmt", &mut form.federal_income_tax_withheld),
+        ("SocialSecurityWagesAmt", &mut form.social_security_wages),
+        (
+            "SocialSecurityTaxAmt",
+            &mut form.social_security_tax_withheld,
+        ),
+        ("MedicareWagesAndTipsAmt", &mut form.medicare_wages_and_tips),
+        ("MedicareTaxWithheldAmt", &mut form.medicare_tax_withheld),
+        ("SocialSecurityTipsAmt", &mut form.social_security_tips),
+        ("AllocatedTipsAmt", &mut form.allocated_tips),
+        (
+            "DependentCareBenefitsAmt",
+            &mut form.dependent_care_benefits,
+        ),
+        ("NonqualifiedPlansAmt", &mut form.nonqualified_plans),
+    ]
+}
+
+fn w2_from_element(element: &Element) -> Result<IrsW2, EfileError> {
+    let mut form = IrsW2::default();
+    for (name, box_amount) in amounts_w2(&mut form) {
+        *box_amount = element.amount_of(name)?;
+    }
+    form.employee_ssn = text(element, "EmployeeSSN");
+    form.employer_ein = text(element, "EmployerEIN");
+    form.employer_name_address = element
+        .child("EmployerName")
+        .map(|name| text(name, "BusinessNameLine1Txt"))
+        .unwrap_or_default();
+    form.control_number = opt_text(element, "ControlNum");
+    form.employee_name = text(element, "EmployeeNm");
+    form.box_12 = element
+        .children_named("EmployersUseGrp")
+        .map(|group| {
+            let code = group.text_of("EmployersUseCd").unwrap_or("");
+            Ok(Box12Entry {
+                code: Box12Code::from_code(code).ok_or_else(|| invalid("EmployersUseCd", code))?,
+                amount: group.amount_of("EmployersUseAmt")?,
+            })
+        })
+        .collect::<Result<_, EfileError>>()?;
+    form.statutory_employee = element.checkbox_of("StatutoryEmployeeInd");
+    form.retirement_plan = element.checkbox_of("RetirementPlanInd");
+    form.third_party_sick_pay = element.checkbox_of("ThirdPartySickPayInd");
+    if let Some(groups) = element.child("W2StateLocalTaxGrp") {
+        form.state_tax = groups
+            .children_named("W2StateTaxGrp")
+            .map(|state| {
+                Ok(W2StateTaxGrp {
+                    state: text(state, "StateAbbreviationCd"),
+                    employer_state_id: opt_text(state, "EmployerStateIdNum"),
+                    state_wages: state.amount_of("StateWagesAmt")?,
+                    state_income_tax: state.amount_of("StateIncomeTaxAmt")?,
+                    local_tax: state
+                        .children_named("W2LocalTaxGrp")
+                        .map(|local| {
+                            Ok(W2LocalTaxGrp {
+                                local_wages: local.amount_of("LocalWagesAndTipsAmt")?,
+                                local_income_tax: local.amount_of("LocalIncomeTaxAmt")?,
+                                locality_name: text(local, "LocalityNm"),
+                            })
+                        })
+                        .collect::<Result<_, EfileError>>()?,
+                })
+            })
+            .collect::<Result<_, EfileError>>()?;
+    }
+    Ok(form)
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::compute_spine;
+
+    use super::*;
+    use crate::efile::form_1040_xml;
+    use crate::y2025::federal::test_input as input;
+
+    fn person(first: &str, last: &str, ssn: &str) -> Form1040Person {
+        Form1040Person {
+            first_name: first.to_string(),
+            last_name: last.to_string(),
+            ssn: ssn.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn w2() -> IrsW2 {
+        IrsW2 {
+            employee_ssn: "400000001".to_string(),
+            employer_ein: "123456789".to_string(),
+            employer_name_address: "Acme & Co".to_string(),
+            employee_name: "Pat Doe".to_string(),
+            wages_tips_other_comp: Usd::from_dollars(60_000),
+            federal_income_tax_withheld: Usd::from_dollars(9_000),
+            social_security_wages: Usd::from_dollars(60_000),
+            box_12: vec![Box12Entry {
+                code: Box12Code::DD,
+                amount: Usd::from_dollars(8_000),
+            }],
+            retirement_plan: true,
+            state_tax: vec![W2StateTaxGrp {
+                state: "OH".to_string(),
+                state_wages: Usd::from_dollars(60_000),
+                state_income_tax: Usd::from_dollars(1_500),
+                local_tax: vec![W2LocalTaxGrp {
+                    local_wages: Usd::from_dollars(60_000),
+                    local_income_tax: Usd::from_dollars(1_200),
+                    locality_name: "COLUMBUS".to_string(),
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn signed(mut form: IrsForm1040) -> IrsForm1040 {
+        form.signature.self_select_pin = Some("12345".to_string());
+        form.signature.date = Date::new(2026, 2, 14);
+        form
+    }
+
+    #[test]
+    fn recomputes_a_written_return() {
+        let mut original = input();
+        SourceForms {
+            w2: vec![w2()],
+            ..Default::default()
+        }
+        .apply_to(&mut original);
+        let ledger = compute_spine(&Rules2025, &original).unwrap();
+        let identity = crate::y2025::federal::Form1040Identity {
+            taxpayer: person("Pat", "Doe", "400000001"),
+            ..Default::default()
+        };
+        let form = signed(IrsForm1040::from_ledger(identity, &original, &ledger));
+        let xml = form_1040_xml(&ReturnHeader::default(), &form, &[w2()]).unwrap();
+
+        let parsed = parse_return(&xml).unwrap();
+        assert_eq!(parsed.w2s, vec![w2()]);
+        assert_eq!(parsed.form_1040.identity.taxpayer.last_name, "DOE");
+        assert_eq!(parsed.form_1040.total_tax, form.total_tax.irs_round());
+        assert_eq!(parsed.form_1040.signature.date, Date::new(2026, 2, 14));
+
+        let mut recomputed = input();
+        parsed.apply_to(&mut recomputed);
+        let ledger = compute_spine(&Rules2025, &recomputed).unwrap();
+        let again =
+            IrsForm1040::from_ledger(parsed.form_1040.identity.clone(), &recomputed, &ledger);
+        assert_eq!(again.total_tax.irs_round(), parsed.form_1040.total_tax);
+        assert_eq!(again.refund.irs_round(), parsed.form_1040.refund);
+    }
+
+    #[test]
+    fn every_1040_amount_round_trips() {
+        let mut form = IrsForm1040::default();
+        form.identity.taxpayer = person("Pat", "Doe", "400000001");
+        for (i, (_, line)) in amounts_1040(&mut form).into_iter().enumerate() {
+            *line = Usd::from_dollars(i as i64 + 1);
+        }
+        let form = signed(form);
+        let xml = form_1040_xml(&ReturnHeader::default(), &form, &[]).unwrap();
+        let mut parsed = parse_return(&xml).unwrap().form_1040;
+        let mut form = form;
+        let expected: Vec<_> = amounts_1040(&mut form)
+            .into_iter()
+            .map(|(name, line)| (name, *line))
+            .collect();
+        let actual: Vec<_> = amounts_1040(&mut parsed)
+            .into_iter()
+            .map(|(name, line)| (name, *line))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn joint_names_and_w2_fragments() {
+        let names = parse_name_line("PAT<DOE<& SAM ROE");
+        assert_eq!((names.first.as_str(), names.last.as_str()), ("PAT", "DOE"));
+        assert_eq!(names.spouse, Some(("SAM".to_string(), "ROE".to_string())));
+        let names = parse_name_line("PAT & SAM<DOE<");
+        assert_eq!(names.first, "PAT");
+        assert_eq!(names.spouse, Some(("SAM".to_string(), "DOE".to_string())));
+
+        let fragment = "<IRSW2 documentId=\"IRSW2-1\"><WagesAmt>52000</WagesAmt>\
+                        <EmployersUseGrp><EmployersUseCd>D</EmployersUseCd>\
+                        <EmployersUseAmt>3000</EmployersUseAmt></EmployersUseGrp></IRSW2>";
+        let w2 = parse_w2(fragment).unwrap();
+        assert_eq!(w2.wages_tips_other_comp, Usd::from_dollars(52_000));
+        assert_eq!(w2.box_12[0].code, Box12Code::D);
+    }
+
+    #[test]
+    fn rejects_other_years_and_bad_values() {
+        let xml = "<Return><ReturnHeader><TaxYr>2024</TaxYr></ReturnHeader></Return>";
+        assert_eq!(
+            parse_return(xml),
+            Err(EfileError::UnsupportedTaxYear("2024".to_string()))
+        );
+        assert_eq!(
+            parse_w2("<IRSW2><WagesAmt>12.50</WagesAmt></IRSW2>"),
+            Err(EfileError::InvalidValue {
+                element: "WagesAmt".to_string(),
+                value: "12.50".to_string(),
+            })
+        );
+        assert!(matches!(
+            parse_w2("<IRS1040/>"),
+            Err(EfileError::MissingElement("IRSW2"))
+        ));
+    }
+}
@@ -0,0 +1,143 @@
+//! Reading and writing IRS Modernized e-File (MeF) XML for individual
+//! returns, and writing the state returns filed through MeF with them.
+//!
+//! Element names follow the MeF individual (1040) schemas, which the IRS
+//! distributes to registered software developers rather than publishing.
+//! Validate output against the schema package for the year, and pass the
+//! Assurance Testing System, before transmitting.
+//!
+//! See: <https://www.irs.gov/e-file-providers/modernized-e-file-mef-schemas-and-business-rules>
+
+use std::fmt;
+
+use gideon_tax_core::TaxYear;
+
+#[cfg(feature = "json")]
+mod completeness;
+mod form_1040;
+mod import;
+mod rules;
+mod state;
+pub(crate) mod xml;
+
+#[cfg(feature = "json")]
+pub use completeness::{MissingField, TransmissionPath, missing_fields};
+pub use form_1040::form_1040_xml;
+pub use import::{MefReturn, parse_return, parse_w2};
+pub use rules::{
+    BUSINESS_RULES, BusinessRule, DEPENDENT_SSN, DEPENDENT_SSN_UNIQUE, EIC_CHILD_AGE,
+    EIC_CHILD_SSN, PRIMARY_SSN, SPOUSE_SSN, W2_WITHHOLDING, pre_check,
+};
+pub use state::{CA_SCHEMA_VERSION, StateLink, state_return_xml};
+
+/// The 1040 schema release that returns for `year` are written against.
+///
+/// The IRS revises schemas during the filing season; update the release
+/// here when a new one is published.
+pub const fn schema_version(year: TaxYear) -> &'static str {
+    match year {
+        TaxYear::Y2023 => "2023v5.3",
+        TaxYear::Y2024 => "2024v5.2",
+        TaxYear::Y2025 => "2025v5.0",
+    }
+}
+
+/// Who transmits the return and how it is signed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Originator {
+    /// The taxpayer files through online software and signs with a
+    /// self-select PIN.
+    #[default]
+    OnlineFiler,
+    /// An Electronic Return Originator files for the taxpayer, who signs
+    /// with a self-select PIN entered by the ERO.
+    Ero,
+}
+
+impl Originator {
+    fn type_code(self) -> &'static str {
+        match self {
+            Originator::OnlineFiler => "OnlineFiler",
+            Originator::Ero => "ERO",
+        }
+    }
+
+    fn pin_type_code(self) -> &'static str {
+        match self {
+            Originator::OnlineFiler => "Self-Select On-Line",
+            Originator::Ero => "Self-Select Practitioner",
+        }
+    }
+
+    fn jurat_code(self) -> &'static str {
+        match self {
+            Originator::OnlineFiler => "Online Self Select PIN",
+            Originator::Ero => "Self Select Practitioner PIN",
+        }
+    }
+
+    fn pin_entered_by(self) -> &'static str {
+        match self {
+            Originator::OnlineFiler => "Taxpayer",
+            Originator::Ero => "ERO",
+        }
+    }
+}
+
+/// Transmission details for the return header that are not on the form.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReturnHeader {
+    /// When the return was created, as an ISO 8601 timestamp with offset,
+    /// such as `2026-02-14T09:30:00-05:00`.
+    pub timestamp: String,
+    /// Eight-digit ID the IRS assigns to the software package.
+    pub software_id: String,
+    /// Electronic Filing Identification Number of the originator.
+    pub efin: String,
+    pub originator: Originator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EfileError {
+    /// A field MeF requires is empty; names the form field.
+    MissingField(&'static str),
+    /// The document is not well-formed XML.
+    Xml {
+        offset: usize,
+        message: &'static str,
+    },
+    /// A required element is absent from the document.
+    MissingElement(&'static str),
+    InvalidValue {
+        element: String,
+        value: String,
+    },
+    /// The return is for a tax year this crate has no forms for.
+    UnsupportedTaxYear(String),
+    /// No state e-file return is written for this state or form; names
+    /// it, such as `OH` or `CA 540NR`.
+    UnsupportedStateReturn(String),
+}
+
+impl fmt::Display for EfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EfileError::MissingField(field) => write!(f, "e-file requires {field}"),
+            EfileError::Xml { offset, message } => {
+                write!(f, "malformed XML at byte {offset}: {message}")
+            }
+            EfileError::MissingElement(name) => write!(f, "missing <{name}>"),
+            EfileError::InvalidValue { element, value } => {
+                write!(f, "invalid <{element}> value {value:?}")
+            }
+            EfileError::UnsupportedTaxYear(year) => {
+                write!(f, "no forms for tax year {year}")
+            }
+            EfileError::UnsupportedStateReturn(form) => {
+                write!(f, "no state e-file return for {form}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EfileError {}
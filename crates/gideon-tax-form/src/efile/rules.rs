@@ -0,0 +1,330 @@
+//! Pre-submission checks for MeF business rules.
+//!
+//! MeF rejects a return that breaks a business rule and reports the rule's
+//! number, such as `R0000-504-02`. The checks here catch the rules that can
+//! be tested from the return alone, before it is transmitted; rules that
+//! match against IRS and SSA records, such as a name control that does not
+//! match the SSN, can only be caught by transmitting. Each diagnostic's
+//! code is the number of the rule it anticipates.
+//!
+//! The IRS revises and renumbers business rules with each schema release;
+//! compare [`BUSINESS_RULES`] against the rules for the year before relying
+//! on the numbers.
+
+use std::collections::BTreeMap;
+
+use gideon_tax_core::Usd;
+use gideon_tax_core::diagnostics::Diagnostic;
+
+use super::form_1040::{digits, name_control};
+use crate::y2025::federal::{Form1040Person, IrsForm1040, IrsScheduleEic, IrsW2, is_valid_ssn};
+
+/// A business rule MeF enforces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusinessRule {
+    /// The number MeF reports when it rejects a return for this rule.
+    pub code: &'static str,
+    /// What the rule requires, paraphrased.
+    pub text: &'static str,
+}
+
+pub const PRIMARY_SSN: BusinessRule = BusinessRule {
+    code: "R0000-500-01",
+    text: "The primary SSN and name control must match the e-file database.",
+};
+
+pub const SPOUSE_SSN: BusinessRule = BusinessRule {
+    code: "R0000-503-02",
+    text: "The spouse SSN and name control must match the e-file database.",
+};
+
+pub const DEPENDENT_SSN: BusinessRule = BusinessRule {
+    code: "R0000-504-02",
+    text: "Each dependent SSN and name control must match the e-file database.",
+};
+
+pub const DEPENDENT_SSN_UNIQUE: BusinessRule = BusinessRule {
+    code: "IND-510-01",
+    text: "A dependent SSN must not equal the primary SSN, the spouse SSN, or \
+           another dependent SSN on the return.",
+};
+
+pub const W2_WITHHOLDING: BusinessRule = BusinessRule {
+    code: "FW2-502-02",
+    text: "Form W-2 federal income tax withheld must be less than wages.",
+};
+
+pub const EIC_CHILD_SSN: BusinessRule = BusinessRule {
+    code: "SEIC-F1040-501-02",
+    text: "A Schedule EIC qualifying child SSN must not equal the primary or \
+           spouse SSN.",
+};
+
+pub const EIC_CHILD_AGE: BusinessRule = BusinessRule {
+    code: "SEIC-F1040-506-03",
+    text: "A Schedule EIC qualifying child must be under 19, under 24 and a \
+           student, or permanently and totally disabled.",
+};
+
+/// Every rule [`pre_check`] tests.
+pub const BUSINESS_RULES: &[BusinessRule] = &[
+    PRIMARY_SSN,
+    SPOUSE_SSN,
+    DEPENDENT_SSN,
+    DEPENDENT_SSN_UNIQUE,
+    W2_WITHHOLDING,
+    EIC_CHILD_SSN,
+    EIC_CHILD_AGE,
+];
+
+/// Checks a 2025 return, the W-2s attached to it, and its Schedule EIC
+/// against [`BUSINESS_RULES`], with the arguments
+/// [`form_1040_xml`](super::form_1040_xml) takes. Every finding is an
+/// error: MeF would reject the return.
+///
+/// Paths name fields from the arguments: `identity.…` on `form`,
+/// `w2s[i].…`, and `schedule_eic.…`.
+pub fn pre_check(
+    form: &IrsForm1040,
+    w2s: &[IrsW2],
+    schedule_eic: Option<&IrsScheduleEic>,
+) -> Vec<Diagnostic> {
+    let identity = &form.identity;
+    let mut out = Vec::new();
+    check_person(
+        &mut out,
+        PRIMARY_SSN,
+        "identity.taxpayer",
+        &identity.taxpayer,
+    );
+    if let Some(spouse) = &identity.spouse {
+        check_person(&mut out, SPOUSE_SSN, "identity.spouse", spouse);
+    }
+
+    let mut seen: BTreeMap<String, String> = BTreeMap::new();
+    seen.insert(
+        digits(&identity.taxpayer.ssn),
+        "the primary SSN".to_string(),
+    );
+    if let Some(spouse) = &identity.spouse {
+        seen.insert(digits(&spouse.ssn), "the spouse SSN".to_string());
+    }
+    for (i, dependent) in identity.dependents.iter().enumerate() {
+        let path = format!("identity.dependents[{i}]");
+        if !is_valid_ssn(&dependent.ssn) || name_control(&dependent.last_name).is_empty() {
+            out.push(
+                Diagnostic::error(
+                    DEPENDENT_SSN.code,
+                    "the dependent needs a valid SSN and a last name",
+                )
+                .at(path.clone())
+                .with_fix("enter the SSN and last name as shown on the Social Security card"),
+            );
+            continue;
+        }
+        let ssn = digits(&dependent.ssn);
+        match seen.get(&ssn) {
+            Some(other) => out.push(
+                Diagnostic::error(
+                    DEPENDENT_SSN_UNIQUE.code,
+                    format!("the dependent's SSN is also {other}"),
+                )
+                .at(format!("{path}.ssn"))
+                .with_fix("correct the SSN, or remove the duplicate entry"),
+            ),
+            None => {
+                seen.insert(ssn, format!("dependent {}'s SSN", i + 1));
+            }
+        }
+    }
+
+    for (i, w2) in w2s.iter().enumerate() {
+        let withheld = w2.federal_income_tax_withheld;
+        if withheld > Usd::ZERO && withheld >= w2.wages_tips_other_comp {
+            out.push(
+                Diagnostic::error(
+                    W2_WITHHOLDING.code,
+                    format!(
+                        "box 2 ({withheld}) is not less than box 1 ({})",
+                        w2.wages_tips_other_comp
+                    ),
+                )
+                .at(format!("w2s[{i}].federal_income_tax_withheld"))
+                .with_fix("check boxes 1 and 2 against the paper W-2"),
+            );
+        }
+    }
+
+    let filers: Vec<String> = std::iter::once(&identity.taxpayer)
+        .chain(&identity.spouse)
+        .map(|p| digits(&p.ssn))
+        .collect();
+    let children = schedule_eic.map_or(&[][..], |s| &s.children[..]);
+    for (i, child) in children.iter().enumerate() {
+        let path = format!("schedule_eic.children[{i}]");
+        if filers.contains(&digits(&child.ssn)) {
+            out.push(
+                Diagnostic::error(
+                    EIC_CHILD_SSN.code,
+                    "the qualifying child's SSN is the taxpayer's or the spouse's",
+                )
+                .at(format!("{path}.ssn"))
+                .with_fix("enter the child's own SSN from line 2"),
+            );
+        }
+        if !child.meets_age_test(2025) {
+            out.push(
+                Diagnostic::error(
+                    EIC_CHILD_AGE.code,
+                    format!(
+                        "a child born in {} is too old to be a qualifying child",
+                        child.birth_year
+                    ),
+                )
+                .at(format!("{path}.birth_year"))
+                .with_fix(
+                    "check the year of birth and lines 4a and 4b, or remove the child \
+                     from Schedule EIC",
+                ),
+            );
+        }
+    }
+    out
+}
+
+fn check_person(
+    out: &mut Vec<Diagnostic>,
+    rule: BusinessRule,
+    path: &str,
+    person: &Form1040Person,
+) {
+    if !is_valid_ssn(&person.ssn) {
+        out.push(
+            Diagnostic::error(
+                rule.code,
+                format!("{:?} is not a valid SSN or ITIN", person.ssn),
+            )
+            .at(format!("{path}.ssn"))
+            .with_fix("enter the SSN as shown on the Social Security card"),
+        );
+    }
+    if name_control(&person.last_name).is_empty() {
+        out.push(
+            Diagnostic::error(rule.code, "a last name is needed for the name control")
+                .at(format!("{path}.last_name"))
+                .with_fix("enter the last name as shown on the Social Security card"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2025::federal::{EicQualifyingChild, Form1040Dependent, Form1040Identity};
+
+    fn person(last: &str, ssn: &str) -> Form1040Person {
+        Form1040Person {
+            first_name: "Pat".to_string(),
+            last_name: last.to_string(),
+            ssn: ssn.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn dependent(ssn: &str) -> Form1040Dependent {
+        Form1040Dependent {
+            first_name: "Sam".to_string(),
+            last_name: "Doe".to_string(),
+            ssn: ssn.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn codes(diagnostics: &[Diagnostic]) -> Vec<(&str, &str)> {
+        diagnostics
+            .iter()
+            .map(|d| (d.code, d.path.as_deref().unwrap_or("")))
+            .collect()
+    }
+
+    #[test]
+    fn clean_return_passes() {
+        let form = IrsForm1040 {
+            identity: Form1040Identity {
+                taxpayer: person("Doe", "400-12-0001"),
+                dependents: vec![dependent("400-12-0002")],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let w2 = IrsW2 {
+            wages_tips_other_comp: Usd::from_dollars(40_000),
+            federal_income_tax_withheld: Usd::from_dollars(3_000),
+            ..Default::default()
+        };
+        assert_eq!(pre_check(&form, &[w2], None), []);
+    }
+
+    #[test]
+    fn flags_identity_and_w2_rules() {
+        let form = IrsForm1040 {
+            identity: Form1040Identity {
+                taxpayer: person("Doe", "400-12-0001"),
+                spouse: Some(person("", "000-12-0003")),
+                dependents: vec![
+                    dependent("400-12-0002"),
+                    dependent("400120002"),
+                    dependent("400-12-0001"),
+                ],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let w2 = IrsW2 {
+            wages_tips_other_comp: Usd::from_dollars(500),
+            federal_income_tax_withheld: Usd::from_dollars(500),
+            ..Default::default()
+        };
+        assert_eq!(
+            codes(&pre_check(&form, &[IrsW2::default(), w2], None)),
+            [
+                ("R0000-503-02", "identity.spouse.ssn"),
+                ("R0000-503-02", "identity.spouse.last_name"),
+                ("IND-510-01", "identity.dependents[1].ssn"),
+                ("IND-510-01", "identity.dependents[2].ssn"),
+                ("FW2-502-02", "w2s[1].federal_income_tax_withheld"),
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_eic_children() {
+        let form = IrsForm1040 {
+            identity: Form1040Identity {
+                taxpayer: person("Doe", "400-12-0001"),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let child = |ssn: &str, birth_year, student_under_24| EicQualifyingChild {
+            ssn: ssn.to_string(),
+            birth_year,
+            student_under_24,
+            ..Default::default()
+        };
+        let eic = IrsScheduleEic {
+            children: vec![
+                child("400-12-0002", 2007, false),
+                child("400-12-0003", 2002, true),
+                child("400-12-0001", 2006, false),
+            ],
+        };
+        assert_eq!(
+            codes(&pre_check(&form, &[], Some(&eic))),
+            [
+                ("SEIC-F1040-501-02", "schedule_eic.children[2].ssn"),
+                ("SEIC-F1040-506-03", "schedule_eic.children[2].birth_year"),
+            ]
+        );
+    }
+}
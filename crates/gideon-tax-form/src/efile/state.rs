@@ -0,0 +1,246 @@
+use gideon_tax_core::rules::calendar_year;
+use gideon_tax_core::state::{State, StateInput, StateKey, StateLedger};
+use gideon_tax_core::{TaxYear, Usd};
+
+use super::form_1040::{Signatures, filing_status_code, write_filer};
+use super::xml::{XmlWriter, invalid};
+use super::{EfileError, ReturnHeader};
+use crate::y2025::federal::IrsForm1040;
+
+/// The California Form 540 schema release that 2025 returns are written
+/// against. FTB publishes it to registered software developers.
+pub const CA_SCHEMA_VERSION: &str = "2025v1.0";
+
+/// Whether a state return travels with the federal return it starts from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum StateLink {
+    /// A state-only submission, processed without waiting on the federal
+    /// return.
+    #[default]
+    Unlinked,
+    /// Linked to the federal submission with this 20-character ID. The
+    /// state rejects it if the federal submission is rejected.
+    Linked { federal_submission_id: String },
+}
+
+/// Writes the state return for `input`, as computed into `ledger`, as an
+/// MeF state return: the state return header and the state's form.
+///
+/// The identity, address, and self-select PINs come from the federal
+/// `form`, which the state return shares. Amounts are rounded to whole
+/// dollars and zero amounts are left out, as for the federal return.
+///
+/// Only California Form 540, for full-year residents, is written. Other
+/// states, and part-year and nonresident returns, which California takes
+/// on Form 540NR, return [`EfileError::UnsupportedStateReturn`].
+pub fn state_return_xml(
+    header: &ReturnHeader,
+    link: &StateLink,
+    form: &IrsForm1040,
+    input: &StateInput,
+    ledger: &StateLedger,
+) -> Result<String, EfileError> {
+    if input.tax_year != TaxYear::Y2025 {
+        return Err(EfileError::UnsupportedTaxYear(
+            calendar_year(input.tax_year).to_string(),
+        ));
+    }
+    match input.state {
+        State::CA if input.state_source_income.is_none() => ca_540(header, link, form, ledger),
+        State::CA => Err(EfileError::UnsupportedStateReturn("CA 540NR".to_string())),
+        state => Err(EfileError::UnsupportedStateReturn(state.to_string())),
+    }
+}
+
+fn ca_540(
+    header: &ReturnHeader,
+    link: &StateLink,
+    form: &IrsForm1040,
+    ledger: &StateLedger,
+) -> Result<String, EfileError> {
+    let signatures = Signatures::of(form)?;
+    let get = |key: StateKey| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+
+    let mut w = XmlWriter::new();
+    w.start(
+        "ReturnState",
+        &[
+            ("xmlns", "http://www.irs.gov/efile"),
+            ("stateSchemaVersion", CA_SCHEMA_VERSION),
+        ],
+    );
+
+    w.start("ReturnHeaderState", &[]);
+    w.text("Jurisdiction", "CAST");
+    w.text("ReturnTs", &header.timestamp);
+    w.text("TaxYr", "2025");
+    w.text("TaxPeriodBeginDt", "2025-01-01");
+    w.text("TaxPeriodEndDt", "2025-12-31");
+    w.text("SoftwareId", &header.software_id);
+    w.start("OriginatorGrp", &[]);
+    w.text("EFIN", &header.efin);
+    w.text("OriginatorTypeCd", header.originator.type_code());
+    w.end();
+    w.text("PINTypeCd", header.originator.pin_type_code());
+    w.text("JuratDisclosureCd", header.originator.jurat_code());
+    signatures.write(&mut w, header.originator);
+    w.text("ReturnTypeCd", "540");
+    if let StateLink::Linked {
+        federal_submission_id,
+    } = link
+    {
+        if federal_submission_id.len() != 20
+            || !federal_submission_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(invalid("FederalSubmissionId", federal_submission_id));
+        }
+        w.text("FederalSubmissionId", federal_submission_id);
+    }
+    write_filer(&mut w, &form.identity);
+    w.end();
+
+    w.start("ReturnDataState", &[("documentCnt", "1")]);
+    w.start("FormCA540", &[("documentId", "FormCA540")]);
+    w.text(
+        "FilingStatusCd",
+        filing_status_code(form.identity.filing_status),
+    );
+    w.amount("FederalAGIAmt", get(StateKey::FederalAgi));
+    w.amount("CASubtractionsAmt", get(StateKey::Subtractions));
+    w.amount("CAAdditionsAmt", get(StateKey::Additions));
+    w.amount("CAAGIAmt", get(StateKey::StateAgi));
+    w.amount("DeductionAmt", get(StateKey::Deductions));
+    w.amount("TaxableIncomeAmt", get(StateKey::TaxableIncome));
+    w.amount("TaxAmt", get(StateKey::Tax));
+    // Exemption credits (line 32) and special credits (line 47) together.
+    w.amount("TotalCreditsAmt", get(StateKey::NonRefundableCredits));
+    w.amount("MentalHealthServicesTaxAmt", get(StateKey::AdditionalTax));
+    // Line 64 comes before refundable credits, which are payments on Form
+    // 540 rather than reductions of tax.
+    w.amount("TotalTaxAmt", get(StateKey::TaxAfterNonRefundableCredits));
+    w.amount("CAIncomeTaxWithheldAmt", get(StateKey::Withholding));
+    w.amount("EstimatedTaxPaymentsAmt", get(StateKey::EstimatedPayments));
+    w.amount("RefundableCreditsAmt", get(StateKey::RefundableCredits));
+    w.amount(
+        "TotalPaymentsAmt",
+        get(StateKey::TotalPayments) + get(StateKey::RefundableCredits),
+    );
+    w.amount("AmountOwedAmt", get(StateKey::AmountOwed));
+    w.amount("RefundAmt", get(StateKey::Refund));
+    w.end();
+    w.end();
+
+    w.end();
+    Ok(w.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::Date;
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::compute_spine;
+    use gideon_tax_core::state::ca::California2025;
+    use gideon_tax_core::state::compute_state_spine;
+
+    use super::*;
+    use crate::y2025::federal::test_input;
+    use crate::y2025::federal::{Form1040Identity, Form1040Person, Form1040Signature};
+
+    fn state_input(state: State) -> StateInput {
+        StateInput {
+            withholding: Usd::from_dollars(2_000),
+            ..StateInput::new(TaxYear::Y2025, state)
+        }
+    }
+
+    /// A signed federal return for $60,000 of wages and its California
+    /// ledger.
+    fn returns() -> (IrsForm1040, StateLedger) {
+        let mut input = test_input();
+        input.w2_wages = Usd::from_dollars(60_000);
+        let federal = compute_spine(&Rules2025, &input).unwrap();
+        let identity = Form1040Identity {
+            taxpayer: Form1040Person {
+                first_name: "Pat".to_string(),
+                last_name: "Doe".to_string(),
+                ssn: "400-00-0001".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut form = IrsForm1040::from_ledger(identity, &input, &federal);
+        form.signature = Form1040Signature {
+            date: Date::new(2026, 2, 14),
+            self_select_pin: Some("12345".to_string()),
+            ..Default::default()
+        };
+        let ledger =
+            compute_state_spine(&California2025, &input, &federal, &state_input(State::CA))
+                .unwrap();
+        (form, ledger)
+    }
+
+    #[test]
+    fn writes_a_linked_540() {
+        let (form, ledger) = returns();
+        let link = StateLink::Linked {
+            federal_submission_id: "12345620260450000001".to_string(),
+        };
+        let xml = state_return_xml(
+            &ReturnHeader::default(),
+            &link,
+            &form,
+            &state_input(State::CA),
+            &ledger,
+        )
+        .unwrap();
+        assert!(xml.contains("stateSchemaVersion=\"2025v1.0\""));
+        assert!(xml.contains("<FederalSubmissionId>12345620260450000001</FederalSubmissionId>"));
+        assert!(xml.contains("<PrimarySignaturePIN>12345</PrimarySignaturePIN>"));
+        assert!(xml.contains("<PrimarySSN>400000001</PrimarySSN>"));
+        assert!(xml.contains("<FederalAGIAmt>60000</FederalAGIAmt>"));
+        assert!(xml.contains("<TaxableIncomeAmt>54294</TaxableIncomeAmt>"));
+        assert!(xml.contains("<TaxAmt>1793</TaxAmt>"));
+        assert!(xml.contains("<TotalCreditsAmt>153</TotalCreditsAmt>"));
+        assert!(xml.contains("<TotalTaxAmt>1640</TotalTaxAmt>"));
+        assert!(xml.contains("<RefundAmt>360</RefundAmt>"));
+        assert!(!xml.contains("MentalHealthServicesTaxAmt"));
+    }
+
+    #[test]
+    fn unlinked_return_has_no_federal_submission() {
+        let (form, ledger) = returns();
+        let input = state_input(State::CA);
+        let header = ReturnHeader::default();
+        let xml = state_return_xml(&header, &StateLink::Unlinked, &form, &input, &ledger).unwrap();
+        assert!(!xml.contains("FederalSubmissionId"));
+        let link = StateLink::Linked {
+            federal_submission_id: "123456-2026".to_string(),
+        };
+        assert!(matches!(
+            state_return_xml(&header, &link, &form, &input, &ledger),
+            Err(EfileError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn other_states_and_part_year_returns_unsupported() {
+        let (form, ledger) = returns();
+        let header = ReturnHeader::default();
+        let link = StateLink::Unlinked;
+        assert_eq!(
+            state_return_xml(&header, &link, &form, &state_input(State::OH), &ledger),
+            Err(EfileError::UnsupportedStateReturn("OH".to_string()))
+        );
+        let part_year = StateInput {
+            state_source_income: Some(Usd::from_dollars(30_000)),
+            ..state_input(State::CA)
+        };
+        assert_eq!(
+            state_return_xml(&header, &link, &form, &part_year, &ledger),
+            Err(EfileError::UnsupportedStateReturn("CA 540NR".to_string()))
+        );
+    }
+}
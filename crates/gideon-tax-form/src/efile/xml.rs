@@ -0,0 +1,406 @@
+use gideon_tax_core::Usd;
+
+use super::EfileError;
+
+/// Writes indented XML one element at a time.
+pub(crate) struct XmlWriter {
+    out: String,
+    open: Vec<&'static str>,
+}
+
+impl XmlWriter {
+    /// Starts a UTF-8 document.
+    pub(crate) fn new() -> Self {
+        XmlWriter {
+            out: String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"),
+            open: Vec::new(),
+        }
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.open.len() {
+            self.out.push_str("  ");
+        }
+    }
+
+    /// Opens `name` with `attrs`; close it with [`end`](XmlWriter::end).
+    pub(crate) fn start(&mut self, name: &'static str, attrs: &[(&str, &str)]) {
+        self.indent();
+        self.out.push('<');
+        self.out.push_str(name);
+        for (key, value) in attrs {
+            self.out.push(' ');
+            self.out.push_str(key);
+            self.out.push_str("=\"");
+            self.out.push_str(&escape(value));
+            self.out.push('"');
+        }
+        self.out.push_str(">\n");
+        self.open.push(name);
+    }
+
+    /// Closes the most recently opened element.
+    pub(crate) fn end(&mut self) {
+        let name = self.open.pop().expect("no element is open");
+        self.indent();
+        self.out.push_str("</");
+        self.out.push_str(name);
+        self.out.push_str(">\n");
+    }
+
+    /// `<name>text</name>`, skipped when `text` is empty after trimming.
+    pub(crate) fn text(&mut self, name: &str, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        self.indent();
+        self.out.push('<');
+        self.out.push_str(name);
+        self.out.push('>');
+        self.out.push_str(&escape(text));
+        self.out.push_str("</");
+        self.out.push_str(name);
+        self.out.push_str(">\n");
+    }
+
+    pub(crate) fn opt_text(&mut self, name: &str, text: Option<&str>) {
+        if let Some(text) = text {
+            self.text(name, text);
+        }
+    }
+
+    /// A whole-dollar amount, skipped when it rounds to zero.
+    pub(crate) fn amount(&mut self, name: &str, amount: Usd) {
+        let dollars = amount.irs_round().cents() / 100;
+        if dollars != 0 {
+            self.text(name, &dollars.to_string());
+        }
+    }
+
+    /// A checkbox: `X` when checked, skipped otherwise.
+    pub(crate) fn checkbox(&mut self, name: &str, checked: bool) {
+        if checked {
+            self.text(name, "X");
+        }
+    }
+
+    /// Returns the document. Panics if an element is still open.
+    pub(crate) fn finish(self) -> String {
+        assert!(self.open.is_empty(), "unclosed elements: {:?}", self.open);
+        self.out
+    }
+}
+
+/// Escapes the five XML special characters.
+pub(crate) fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// An element read by [`parse`], with namespace prefixes dropped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Element {
+    pub(crate) name: String,
+    pub(crate) attrs: Vec<(String, String)>,
+    pub(crate) children: Vec<Element>,
+    /// Character data directly inside the element, trimmed.
+    pub(crate) text: String,
+}
+
+impl Element {
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    pub(crate) fn children_named<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    /// Text of the first child named `name`, if present and not empty.
+    pub(crate) fn text_of(&self, name: &str) -> Option<&str> {
+        self.child(name)
+            .map(|c| c.text.as_str())
+            .filter(|t| !t.is_empty())
+    }
+
+    /// A whole-dollar amount; zero when the child is absent.
+    pub(crate) fn amount_of(&self, name: &str) -> Result<Usd, EfileError> {
+        match self.text_of(name) {
+            None => Ok(Usd::ZERO),
+            Some(text) => text
+                .parse::<i64>()
+                .map(Usd::from_dollars)
+                .map_err(|_| invalid(name, text)),
+        }
+    }
+
+    /// `true` when the child is present with `X` or `true`.
+    pub(crate) fn checkbox_of(&self, name: &str) -> bool {
+        matches!(self.text_of(name), Some("X" | "true" | "1"))
+    }
+}
+
+pub(crate) fn invalid(element: &str, value: &str) -> EfileError {
+    EfileError::InvalidValue {
+        element: element.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Parses the root element of `xml`.
+///
+/// Handles what MeF documents use: the XML declaration, comments,
+/// attributes, character references, and CDATA. Document type
+/// declarations are rejected.
+pub(crate) fn parse(xml: &str) -> Result<Element, EfileError> {
+    let mut parser = Parser {
+        src: xml.trim_start_matches('\u{feff}'),
+        pos: 0,
+    };
+    parser.skip_misc()?;
+    let root = parser.element()?;
+    parser.skip_misc()?;
+    if parser.pos < parser.src.len() {
+        return Err(parser.error("content after the root element"));
+    }
+    Ok(root)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &'static str) -> EfileError {
+        EfileError::Xml {
+            offset: self.pos,
+            message,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips to just past `end`.
+    fn skip_past(&mut self, end: &str, message: &'static str) -> Result<&'a str, EfileError> {
+        let start = self.pos;
+        let at = self.rest().find(end).ok_or_else(|| self.error(message))?;
+        self.pos += at + end.len();
+        Ok(&self.src[start..start + at])
+    }
+
+    /// Skips whitespace, processing instructions, and comments.
+    fn skip_misc(&mut self) -> Result<(), EfileError> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>", "unterminated processing instruction")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->", "unterminated comment")?;
+            } else if self.rest().starts_with("<!") {
+                return Err(self.error("document type declarations are not supported"));
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, EfileError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '>' | '/' | '='))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    fn element(&mut self) -> Result<Element, EfileError> {
+        if !self.rest().starts_with('<') {
+            return Err(self.error("expected an element"));
+        }
+        self.pos += 1;
+        let qualified = self.name()?.to_string();
+        let mut element = Element {
+            name: local_name(&qualified).to_string(),
+            ..Default::default()
+        };
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+            let key = self.name()?.to_string();
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.error("expected '=' after attribute name"));
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(q @ ('"' | '\'')) => q,
+                _ => return Err(self.error("expected a quoted attribute value")),
+            };
+            self.pos += 1;
+            let raw = self.skip_past(&quote.to_string(), "unterminated attribute value")?;
+            let value = unescape(raw).ok_or_else(|| self.error("invalid character reference"))?;
+            element.attrs.push((key, value));
+        }
+        let mut text = String::new();
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.pos += 2;
+                if self.name()? != qualified {
+                    return Err(self.error("mismatched closing tag"));
+                }
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return Err(self.error("expected '>'"));
+                }
+                self.pos += 1;
+                element.text = text.trim().to_string();
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->", "unterminated comment")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                text.push_str(self.skip_past("]]>", "unterminated CDATA section")?);
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>", "unterminated processing instruction")?;
+            } else if rest.starts_with('<') {
+                element.children.push(self.element()?);
+            } else if rest.is_empty() {
+                return Err(self.error("unclosed element"));
+            } else {
+                let len = rest.find('<').unwrap_or(rest.len());
+                let chunk = unescape(&rest[..len])
+                    .ok_or_else(|| self.error("invalid character reference"))?;
+                text.push_str(&chunk);
+                self.pos += len;
+            }
+        }
+    }
+}
+
+/// Replaces entity and character references, or `None` if one is invalid.
+pub(crate) fn unescape(text: &str) -> Option<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find('&') {
+        out.push_str(&rest[..at]);
+        rest = &rest[at + 1..];
+        let end = rest.find(';')?;
+        let reference = &rest[..end];
+        let c = match reference {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = reference.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()?
+                } else {
+                    reference.strip_prefix('#')?.parse().ok()?
+                };
+                char::from_u32(code)?
+            }
+        };
+        out.push(c);
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_elements_are_indented_and_escaped() {
+        let mut w = XmlWriter::new();
+        w.start("Return", &[("returnVersion", "2025v5.0")]);
+        w.text("NameLine1Txt", "Smith & Sons <Ltd>");
+        w.text("Empty", "  ");
+        w.amount("WagesAmt", Usd::from_cents(6_000_050));
+        w.amount("ZeroAmt", Usd::from_cents(49));
+        w.checkbox("BlindInd", true);
+        w.checkbox("UncheckedInd", false);
+        w.end();
+        assert_eq!(
+            w.finish(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <Return returnVersion=\"2025v5.0\">\n\
+             \x20 <NameLine1Txt>Smith &amp; Sons &lt;Ltd&gt;</NameLine1Txt>\n\
+             \x20 <WagesAmt>60001</WagesAmt>\n\
+             \x20 <BlindInd>X</BlindInd>\n\
+             </Return>\n"
+        );
+    }
+
+    #[test]
+    fn parses_what_it_writes() {
+        let xml = "<?xml version=\"1.0\"?>\n<!-- note -->\n\
+                   <efile:Return xmlns:efile=\"http://www.irs.gov/efile\" returnVersion='2025v5.0'>\
+                   <NameLine1Txt>A &amp; B &#60;C&#x3C;</NameLine1Txt>\
+                   <Empty/><Desc><![CDATA[a < b]]></Desc>\
+                   </efile:Return>";
+        let root = parse(xml).unwrap();
+        assert_eq!(root.name, "Return");
+        assert_eq!(
+            root.attrs[1],
+            ("returnVersion".to_string(), "2025v5.0".to_string())
+        );
+        assert_eq!(root.text_of("NameLine1Txt"), Some("A & B <C<"));
+        assert_eq!(root.text_of("Empty"), None);
+        assert_eq!(root.text_of("Desc"), Some("a < b"));
+    }
+
+    #[test]
+    fn rejects_malformed_documents() {
+        assert!(matches!(
+            parse("<a><b></a>"),
+            Err(EfileError::Xml {
+                message: "mismatched closing tag",
+                ..
+            })
+        ));
+        assert!(parse("<a>&bogus;</a>").is_err());
+        assert!(parse("<!DOCTYPE a><a/>").is_err());
+        assert!(parse("<a/><b/>").is_err());
+    }
+}
@@ -0,0 +1,624 @@
+//! Writing information returns in the fixed-width format of the IRS FIRE
+//! (Filing Information Returns Electronically) system, for payers filing
+//! Forms 1099-NEC, 1099-MISC, and 1099-INT.
+//!
+//! Record layouts follow Publication 1220. Each record is 750 bytes: a
+//! T record for the transmitter, then for each payer and form type an A
+//! record, a B record per payee, a C record of totals, and a K record
+//! per state in the Combined Federal/State Filing Program, and finally
+//! an F record. Send a test file before the first production file of
+//! each year.
+//!
+//! See: <https://www.irs.gov/pub/irs-pdf/p1220.pdf>
+
+use std::fmt;
+
+use gideon_tax_core::rules::calendar_year;
+use gideon_tax_core::{TaxYear, Usd};
+
+use crate::y2025::federal::{Irs1099Int, Irs1099Misc, Irs1099Nec};
+
+const RECORD_LEN: usize = 750;
+
+/// Payment amount codes, in the order of the B record's amount fields.
+const AMOUNT_CODES: [char; 18] = [
+    '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J',
+];
+
+/// The person or business sending the file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Transmitter {
+    pub tin: String,
+    /// Transmitter Control Code, assigned by the IRS.
+    pub tcc: String,
+    pub name: String,
+    pub address: FireAddress,
+    pub contact_name: String,
+    pub contact_phone: String,
+    pub contact_email: String,
+    /// Marks the file as a test file.
+    pub test: bool,
+}
+
+/// A mailing address in the layout FIRE records use.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FireAddress {
+    pub street: String,
+    pub city: String,
+    /// Two-letter postal abbreviation.
+    pub state: String,
+    pub zip: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Payer {
+    pub tin: String,
+    /// From the IRS notice assigning the TIN; may be left empty.
+    pub name_control: String,
+    pub name: String,
+    pub address: FireAddress,
+    pub phone: String,
+    /// The payer will not file information returns again.
+    pub last_filing: bool,
+}
+
+/// One form to a payee.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InformationReturn {
+    Nec(Irs1099Nec),
+    Misc(Irs1099Misc),
+    Int(Irs1099Int),
+}
+
+impl InformationReturn {
+    /// Type of return code in the A record.
+    fn type_code(&self) -> &'static str {
+        match self {
+            InformationReturn::Nec(_) => "NE",
+            InformationReturn::Misc(_) => "A",
+            InformationReturn::Int(_) => "6",
+        }
+    }
+
+    fn recipient(&self) -> (&str, &str, Option<&str>) {
+        let (name, tin, account) = match self {
+            InformationReturn::Nec(f) => (&f.recipient_name, &f.recipient_tin, &f.account_number),
+            InformationReturn::Misc(f) => (&f.recipient_name, &f.recipient_tin, &f.account_number),
+            InformationReturn::Int(f) => (&f.recipient_name, &f.recipient_tin, &f.account_number),
+        };
+        (name, tin, account.as_deref())
+    }
+
+    /// Box amounts by payment amount code.
+    fn amounts(&self) -> Vec<(char, Usd)> {
+        match self {
+            InformationReturn::Nec(f) => vec![
+                ('1', f.nonemployee_compensation),
+                ('4', f.federal_income_tax_withheld),
+            ],
+            InformationReturn::Misc(f) => vec![
+                ('1', f.rents),
+                ('2', f.royalties),
+                ('3', f.other_income),
+                ('4', f.federal_income_tax_withheld),
+                ('5', f.fishing_boat_proceeds),
+                ('6', f.medical_and_health_care_payments),
+                ('8', f.substitute_payments),
+                ('A', f.crop_insurance_proceeds),
+                ('B', f.excess_golden_parachute_payments),
+                ('C', f.gross_proceeds_paid_to_attorney),
+                ('D', f.section_409a_deferrals),
+                ('F', f.fish_purchased_for_resale),
+                ('G', f.nonqualified_deferred_compensation),
+            ],
+            InformationReturn::Int(f) => vec![
+                ('1', f.interest_income),
+                ('2', f.early_withdrawal_penalty),
+                ('3', f.us_savings_bond_and_treasury_interest),
+                ('4', f.federal_income_tax_withheld),
+                ('5', f.investment_expenses),
+                ('6', f.foreign_tax_paid),
+                ('8', f.tax_exempt_interest),
+                ('9', f.private_activity_bond_interest),
+            ],
+        }
+    }
+}
+
+/// A payee's form with what the B record needs beyond it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payee {
+    pub form: InformationReturn,
+    pub address: FireAddress,
+    /// Set when the form is also filed with a state through the
+    /// Combined Federal/State Filing Program.
+    pub state: Option<CombinedFiling>,
+}
+
+/// State amounts for the Combined Federal/State Filing Program.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CombinedFiling {
+    /// The state's code from the program's table in Publication 1220.
+    pub code: u8,
+    pub state_income_tax_withheld: Usd,
+    pub local_income_tax_withheld: Usd,
+}
+
+/// One payer's forms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayerReturns {
+    pub payer: Payer,
+    pub payees: Vec<Payee>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FireError {
+    /// A TIN is not nine digits; `whose` names the transmitter, payer,
+    /// or recipient.
+    InvalidTin { whose: String },
+    /// A TCC is not five letters and digits.
+    InvalidTcc,
+    /// An amount is negative or too large for its field.
+    InvalidAmount { recipient: String, field: String },
+}
+
+impl fmt::Display for FireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FireError::InvalidTin { whose } => write!(f, "{whose}: TIN is not nine digits"),
+            FireError::InvalidTcc => write!(f, "transmitter control code is not five characters"),
+            FireError::InvalidAmount { recipient, field } => {
+                write!(f, "{recipient}: {field} is out of range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FireError {}
+
+/// Writes a FIRE file for `year`, with records separated by CR LF.
+///
+/// Each payer's payees are grouped by form type, one A record per type,
+/// in the order the types first appear. The A record lists the amount
+/// codes with a nonzero amount for any payee in the group. Text longer
+/// than its field is cut off, and characters outside printable ASCII are
+/// written as blanks. Dollar amounts are written in cents.
+pub fn fire_file(
+    year: TaxYear,
+    transmitter: &Transmitter,
+    payers: &[PayerReturns],
+) -> Result<String, FireError> {
+    let year = calendar_year(year).to_string();
+    let mut w = Writer::default();
+
+    let payee_count = payers.iter().map(|p| p.payees.len()).sum::<usize>();
+    let mut t = Record::new('T');
+    t.text(2, 4, &year);
+    t.text(7, 9, &tin(&transmitter.tin, "transmitter")?);
+    let tcc = transmitter.tcc.trim();
+    if tcc.len() != 5 || !tcc.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(FireError::InvalidTcc);
+    }
+    t.text(16, 5, tcc);
+    if transmitter.test {
+        t.text(28, 1, "T");
+    }
+    t.text(30, 40, &transmitter.name);
+    t.text(110, 40, &transmitter.name);
+    t.text(190, 40, &transmitter.address.street);
+    t.text(230, 40, &transmitter.address.city);
+    t.text(270, 2, &transmitter.address.state);
+    t.text(272, 9, &digits(&transmitter.address.zip));
+    t.number(296, 8, payee_count as u64);
+    t.text(304, 40, &transmitter.contact_name);
+    t.text(344, 15, &digits(&transmitter.contact_phone));
+    t.text(359, 50, &transmitter.contact_email);
+    // In-house software rather than a vendor's.
+    t.text(518, 1, "I");
+    w.push(t);
+
+    let mut a_records = 0;
+    for returns in payers {
+        let mut types: Vec<&str> = Vec::new();
+        for payee in &returns.payees {
+            if !types.contains(&payee.form.type_code()) {
+                types.push(payee.form.type_code());
+            }
+        }
+        for type_code in types {
+            let group: Vec<&Payee> = returns
+                .payees
+                .iter()
+                .filter(|p| p.form.type_code() == type_code)
+                .collect();
+            payer_group(&mut w, &year, &returns.payer, type_code, &group)?;
+            a_records += 1;
+        }
+    }
+
+    let mut f = Record::new('F');
+    f.number(2, 8, a_records);
+    f.number(10, 21, 0);
+    f.number(50, 8, payee_count as u64);
+    w.push(f);
+    Ok(w.out)
+}
+
+/// The A, B, C, and K records for one payer and form type.
+fn payer_group(
+    w: &mut Writer,
+    year: &str,
+    payer: &Payer,
+    type_code: &str,
+    payees: &[&Payee],
+) -> Result<(), FireError> {
+    let mut rows = Vec::new();
+    for payee in payees {
+        let (recipient, _, _) = payee.form.recipient();
+        let mut amounts = [0u64; 18];
+        for (code, amount) in payee.form.amounts() {
+            let slot = AMOUNT_CODES.iter().position(|&c| c == code).unwrap();
+            amounts[slot] = cents(amount, recipient, || format!("amount code {code}"))?;
+        }
+        rows.push((payee, amounts));
+    }
+    let used: String = AMOUNT_CODES
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| rows.iter().any(|(_, amounts)| amounts[i] > 0))
+        .map(|(_, &c)| c)
+        .collect();
+
+    let mut a = Record::new('A');
+    a.text(2, 4, year);
+    if payees.iter().any(|p| p.state.is_some()) {
+        a.text(6, 1, "1");
+    }
+    a.text(12, 9, &tin(&payer.tin, &payer.name)?);
+    a.text(21, 4, &payer.name_control.to_uppercase());
+    if payer.last_filing {
+        a.text(25, 1, "1");
+    }
+    a.text(26, 2, type_code);
+    // At least one amount code is required, even with every amount zero.
+    a.text(28, 18, if used.is_empty() { "1" } else { &used });
+    a.text(53, 40, &payer.name);
+    a.text(133, 1, "0");
+    a.text(134, 40, &payer.address.street);
+    a.text(174, 40, &payer.address.city);
+    a.text(214, 2, &payer.address.state);
+    a.text(216, 9, &digits(&payer.address.zip));
+    a.text(225, 15, &digits(&payer.phone));
+    w.push(a);
+
+    let mut totals = [0u64; 18];
+    let mut states: Vec<(u8, u64, [u64; 18], Usd, Usd)> = Vec::new();
+    for (payee, amounts) in &rows {
+        let (name, recipient_tin, account) = payee.form.recipient();
+        let mut b = Record::new('B');
+        b.text(2, 4, year);
+        b.text(11, 1, tin_type(recipient_tin));
+        b.text(12, 9, &tin(recipient_tin, name)?);
+        b.text(21, 20, account.unwrap_or_default());
+        for (i, &amount) in amounts.iter().enumerate() {
+            b.number(55 + 12 * i, 12, amount);
+            totals[i] += amount;
+        }
+        b.text(288, 40, name);
+        b.text(368, 40, &payee.address.street);
+        b.text(448, 40, &payee.address.city);
+        b.text(488, 2, &payee.address.state);
+        b.text(490, 9, &digits(&payee.address.zip));
+        match &payee.form {
+            InformationReturn::Nec(f) if f.direct_sales => b.text(547, 1, "1"),
+            InformationReturn::Misc(f) => {
+                if f.direct_sales {
+                    b.text(547, 1, "1");
+                }
+                if f.fatca_filing_requirement {
+                    b.text(548, 1, "1");
+                }
+            }
+            _ => {}
+        }
+        if let Some(state) = payee.state {
+            let state_tax = cents(state.state_income_tax_withheld, name, || {
+                "state income tax withheld".to_string()
+            })?;
+            let local_tax = cents(state.local_income_tax_withheld, name, || {
+                "local income tax withheld".to_string()
+            })?;
+            b.number(723, 12, state_tax);
+            b.number(735, 12, local_tax);
+            b.number(747, 2, u64::from(state.code));
+            let entry = match states.iter_mut().find(|s| s.0 == state.code) {
+                Some(entry) => entry,
+                None => {
+                    states.push((state.code, 0, [0; 18], Usd::ZERO, Usd::ZERO));
+                    states.last_mut().unwrap()
+                }
+            };
+            entry.1 += 1;
+            for (total, amount) in entry.2.iter_mut().zip(amounts) {
+                *total += amount;
+            }
+            entry.3 += state.state_income_tax_withheld;
+            entry.4 += state.local_income_tax_withheld;
+        }
+        w.push(b);
+    }
+
+    let mut c = Record::new('C');
+    c.number(2, 8, rows.len() as u64);
+    for (i, &total) in totals.iter().enumerate() {
+        c.number(16 + 18 * i, 18, total);
+    }
+    w.push(c);
+
+    for (code, count, totals, state_tax, local_tax) in states {
+        let mut k = Record::new('K');
+        k.number(2, 8, count);
+        for (i, &total) in totals.iter().enumerate() {
+            k.number(16 + 18 * i, 18, total);
+        }
+        // Each payee's state amounts were checked when its B record was
+        // written.
+        k.number(707, 18, state_tax.cents() as u64);
+        k.number(725, 18, local_tax.cents() as u64);
+        k.number(747, 2, u64::from(code));
+        w.push(k);
+    }
+    Ok(())
+}
+
+/// Records written so far, with their sequence numbers.
+#[derive(Default)]
+struct Writer {
+    out: String,
+    count: u64,
+}
+
+impl Writer {
+    fn push(&mut self, mut record: Record) {
+        self.count += 1;
+        record.number(500, 8, self.count);
+        self.out
+            .push_str(std::str::from_utf8(&record.0).expect("records are ASCII"));
+        self.out.push_str("\r\n");
+    }
+}
+
+/// One record, without its line ending.
+struct Record([u8; RECORD_LEN - 2]);
+
+impl Record {
+    fn new(kind: char) -> Self {
+        let mut record = Record([b' '; RECORD_LEN - 2]);
+        record.0[0] = kind as u8;
+        record
+    }
+
+    /// Writes `value` left-justified at 1-based `start`, cut to `len`.
+    fn text(&mut self, start: usize, len: usize, value: &str) {
+        let field = &mut self.0[start - 1..start - 1 + len];
+        for (slot, c) in field.iter_mut().zip(value.trim().chars()) {
+            *slot = if c.is_ascii_graphic() { c as u8 } else { b' ' };
+        }
+    }
+
+    /// Writes `value` right-justified and zero-filled.
+    fn number(&mut self, start: usize, len: usize, value: u64) {
+        let text = format!("{value:0len$}");
+        self.0[start - 1..start - 1 + len].copy_from_slice(&text.as_bytes()[text.len() - len..]);
+    }
+}
+
+fn digits(text: &str) -> String {
+    text.chars().filter(char::is_ascii_digit).collect()
+}
+
+fn tin(text: &str, whose: &str) -> Result<String, FireError> {
+    let tin = digits(text);
+    if tin.len() == 9 {
+        Ok(tin)
+    } else {
+        Err(FireError::InvalidTin {
+            whose: whose.to_string(),
+        })
+    }
+}
+
+/// `1` for an EIN and `2` for an SSN, ITIN, or ATIN, going by where the
+/// hyphens are; blank when the TIN is written without them.
+fn tin_type(text: &str) -> &'static str {
+    let hyphens: Vec<_> = text.trim().match_indices('-').map(|(i, _)| i).collect();
+    match hyphens[..] {
+        [2] => "1",
+        [3, 6] => "2",
+        _ => "",
+    }
+}
+
+/// `amount` in cents if it fits a 12-position B record field.
+fn cents(amount: Usd, recipient: &str, field: impl Fn() -> String) -> Result<u64, FireError> {
+    u64::try_from(amount.cents())
+        .ok()
+        .filter(|&cents| cents < 10u64.pow(12))
+        .ok_or_else(|| FireError::InvalidAmount {
+            recipient: recipient.to_string(),
+            field: field(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address() -> FireAddress {
+        FireAddress {
+            street: "1 Main St".to_string(),
+            city: "Springfield".to_string(),
+            state: "IL".to_string(),
+            zip: "62701-1234".to_string(),
+        }
+    }
+
+    fn transmitter() -> Transmitter {
+        Transmitter {
+            tin: "12-3456789".to_string(),
+            tcc: "ABC12".to_string(),
+            name: "Acme Corp".to_string(),
+            address: address(),
+            contact_name: "Pat Doe".to_string(),
+            contact_phone: "(217) 555-0100".to_string(),
+            contact_email: "pat@example.com".to_string(),
+            test: true,
+        }
+    }
+
+    fn payee(form: InformationReturn, state: Option<CombinedFiling>) -> Payee {
+        Payee {
+            form,
+            address: address(),
+            state,
+        }
+    }
+
+    fn returns() -> PayerReturns {
+        PayerReturns {
+            payer: Payer {
+                tin: "12-3456789".to_string(),
+                name: "Acme Corp".to_string(),
+                address: address(),
+                ..Default::default()
+            },
+            payees: vec![
+                payee(
+                    InformationReturn::Nec(Irs1099Nec {
+                        recipient_tin: "400-00-0001".to_string(),
+                        recipient_name: "Sam Roe".to_string(),
+                        nonemployee_compensation: Usd::from_cents(1_234_567),
+                        federal_income_tax_withheld: Usd::from_dollars(100),
+                        ..Default::default()
+                    }),
+                    Some(CombinedFiling {
+                        code: 6,
+                        state_income_tax_withheld: Usd::from_dollars(50),
+                        ..Default::default()
+                    }),
+                ),
+                payee(
+                    InformationReturn::Int(Irs1099Int {
+                        recipient_tin: "98-7654321".to_string(),
+                        recipient_name: "Widget LLC".to_string(),
+                        interest_income: Usd::from_dollars(20),
+                        tax_exempt_interest: Usd::from_dollars(5),
+                        ..Default::default()
+                    }),
+                    None,
+                ),
+                payee(
+                    InformationReturn::Nec(Irs1099Nec {
+                        recipient_tin: "400-00-0002".to_string(),
+                        recipient_name: "Lee Poe".to_string(),
+                        nonemployee_compensation: Usd::from_dollars(700),
+                        direct_sales: true,
+                        ..Default::default()
+                    }),
+                    None,
+                ),
+            ],
+        }
+    }
+
+    /// Positions `start` through `start + len - 1` of `record`.
+    fn field(record: &str, start: usize, len: usize) -> &str {
+        &record[start - 1..start - 1 + len]
+    }
+
+    #[test]
+    fn writes_fixed_width_records_in_order() {
+        let file = fire_file(TaxYear::Y2025, &transmitter(), &[returns()]).unwrap();
+        let records: Vec<&str> = file.split_terminator("\r\n").collect();
+        assert!(records.iter().all(|r| r.len() == RECORD_LEN - 2));
+        let kinds: String = records.iter().map(|r| &r[..1]).collect();
+        assert_eq!(kinds, "TABBCKABCF");
+        for (i, record) in records.iter().enumerate() {
+            assert_eq!(field(record, 500, 8), format!("{:08}", i + 1));
+        }
+
+        let t = records[0];
+        assert_eq!(field(t, 2, 4), "2025");
+        assert_eq!(field(t, 7, 9), "123456789");
+        assert_eq!(field(t, 28, 1), "T");
+        assert_eq!(field(t, 296, 8), "00000003");
+        assert_eq!(field(t, 344, 15), "2175550100     ");
+
+        let a = records[1];
+        assert_eq!(field(a, 6, 1), "1");
+        assert_eq!(field(a, 26, 2), "NE");
+        assert_eq!(field(a, 28, 18).trim_end(), "14");
+        assert_eq!(field(a, 216, 9), "627011234");
+
+        let b = records[2];
+        assert_eq!(field(b, 11, 10), "2400000001");
+        assert_eq!(field(b, 55, 12), "000001234567");
+        assert_eq!(field(b, 91, 12), "000000010000");
+        assert_eq!(field(b, 288, 40).trim_end(), "Sam Roe");
+        assert_eq!(field(b, 723, 12), "000000005000");
+        assert_eq!(field(b, 747, 2), "06");
+        assert_eq!(field(records[3], 547, 1), "1");
+    }
+
+    #[test]
+    fn totals_payees_by_form_and_state() {
+        let file = fire_file(TaxYear::Y2025, &transmitter(), &[returns()]).unwrap();
+        let records: Vec<&str> = file.split_terminator("\r\n").collect();
+        let c = records[4];
+        assert_eq!(field(c, 2, 8), "00000002");
+        assert_eq!(field(c, 16, 18), "000000000001304567");
+        assert_eq!(field(c, 70, 18), "000000000000010000");
+        let k = records[5];
+        assert_eq!(field(k, 2, 8), "00000001");
+        assert_eq!(field(k, 16, 18), "000000000001234567");
+        assert_eq!(field(k, 707, 18), "000000000000005000");
+        assert_eq!(field(k, 747, 2), "06");
+
+        let a = records[6];
+        assert_eq!(field(a, 6, 1), " ");
+        assert_eq!(field(a, 26, 2), "6 ");
+        assert_eq!(field(a, 28, 18).trim_end(), "18");
+        assert_eq!(field(records[7], 11, 1), "1");
+        let f = records[9];
+        assert_eq!(field(f, 2, 8), "00000002");
+        assert_eq!(field(f, 50, 8), "00000003");
+    }
+
+    #[test]
+    fn rejects_bad_tins_and_amounts() {
+        let mut bad = returns();
+        bad.payees[1].form = InformationReturn::Misc(Irs1099Misc {
+            recipient_tin: "123".to_string(),
+            recipient_name: "Nobody".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(
+            fire_file(TaxYear::Y2025, &transmitter(), &[bad])
+                .unwrap_err()
+                .to_string(),
+            "Nobody: TIN is not nine digits"
+        );
+
+        let mut negative = returns();
+        if let InformationReturn::Nec(f) = &mut negative.payees[0].form {
+            f.nonemployee_compensation = Usd::from_dollars(-1);
+        }
+        assert_eq!(
+            fire_file(TaxYear::Y2025, &transmitter(), &[negative]),
+            Err(FireError::InvalidAmount {
+                recipient: "Sam Roe".to_string(),
+                field: "amount code 1".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,471 @@
+use std::fmt;
+
+use gideon_tax_core::Usd;
+
+use super::{parse_amount, parse_csv, parse_date};
+use crate::y2025::federal::{GainType, Irs1099B};
+
+/// A reader for one broker's 1099-B export.
+pub trait BrokerImport {
+    /// The broker's name, used as the payer on each form.
+    fn broker(&self) -> &str;
+
+    /// Whether `text` looks like this broker's export.
+    fn detect(&self, text: &str) -> bool;
+
+    /// One form per lot sold.
+    fn parse(&self, text: &str) -> Result<Vec<Irs1099B>, BrokerError>;
+}
+
+/// The built-in readers: Schwab, Fidelity, and Vanguard.
+pub fn builtin_brokers() -> Vec<Box<dyn BrokerImport>> {
+    vec![
+        Box::new(CsvLayout::schwab()),
+        Box::new(CsvLayout::fidelity()),
+        Box::new(CsvLayout::vanguard()),
+    ]
+}
+
+/// The first of `brokers` that recognizes `text`.
+pub fn detect_broker<'a>(
+    text: &str,
+    brokers: &'a [Box<dyn BrokerImport>],
+) -> Option<&'a dyn BrokerImport> {
+    brokers.iter().map(|b| &**b).find(|b| b.detect(text))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrokerError {
+    /// A quoted field is never closed; `line` counts from 1.
+    UnterminatedQuote { line: usize },
+    /// No row has the layout's description and proceeds headers.
+    NoHeader,
+    /// `row` counts from 1.
+    InvalidValue {
+        row: usize,
+        column: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for BrokerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrokerError::UnterminatedQuote { line } => {
+                write!(f, "line {line}: unterminated quoted field")
+            }
+            BrokerError::NoHeader => write!(f, "no 1099-B header row found"),
+            BrokerError::InvalidValue { row, column, value } => {
+                write!(f, "row {row}, column {column:?}: invalid value {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BrokerError {}
+
+/// What a column of a 1099-B export holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrokerColumn {
+    /// Box 1a.
+    Description,
+    /// Used for the description when there is no description column or
+    /// its cell is empty.
+    Symbol,
+    /// Box 1b; `Various` leaves it empty.
+    DateAcquired,
+    /// Box 1c.
+    DateSold,
+    /// Box 1d.
+    Proceeds,
+    /// Box 1e.
+    CostBasis,
+    /// Box 1f.
+    MarketDiscount,
+    /// Box 1g.
+    WashSaleLossDisallowed,
+    /// Box 4.
+    Withholding,
+    /// Box 2: short-term, long-term, or ordinary.
+    Term,
+    /// Box 12: covered or noncovered.
+    Covered,
+    /// The Form 8949 box, A through F, which gives both the term and
+    /// whether basis was reported.
+    Form8949Box,
+}
+
+/// A CSV export described by its column headers.
+///
+/// Headers match ignoring case and punctuation, and a header matches
+/// when it starts with one of the column's names, after any leading box
+/// number such as `1d-`. The header row need not be the first row;
+/// consolidated 1099 exports often start with account details.
+///
+/// Rows with a single filled cell are section headings. When a heading
+/// says short-term or long-term, or covered or noncovered, it applies to
+/// the rows below it until the next heading, unless a column says
+/// otherwise. Rows without proceeds and rows whose description starts
+/// with `Total` are skipped. With nothing to say otherwise, a lot is
+/// short-term with basis not reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvLayout {
+    pub broker: String,
+    pub columns: Vec<(BrokerColumn, Vec<String>)>,
+}
+
+impl CsvLayout {
+    pub fn new(broker: &str) -> Self {
+        CsvLayout {
+            broker: broker.to_string(),
+            columns: Vec::new(),
+        }
+    }
+
+    /// Adds headers that name `column`.
+    pub fn column(mut self, column: BrokerColumn, headers: &[&str]) -> Self {
+        self.columns
+            .push((column, headers.iter().map(|h| normalize(h)).collect()));
+        self
+    }
+
+    /// Schwab's realized gain and loss export.
+    pub fn schwab() -> Self {
+        use BrokerColumn::*;
+        CsvLayout::new("Charles Schwab")
+            .column(Description, &["Description of property", "Description"])
+            .column(Symbol, &["Symbol"])
+            .column(DateAcquired, &["Date acquired", "Opened date"])
+            .column(DateSold, &["Date sold or disposed", "Closed date"])
+            .column(Proceeds, &["Proceeds"])
+            .column(CostBasis, &["Cost or other basis", "Cost basis"])
+            .column(MarketDiscount, &["Accrued market discount"])
+            .column(
+                WashSaleLossDisallowed,
+                &["Wash sale loss disallowed", "Disallowed loss"],
+            )
+            .column(Withholding, &["Federal income tax withheld"])
+            .column(Term, &["Term"])
+    }
+
+    /// Fidelity's Form 1099 CSV, whose headers carry box numbers.
+    pub fn fidelity() -> Self {
+        use BrokerColumn::*;
+        CsvLayout::new("Fidelity")
+            .column(Description, &["Description of property"])
+            .column(Symbol, &["Symbol"])
+            .column(DateAcquired, &["Date acquired"])
+            .column(DateSold, &["Date sold or disposed"])
+            .column(Proceeds, &["Proceeds"])
+            .column(CostBasis, &["Cost or other basis"])
+            .column(MarketDiscount, &["Accrued market discount"])
+            .column(WashSaleLossDisallowed, &["Wash sale loss disallowed"])
+            .column(Withholding, &["Federal income tax withheld"])
+            .column(Term, &["Term"])
+            .column(Covered, &["Covered"])
+    }
+
+    /// Vanguard's cost basis export.
+    pub fn vanguard() -> Self {
+        use BrokerColumn::*;
+        CsvLayout::new("Vanguard")
+            .column(Description, &["Security description", "Description"])
+            .column(Symbol, &["Symbol", "Ticker"])
+            .column(DateAcquired, &["Date acquired", "Acquired"])
+            .column(DateSold, &["Date sold", "Sold"])
+            .column(Proceeds, &["Proceeds", "Gross proceeds"])
+            .column(CostBasis, &["Cost basis", "Cost"])
+            .column(
+                WashSaleLossDisallowed,
+                &["Wash sale loss disallowed", "Wash sale"],
+            )
+            .column(Withholding, &["Federal tax withheld"])
+            .column(Term, &["Holding period", "Term"])
+            .column(Covered, &["Covered", "Basis reported"])
+            .column(Form8949Box, &["Form 8949 box"])
+    }
+
+    /// The layout's column for `header`, if any.
+    fn column_for(&self, header: &str) -> Option<BrokerColumn> {
+        let header = normalize(header);
+        // Drop a leading box number, as in `1d- Proceeds` or `Box 1d`.
+        let header = header.strip_prefix("box ").unwrap_or(&header);
+        let header = match header.split_once(' ') {
+            Some((first, rest))
+                if first.starts_with(|c: char| c.is_ascii_digit()) && first.len() <= 3 =>
+            {
+                rest
+            }
+            _ => header,
+        };
+        self.columns.iter().find_map(|(column, names)| {
+            names
+                .iter()
+                .any(|name| {
+                    header == name
+                        || header
+                            .strip_prefix(name.as_str())
+                            .is_some_and(|rest| rest.starts_with(' '))
+                })
+                .then_some(*column)
+        })
+    }
+
+    /// Index and column of each header in the first row that has both a
+    /// description or symbol and proceeds.
+    fn header(&self, rows: &[Vec<String>]) -> Option<(usize, Vec<(usize, BrokerColumn)>)> {
+        rows.iter().enumerate().find_map(|(i, row)| {
+            let columns: Vec<_> = row
+                .iter()
+                .enumerate()
+                .filter_map(|(j, header)| Some((j, self.column_for(header)?)))
+                .collect();
+            let has = |c| columns.iter().any(|&(_, column)| column == c);
+            let named = has(BrokerColumn::Description) || has(BrokerColumn::Symbol);
+            (named && has(BrokerColumn::Proceeds)).then_some((i, columns))
+        })
+    }
+}
+
+impl BrokerImport for CsvLayout {
+    fn broker(&self) -> &str {
+        &self.broker
+    }
+
+    /// The broker's name appears in the text and a header row matches.
+    fn detect(&self, text: &str) -> bool {
+        let name = self.broker.to_lowercase();
+        let first_word = name.split(' ').next_back().unwrap_or(&name);
+        text.to_lowercase().contains(first_word)
+            && parse_csv(text).is_ok_and(|rows| self.header(&rows).is_some())
+    }
+
+    fn parse(&self, text: &str) -> Result<Vec<Irs1099B>, BrokerError> {
+        let rows = parse_csv(text).map_err(|line| BrokerError::UnterminatedQuote { line })?;
+        let (header_row, columns) = self.header(&rows).ok_or(BrokerError::NoHeader)?;
+        let headers = &rows[header_row];
+
+        let mut sales = Vec::new();
+        let mut section = Section::default();
+        for (i, row) in rows.iter().enumerate().skip(header_row + 1) {
+            let filled: Vec<_> = row
+                .iter()
+                .map(|c| c.trim())
+                .filter(|c| !c.is_empty())
+                .collect();
+            if filled.len() == 1 {
+                section.read_heading(filled[0]);
+                continue;
+            }
+            if self.header(std::slice::from_ref(row)).is_some() {
+                continue;
+            }
+            let cell = |column| {
+                columns
+                    .iter()
+                    .find(|&&(_, c)| c == column)
+                    .and_then(|&(j, _)| Some((j, row.get(j)?.trim())))
+                    .filter(|(_, value)| !value.is_empty())
+            };
+            let invalid = |j: usize, value: &str| BrokerError::InvalidValue {
+                row: i + 1,
+                column: headers[j].clone(),
+                value: value.to_string(),
+            };
+            let amount = |column| match cell(column) {
+                None => Ok(Usd::ZERO),
+                Some((j, value)) => parse_amount(value).ok_or_else(|| invalid(j, value)),
+            };
+            let date = |column| match cell(column) {
+                None => Ok(None),
+                Some((_, value)) if value.eq_ignore_ascii_case("various") => Ok(None),
+                Some((j, value)) => parse_date(value).map(Some).ok_or_else(|| invalid(j, value)),
+            };
+
+            let description = cell(BrokerColumn::Description)
+                .or_else(|| cell(BrokerColumn::Symbol))
+                .map_or("", |(_, value)| value);
+            if cell(BrokerColumn::Proceeds).is_none()
+                || description.to_lowercase().starts_with("total")
+            {
+                continue;
+            }
+            let mut gain_type = section.gain_type;
+            let mut covered = section.covered;
+            if let Some((j, value)) = cell(BrokerColumn::Form8949Box) {
+                let (term, reported) = form_8949_box(value).ok_or_else(|| invalid(j, value))?;
+                gain_type = Some(term);
+                covered = Some(reported);
+            }
+            if let Some((j, value)) = cell(BrokerColumn::Term) {
+                gain_type = Some(parse_term(value).ok_or_else(|| invalid(j, value))?);
+            }
+            if let Some((j, value)) = cell(BrokerColumn::Covered) {
+                covered = Some(parse_covered(value).ok_or_else(|| invalid(j, value))?);
+            }
+            sales.push(Irs1099B {
+                payer_name_address: self.broker.clone(),
+                description: description.to_string(),
+                date_acquired: date(BrokerColumn::DateAcquired)?,
+                date_sold: date(BrokerColumn::DateSold)?,
+                proceeds: amount(BrokerColumn::Proceeds)?,
+                cost_basis: amount(BrokerColumn::CostBasis)?,
+                accrued_market_discount: amount(BrokerColumn::MarketDiscount)?,
+                wash_sale_loss_disallowed: amount(BrokerColumn::WashSaleLossDisallowed)?,
+                gain_type: gain_type.unwrap_or_default(),
+                federal_income_tax_withheld: amount(BrokerColumn::Withholding)?,
+                basis_reported_to_irs: covered.unwrap_or(false),
+                ..Default::default()
+            });
+        }
+        Ok(sales)
+    }
+}
+
+/// What the latest section heading said.
+#[derive(Default)]
+struct Section {
+    gain_type: Option<GainType>,
+    covered: Option<bool>,
+}
+
+impl Section {
+    fn read_heading(&mut self, heading: &str) {
+        let heading = normalize(heading);
+        if heading.contains("long term") {
+            self.gain_type = Some(GainType::LongTerm);
+        } else if heading.contains("short term") {
+            self.gain_type = Some(GainType::ShortTerm);
+        }
+        if ["noncovered", "non covered", "uncovered", "not reported"]
+            .iter()
+            .any(|s| heading.contains(s))
+        {
+            self.covered = Some(false);
+        } else if heading.contains("covered") || heading.contains("reported to the irs") {
+            self.covered = Some(true);
+        }
+    }
+}
+
+fn parse_term(value: &str) -> Option<GainType> {
+    let value = normalize(value);
+    if value.starts_with("long") || value == "lt" {
+        Some(GainType::LongTerm)
+    } else if value.starts_with("short") || value == "st" {
+        Some(GainType::ShortTerm)
+    } else if value.starts_with("ordinary") {
+        Some(GainType::Ordinary)
+    } else {
+        None
+    }
+}
+
+fn parse_covered(value: &str) -> Option<bool> {
+    match normalize(value).as_str() {
+        "covered" | "yes" | "y" | "true" => Some(true),
+        "noncovered" | "non covered" | "uncovered" | "no" | "n" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// The term and whether basis was reported for a Form 8949 box.
+fn form_8949_box(value: &str) -> Option<(GainType, bool)> {
+    let value = normalize(value);
+    match value.strip_prefix("box ").unwrap_or(&value) {
+        "a" => Some((GainType::ShortTerm, true)),
+        "b" | "c" => Some((GainType::ShortTerm, false)),
+        "d" => Some((GainType::LongTerm, true)),
+        "e" | "f" => Some((GainType::LongTerm, false)),
+        _ => None,
+    }
+}
+
+/// Lowercase words separated by single spaces.
+fn normalize(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::Date;
+
+    use super::*;
+
+    const FIDELITY: &str = "\
+Fidelity Investments,Form 1099 2025
+Account,X12345678
+
+1a- Description of property,Symbol,1b- Date Acquired,1c- Date Sold or Disposed,1d- Proceeds,1e- Cost or Other Basis,1g- Wash Sale Loss Disallowed,Term,Covered
+Short-term transactions for covered tax lots
+100 SH ACME CORP,ACME,03/01/2025,06/02/2025,\"5,000.00\",\"6,000.00\",400.00,,
+Total Short-term,,,,\"5,000.00\",\"6,000.00\",400.00,,
+Long-term transactions for noncovered tax lots
+50 SH WIDGET,WID,VARIOUS,11/20/2025,2500,1000,,,
+10 SH GADGET,GAD,01/05/2020,11/20/2025,800,500,,Long-term,Covered
+";
+
+    #[test]
+    fn reads_sections_totals_and_wash_sales() {
+        let fidelity = CsvLayout::fidelity();
+        let sales = fidelity.parse(FIDELITY).unwrap();
+        assert_eq!(sales.len(), 3);
+
+        let acme = &sales[0];
+        assert_eq!(acme.payer_name_address, "Fidelity");
+        assert_eq!(acme.description, "100 SH ACME CORP");
+        assert_eq!(acme.date_sold, Date::new(2025, 6, 2));
+        assert_eq!(acme.wash_sale_loss_disallowed, Usd::from_dollars(400));
+        assert_eq!(acme.gain_or_loss(), Usd::from_dollars(-600));
+        assert_eq!(acme.gain_type, GainType::ShortTerm);
+        assert!(acme.basis_reported_to_irs);
+
+        let widget = &sales[1];
+        assert_eq!(widget.date_acquired, None);
+        assert_eq!(widget.gain_type, GainType::LongTerm);
+        assert!(!widget.basis_reported_to_irs);
+        assert!(sales[2].basis_reported_to_irs);
+    }
+
+    #[test]
+    fn detects_the_broker_and_accepts_custom_layouts() {
+        let brokers = builtin_brokers();
+        let broker = detect_broker(FIDELITY, &brokers).unwrap();
+        assert_eq!(broker.broker(), "Fidelity");
+        let vanguard =
+            "Vanguard Brokerage\nSymbol,Proceeds,Cost basis,Form 8949 box\nVTI,100,80,D\n";
+        let broker = detect_broker(vanguard, &brokers).unwrap();
+        let sale = &broker.parse(vanguard).unwrap()[0];
+        assert_eq!(sale.description, "VTI");
+        assert_eq!(sale.gain_type, GainType::LongTerm);
+        assert!(sale.basis_reported_to_irs);
+        assert!(detect_broker("Name,Amount\nx,1\n", &brokers).is_none());
+
+        let custom = CsvLayout::new("Small Broker")
+            .column(BrokerColumn::Description, &["Security"])
+            .column(BrokerColumn::Proceeds, &["Sale amount"])
+            .column(BrokerColumn::Form8949Box, &["Box"]);
+        let sales = custom
+            .parse("Security,Sale Amount,Box\nXYZ,$1,Box B\n")
+            .unwrap();
+        assert_eq!(sales[0].proceeds, Usd::from_dollars(1));
+        assert!(!sales[0].basis_reported_to_irs);
+    }
+
+    #[test]
+    fn reports_missing_headers_and_bad_cells() {
+        let schwab = CsvLayout::schwab();
+        assert_eq!(schwab.parse("a,b\n1,2\n"), Err(BrokerError::NoHeader));
+        let err = schwab
+            .parse("Description,Proceeds,Term\nACME,100,sometime\n")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "row 2, column \"Term\": invalid value \"sometime\""
+        );
+    }
+}
@@ -0,0 +1,518 @@
+use std::fmt;
+
+use gideon_tax_core::Usd;
+use gideon_tax_core::state::State;
+
+use crate::y2025::federal::{Box12Code, Box12Entry, IrsW2, W2LocalTaxGrp, W2StateTaxGrp};
+
+/// Every EFW2 record is this many bytes.
+const RECORD_LEN: usize = 512;
+
+/// Numeric state codes used in RS records, in the order of [`State::ALL`].
+const STATE_CODES: [u8; 51] = [
+    1, 2, 4, 5, 6, 8, 9, 10, 11, 12, 13, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28,
+    29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 44, 45, 46, 47, 48, 49, 50, 51, 53, 54,
+    55, 56,
+];
+
+/// Box 12 amounts in the RW record: code and 1-based start position.
+const RW_BOX_12: [(Box12Code, usize); 14] = [
+    (Box12Code::D, 287),
+    (Box12Code::E, 298),
+    (Box12Code::F, 309),
+    (Box12Code::G, 320),
+    (Box12Code::H, 331),
+    (Box12Code::W, 364),
+    (Box12Code::Q, 386),
+    (Box12Code::C, 408),
+    (Box12Code::V, 419),
+    (Box12Code::Y, 430),
+    (Box12Code::AA, 441),
+    (Box12Code::BB, 452),
+    (Box12Code::DD, 463),
+    (Box12Code::FF, 474),
+];
+
+/// Box 12 amounts in the RO record.
+const RO_BOX_12: [(Box12Code, usize); 9] = [
+    (Box12Code::R, 34),
+    (Box12Code::S, 45),
+    (Box12Code::T, 56),
+    (Box12Code::M, 67),
+    (Box12Code::N, 78),
+    (Box12Code::Z, 89),
+    (Box12Code::EE, 111),
+    (Box12Code::GG, 122),
+    (Box12Code::HH, 133),
+];
+
+/// W-2s read from an EFW2 file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Efw2File {
+    /// From the RA record.
+    pub submitter_ein: String,
+    /// One per RW record, in file order.
+    pub w2s: Vec<IrsW2>,
+    /// Tax year of each W-2, from its RE record.
+    pub tax_years: Vec<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Efw2Error {
+    /// `record` counts from 1.
+    RecordLength {
+        record: usize,
+        len: usize,
+    },
+    UnknownRecord {
+        record: usize,
+        id: String,
+    },
+    /// A record appears where the format does not allow it, such as an
+    /// RW record before any RE record.
+    OutOfOrder {
+        record: usize,
+        id: String,
+    },
+    /// A money field holds something other than digits.
+    InvalidAmount {
+        record: usize,
+        position: usize,
+    },
+    /// An RT record's totals differ from the RW records above it.
+    TotalMismatch {
+        record: usize,
+        field: &'static str,
+    },
+}
+
+impl fmt::Display for Efw2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Efw2Error::RecordLength { record, len } => {
+                write!(f, "record {record}: {len} bytes, expected {RECORD_LEN}")
+            }
+            Efw2Error::UnknownRecord { record, id } => {
+                write!(f, "record {record}: unknown record type {id:?}")
+            }
+            Efw2Error::OutOfOrder { record, id } => {
+                write!(f, "record {record}: {id} record out of order")
+            }
+            Efw2Error::InvalidAmount { record, position } => {
+                write!(f, "record {record}: invalid amount at position {position}")
+            }
+            Efw2Error::TotalMismatch { record, field } => {
+                write!(
+                    f,
+                    "record {record}: RT {field} does not match the RW records"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Efw2Error {}
+
+/// Reads an EFW2 file, the fixed-width format employers use to file
+/// Forms W-2 with the Social Security Administration.
+///
+/// Each RW record becomes one W-2 with its employer from the RE record
+/// before it. An RO record that follows adds box 8 and the box 12 codes
+/// it carries, and each RS record adds a state row. Each RT record's
+/// employee count, box 1 total, and box 2 total are checked against the
+/// RW records since the last RE record. RU, RV, and RF records are
+/// skipped.
+///
+/// Records may be separated by line breaks or run together. RS records
+/// carry no locality name, so local rows are named by the taxing entity
+/// code.
+pub fn parse_efw2(text: &str) -> Result<Efw2File, Efw2Error> {
+    let bytes = text.as_bytes();
+    let records: Vec<&[u8]> = if bytes.contains(&b'\n') {
+        text.lines()
+            .map(str::as_bytes)
+            .filter(|r| !r.is_empty())
+            .collect()
+    } else {
+        bytes.chunks(RECORD_LEN).collect()
+    };
+
+    let mut file = Efw2File::default();
+    let mut employer: Option<(i32, IrsW2)> = None;
+    let mut batch = Batch::default();
+    for (i, record) in records.into_iter().enumerate() {
+        let r = Record {
+            bytes: record,
+            number: i + 1,
+        };
+        if record.len() != RECORD_LEN {
+            return Err(Efw2Error::RecordLength {
+                record: r.number,
+                len: record.len(),
+            });
+        }
+        let id = r.text(1, 2);
+        let out_of_order = || Efw2Error::OutOfOrder {
+            record: r.number,
+            id: id.clone(),
+        };
+        match id.as_str() {
+            "RA" => file.submitter_ein = r.text(3, 11),
+            "RE" => {
+                employer = Some((r.text(3, 6).parse().unwrap_or_default(), employer_w2(&r)));
+                batch = Batch::default();
+            }
+            "RW" => {
+                let (year, employer) = employer.as_ref().ok_or_else(out_of_order)?;
+                let w2 = wage_record(&r, employer.clone())?;
+                batch.count += 1;
+                batch.wages += w2.wages_tips_other_comp;
+                batch.withholding += w2.federal_income_tax_withheld;
+                file.w2s.push(w2);
+                file.tax_years.push(*year);
+                batch.open = true;
+            }
+            "RO" | "RS" => {
+                let w2 = file
+                    .w2s
+                    .last_mut()
+                    .filter(|_| batch.open)
+                    .ok_or_else(out_of_order)?;
+                if id == "RO" {
+                    optional_record(&r, w2)?;
+                } else {
+                    w2.state_tax.push(state_record(&r)?);
+                }
+            }
+            "RT" => {
+                let mismatch = |field| Efw2Error::TotalMismatch {
+                    record: r.number,
+                    field,
+                };
+                if r.text(3, 9).parse::<usize>().ok() != Some(batch.count) {
+                    return Err(mismatch("employee count"));
+                }
+                if r.amount(10, 24)? != batch.wages {
+                    return Err(mismatch("wages"));
+                }
+                if r.amount(25, 39)? != batch.withholding {
+                    return Err(mismatch("federal income tax withheld"));
+                }
+                batch.open = false;
+            }
+            "RU" | "RV" | "RF" => batch.open = false,
+            _ => {
+                return Err(Efw2Error::UnknownRecord {
+                    record: r.number,
+                    id,
+                });
+            }
+        }
+    }
+    Ok(file)
+}
+
+/// RW records since the last RE record.
+#[derive(Default)]
+struct Batch {
+    count: usize,
+    wages: Usd,
+    withholding: Usd,
+    /// Whether RO and RS records may follow.
+    open: bool,
+}
+
+struct Record<'a> {
+    bytes: &'a [u8],
+    number: usize,
+}
+
+impl Record<'_> {
+    /// Positions `start` through `end`, counting from 1, trimmed.
+    fn text(&self, start: usize, end: usize) -> String {
+        String::from_utf8_lossy(&self.bytes[start - 1..end])
+            .trim()
+            .to_string()
+    }
+
+    /// Whole cents, right-justified and zero-filled; blank is zero.
+    fn amount(&self, start: usize, end: usize) -> Result<Usd, Efw2Error> {
+        let field = self.text(start, end);
+        if field.is_empty() {
+            return Ok(Usd::ZERO);
+        }
+        field
+            .parse::<u64>()
+            .ok()
+            .and_then(|cents| i64::try_from(cents).ok())
+            .map(Usd::from_cents)
+            .ok_or(Efw2Error::InvalidAmount {
+                record: self.number,
+                position: start,
+            })
+    }
+
+    /// An 11-position amount starting at `start`.
+    fn amount_at(&self, start: usize) -> Result<Usd, Efw2Error> {
+        self.amount(start, start + 10)
+    }
+
+    fn checked(&self, position: usize) -> bool {
+        self.bytes[position - 1] == b'1'
+    }
+
+    /// Address lines from location and delivery fields at `start` and a
+    /// city, state, and ZIP line after them.
+    fn address(&self, start: usize) -> Vec<String> {
+        let zip = [
+            self.text(start + 68, start + 72),
+            self.text(start + 73, start + 76),
+        ]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+        let city_line = join_words(&[
+            self.text(start + 44, start + 65),
+            self.text(start + 66, start + 67),
+            zip,
+        ]);
+        [
+            self.text(start, start + 21),
+            self.text(start + 22, start + 43),
+            city_line,
+        ]
+        .into_iter()
+        .filter(|line| !line.is_empty())
+        .collect()
+    }
+}
+
+fn join_words(words: &[String]) -> String {
+    words
+        .iter()
+        .filter(|w| !w.is_empty())
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The employer fields of every W-2 under an RE record.
+fn employer_w2(r: &Record) -> IrsW2 {
+    let mut lines = vec![r.text(40, 96)];
+    lines.extend(r.address(97));
+    IrsW2 {
+        employer_ein: r.text(8, 16),
+        employer_name_address: lines.join("\n"),
+        third_party_sick_pay: r.checked(221),
+        ..Default::default()
+    }
+}
+
+fn wage_record(r: &Record, employer: IrsW2) -> Result<IrsW2, Efw2Error> {
+    let mut box_12 = Vec::new();
+    for (code, start) in RW_BOX_12 {
+        push_box_12(&mut box_12, code, r.amount_at(start)?);
+    }
+    Ok(IrsW2 {
+        employee_ssn: r.text(3, 11),
+        employee_name: join_words(&[
+            r.text(12, 26),
+            r.text(27, 41),
+            r.text(42, 61),
+            r.text(62, 65),
+        ]),
+        employee_address: r.address(66).join("\n"),
+        wages_tips_other_comp: r.amount_at(188)?,
+        federal_income_tax_withheld: r.amount_at(199)?,
+        social_security_wages: r.amount_at(210)?,
+        social_security_tax_withheld: r.amount_at(221)?,
+        medicare_wages_and_tips: r.amount_at(232)?,
+        medicare_tax_withheld: r.amount_at(243)?,
+        social_security_tips: r.amount_at(254)?,
+        dependent_care_benefits: r.amount_at(276)?,
+        // Section 457 and other nonqualified plans.
+        nonqualified_plans: r.amount_at(353)? + r.amount_at(375)?,
+        box_12,
+        statutory_employee: r.checked(486),
+        retirement_plan: r.checked(488),
+        third_party_sick_pay: employer.third_party_sick_pay || r.checked(489),
+        ..employer
+    })
+}
+
+fn optional_record(r: &Record, w2: &mut IrsW2) -> Result<(), Efw2Error> {
+    w2.allocated_tips = r.amount_at(12)?;
+    for (code, start) in RO_BOX_12 {
+        push_box_12(&mut w2.box_12, code, r.amount_at(start)?);
+    }
+    Ok(())
+}
+
+fn state_record(r: &Record) -> Result<W2StateTaxGrp, Efw2Error> {
+    let code = r.text(274, 275);
+    let state = code
+        .parse::<u8>()
+        .ok()
+        .and_then(|n| STATE_CODES.iter().position(|&c| c == n))
+        .map_or(code, |i| State::ALL[i].code().to_string());
+    let local = W2LocalTaxGrp {
+        local_wages: r.amount_at(309)?,
+        local_income_tax: r.amount_at(320)?,
+        locality_name: r.text(5, 9),
+    };
+    let employer_state_id = r.text(248, 267);
+    Ok(W2StateTaxGrp {
+        state,
+        employer_state_id: (!employer_state_id.is_empty()).then_some(employer_state_id),
+        state_wages: r.amount_at(276)?,
+        state_income_tax: r.amount_at(287)?,
+        local_tax: if local.local_wages == Usd::ZERO && local.local_income_tax == Usd::ZERO {
+            Vec::new()
+        } else {
+            vec![local]
+        },
+    })
+}
+
+fn push_box_12(box_12: &mut Vec<Box12Entry>, code: Box12Code, amount: Usd) {
+    if amount != Usd::ZERO {
+        box_12.push(Box12Entry { code, amount });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A blank record with `id` and the given fields, each a 1-based
+    /// start position and its text.
+    fn record(id: &str, fields: &[(usize, &str)]) -> String {
+        let mut bytes = vec![b' '; RECORD_LEN];
+        bytes[..2].copy_from_slice(id.as_bytes());
+        for &(start, text) in fields {
+            bytes[start - 1..start - 1 + text.len()].copy_from_slice(text.as_bytes());
+        }
+        String::from_utf8(bytes).unwrap()
+    }
+
+    fn cents(amount: i64) -> String {
+        format!("{amount:011}")
+    }
+
+    fn file(rt_wages: i64) -> String {
+        let wages = cents(5_200_000);
+        let tax = cents(610_000);
+        let deferral = cents(150_000);
+        let state_wages = cents(5_200_000);
+        let state_tax = cents(240_000);
+        let tips = cents(12_345);
+        let total_wages = format!("{rt_wages:015}");
+        let total_tax = format!("{:015}", 610_000);
+        [
+            record("RA", &[(3, "123456789")]),
+            record(
+                "RE",
+                &[
+                    (3, "2025"),
+                    (8, "987654321"),
+                    (40, "ACME CORP"),
+                    (97, "1 MAIN ST"),
+                    (141, "SPRINGFIELD"),
+                    (163, "IL"),
+                    (165, "62701"),
+                ],
+            ),
+            record(
+                "RW",
+                &[
+                    (3, "400000001"),
+                    (12, "PAT"),
+                    (27, "Q"),
+                    (42, "DOE"),
+                    (66, "2 ELM ST"),
+                    (110, "SPRINGFIELD"),
+                    (132, "IL"),
+                    (134, "62702"),
+                    (139, "1234"),
+                    (188, &wages),
+                    (199, &tax),
+                    (287, &deferral),
+                    (488, "1"),
+                ],
+            ),
+            record("RO", &[(12, &tips)]),
+            record(
+                "RS",
+                &[
+                    (5, "00123"),
+                    (248, "IL-555"),
+                    (274, "17"),
+                    (276, &state_wages),
+                    (287, &state_tax),
+                ],
+            ),
+            record(
+                "RT",
+                &[(3, "0000001"), (10, &total_wages), (25, &total_tax)],
+            ),
+            record("RF", &[]),
+        ]
+        .join("\r\n")
+    }
+
+    #[test]
+    fn reads_employer_employee_and_state_records() {
+        let file = parse_efw2(&file(5_200_000)).unwrap();
+        assert_eq!(file.submitter_ein, "123456789");
+        assert_eq!(file.tax_years, [2025]);
+        let w2 = &file.w2s[0];
+        assert_eq!(w2.employer_ein, "987654321");
+        assert_eq!(
+            w2.employer_name_address,
+            "ACME CORP\n1 MAIN ST\nSPRINGFIELD IL 62701"
+        );
+        assert_eq!(w2.employee_name, "PAT Q DOE");
+        assert_eq!(w2.employee_address, "2 ELM ST\nSPRINGFIELD IL 62702-1234");
+        assert_eq!(w2.wages_tips_other_comp, Usd::from_dollars(52_000));
+        assert_eq!(w2.allocated_tips, Usd::from_cents(12_345));
+        assert_eq!(
+            w2.box_12,
+            [Box12Entry {
+                code: Box12Code::D,
+                amount: Usd::from_dollars(1_500),
+            }]
+        );
+        assert!(w2.retirement_plan);
+        let state = &w2.state_tax[0];
+        assert_eq!(state.state, "IL");
+        assert_eq!(state.employer_state_id.as_deref(), Some("IL-555"));
+        assert_eq!(state.state_income_tax, Usd::from_dollars(2_400));
+        assert!(state.local_tax.is_empty());
+    }
+
+    #[test]
+    fn records_may_run_together() {
+        let joined = file(5_200_000).replace("\r\n", "");
+        assert_eq!(parse_efw2(&joined), parse_efw2(&file(5_200_000)));
+    }
+
+    #[test]
+    fn checks_totals_order_and_lengths() {
+        assert_eq!(
+            parse_efw2(&file(5_200_001)),
+            Err(Efw2Error::TotalMismatch {
+                record: 6,
+                field: "wages"
+            })
+        );
+        assert!(matches!(
+            parse_efw2(&record("RW", &[])),
+            Err(Efw2Error::OutOfOrder { record: 1, .. })
+        ));
+        assert_eq!(
+            parse_efw2("RA123\n").unwrap_err().to_string(),
+            "record 1: 5 bytes, expected 512"
+        );
+    }
+}
@@ -0,0 +1,574 @@
+use std::fmt;
+
+use gideon_tax_core::{Date, Usd};
+use serde_json::{Map, Value};
+
+use super::parse_amount;
+use crate::y2025::federal::{
+    Box12Code, Box12Entry, GainType, Irs1099B, Irs1099Div, Irs1099G, Irs1099Int, Irs1099Misc,
+    Irs1099Nec, Irs1099R, IrsW2, W2LocalTaxGrp, W2StateTaxGrp,
+};
+
+/// Forms read from FDX tax data.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FdxImport {
+    pub w2s: Vec<IrsW2>,
+    pub interest: Vec<Irs1099Int>,
+    pub dividends: Vec<Irs1099Div>,
+    /// One form per security sold.
+    pub sales: Vec<Irs1099B>,
+    pub nonemployee_compensation: Vec<Irs1099Nec>,
+    pub miscellaneous: Vec<Irs1099Misc>,
+    pub government_payments: Vec<Irs1099G>,
+    pub retirement: Vec<Irs1099R>,
+    /// FDX names of forms this crate does not read, such as `tax1098`,
+    /// one per form.
+    pub unsupported: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum FdxError {
+    Json(serde_json::Error),
+    /// `field` is the FDX property name.
+    InvalidValue {
+        field: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for FdxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FdxError::Json(e) => write!(f, "invalid FDX JSON: {e}"),
+            FdxError::InvalidValue { field, value } => {
+                write!(f, "invalid {field} value {value}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FdxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FdxError::Json(e) => Some(e),
+            FdxError::InvalidValue { .. } => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for FdxError {
+    fn from(e: serde_json::Error) -> Self {
+        FdxError::Json(e)
+    }
+}
+
+/// Reads the tax forms in an FDX (Financial Data Exchange) JSON payload:
+/// a tax statement, a list of them, or a single `TaxData` entry.
+///
+/// Forms are found wherever a `taxW2` or `tax1099...` property holds an
+/// object, so the surrounding envelope may vary. Amounts may be numbers
+/// or strings and are read to the cent. Properties this crate has no
+/// field for are ignored. Each entry in a 1099-B's `securityDetails`
+/// becomes its own [`Irs1099B`].
+pub fn parse_fdx(json: &str) -> Result<FdxImport, FdxError> {
+    let value: Value = serde_json::from_str(json)?;
+    let mut import = FdxImport::default();
+    collect(&value, &mut import)?;
+    Ok(import)
+}
+
+fn collect(value: &Value, import: &mut FdxImport) -> Result<(), FdxError> {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                collect(item, import)?;
+            }
+        }
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                let Value::Object(form) = value else {
+                    collect(value, import)?;
+                    continue;
+                };
+                let form = Obj(form);
+                match key.as_str() {
+                    "taxW2" => import.w2s.push(w2(form)?),
+                    "tax1099Int" => import.interest.push(interest(form)?),
+                    "tax1099Div" => import.dividends.push(dividends(form)?),
+                    "tax1099B" => sales(form, &mut import.sales)?,
+                    "tax1099Nec" => import.nonemployee_compensation.push(nonemployee(form)?),
+                    "tax1099Misc" => import.miscellaneous.push(miscellaneous(form)?),
+                    "tax1099G" => import.government_payments.push(government(form)?),
+                    "tax1099R" => import.retirement.push(retirement(form)?),
+                    _ if is_form_key(key) => import.unsupported.push(key.clone()),
+                    _ => collect(value, import)?,
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// `tax` followed by a form number, as in `tax1098` or `taxW2G`.
+fn is_form_key(key: &str) -> bool {
+    key.strip_prefix("tax")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_digit() || c == 'W')
+}
+
+/// The parts of a form every 1099 shares.
+struct Parties {
+    payer_name_address: String,
+    payer_tin: String,
+    recipient_tin: String,
+    recipient_name: String,
+    account_number: Option<String>,
+}
+
+fn parties(form: Obj) -> Parties {
+    let recipient = form.obj("recipientNameAddress");
+    Parties {
+        payer_name_address: form.obj("payerNameAddress").map_or_else(String::new, |p| {
+            let mut lines = p.name_lines();
+            lines.extend(p.address_lines());
+            lines.join("\n")
+        }),
+        payer_tin: form.text("payerTin"),
+        recipient_tin: form.text("recipientTin"),
+        recipient_name: recipient.map_or_else(String::new, |r| r.name_lines().join(" ")),
+        account_number: Some(form.text("accountNumber")).filter(|a| !a.is_empty()),
+    }
+}
+
+fn w2(form: Obj) -> Result<IrsW2, FdxError> {
+    let employer = form.obj("employerNameAddress");
+    let employee = form.obj("employeeNameAddress");
+    let mut w2 = IrsW2 {
+        employee_ssn: form.text("employeeTin"),
+        employer_ein: form.text("employerTin"),
+        employer_name_address: employer.map_or_else(String::new, |e| {
+            let mut lines = e.name_lines();
+            lines.extend(e.address_lines());
+            lines.join("\n")
+        }),
+        control_number: Some(form.text("controlNumber")).filter(|c| !c.is_empty()),
+        employee_name: employee.map_or_else(String::new, |e| e.name_lines().join(" ")),
+        employee_address: employee.map_or_else(String::new, |e| e.address_lines().join("\n")),
+        wages_tips_other_comp: form.amount("wages")?,
+        federal_income_tax_withheld: form.amount("federalTaxWithheld")?,
+        social_security_wages: form.amount("socialSecurityWages")?,
+        social_security_tax_withheld: form.amount("socialSecurityTaxWithheld")?,
+        medicare_wages_and_tips: form.amount("medicareWages")?,
+        medicare_tax_withheld: form.amount("medicareTaxWithheld")?,
+        social_security_tips: form.amount("socialSecurityTips")?,
+        allocated_tips: form.amount("allocatedTips")?,
+        dependent_care_benefits: form.amount("dependentCareBenefit")?,
+        nonqualified_plans: form.amount("nonQualifiedPlan")?,
+        statutory_employee: form.flag("statutory"),
+        retirement_plan: form.flag("retirementPlan"),
+        third_party_sick_pay: form.flag("thirdPartySickPay"),
+        ..Default::default()
+    };
+    for entry in form.objects("codes") {
+        let code = entry.text("code");
+        w2.box_12.push(Box12Entry {
+            code: Box12Code::from_code(&code).ok_or_else(|| invalid("codes.code", &code))?,
+            amount: entry.amount("amount")?,
+        });
+    }
+    let other: Vec<String> = form
+        .objects("other")
+        .map(|entry| {
+            Ok(format!(
+                "{} {}",
+                entry.text("description"),
+                entry.amount("amount")?
+            ))
+        })
+        .collect::<Result<_, FdxError>>()?;
+    if !other.is_empty() {
+        w2.box_14a_other = Some(other.join("; "));
+    }
+    for row in form.objects("stateAndLocal") {
+        let local = W2LocalTaxGrp {
+            local_wages: row.amount("localWages")?,
+            local_income_tax: row.amount("localTaxWithheld")?,
+            locality_name: row.text("localityName"),
+        };
+        w2.state_tax.push(W2StateTaxGrp {
+            state: row.text("state"),
+            employer_state_id: Some(row.text("employerStateId")).filter(|s| !s.is_empty()),
+            state_wages: row.amount("stateWages")?,
+            state_income_tax: row.amount("stateTaxWithheld")?,
+            local_tax: if local == W2LocalTaxGrp::default() {
+                Vec::new()
+            } else {
+                vec![local]
+            },
+        });
+    }
+    Ok(w2)
+}
+
+fn interest(form: Obj) -> Result<Irs1099Int, FdxError> {
+    let p = parties(form);
+    Ok(Irs1099Int {
+        payer_name_address: p.payer_name_address,
+        payer_tin: p.payer_tin,
+        recipient_tin: p.recipient_tin,
+        recipient_name: p.recipient_name,
+        account_number: p.account_number,
+        interest_income: form.amount("interestIncome")?,
+        early_withdrawal_penalty: form.amount("earlyWithdrawalPenalty")?,
+        us_savings_bond_and_treasury_interest: form.amount("usBondInterest")?,
+        federal_income_tax_withheld: form.amount("federalTaxWithheld")?,
+        investment_expenses: form.amount("investmentExpenses")?,
+        foreign_tax_paid: form.amount("foreignTaxPaid")?,
+        tax_exempt_interest: form.amount("taxExemptInterest")?,
+        private_activity_bond_interest: form.amount("specifiedPabInterest")?,
+    })
+}
+
+fn dividends(form: Obj) -> Result<Irs1099Div, FdxError> {
+    let p = parties(form);
+    Ok(Irs1099Div {
+        payer_name_address: p.payer_name_address,
+        payer_tin: p.payer_tin,
+        recipient_tin: p.recipient_tin,
+        recipient_name: p.recipient_name,
+        account_number: p.account_number,
+        total_ordinary_dividends: form.amount("ordinaryDividends")?,
+        qualified_dividends: form.amount("qualifiedDividends")?,
+        total_capital_gain_distributions: form.amount("totalCapitalGain")?,
+        unrecaptured_section_1250_gain: form.amount("unrecaptured1250Gain")?,
+        section_1202_gain: form.amount("section1202Gain")?,
+        collectibles_gain: form.amount("collectiblesGain")?,
+        nondividend_distributions: form.amount("nonTaxableDistribution")?,
+        federal_income_tax_withheld: form.amount("federalTaxWithheld")?,
+        section_199a_dividends: form.amount("section199ADividends")?,
+        investment_expenses: form.amount("investmentExpenses")?,
+        foreign_tax_paid: form.amount("foreignTaxPaid")?,
+        exempt_interest_dividends: form.amount("exemptInterestDividends")?,
+    })
+}
+
+fn sales(form: Obj, out: &mut Vec<Irs1099B>) -> Result<(), FdxError> {
+    for detail in form.objects("securityDetails") {
+        let p = parties(form);
+        let gain_type = if detail.flag("ordinary") {
+            GainType::Ordinary
+        } else if detail.text("longOrShort").eq_ignore_ascii_case("long") {
+            GainType::LongTerm
+        } else {
+            GainType::ShortTerm
+        };
+        let basis_reported_to_irs = match detail.0.get("basisReported") {
+            Some(Value::Bool(reported)) => *reported,
+            _ => {
+                !detail.flag("nonCoveredSecurity")
+                    && matches!(detail.text("checkboxOnForm8949").as_str(), "A" | "D" | "")
+            }
+        };
+        let description = match detail.text("saleDescription") {
+            d if d.is_empty() => detail.text("securityName"),
+            d => d,
+        };
+        out.push(Irs1099B {
+            payer_name_address: p.payer_name_address,
+            payer_tin: p.payer_tin,
+            recipient_tin: p.recipient_tin,
+            recipient_name: p.recipient_name,
+            account_number: p.account_number,
+            description,
+            date_acquired: if detail.flag("variousDatesAcquired") {
+                None
+            } else {
+                detail.date("dateAcquired")?
+            },
+            date_sold: detail.date("dateOfSale")?,
+            proceeds: detail.amount("salesPrice")?,
+            cost_basis: detail.amount("costBasis")?,
+            accrued_market_discount: detail.amount("accruedMarketDiscount")?,
+            wash_sale_loss_disallowed: detail.amount("washSaleLossDisallowed")?,
+            gain_type,
+            federal_income_tax_withheld: detail.amount("federalTaxWithheld")?,
+            basis_reported_to_irs,
+        });
+    }
+    Ok(())
+}
+
+fn nonemployee(form: Obj) -> Result<Irs1099Nec, FdxError> {
+    let p = parties(form);
+    Ok(Irs1099Nec {
+        payer_name_address: p.payer_name_address,
+        payer_tin: p.payer_tin,
+        recipient_tin: p.recipient_tin,
+        recipient_name: p.recipient_name,
+        account_number: p.account_number,
+        nonemployee_compensation: form.amount("nonEmployeeCompensation")?,
+        direct_sales: form.flag("payerDirectSales"),
+        federal_income_tax_withheld: form.amount("federalTaxWithheld")?,
+    })
+}
+
+fn miscellaneous(form: Obj) -> Result<Irs1099Misc, FdxError> {
+    let p = parties(form);
+    Ok(Irs1099Misc {
+        payer_name_address: p.payer_name_address,
+        payer_tin: p.payer_tin,
+        recipient_tin: p.recipient_tin,
+        recipient_name: p.recipient_name,
+        account_number: p.account_number,
+        rents: form.amount("rents")?,
+        royalties: form.amount("royalties")?,
+        other_income: form.amount("otherIncome")?,
+        federal_income_tax_withheld: form.amount("federalTaxWithheld")?,
+        fishing_boat_proceeds: form.amount("fishingBoatProceeds")?,
+        medical_and_health_care_payments: form.amount("medicalHealthPayment")?,
+        direct_sales: form.flag("payerDirectSales"),
+        substitute_payments: form.amount("substitutePayments")?,
+        crop_insurance_proceeds: form.amount("cropInsurance")?,
+        gross_proceeds_paid_to_attorney: form.amount("grossProceedsAttorney")?,
+        fish_purchased_for_resale: form.amount("fishPurchased")?,
+        section_409a_deferrals: form.amount("section409ADeferrals")?,
+        fatca_filing_requirement: form.flag("foreignAccountTaxCompliance"),
+        excess_golden_parachute_payments: form.amount("excessGolden")?,
+        nonqualified_deferred_compensation: form.amount("nonQualifiedDeferredCompensation")?,
+    })
+}
+
+fn government(form: Obj) -> Result<Irs1099G, FdxError> {
+    let p = parties(form);
+    Ok(Irs1099G {
+        payer_name_address: p.payer_name_address,
+        payer_tin: p.payer_tin,
+        recipient_tin: p.recipient_tin,
+        recipient_name: p.recipient_name,
+        account_number: p.account_number,
+        unemployment_compensation: form.amount("unemploymentCompensation")?,
+        state_or_local_income_tax_refunds: form.amount("taxRefund")?,
+        refund_tax_year: form
+            .0
+            .get("refundYear")
+            .and_then(Value::as_i64)
+            .map(|y| y as i32),
+        federal_income_tax_withheld: form.amount("federalTaxWithheld")?,
+        rtaa_payments: form.amount("rtaaPayments")?,
+        taxable_grants: form.amount("taxableGrants")?,
+        agriculture_payments: form.amount("agriculturePayments")?,
+    })
+}
+
+fn retirement(form: Obj) -> Result<Irs1099R, FdxError> {
+    let p = parties(form);
+    let codes = match form.0.get("distributionCodes") {
+        Some(Value::Array(codes)) => codes.iter().filter_map(Value::as_str).collect(),
+        _ => form.text("distributionCodes"),
+    };
+    Ok(Irs1099R {
+        payer_name_address: p.payer_name_address,
+        payer_tin: p.payer_tin,
+        recipient_tin: p.recipient_tin,
+        recipient_name: p.recipient_name,
+        account_number: p.account_number,
+        gross_distribution: form.amount("grossDistribution")?,
+        taxable_amount: form.amount("taxableAmount")?,
+        taxable_amount_not_determined: form.flag("taxableAmountNotDetermined"),
+        total_distribution: form.flag("totalDistribution"),
+        capital_gain: form.amount("capitalGain")?,
+        federal_income_tax_withheld: form.amount("federalTaxWithheld")?,
+        employee_contributions: form.amount("employeeContributions")?,
+        distribution_codes: codes,
+        ira_sep_simple: form.flag("iraSepSimple"),
+    })
+}
+
+fn invalid(field: &str, value: &str) -> FdxError {
+    FdxError::InvalidValue {
+        field: field.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// An FDX object, read leniently.
+#[derive(Clone, Copy)]
+struct Obj<'a>(&'a Map<String, Value>);
+
+impl<'a> Obj<'a> {
+    fn obj(self, key: &str) -> Option<Obj<'a>> {
+        self.0.get(key).and_then(Value::as_object).map(Obj)
+    }
+
+    fn objects(self, key: &str) -> impl Iterator<Item = Obj<'a>> {
+        self.0
+            .get(key)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_object)
+            .map(Obj)
+    }
+
+    fn text(self, key: &str) -> String {
+        match self.0.get(key) {
+            Some(Value::String(text)) => text.trim().to_string(),
+            Some(Value::Number(n)) => n.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    fn flag(self, key: &str) -> bool {
+        matches!(self.0.get(key), Some(Value::Bool(true)))
+    }
+
+    /// Missing and null amounts are zero.
+    fn amount(self, key: &str) -> Result<Usd, FdxError> {
+        match self.0.get(key) {
+            None | Some(Value::Null) => Ok(Usd::ZERO),
+            Some(value @ (Value::Number(_) | Value::String(_))) => {
+                let text = self.text(key);
+                parse_amount(&text).ok_or_else(|| invalid(key, &value.to_string()))
+            }
+            Some(value) => Err(invalid(key, &value.to_string())),
+        }
+    }
+
+    /// An ISO 8601 date; only the `YYYY-MM-DD` part is read.
+    fn date(self, key: &str) -> Result<Option<Date>, FdxError> {
+        let text = self.text(key);
+        if text.is_empty() {
+            return Ok(None);
+        }
+        text.get(..10)
+            .and_then(|d| d.parse().ok())
+            .map(Some)
+            .ok_or_else(|| invalid(key, &text))
+    }
+
+    fn name_lines(self) -> Vec<String> {
+        ["name1", "name2"]
+            .into_iter()
+            .map(|k| self.text(k))
+            .filter(|l| !l.is_empty())
+            .collect()
+    }
+
+    fn address_lines(self) -> Vec<String> {
+        let mut lines: Vec<String> = ["line1", "line2", "line3"]
+            .into_iter()
+            .map(|k| self.text(k))
+            .filter(|l| !l.is_empty())
+            .collect();
+        let city = ["city", "state", "postalCode"]
+            .into_iter()
+            .map(|k| self.text(k))
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !city.is_empty() {
+            lines.push(city);
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATEMENT: &str = r#"{
+      "taxYear": 2025,
+      "forms": [
+        {
+          "taxW2": {
+            "taxYear": 2025,
+            "employerNameAddress": {
+              "name1": "Acme Corp", "line1": "1 Main St",
+              "city": "Springfield", "state": "IL", "postalCode": "62701"
+            },
+            "employerTin": "12-3456789",
+            "employeeNameAddress": {"name1": "Pat Doe", "line1": "2 Elm St", "city": "Springfield", "state": "IL", "postalCode": "62702"},
+            "employeeTin": "400-00-0001",
+            "wages": 52000.00,
+            "federalTaxWithheld": "6100.00",
+            "codes": [{"code": "D", "amount": 1500}, {"code": "dd", "amount": 8000.5}],
+            "retirementPlan": true,
+            "stateAndLocal": [{"state": "IL", "stateWages": 52000, "stateTaxWithheld": 2574}]
+          }
+        },
+        {
+          "tax1099Int": {
+            "payerNameAddress": {"name1": "First Bank"},
+            "payerTin": "98-7654321",
+            "recipientNameAddress": {"name1": "Pat Doe"},
+            "accountNumber": "1234",
+            "interestIncome": 123.45,
+            "taxExemptInterest": null
+          }
+        },
+        {
+          "tax1099B": {
+            "payerNameAddress": {"name1": "Acme Brokerage"},
+            "securityDetails": [
+              {"saleDescription": "100 sh ACME", "dateAcquired": "2024-03-07", "dateOfSale": "2025-11-20",
+               "salesPrice": 1250.5, "costBasis": 1000, "longOrShort": "LONG", "checkboxOnForm8949": "D"},
+              {"securityName": "WIDGET", "variousDatesAcquired": true, "dateOfSale": "2025-06-02T00:00:00Z",
+               "salesPrice": 1500, "costBasis": 2000, "washSaleLossDisallowed": 120, "nonCoveredSecurity": true}
+            ]
+          }
+        },
+        {"tax1098": {"mortgageInterest": 9000}}
+      ]
+    }"#;
+
+    #[test]
+    fn reads_a_tax_statement() {
+        let import = parse_fdx(STATEMENT).unwrap();
+        let w2 = &import.w2s[0];
+        assert_eq!(
+            w2.employer_name_address,
+            "Acme Corp\n1 Main St\nSpringfield IL 62701"
+        );
+        assert_eq!(w2.employee_name, "Pat Doe");
+        assert_eq!(w2.employee_address, "2 Elm St\nSpringfield IL 62702");
+        assert_eq!(w2.wages_tips_other_comp, Usd::from_dollars(52_000));
+        assert_eq!(w2.federal_income_tax_withheld, Usd::from_dollars(6_100));
+        assert_eq!(w2.box_12[1].code, Box12Code::DD);
+        assert_eq!(w2.box_12[1].amount, Usd::from_cents(800_050));
+        assert!(w2.retirement_plan);
+        assert_eq!(w2.state_tax[0].state_income_tax, Usd::from_dollars(2_574));
+
+        let int = &import.interest[0];
+        assert_eq!(int.payer_name_address, "First Bank");
+        assert_eq!(int.account_number.as_deref(), Some("1234"));
+        assert_eq!(int.interest_income, Usd::from_cents(12_345));
+        assert_eq!(import.unsupported, ["tax1098"]);
+    }
+
+    #[test]
+    fn splits_1099_b_security_details() {
+        let sales = parse_fdx(STATEMENT).unwrap().sales;
+        assert_eq!(sales.len(), 2);
+        assert_eq!(sales[0].date_acquired, Date::new(2024, 3, 7));
+        assert_eq!(sales[0].gain_type, GainType::LongTerm);
+        assert!(sales[0].basis_reported_to_irs);
+        assert_eq!(sales[1].description, "WIDGET");
+        assert_eq!(sales[1].date_acquired, None);
+        assert_eq!(sales[1].date_sold, Date::new(2025, 6, 2));
+        assert_eq!(sales[1].gain_or_loss(), Usd::from_dollars(-380));
+        assert!(!sales[1].basis_reported_to_irs);
+    }
+
+    #[test]
+    fn reports_bad_values() {
+        let err =
+            parse_fdx(r#"[{"tax1099Nec": {"nonEmployeeCompensation": "lots"}}]"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid nonEmployeeCompensation value \"lots\""
+        );
+        assert!(matches!(parse_fdx("{"), Err(FdxError::Json(_))));
+    }
+}
@@ -0,0 +1,160 @@
+//! Reading tax documents exported by brokers, banks, and accounting
+//! software into this crate's form structs.
+
+use gideon_tax_core::{Date, Usd};
+
+mod broker;
+mod efw2;
+#[cfg(feature = "json")]
+mod fdx;
+mod ofx;
+mod transcript;
+mod txf;
+mod w2_csv;
+
+pub use broker::{
+    BrokerColumn, BrokerError, BrokerImport, CsvLayout, builtin_brokers, detect_broker,
+};
+pub use efw2::{Efw2Error, Efw2File, parse_efw2};
+#[cfg(feature = "json")]
+pub use fdx::{FdxError, FdxImport, parse_fdx};
+pub use ofx::{OfxError, OfxImport, parse_ofx};
+pub use transcript::{TranscriptImport, UnreadLine, parse_transcript};
+pub use txf::{
+    DividendBox, InterestBox, TxfError, TxfImport, TxfMap, TxfRecord, TxfTarget, parse_txf,
+};
+pub use w2_csv::{W2CsvError, W2CsvMap, W2Field, parse_w2_csv};
+
+/// Reads a dollar amount such as `1234.5`, `$1,234.56`, `-12`, or `(12.00)`,
+/// rounding anything past cents to the nearest cent.
+pub(crate) fn parse_amount(text: &str) -> Option<Usd> {
+    let text = text.trim();
+    let (negative, text) = match text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        Some(inner) => (true, inner.trim()),
+        None => match text.strip_prefix('-') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, text),
+        },
+    };
+    let digits = text.strip_prefix('$').unwrap_or(text).replace(',', "");
+    let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: i64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let mut cents = fraction
+        .bytes()
+        .take(2)
+        .fold(0, |c, b| c * 10 + i64::from(b - b'0'));
+    if fraction.len() == 1 {
+        cents *= 10;
+    }
+    if fraction.as_bytes().get(2).is_some_and(|&b| b >= b'5') {
+        cents += 1;
+    }
+    let cents = whole.checked_mul(100)?.checked_add(cents)?;
+    Some(Usd::from_cents(if negative { -cents } else { cents }))
+}
+
+/// Reads a date as `MM/DD/YYYY` or `YYYY-MM-DD`.
+pub(crate) fn parse_date(text: &str) -> Option<Date> {
+    let text = text.trim();
+    if let Ok(date) = text.parse() {
+        return Some(date);
+    }
+    let mut parts = text.splitn(3, '/');
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    let year = parts.next()?.parse().ok()?;
+    Date::new(year, month, day)
+}
+
+/// Splits comma-separated text into rows of fields. Quoted fields may
+/// hold commas, doubled quotes, and line breaks. A leading byte order mark
+/// is skipped. On an unterminated quote, returns the line it starts on.
+pub(crate) fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, usize> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() => {
+                let start = line;
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        }
+                        None => return Err(start),
+                    }
+                }
+            }
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+                line += 1;
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_in_common_spellings() {
+        assert_eq!(parse_amount("1234.5"), Some(Usd::from_cents(123_450)));
+        assert_eq!(parse_amount(" $1,234.56 "), Some(Usd::from_cents(123_456)));
+        assert_eq!(parse_amount("-12"), Some(Usd::from_dollars(-12)));
+        assert_eq!(parse_amount("(12.00)"), Some(Usd::from_dollars(-12)));
+        assert_eq!(parse_amount(".995"), Some(Usd::from_cents(100)));
+        assert_eq!(parse_amount(""), None);
+        assert_eq!(parse_amount("12a"), None);
+    }
+
+    #[test]
+    fn dates_in_either_order() {
+        let date = Date::new(2025, 3, 7);
+        assert_eq!(parse_date("03/07/2025"), date);
+        assert_eq!(parse_date("3/7/2025"), date);
+        assert_eq!(parse_date("2025-03-07"), date);
+        assert_eq!(parse_date("VARIOUS"), None);
+    }
+
+    #[test]
+    fn csv_quoting() {
+        let rows = parse_csv("\u{feff}a,\"b,c\",\"say \"\"hi\"\"\"\r\n,\"two\nlines\"\n").unwrap();
+        assert_eq!(
+            rows,
+            [vec!["a", "b,c", "say \"hi\""], vec!["", "two\nlines"]]
+        );
+        assert_eq!(parse_csv("a\nb,\"open\n"), Err(2));
+    }
+}
@@ -0,0 +1,467 @@
+use std::fmt;
+
+use gideon_tax_core::{Date, Usd};
+
+use super::parse_amount;
+use crate::efile::xml::unescape;
+use crate::y2025::federal::{GainType, Irs1099B, Irs1099Div, Irs1099Int};
+
+/// Forms read from an OFX or QFX tax download.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OfxImport {
+    pub interest: Vec<Irs1099Int>,
+    pub dividends: Vec<Irs1099Div>,
+    /// One form per sale.
+    pub sales: Vec<Irs1099B>,
+    /// Names of tax form aggregates this crate does not read, such as
+    /// `TAX1099MISC_V100`, one per form.
+    pub unsupported: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OfxError {
+    /// The document has no `<OFX>` element.
+    NotOfx,
+    /// `offset` is in bytes from the start of the document.
+    Syntax {
+        offset: usize,
+        message: &'static str,
+    },
+    InvalidValue {
+        element: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for OfxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OfxError::NotOfx => write!(f, "not an OFX document"),
+            OfxError::Syntax { offset, message } => {
+                write!(f, "malformed OFX at byte {offset}: {message}")
+            }
+            OfxError::InvalidValue { element, value } => {
+                write!(f, "invalid <{element}> value {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OfxError {}
+
+/// Reads the Forms 1099-INT, 1099-DIV, and 1099-B in an OFX tax download
+/// (the `TAX1099` message set), in either the SGML syntax of OFX 1.x or
+/// the XML syntax of OFX 2.x. Quicken's QFX files are OFX and read the
+/// same way.
+///
+/// Each `PROCDET_V100` in a 1099-B becomes its own [`Irs1099B`] with the
+/// broker's name and TIN. A sale is reported to the IRS unless its Form
+/// 8949 code is B, C, E, or F or it is marked as a noncovered security.
+pub fn parse_ofx(text: &str) -> Result<OfxImport, OfxError> {
+    let root = parse(text)?;
+    let ofx = find(&root, "OFX").ok_or(OfxError::NotOfx)?;
+    let mut import = OfxImport::default();
+    let mut forms = Vec::new();
+    collect_forms(ofx, &mut forms);
+    for form in forms {
+        match form.name.as_str() {
+            "TAX1099INT_V100" => import.interest.push(interest(form)?),
+            "TAX1099DIV_V100" => import.dividends.push(dividends(form)?),
+            "TAX1099B_V100" => sales(form, &mut import.sales)?,
+            _ => import.unsupported.push(form.name.clone()),
+        }
+    }
+    Ok(import)
+}
+
+/// The parts of a form every 1099 shares.
+struct Parties {
+    payer_name_address: String,
+    payer_tin: String,
+    recipient_tin: String,
+    recipient_name: String,
+    account_number: Option<String>,
+}
+
+fn parties(form: &Node) -> Parties {
+    fn lines<'a>(node: Option<&'a Node>, names: &[&str]) -> Vec<&'a str> {
+        names
+            .iter()
+            .filter_map(|name| node.and_then(|n| n.text_of(name)))
+            .collect()
+    }
+    let payer = form.child("PAYERADDR");
+    let mut payer_lines = lines(
+        payer,
+        &["PAYERNAME1", "PAYERNAME2", "ADDR1", "ADDR2", "ADDR3"],
+    );
+    let city_line = lines(payer, &["CITY", "STATE", "POSTALCODE"]).join(" ");
+    if !city_line.is_empty() {
+        payer_lines.push(&city_line);
+    }
+    Parties {
+        payer_name_address: payer_lines.join("\n"),
+        payer_tin: form.text_of("PAYERID").unwrap_or_default().to_string(),
+        recipient_tin: form.text_of("RECID").unwrap_or_default().to_string(),
+        recipient_name: lines(form.child("RECADDR"), &["RECNAME1", "RECNAME2"]).join(" "),
+        account_number: form.text_of("RECACCT").map(str::to_string),
+    }
+}
+
+fn interest(form: &Node) -> Result<Irs1099Int, OfxError> {
+    let p = parties(form);
+    Ok(Irs1099Int {
+        payer_name_address: p.payer_name_address,
+        payer_tin: p.payer_tin,
+        recipient_tin: p.recipient_tin,
+        recipient_name: p.recipient_name,
+        account_number: p.account_number,
+        interest_income: form.amount_of("INTINCOME")?,
+        early_withdrawal_penalty: form.amount_of("ERLWITHPEN")?,
+        us_savings_bond_and_treasury_interest: form.amount_of("INTUSBNDTRS")?,
+        federal_income_tax_withheld: form.amount_of("FEDTAXWH")?,
+        investment_expenses: form.amount_of("INVESTEXP")?,
+        foreign_tax_paid: form.amount_of("FORTAXPD")?,
+        tax_exempt_interest: form.amount_of("TAXEXEMPTINT")?,
+        private_activity_bond_interest: form.amount_of("SPECIFIEDPABINT")?,
+    })
+}
+
+fn dividends(form: &Node) -> Result<Irs1099Div, OfxError> {
+    let p = parties(form);
+    Ok(Irs1099Div {
+        payer_name_address: p.payer_name_address,
+        payer_tin: p.payer_tin,
+        recipient_tin: p.recipient_tin,
+        recipient_name: p.recipient_name,
+        account_number: p.account_number,
+        total_ordinary_dividends: form.amount_of("ORDDIV")?,
+        qualified_dividends: form.amount_of("QUALIFIEDDIV")?,
+        total_capital_gain_distributions: form.amount_of("TOTCAPGAIN")?,
+        unrecaptured_section_1250_gain: form.amount_of("UNRECSEC1250")?,
+        section_1202_gain: form.amount_of("SEC1202")?,
+        collectibles_gain: form.amount_of("P28GAIN")?,
+        nondividend_distributions: form.amount_of("NONTAXDIST")?,
+        federal_income_tax_withheld: form.amount_of("FEDTAXWH")?,
+        investment_expenses: form.amount_of("INVESTEXP")?,
+        foreign_tax_paid: form.amount_of("FORTAXPD")?,
+        exempt_interest_dividends: form.amount_of("EXEMPTINTDIV")?,
+        ..Default::default()
+    })
+}
+
+fn sales(form: &Node, out: &mut Vec<Irs1099B>) -> Result<(), OfxError> {
+    let mut details = Vec::new();
+    form.descendants_named("PROCDET_V100", &mut details);
+    for detail in details {
+        let p = parties(form);
+        let code = detail.text_of("FORM8949CODE").unwrap_or_default();
+        let noncovered = matches!(detail.text_of("NONCOVEREDSECURITY"), Some("Y"));
+        out.push(Irs1099B {
+            payer_name_address: p.payer_name_address,
+            payer_tin: p.payer_tin,
+            recipient_tin: p.recipient_tin,
+            recipient_name: p.recipient_name,
+            account_number: p.account_number,
+            description: detail.text_of("SECNAME").unwrap_or_default().to_string(),
+            date_acquired: detail.date_of("DTAQD")?,
+            date_sold: detail.date_of("DTSALE")?,
+            proceeds: detail.amount_of("SALESPR")?,
+            cost_basis: detail.amount_of("COSTBASIS")?,
+            accrued_market_discount: detail.amount_of("ACCRUEDMKTDISC")?,
+            wash_sale_loss_disallowed: detail.amount_of("WASHSALELOSSDISALLOWED")?,
+            gain_type: match detail.text_of("LONGSHORT") {
+                Some("LONG") => GainType::LongTerm,
+                _ if detail.text_of("ORDINARY") == Some("Y") => GainType::Ordinary,
+                _ => GainType::ShortTerm,
+            },
+            federal_income_tax_withheld: detail.amount_of("FEDTAXWH")?,
+            basis_reported_to_irs: !noncovered && !matches!(code, "B" | "C" | "E" | "F"),
+        });
+    }
+    Ok(())
+}
+
+/// Tax form aggregates, which are named `TAX1099*_V100` and sit inside
+/// `TAX1099RS`.
+fn collect_forms<'a>(node: &'a Node, out: &mut Vec<&'a Node>) {
+    for child in &node.children {
+        if child.name.starts_with("TAX1099") && child.name.ends_with("_V100") {
+            out.push(child);
+        } else {
+            collect_forms(child, out);
+        }
+    }
+}
+
+fn find<'a>(node: &'a Node, name: &str) -> Option<&'a Node> {
+    if node.name == name {
+        return Some(node);
+    }
+    node.children.iter().find_map(|c| find(c, name))
+}
+
+/// An element of an OFX document.
+#[derive(Debug, Default)]
+struct Node {
+    name: String,
+    text: String,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn child(&self, name: &str) -> Option<&Node> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    fn text_of(&self, name: &str) -> Option<&str> {
+        self.child(name)
+            .map(|c| c.text.as_str())
+            .filter(|t| !t.is_empty())
+    }
+
+    fn descendants_named<'a>(&'a self, name: &str, out: &mut Vec<&'a Node>) {
+        for child in &self.children {
+            if child.name == name {
+                out.push(child);
+            } else {
+                child.descendants_named(name, out);
+            }
+        }
+    }
+
+    fn amount_of(&self, name: &str) -> Result<Usd, OfxError> {
+        match self.text_of(name) {
+            None => Ok(Usd::ZERO),
+            Some(text) => parse_amount(text).ok_or_else(|| invalid(name, text)),
+        }
+    }
+
+    /// An OFX date, `YYYYMMDD` with an optional time and zone after it.
+    fn date_of(&self, name: &str) -> Result<Option<Date>, OfxError> {
+        let Some(text) = self.text_of(name) else {
+            return Ok(None);
+        };
+        let date = text
+            .get(..8)
+            .filter(|d| d.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|d| {
+                Date::new(
+                    d[..4].parse().ok()?,
+                    d[4..6].parse().ok()?,
+                    d[6..].parse().ok()?,
+                )
+            })
+            .ok_or_else(|| invalid(name, text))?;
+        Ok(Some(date))
+    }
+}
+
+fn invalid(element: &str, value: &str) -> OfxError {
+    OfxError::InvalidValue {
+        element: element.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// Parses OFX 1.x SGML or OFX 2.x XML into a tree under an unnamed root.
+///
+/// SGML leaves have no end tag: an element with text ends at the next tag,
+/// and an end tag closes any elements still open inside it. Headers,
+/// processing instructions, and comments are skipped.
+fn parse(text: &str) -> Result<Node, OfxError> {
+    let mut stack = vec![Node::default()];
+    let mut pos = text.find('<').unwrap_or(text.len());
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let error = |message| OfxError::Syntax {
+            offset: pos,
+            message,
+        };
+        if rest.starts_with("<?") || rest.starts_with("<!") {
+            let end = rest.find('>').ok_or(error("unterminated declaration"))?;
+            pos += end + 1;
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').ok_or(error("unterminated end tag"))?;
+            let name = tag[..end].trim();
+            if !stack[1..].iter().any(|n| n.name == name) {
+                return Err(error("end tag without a start tag"));
+            }
+            loop {
+                let node = stack.pop().expect("the root is never popped");
+                let done = node.name == name;
+                stack.last_mut().unwrap().children.push(node);
+                if done {
+                    break;
+                }
+            }
+            pos += end + 3;
+        } else if let Some(tag) = rest.strip_prefix('<') {
+            let end = tag.find('>').ok_or(error("unterminated start tag"))?;
+            let tag = tag[..end].trim();
+            // A leaf with text is closed by the next tag.
+            if stack.len() > 1 && !stack.last().unwrap().text.is_empty() {
+                let leaf = stack.pop().unwrap();
+                stack.last_mut().unwrap().children.push(leaf);
+            }
+            let (tag, empty) = match tag.strip_suffix('/') {
+                Some(tag) => (tag.trim_end(), true),
+                None => (tag, false),
+            };
+            let name = tag.split_whitespace().next().unwrap_or_default();
+            let node = Node {
+                name: name.to_string(),
+                ..Default::default()
+            };
+            if empty {
+                stack.last_mut().unwrap().children.push(node);
+            } else {
+                stack.push(node);
+            }
+            pos += end + 2;
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let chunk = unescape(rest[..end].trim()).ok_or(error("invalid character reference"))?;
+            if !chunk.is_empty() {
+                stack.last_mut().unwrap().text.push_str(&chunk);
+            }
+            pos += end;
+        }
+    }
+    while stack.len() > 1 {
+        let node = stack.pop().unwrap();
+        stack.last_mut().unwrap().children.push(node);
+    }
+    Ok(stack.pop().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SGML: &str = "OFXHEADER:100
+DATA:OFXSGML
+VERSION:102
+
+<OFX>
+<SIGNONMSGSRSV1><SONRS><STATUS><CODE>0<SEVERITY>INFO</STATUS></SONRS></SIGNONMSGSRSV1>
+<TAX1099MSGSRSV1>
+<TAX1099TRNRS>
+<TRNUID>1
+<TAX1099RS>
+<TAX1099INT_V100>
+<SRVRTID>1
+<TAXYEAR>2025
+<INTINCOME>1234.56
+<FEDTAXWH>120.00
+<PAYERADDR>
+<PAYERNAME1>First Bank &amp; Trust
+<ADDR1>1 Main St
+<CITY>Springfield
+<STATE>IL
+<POSTALCODE>62701
+</PAYERADDR>
+<PAYERID>12-3456789
+<RECID>XXX-XX-1234
+<RECACCT>9876
+</TAX1099INT_V100>
+<TAX1099MISC_V100>
+<RENTS>500.00
+</TAX1099MISC_V100>
+</TAX1099RS>
+</TAX1099TRNRS>
+</TAX1099MSGSRSV1>
+</OFX>
+";
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<?OFX OFXHEADER="200" VERSION="220"?>
+<OFX>
+  <TAX1099MSGSRSV1><TAX1099TRNRS><TAX1099RS>
+    <TAX1099DIV_V100>
+      <ORDDIV>300.00</ORDDIV>
+      <QUALIFIEDDIV>250.00</QUALIFIEDDIV>
+      <TOTCAPGAIN>40.00</TOTCAPGAIN>
+      <PAYERADDR><PAYERNAME1>Acme Fund</PAYERNAME1></PAYERADDR>
+      <PAYERID>98-7654321</PAYERID>
+    </TAX1099DIV_V100>
+    <TAX1099B_V100>
+      <EXTDBINFO_V100>
+        <PROCDET_V100>
+          <DTAQD>20240307</DTAQD>
+          <DTSALE>20251120120000.000[-5:EST]</DTSALE>
+          <SECNAME>ACME CORP</SECNAME>
+          <COSTBASIS>1000.00</COSTBASIS>
+          <SALESPR>1250.50</SALESPR>
+          <LONGSHORT>LONG</LONGSHORT>
+          <FORM8949CODE>D</FORM8949CODE>
+        </PROCDET_V100>
+        <PROCDET_V100>
+          <DTSALE>20250602</DTSALE>
+          <SECNAME>WIDGET INC</SECNAME>
+          <COSTBASIS>2000.00</COSTBASIS>
+          <SALESPR>1500.00</SALESPR>
+          <WASHSALELOSSDISALLOWED>120.00</WASHSALELOSSDISALLOWED>
+          <LONGSHORT>SHORT</LONGSHORT>
+          <NONCOVEREDSECURITY>Y</NONCOVEREDSECURITY>
+        </PROCDET_V100>
+      </EXTDBINFO_V100>
+      <PAYERADDR><PAYERNAME1>Acme Brokerage</PAYERNAME1></PAYERADDR>
+      <PAYERID>11-1111111</PAYERID>
+    </TAX1099B_V100>
+  </TAX1099RS></TAX1099TRNRS></TAX1099MSGSRSV1>
+</OFX>"#;
+
+    #[test]
+    fn reads_sgml_interest_forms() {
+        let import = parse_ofx(SGML).unwrap();
+        assert_eq!(import.interest.len(), 1);
+        let form = &import.interest[0];
+        assert_eq!(
+            form.payer_name_address,
+            "First Bank & Trust\n1 Main St\nSpringfield IL 62701"
+        );
+        assert_eq!(form.payer_tin, "12-3456789");
+        assert_eq!(form.account_number.as_deref(), Some("9876"));
+        assert_eq!(form.interest_income, Usd::from_cents(123_456));
+        assert_eq!(form.federal_income_tax_withheld, Usd::from_dollars(120));
+        assert_eq!(import.unsupported, ["TAX1099MISC_V100"]);
+    }
+
+    #[test]
+    fn reads_xml_dividends_and_sales() {
+        let import = parse_ofx(XML).unwrap();
+        let div = &import.dividends[0];
+        assert_eq!(div.payer_name_address, "Acme Fund");
+        assert_eq!(div.total_ordinary_dividends, Usd::from_dollars(300));
+        assert_eq!(div.qualified_dividends, Usd::from_dollars(250));
+        assert_eq!(div.total_capital_gain_distributions, Usd::from_dollars(40));
+
+        assert_eq!(import.sales.len(), 2);
+        let acme = &import.sales[0];
+        assert_eq!(acme.payer_tin, "11-1111111");
+        assert_eq!(acme.date_acquired, Date::new(2024, 3, 7));
+        assert_eq!(acme.date_sold, Date::new(2025, 11, 20));
+        assert_eq!(acme.gain_type, GainType::LongTerm);
+        assert!(acme.basis_reported_to_irs);
+        let widget = &import.sales[1];
+        assert_eq!(widget.date_acquired, None);
+        assert_eq!(widget.gain_or_loss(), Usd::from_dollars(-380));
+        assert!(!widget.basis_reported_to_irs);
+    }
+
+    #[test]
+    fn rejects_other_documents_and_bad_values() {
+        assert_eq!(parse_ofx("<html></html>"), Err(OfxError::NotOfx));
+        assert_eq!(
+            parse_ofx("<OFX><TAX1099INT_V100><INTINCOME>lots</TAX1099INT_V100></OFX>")
+                .unwrap_err()
+                .to_string(),
+            "invalid <INTINCOME> value \"lots\""
+        );
+        assert!(matches!(
+            parse_ofx("<OFX></TAX1099RS></OFX>"),
+            Err(OfxError::Syntax { offset: 5, .. })
+        ));
+    }
+}
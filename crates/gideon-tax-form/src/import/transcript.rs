@@ -0,0 +1,610 @@
+use gideon_tax_core::Usd;
+
+use super::parse_amount;
+use crate::y2025::federal::{
+    Box12Code, Box12Entry, Irs1099Div, Irs1099G, Irs1099Int, Irs1099Misc, Irs1099Nec, Irs1099R,
+    IrsW2,
+};
+
+/// Forms read from a Wage and Income transcript.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranscriptImport {
+    pub w2s: Vec<IrsW2>,
+    pub interest: Vec<Irs1099Int>,
+    pub dividends: Vec<Irs1099Div>,
+    pub nonemployee_compensation: Vec<Irs1099Nec>,
+    pub miscellaneous: Vec<Irs1099Misc>,
+    pub government_payments: Vec<Irs1099G>,
+    pub retirement: Vec<Irs1099R>,
+    /// Forms this crate does not read, such as `1099-B` or `1098`, one
+    /// per form.
+    pub unsupported: Vec<String>,
+    /// Nonzero amounts on a read form whose labels match no field.
+    pub unread: Vec<UnreadLine>,
+}
+
+/// A labeled amount that was not placed on a form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreadLine {
+    /// As in the form's heading, such as `W-2`.
+    pub form: String,
+    pub label: String,
+    pub amount: Usd,
+}
+
+/// Reads the text of an IRS Wage and Income transcript, as copied from
+/// the transcript page or a text export of it.
+///
+/// Each `Form ...` heading starts a form. Within a form, lines under
+/// `Employer:` or `Payer:` and `Employee:` or `Recipient:` are the
+/// parties' names and addresses, and `Label:....value` lines are the
+/// reported amounts. Labels match ignoring case and punctuation.
+///
+/// Transcripts mask TINs, account numbers, and most of each name and
+/// address, and the masked values are kept as shown. Complete them from
+/// the payer's records before filing. Box 12 amounts are read from their
+/// `Code "X"` lines; the transcript's combined deferred compensation
+/// line does not say which code it belongs to and is left in
+/// [`TranscriptImport::unread`].
+pub fn parse_transcript(text: &str) -> TranscriptImport {
+    let mut import = TranscriptImport::default();
+    let mut forms = Vec::new();
+    let mut current: Option<Form> = None;
+    for line in text.lines().map(str::trim) {
+        if let Some(heading) = line.strip_prefix("Form ") {
+            forms.extend(current.take());
+            current = Some(Form::new(
+                heading.split_whitespace().next().unwrap_or_default(),
+            ));
+            continue;
+        }
+        if let Some(form) = &mut current {
+            form.read_line(line);
+        }
+    }
+    forms.extend(current);
+
+    for form in forms {
+        let mut unread = Vec::new();
+        match form.name.as_str() {
+            "W-2" => import.w2s.push(form.w2(&mut unread)),
+            "1099-INT" => import.interest.push(form.interest(&mut unread)),
+            "1099-DIV" => import.dividends.push(form.dividends(&mut unread)),
+            "1099-NEC" => import
+                .nonemployee_compensation
+                .push(form.nonemployee(&mut unread)),
+            "1099-MISC" => import.miscellaneous.push(form.miscellaneous(&mut unread)),
+            "1099-G" => import
+                .government_payments
+                .push(form.government(&mut unread)),
+            "1099-R" => import.retirement.push(form.retirement(&mut unread)),
+            _ => {
+                import.unsupported.push(form.name);
+                continue;
+            }
+        }
+        import
+            .unread
+            .extend(unread.into_iter().map(|(label, amount)| UnreadLine {
+                form: form.name.clone(),
+                label,
+                amount,
+            }));
+    }
+    import
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Party {
+    /// Employer or payer.
+    Payer,
+    /// Employee or recipient.
+    Recipient,
+}
+
+/// One form's lines, as read.
+struct Form {
+    name: String,
+    party: Option<Party>,
+    payer_lines: Vec<String>,
+    recipient_lines: Vec<String>,
+    payer_tin: String,
+    recipient_tin: String,
+    account_number: Option<String>,
+    /// Other labeled lines: label as printed and value.
+    fields: Vec<(String, String)>,
+}
+
+impl Form {
+    fn new(name: &str) -> Self {
+        Form {
+            name: name.to_string(),
+            party: None,
+            payer_lines: Vec::new(),
+            recipient_lines: Vec::new(),
+            payer_tin: String::new(),
+            recipient_tin: String::new(),
+            account_number: None,
+            fields: Vec::new(),
+        }
+    }
+
+    fn read_line(&mut self, line: &str) {
+        if line.is_empty() {
+            return;
+        }
+        let Some((label, value)) = line.split_once(':') else {
+            match self.party {
+                Some(Party::Payer) => self.payer_lines.push(line.to_string()),
+                Some(Party::Recipient) => self.recipient_lines.push(line.to_string()),
+                None => {}
+            }
+            return;
+        };
+        let label = label.trim();
+        let value = value.trim_start_matches(['.', ' ']).trim();
+        let key = normalize(label);
+        match key.as_str() {
+            "employer" | "payer" => self.party = Some(Party::Payer),
+            "employee" | "recipient" => self.party = Some(Party::Recipient),
+            _ if (key.starts_with("employer") || key.starts_with("payer"))
+                && key.contains("identification number") =>
+            {
+                self.payer_tin = value.to_string();
+            }
+            _ if key.starts_with("employee s social security number")
+                || key.starts_with("recipient s identification number") =>
+            {
+                self.recipient_tin = value.to_string();
+            }
+            _ if key.starts_with("account number") => {
+                self.account_number = Some(value.to_string()).filter(|v| !v.is_empty());
+            }
+            _ => {
+                // Labels end the name and address lines.
+                self.party = None;
+                self.fields.push((label.to_string(), value.to_string()));
+            }
+        }
+    }
+
+    fn payer_name_address(&self) -> String {
+        self.payer_lines.join("\n")
+    }
+
+    fn recipient_name(&self) -> String {
+        self.recipient_lines.first().cloned().unwrap_or_default()
+    }
+
+    /// Calls `set` with each field's normalized label, value, and amount.
+    /// Fields `set` does not take, and whose amounts are nonzero, go to
+    /// `unread`.
+    fn each_field(
+        &self,
+        unread: &mut Vec<(String, Usd)>,
+        mut set: impl FnMut(&str, &str, Usd) -> bool,
+    ) {
+        for (label, value) in &self.fields {
+            let amount = parse_amount(value).unwrap_or(Usd::ZERO);
+            if !set(&normalize(label), value, amount) && amount != Usd::ZERO {
+                unread.push((label.clone(), amount));
+            }
+        }
+    }
+
+    fn w2(&self, unread: &mut Vec<(String, Usd)>) -> IrsW2 {
+        let mut w2 = IrsW2 {
+            employer_ein: self.payer_tin.clone(),
+            employer_name_address: self.payer_name_address(),
+            employee_ssn: self.recipient_tin.clone(),
+            employee_name: self.recipient_name(),
+            employee_address: self
+                .recipient_lines
+                .iter()
+                .skip(1)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("\n"),
+            ..Default::default()
+        };
+        self.each_field(unread, |label, value, amount| {
+            if let Some(code) = box_12_code(label) {
+                if amount != Usd::ZERO {
+                    w2.box_12.push(Box12Entry { code, amount });
+                }
+                return true;
+            }
+            let slot = match label {
+                "wages tips and other compensation" => &mut w2.wages_tips_other_comp,
+                "federal income tax withheld" => &mut w2.federal_income_tax_withheld,
+                "social security wages" => &mut w2.social_security_wages,
+                "social security tax withheld" => &mut w2.social_security_tax_withheld,
+                "medicare wages and tips" => &mut w2.medicare_wages_and_tips,
+                "medicare tax withheld" => &mut w2.medicare_tax_withheld,
+                "social security tips" => &mut w2.social_security_tips,
+                "allocated tips" => &mut w2.allocated_tips,
+                "dependent care benefits" => &mut w2.dependent_care_benefits,
+                "nonqualified plans" | "non qualified plans" => &mut w2.nonqualified_plans,
+                "retirement plan indicator" => {
+                    w2.retirement_plan = checked(value);
+                    return true;
+                }
+                "third party sick pay indicator" => {
+                    w2.third_party_sick_pay = checked(value);
+                    return true;
+                }
+                "statutory employee" => {
+                    w2.statutory_employee = normalize(value) == "statutory employee";
+                    return true;
+                }
+                _ => return false,
+            };
+            *slot = amount;
+            true
+        });
+        w2
+    }
+
+    fn interest(&self, unread: &mut Vec<(String, Usd)>) -> Irs1099Int {
+        let mut form = Irs1099Int {
+            payer_name_address: self.payer_name_address(),
+            payer_tin: self.payer_tin.clone(),
+            recipient_tin: self.recipient_tin.clone(),
+            recipient_name: self.recipient_name(),
+            account_number: self.account_number.clone(),
+            ..Default::default()
+        };
+        self.each_field(unread, |label, _, amount| {
+            let slot = match label {
+                "interest" | "interest income" => &mut form.interest_income,
+                "early withdrawal penalty" => &mut form.early_withdrawal_penalty,
+                "savings bonds" | "interest on us savings bonds and treasury obligations" => {
+                    &mut form.us_savings_bond_and_treasury_interest
+                }
+                "tax withheld" | "federal income tax withheld" => {
+                    &mut form.federal_income_tax_withheld
+                }
+                "investment expense" | "investment expenses" => &mut form.investment_expenses,
+                "foreign tax paid" => &mut form.foreign_tax_paid,
+                "tax exempt interest" => &mut form.tax_exempt_interest,
+                "specified private activity bond interest" => {
+                    &mut form.private_activity_bond_interest
+                }
+                _ => return false,
+            };
+            *slot = amount;
+            true
+        });
+        form
+    }
+
+    fn dividends(&self, unread: &mut Vec<(String, Usd)>) -> Irs1099Div {
+        let mut form = Irs1099Div {
+            payer_name_address: self.payer_name_address(),
+            payer_tin: self.payer_tin.clone(),
+            recipient_tin: self.recipient_tin.clone(),
+            recipient_name: self.recipient_name(),
+            account_number: self.account_number.clone(),
+            ..Default::default()
+        };
+        self.each_field(unread, |label, _, amount| {
+            let slot = match label {
+                "total ordinary dividends" | "ordinary dividends" => {
+                    &mut form.total_ordinary_dividends
+                }
+                "qualified dividends" => &mut form.qualified_dividends,
+                "total capital gains distributions" | "capital gains distributions" => {
+                    &mut form.total_capital_gain_distributions
+                }
+                "unrecaptured section 1250 gain" => &mut form.unrecaptured_section_1250_gain,
+                "section 1202 gain" => &mut form.section_1202_gain,
+                "collectibles 28 gain" => &mut form.collectibles_gain,
+                "non taxable distribution" | "nondividend distributions" => {
+                    &mut form.nondividend_distributions
+                }
+                "tax withheld" | "federal income tax withheld" => {
+                    &mut form.federal_income_tax_withheld
+                }
+                "section 199a dividends" | "section 199a reit dividends" => {
+                    &mut form.section_199a_dividends
+                }
+                "investment expense" | "investment expenses" => &mut form.investment_expenses,
+                "foreign tax paid" => &mut form.foreign_tax_paid,
+                "exempt interest dividends" => &mut form.exempt_interest_dividends,
+                _ => return false,
+            };
+            *slot = amount;
+            true
+        });
+        form
+    }
+
+    fn nonemployee(&self, unread: &mut Vec<(String, Usd)>) -> Irs1099Nec {
+        let mut form = Irs1099Nec {
+            payer_name_address: self.payer_name_address(),
+            payer_tin: self.payer_tin.clone(),
+            recipient_tin: self.recipient_tin.clone(),
+            recipient_name: self.recipient_name(),
+            account_number: self.account_number.clone(),
+            ..Default::default()
+        };
+        self.each_field(unread, |label, value, amount| {
+            let slot = match label {
+                "non employee compensation" | "nonemployee compensation" => {
+                    &mut form.nonemployee_compensation
+                }
+                "tax withheld" | "federal income tax withheld" => {
+                    &mut form.federal_income_tax_withheld
+                }
+                "direct sales indicator" => {
+                    form.direct_sales = checked(value);
+                    return true;
+                }
+                _ => return false,
+            };
+            *slot = amount;
+            true
+        });
+        form
+    }
+
+    fn miscellaneous(&self, unread: &mut Vec<(String, Usd)>) -> Irs1099Misc {
+        let mut form = Irs1099Misc {
+            payer_name_address: self.payer_name_address(),
+            payer_tin: self.payer_tin.clone(),
+            recipient_tin: self.recipient_tin.clone(),
+            recipient_name: self.recipient_name(),
+            account_number: self.account_number.clone(),
+            ..Default::default()
+        };
+        self.each_field(unread, |label, value, amount| {
+            let slot = match label {
+                "rents" => &mut form.rents,
+                "royalties" => &mut form.royalties,
+                "other income" => &mut form.other_income,
+                "tax withheld" | "federal income tax withheld" => {
+                    &mut form.federal_income_tax_withheld
+                }
+                "fishing boat proceeds" => &mut form.fishing_boat_proceeds,
+                "medical payments" | "medical and health care payments" => {
+                    &mut form.medical_and_health_care_payments
+                }
+                "substitute payments for dividends" | "substitute payments" => {
+                    &mut form.substitute_payments
+                }
+                "crop insurance proceeds" => &mut form.crop_insurance_proceeds,
+                "gross proceeds paid to an attorney" | "attorney fees" => {
+                    &mut form.gross_proceeds_paid_to_attorney
+                }
+                "fish purchased for resale" => &mut form.fish_purchased_for_resale,
+                "section 409a deferrals" => &mut form.section_409a_deferrals,
+                "excess golden parachute payments" => &mut form.excess_golden_parachute_payments,
+                "non qualified deferred compensation" | "nonqualified deferred compensation" => {
+                    &mut form.nonqualified_deferred_compensation
+                }
+                "direct sales indicator" => {
+                    form.direct_sales = checked(value);
+                    return true;
+                }
+                "fatca filing requirement" => {
+                    form.fatca_filing_requirement = checked(value);
+                    return true;
+                }
+                _ => return false,
+            };
+            *slot = amount;
+            true
+        });
+        form
+    }
+
+    fn government(&self, unread: &mut Vec<(String, Usd)>) -> Irs1099G {
+        let mut form = Irs1099G {
+            payer_name_address: self.payer_name_address(),
+            payer_tin: self.payer_tin.clone(),
+            recipient_tin: self.recipient_tin.clone(),
+            recipient_name: self.recipient_name(),
+            account_number: self.account_number.clone(),
+            ..Default::default()
+        };
+        self.each_field(unread, |label, value, amount| {
+            let slot = match label {
+                "unemployment compensation" => &mut form.unemployment_compensation,
+                "tax refunds credits or offsets" | "state or local income tax refunds" => {
+                    &mut form.state_or_local_income_tax_refunds
+                }
+                "tax withheld" | "federal income tax withheld" => {
+                    &mut form.federal_income_tax_withheld
+                }
+                "ataa rtaa payments" | "rtaa payments" => &mut form.rtaa_payments,
+                "taxable grants" => &mut form.taxable_grants,
+                "agricultural payments" | "agriculture payments" => &mut form.agriculture_payments,
+                "tax year of refund" => {
+                    form.refund_tax_year = value.parse().ok();
+                    return true;
+                }
+                _ => return false,
+            };
+            *slot = amount;
+            true
+        });
+        form
+    }
+
+    fn retirement(&self, unread: &mut Vec<(String, Usd)>) -> Irs1099R {
+        let mut form = Irs1099R {
+            payer_name_address: self.payer_name_address(),
+            payer_tin: self.payer_tin.clone(),
+            recipient_tin: self.recipient_tin.clone(),
+            recipient_name: self.recipient_name(),
+            account_number: self.account_number.clone(),
+            ..Default::default()
+        };
+        self.each_field(unread, |label, value, amount| {
+            let slot = match label {
+                "gross distribution" => &mut form.gross_distribution,
+                "taxable amount" => &mut form.taxable_amount,
+                "capital gain" => &mut form.capital_gain,
+                "tax withheld" | "federal income tax withheld" => {
+                    &mut form.federal_income_tax_withheld
+                }
+                "employee contributions" => &mut form.employee_contributions,
+                "taxable amount not determined" => {
+                    form.taxable_amount_not_determined = checked(value);
+                    return true;
+                }
+                "total distribution" => {
+                    form.total_distribution = checked(value);
+                    return true;
+                }
+                "distribution code" | "distribution codes" => {
+                    form.distribution_codes = value.to_string();
+                    return true;
+                }
+                "ira sep simple indicator" | "ira sep simple" => {
+                    form.ira_sep_simple = checked(value);
+                    return true;
+                }
+                _ => return false,
+            };
+            *slot = amount;
+            true
+        });
+        form
+    }
+}
+
+/// The code in a `Code "X" ...` label.
+fn box_12_code(label: &str) -> Option<Box12Code> {
+    let rest = label.strip_prefix("code ")?;
+    Box12Code::from_code(rest.split(' ').next()?)
+}
+
+/// Transcripts answer checkboxes with `Yes - ...`, `No`, or `Unanswered`.
+fn checked(value: &str) -> bool {
+    let value = normalize(value);
+    value.starts_with("yes") || value == "x" || value == "true"
+}
+
+/// Lowercase words separated by single spaces; apostrophes and other
+/// punctuation separate words.
+fn normalize(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSCRIPT: &str = "\
+This Product Contains Sensitive Taxpayer Data
+Wage and Income Transcript
+Request Date: 03-01-2026
+Tax Period Requested: December, 2025
+
+Form W-2 Wage and Tax Statement
+Employer:
+Employer Identification Number (EIN):XXXXX6789
+ACME
+1 MAI
+Employee:
+Employee's Social Security Number:XXX-XX-1234
+PAT DO
+2 ELM
+Submission Type:..............................Original document
+Wages, Tips and Other Compensation:...........$52,000.00
+Federal Income Tax Withheld:..................$6,100.00
+Social Security Wages:........................$52,000.00
+Medicare Tax Withheld:........................$754.00
+Deferred Compensation:........................$1,500.00
+Code \"W\" Employer Contributions to a Health Savings Account:$300.00
+Code \"DD\" Cost of Employer-Sponsored Health Coverage:$0.00
+Third Party Sick Pay Indicator:...............Unanswered
+Retirement Plan Indicator:....................Yes - Retirement Plan
+Statutory Employee:...........................Not Statutory Employee
+
+Form 1099-INT
+Payer:
+Payer's Federal Identification Number (FIN):XXXXX4321
+FIRS
+Recipient:
+Recipient's Identification Number:XXX-XX-1234
+PAT DO
+Submission Type:..............................Original document
+Account Number (Optional):....................XXXXXXXX9876
+Tax Withheld:.................................$0.00
+Interest:.....................................$123.45
+Tax-Exempt Interest:..........................$10.00
+
+Form 1099-B Proceeds From Broker and Barter Exchange Transactions
+Payer:
+Payer's Federal Identification Number (FIN):XXXXX1111
+Proceeds:.....................................$5,000.00
+
+Form 1099-G
+Payer:
+Payer's Federal Identification Number (FIN):XXXXX2222
+STAT
+Unemployment Compensation:....................$4,200.00
+Tax Year of Refund:...........................2024
+";
+
+    #[test]
+    fn reads_w2_box_12_and_checkboxes() {
+        let import = parse_transcript(TRANSCRIPT);
+        let w2 = &import.w2s[0];
+        assert_eq!(w2.employer_ein, "XXXXX6789");
+        assert_eq!(w2.employer_name_address, "ACME\n1 MAI");
+        assert_eq!(w2.employee_ssn, "XXX-XX-1234");
+        assert_eq!(w2.employee_name, "PAT DO");
+        assert_eq!(w2.employee_address, "2 ELM");
+        assert_eq!(w2.wages_tips_other_comp, Usd::from_dollars(52_000));
+        assert_eq!(w2.medicare_tax_withheld, Usd::from_dollars(754));
+        assert_eq!(
+            w2.box_12,
+            [Box12Entry {
+                code: Box12Code::W,
+                amount: Usd::from_dollars(300),
+            }]
+        );
+        assert!(w2.retirement_plan);
+        assert!(!w2.third_party_sick_pay);
+        assert!(!w2.statutory_employee);
+        assert_eq!(
+            import.unread,
+            [UnreadLine {
+                form: "W-2".to_string(),
+                label: "Deferred Compensation".to_string(),
+                amount: Usd::from_dollars(1_500),
+            }]
+        );
+    }
+
+    #[test]
+    fn reads_1099s_and_lists_other_forms() {
+        let import = parse_transcript(TRANSCRIPT);
+        let int = &import.interest[0];
+        assert_eq!(int.payer_tin, "XXXXX4321");
+        assert_eq!(int.payer_name_address, "FIRS");
+        assert_eq!(int.recipient_name, "PAT DO");
+        assert_eq!(int.account_number.as_deref(), Some("XXXXXXXX9876"));
+        assert_eq!(int.interest_income, Usd::from_cents(12_345));
+        assert_eq!(int.tax_exempt_interest, Usd::from_dollars(10));
+        let g = &import.government_payments[0];
+        assert_eq!(g.unemployment_compensation, Usd::from_dollars(4_200));
+        assert_eq!(g.refund_tax_year, Some(2024));
+        assert_eq!(import.unsupported, ["1099-B"]);
+    }
+
+    #[test]
+    fn text_without_forms_reads_nothing() {
+        assert_eq!(
+            parse_transcript("Wage and Income Transcript\nNo record of return filed\n"),
+            TranscriptImport::default()
+        );
+    }
+}
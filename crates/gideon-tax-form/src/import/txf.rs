@@ -0,0 +1,458 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use gideon_tax_core::{Date, Usd};
+
+use super::{parse_amount, parse_date};
+use crate::y2025::federal::{GainType, Irs1099B, Irs1099Div, Irs1099Int};
+
+/// A Form 1099-INT box a TXF reference number can fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterestBox {
+    /// Box 1.
+    Interest,
+    /// Box 2.
+    EarlyWithdrawalPenalty,
+    /// Box 3.
+    TreasuryInterest,
+    /// Box 4.
+    Withholding,
+    /// Box 6.
+    ForeignTax,
+    /// Box 8.
+    TaxExempt,
+}
+
+/// A Form 1099-DIV box a TXF reference number can fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DividendBox {
+    /// Box 1a.
+    Ordinary,
+    /// Box 1b.
+    Qualified,
+    /// Box 2a.
+    CapitalGainDistributions,
+    /// Box 3.
+    Nondividend,
+    /// Box 4.
+    Withholding,
+    /// Box 5.
+    Section199A,
+    /// Box 7.
+    ForeignTax,
+    /// Box 12.
+    ExemptInterest,
+}
+
+/// Where records with a reference number go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxfTarget {
+    /// One Form 1099-B per record.
+    Sale {
+        gain_type: GainType,
+        basis_reported_to_irs: bool,
+    },
+    Interest(InterestBox),
+    Dividend(DividendBox),
+}
+
+/// TXF reference numbers and the boxes they fill.
+///
+/// [`TxfMap::default`] knows the capital gain categories and the plain
+/// interest and dividend income categories. Add others that a particular
+/// exporter uses with [`insert`](TxfMap::insert).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxfMap(BTreeMap<u32, TxfTarget>);
+
+impl Default for TxfMap {
+    fn default() -> Self {
+        let sale = |gain_type, basis_reported_to_irs| TxfTarget::Sale {
+            gain_type,
+            basis_reported_to_irs,
+        };
+        TxfMap(BTreeMap::from([
+            (286, TxfTarget::Dividend(DividendBox::Ordinary)),
+            (287, TxfTarget::Interest(InterestBox::Interest)),
+            // Schedule D categories from before Form 8949.
+            (321, sale(GainType::ShortTerm, false)),
+            (323, sale(GainType::LongTerm, false)),
+            // Form 8949 boxes A, B, D, and E.
+            (711, sale(GainType::ShortTerm, true)),
+            (712, sale(GainType::ShortTerm, false)),
+            (713, sale(GainType::LongTerm, true)),
+            (714, sale(GainType::LongTerm, false)),
+        ]))
+    }
+}
+
+impl TxfMap {
+    /// Maps `reference` to `target`, replacing any earlier mapping.
+    pub fn insert(&mut self, reference: u32, target: TxfTarget) {
+        self.0.insert(reference, target);
+    }
+
+    pub fn get(&self, reference: u32) -> Option<TxfTarget> {
+        self.0.get(&reference).copied()
+    }
+}
+
+/// One record, as read.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxfRecord {
+    /// `N` line.
+    pub reference: u32,
+    /// `P` line: payer or security description.
+    pub description: String,
+    /// `D` lines, in order; `None` for `VARIOUS`.
+    pub dates: Vec<Option<Date>>,
+    /// `$` lines, in order.
+    pub amounts: Vec<Usd>,
+}
+
+/// Forms read from a TXF file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxfImport {
+    /// From the `A` header line.
+    pub exporter: String,
+    pub sales: Vec<Irs1099B>,
+    /// One form per payer, in the order each payer first appears.
+    pub interest: Vec<Irs1099Int>,
+    /// One form per payer, in the order each payer first appears.
+    pub dividends: Vec<Irs1099Div>,
+    /// Records with reference numbers the map does not know.
+    pub unmapped: Vec<TxfRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxfError {
+    /// The file does not start with a `V` version line.
+    MissingHeader,
+    /// `line` counts from 1.
+    Syntax {
+        line: usize,
+        message: &'static str,
+    },
+    InvalidValue {
+        line: usize,
+        value: String,
+    },
+}
+
+impl fmt::Display for TxfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TxfError::MissingHeader => write!(f, "not a TXF file: missing version line"),
+            TxfError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            TxfError::InvalidValue { line, value } => {
+                write!(f, "line {line}: invalid value {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TxfError {}
+
+/// Reads a Tax Exchange Format (TXF) file, version 042 or compatible.
+///
+/// Detail (`TD`) and summary (`TS`) records are both read; exporters
+/// write one or the other for a category. A sale record's dates are
+/// acquired then sold, and its amounts are cost basis, proceeds, and an
+/// optional disallowed wash sale loss. An income record's last amount is
+/// the box amount, and its `P` line names the payer. TXF carries no payer
+/// TINs or addresses, so those fields are left empty.
+pub fn parse_txf(text: &str, map: &TxfMap) -> Result<TxfImport, TxfError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+    match lines.next() {
+        Some((_, version)) if version.starts_with('V') => {}
+        _ => return Err(TxfError::MissingHeader),
+    }
+
+    let mut import = TxfImport::default();
+    // Header lines run to the first `^`.
+    for (_, line) in lines.by_ref() {
+        if line == "^" {
+            break;
+        }
+        if let Some(exporter) = line.strip_prefix('A') {
+            import.exporter = exporter.to_string();
+        }
+    }
+
+    let mut record: Option<(usize, TxfRecord)> = None;
+    for (line_no, line) in lines {
+        let (tag, value) = line.split_at(line.chars().next().map_or(0, char::len_utf8));
+        let invalid = || TxfError::InvalidValue {
+            line: line_no,
+            value: value.to_string(),
+        };
+        if line == "^" {
+            let (start, record) = record.take().ok_or(TxfError::Syntax {
+                line: line_no,
+                message: "record end without a record",
+            })?;
+            add(&mut import, map, record, start)?;
+            continue;
+        }
+        if line == "TD" || line == "TS" {
+            if record.is_some() {
+                return Err(TxfError::Syntax {
+                    line: line_no,
+                    message: "record starts before the last one ended",
+                });
+            }
+            record = Some((line_no, TxfRecord::default()));
+            continue;
+        }
+        let Some((_, current)) = record.as_mut() else {
+            return Err(TxfError::Syntax {
+                line: line_no,
+                message: "expected TD or TS",
+            });
+        };
+        match tag {
+            "N" => current.reference = value.parse().map_err(|_| invalid())?,
+            "P" => current.description = value.to_string(),
+            "D" => current
+                .dates
+                .push(if value.eq_ignore_ascii_case("various") {
+                    None
+                } else {
+                    Some(parse_date(value).ok_or_else(invalid)?)
+                }),
+            "$" => current
+                .amounts
+                .push(parse_amount(value).ok_or_else(invalid)?),
+            // Copy and line numbers, and free-form detail.
+            _ => {}
+        }
+    }
+    if let Some((start, _)) = record {
+        return Err(TxfError::Syntax {
+            line: start,
+            message: "record is not ended with ^",
+        });
+    }
+    Ok(import)
+}
+
+fn add(
+    import: &mut TxfImport,
+    map: &TxfMap,
+    record: TxfRecord,
+    start: usize,
+) -> Result<(), TxfError> {
+    let Some(target) = map.get(record.reference) else {
+        import.unmapped.push(record);
+        return Ok(());
+    };
+    let amount = |i: usize| record.amounts.get(i).copied().unwrap_or(Usd::ZERO);
+    match target {
+        TxfTarget::Sale {
+            gain_type,
+            basis_reported_to_irs,
+        } => {
+            if record.amounts.len() < 2 {
+                return Err(TxfError::Syntax {
+                    line: start,
+                    message: "sale record needs cost basis and proceeds",
+                });
+            }
+            import.sales.push(Irs1099B {
+                description: record.description.clone(),
+                date_acquired: record.dates.first().copied().flatten(),
+                date_sold: record.dates.get(1).copied().flatten(),
+                cost_basis: amount(0),
+                proceeds: amount(1),
+                wash_sale_loss_disallowed: amount(2),
+                gain_type,
+                basis_reported_to_irs,
+                ..Default::default()
+            });
+        }
+        TxfTarget::Interest(field) => {
+            let amount = record.amounts.last().copied().unwrap_or(Usd::ZERO);
+            let form = match import
+                .interest
+                .iter_mut()
+                .position(|f| f.payer_name_address == record.description)
+            {
+                Some(i) => &mut import.interest[i],
+                None => {
+                    import.interest.push(Irs1099Int {
+                        payer_name_address: record.description.clone(),
+                        ..Default::default()
+                    });
+                    import.interest.last_mut().unwrap()
+                }
+            };
+            *match field {
+                InterestBox::Interest => &mut form.interest_income,
+                InterestBox::EarlyWithdrawalPenalty => &mut form.early_withdrawal_penalty,
+                InterestBox::TreasuryInterest => &mut form.us_savings_bond_and_treasury_interest,
+                InterestBox::Withholding => &mut form.federal_income_tax_withheld,
+                InterestBox::ForeignTax => &mut form.foreign_tax_paid,
+                InterestBox::TaxExempt => &mut form.tax_exempt_interest,
+            } += amount;
+        }
+        TxfTarget::Dividend(field) => {
+            let amount = record.amounts.last().copied().unwrap_or(Usd::ZERO);
+            let form = match import
+                .dividends
+                .iter_mut()
+                .position(|f| f.payer_name_address == record.description)
+            {
+                Some(i) => &mut import.dividends[i],
+                None => {
+                    import.dividends.push(Irs1099Div {
+                        payer_name_address: record.description.clone(),
+                        ..Default::default()
+                    });
+                    import.dividends.last_mut().unwrap()
+                }
+            };
+            *match field {
+                DividendBox::Ordinary => &mut form.total_ordinary_dividends,
+                DividendBox::Qualified => &mut form.qualified_dividends,
+                DividendBox::CapitalGainDistributions => &mut form.total_capital_gain_distributions,
+                DividendBox::Nondividend => &mut form.nondividend_distributions,
+                DividendBox::Withholding => &mut form.federal_income_tax_withheld,
+                DividendBox::Section199A => &mut form.section_199a_dividends,
+                DividendBox::ForeignTax => &mut form.foreign_tax_paid,
+                DividendBox::ExemptInterest => &mut form.exempt_interest_dividends,
+            } += amount;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = "V042
+Abroker export 1.0
+D01/15/2026
+^
+TD
+N711
+C1
+L1
+P100 sh ACME
+D03/07/2024
+D11/20/2025
+$1000.00
+$1250.50
+^
+TD
+N714
+C1
+L1
+P10 sh WIDGET
+DVARIOUS
+D06/02/2025
+$2,000.00
+$1,500.00
+$120.00
+^
+TD
+N287
+C1
+L1
+PFirst Bank
+$45.10
+^
+TD
+N287
+C1
+L1
+PFirst Bank
+$4.90
+^
+TD
+N286
+C1
+L1
+PAcme Fund
+$300.00
+^
+TS
+N999
+C1
+L1
+$7.00
+^
+";
+
+    #[test]
+    fn reads_sales_and_income_by_payer() {
+        let import = parse_txf(EXPORT, &TxfMap::default()).unwrap();
+        assert_eq!(import.exporter, "broker export 1.0");
+        assert_eq!(import.sales.len(), 2);
+        let acme = &import.sales[0];
+        assert_eq!(acme.description, "100 sh ACME");
+        assert_eq!(acme.date_acquired, Date::new(2024, 3, 7));
+        assert_eq!(acme.cost_basis, Usd::from_dollars(1_000));
+        assert_eq!(acme.proceeds, Usd::from_cents(125_050));
+        assert_eq!(acme.gain_type, GainType::ShortTerm);
+        assert!(acme.basis_reported_to_irs);
+        let widget = &import.sales[1];
+        assert_eq!(widget.date_acquired, None);
+        assert_eq!(widget.wash_sale_loss_disallowed, Usd::from_dollars(120));
+        assert_eq!(widget.gain_or_loss(), Usd::from_dollars(-380));
+        assert_eq!(widget.gain_type, GainType::LongTerm);
+        assert!(!widget.basis_reported_to_irs);
+
+        assert_eq!(import.interest.len(), 1);
+        assert_eq!(import.interest[0].payer_name_address, "First Bank");
+        assert_eq!(import.interest[0].interest_income, Usd::from_dollars(50));
+        assert_eq!(
+            import.dividends[0].total_ordinary_dividends,
+            Usd::from_dollars(300)
+        );
+        assert_eq!(import.unmapped.len(), 1);
+        assert_eq!(import.unmapped[0].reference, 999);
+    }
+
+    #[test]
+    fn custom_reference_numbers() {
+        let mut map = TxfMap::default();
+        map.insert(999, TxfTarget::Dividend(DividendBox::Qualified));
+        let import = parse_txf(EXPORT, &map).unwrap();
+        assert!(import.unmapped.is_empty());
+        assert_eq!(import.dividends.len(), 2);
+        assert_eq!(
+            import.dividends[1].qualified_dividends,
+            Usd::from_dollars(7)
+        );
+    }
+
+    #[test]
+    fn malformed_files() {
+        let map = TxfMap::default();
+        assert_eq!(parse_txf("TD\n", &map), Err(TxfError::MissingHeader));
+        assert_eq!(
+            parse_txf("V042\n^\nTD\nN711\n$1\n^\n", &map),
+            Err(TxfError::Syntax {
+                line: 3,
+                message: "sale record needs cost basis and proceeds"
+            })
+        );
+        assert_eq!(
+            parse_txf("V042\n^\nTD\nN287\n$abc\n^\n", &map)
+                .unwrap_err()
+                .to_string(),
+            "line 5: invalid value \"abc\""
+        );
+        assert_eq!(
+            parse_txf("V042\n^\nTD\nN287\n$1\n", &map),
+            Err(TxfError::Syntax {
+                line: 3,
+                message: "record is not ended with ^"
+            })
+        );
+    }
+}
@@ -0,0 +1,491 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use gideon_tax_core::Usd;
+
+use super::{parse_amount, parse_csv};
+use crate::y2025::federal::{Box12Code, Box12Entry, IrsW2, W2LocalTaxGrp, W2StateTaxGrp};
+
+/// A W-2 box or part of one that a CSV column can fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum W2Field {
+    /// Box a.
+    EmployeeSsn,
+    /// Box b.
+    EmployerEin,
+    /// Box c.
+    EmployerNameAddress,
+    /// Box d.
+    ControlNumber,
+    /// Box e.
+    EmployeeName,
+    /// Box f.
+    EmployeeAddress,
+    /// Box 1.
+    Wages,
+    /// Box 2.
+    FederalWithholding,
+    /// Box 3.
+    SocialSecurityWages,
+    /// Box 4.
+    SocialSecurityTax,
+    /// Box 5.
+    MedicareWages,
+    /// Box 6.
+    MedicareTax,
+    /// Box 7.
+    SocialSecurityTips,
+    /// Box 8.
+    AllocatedTips,
+    /// Box 10.
+    DependentCareBenefits,
+    /// Box 11.
+    NonqualifiedPlans,
+    /// Box 12, any number of code and amount pairs in one column, such as
+    /// `D 1500.00; DD 8000.00`.
+    Box12,
+    /// Box 12, the amount for one code.
+    Box12Amount(Box12Code),
+    /// Box 12a–12d, the code of one row; the index counts from 0 for 12a.
+    Box12SlotCode(u8),
+    /// Box 12a–12d, the amount of one row.
+    Box12SlotAmount(u8),
+    /// Box 13.
+    StatutoryEmployee,
+    /// Box 13.
+    RetirementPlan,
+    /// Box 13.
+    ThirdPartySickPay,
+    /// Box 14a.
+    Other,
+    /// Box 15.
+    State,
+    /// Box 15.
+    EmployerStateId,
+    /// Box 16.
+    StateWages,
+    /// Box 17.
+    StateIncomeTax,
+    /// Box 18.
+    LocalWages,
+    /// Box 19.
+    LocalIncomeTax,
+    /// Box 20.
+    LocalityName,
+}
+
+/// CSV column headers and the W-2 fields they fill.
+///
+/// Headers match ignoring case, punctuation, and spacing, so
+/// `Employee's SSN` and `employees_ssn` are the same header. Columns
+/// without a mapping are ignored.
+///
+/// [`W2CsvMap::default`] combines both presets. Payroll exports that
+/// name their columns some other way can be mapped with
+/// [`insert`](W2CsvMap::insert).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct W2CsvMap(BTreeMap<String, W2Field>);
+
+impl Default for W2CsvMap {
+    fn default() -> Self {
+        let mut map = W2CsvMap::box_numbers();
+        map.0.extend(W2CsvMap::box_titles().0);
+        map
+    }
+}
+
+impl W2CsvMap {
+    pub fn new() -> Self {
+        W2CsvMap(BTreeMap::new())
+    }
+
+    /// Headers named by box, such as `Box 1`, `Box 12a Code`, and
+    /// `Box 13 Retirement Plan`.
+    pub fn box_numbers() -> Self {
+        use W2Field::*;
+        let mut map = W2CsvMap::from_pairs(&[
+            ("Box a", EmployeeSsn),
+            ("Box b", EmployerEin),
+            ("Box c", EmployerNameAddress),
+            ("Box d", ControlNumber),
+            ("Box e", EmployeeName),
+            ("Box f", EmployeeAddress),
+            ("Box 1", Wages),
+            ("Box 2", FederalWithholding),
+            ("Box 3", SocialSecurityWages),
+            ("Box 4", SocialSecurityTax),
+            ("Box 5", MedicareWages),
+            ("Box 6", MedicareTax),
+            ("Box 7", SocialSecurityTips),
+            ("Box 8", AllocatedTips),
+            ("Box 10", DependentCareBenefits),
+            ("Box 11", NonqualifiedPlans),
+            ("Box 12", Box12),
+            ("Box 13 Statutory Employee", StatutoryEmployee),
+            ("Box 13 Retirement Plan", RetirementPlan),
+            ("Box 13 Third-Party Sick Pay", ThirdPartySickPay),
+            ("Box 14", Other),
+            ("Box 15", State),
+            ("Box 15 State", State),
+            ("Box 15 Employer State ID", EmployerStateId),
+            ("Box 16", StateWages),
+            ("Box 17", StateIncomeTax),
+            ("Box 18", LocalWages),
+            ("Box 19", LocalIncomeTax),
+            ("Box 20", LocalityName),
+        ]);
+        for (slot, letter) in (0..).zip(['a', 'b', 'c', 'd']) {
+            map.insert(&format!("Box 12{letter} Code"), Box12SlotCode(slot));
+            map.insert(&format!("Box 12{letter} Amount"), Box12SlotAmount(slot));
+        }
+        for code in Box12Code::ALL {
+            map.insert(&format!("Box 12 {}", code.code()), Box12Amount(code));
+        }
+        map
+    }
+
+    /// Headers named with the box titles printed on the form, such as
+    /// `Wages, tips, other compensation`. A split name in box e, with
+    /// `Employee's first name and initial` and `Last name` columns, is
+    /// joined with a space.
+    pub fn box_titles() -> Self {
+        use W2Field::*;
+        W2CsvMap::from_pairs(&[
+            ("Employee's social security number", EmployeeSsn),
+            ("Employer identification number (EIN)", EmployerEin),
+            ("Employer identification number", EmployerEin),
+            (
+                "Employer's name, address, and ZIP code",
+                EmployerNameAddress,
+            ),
+            ("Control number", ControlNumber),
+            ("Employee's first name and initial", EmployeeName),
+            ("Last name", EmployeeName),
+            ("Employee's name", EmployeeName),
+            ("Employee's address and ZIP code", EmployeeAddress),
+            ("Wages, tips, other compensation", Wages),
+            ("Federal income tax withheld", FederalWithholding),
+            ("Social security wages", SocialSecurityWages),
+            ("Social security tax withheld", SocialSecurityTax),
+            ("Medicare wages and tips", MedicareWages),
+            ("Medicare tax withheld", MedicareTax),
+            ("Social security tips", SocialSecurityTips),
+            ("Allocated tips", AllocatedTips),
+            ("Dependent care benefits", DependentCareBenefits),
+            ("Nonqualified plans", NonqualifiedPlans),
+            ("Statutory employee", StatutoryEmployee),
+            ("Retirement plan", RetirementPlan),
+            ("Third-party sick pay", ThirdPartySickPay),
+            ("Other", Other),
+            ("State", State),
+            ("Employer's state ID number", EmployerStateId),
+            ("State wages, tips, etc.", StateWages),
+            ("State income tax", StateIncomeTax),
+            ("Local wages, tips, etc.", LocalWages),
+            ("Local income tax", LocalIncomeTax),
+            ("Locality name", LocalityName),
+        ])
+    }
+
+    fn from_pairs(pairs: &[(&str, W2Field)]) -> Self {
+        let mut map = W2CsvMap::new();
+        for &(header, field) in pairs {
+            map.insert(header, field);
+        }
+        map
+    }
+
+    /// Maps `header` to `field`, replacing any earlier mapping.
+    pub fn insert(&mut self, header: &str, field: W2Field) {
+        self.0.insert(normalize(header), field);
+    }
+
+    pub fn get(&self, header: &str) -> Option<W2Field> {
+        self.0.get(&normalize(header)).copied()
+    }
+}
+
+/// Lowercase words separated by single spaces; apostrophes are dropped
+/// so that `Employee's` and `Employees` match.
+fn normalize(header: &str) -> String {
+    header
+        .replace(['\'', '\u{2019}'], "")
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum W2CsvError {
+    /// A quoted field is never closed; `line` counts from 1.
+    UnterminatedQuote { line: usize },
+    /// No header matches the map.
+    NoMappedColumns,
+    /// `row` counts from 1 and includes the header row.
+    InvalidValue {
+        row: usize,
+        column: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for W2CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            W2CsvError::UnterminatedQuote { line } => {
+                write!(f, "line {line}: unterminated quoted field")
+            }
+            W2CsvError::NoMappedColumns => write!(f, "no column headers are W-2 fields"),
+            W2CsvError::InvalidValue { row, column, value } => {
+                write!(f, "row {row}, column {column:?}: invalid value {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for W2CsvError {}
+
+/// Reads one W-2 per row from CSV text whose first row is headers.
+///
+/// Blank rows are skipped, as are empty cells. Amounts may have a dollar
+/// sign and thousands separators. Box 13 checkboxes read `X`, `Y`, `Yes`,
+/// `True`, or `1` as checked. Each row fills at most one state row and
+/// one local row, so an employee with wages in two states appears as two
+/// rows. When several columns map to the same text field, their values
+/// are joined, names with a space and addresses with a line break; for
+/// other fields the last column wins.
+pub fn parse_w2_csv(text: &str, map: &W2CsvMap) -> Result<Vec<IrsW2>, W2CsvError> {
+    let rows = parse_csv(text).map_err(|line| W2CsvError::UnterminatedQuote { line })?;
+    let mut rows = rows.into_iter().enumerate();
+    let Some((_, headers)) = rows.next() else {
+        return Ok(Vec::new());
+    };
+    let columns: Vec<_> = headers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, header)| Some((i, header.as_str(), map.get(header)?)))
+        .collect();
+    if columns.is_empty() {
+        return Err(W2CsvError::NoMappedColumns);
+    }
+
+    let mut forms = Vec::new();
+    for (i, row) in rows {
+        if row.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        let mut builder = Builder::default();
+        for &(index, header, field) in &columns {
+            let value = row.get(index).map_or("", |cell| cell.trim());
+            if value.is_empty() {
+                continue;
+            }
+            builder
+                .set(field, value)
+                .ok_or_else(|| W2CsvError::InvalidValue {
+                    row: i + 1,
+                    column: header.to_string(),
+                    value: value.to_string(),
+                })?;
+        }
+        forms.push(builder.finish());
+    }
+    Ok(forms)
+}
+
+/// One row's W-2 while its cells are read.
+#[derive(Default)]
+struct Builder {
+    w2: IrsW2,
+    slots: [(Option<Box12Code>, Option<Usd>); 4],
+    state: W2StateTaxGrp,
+    local: W2LocalTaxGrp,
+}
+
+impl Builder {
+    /// Returns `None` if `value` does not suit `field`.
+    fn set(&mut self, field: W2Field, value: &str) -> Option<()> {
+        use W2Field::*;
+        let w2 = &mut self.w2;
+        let amount = || parse_amount(value);
+        match field {
+            EmployeeSsn => w2.employee_ssn = value.to_string(),
+            EmployerEin => w2.employer_ein = value.to_string(),
+            EmployerNameAddress => join(&mut w2.employer_name_address, "\n", value),
+            ControlNumber => w2.control_number = Some(value.to_string()),
+            EmployeeName => join(&mut w2.employee_name, " ", value),
+            EmployeeAddress => join(&mut w2.employee_address, "\n", value),
+            Wages => w2.wages_tips_other_comp = amount()?,
+            FederalWithholding => w2.federal_income_tax_withheld = amount()?,
+            SocialSecurityWages => w2.social_security_wages = amount()?,
+            SocialSecurityTax => w2.social_security_tax_withheld = amount()?,
+            MedicareWages => w2.medicare_wages_and_tips = amount()?,
+            MedicareTax => w2.medicare_tax_withheld = amount()?,
+            SocialSecurityTips => w2.social_security_tips = amount()?,
+            AllocatedTips => w2.allocated_tips = amount()?,
+            DependentCareBenefits => w2.dependent_care_benefits = amount()?,
+            NonqualifiedPlans => w2.nonqualified_plans = amount()?,
+            Box12 => w2.box_12.extend(parse_box_12(value)?),
+            Box12Amount(code) => w2.box_12.push(Box12Entry {
+                code,
+                amount: amount()?,
+            }),
+            Box12SlotCode(slot) => {
+                self.slots.get_mut(usize::from(slot))?.0 = Some(Box12Code::from_code(value)?);
+            }
+            Box12SlotAmount(slot) => self.slots.get_mut(usize::from(slot))?.1 = Some(amount()?),
+            StatutoryEmployee => w2.statutory_employee = parse_check(value)?,
+            RetirementPlan => w2.retirement_plan = parse_check(value)?,
+            ThirdPartySickPay => w2.third_party_sick_pay = parse_check(value)?,
+            Other => w2.box_14a_other = Some(value.to_string()),
+            State => self.state.state = value.to_uppercase(),
+            EmployerStateId => self.state.employer_state_id = Some(value.to_string()),
+            StateWages => self.state.state_wages = amount()?,
+            StateIncomeTax => self.state.state_income_tax = amount()?,
+            LocalWages => self.local.local_wages = amount()?,
+            LocalIncomeTax => self.local.local_income_tax = amount()?,
+            LocalityName => self.local.locality_name = value.to_string(),
+        }
+        Some(())
+    }
+
+    fn finish(mut self) -> IrsW2 {
+        for (code, amount) in self.slots {
+            if let Some(code) = code {
+                self.w2.box_12.push(Box12Entry {
+                    code,
+                    amount: amount.unwrap_or(Usd::ZERO),
+                });
+            }
+        }
+        if self.local != W2LocalTaxGrp::default() {
+            self.state.local_tax.push(self.local);
+        }
+        if self.state != W2StateTaxGrp::default() {
+            self.w2.state_tax.push(self.state);
+        }
+        self.w2
+    }
+}
+
+fn join(text: &mut String, separator: &str, value: &str) {
+    if !text.is_empty() {
+        text.push_str(separator);
+    }
+    text.push_str(value);
+}
+
+fn parse_check(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "x" | "y" | "yes" | "true" | "1" => Some(true),
+        "n" | "no" | "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Reads code and amount pairs such as `D 1500.00; DD 8000.00`,
+/// `D:1,500 | W=300`, or `D1500`.
+fn parse_box_12(value: &str) -> Option<Vec<Box12Entry>> {
+    let mut tokens = value
+        .split(|c: char| c.is_whitespace() || matches!(c, ';' | '|' | ':' | '='))
+        .filter(|token| !token.is_empty())
+        .flat_map(|token| {
+            // A code written against its amount, as in `D1500`.
+            match token.find(|c: char| c.is_ascii_digit() || c == '$' || c == '.') {
+                Some(at) if at > 0 && Box12Code::from_code(&token[..at]).is_some() => {
+                    vec![&token[..at], &token[at..]]
+                }
+                _ => vec![token],
+            }
+        });
+    let mut entries = Vec::new();
+    while let Some(code) = tokens.next() {
+        entries.push(Box12Entry {
+            code: Box12Code::from_code(code)?,
+            amount: parse_amount(tokens.next()?)?,
+        });
+    }
+    Some(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_box_number_headers_and_combined_box_12() {
+        let csv = "\
+Box a,Box b,Box c,Box e,Box 1,Box 2,Box 12,Box 13 Retirement Plan,Box 15,Box 16,Box 17
+123-45-6789,12-3456789,\"Acme Corp
+1 Main St\",Pat Doe,\"$52,000.00\",6100,\"D 1,500.00; DD 8000\",X,ca,52000,2400
+
+987-65-4321,98-7654321,Widget LLC,Sam Roe,30000,3000,W=300|C:12.50,,,,
+";
+        let forms = parse_w2_csv(csv, &W2CsvMap::default()).unwrap();
+        assert_eq!(forms.len(), 2);
+        let acme = &forms[0];
+        assert_eq!(acme.employer_name_address, "Acme Corp\n1 Main St");
+        assert_eq!(acme.wages_tips_other_comp, Usd::from_dollars(52_000));
+        assert_eq!(
+            acme.box_12,
+            [
+                Box12Entry {
+                    code: Box12Code::D,
+                    amount: Usd::from_dollars(1_500),
+                },
+                Box12Entry {
+                    code: Box12Code::DD,
+                    amount: Usd::from_dollars(8_000),
+                },
+            ]
+        );
+        assert!(acme.retirement_plan);
+        assert_eq!(acme.state_tax[0].state, "CA");
+        assert_eq!(acme.state_tax[0].state_income_tax, Usd::from_dollars(2_400));
+        let widget = &forms[1];
+        assert_eq!(widget.box_12[1].code, Box12Code::C);
+        assert_eq!(widget.box_12[1].amount, Usd::from_cents(1_250));
+        assert!(widget.state_tax.is_empty());
+    }
+
+    #[test]
+    fn reads_box_titles_slots_and_custom_headers() {
+        let mut map = W2CsvMap::default();
+        map.insert("Gross Taxable Wages", W2Field::Wages);
+        let csv = "Employee's first name and initial,Last name,Gross taxable wages,\
+Medicare tax withheld,Box 12a Code,Box 12a Amount,Box 12 W,Notes\n\
+Pat Q,Doe,45000,652.50,d,2000,450,ignored\n";
+        let w2 = &parse_w2_csv(csv, &map).unwrap()[0];
+        assert_eq!(w2.employee_name, "Pat Q Doe");
+        assert_eq!(w2.wages_tips_other_comp, Usd::from_dollars(45_000));
+        assert_eq!(w2.medicare_tax_withheld, Usd::from_cents(65_250));
+        let codes: Vec<_> = w2.box_12.iter().map(|e| (e.code, e.amount)).collect();
+        assert_eq!(
+            codes,
+            [
+                (Box12Code::W, Usd::from_dollars(450)),
+                (Box12Code::D, Usd::from_dollars(2_000))
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_bad_cells_and_unknown_layouts() {
+        assert_eq!(
+            parse_w2_csv("Name,Pay\nPat,100\n", &W2CsvMap::default()),
+            Err(W2CsvError::NoMappedColumns)
+        );
+        let err =
+            parse_w2_csv("Box 1,Box 12\n100,\"D 5; ZZ 3\"\n", &W2CsvMap::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "row 2, column \"Box 12\": invalid value \"D 5; ZZ 3\""
+        );
+        assert_eq!(
+            parse_w2_csv("Box 1\n\"100\n", &W2CsvMap::default()),
+            Err(W2CsvError::UnterminatedQuote { line: 2 })
+        );
+    }
+}
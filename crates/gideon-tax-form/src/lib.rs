@@ -1 +1,8 @@
+pub mod efile;
+pub mod fire;
+pub mod import;
+#[cfg(feature = "pdf")]
+pub mod pdf;
+#[cfg(feature = "json")]
+pub mod text;
 pub mod y2025;
@@ -0,0 +1,448 @@
+use gideon_tax_core::spine::{Key, Ledger, ReturnInput};
+use gideon_tax_core::{Date, FilingStatus, Usd};
+
+use super::{FieldValue, FieldValues};
+use crate::y2025::federal::{Form1040Identity, IrsForm1040};
+
+/// Form 1040 field values, keyed by the names in
+/// `schemas/2025/federal/return/1040.toml`.
+///
+/// Amounts are whole dollars, and zero amounts and unchecked boxes are
+/// left blank.
+pub fn form_1040_values(form: &IrsForm1040) -> FieldValues {
+    let mut v = Values::default();
+    let identity = &form.identity;
+    v.choice(
+        "filing_status",
+        filing_status_choice(identity.filing_status),
+    );
+    v.text("filing_status_name", identity.filing_status_name.as_deref());
+    v.text("first_name", Some(&identity.taxpayer.first_name));
+    v.text("last_name", Some(&identity.taxpayer.last_name));
+    v.text("ssn", Some(&identity.taxpayer.ssn));
+    if let Some(spouse) = &identity.spouse {
+        v.text("spouse_first_name", Some(&spouse.first_name));
+        v.text("spouse_last_name", Some(&spouse.last_name));
+        v.text("spouse_ssn", Some(&spouse.ssn));
+        v.check("spouse_born_before_1961", spouse.filer.is_65_or_older);
+        v.check("spouse_blind", spouse.filer.is_blind);
+    }
+    let address = &identity.address;
+    v.text("street", Some(&address.street));
+    v.text("apartment", address.apartment.as_deref());
+    v.text("city", Some(&address.city));
+    v.text("state", Some(&address.state));
+    v.text("zip", Some(&address.zip));
+    v.text("foreign_country", address.foreign_country.as_deref());
+    v.text("foreign_province", address.foreign_province.as_deref());
+    v.text(
+        "foreign_postal_code",
+        address.foreign_postal_code.as_deref(),
+    );
+    v.check("presidential_campaign", identity.presidential_campaign);
+    v.check(
+        "spouse_presidential_campaign",
+        identity.spouse_presidential_campaign,
+    );
+    v.choice(
+        "digital_assets",
+        if identity.digital_assets { "yes" } else { "no" },
+    );
+    v.check(
+        "can_be_claimed_as_dependent",
+        identity.can_be_claimed_as_dependent,
+    );
+    v.check(
+        "spouse_can_be_claimed_as_dependent",
+        identity.spouse_can_be_claimed_as_dependent,
+    );
+    v.check(
+        "spouse_itemizes_or_dual_status",
+        identity.spouse_itemizes_or_dual_status,
+    );
+    v.check("born_before_1961", identity.taxpayer.filer.is_65_or_older);
+    v.check("blind", identity.taxpayer.filer.is_blind);
+    for (i, dependent) in identity.dependents.iter().enumerate() {
+        let field = |name: &str| format!("dependents.{i}.{name}");
+        v.text(&field("first_name"), Some(&dependent.first_name));
+        v.text(&field("last_name"), Some(&dependent.last_name));
+        v.text(&field("ssn"), Some(&dependent.ssn));
+        v.text(&field("relationship"), Some(&dependent.relationship));
+        v.check(&field("lived_with_you"), dependent.lived_with_you);
+        v.check(&field("full_time_student"), dependent.full_time_student);
+        v.check(
+            &field("permanently_disabled"),
+            dependent.permanently_disabled,
+        );
+        v.check(&field("child_tax_credit"), dependent.child_tax_credit);
+        v.check(
+            &field("credit_for_other_dependents"),
+            dependent.credit_for_other_dependents,
+        );
+    }
+
+    v.amount("w2_wages", form.w2_wages);
+    v.amount("household_employee_wages", form.household_employee_wages);
+    v.amount("unreported_tips", form.unreported_tips);
+    v.amount("medicaid_waiver_payments", form.medicaid_waiver_payments);
+    v.amount("dependent_care_benefits", form.dependent_care_benefits);
+    v.amount("adoption_benefits", form.adoption_benefits);
+    v.amount("form_8919_wages", form.form_8919_wages);
+    v.amount("other_earned_income", form.other_earned_income);
+    v.amount("nontaxable_combat_pay", form.nontaxable_combat_pay);
+    v.amount("total_wages", form.total_wages);
+    v.amount("tax_exempt_interest", form.tax_exempt_interest);
+    v.amount("taxable_interest", form.taxable_interest);
+    v.amount("qualified_dividends", form.qualified_dividends);
+    v.amount("ordinary_dividends", form.ordinary_dividends);
+    v.amount("ira_distributions", form.ira_distributions);
+    v.amount("taxable_ira_distributions", form.taxable_ira_distributions);
+    v.amount("pensions_and_annuities", form.pensions_and_annuities);
+    v.amount(
+        "taxable_pensions_and_annuities",
+        form.taxable_pensions_and_annuities,
+    );
+    v.amount("social_security_benefits", form.social_security_benefits);
+    v.amount("taxable_social_security", form.taxable_social_security);
+    v.check("lump_sum_election", form.lump_sum_election);
+    v.amount("capital_gain_or_loss", form.capital_gain_or_loss);
+    v.check("schedule_d_not_required", form.schedule_d_not_required);
+    v.amount("additional_income", form.additional_income);
+    v.amount("total_income", form.total_income);
+    v.amount("adjustments_to_income", form.adjustments_to_income);
+    v.amount("adjusted_gross_income", form.adjusted_gross_income);
+    v.amount(
+        "standard_or_itemized_deduction",
+        form.standard_or_itemized_deduction,
+    );
+    v.amount("qbi_deduction", form.qbi_deduction);
+    v.amount("schedule_1a_deductions", form.schedule_1a_deductions);
+    v.amount("total_deductions", form.total_deductions);
+    v.amount("taxable_income", form.taxable_income);
+    v.amount("tax", form.tax);
+    v.check("tax_form_8814", form.tax_from_forms.form_8814);
+    v.check("tax_form_4972", form.tax_from_forms.form_4972);
+    v.text("tax_other_form", form.tax_from_forms.other_form.as_deref());
+    v.amount("schedule_2_part_1_tax", form.schedule_2_part_1_tax);
+    v.amount("tax_before_credits", form.tax_before_credits);
+    v.amount("child_tax_credit", form.child_tax_credit);
+    v.amount("schedule_3_credits", form.schedule_3_credits);
+    v.amount("total_credits", form.total_credits);
+    v.amount("tax_after_credits", form.tax_after_credits);
+    v.amount("other_taxes", form.other_taxes);
+    v.amount("total_tax", form.total_tax);
+    v.amount("w2_withholding", form.w2_withholding);
+    v.amount("form_1099_withholding", form.form_1099_withholding);
+    v.amount("other_withholding", form.other_withholding);
+    v.amount("total_withholding", form.total_withholding);
+    v.amount("estimated_tax_payments", form.estimated_tax_payments);
+    v.amount("earned_income_credit", form.earned_income_credit);
+    v.amount(
+        "additional_child_tax_credit",
+        form.additional_child_tax_credit,
+    );
+    v.amount(
+        "american_opportunity_credit",
+        form.american_opportunity_credit,
+    );
+    v.amount("schedule_3_payments", form.schedule_3_payments);
+    v.amount("total_other_payments", form.total_other_payments);
+    v.amount("total_payments", form.total_payments);
+    v.amount("overpaid", form.overpaid);
+    v.amount("refund", form.refund);
+    if let Some(deposit) = &form.direct_deposit {
+        v.text("routing_number", Some(&deposit.routing_number));
+        v.choice(
+            "savings",
+            if deposit.savings {
+                "savings"
+            } else {
+                "checking"
+            },
+        );
+        v.text("account_number", Some(&deposit.account_number));
+    }
+    v.amount("applied_to_next_year", form.applied_to_next_year);
+    v.amount("amount_owed", form.amount_owed);
+    v.amount("estimated_tax_penalty", form.estimated_tax_penalty);
+
+    if let Some(designee) = &form.third_party_designee {
+        v.text("designee_name", Some(&designee.name));
+        v.text("designee_phone", Some(&designee.phone));
+        v.text("designee_pin", Some(&designee.pin));
+    }
+    let signature = &form.signature;
+    v.date("date", signature.date);
+    v.text("occupation", Some(&signature.occupation));
+    v.text(
+        "identity_protection_pin",
+        signature.identity_protection_pin.as_deref(),
+    );
+    v.date("spouse_date", signature.spouse_date);
+    v.text("spouse_occupation", signature.spouse_occupation.as_deref());
+    v.text(
+        "spouse_identity_protection_pin",
+        signature.spouse_identity_protection_pin.as_deref(),
+    );
+    v.text("phone", signature.phone.as_deref());
+    v.text("email", signature.email.as_deref());
+    if let Some(preparer) = &form.paid_preparer {
+        v.text("preparer_name", Some(&preparer.name));
+        v.date("preparer_date", preparer.date);
+        v.text("preparer_ptin", Some(&preparer.ptin));
+        v.check("preparer_self_employed", preparer.self_employed);
+        v.text("firm_name", Some(&preparer.firm_name));
+        v.text("firm_address", Some(&preparer.firm_address));
+        v.text("firm_ein", Some(&preparer.firm_ein));
+        v.text("preparer_phone", Some(&preparer.phone));
+    }
+    v.0
+}
+
+/// Schedule 1 field values, keyed by the names in
+/// `schemas/2025/federal/return/schedule-1.toml`.
+///
+/// Part I carries the Schedule E, NOL, HSA, scholarship, and Form 8814
+/// lines of `ledger`; Part II carries the deductible part of
+/// self-employment tax in `ledger` and the adjustments in `input`. Lines 10
+/// and 26 are taken from `form` so the schedule agrees with Form 1040.
+pub fn schedule_1_values(form: &IrsForm1040, input: &ReturnInput, ledger: &Ledger) -> FieldValues {
+    let line = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let mut v = Values::default();
+    v.header(&form.identity);
+
+    v.amount("rental_real_estate", line(Key::SupplementalIncome));
+    v.amount("nol_deduction", Usd::ZERO - line(Key::NolDeduction));
+    v.amount("income_form_8889", line(Key::TaxableHsaDistributions));
+    v.amount("scholarship_grants", line(Key::TaxableScholarships));
+    let child_income = line(Key::ChildInvestmentIncome);
+    if child_income != Usd::ZERO {
+        v.text("other_income_type", Some("Form 8814"));
+        v.amount("other_income", child_income);
+    }
+    v.amount(
+        "total_other_income",
+        line(Key::TaxableHsaDistributions) + line(Key::TaxableScholarships) + child_income
+            - line(Key::NolDeduction),
+    );
+    v.amount("total_additional_income", form.additional_income);
+
+    let adjustments = &input.adjustments;
+    v.amount(
+        "business_expenses_reservists",
+        adjustments.business_expenses_reservists,
+    );
+    v.amount("hsa_deduction", adjustments.hsa_deduction);
+    v.amount("moving_expenses", adjustments.moving_expenses);
+    v.amount("se_tax_deduction", line(Key::SelfEmploymentTaxDeduction));
+    v.amount("ira_deduction", adjustments.ira_deduction);
+    v.amount(
+        "excess_deductions_67e",
+        adjustments.section_67e_excess_deductions,
+    );
+    v.amount(
+        "total_other_adjustments",
+        adjustments.section_67e_excess_deductions,
+    );
+    v.amount("total_adjustments", form.adjustments_to_income);
+    v.0
+}
+
+/// Schedule 2 field values, keyed by the names in
+/// `schemas/2025/federal/return/schedule-2.toml`: the excess advance
+/// premium tax credit repayment, self-employment tax, the tax on
+/// unreported tips, and the additional tax on HSA distributions in
+/// `ledger`, with lines 3 and 21 taken from `form`.
+pub fn schedule_2_values(form: &IrsForm1040, ledger: &Ledger) -> FieldValues {
+    let line = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let mut v = Values::default();
+    v.header(&form.identity);
+
+    let excess_ptc = line(Key::ExcessAdvancePremiumTaxCredit);
+    v.amount("excess_ptc_repayment", excess_ptc);
+    v.amount("total_additions", excess_ptc);
+    v.amount("total_part_1", form.schedule_2_part_1_tax);
+
+    v.amount("se_tax", line(Key::SelfEmploymentTax));
+    let tips_tax = line(Key::UnreportedTipsTax);
+    v.amount("unreported_ss_medicare_4137", tips_tax);
+    v.amount("total_ss_medicare", tips_tax);
+    let hsa_tax = line(Key::HsaAdditionalTax);
+    v.amount("additional_tax_hsa_dist", hsa_tax);
+    v.amount("total_line_17", hsa_tax);
+    v.amount("total_other_taxes", form.other_taxes);
+    v.0
+}
+
+/// Schedule 3 field values, keyed by the names in
+/// `schemas/2025/federal/return/schedule-3.toml`: the net premium tax
+/// credit in `ledger` and the extension payment in `input`, with lines 8
+/// and 15 taken from `form`.
+pub fn schedule_3_values(form: &IrsForm1040, input: &ReturnInput, ledger: &Ledger) -> FieldValues {
+    let line = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+    let mut v = Values::default();
+    v.header(&form.identity);
+
+    v.amount("total_nonrefundable_credits", form.schedule_3_credits);
+    v.amount("net_premium_tax_credit", line(Key::NetPremiumTaxCredit));
+    v.amount("extension_payment", input.extension_payment);
+    v.amount("total_payments_credits", form.schedule_3_payments);
+    v.0
+}
+
+/// Export value of the filing status button: the checkbox's position on
+/// the form, top to bottom.
+fn filing_status_choice(status: FilingStatus) -> &'static str {
+    match status {
+        FilingStatus::Single => "1",
+        FilingStatus::MarriedFilingJointly => "2",
+        FilingStatus::MarriedFilingSeparately => "3",
+        FilingStatus::HeadOfHousehold => "4",
+        FilingStatus::QualifyingSurvivingSpouse => "5",
+    }
+}
+
+/// Collects values, leaving out what would print as blank anyway.
+#[derive(Default)]
+struct Values(FieldValues);
+
+impl Values {
+    fn text(&mut self, name: &str, text: Option<&str>) {
+        if let Some(text) = text.filter(|t| !t.is_empty()) {
+            self.0
+                .insert(name.to_string(), FieldValue::Text(text.to_string()));
+        }
+    }
+
+    fn check(&mut self, name: &str, checked: bool) {
+        if checked {
+            self.0.insert(name.to_string(), FieldValue::Check(true));
+        }
+    }
+
+    fn choice(&mut self, name: &str, choice: &str) {
+        self.0
+            .insert(name.to_string(), FieldValue::Choice(choice.to_string()));
+    }
+
+    fn amount(&mut self, name: &str, amount: Usd) {
+        let dollars = amount.irs_round().cents() / 100;
+        if dollars != 0 {
+            self.text(name, Some(&dollars.to_string()));
+        }
+    }
+
+    fn date(&mut self, name: &str, date: Option<Date>) {
+        if let Some(date) = date {
+            let text = format!("{:02}/{:02}/{}", date.month(), date.day(), date.year());
+            self.text(name, Some(&text));
+        }
+    }
+
+    /// The name and SSN at the top of a schedule.
+    fn header(&mut self, identity: &Form1040Identity) {
+        let taxpayer = &identity.taxpayer;
+        let name = match &identity.spouse {
+            Some(spouse) if identity.filing_status == FilingStatus::MarriedFilingJointly => {
+                if spouse.last_name == taxpayer.last_name {
+                    format!(
+                        "{} & {} {}",
+                        taxpayer.first_name, spouse.first_name, taxpayer.last_name
+                    )
+                } else {
+                    format!(
+                        "{} {} & {} {}",
+                        taxpayer.first_name,
+                        taxpayer.last_name,
+                        spouse.first_name,
+                        spouse.last_name
+                    )
+                }
+            }
+            _ => format!("{} {}", taxpayer.first_name, taxpayer.last_name),
+        };
+        self.text("name", Some(name.trim()));
+        self.text("ssn", Some(&taxpayer.ssn));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::compute_spine;
+
+    use super::*;
+    use crate::y2025::federal::{Form1040Dependent, Form1040Person, test_input};
+
+    fn text(values: &FieldValues, name: &str) -> Option<String> {
+        match values.get(name) {
+            Some(FieldValue::Text(text)) => Some(text.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn form_1040_values_use_schema_names() {
+        let mut input = test_input();
+        input.w2_wages = Usd::from_cents(5_000_050);
+        input.fed_withholding = Usd::from_dollars(4_000);
+        let ledger = compute_spine(&Rules2025, &input).unwrap();
+        let identity = Form1040Identity {
+            filing_status: FilingStatus::HeadOfHousehold,
+            taxpayer: Form1040Person {
+                first_name: "Pat".to_string(),
+                last_name: "Doe".to_string(),
+                ..Default::default()
+            },
+            dependents: vec![Form1040Dependent {
+                first_name: "Sam".to_string(),
+                child_tax_credit: true,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let form = IrsForm1040::from_ledger(identity, &input, &ledger);
+        let values = form_1040_values(&form);
+        assert_eq!(values["filing_status"], FieldValue::Choice("4".to_string()));
+        assert_eq!(text(&values, "w2_wages").as_deref(), Some("50001"));
+        assert_eq!(text(&values, "w2_withholding").as_deref(), Some("4000"));
+        assert_eq!(
+            text(&values, "dependents.0.first_name").as_deref(),
+            Some("Sam")
+        );
+        assert_eq!(
+            values["dependents.0.child_tax_credit"],
+            FieldValue::Check(true)
+        );
+        assert!(!values.contains_key("household_employee_wages"));
+        assert!(!values.contains_key("blind"));
+    }
+
+    #[test]
+    fn schedules_carry_ledger_detail_to_their_totals() {
+        let mut input = test_input();
+        input.w2_wages = Usd::from_dollars(70_000);
+        input.adjustments.ira_deduction = Usd::from_dollars(7_000);
+        input.extension_payment = Usd::from_dollars(1_500);
+        let ledger = compute_spine(&Rules2025, &input).unwrap();
+        let form = IrsForm1040::from_ledger(Form1040Identity::default(), &input, &ledger);
+
+        let schedule_1 = schedule_1_values(&form, &input, &ledger);
+        assert_eq!(text(&schedule_1, "ira_deduction").as_deref(), Some("7000"));
+        assert_eq!(
+            text(&schedule_1, "total_adjustments").as_deref(),
+            Some("7000")
+        );
+
+        let schedule_3 = schedule_3_values(&form, &input, &ledger);
+        assert_eq!(
+            text(&schedule_3, "extension_payment").as_deref(),
+            Some("1500")
+        );
+        assert_eq!(
+            text(&schedule_3, "total_payments_credits"),
+            text(&form_1040_values(&form), "schedule_3_payments")
+        );
+        assert!(schedule_2_values(&form, &ledger).is_empty());
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::{BTreeMap, HashMap};
+
+use lopdf::{Dictionary, Document, Object, ObjectId};
+
+use super::{FieldValue, FieldValues, PdfError};
+
+/// A terminal field: the one that holds the value, with the widgets it is
+/// drawn by.
+struct Field {
+    id: ObjectId,
+    /// `/FT`, which a field may inherit from its parent.
+    kind: Vec<u8>,
+    widgets: Vec<ObjectId>,
+}
+
+pub(super) fn fill(
+    template: &[u8],
+    field_map: &BTreeMap<String, String>,
+    values: &FieldValues,
+) -> Result<Vec<u8>, PdfError> {
+    let mut doc = Document::load_mem(template)?;
+    let form_id = match doc.catalog()?.get(b"AcroForm") {
+        Ok(Object::Reference(id)) => Some(*id),
+        Ok(Object::Dictionary(_)) => None,
+        _ => return Err(PdfError::NoForm),
+    };
+    let roots = form(&mut doc, form_id)?
+        .get(b"Fields")
+        .and_then(Object::as_array)
+        .map_err(|_| PdfError::NoForm)?
+        .iter()
+        .filter_map(|f| f.as_reference().ok())
+        .collect::<Vec<_>>();
+
+    let mut fields = HashMap::new();
+    for root in roots {
+        collect(&doc, root, "", b"", &mut fields)?;
+    }
+    for (name, value) in values {
+        let Some(pdf_name) = field_map.get(name) else {
+            continue;
+        };
+        let field = fields
+            .get(pdf_name)
+            .ok_or_else(|| PdfError::MissingField(pdf_name.clone()))?;
+        set(&mut doc, field, value)?;
+    }
+
+    let form = form(&mut doc, form_id)?;
+    form.set("NeedAppearances", true);
+    form.remove(b"XFA");
+    let mut pdf = Vec::new();
+    doc.save_to(&mut pdf)
+        .expect("writing to memory does not fail");
+    Ok(pdf)
+}
+
+/// The `/AcroForm` dictionary, stored either as its own object or inline in
+/// the catalog.
+fn form(doc: &mut Document, id: Option<ObjectId>) -> Result<&mut Dictionary, PdfError> {
+    let form = match id {
+        Some(id) => doc.get_object_mut(id)?,
+        None => doc.catalog_mut()?.get_mut(b"AcroForm")?,
+    };
+    Ok(form.as_dict_mut()?)
+}
+
+/// Adds the terminal fields under `id` to `fields` by fully qualified name.
+fn collect(
+    doc: &Document,
+    id: ObjectId,
+    parent: &str,
+    parent_kind: &[u8],
+    fields: &mut HashMap<String, Field>,
+) -> Result<(), PdfError> {
+    let dict = doc.get_dictionary(id)?;
+    let name = match dict.get(b"T").and_then(Object::as_str) {
+        Ok(partial) => {
+            let partial = String::from_utf8_lossy(partial);
+            if parent.is_empty() {
+                partial.into_owned()
+            } else {
+                format!("{parent}.{partial}")
+            }
+        }
+        Err(_) => parent.to_string(),
+    };
+    let kind = dict
+        .get(b"FT")
+        .and_then(Object::as_name)
+        .unwrap_or(parent_kind)
+        .to_vec();
+    let kids = dict
+        .get(b"Kids")
+        .and_then(Object::as_array)
+        .map(|kids| {
+            kids.iter()
+                .filter_map(|k| k.as_reference().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let child_fields = kids
+        .iter()
+        .filter(|&&kid| doc.get_dictionary(kid).is_ok_and(|k| k.has(b"T")))
+        .copied()
+        .collect::<Vec<_>>();
+    if child_fields.is_empty() {
+        let widgets = if kids.is_empty() { vec![id] } else { kids };
+        fields.insert(name, Field { id, kind, widgets });
+    } else {
+        for kid in child_fields {
+            collect(doc, kid, &name, &kind, fields)?;
+        }
+    }
+    Ok(())
+}
+
+fn set(doc: &mut Document, field: &Field, value: &FieldValue) -> Result<(), PdfError> {
+    if field.kind != b"Btn" {
+        let text = match value {
+            FieldValue::Text(text) | FieldValue::Choice(text) => text.as_str(),
+            FieldValue::Check(true) => "X",
+            FieldValue::Check(false) => "",
+        };
+        doc.get_dictionary_mut(field.id)?
+            .set("V", text_string(text));
+        return Ok(());
+    }
+
+    // A checkbox is on in whichever appearance state is not `Off`; a radio
+    // group's buttons each have their own.
+    let mut selected = None;
+    for &widget in &field.widgets {
+        let states = on_states(doc, widget);
+        let on = match value {
+            FieldValue::Check(true) => states.into_iter().next(),
+            FieldValue::Text(choice) | FieldValue::Choice(choice) => {
+                states.into_iter().find(|s| s == choice.as_bytes())
+            }
+            FieldValue::Check(false) => None,
+        };
+        let state = on.clone().unwrap_or_else(|| b"Off".to_vec());
+        doc.get_dictionary_mut(widget)?
+            .set("AS", Object::Name(state));
+        selected = selected.or(on);
+    }
+    doc.get_dictionary_mut(field.id)?.set(
+        "V",
+        Object::Name(selected.unwrap_or_else(|| b"Off".to_vec())),
+    );
+    Ok(())
+}
+
+/// Names of a widget's normal appearances other than `Off`.
+fn on_states(doc: &Document, widget: ObjectId) -> Vec<Vec<u8>> {
+    let Ok(normal) = doc
+        .get_dictionary(widget)
+        .and_then(|w| w.get_deref(b"AP", doc))
+        .and_then(Object::as_dict)
+        .and_then(|ap| ap.get_deref(b"N", doc))
+        .and_then(Object::as_dict)
+    else {
+        return Vec::new();
+    };
+    normal
+        .iter()
+        .map(|(state, _)| state.clone())
+        .filter(|state| state != b"Off")
+        .collect()
+}
+
+/// A PDF text string: the bytes as-is for ASCII, UTF-16BE with a byte order
+/// mark otherwise.
+fn text_string(text: &str) -> Object {
+    if text.is_ascii() {
+        return Object::string_literal(text);
+    }
+    let mut bytes = vec![0xfe, 0xff];
+    for unit in text.encode_utf16() {
+        bytes.extend_from_slice(&unit.to_be_bytes());
+    }
+    Object::string_literal(bytes)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use lopdf::{Stream, dictionary};
+
+    use super::*;
+
+    /// A one-page PDF with a text field nested under `form[0]`, a checkbox,
+    /// and a two-button radio group.
+    pub(crate) fn template() -> Vec<u8> {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let page_id = doc.new_object_id();
+        let appearance = doc.add_object(Stream::new(Dictionary::new(), Vec::new()));
+        let states = |on: &str| {
+            Object::Dictionary(dictionary! {
+                "N" => dictionary! { on => appearance, "Off" => appearance },
+            })
+        };
+
+        let parent_id = doc.new_object_id();
+        let wages_id = doc.add_object(dictionary! {
+            "Type" => "Annot", "Subtype" => "Widget", "FT" => "Tx",
+            "T" => Object::string_literal("f1_32[0]"), "Parent" => parent_id,
+            "Rect" => vec![0.into(), 0.into(), 100.into(), 20.into()], "P" => page_id,
+        });
+        doc.objects.insert(
+            parent_id,
+            Object::Dictionary(dictionary! {
+                "T" => Object::string_literal("form[0]"),
+                "Kids" => vec![wages_id.into()],
+            }),
+        );
+        let check_id = doc.add_object(dictionary! {
+            "Type" => "Annot", "Subtype" => "Widget", "FT" => "Btn",
+            "T" => Object::string_literal("c1_1[0]"), "AP" => states("1"), "AS" => "Off",
+            "Rect" => vec![0.into(), 30.into(), 10.into(), 40.into()], "P" => page_id,
+        });
+        let radio_id = doc.new_object_id();
+        let buttons = ["1", "2"]
+            .iter()
+            .map(|on| {
+                doc.add_object(dictionary! {
+                    "Type" => "Annot", "Subtype" => "Widget", "Parent" => radio_id,
+                    "AP" => states(on), "AS" => "Off", "P" => page_id,
+                    "Rect" => vec![0.into(), 50.into(), 10.into(), 60.into()],
+                })
+            })
+            .collect::<Vec<_>>();
+        doc.objects.insert(
+            radio_id,
+            Object::Dictionary(dictionary! {
+                "FT" => "Btn", "Ff" => 49152, "T" => Object::string_literal("c1_3"),
+                "Kids" => buttons.iter().map(|&b| b.into()).collect::<Vec<Object>>(),
+            }),
+        );
+
+        let mut annots: Vec<Object> = vec![wages_id.into(), check_id.into()];
+        annots.extend(buttons.iter().map(|&b| Object::from(b)));
+        doc.objects.insert(
+            page_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Page", "Parent" => pages_id, "Annots" => annots,
+                "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            }),
+        );
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages", "Kids" => vec![page_id.into()], "Count" => 1,
+            }),
+        );
+        let form_id = doc.add_object(dictionary! {
+            "Fields" => vec![parent_id.into(), check_id.into(), radio_id.into()],
+            "XFA" => Object::string_literal("<xdp/>"),
+        });
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog", "Pages" => pages_id, "AcroForm" => form_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        let mut pdf = Vec::new();
+        doc.save_to(&mut pdf).unwrap();
+        pdf
+    }
+
+    pub(crate) fn field_map() -> BTreeMap<String, String> {
+        [
+            ("w2_wages", "form[0].f1_32[0]"),
+            ("presidential_campaign", "c1_1[0]"),
+            ("filing_status", "c1_3"),
+        ]
+        .into_iter()
+        .map(|(schema, pdf)| (schema.to_string(), pdf.to_string()))
+        .collect()
+    }
+
+    /// `(/V, /AS)` of each field and widget, by `/T` or object number.
+    pub(crate) fn read_values(pdf: &[u8]) -> BTreeMap<String, String> {
+        let doc = Document::load_mem(pdf).unwrap();
+        let mut values = BTreeMap::new();
+        for (id, object) in &doc.objects {
+            let Ok(dict) = object.as_dict() else { continue };
+            let key = match dict.get(b"T").and_then(Object::as_str) {
+                Ok(t) => String::from_utf8_lossy(t).into_owned(),
+                Err(_) => id.0.to_string(),
+            };
+            for (entry, suffix) in [(&b"V"[..], ""), (b"AS", "#AS")] {
+                let value = match dict.get(entry) {
+                    Ok(Object::String(s, _)) => String::from_utf8_lossy(s).into_owned(),
+                    Ok(Object::Name(n)) => format!("/{}", String::from_utf8_lossy(n)),
+                    _ => continue,
+                };
+                values.insert(format!("{key}{suffix}"), value);
+            }
+        }
+        values
+    }
+
+    #[test]
+    fn fills_text_checkbox_and_radio_fields() {
+        let values = FieldValues::from([
+            (
+                "w2_wages".to_string(),
+                FieldValue::Text("50001".to_string()),
+            ),
+            ("presidential_campaign".to_string(), FieldValue::Check(true)),
+            (
+                "filing_status".to_string(),
+                FieldValue::Choice("2".to_string()),
+            ),
+            (
+                "unmapped".to_string(),
+                FieldValue::Text("left off".to_string()),
+            ),
+        ]);
+        let pdf = fill(&template(), &field_map(), &values).unwrap();
+        let read = read_values(&pdf);
+        assert_eq!(read["f1_32[0]"], "50001");
+        assert_eq!(read["c1_1[0]"], "/1");
+        assert_eq!(read["c1_1[0]#AS"], "/1");
+        assert_eq!(read["c1_3"], "/2");
+        let mut states = read
+            .iter()
+            .filter(|(k, _)| k.ends_with("#AS") && !k.starts_with('c'))
+            .map(|(_, v)| v.as_str())
+            .collect::<Vec<_>>();
+        states.sort();
+        assert_eq!(states, ["/2", "/Off"]);
+        assert!(!read.values().any(|v| v == "left off"));
+
+        let doc = Document::load_mem(&pdf).unwrap();
+        let form = doc
+            .catalog()
+            .unwrap()
+            .get_deref(b"AcroForm", &doc)
+            .unwrap()
+            .as_dict()
+            .unwrap();
+        assert!(matches!(
+            form.get(b"NeedAppearances"),
+            Ok(Object::Boolean(true))
+        ));
+        assert!(!form.has(b"XFA"));
+    }
+
+    #[test]
+    fn missing_fields_and_forms_are_errors() {
+        let mut map = field_map();
+        map.insert("w2_wages".to_string(), "form[0].f1_99[0]".to_string());
+        let values =
+            FieldValues::from([("w2_wages".to_string(), FieldValue::Text("1".to_string()))]);
+        let err = fill(&template(), &map, &values).unwrap_err();
+        assert_eq!(err.to_string(), "PDF has no field form[0].f1_99[0]");
+
+        let mut doc = Document::load_mem(&template()).unwrap();
+        doc.catalog_mut().unwrap().remove(b"AcroForm");
+        let mut pdf = Vec::new();
+        doc.save_to(&mut pdf).unwrap();
+        assert!(matches!(fill(&pdf, &map, &values), Err(PdfError::NoForm)));
+        assert!(matches!(
+            fill(b"not a pdf", &map, &values),
+            Err(PdfError::Pdf(_))
+        ));
+    }
+}
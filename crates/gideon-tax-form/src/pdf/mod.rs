@@ -0,0 +1,91 @@
+//! Filling the official fillable IRS PDFs to print a return.
+//!
+//! Values are keyed by the field names in the form's schema under
+//! `schemas/`, such as `w2_wages` or `dependents.0.first_name`. Each
+//! [`Template`] pairs a blank PDF downloaded from irs.gov with a map from
+//! those names to the PDF's own AcroForm field names, which the IRS
+//! renumbers with each revision. Neither the PDFs nor the maps are shipped
+//! with this crate.
+//!
+//! See: <https://www.irs.gov/forms-instructions>
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+mod fields;
+mod fill;
+mod package;
+mod worksheet;
+
+pub use fields::{form_1040_values, schedule_1_values, schedule_2_values, schedule_3_values};
+pub use package::{PackageDocument, ReturnPackage, Templates, return_package};
+pub use worksheet::w2_worksheet;
+
+/// What to put in one field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    Text(String),
+    /// A checkbox, set to its on state when `true`. A text field gets `X`.
+    Check(bool),
+    /// One choice of a radio group, by the export value of its button, such
+    /// as `2` for the married filing jointly box.
+    Choice(String),
+}
+
+/// Field values keyed by schema field name.
+pub type FieldValues = BTreeMap<String, FieldValue>;
+
+/// A blank fillable PDF and where each schema field goes on it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Template {
+    pub pdf: Vec<u8>,
+    /// Schema field name to fully qualified AcroForm field name, such as
+    /// `w2_wages` to `topmostSubform[0].Page1[0].f1_32[0]`. Values with no
+    /// entry are left off the printed form.
+    pub fields: BTreeMap<String, String>,
+}
+
+impl Template {
+    /// A copy of the PDF with `values` filled in.
+    ///
+    /// Viewers are asked to redraw the fields, so filled values show
+    /// without generated appearance streams. Any XFA form in the template
+    /// is removed so viewers use the filled AcroForm fields.
+    pub fn fill(&self, values: &FieldValues) -> Result<Vec<u8>, PdfError> {
+        fill::fill(&self.pdf, &self.fields, values)
+    }
+}
+
+#[derive(Debug)]
+pub enum PdfError {
+    Pdf(lopdf::Error),
+    /// The PDF has no interactive form.
+    NoForm,
+    /// The field map names a field the PDF does not have.
+    MissingField(String),
+}
+
+impl fmt::Display for PdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PdfError::Pdf(e) => write!(f, "invalid PDF: {e}"),
+            PdfError::NoForm => write!(f, "PDF has no fillable fields"),
+            PdfError::MissingField(name) => write!(f, "PDF has no field {name}"),
+        }
+    }
+}
+
+impl std::error::Error for PdfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PdfError::Pdf(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<lopdf::Error> for PdfError {
+    fn from(e: lopdf::Error) -> Self {
+        PdfError::Pdf(e)
+    }
+}
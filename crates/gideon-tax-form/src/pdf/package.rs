@@ -0,0 +1,123 @@
+use gideon_tax_core::Usd;
+use gideon_tax_core::spine::{Ledger, ReturnInput};
+
+use super::{
+    PdfError, Template, form_1040_values, schedule_1_values, schedule_2_values, schedule_3_values,
+    w2_worksheet,
+};
+use crate::y2025::federal::{IrsForm1040, IrsW2};
+
+/// The blank forms a return package is filled from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Templates {
+    pub form_1040: Template,
+    pub schedule_1: Template,
+    pub schedule_2: Template,
+    pub schedule_3: Template,
+}
+
+/// One filled form in a [`ReturnPackage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageDocument {
+    /// Form name as printed, such as `Schedule 1 (Form 1040)`.
+    pub name: &'static str,
+    pub pdf: Vec<u8>,
+}
+
+/// Filled forms in the order they are assembled for mailing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReturnPackage {
+    pub documents: Vec<PackageDocument>,
+}
+
+/// Fills Form 1040 and, when lines 8, 10, 17, 20, 23, or 31 carry an
+/// amount, the schedules behind them, in attachment sequence order, then
+/// adds the W-2 worksheet when there are `w2s`.
+///
+/// `form` should come from [`IrsForm1040::from_ledger`] for the same
+/// `input` and `ledger`, so the schedules' totals agree with it. Copy B of
+/// each W-2 is attached to a paper return separately.
+pub fn return_package(
+    templates: &Templates,
+    form: &IrsForm1040,
+    input: &ReturnInput,
+    ledger: &Ledger,
+    w2s: &[IrsW2],
+) -> Result<ReturnPackage, PdfError> {
+    let mut documents = vec![PackageDocument {
+        name: "Form 1040",
+        pdf: templates.form_1040.fill(&form_1040_values(form))?,
+    }];
+    if form.additional_income != Usd::ZERO || form.adjustments_to_income != Usd::ZERO {
+        documents.push(PackageDocument {
+            name: "Schedule 1 (Form 1040)",
+            pdf: templates
+                .schedule_1
+                .fill(&schedule_1_values(form, input, ledger))?,
+        });
+    }
+    if form.schedule_2_part_1_tax != Usd::ZERO || form.other_taxes != Usd::ZERO {
+        documents.push(PackageDocument {
+            name: "Schedule 2 (Form 1040)",
+            pdf: templates
+                .schedule_2
+                .fill(&schedule_2_values(form, ledger))?,
+        });
+    }
+    if form.schedule_3_credits != Usd::ZERO || form.schedule_3_payments != Usd::ZERO {
+        documents.push(PackageDocument {
+            name: "Schedule 3 (Form 1040)",
+            pdf: templates
+                .schedule_3
+                .fill(&schedule_3_values(form, input, ledger))?,
+        });
+    }
+    if !w2s.is_empty() {
+        documents.push(PackageDocument {
+            name: "Form W-2 Worksheet",
+            pdf: w2_worksheet(form, w2s)?,
+        });
+    }
+    Ok(ReturnPackage { documents })
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::compute_spine;
+
+    use super::*;
+    use crate::pdf::fill::tests::{field_map, read_values, template};
+    use crate::y2025::federal::{Form1040Identity, test_input};
+
+    #[test]
+    fn fills_only_the_schedules_in_use() {
+        let mut input = test_input();
+        input.w2_wages = Usd::from_dollars(50_000);
+        input.fed_withholding = Usd::from_dollars(5_000);
+        input.extension_payment = Usd::from_dollars(500);
+        let ledger = compute_spine(&Rules2025, &input).unwrap();
+        let form = IrsForm1040::from_ledger(Form1040Identity::default(), &input, &ledger);
+        let blank = Template {
+            pdf: template(),
+            fields: field_map(),
+        };
+        let templates = Templates {
+            form_1040: blank.clone(),
+            schedule_1: blank.clone(),
+            schedule_2: blank.clone(),
+            schedule_3: blank,
+        };
+        let w2s = [IrsW2::default()];
+
+        let package = return_package(&templates, &form, &input, &ledger, &w2s).unwrap();
+        let names = package.documents.iter().map(|d| d.name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["Form 1040", "Schedule 3 (Form 1040)", "Form W-2 Worksheet"]
+        );
+        let form_1040 = read_values(&package.documents[0].pdf);
+        assert_eq!(form_1040["f1_32[0]"], "50000");
+        assert_eq!(form_1040["c1_3"], "/1");
+    }
+}
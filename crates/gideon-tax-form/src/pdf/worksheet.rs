@@ -0,0 +1,163 @@
+use lopdf::content::{Content, Operation};
+use lopdf::{Document, Object, Stream, dictionary};
+
+use gideon_tax_core::Usd;
+
+use super::PdfError;
+use crate::y2025::federal::{IrsForm1040, IrsW2};
+
+/// Lines of text per page.
+const LINES_PER_PAGE: usize = 54;
+
+/// A worksheet listing each W-2's box 1 wages and box 2 withholding, with
+/// totals tied to Form 1040, lines 1a and 25a.
+///
+/// The IRS has no form for this; it is printed on letter paper for the
+/// taxpayer's records and for reviewing the return.
+pub fn w2_worksheet(form: &IrsForm1040, w2s: &[IrsW2]) -> Result<Vec<u8>, PdfError> {
+    let taxpayer = &form.identity.taxpayer;
+    let mut lines = vec![
+        "Form W-2 Wage and Withholding Worksheet - 2025".to_string(),
+        String::new(),
+        format!(
+            "{} {}    {}",
+            taxpayer.first_name, taxpayer.last_name, taxpayer.ssn
+        ),
+        String::new(),
+        format!(
+            "{:<34} {:<12} {:>16} {:>16}",
+            "Employer", "EIN", "Box 1 wages", "Box 2 withheld"
+        ),
+    ];
+    for w2 in w2s {
+        let employer = w2.employer_name_address.lines().next().unwrap_or_default();
+        lines.push(format!(
+            "{:<34} {:<12} {:>16} {:>16}",
+            truncate(employer, 34),
+            truncate(&w2.employer_ein, 12),
+            w2.wages_tips_other_comp.to_string(),
+            w2.federal_income_tax_withheld.to_string(),
+        ));
+    }
+    let wages = w2s.iter().map(|w| w.wages_tips_other_comp).sum::<Usd>();
+    let withheld = w2s
+        .iter()
+        .map(|w| w.federal_income_tax_withheld)
+        .sum::<Usd>();
+    lines.push(format!(
+        "{:<47} {:>16} {:>16}",
+        "Total",
+        wages.to_string(),
+        withheld.to_string()
+    ));
+    lines.push(String::new());
+    lines.push(format!(
+        "{:<64} {:>16}",
+        "Form 1040, line 1a",
+        form.w2_wages.to_string()
+    ));
+    lines.push(format!(
+        "{:<64} {:>16}",
+        "Form 1040, line 25a",
+        form.w2_withholding.to_string()
+    ));
+    text_pdf(&lines)
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    text.chars().take(width).collect()
+}
+
+/// Letter-size pages of `lines` in 10-point Courier.
+fn text_pdf(lines: &[String]) -> Result<Vec<u8>, PdfError> {
+    let mut doc = Document::with_version("1.7");
+    let pages_id = doc.new_object_id();
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Courier",
+        "Encoding" => "WinAnsiEncoding",
+    });
+    let resources_id = doc.add_object(dictionary! {
+        "Font" => dictionary! { "F1" => font_id },
+    });
+
+    let mut pages = Vec::new();
+    for chunk in lines.chunks(LINES_PER_PAGE) {
+        let mut operations = vec![
+            Operation::new("BT", vec![]),
+            Operation::new("Tf", vec!["F1".into(), 10.into()]),
+            Operation::new("TL", vec![13.into()]),
+            Operation::new("Td", vec![54.into(), 740.into()]),
+        ];
+        for line in chunk {
+            // Courier's WinAnsi encoding covers Latin-1; anything else
+            // prints as a question mark.
+            let bytes = line
+                .chars()
+                .map(|c| u8::try_from(u32::from(c)).unwrap_or(b'?'))
+                .collect::<Vec<_>>();
+            operations.push(Operation::new("Tj", vec![Object::string_literal(bytes)]));
+            operations.push(Operation::new("T*", vec![]));
+        }
+        operations.push(Operation::new("ET", vec![]));
+        let content_id = doc.add_object(Stream::new(
+            dictionary! {},
+            Content { operations }.encode()?,
+        ));
+        pages.push(Object::from(doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+        })));
+    }
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Count" => pages.len() as i64,
+            "Kids" => pages,
+            "Resources" => resources_id,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+        }),
+    );
+    let catalog_id = doc.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => pages_id,
+    });
+    doc.trailer.set("Root", catalog_id);
+    let mut pdf = Vec::new();
+    doc.save_to(&mut pdf)
+        .expect("writing to memory does not fail");
+    Ok(pdf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_each_w2_and_ties_totals_to_form_1040() {
+        let w2 = |employer: &str, wages: i64, withheld: i64| IrsW2 {
+            employer_name_address: format!("{employer}\n1 Main St"),
+            employer_ein: "12-3456789".to_string(),
+            wages_tips_other_comp: Usd::from_dollars(wages),
+            federal_income_tax_withheld: Usd::from_dollars(withheld),
+            ..Default::default()
+        };
+        let w2s = [w2("Acme Corp", 40_000, 4_000), w2("Café Rio", 10_000, 900)];
+        let form = IrsForm1040 {
+            w2_wages: Usd::from_dollars(50_000),
+            w2_withholding: Usd::from_dollars(4_900),
+            ..Default::default()
+        };
+        let pdf = w2_worksheet(&form, &w2s).unwrap();
+        let doc = Document::load_mem(&pdf).unwrap();
+        assert_eq!(doc.get_pages().len(), 1);
+        let text = doc.extract_text(&[1]).unwrap();
+        assert!(text.contains("Acme Corp"), "{text}");
+        assert!(text.contains("Caf\u{e9} Rio"), "{text}");
+        assert!(text.contains("$50000.00"), "{text}");
+        assert!(text.contains("$4900.00"), "{text}");
+    }
+}
@@ -0,0 +1,345 @@
+//! A line-oriented text form of any serializable return data, for keeping
+//! returns under version control and diffing them between software
+//! versions or amendment drafts.
+//!
+//! Each line holds one value as `path = value`, where the path names object
+//! fields with `.` and list items with `[index]`, and the value is JSON.
+//! Fields are sorted by name and list items kept in order, so the same data
+//! always produces the same text and a changed amount changes one line.
+//! Amounts are in cents, as they serialize.
+//!
+//! ```text
+//! # gideon-tax text v1
+//! carryovers.capital_loss.long_term = 400000
+//! identity.filing_status = "MarriedFilingJointly"
+//! source_forms.w2[0].box_12 = []
+//! ```
+
+use std::fmt;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// First line of every document.
+const HEADER: &str = "# gideon-tax text v1";
+
+#[derive(Debug)]
+pub enum TextError {
+    /// A line is not `path = value`; `line` counts from 1.
+    Syntax { line: usize, message: &'static str },
+    /// Two lines give the same path different shapes, such as a field
+    /// and a list item.
+    Conflict { line: usize },
+    /// The lines parsed but do not deserialize as the requested type.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TextError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            TextError::Conflict { line } => {
+                write!(f, "line {line}: conflicts with an earlier line")
+            }
+            TextError::Json(e) => write!(f, "invalid return data: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for TextError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TextError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for TextError {
+    fn from(e: serde_json::Error) -> Self {
+        TextError::Json(e)
+    }
+}
+
+/// Writes `value` one scalar per line, ending with a newline.
+///
+/// Empty lists and objects are written as `[]` and `{}` so that a field
+/// going from empty to filled shows in a diff.
+pub fn to_text<T: Serialize>(value: &T) -> Result<String, TextError> {
+    let mut out = format!("{HEADER}\n");
+    write_value(&mut out, &mut String::new(), &serde_json::to_value(value)?);
+    Ok(out)
+}
+
+/// Reads text written by [`to_text`]. Blank lines and lines starting with
+/// `#` are skipped. Lines may be in any order, except that the items of a
+/// list must appear in index order.
+pub fn from_text<T: DeserializeOwned>(text: &str) -> Result<T, TextError> {
+    let mut root = None;
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let syntax = |message| TextError::Syntax {
+            line: line_no,
+            message,
+        };
+        let (path, rest) = parse_path(line).map_err(syntax)?;
+        let json = rest
+            .trim_start()
+            .strip_prefix('=')
+            .ok_or(syntax("expected `=` after the path"))?;
+        let value = serde_json::from_str(json.trim()).map_err(|_| syntax("invalid value"))?;
+        insert(&mut root, &path, value).map_err(|()| TextError::Conflict { line: line_no })?;
+    }
+    Ok(serde_json::from_value(root.unwrap_or(Value::Null))?)
+}
+
+fn write_value(out: &mut String, path: &mut String, value: &Value) {
+    let len = path.len();
+    match value {
+        Value::Object(fields) if !fields.is_empty() => {
+            for (name, field) in fields {
+                push_field(path, name);
+                write_value(out, path, field);
+                path.truncate(len);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (i, item) in items.iter().enumerate() {
+                path.push_str(&format!("[{i}]"));
+                write_value(out, path, item);
+                path.truncate(len);
+            }
+        }
+        _ => {
+            out.push_str(path);
+            out.push_str(" = ");
+            out.push_str(&value.to_string());
+            out.push('\n');
+        }
+    }
+}
+
+/// Appends `.name`, or `["name"]` when the name is not a plain identifier.
+fn push_field(path: &mut String, name: &str) {
+    let plain = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if plain {
+        if !path.is_empty() {
+            path.push('.');
+        }
+        path.push_str(name);
+    } else {
+        path.push('[');
+        path.push_str(&Value::from(name).to_string());
+        path.push(']');
+    }
+}
+
+enum Segment {
+    Field(String),
+    Item(usize),
+}
+
+/// Splits a line into its path and what follows it.
+fn parse_path(line: &str) -> Result<(Vec<Segment>, &str), &'static str> {
+    let mut path = Vec::new();
+    let mut rest = line;
+    loop {
+        if let Some(after) = rest.strip_prefix("[\"") {
+            // A quoted name ends at the first unescaped quote.
+            let mut escaped = false;
+            let end = after
+                .char_indices()
+                .find(|&(_, c)| {
+                    let end = c == '"' && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    end
+                })
+                .map(|(i, _)| i)
+                .ok_or("unterminated field name")?;
+            let name = serde_json::from_str(&rest[1..end + 3]).map_err(|_| "invalid field name")?;
+            path.push(Segment::Field(name));
+            rest = after[end + 1..]
+                .strip_prefix(']')
+                .ok_or("expected `]` after a field name")?;
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']').ok_or("expected `]` after an index")?;
+            let index = after[..end].parse().map_err(|_| "invalid index")?;
+            path.push(Segment::Item(index));
+            rest = &after[end + 1..];
+        } else if path.is_empty() || rest.starts_with('.') {
+            let name = rest.strip_prefix('.').unwrap_or(rest);
+            let end = name
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(name.len());
+            if end == 0 {
+                if path.is_empty() {
+                    return Ok((path, rest));
+                }
+                return Err("expected a field name after `.`");
+            }
+            path.push(Segment::Field(name[..end].to_string()));
+            rest = &name[end..];
+        } else {
+            return Ok((path, rest));
+        }
+    }
+}
+
+/// Sets the value at `path`, creating objects and lists along the way. List
+/// items must appear in order.
+fn insert(slot: &mut Option<Value>, path: &[Segment], value: Value) -> Result<(), ()> {
+    let Some((first, rest)) = path.split_first() else {
+        return match slot {
+            None => {
+                *slot = Some(value);
+                Ok(())
+            }
+            Some(_) => Err(()),
+        };
+    };
+    match first {
+        Segment::Field(name) => {
+            let fields = match slot.get_or_insert_with(|| Value::Object(Map::new())) {
+                Value::Object(fields) if !(rest.is_empty() && fields.contains_key(name)) => fields,
+                _ => return Err(()),
+            };
+            let mut child = fields.remove(name);
+            let result = insert(&mut child, rest, value);
+            if let Some(child) = child {
+                fields.insert(name.clone(), child);
+            }
+            result
+        }
+        Segment::Item(index) => {
+            let items = match slot.get_or_insert_with(|| Value::Array(Vec::new())) {
+                Value::Array(items) if *index <= items.len() => items,
+                _ => return Err(()),
+            };
+            if *index == items.len() {
+                let mut child = None;
+                insert(&mut child, rest, value)?;
+                items.push(child.unwrap_or(Value::Null));
+                Ok(())
+            } else {
+                let mut child = Some(std::mem::take(&mut items[*index]));
+                let result = insert(&mut child, rest, value);
+                items[*index] = child.unwrap_or(Value::Null);
+                result
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use gideon_tax_core::Usd;
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::{Ledger, compute_spine};
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::y2025::federal::{
+        Form1040Identity, IrsForm1040, IrsW2, ReturnBundle, SourceForms, test_input,
+    };
+
+    /// What an app would keep for a return: the inputs and what was
+    /// computed from them.
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Snapshot {
+        bundle: ReturnBundle,
+        form_1040: IrsForm1040,
+        ledger: Ledger,
+    }
+
+    fn snapshot(wages: i64) -> Snapshot {
+        let bundle = ReturnBundle {
+            source_forms: SourceForms {
+                w2: vec![IrsW2 {
+                    employer_name_address: "Acme\n1 Main St".to_string(),
+                    wages_tips_other_comp: Usd::from_dollars(wages),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut input = test_input();
+        bundle.apply_to(&mut input);
+        let ledger = compute_spine(&Rules2025, &input).unwrap();
+        let form_1040 = IrsForm1040::from_ledger(Form1040Identity::default(), &input, &ledger);
+        Snapshot {
+            bundle,
+            form_1040,
+            ledger,
+        }
+    }
+
+    #[test]
+    fn round_trips_a_full_return() {
+        let snapshot = snapshot(50_000);
+        let text = to_text(&snapshot).unwrap();
+        assert!(text.starts_with("# gideon-tax text v1\n"), "{text}");
+        assert!(
+            text.contains(
+                "bundle.source_forms.w2[0].employer_name_address = \"Acme\\n1 Main St\"\n"
+            ),
+            "{text}"
+        );
+        assert!(
+            text.contains("bundle.source_forms.w2[0].box_12 = []\n"),
+            "{text}"
+        );
+        assert!(text.contains("form_1040.w2_wages = 5000000\n"), "{text}");
+        assert!(text.contains("ledger.AGI = 5000000\n"), "{text}");
+        assert_eq!(from_text::<Snapshot>(&text).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn a_changed_amount_changes_only_its_lines() {
+        let before = to_text(&snapshot(50_000)).unwrap();
+        let after = to_text(&snapshot(50_001)).unwrap();
+        assert_eq!(before.lines().count(), after.lines().count());
+        let changed = before
+            .lines()
+            .zip(after.lines())
+            .filter(|(b, a)| b != a)
+            .map(|(_, a)| a.split(" = ").next().unwrap())
+            .collect::<Vec<_>>();
+        assert!(changed.contains(&"bundle.source_forms.w2[0].wages_tips_other_comp"));
+        assert!(
+            changed
+                .iter()
+                .all(|path| !path.starts_with("bundle.identity"))
+        );
+    }
+
+    #[test]
+    fn quotes_odd_field_names_and_reports_bad_lines() {
+        let map = BTreeMap::from([("state returns".to_string(), vec![1, 2])]);
+        let text = to_text(&map).unwrap();
+        assert_eq!(
+            text,
+            "# gideon-tax text v1\n[\"state returns\"][0] = 1\n[\"state returns\"][1] = 2\n"
+        );
+        assert_eq!(from_text::<BTreeMap<String, Vec<i32>>>(&text).unwrap(), map);
+
+        let err = from_text::<Value>("a = 1\nb 2\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: expected `=` after the path");
+        let err = from_text::<Value>("a = 1\na.b = 2\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: conflicts with an earlier line");
+        assert!(matches!(
+            from_text::<Value>("a[1] = 1\n"),
+            Err(TextError::Conflict { line: 1 })
+        ));
+    }
+}
@@ -1 +0,0 @@
-// 2025 federal tax form types will be generated from schemas.
@@ -0,0 +1,431 @@
+use std::collections::BTreeMap;
+
+use gideon_tax_core::Usd;
+use gideon_tax_core::spine::ReturnInput;
+
+use super::SourceForms;
+
+const REDACTED: &str = "REDACTED";
+
+/// Largest perturbation of amounts, in basis points (1%).
+const MAX_PERTURBATION_BPS: i64 = 100;
+
+/// Masks identifiers in a return's forms and input so they can be attached
+/// to a bug report.
+///
+/// TINs are replaced with sequential placeholders that keep their
+/// punctuation, and the same TIN always maps to the same placeholder, so
+/// forms issued to one person still match. Names, addresses, and account
+/// numbers are replaced with `REDACTED`. Amounts are left alone unless a
+/// perturbation seed is set.
+#[derive(Debug, Clone, Default)]
+pub struct Anonymizer {
+    perturbation_seed: Option<u64>,
+    tins: BTreeMap<String, String>,
+}
+
+impl Anonymizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also scales every amount in [`anonymize_input`](Self::anonymize_input)
+    /// by a factor within 1% of one, derived from `seed`.
+    pub fn with_perturbation(seed: u64) -> Self {
+        Anonymizer {
+            perturbation_seed: Some(seed),
+            tins: BTreeMap::new(),
+        }
+    }
+
+    /// Replaces `tin` with its placeholder: the digits are renumbered in
+    /// order of first appearance and everything else is kept. Empty TINs
+    /// stay empty.
+    pub fn mask_tin(&mut self, tin: &str) -> String {
+        if tin.trim().is_empty() {
+            return String::new();
+        }
+        let next = self.tins.len() + 1;
+        self.tins
+            .entry(tin.trim().to_string())
+            .or_insert_with(|| {
+                let digits = tin.chars().filter(char::is_ascii_digit).count();
+                let mut number = format!("{next:0digits$}").into_bytes().into_iter();
+                tin.trim()
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_digit() {
+                            number.next().map_or('0', char::from)
+                        } else {
+                            c
+                        }
+                    })
+                    .collect()
+            })
+            .clone()
+    }
+
+    /// Masks identifiers on every form.
+    pub fn anonymize_forms(&mut self, forms: &mut SourceForms) {
+        for f in &mut forms.w2 {
+            f.employee_ssn = self.mask_tin(&f.employee_ssn);
+            f.employer_ein = self.mask_tin(&f.employer_ein);
+            redact(&mut f.employer_name_address);
+            redact_opt(&mut f.control_number);
+            redact(&mut f.employee_name);
+            redact(&mut f.employee_address);
+            for state in &mut f.state_tax {
+                redact_opt(&mut state.employer_state_id);
+            }
+        }
+        for f in &mut forms.ssa_1099 {
+            f.beneficiary_ssn = self.mask_tin(&f.beneficiary_ssn);
+            redact(&mut f.beneficiary_name);
+            redact_opt(&mut f.address);
+            redact_opt(&mut f.claim_number);
+        }
+        // Forms 1099 share their payer and recipient boxes.
+        macro_rules! mask_1099 {
+            ($($forms:expr),*) => {$(
+                for f in $forms.iter_mut() {
+                    f.payer_tin = self.mask_tin(&f.payer_tin);
+                    f.recipient_tin = self.mask_tin(&f.recipient_tin);
+                    redact(&mut f.payer_name_address);
+                    redact(&mut f.recipient_name);
+                    redact_opt(&mut f.account_number);
+                }
+            )*};
+        }
+        mask_1099!(
+            forms.form_1099_int,
+            forms.form_1099_div,
+            forms.form_1099_nec,
+            forms.form_1099_b,
+            forms.form_1099_r,
+            forms.form_1099_g,
+            forms.form_1099_sa
+        );
+        for f in &mut forms.form_1095_a {
+            f.recipient_ssn = self.mask_tin(&f.recipient_ssn);
+            redact(&mut f.marketplace_identifier);
+            redact(&mut f.marketplace_policy_number);
+            redact(&mut f.policy_issuer_name);
+            redact(&mut f.recipient_name);
+            for individual in &mut f.covered_individuals {
+                individual.ssn = self.mask_tin(&individual.ssn);
+                redact(&mut individual.name);
+            }
+        }
+        for f in &mut forms.form_1098_t {
+            f.filer_ein = self.mask_tin(&f.filer_ein);
+            f.student_tin = self.mask_tin(&f.student_tin);
+            redact(&mut f.filer_name_address);
+            redact(&mut f.student_name);
+            redact_opt(&mut f.account_number);
+        }
+        for f in &mut forms.schedule_k1_1041 {
+            f.estate_or_trust_ein = self.mask_tin(&f.estate_or_trust_ein);
+            f.beneficiary_tin = self.mask_tin(&f.beneficiary_tin);
+            redact(&mut f.estate_or_trust_name);
+            redact(&mut f.fiduciary_name_address);
+            redact(&mut f.beneficiary_name_address);
+        }
+        for f in &mut forms.schedule_k1_1065 {
+            f.partnership_ein = self.mask_tin(&f.partnership_ein);
+            f.partner_tin = self.mask_tin(&f.partner_tin);
+            redact(&mut f.partnership_name_address);
+            redact(&mut f.partner_name_address);
+        }
+        for f in &mut forms.schedule_k1_1120_s {
+            f.corporation_ein = self.mask_tin(&f.corporation_ein);
+            f.shareholder_tin = self.mask_tin(&f.shareholder_tin);
+            redact(&mut f.corporation_name_address);
+            redact(&mut f.shareholder_name_address);
+        }
+    }
+
+    /// Masks pass-through entity, rental, and tip employer identifiers,
+    /// redacts children's names, and, if a seed is set, perturbs amounts.
+    ///
+    /// Every amount is scaled by the same factor, so zero amounts, signs,
+    /// and the ordering and ratios between amounts are kept. Thresholds
+    /// and phase-outs are not scaled, so a return within 1% of one may take
+    /// a different branch.
+    pub fn anonymize_input(&mut self, input: &mut ReturnInput) {
+        for p in &mut input.passthroughs {
+            p.entity_ein = self.mask_tin(&p.entity_ein);
+            redact(&mut p.entity_name);
+        }
+        for r in &mut input.rental_properties {
+            redact(&mut r.address);
+            for a in &mut r.assets {
+                redact(&mut a.description);
+            }
+        }
+        for e in &mut input.unreported_tips.employers {
+            e.employer_ein = self.mask_tin(&e.employer_ein);
+            redact(&mut e.employer_name);
+        }
+        for c in &mut input.children_investment_income {
+            redact(&mut c.child_name);
+        }
+        for w in &mut input.form_1099_withholding {
+            w.payer_tin = self.mask_tin(&w.payer_tin);
+            redact(&mut w.payer_name);
+        }
+        for c in &mut input.itemized_deductions.casualties {
+            for p in &mut c.properties {
+                redact(&mut p.description);
+            }
+        }
+        let Some(seed) = self.perturbation_seed else {
+            return;
+        };
+        let bps = perturbation_bps(seed);
+        let scale = |usd: &mut Usd| *usd = usd.mul_div(10_000 + bps, 10_000);
+
+        for usd in [
+            &mut input.w2_wages,
+            &mut input.social_security_wages,
+            &mut input.spouse_social_security_wages,
+            &mut input.unreported_tips.under_20_a_month,
+            &mut input.unreported_tips.social_security_wages_and_tips,
+            &mut input.capital_loss_carryover.short_term,
+            &mut input.capital_loss_carryover.long_term,
+            &mut input.social_security_benefits,
+            &mut input.hsa_distributions.gross_distributions,
+            &mut input.hsa_distributions.rollovers_and_excess_withdrawn,
+            &mut input.hsa_distributions.qualified_medical_expenses,
+            &mut input.adjustments.business_expenses_reservists,
+            &mut input.adjustments.hsa_deduction,
+            &mut input.adjustments.moving_expenses,
+            &mut input.adjustments.ira_deduction,
+            &mut input.adjustments.section_67e_excess_deductions,
+            &mut input.fed_withholding,
+            &mut input.extension_payment,
+        ] {
+            scale(usd);
+        }
+        for e in &mut input.unreported_tips.employers {
+            scale(&mut e.tips_received);
+            scale(&mut e.tips_reported);
+        }
+        for c in &mut input.nol_carryovers {
+            scale(&mut c.amount);
+        }
+        for w in &mut input.form_1099_withholding {
+            scale(&mut w.amount);
+        }
+        for h in &mut input.home_sales {
+            for usd in [
+                &mut h.selling_price,
+                &mut h.selling_expenses,
+                &mut h.cost,
+                &mut h.improvements,
+                &mut h.depreciation,
+            ] {
+                scale(usd);
+            }
+        }
+        let i = &mut input.itemized_deductions;
+        for usd in [
+            &mut i.medical_expenses,
+            &mut i.state_and_local_taxes,
+            &mut i.real_estate_taxes,
+            &mut i.personal_property_taxes,
+            &mut i.mortgage_interest,
+            &mut i.investment_interest,
+            &mut i.charitable_contributions.sixty,
+            &mut i.charitable_contributions.fifty,
+            &mut i.charitable_contributions.thirty,
+            &mut i.charitable_contributions.twenty,
+            &mut i.other,
+        ] {
+            scale(usd);
+        }
+        for c in &mut i.charitable_carryovers {
+            scale(&mut c.amount);
+        }
+        for p in i.casualties.iter_mut().flat_map(|c| &mut c.properties) {
+            for usd in [
+                &mut p.basis,
+                &mut p.reimbursement,
+                &mut p.fmv_before,
+                &mut p.fmv_after,
+            ] {
+                scale(usd);
+            }
+        }
+        for c in &mut input.children_investment_income {
+            for usd in [
+                &mut c.taxable_interest,
+                &mut c.tax_exempt_interest,
+                &mut c.ordinary_dividends,
+                &mut c.qualified_dividends,
+                &mut c.capital_gain_distributions,
+            ] {
+                scale(usd);
+            }
+        }
+        for s in &mut input.students {
+            for usd in [
+                &mut s.tuition_and_fees,
+                &mut s.course_materials,
+                &mut s.scholarships,
+                &mut s.scholarships_elected_taxable,
+            ] {
+                scale(usd);
+            }
+        }
+        for p in &mut input.passthroughs {
+            for usd in [
+                &mut p.ordinary_income,
+                &mut p.rental_income,
+                &mut p.guaranteed_payments,
+                &mut p.portfolio_income,
+                &mut p.section_179_deduction,
+                &mut p.interest,
+                &mut p.ordinary_dividends,
+                &mut p.qualified_dividends,
+                &mut p.net_short_term_capital_gain,
+                &mut p.net_long_term_capital_gain,
+                &mut p.self_employment_earnings,
+                &mut p.qualified_business_income,
+            ] {
+                scale(usd);
+            }
+            for usd in [&mut p.loss_limits.basis, &mut p.loss_limits.at_risk]
+                .into_iter()
+                .flatten()
+            {
+                scale(usd);
+            }
+        }
+        for r in &mut input.rental_properties {
+            let e = &mut r.expenses;
+            for usd in [
+                &mut r.rents_received,
+                &mut r.royalties_received,
+                &mut r.prior_year_unallowed,
+                &mut e.advertising,
+                &mut e.auto_and_travel,
+                &mut e.cleaning_and_maintenance,
+                &mut e.commissions,
+                &mut e.insurance,
+                &mut e.legal_and_professional_fees,
+                &mut e.management_fees,
+                &mut e.mortgage_interest,
+                &mut e.other_interest,
+                &mut e.repairs,
+                &mut e.supplies,
+                &mut e.taxes,
+                &mut e.utilities,
+                &mut e.depreciation,
+                &mut e.other,
+            ] {
+                scale(usd);
+            }
+            if let Some(at_risk) = &mut r.at_risk {
+                scale(at_risk);
+            }
+            for a in &mut r.assets {
+                scale(&mut a.cost);
+                scale(&mut a.land);
+            }
+        }
+        if let Some(coverage) = &mut input.marketplace_coverage {
+            for m in &mut coverage.months {
+                scale(&mut m.enrollment_premium);
+                scale(&mut m.slcsp_premium);
+                scale(&mut m.advance_payment);
+            }
+        }
+    }
+}
+
+fn redact(s: &mut String) {
+    if !s.is_empty() {
+        *s = REDACTED.to_string();
+    }
+}
+
+fn redact_opt(s: &mut Option<String>) {
+    if let Some(s) = s {
+        redact(s);
+    }
+}
+
+/// A perturbation in `-MAX_PERTURBATION_BPS..=MAX_PERTURBATION_BPS`, from
+/// one round of SplitMix64.
+fn perturbation_bps(seed: u64) -> i64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    (z % (2 * MAX_PERTURBATION_BPS as u64 + 1)) as i64 - MAX_PERTURBATION_BPS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2025::federal::{IrsSsa1099, IrsW2, source_forms};
+
+    #[test]
+    fn same_tin_masked_consistently() {
+        let mut forms = SourceForms {
+            w2: vec![
+                IrsW2 {
+                    employee_ssn: "123-45-6789".to_string(),
+                    employer_ein: "98-7654321".to_string(),
+                    employee_name: "Pat Doe".to_string(),
+                    ..Default::default()
+                },
+                IrsW2 {
+                    employee_ssn: "123-45-6789".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ssa_1099: vec![IrsSsa1099 {
+                beneficiary_ssn: "123-45-6789".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        Anonymizer::new().anonymize_forms(&mut forms);
+        assert_eq!(forms.w2[0].employee_ssn, "000-00-0001");
+        assert_eq!(forms.w2[0].employer_ein, "00-0000002");
+        assert_eq!(forms.w2[0].employee_name, REDACTED);
+        assert_eq!(forms.w2[1].employee_ssn, "000-00-0001");
+        assert_eq!(forms.w2[1].employer_ein, "");
+        assert_eq!(forms.w2[1].employee_name, "");
+        assert_eq!(forms.ssa_1099[0].beneficiary_ssn, "000-00-0001");
+    }
+
+    #[test]
+    fn perturbation_within_one_percent() {
+        for seed in 0..200 {
+            let bps = perturbation_bps(seed);
+            assert!((-MAX_PERTURBATION_BPS..=MAX_PERTURBATION_BPS).contains(&bps));
+        }
+        assert_eq!(perturbation_bps(7), perturbation_bps(7));
+
+        let mut forms = SourceForms {
+            w2: vec![IrsW2 {
+                wages_tips_other_comp: Usd::from_dollars(50_000),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut input = source_forms::tests::input();
+        forms.w2[0].federal_income_tax_withheld = Usd::from_dollars(5_000);
+        forms.apply_to(&mut input);
+        Anonymizer::with_perturbation(7).anonymize_input(&mut input);
+        let factor = 10_000 + perturbation_bps(7);
+        assert_eq!(
+            input.w2_wages,
+            Usd::from_dollars(50_000).mul_div(factor, 10_000)
+        );
+        assert_eq!(input.fed_withholding, input.w2_wages.mul_div(1, 10));
+        assert_eq!(input.social_security_benefits, Usd::ZERO);
+    }
+}
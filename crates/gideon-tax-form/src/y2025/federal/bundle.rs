@@ -0,0 +1,313 @@
+//! A versioned JSON document holding everything entered for a return.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use gideon_tax_core::capital_loss::CapitalLossCarryover;
+use gideon_tax_core::charitable::CharitableCarryover;
+use gideon_tax_core::foreign_tax_credit::FtcCarryover;
+use gideon_tax_core::nol::NolCarryover;
+use gideon_tax_core::parents_election::ChildInvestmentIncome;
+use gideon_tax_core::spine::ReturnInput;
+use gideon_tax_core::{FilingStatus, Usd};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::y2025::federal::{DirectDeposit, Form1040Identity, Form1040Signature, SourceForms};
+
+/// Version written by [`export`] and the newest [`import`] reads.
+///
+/// Bumped only for changes older readers would misread, such as renaming
+/// or re-meaning a field. Adding a field does not bump it: older readers
+/// keep the field in [`ReturnBundle::extra`] and newer readers default it
+/// when absent.
+pub const SCHEMA_VERSION: u64 = 1;
+
+/// Tax year of the forms in a bundle.
+pub const TAX_YEAR: u64 = 2025;
+
+/// The taxpayer's identity, source forms, elections, and carryovers.
+///
+/// The canonical storage format for a return. Computed amounts are not
+/// stored; recompute them from [`apply_to`](ReturnBundle::apply_to).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReturnBundle {
+    pub identity: Form1040Identity,
+    pub source_forms: SourceForms,
+    pub elections: Elections,
+    pub carryovers: Carryovers,
+    pub filing: Filing,
+    /// Top-level fields this version does not know, kept so a file written
+    /// by a newer version survives a round trip through this one.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Elections that change how income is reported.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Elections {
+    /// Children's interest and dividends reported on the parent's return
+    /// (Form 8814), one entry per child.
+    pub children_investment_income: Vec<ChildInvestmentIncome>,
+}
+
+/// How the return is signed and where a refund goes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Filing {
+    pub signature: Form1040Signature,
+    /// Lines 35b–35d; `None` to receive a refund by check.
+    pub direct_deposit: Option<DirectDeposit>,
+}
+
+/// Amounts carried in from prior years' returns.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Carryovers {
+    pub capital_loss: CapitalLossCarryover,
+    pub net_operating_losses: Vec<NolCarryover>,
+    pub charitable: Vec<CharitableCarryover>,
+    /// Form 1116 carryovers, by basket.
+    pub foreign_tax_credit: Vec<FtcCarryover>,
+    /// Form 8801, line 26.
+    pub minimum_tax_credit: Usd,
+}
+
+#[derive(Debug)]
+pub enum BundleError {
+    Json(serde_json::Error),
+    /// The document has no `schema_version`.
+    MissingVersion,
+    /// Written by a newer version with changes this one cannot read.
+    UnsupportedVersion {
+        found: u64,
+        supported: u64,
+    },
+    TaxYearMismatch {
+        found: u64,
+    },
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleError::Json(e) => write!(f, "invalid return bundle: {e}"),
+            BundleError::MissingVersion => write!(f, "return bundle has no schema_version"),
+            BundleError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "return bundle schema version {found} is not supported (newest is {supported})"
+            ),
+            BundleError::TaxYearMismatch { found } => {
+                write!(f, "return bundle is for {found}, expected {TAX_YEAR}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BundleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BundleError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for BundleError {
+    fn from(e: serde_json::Error) -> Self {
+        BundleError::Json(e)
+    }
+}
+
+impl ReturnBundle {
+    /// A 2025 [`ReturnInput`] holding only what the bundle records, as
+    /// [`apply_to`](ReturnBundle::apply_to) fills it.
+    pub fn to_input(&self) -> ReturnInput {
+        let mut input = ReturnInput::new(2025, FilingStatus::Single);
+        self.apply_to(&mut input);
+        input
+    }
+
+    /// Sets the filing status, filers, and dependents from the identity,
+    /// then adds the source forms, elections, and carryovers to `input`.
+    ///
+    /// Foreign tax credit and minimum tax credit carryovers are not part of
+    /// [`ReturnInput`]; pass them to Forms 1116 and 8801 directly.
+    pub fn apply_to(&self, input: &mut ReturnInput) {
+        let identity = &self.identity;
+        input.filing_status = identity.filing_status;
+        input.taxpayer = identity.taxpayer.filer;
+        input.spouse = identity.spouse.as_ref().map(|s| s.filer);
+        input.is_dependent = identity.can_be_claimed_as_dependent;
+        input.num_dependents = identity.dependents.len() as u32;
+        match &identity.spouse {
+            Some(spouse) => self.source_forms.apply_to_joint(&spouse.ssn, input),
+            None => self.source_forms.apply_to(input),
+        }
+        input
+            .children_investment_income
+            .extend(self.elections.children_investment_income.iter().cloned());
+        let carryovers = &self.carryovers;
+        input.capital_loss_carryover.short_term += carryovers.capital_loss.short_term;
+        input.capital_loss_carryover.long_term += carryovers.capital_loss.long_term;
+        input
+            .nol_carryovers
+            .extend_from_slice(&carryovers.net_operating_losses);
+        input
+            .itemized_deductions
+            .charitable_carryovers
+            .extend_from_slice(&carryovers.charitable);
+    }
+}
+
+/// Writes `bundle` as pretty-printed JSON, with its `schema_version` and
+/// `tax_year`.
+pub fn export(bundle: &ReturnBundle) -> String {
+    let mut doc = serde_json::Map::new();
+    doc.insert("schema_version".to_string(), SCHEMA_VERSION.into());
+    doc.insert("tax_year".to_string(), TAX_YEAR.into());
+    match serde_json::to_value(bundle).expect("bundle fields have string keys") {
+        Value::Object(fields) => doc.extend(fields),
+        _ => unreachable!("a struct serializes as an object"),
+    }
+    serde_json::to_string_pretty(&Value::Object(doc)).expect("a JSON value always serializes")
+}
+
+/// Reads a bundle written by [`export`] from this or any older compatible
+/// version.
+///
+/// Fields the bundle lacks take their defaults and unknown fields are kept
+/// in [`ReturnBundle::extra`] (top level) or ignored (nested).
+pub fn import(json: &str) -> Result<ReturnBundle, BundleError> {
+    let Value::Object(mut doc) = serde_json::from_str::<Value>(json)? else {
+        return Err(BundleError::MissingVersion);
+    };
+    let version = doc
+        .remove("schema_version")
+        .and_then(|v| v.as_u64())
+        .ok_or(BundleError::MissingVersion)?;
+    if version == 0 || version > SCHEMA_VERSION {
+        return Err(BundleError::UnsupportedVersion {
+            found: version,
+            supported: SCHEMA_VERSION,
+        });
+    }
+    if let Some(year) = doc.remove("tax_year").and_then(|v| v.as_u64())
+        && year != TAX_YEAR
+    {
+        return Err(BundleError::TaxYearMismatch { found: year });
+    }
+    Ok(serde_json::from_value(Value::Object(doc))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2025::federal::source_forms::tests::input;
+    use crate::y2025::federal::{Box12Code, Box12Entry, Form1040Person, IrsW2};
+    use gideon_tax_core::{Filer, FilingStatus};
+
+    fn bundle() -> ReturnBundle {
+        ReturnBundle {
+            identity: Form1040Identity {
+                filing_status: FilingStatus::MarriedFilingJointly,
+                taxpayer: Form1040Person {
+                    first_name: "Ada".to_string(),
+                    filer: Filer {
+                        is_65_or_older: true,
+                        is_blind: false,
+                    },
+                    ..Default::default()
+                },
+                spouse: Some(Form1040Person::default()),
+                ..Default::default()
+            },
+            source_forms: SourceForms {
+                w2: vec![IrsW2 {
+                    wages_tips_other_comp: Usd::from_dollars(90_000),
+                    federal_income_tax_withheld: Usd::from_dollars(9_000),
+                    box_12: vec![Box12Entry {
+                        code: Box12Code::D,
+                        amount: Usd::from_dollars(6_000),
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            carryovers: Carryovers {
+                capital_loss: CapitalLossCarryover {
+                    short_term: Usd::from_dollars(1_000),
+                    long_term: Usd::from_dollars(4_000),
+                },
+                net_operating_losses: vec![NolCarryover {
+                    year: 2023,
+                    amount: Usd::from_dollars(2_500),
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn export_import_round_trip() {
+        let bundle = bundle();
+        let json = export(&bundle);
+        assert!(json.contains("\"schema_version\": 1,"), "{json}");
+        assert_eq!(import(&json).unwrap(), bundle);
+    }
+
+    #[test]
+    fn apply_to_fills_the_return_input() {
+        let mut input = input();
+        bundle().apply_to(&mut input);
+        assert_eq!(input.filing_status, FilingStatus::MarriedFilingJointly);
+        assert!(input.taxpayer.is_65_or_older);
+        assert_eq!(input.spouse, Some(Filer::default()));
+        assert_eq!(input.w2_wages, Usd::from_dollars(90_000));
+        assert_eq!(input.fed_withholding, Usd::from_dollars(9_000));
+        assert_eq!(
+            input.capital_loss_carryover.long_term,
+            Usd::from_dollars(4_000)
+        );
+        assert_eq!(input.nol_carryovers.len(), 1);
+    }
+
+    #[test]
+    fn newer_minor_fields_survive_and_missing_fields_default() {
+        let json = r#"{
+            "schema_version": 1,
+            "tax_year": 2025,
+            "carryovers": { "minimum_tax_credit": 120000, "future_carryover": 5 },
+            "state_returns": [{ "state": "CA" }]
+        }"#;
+        let bundle = import(json).unwrap();
+        assert_eq!(
+            bundle.carryovers.minimum_tax_credit,
+            Usd::from_dollars(1_200)
+        );
+        assert_eq!(bundle.identity, Form1040Identity::default());
+        assert!(bundle.extra.contains_key("state_returns"));
+        assert!(export(&bundle).contains("\"state_returns\""));
+    }
+
+    #[test]
+    fn rejects_unknown_versions_and_other_years() {
+        assert!(matches!(
+            import(r#"{ "tax_year": 2025 }"#),
+            Err(BundleError::MissingVersion)
+        ));
+        assert!(matches!(
+            import(r#"{ "schema_version": 2 }"#),
+            Err(BundleError::UnsupportedVersion {
+                found: 2,
+                supported: 1
+            })
+        ));
+        let err = import(r#"{ "schema_version": 1, "tax_year": 2024 }"#).unwrap_err();
+        assert_eq!(err.to_string(), "return bundle is for 2024, expected 2025");
+        assert!(matches!(import("[]"), Err(BundleError::MissingVersion)));
+    }
+}
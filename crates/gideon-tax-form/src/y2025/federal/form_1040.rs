@@ -0,0 +1,467 @@
+use gideon_tax_core::spine::{Key, Ledger, ReturnInput};
+use gideon_tax_core::withholding;
+use gideon_tax_core::{Date, Filer, FilingStatus, Usd};
+
+use super::{FormLabels, FormLanguage};
+
+/// Form 1040, U.S. Individual Income Tax Return.
+///
+/// Lines the spine does not compute yet (IRA and pension distributions,
+/// capital gains, the child tax credit, the earned income credit, and so
+/// on) are left at zero by [`from_ledger`](IrsForm1040::from_ledger).
+///
+/// See: <https://www.irs.gov/instructions/i1040gi>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsForm1040 {
+    /// Filing status, name, address, and the questions on page 1 above
+    /// line 1a.
+    pub identity: Form1040Identity,
+    /// Not on the form: print as Form 1040 or Form 1040-SP.
+    pub language: FormLanguage,
+    /// Line 1a.
+    pub w2_wages: Usd,
+    /// Line 1b.
+    pub household_employee_wages: Usd,
+    /// Line 1c.
+    pub unreported_tips: Usd,
+    /// Line 1d.
+    pub medicaid_waiver_payments: Usd,
+    /// Line 1e.
+    pub dependent_care_benefits: Usd,
+    /// Line 1f.
+    pub adoption_benefits: Usd,
+    /// Line 1g.
+    pub form_8919_wages: Usd,
+    /// Line 1h.
+    pub other_earned_income: Usd,
+    /// Line 1i.
+    pub nontaxable_combat_pay: Usd,
+    /// Line 1z.
+    pub total_wages: Usd,
+    /// Line 2a.
+    pub tax_exempt_interest: Usd,
+    /// Line 2b.
+    pub taxable_interest: Usd,
+    /// Line 3a.
+    pub qualified_dividends: Usd,
+    /// Line 3b.
+    pub ordinary_dividends: Usd,
+    /// Line 4a.
+    pub ira_distributions: Usd,
+    /// Line 4b.
+    pub taxable_ira_distributions: Usd,
+    /// Line 5a.
+    pub pensions_and_annuities: Usd,
+    /// Line 5b.
+    pub taxable_pensions_and_annuities: Usd,
+    /// Line 6a.
+    pub social_security_benefits: Usd,
+    /// Line 6b.
+    pub taxable_social_security: Usd,
+    /// Line 6c.
+    pub lump_sum_election: bool,
+    /// Line 7.
+    pub capital_gain_or_loss: Usd,
+    /// Line 7 checkbox: Schedule D not required.
+    pub schedule_d_not_required: bool,
+    /// Line 8, from Schedule 1, line 10.
+    pub additional_income: Usd,
+    /// Line 9.
+    pub total_income: Usd,
+    /// Line 10, from Schedule 1, line 26.
+    pub adjustments_to_income: Usd,
+    /// Line 11.
+    pub adjusted_gross_income: Usd,
+    /// Line 12.
+    pub standard_or_itemized_deduction: Usd,
+    /// Line 13a.
+    pub qbi_deduction: Usd,
+    /// Line 13b, from Schedule 1-A, line 38.
+    pub schedule_1a_deductions: Usd,
+    /// Line 14.
+    pub total_deductions: Usd,
+    /// Line 15.
+    pub taxable_income: Usd,
+    /// Line 16.
+    pub tax: Usd,
+    /// Line 16 checkboxes: Form 8814, Form 4972, or another form.
+    pub tax_from_forms: TaxFromForms,
+    /// Line 17, from Schedule 2, line 3.
+    pub schedule_2_part_1_tax: Usd,
+    /// Line 18.
+    pub tax_before_credits: Usd,
+    /// Line 19, from Schedule 8812.
+    pub child_tax_credit: Usd,
+    /// Line 20, from Schedule 3, line 8.
+    pub schedule_3_credits: Usd,
+    /// Line 21.
+    pub total_credits: Usd,
+    /// Line 22.
+    pub tax_after_credits: Usd,
+    /// Line 23, from Schedule 2, line 21.
+    pub other_taxes: Usd,
+    /// Line 24.
+    pub total_tax: Usd,
+    /// Line 25a.
+    pub w2_withholding: Usd,
+    /// Line 25b.
+    pub form_1099_withholding: Usd,
+    /// Line 25c.
+    pub other_withholding: Usd,
+    /// Line 25d.
+    pub total_withholding: Usd,
+    /// Line 26.
+    pub estimated_tax_payments: Usd,
+    /// Line 27.
+    pub earned_income_credit: Usd,
+    /// Line 28, from Schedule 8812.
+    pub additional_child_tax_credit: Usd,
+    /// Line 29, from Form 8863, line 8.
+    pub american_opportunity_credit: Usd,
+    /// Line 31, from Schedule 3, line 15.
+    pub schedule_3_payments: Usd,
+    /// Line 32.
+    pub total_other_payments: Usd,
+    /// Line 33.
+    pub total_payments: Usd,
+    /// Line 34.
+    pub overpaid: Usd,
+    /// Line 35a.
+    pub refund: Usd,
+    /// Lines 35b–35d.
+    pub direct_deposit: Option<DirectDeposit>,
+    /// Line 36.
+    pub applied_to_next_year: Usd,
+    /// Line 37.
+    pub amount_owed: Usd,
+    /// Line 38.
+    pub estimated_tax_penalty: Usd,
+    pub third_party_designee: Option<ThirdPartyDesignee>,
+    pub signature: Form1040Signature,
+    pub paid_preparer: Option<PaidPreparer>,
+}
+
+/// Page 1 above line 1a: filing status, names, address, and the yes/no
+/// questions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form1040Identity {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "gideon_tax_core::types::filing_status_serde")
+    )]
+    pub filing_status: FilingStatus,
+    /// Filing status: spouse's name if married filing separately, or the
+    /// qualifying person's name if head of household or qualifying surviving
+    /// spouse and that person is not a dependent.
+    pub filing_status_name: Option<String>,
+    pub taxpayer: Form1040Person,
+    pub spouse: Option<Form1040Person>,
+    pub address: Form1040Address,
+    /// Presidential Election Campaign: you.
+    pub presidential_campaign: bool,
+    /// Presidential Election Campaign: spouse.
+    pub spouse_presidential_campaign: bool,
+    /// Digital assets: received, sold, exchanged, or otherwise disposed of a
+    /// digital asset during the year.
+    pub digital_assets: bool,
+    /// Someone can claim you as a dependent.
+    pub can_be_claimed_as_dependent: bool,
+    /// Someone can claim your spouse as a dependent.
+    pub spouse_can_be_claimed_as_dependent: bool,
+    /// Spouse itemizes on a separate return or you were a dual-status alien.
+    pub spouse_itemizes_or_dual_status: bool,
+    pub dependents: Vec<Form1040Dependent>,
+}
+
+/// Defaults to single with every other field empty.
+impl Default for Form1040Identity {
+    fn default() -> Self {
+        Form1040Identity {
+            filing_status: FilingStatus::Single,
+            filing_status_name: None,
+            taxpayer: Form1040Person::default(),
+            spouse: None,
+            address: Form1040Address::default(),
+            presidential_campaign: false,
+            spouse_presidential_campaign: false,
+            digital_assets: false,
+            can_be_claimed_as_dependent: false,
+            spouse_can_be_claimed_as_dependent: false,
+            spouse_itemizes_or_dual_status: false,
+            dependents: Vec::new(),
+        }
+    }
+}
+
+/// The taxpayer or spouse.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form1040Person {
+    pub first_name: String,
+    pub last_name: String,
+    pub ssn: String,
+    /// Age/Blindness checkboxes: born before January 2, 1961, and blind.
+    pub filer: Filer,
+}
+
+/// Home address, with the foreign address lines when outside the U.S.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form1040Address {
+    pub street: String,
+    pub apartment: Option<String>,
+    pub city: String,
+    /// Two-letter postal abbreviation.
+    pub state: String,
+    pub zip: String,
+    pub foreign_country: Option<String>,
+    pub foreign_province: Option<String>,
+    pub foreign_postal_code: Option<String>,
+}
+
+/// One row of the Dependents table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form1040Dependent {
+    /// Column (1).
+    pub first_name: String,
+    /// Column (1).
+    pub last_name: String,
+    /// Column (2).
+    pub ssn: String,
+    /// Column (3).
+    pub relationship: String,
+    /// Column (4): lived with you more than half of the year in the U.S.
+    pub lived_with_you: bool,
+    /// Column (4): full-time student.
+    pub full_time_student: bool,
+    /// Column (4): permanently and totally disabled.
+    pub permanently_disabled: bool,
+    /// Column (5): child tax credit.
+    pub child_tax_credit: bool,
+    /// Column (5): credit for other dependents.
+    pub credit_for_other_dependents: bool,
+}
+
+/// Line 16 checkboxes for tax figured on another form.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaxFromForms {
+    pub form_8814: bool,
+    pub form_4972: bool,
+    /// Form number entered next to the third checkbox.
+    pub other_form: Option<String>,
+}
+
+/// Lines 35b–35d.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DirectDeposit {
+    /// Line 35b.
+    pub routing_number: String,
+    /// Line 35c.
+    pub savings: bool,
+    /// Line 35d.
+    pub account_number: String,
+}
+
+/// Third Party Designee.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThirdPartyDesignee {
+    pub name: String,
+    pub phone: String,
+    /// Personal identification number (five digits).
+    pub pin: String,
+}
+
+/// Sign Here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form1040Signature {
+    pub date: Option<Date>,
+    pub occupation: String,
+    /// Identity Protection PIN, if the IRS sent one.
+    pub identity_protection_pin: Option<String>,
+    /// Not on the form: five-digit self-select PIN used to sign an e-filed
+    /// return.
+    pub self_select_pin: Option<String>,
+    pub spouse_date: Option<Date>,
+    pub spouse_occupation: Option<String>,
+    pub spouse_identity_protection_pin: Option<String>,
+    /// Not on the form: spouse's self-select PIN.
+    pub spouse_self_select_pin: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+}
+
+/// Paid Preparer Use Only.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PaidPreparer {
+    pub name: String,
+    pub date: Option<Date>,
+    pub ptin: String,
+    pub self_employed: bool,
+    pub firm_name: String,
+    pub firm_address: String,
+    pub firm_ein: String,
+    pub phone: String,
+}
+
+impl IrsForm1040 {
+    /// Number, title, and line labels for the selected language.
+    pub fn labels(&self) -> &'static FormLabels {
+        self.language.form_1040()
+    }
+
+    /// Fills in the form from the spine's ledger for `input`.
+    ///
+    /// Wages, unreported tips, gross Social Security benefits, and the split
+    /// of withholding between lines 25a and 25b are not in the ledger and
+    /// come from `input`, as do a child's tax-exempt interest and the Form 8814
+    /// checkbox. Schedule E, HSA, scholarship, and a child's interest and
+    /// dividends other than qualified dividends, less any NOL deduction, are
+    /// carried through Schedule 1 to line 8; self-employment tax, the HSA
+    /// additional tax, and the tax on unreported tips through Schedule 2 to
+    /// line 23; and the net
+    /// premium tax credit and extension payment through Schedule 3 to line 31.
+    /// The full overpayment is refunded.
+    pub fn from_ledger(identity: Form1040Identity, input: &ReturnInput, ledger: &Ledger) -> Self {
+        let line = |key: Key| ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+
+        let unreported_tips = input.unreported_tips.total();
+        let total_wages = input.w2_wages + unreported_tips;
+        let total_deductions = line(Key::Deductions) + line(Key::QbiDeduction);
+        let schedule_2_part_1_tax = line(Key::ExcessAdvancePremiumTaxCredit);
+        let tax = line(Key::RegularTax) + line(Key::ChildInvestmentTax);
+        let tax_before_credits = tax + schedule_2_part_1_tax;
+        let total_credits = line(Key::NonRefundableCredits);
+        let tax_after_credits = (tax_before_credits - total_credits).max(Usd::ZERO);
+        let other_taxes = line(Key::SelfEmploymentTax)
+            + line(Key::HsaAdditionalTax)
+            + line(Key::UnreportedTipsTax);
+        let total_tax = tax_after_credits + other_taxes;
+        let form_1099_withholding = withholding::reported_total(&input.form_1099_withholding);
+        let total_withholding = input.fed_withholding + form_1099_withholding;
+        let total_other_payments = line(Key::RefundableCredits) + line(Key::ExtensionPayment);
+        let total_payments =
+            total_withholding + line(Key::EstimatedPayments) + total_other_payments;
+        let overpaid = (total_payments - total_tax).max(Usd::ZERO);
+
+        IrsForm1040 {
+            identity,
+            w2_wages: input.w2_wages,
+            unreported_tips,
+            total_wages,
+            tax_exempt_interest: input
+                .children_investment_income
+                .iter()
+                .map(|c| c.tax_exempt_interest)
+                .sum(),
+            taxable_interest: line(Key::TaxableInterest),
+            qualified_dividends: input
+                .passthroughs
+                .iter()
+                .map(|p| p.qualified_dividends)
+                .sum(),
+            ordinary_dividends: line(Key::OrdinaryDividends),
+            social_security_benefits: input.social_security_benefits,
+            taxable_social_security: line(Key::TaxableSocialSecurity),
+            capital_gain_or_loss: line(Key::CapitalGainOrLoss),
+            additional_income: line(Key::SupplementalIncome)
+                + line(Key::TaxableHsaDistributions)
+                + line(Key::TaxableScholarships)
+                + line(Key::ChildInvestmentIncome)
+                - line(Key::NolDeduction),
+            total_income: line(Key::TotalIncome),
+            adjustments_to_income: line(Key::Adjustments),
+            adjusted_gross_income: line(Key::AGI),
+            standard_or_itemized_deduction: line(Key::Deductions),
+            qbi_deduction: line(Key::QbiDeduction),
+            total_deductions,
+            taxable_income: line(Key::TaxableIncome),
+            tax,
+            tax_from_forms: TaxFromForms {
+                form_8814: !input.children_investment_income.is_empty(),
+                ..Default::default()
+            },
+            schedule_2_part_1_tax,
+            tax_before_credits,
+            schedule_3_credits: total_credits,
+            total_credits,
+            tax_after_credits,
+            other_taxes,
+            total_tax,
+            w2_withholding: input.fed_withholding,
+            form_1099_withholding,
+            total_withholding,
+            estimated_tax_payments: line(Key::EstimatedPayments),
+            schedule_3_payments: total_other_payments,
+            total_other_payments,
+            total_payments,
+            overpaid,
+            refund: overpaid,
+            amount_owed: (total_tax - total_payments).max(Usd::ZERO),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::compute_spine;
+
+    use super::*;
+
+    fn input(wages: i64, withheld: i64) -> ReturnInput {
+        ReturnInput {
+            w2_wages: Usd::from_dollars(wages),
+            fed_withholding: Usd::from_dollars(withheld),
+            ..ReturnInput::new(2025, FilingStatus::Single)
+        }
+    }
+
+    fn identity() -> Form1040Identity {
+        Form1040Identity {
+            taxpayer: Form1040Person {
+                first_name: "Pat".to_string(),
+                last_name: "Doe".to_string(),
+                ssn: "400-00-0001".to_string(),
+                ..Default::default()
+            },
+            digital_assets: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn refund_matches_ledger() {
+        let input = input(60_000, 9_000);
+        let ledger = compute_spine(&Rules2025, &input).unwrap();
+        let form = IrsForm1040::from_ledger(identity(), &input, &ledger);
+        assert_eq!(form.identity.taxpayer.last_name, "Doe");
+        assert_eq!(form.labels().number, "1040");
+        assert!(form.identity.digital_assets);
+        assert_eq!(form.total_wages, Usd::from_dollars(60_000));
+        assert_eq!(form.adjusted_gross_income, ledger[&Key::AGI]);
+        assert_eq!(form.taxable_income, ledger[&Key::TaxableIncome]);
+        assert_eq!(form.total_tax, ledger[&Key::TotalTax]);
+        assert_eq!(form.total_payments, ledger[&Key::TotalPayments]);
+        assert_eq!(form.refund, ledger[&Key::Refund]);
+        assert_eq!(form.amount_owed, Usd::ZERO);
+    }
+
+    #[test]
+    fn amount_owed_matches_ledger() {
+        let input = input(120_000, 1_000);
+        let ledger = compute_spine(&Rules2025, &input).unwrap();
+        let form = IrsForm1040::from_ledger(identity(), &input, &ledger);
+        assert_eq!(form.total_withholding, Usd::from_dollars(1_000));
+        assert_eq!(form.refund, Usd::ZERO);
+        assert_eq!(form.amount_owed, ledger[&Key::AmountOwed]);
+    }
+}
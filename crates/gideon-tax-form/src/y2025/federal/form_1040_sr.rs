@@ -0,0 +1,165 @@
+use gideon_tax_core::rules::TaxYearRules;
+use gideon_tax_core::{FilingStatus, Usd};
+
+use super::{FormLabels, IrsForm1040};
+
+/// Form 1040-SR, U.S. Tax Return for Seniors.
+///
+/// Form 1040-SR has the same lines as Form 1040 in a larger print, plus a
+/// Standard Deduction Chart for filers born before January 2, 1961 or
+/// blind, so it wraps an [`IrsForm1040`] filled from the same ledger.
+///
+/// See: <https://www.irs.gov/instructions/i1040gi>
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsForm1040Sr {
+    pub form: IrsForm1040,
+    /// Standard Deduction Chart.
+    pub standard_deduction_chart: Vec<StandardDeductionChartRow>,
+}
+
+/// One amount in the Standard Deduction Chart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StandardDeductionChartRow {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "gideon_tax_core::types::filing_status_serde")
+    )]
+    pub filing_status: FilingStatus,
+    /// Number of age and blindness boxes checked.
+    pub boxes_checked: u32,
+    pub standard_deduction: Usd,
+}
+
+/// The Standard Deduction Chart for `rules`: the deduction for each filing
+/// status and number of boxes checked, in the order printed on the form.
+///
+/// The chart does not apply to filers who can be claimed as a dependent.
+pub fn standard_deduction_chart(rules: &dyn TaxYearRules) -> Vec<StandardDeductionChartRow> {
+    use FilingStatus::*;
+    [
+        (Single, 2),
+        (MarriedFilingJointly, 4),
+        (QualifyingSurvivingSpouse, 2),
+        (MarriedFilingSeparately, 4),
+        (HeadOfHousehold, 2),
+    ]
+    .into_iter()
+    .flat_map(|(status, max_boxes)| {
+        let base = rules.typical_standard_deduction(status);
+        let per_box = match status {
+            Single | HeadOfHousehold => rules.additional_deduction_unmarried(),
+            _ => rules.additional_deduction_married(),
+        };
+        (1..=max_boxes).map(move |boxes| StandardDeductionChartRow {
+            filing_status: status,
+            boxes_checked: boxes,
+            standard_deduction: base + per_box * i64::from(boxes),
+        })
+    })
+    .collect()
+}
+
+impl IrsForm1040Sr {
+    /// Presents `form` as Form 1040-SR, or returns `None` if neither the
+    /// taxpayer nor the spouse was born before January 2, 1961.
+    pub fn from_form_1040(rules: &dyn TaxYearRules, form: IrsForm1040) -> Option<Self> {
+        let identity = &form.identity;
+        let senior = identity.taxpayer.filer.is_65_or_older
+            || identity
+                .spouse
+                .as_ref()
+                .is_some_and(|s| s.filer.is_65_or_older);
+        senior.then(|| IrsForm1040Sr {
+            form,
+            standard_deduction_chart: standard_deduction_chart(rules),
+        })
+    }
+
+    /// Number, title, and line labels for the form's selected language.
+    pub fn labels(&self) -> &'static FormLabels {
+        self.form.language.form_1040_sr()
+    }
+
+    /// Number of age and blindness boxes checked on the form.
+    pub fn boxes_checked(&self) -> u32 {
+        let identity = &self.form.identity;
+        let boxes = identity.taxpayer.filer.checked_boxes()
+            + identity
+                .spouse
+                .as_ref()
+                .map_or(0, |s| s.filer.checked_boxes());
+        boxes as u32
+    }
+
+    /// The chart amount for this return's filing status and boxes checked,
+    /// if any boxes are checked.
+    pub fn chart_standard_deduction(&self) -> Option<Usd> {
+        let status = self.form.identity.filing_status;
+        let boxes = self.boxes_checked();
+        self.standard_deduction_chart
+            .iter()
+            .find(|r| r.filing_status == status && r.boxes_checked == boxes)
+            .map(|r| r.standard_deduction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::Filer;
+    use gideon_tax_core::rules::y2025::Rules2025;
+
+    use super::*;
+    use crate::y2025::federal::{Form1040Identity, Form1040Person, FormLanguage};
+
+    fn form(taxpayer: Filer) -> IrsForm1040 {
+        IrsForm1040 {
+            identity: Form1040Identity {
+                taxpayer: Form1040Person {
+                    filer: taxpayer,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn chart_rows() {
+        let chart = standard_deduction_chart(&Rules2025);
+        assert_eq!(chart.len(), 14);
+        assert_eq!(
+            chart[0],
+            StandardDeductionChartRow {
+                filing_status: FilingStatus::Single,
+                boxes_checked: 1,
+                standard_deduction: Usd::from_dollars(17_750),
+            }
+        );
+        // Married filing jointly, all four boxes: 31,500 + 4 × 1,600.
+        assert_eq!(chart[5].standard_deduction, Usd::from_dollars(37_900));
+    }
+
+    #[test]
+    fn only_for_seniors() {
+        assert_eq!(
+            IrsForm1040Sr::from_form_1040(&Rules2025, form(Filer::default())),
+            None
+        );
+        let senior = Filer {
+            is_65_or_older: true,
+            is_blind: true,
+        };
+        let mut sr = IrsForm1040Sr::from_form_1040(&Rules2025, form(senior)).unwrap();
+        assert_eq!(sr.boxes_checked(), 2);
+        assert_eq!(
+            sr.chart_standard_deduction(),
+            Some(Usd::from_dollars(19_750))
+        );
+        assert_eq!(sr.labels().number, "1040-SR");
+        sr.form.language = FormLanguage::Spanish;
+        assert_eq!(sr.labels().number, "1040-SR (SP)");
+    }
+}
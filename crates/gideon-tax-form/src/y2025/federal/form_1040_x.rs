@@ -0,0 +1,275 @@
+use gideon_tax_core::Usd;
+use gideon_tax_core::spine::{Key, Ledger};
+
+/// Form 1040-X, Amended U.S. Individual Income Tax Return.
+///
+/// Built from the ledger of the original return (or as previously
+/// adjusted) and the ledger of the corrected return. Lines 14 (earned
+/// income credit) and 23 (applied to estimated tax) are left at zero.
+///
+/// See: <https://www.irs.gov/instructions/i1040x>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsForm1040X {
+    /// Line 1.
+    pub adjusted_gross_income: AmendedLine,
+    /// Line 2.
+    pub deductions: AmendedLine,
+    /// Line 3.
+    pub agi_less_deductions: AmendedLine,
+    /// Line 4a.
+    pub exemptions: AmendedLine,
+    /// Line 4b.
+    pub qbi_deduction: AmendedLine,
+    /// Line 5.
+    pub taxable_income: AmendedLine,
+    /// Line 6.
+    pub tax: AmendedLine,
+    /// Line 7.
+    pub nonrefundable_credits: AmendedLine,
+    /// Line 8.
+    pub tax_after_credits: AmendedLine,
+    /// Line 10.
+    pub other_taxes: AmendedLine,
+    /// Line 11.
+    pub total_tax: AmendedLine,
+    /// Line 12.
+    pub withholding: AmendedLine,
+    /// Line 13.
+    pub estimated_tax_payments: AmendedLine,
+    /// Line 14.
+    pub earned_income_credit: AmendedLine,
+    /// Line 15.
+    pub refundable_credits: AmendedLine,
+    /// Line 16.
+    pub paid_with_original: Usd,
+    /// Line 17.
+    pub total_payments: Usd,
+    /// Line 18.
+    pub overpayment_on_original: Usd,
+    /// Line 19.
+    pub payments_less_overpayment: Usd,
+    /// Line 20.
+    pub amount_owed: Usd,
+    /// Line 21.
+    pub overpaid: Usd,
+    /// Line 22.
+    pub refund: Usd,
+    /// Line 23.
+    pub applied_to_estimated_tax: Usd,
+    /// Part II, one entry per ledger line that changed, in form order.
+    pub explanation: Vec<LedgerChange>,
+}
+
+/// A line with columns A (original), B (net change), and C (correct).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AmendedLine {
+    pub original: Usd,
+    pub net_change: Usd,
+    pub correct: Usd,
+}
+
+impl AmendedLine {
+    pub fn new(original: Usd, correct: Usd) -> Self {
+        AmendedLine {
+            original,
+            net_change: correct - original,
+            correct,
+        }
+    }
+
+    fn zip(a: AmendedLine, b: AmendedLine, f: impl Fn(Usd, Usd) -> Usd) -> Self {
+        Self::new(f(a.original, b.original), f(a.correct, b.correct))
+    }
+}
+
+/// Amounts already paid or refunded for the year (lines 16 and 18).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriorPayments {
+    /// Paid with the original return, with an extension request, and after
+    /// the return was filed.
+    pub paid: Usd,
+    /// Overpayment shown on the original return or as previously adjusted,
+    /// whether refunded or applied to estimated tax.
+    pub overpayment: Usd,
+}
+
+impl PriorPayments {
+    /// Assumes the balance due on `original` was paid in full and its refund
+    /// was issued in full.
+    pub fn from_original(original: &Ledger) -> Self {
+        let get = |key: Key| original.get(&key).copied().unwrap_or(Usd::ZERO);
+        PriorPayments {
+            paid: get(Key::AmountOwed) + get(Key::ExtensionPayment),
+            overpayment: get(Key::Refund),
+        }
+    }
+}
+
+/// One changed line, for Part II.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LedgerChange {
+    pub key: Key,
+    pub original: Usd,
+    pub corrected: Usd,
+    pub text: String,
+}
+
+impl IrsForm1040X {
+    /// Fills in the form from the original and corrected ledgers.
+    pub fn from_ledgers(original: &Ledger, corrected: &Ledger, prior: PriorPayments) -> Self {
+        let line = |keys: &[Key]| {
+            let sum = |ledger: &Ledger| -> Usd {
+                keys.iter()
+                    .map(|k| ledger.get(k).copied().unwrap_or(Usd::ZERO))
+                    .sum()
+            };
+            AmendedLine::new(sum(original), sum(corrected))
+        };
+
+        let adjusted_gross_income = line(&[Key::AGI]);
+        let deductions = line(&[Key::Deductions]);
+        let tax = line(&[
+            Key::RegularTax,
+            Key::ChildInvestmentTax,
+            Key::ExcessAdvancePremiumTaxCredit,
+        ]);
+        let nonrefundable_credits = line(&[Key::NonRefundableCredits]);
+        let tax_after_credits =
+            AmendedLine::zip(tax, nonrefundable_credits, |t, c| (t - c).max(Usd::ZERO));
+        let other_taxes = line(&[
+            Key::SelfEmploymentTax,
+            Key::HsaAdditionalTax,
+            Key::UnreportedTipsTax,
+        ]);
+        let total_tax = AmendedLine::zip(tax_after_credits, other_taxes, |a, b| a + b);
+        let withholding = line(&[Key::Withholding]);
+        let estimated_tax_payments = line(&[Key::EstimatedPayments]);
+        let refundable_credits = line(&[Key::RefundableCredits]);
+
+        let total_payments = withholding.correct
+            + estimated_tax_payments.correct
+            + refundable_credits.correct
+            + prior.paid;
+        let payments_less_overpayment = total_payments - prior.overpayment;
+        let overpaid = (payments_less_overpayment - total_tax.correct).max(Usd::ZERO);
+
+        IrsForm1040X {
+            adjusted_gross_income,
+            deductions,
+            agi_less_deductions: AmendedLine::zip(adjusted_gross_income, deductions, |a, d| a - d),
+            exemptions: line(&[Key::Exemptions]),
+            qbi_deduction: line(&[Key::QbiDeduction]),
+            taxable_income: line(&[Key::TaxableIncome]),
+            tax,
+            nonrefundable_credits,
+            tax_after_credits,
+            other_taxes,
+            total_tax,
+            withholding,
+            estimated_tax_payments,
+            earned_income_credit: AmendedLine::default(),
+            refundable_credits,
+            paid_with_original: prior.paid,
+            total_payments,
+            overpayment_on_original: prior.overpayment,
+            payments_less_overpayment,
+            amount_owed: (total_tax.correct - payments_less_overpayment).max(Usd::ZERO),
+            overpaid,
+            refund: overpaid,
+            applied_to_estimated_tax: Usd::ZERO,
+            explanation: explain_changes(original, corrected),
+        }
+    }
+}
+
+/// Lists every ledger line whose amount differs between the two ledgers,
+/// in form order. A line missing from one ledger counts as zero.
+pub fn explain_changes(original: &Ledger, corrected: &Ledger) -> Vec<LedgerChange> {
+    Key::ALL
+        .into_iter()
+        .filter_map(|key| {
+            let before = original.get(&key).copied().unwrap_or(Usd::ZERO);
+            let after = corrected.get(&key).copied().unwrap_or(Usd::ZERO);
+            if before == after {
+                return None;
+            }
+            let (direction, change) = if after > before {
+                ("increased", after - before)
+            } else {
+                ("decreased", before - after)
+            };
+            Some(LedgerChange {
+                key,
+                original: before,
+                corrected: after,
+                text: format!(
+                    "{} {direction} by {change}, from {before} to {after}.",
+                    key.label()
+                ),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::compute_spine;
+
+    use super::*;
+    use crate::y2025::federal::source_forms;
+
+    fn ledger(wages: i64, withheld: i64) -> Ledger {
+        let mut input = source_forms::tests::input();
+        input.w2_wages = Usd::from_dollars(wages);
+        input.fed_withholding = Usd::from_dollars(withheld);
+        compute_spine(&Rules2025, &input).unwrap()
+    }
+
+    #[test]
+    fn unreported_wages_increase_tax_owed() {
+        let original = ledger(50_000, 6_000);
+        let corrected = ledger(55_000, 6_000);
+        let prior = PriorPayments::from_original(&original);
+        let form = IrsForm1040X::from_ledgers(&original, &corrected, prior);
+
+        assert_eq!(
+            form.adjusted_gross_income.net_change,
+            Usd::from_dollars(5_000)
+        );
+        assert_eq!(form.withholding.net_change, Usd::ZERO);
+        assert_eq!(form.total_tax.correct, corrected[&Key::TotalTax]);
+        // The original refund was received, so the added tax is owed.
+        assert_eq!(form.overpayment_on_original, original[&Key::Refund]);
+        assert_eq!(form.amount_owed, form.total_tax.net_change);
+        assert_eq!(form.refund, Usd::ZERO);
+    }
+
+    #[test]
+    fn missed_withholding_refunded() {
+        let original = ledger(50_000, 3_000);
+        let corrected = ledger(50_000, 5_000);
+        let prior = PriorPayments::from_original(&original);
+        let form = IrsForm1040X::from_ledgers(&original, &corrected, prior);
+        assert_eq!(form.paid_with_original, original[&Key::AmountOwed]);
+        assert_eq!(form.amount_owed, Usd::ZERO);
+        assert_eq!(form.refund, Usd::from_dollars(2_000));
+    }
+
+    #[test]
+    fn explanation_lists_changed_lines() {
+        let original = ledger(50_000, 5_000);
+        let corrected = ledger(50_000, 5_500);
+        let changes = explain_changes(&original, &corrected);
+        let keys: Vec<Key> = changes.iter().map(|c| c.key).collect();
+        assert_eq!(keys[..2], [Key::Withholding, Key::TotalPayments]);
+        assert_eq!(
+            changes[0].text,
+            "Federal income tax withheld increased by $500.00, from $5000.00 to $5500.00."
+        );
+    }
+}
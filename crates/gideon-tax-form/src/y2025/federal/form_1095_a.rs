@@ -0,0 +1,144 @@
+use gideon_tax_core::premium_tax_credit::{CoverageMonth, PovertyRegion};
+use gideon_tax_core::{Date, Rate};
+
+/// Form 1095-A, Health Insurance Marketplace Statement.
+///
+/// Monthly columns are indexed January (0) through December (11).
+///
+/// See: <https://www.irs.gov/instructions/i109495a>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1095A {
+    /// Line 1.
+    pub marketplace_identifier: String,
+    /// Line 2.
+    pub marketplace_policy_number: String,
+    /// Line 3.
+    pub policy_issuer_name: String,
+    /// Line 4.
+    pub recipient_name: String,
+    /// Line 5.
+    pub recipient_ssn: String,
+    /// Line 10.
+    pub policy_start_date: Option<Date>,
+    /// Line 11.
+    pub policy_termination_date: Option<Date>,
+    /// Line 14, two-letter postal abbreviation.
+    pub recipient_state: String,
+    /// Lines 16–20.
+    pub covered_individuals: Vec<CoveredIndividual>,
+    /// Lines 21–32, columns A–C.
+    pub monthly: [CoverageMonth; 12],
+    /// Not on the form: how this policy is shared with another tax family
+    /// (Form 8962, Part IV). Months outside every allocation are allocated
+    /// entirely to this return.
+    pub allocations: Vec<PolicyAllocation>,
+}
+
+/// One individual covered by the policy (Part II).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoveredIndividual {
+    /// Column A.
+    pub name: String,
+    /// Column B.
+    pub ssn: String,
+    /// Column C, when no SSN is entered.
+    pub date_of_birth: Option<Date>,
+    /// Column D.
+    pub coverage_start_date: Option<Date>,
+    /// Column E.
+    pub coverage_termination_date: Option<Date>,
+}
+
+/// Allocation of a policy shared with another tax family for a range of
+/// months (Form 8962, lines 30–33).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolicyAllocation {
+    /// First month allocated, 0 = January.
+    pub start_month: usize,
+    /// Last month allocated, inclusive.
+    pub end_month: usize,
+    /// Share of the enrollment premium (column (c)).
+    pub premium: Rate,
+    /// Share of the SLCSP premium (column (d)).
+    pub slcsp: Rate,
+    /// Share of the advance payments (column (e)).
+    pub advance_payment: Rate,
+}
+
+impl Irs1095A {
+    /// Poverty guidelines region implied by the recipient's state.
+    pub fn poverty_region(&self) -> PovertyRegion {
+        match self.recipient_state.trim().to_ascii_uppercase().as_str() {
+            "AK" => PovertyRegion::Alaska,
+            "HI" => PovertyRegion::Hawaii,
+            _ => PovertyRegion::Contiguous,
+        }
+    }
+
+    /// Monthly amounts allocated to this return, for Form 8962, lines 12–23.
+    pub fn allocated_months(&self) -> [CoverageMonth; 12] {
+        std::array::from_fn(|m| {
+            let month = self.monthly[m];
+            match self
+                .allocations
+                .iter()
+                .find(|a| (a.start_month..=a.end_month).contains(&m))
+            {
+                Some(a) => CoverageMonth {
+                    enrollment_premium: month.enrollment_premium * a.premium,
+                    slcsp_premium: month.slcsp_premium * a.slcsp,
+                    advance_payment: month.advance_payment * a.advance_payment,
+                },
+                None => month,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::Usd;
+
+    use super::*;
+
+    fn month(premium: i64, slcsp: i64, advance: i64) -> CoverageMonth {
+        CoverageMonth {
+            enrollment_premium: Usd::from_dollars(premium),
+            slcsp_premium: Usd::from_dollars(slcsp),
+            advance_payment: Usd::from_dollars(advance),
+        }
+    }
+
+    #[test]
+    fn poverty_region_from_state() {
+        let mut form = Irs1095A {
+            recipient_state: "hi".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(form.poverty_region(), PovertyRegion::Hawaii);
+        form.recipient_state = "OH".to_string();
+        assert_eq!(form.poverty_region(), PovertyRegion::Contiguous);
+    }
+
+    #[test]
+    fn shared_policy_allocated_for_months() {
+        let form = Irs1095A {
+            monthly: [month(800, 700, 600); 12],
+            allocations: vec![PolicyAllocation {
+                start_month: 6,
+                end_month: 11,
+                premium: Rate::from_percent(50),
+                slcsp: Rate::ZERO,
+                advance_payment: Rate::from_percent(50),
+            }],
+            ..Default::default()
+        };
+        let months = form.allocated_months();
+        assert_eq!(months[5], month(800, 700, 600));
+        assert_eq!(months[6], month(400, 0, 300));
+        assert_eq!(months[11], month(400, 0, 300));
+    }
+}
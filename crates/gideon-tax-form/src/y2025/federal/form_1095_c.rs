@@ -0,0 +1,339 @@
+use gideon_tax_core::Usd;
+use gideon_tax_core::diagnostics::Diagnostic;
+use gideon_tax_core::employer_coverage::{self, EmployerOffer, PtcEligibility};
+use gideon_tax_core::rules::TaxYearRules;
+
+use super::IrsW2;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Form 1095-C, Employer-Provided Health Insurance Offer and Coverage.
+///
+/// Monthly columns are indexed January (0) through December (11).
+///
+/// See: <https://www.irs.gov/instructions/i109495c>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1095C {
+    /// Line 1.
+    pub employee_name: String,
+    /// Line 2.
+    pub employee_ssn: String,
+    /// Line 7.
+    pub employer_name: String,
+    /// Line 8.
+    pub employer_ein: String,
+    /// Line 14.
+    pub offer_of_coverage: [Option<OfferCode>; 12],
+    /// Line 15, monthly.
+    pub employee_required_contribution: [Option<Usd>; 12],
+    /// Line 16.
+    pub section_4980h_safe_harbor: [Option<SafeHarborCode>; 12],
+}
+
+/// Line 14 offer of coverage codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OfferCode {
+    Code1A,
+    Code1B,
+    Code1C,
+    Code1D,
+    Code1E,
+    Code1F,
+    Code1G,
+    Code1H,
+    Code1J,
+    Code1K,
+    Code1L,
+    Code1M,
+    Code1N,
+    Code1O,
+    Code1P,
+    Code1Q,
+    Code1R,
+    Code1S,
+    Code1T,
+    Code1U,
+}
+
+/// Line 16 section 4980H safe harbor and other relief codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SafeHarborCode {
+    Code2A,
+    Code2B,
+    Code2C,
+    Code2D,
+    Code2E,
+    Code2F,
+    Code2G,
+    Code2H,
+    Code2I,
+}
+
+impl OfferCode {
+    pub const ALL: [OfferCode; 20] = {
+        use OfferCode::*;
+        [
+            Code1A, Code1B, Code1C, Code1D, Code1E, Code1F, Code1G, Code1H, Code1J, Code1K, Code1L,
+            Code1M, Code1N, Code1O, Code1P, Code1Q, Code1R, Code1S, Code1T, Code1U,
+        ]
+    };
+
+    /// The official code as printed on line 14.
+    pub fn code(self) -> &'static str {
+        use OfferCode::*;
+        match self {
+            Code1A => "1A",
+            Code1B => "1B",
+            Code1C => "1C",
+            Code1D => "1D",
+            Code1E => "1E",
+            Code1F => "1F",
+            Code1G => "1G",
+            Code1H => "1H",
+            Code1J => "1J",
+            Code1K => "1K",
+            Code1L => "1L",
+            Code1M => "1M",
+            Code1N => "1N",
+            Code1O => "1O",
+            Code1P => "1P",
+            Code1Q => "1Q",
+            Code1R => "1R",
+            Code1S => "1S",
+            Code1T => "1T",
+            Code1U => "1U",
+        }
+    }
+
+    /// Parses an official code (case-insensitive).
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|c| c.code().eq_ignore_ascii_case(code))
+    }
+
+    /// `true` if the employee was offered minimum essential coverage that
+    /// provides minimum value.
+    pub fn is_minimum_value_offer(self) -> bool {
+        use OfferCode::*;
+        matches!(
+            self,
+            Code1A | Code1B | Code1C | Code1D | Code1E | Code1J | Code1K
+        )
+    }
+
+    /// `true` for individual coverage HRA offers, whose affordability depends
+    /// on the lowest-cost silver plan premium rather than line 15 alone.
+    pub fn is_individual_coverage_hra(self) -> bool {
+        use OfferCode::*;
+        matches!(
+            self,
+            Code1L | Code1M | Code1N | Code1O | Code1P | Code1Q | Code1R | Code1S | Code1T | Code1U
+        )
+    }
+}
+
+impl SafeHarborCode {
+    pub const ALL: [SafeHarborCode; 9] = {
+        use SafeHarborCode::*;
+        [
+            Code2A, Code2B, Code2C, Code2D, Code2E, Code2F, Code2G, Code2H, Code2I,
+        ]
+    };
+
+    /// The official code as printed on line 16.
+    pub fn code(self) -> &'static str {
+        use SafeHarborCode::*;
+        match self {
+            Code2A => "2A",
+            Code2B => "2B",
+            Code2C => "2C",
+            Code2D => "2D",
+            Code2E => "2E",
+            Code2F => "2F",
+            Code2G => "2G",
+            Code2H => "2H",
+            Code2I => "2I",
+        }
+    }
+
+    /// Parses an official code (case-insensitive).
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|c| c.code().eq_ignore_ascii_case(code))
+    }
+}
+
+impl Irs1095C {
+    /// The offer made for `month` (0 = January), if any.
+    ///
+    /// Individual coverage HRA offers return `None`; see
+    /// [`affordability_diagnostics`](Irs1095C::affordability_diagnostics).
+    pub fn employer_offer(&self, month: usize) -> Option<EmployerOffer> {
+        let code = self.offer_of_coverage[month]?;
+        let enrolled = self.section_4980h_safe_harbor[month] == Some(SafeHarborCode::Code2C);
+        if code == OfferCode::Code1H || code.is_individual_coverage_hra() {
+            return None;
+        }
+        Some(EmployerOffer {
+            minimum_value: code.is_minimum_value_offer(),
+            // A qualifying offer (1A) costs no more than 9.5% of the poverty
+            // line and may leave line 15 blank.
+            employee_required_contribution: self.employee_required_contribution[month]
+                .unwrap_or(Usd::ZERO),
+            enrolled,
+        })
+    }
+
+    /// Premium tax credit eligibility for each month.
+    pub fn ptc_eligibility(
+        &self,
+        rules: &dyn TaxYearRules,
+        household_income: Usd,
+    ) -> [PtcEligibility; 12] {
+        std::array::from_fn(|m| {
+            employer_coverage::employee_ptc_eligibility(
+                rules,
+                self.employer_offer(m).as_ref(),
+                household_income,
+            )
+        })
+    }
+
+    /// Flags months in which an affordable, minimum-value offer makes the
+    /// employee ineligible for the premium tax credit.
+    ///
+    /// Uses `household_income` when known; otherwise falls back to box 1 of
+    /// the offering employer's W-2, the same measure as the employer's W-2
+    /// affordability safe harbor.
+    pub fn affordability_diagnostics(
+        &self,
+        rules: &dyn TaxYearRules,
+        w2: &IrsW2,
+        household_income: Option<Usd>,
+    ) -> Vec<Diagnostic> {
+        let income = household_income.unwrap_or(w2.wages_tips_other_comp);
+        let eligibility = self.ptc_eligibility(rules, income);
+        let months_where = |pred: &dyn Fn(usize) -> bool| -> Vec<&str> {
+            (0..12).filter(|&m| pred(m)).map(|m| MONTHS[m]).collect()
+        };
+
+        let mut out = Vec::new();
+        let blocked = months_where(&|m| eligibility[m] == PtcEligibility::AffordableOffer);
+        if !blocked.is_empty() {
+            out.push(Diagnostic::warning(
+                "aca.affordable_employer_offer",
+                format!(
+                    "{} offered affordable, minimum-value coverage in {}; the premium tax credit \
+                     cannot be claimed for those months and any advance payments must be repaid",
+                    self.employer_name,
+                    blocked.join(", ")
+                ),
+            ));
+        }
+        let ichra = months_where(&|m| {
+            self.offer_of_coverage[m].is_some_and(OfferCode::is_individual_coverage_hra)
+        });
+        if !ichra.is_empty() {
+            out.push(Diagnostic::info(
+                "aca.ichra_affordability_not_evaluated",
+                format!(
+                    "Individual coverage HRA offered in {}; affordability was not evaluated",
+                    ichra.join(", ")
+                ),
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::rules::y2025::Rules2025;
+
+    use super::*;
+
+    fn form(code: OfferCode, premium: i64) -> Irs1095C {
+        Irs1095C {
+            employer_name: "Acme".to_string(),
+            offer_of_coverage: [Some(code); 12],
+            employee_required_contribution: [Some(Usd::from_dollars(premium)); 12],
+            ..Default::default()
+        }
+    }
+
+    fn w2(box1: i64) -> IrsW2 {
+        IrsW2 {
+            wages_tips_other_comp: Usd::from_dollars(box1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn code_round_trip() {
+        for code in OfferCode::ALL {
+            assert_eq!(OfferCode::from_code(code.code()), Some(code));
+        }
+        for code in SafeHarborCode::ALL {
+            assert_eq!(SafeHarborCode::from_code(code.code()), Some(code));
+        }
+    }
+
+    #[test]
+    fn affordable_offer_flagged_using_w2_box_1() {
+        let f = form(OfferCode::Code1E, 150);
+        let diags = f.affordability_diagnostics(&Rules2025, &w2(40_000), None);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].code, "aca.affordable_employer_offer");
+        assert!(diags[0].message.contains("Jan, Feb"));
+    }
+
+    #[test]
+    fn household_income_overrides_w2() {
+        // 400 * 12 = 4,800 > 9.02% of 40,000 but ≤ 9.02% of 60,000
+        let f = form(OfferCode::Code1B, 400);
+        assert!(
+            f.affordability_diagnostics(&Rules2025, &w2(40_000), None)
+                .is_empty()
+        );
+        assert_eq!(
+            f.affordability_diagnostics(&Rules2025, &w2(40_000), Some(Usd::from_dollars(60_000)))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn partial_year_offer() {
+        let mut f = form(OfferCode::Code1B, 100);
+        for m in 0..6 {
+            f.offer_of_coverage[m] = Some(OfferCode::Code1H);
+        }
+        let e = f.ptc_eligibility(&Rules2025, Usd::from_dollars(40_000));
+        assert_eq!(e[0], PtcEligibility::Eligible);
+        assert_eq!(e[6], PtcEligibility::AffordableOffer);
+    }
+
+    #[test]
+    fn enrolled_month() {
+        let mut f = form(OfferCode::Code1F, 100);
+        f.section_4980h_safe_harbor[0] = Some(SafeHarborCode::Code2C);
+        let e = f.ptc_eligibility(&Rules2025, Usd::from_dollars(40_000));
+        assert_eq!(e[0], PtcEligibility::EnrolledInEmployerCoverage);
+        assert_eq!(e[1], PtcEligibility::Eligible);
+    }
+
+    #[test]
+    fn ichra_not_evaluated() {
+        let f = form(OfferCode::Code1L, 0);
+        let diags = f.affordability_diagnostics(&Rules2025, &w2(40_000), None);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].code, "aca.ichra_affordability_not_evaluated");
+    }
+}
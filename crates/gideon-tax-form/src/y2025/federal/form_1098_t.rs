@@ -0,0 +1,77 @@
+use gideon_tax_core::Usd;
+use gideon_tax_core::education::StudentExpenses;
+
+/// Form 1098-T, Tuition Statement.
+///
+/// See: <https://www.irs.gov/instructions/i1098et>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1098T {
+    pub filer_name_address: String,
+    pub filer_ein: String,
+    pub student_tin: String,
+    pub student_name: String,
+    pub account_number: Option<String>,
+    /// Box 1.
+    pub payments_received: Usd,
+    /// Box 4.
+    pub adjustments_prior_year: Usd,
+    /// Box 5.
+    pub scholarships_or_grants: Usd,
+    /// Box 6.
+    pub scholarship_adjustments_prior_year: Usd,
+    /// Box 7.
+    pub includes_next_year_academic_period: bool,
+    /// Box 8.
+    pub at_least_half_time: bool,
+    /// Box 9.
+    pub graduate_student: bool,
+    /// Box 10.
+    pub insurance_reimbursements: Usd,
+}
+
+impl Irs1098T {
+    /// Current-year expenses and assistance reported on this form.
+    ///
+    /// Box 1 payments are qualified expenses for the year paid, including
+    /// amounts for an academic period beginning in January–March of next
+    /// year (box 7). Insurance reimbursements (box 10) reduce them. Boxes 4
+    /// and 6 adjust a prior year's expenses and scholarships and may require
+    /// recapture of a prior-year credit; they do not change this year's
+    /// amounts. Course materials bought elsewhere are not reported and must
+    /// be added by the caller.
+    pub fn student_expenses(&self) -> StudentExpenses {
+        StudentExpenses {
+            tuition_and_fees: (self.payments_received - self.insurance_reimbursements)
+                .max(Usd::ZERO),
+            course_materials: Usd::ZERO,
+            scholarships: self.scholarships_or_grants,
+            scholarships_elected_taxable: Usd::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::education::EducationCredit;
+
+    use super::*;
+
+    #[test]
+    fn qualified_expenses_net_of_scholarships() {
+        let form = Irs1098T {
+            payments_received: Usd::from_dollars(9_000),
+            scholarships_or_grants: Usd::from_dollars(4_000),
+            insurance_reimbursements: Usd::from_dollars(500),
+            adjustments_prior_year: Usd::from_dollars(1_000),
+            ..Default::default()
+        };
+        let s = form.student_expenses();
+        assert_eq!(s.tuition_and_fees, Usd::from_dollars(8_500));
+        assert_eq!(
+            s.adjusted_qualified_expenses(EducationCredit::LifetimeLearning),
+            Usd::from_dollars(4_500)
+        );
+        assert_eq!(s.taxable_scholarships(), Usd::ZERO);
+    }
+}
@@ -0,0 +1,52 @@
+use gideon_tax_core::{Date, Usd};
+
+/// Form 1099-B, Proceeds From Broker and Barter Exchange Transactions,
+/// one per sale.
+///
+/// See: <https://www.irs.gov/instructions/i1099b>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1099B {
+    pub payer_name_address: String,
+    pub payer_tin: String,
+    pub recipient_tin: String,
+    pub recipient_name: String,
+    pub account_number: Option<String>,
+    /// Box 1a.
+    pub description: String,
+    /// Box 1b, or `None` if various or not reported.
+    pub date_acquired: Option<Date>,
+    /// Box 1c.
+    pub date_sold: Option<Date>,
+    /// Box 1d.
+    pub proceeds: Usd,
+    /// Box 1e.
+    pub cost_basis: Usd,
+    /// Box 1f.
+    pub accrued_market_discount: Usd,
+    /// Box 1g.
+    pub wash_sale_loss_disallowed: Usd,
+    /// Box 2.
+    pub gain_type: GainType,
+    /// Box 4.
+    pub federal_income_tax_withheld: Usd,
+    /// Box 12: basis reported to the IRS.
+    pub basis_reported_to_irs: bool,
+}
+
+/// Box 2 checkboxes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GainType {
+    #[default]
+    ShortTerm,
+    LongTerm,
+    Ordinary,
+}
+
+impl Irs1099B {
+    /// Proceeds less basis, adding back any disallowed wash sale loss.
+    pub fn gain_or_loss(&self) -> Usd {
+        self.proceeds - self.cost_basis + self.wash_sale_loss_disallowed
+    }
+}
@@ -0,0 +1,38 @@
+use gideon_tax_core::Usd;
+
+/// Form 1099-DIV, Dividends and Distributions.
+///
+/// See: <https://www.irs.gov/instructions/i1099div>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1099Div {
+    pub payer_name_address: String,
+    pub payer_tin: String,
+    pub recipient_tin: String,
+    pub recipient_name: String,
+    pub account_number: Option<String>,
+    /// Box 1a.
+    pub total_ordinary_dividends: Usd,
+    /// Box 1b.
+    pub qualified_dividends: Usd,
+    /// Box 2a.
+    pub total_capital_gain_distributions: Usd,
+    /// Box 2b.
+    pub unrecaptured_section_1250_gain: Usd,
+    /// Box 2c.
+    pub section_1202_gain: Usd,
+    /// Box 2d.
+    pub collectibles_gain: Usd,
+    /// Box 3.
+    pub nondividend_distributions: Usd,
+    /// Box 4.
+    pub federal_income_tax_withheld: Usd,
+    /// Box 5.
+    pub section_199a_dividends: Usd,
+    /// Box 6.
+    pub investment_expenses: Usd,
+    /// Box 7.
+    pub foreign_tax_paid: Usd,
+    /// Box 12.
+    pub exempt_interest_dividends: Usd,
+}
@@ -0,0 +1,28 @@
+use gideon_tax_core::Usd;
+
+/// Form 1099-G, Certain Government Payments.
+///
+/// See: <https://www.irs.gov/instructions/i1099g>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1099G {
+    pub payer_name_address: String,
+    pub payer_tin: String,
+    pub recipient_tin: String,
+    pub recipient_name: String,
+    pub account_number: Option<String>,
+    /// Box 1.
+    pub unemployment_compensation: Usd,
+    /// Box 2.
+    pub state_or_local_income_tax_refunds: Usd,
+    /// Box 3: tax year of the box 2 refund.
+    pub refund_tax_year: Option<i32>,
+    /// Box 4.
+    pub federal_income_tax_withheld: Usd,
+    /// Box 5.
+    pub rtaa_payments: Usd,
+    /// Box 6.
+    pub taxable_grants: Usd,
+    /// Box 7.
+    pub agriculture_payments: Usd,
+}
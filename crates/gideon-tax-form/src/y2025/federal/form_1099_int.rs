@@ -0,0 +1,30 @@
+use gideon_tax_core::Usd;
+
+/// Form 1099-INT, Interest Income.
+///
+/// See: <https://www.irs.gov/instructions/i1099int>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1099Int {
+    pub payer_name_address: String,
+    pub payer_tin: String,
+    pub recipient_tin: String,
+    pub recipient_name: String,
+    pub account_number: Option<String>,
+    /// Box 1.
+    pub interest_income: Usd,
+    /// Box 2.
+    pub early_withdrawal_penalty: Usd,
+    /// Box 3.
+    pub us_savings_bond_and_treasury_interest: Usd,
+    /// Box 4.
+    pub federal_income_tax_withheld: Usd,
+    /// Box 5.
+    pub investment_expenses: Usd,
+    /// Box 6.
+    pub foreign_tax_paid: Usd,
+    /// Box 8.
+    pub tax_exempt_interest: Usd,
+    /// Box 9.
+    pub private_activity_bond_interest: Usd,
+}
@@ -0,0 +1,45 @@
+use gideon_tax_core::Usd;
+
+/// Form 1099-MISC, Miscellaneous Information.
+///
+/// See: <https://www.irs.gov/instructions/i1099mec>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1099Misc {
+    pub payer_name_address: String,
+    pub payer_tin: String,
+    pub recipient_tin: String,
+    pub recipient_name: String,
+    pub account_number: Option<String>,
+    /// Box 1.
+    pub rents: Usd,
+    /// Box 2.
+    pub royalties: Usd,
+    /// Box 3.
+    pub other_income: Usd,
+    /// Box 4.
+    pub federal_income_tax_withheld: Usd,
+    /// Box 5.
+    pub fishing_boat_proceeds: Usd,
+    /// Box 6.
+    pub medical_and_health_care_payments: Usd,
+    /// Box 7: payer made direct sales of $5,000 or more of consumer
+    /// products for resale.
+    pub direct_sales: bool,
+    /// Box 8.
+    pub substitute_payments: Usd,
+    /// Box 9.
+    pub crop_insurance_proceeds: Usd,
+    /// Box 10.
+    pub gross_proceeds_paid_to_attorney: Usd,
+    /// Box 11.
+    pub fish_purchased_for_resale: Usd,
+    /// Box 12.
+    pub section_409a_deferrals: Usd,
+    /// Box 13.
+    pub fatca_filing_requirement: bool,
+    /// Box 14.
+    pub excess_golden_parachute_payments: Usd,
+    /// Box 15.
+    pub nonqualified_deferred_compensation: Usd,
+}
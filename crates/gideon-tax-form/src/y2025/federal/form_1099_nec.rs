@@ -0,0 +1,21 @@
+use gideon_tax_core::Usd;
+
+/// Form 1099-NEC, Nonemployee Compensation.
+///
+/// See: <https://www.irs.gov/instructions/i1099mec>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1099Nec {
+    pub payer_name_address: String,
+    pub payer_tin: String,
+    pub recipient_tin: String,
+    pub recipient_name: String,
+    pub account_number: Option<String>,
+    /// Box 1.
+    pub nonemployee_compensation: Usd,
+    /// Box 2: payer made direct sales of $5,000 or more of consumer
+    /// products for resale.
+    pub direct_sales: bool,
+    /// Box 4.
+    pub federal_income_tax_withheld: Usd,
+}
@@ -0,0 +1,33 @@
+use gideon_tax_core::Usd;
+
+/// Form 1099-R, Distributions From Pensions, Annuities, Retirement or
+/// Profit-Sharing Plans, IRAs, Insurance Contracts, etc.
+///
+/// See: <https://www.irs.gov/instructions/i1099r>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1099R {
+    pub payer_name_address: String,
+    pub payer_tin: String,
+    pub recipient_tin: String,
+    pub recipient_name: String,
+    pub account_number: Option<String>,
+    /// Box 1.
+    pub gross_distribution: Usd,
+    /// Box 2a.
+    pub taxable_amount: Usd,
+    /// Box 2b: taxable amount not determined.
+    pub taxable_amount_not_determined: bool,
+    /// Box 2b: total distribution.
+    pub total_distribution: bool,
+    /// Box 3.
+    pub capital_gain: Usd,
+    /// Box 4.
+    pub federal_income_tax_withheld: Usd,
+    /// Box 5.
+    pub employee_contributions: Usd,
+    /// Box 7, one or two codes as printed.
+    pub distribution_codes: String,
+    /// Box 7: IRA/SEP/SIMPLE.
+    pub ira_sep_simple: bool,
+}
@@ -0,0 +1,93 @@
+use gideon_tax_core::Usd;
+
+/// Form 1099-SA, Distributions From an HSA, Archer MSA, or Medicare
+/// Advantage MSA.
+///
+/// See: <https://www.irs.gov/instructions/i1099sa>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs1099Sa {
+    pub payer_name_address: String,
+    pub payer_tin: String,
+    pub recipient_tin: String,
+    pub recipient_name: String,
+    pub account_number: Option<String>,
+    /// Box 1.
+    pub gross_distribution: Usd,
+    /// Box 2.
+    pub earnings_on_excess_contributions: Usd,
+    /// Box 3.
+    pub distribution_code: DistributionCode,
+    /// Box 4.
+    pub fmv_on_date_of_death: Usd,
+    /// Box 5.
+    pub account_type: AccountType,
+}
+
+/// Box 3 distribution codes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DistributionCode {
+    /// Code 1.
+    #[default]
+    Normal,
+    /// Code 2.
+    ExcessContributions,
+    /// Code 3.
+    Disability,
+    /// Code 4.
+    Death,
+    /// Code 5.
+    ProhibitedTransaction,
+    /// Code 6.
+    DeathNonspouseAfterYearOfDeath,
+}
+
+/// Box 5 account type checkbox.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccountType {
+    #[default]
+    Hsa,
+    ArcherMsa,
+    MedicareAdvantageMsa,
+}
+
+impl DistributionCode {
+    pub const ALL: [DistributionCode; 6] = [
+        DistributionCode::Normal,
+        DistributionCode::ExcessContributions,
+        DistributionCode::Disability,
+        DistributionCode::Death,
+        DistributionCode::ProhibitedTransaction,
+        DistributionCode::DeathNonspouseAfterYearOfDeath,
+    ];
+
+    /// The official code printed in box 3.
+    pub fn code(self) -> &'static str {
+        match self {
+            DistributionCode::Normal => "1",
+            DistributionCode::ExcessContributions => "2",
+            DistributionCode::Disability => "3",
+            DistributionCode::Death => "4",
+            DistributionCode::ProhibitedTransaction => "5",
+            DistributionCode::DeathNonspouseAfterYearOfDeath => "6",
+        }
+    }
+
+    /// Parses a box 3 code, ignoring surrounding whitespace.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL.into_iter().find(|c| c.code() == code)
+    }
+
+    /// `true` if the code indicates the account beneficiary was disabled or
+    /// died, which waives the additional 20% tax (Form 8889, line 17a).
+    pub fn waives_additional_tax(self) -> bool {
+        matches!(
+            self,
+            DistributionCode::Disability
+                | DistributionCode::Death
+                | DistributionCode::DeathNonspouseAfterYearOfDeath
+        )
+    }
+}
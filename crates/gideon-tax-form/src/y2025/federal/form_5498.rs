@@ -0,0 +1,310 @@
+use gideon_tax_core::diagnostics::Diagnostic;
+use gideon_tax_core::ira;
+use gideon_tax_core::rules::TaxYearRules;
+use gideon_tax_core::{Date, Usd};
+
+/// Form 5498, IRA Contribution Information.
+///
+/// Contributions in boxes 1 and 10 are those made for the tax year,
+/// including those made by the due date of the return.
+///
+/// See: <https://www.irs.gov/instructions/i5498>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Irs5498 {
+    pub trustee_name: String,
+    pub trustee_tin: String,
+    pub participant_name: String,
+    pub participant_tin: String,
+    /// Box 1.
+    pub ira_contributions: Usd,
+    /// Box 2.
+    pub rollover_contributions: Usd,
+    /// Box 3.
+    pub roth_conversion: Usd,
+    /// Box 4.
+    pub recharacterized_contributions: Usd,
+    /// Box 5.
+    pub fair_market_value: Usd,
+    /// Box 7.
+    pub account_type: IraType,
+    /// Box 8.
+    pub sep_contributions: Usd,
+    /// Box 9.
+    pub simple_contributions: Usd,
+    /// Box 10.
+    pub roth_ira_contributions: Usd,
+    /// Box 11.
+    pub rmd_next_year: bool,
+    /// Box 12a.
+    pub rmd_date: Option<Date>,
+    /// Box 12b.
+    pub rmd_amount: Option<Usd>,
+    /// Box 13a.
+    pub postponed_contribution: Usd,
+    /// Box 13b.
+    pub postponed_contribution_year: Option<i32>,
+}
+
+/// Box 7 account type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IraType {
+    #[default]
+    Ira,
+    Sep,
+    Simple,
+    RothIra,
+}
+
+impl IraType {
+    pub const ALL: [IraType; 4] = [
+        IraType::Ira,
+        IraType::Sep,
+        IraType::Simple,
+        IraType::RothIra,
+    ];
+
+    /// The checkbox label in box 7.
+    pub fn code(self) -> &'static str {
+        match self {
+            IraType::Ira => "IRA",
+            IraType::Sep => "SEP",
+            IraType::Simple => "SIMPLE",
+            IraType::RothIra => "Roth IRA",
+        }
+    }
+
+    /// Parses a box 7 label, ignoring case and surrounding whitespace.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|c| c.code().eq_ignore_ascii_case(code))
+    }
+}
+
+/// The participant facts needed to check contributions against the limit.
+///
+/// Not on the form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IraParticipant {
+    pub age_50_or_older: bool,
+    /// Taxable compensation, or for a spousal IRA, the compensation
+    /// available under [`ira::contribution_limit`].
+    pub compensation: Usd,
+}
+
+/// A contribution moved from one type of IRA to the other, as reported in
+/// box 4 of the receiving IRA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recharacterization {
+    pub trustee_name: String,
+    /// Type of the IRA that received the contribution.
+    pub into: IraType,
+    pub amount: Usd,
+}
+
+/// Result of [`reconcile_ira_contributions`] for one participant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IraContributionReconciliation {
+    /// Contributions to traditional IRAs after recharacterizations.
+    pub traditional_contributions: Usd,
+    /// Contributions to Roth IRAs after recharacterizations.
+    pub roth_contributions: Usd,
+    pub limit: Usd,
+    /// Contributions over `limit`, subject to the 6% excise tax
+    /// (Form 5329, Part III) unless withdrawn by the due date.
+    pub excess_contributions: Usd,
+    pub recharacterizations: Vec<Recharacterization>,
+    /// Deduction claimed on Schedule 1, line 20.
+    pub claimed_deduction: Usd,
+}
+
+/// Reconciles one participant's Forms 5498 with the IRA deduction claimed.
+///
+/// A recharacterized contribution stays in box 1 or box 10 of the first
+/// IRA and appears again in box 4 of the receiving IRA, so box 4 amounts
+/// are moved between the traditional and Roth totals. SEP and SIMPLE
+/// contributions are employer plan contributions and are not counted.
+pub fn reconcile_ira_contributions(
+    rules: &dyn TaxYearRules,
+    forms: &[Irs5498],
+    participant: IraParticipant,
+    claimed_deduction: Usd,
+) -> IraContributionReconciliation {
+    let recharacterizations: Vec<Recharacterization> = forms
+        .iter()
+        .filter(|f| f.recharacterized_contributions > Usd::ZERO)
+        .map(|f| Recharacterization {
+            trustee_name: f.trustee_name.clone(),
+            into: f.account_type,
+            amount: f.recharacterized_contributions,
+        })
+        .collect();
+    let recharacterized_into = |kind: IraType| -> Usd {
+        recharacterizations
+            .iter()
+            .filter(|r| r.into == kind)
+            .map(|r| r.amount)
+            .sum()
+    };
+    let box_1: Usd = forms.iter().map(|f| f.ira_contributions).sum();
+    let box_10: Usd = forms.iter().map(|f| f.roth_ira_contributions).sum();
+    let to_traditional = recharacterized_into(IraType::Ira);
+    let to_roth = recharacterized_into(IraType::RothIra);
+
+    let limit =
+        ira::contribution_limit(rules, participant.age_50_or_older, participant.compensation);
+    IraContributionReconciliation {
+        traditional_contributions: box_1 + to_traditional - to_roth,
+        roth_contributions: box_10 + to_roth - to_traditional,
+        limit,
+        excess_contributions: (box_1 + box_10 - limit).max(Usd::ZERO),
+        recharacterizations,
+        claimed_deduction,
+    }
+}
+
+impl IraContributionReconciliation {
+    /// Flags a deduction larger than traditional contributions, excess
+    /// contributions, and recharacterizations that need a statement.
+    ///
+    /// The deduction phase-out for active participants in an employer plan
+    /// is not checked.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        if self.claimed_deduction > self.traditional_contributions {
+            out.push(Diagnostic::error(
+                "ira.deduction_exceeds_contributions",
+                format!(
+                    "IRA deduction of {} exceeds traditional IRA contributions of {} \
+                     reported on Form 5498",
+                    self.claimed_deduction, self.traditional_contributions
+                ),
+            ));
+        }
+        if self.excess_contributions > Usd::ZERO {
+            out.push(Diagnostic::warning(
+                "ira.excess_contribution",
+                format!(
+                    "IRA contributions exceed the {} limit by {}; withdraw the excess by the \
+                     due date or report the 6% excise tax on Form 5329",
+                    self.limit, self.excess_contributions
+                ),
+            ));
+        }
+        for r in &self.recharacterizations {
+            out.push(Diagnostic::info(
+                "ira.recharacterization",
+                format!(
+                    "{} recharacterized into a {} at {}; attach a statement explaining the \
+                     recharacterization",
+                    r.amount,
+                    r.into.code(),
+                    r.trustee_name
+                ),
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::rules::y2025::Rules2025;
+
+    use super::*;
+
+    const PARTICIPANT: IraParticipant = IraParticipant {
+        age_50_or_older: false,
+        compensation: Usd::from_dollars(60_000),
+    };
+
+    fn codes(r: &IraContributionReconciliation) -> Vec<&'static str> {
+        r.diagnostics().iter().map(|d| d.code).collect()
+    }
+
+    #[test]
+    fn account_type_round_trip() {
+        for kind in IraType::ALL {
+            assert_eq!(IraType::from_code(kind.code()), Some(kind));
+        }
+        assert_eq!(IraType::from_code(" roth ira"), Some(IraType::RothIra));
+    }
+
+    #[test]
+    fn deduction_within_contributions() {
+        let forms = [Irs5498 {
+            ira_contributions: Usd::from_dollars(6_000),
+            ..Default::default()
+        }];
+        let r =
+            reconcile_ira_contributions(&Rules2025, &forms, PARTICIPANT, Usd::from_dollars(6_000));
+        assert_eq!(r.excess_contributions, Usd::ZERO);
+        assert!(r.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn deduction_over_contributions_and_excess_flagged() {
+        let forms = [
+            Irs5498 {
+                ira_contributions: Usd::from_dollars(5_000),
+                ..Default::default()
+            },
+            Irs5498 {
+                account_type: IraType::RothIra,
+                roth_ira_contributions: Usd::from_dollars(4_000),
+                ..Default::default()
+            },
+        ];
+        let r =
+            reconcile_ira_contributions(&Rules2025, &forms, PARTICIPANT, Usd::from_dollars(7_000));
+        assert_eq!(r.excess_contributions, Usd::from_dollars(2_000));
+        assert_eq!(
+            codes(&r),
+            [
+                "ira.deduction_exceeds_contributions",
+                "ira.excess_contribution"
+            ]
+        );
+    }
+
+    #[test]
+    fn recharacterization_moves_contribution() {
+        // 7,000 contributed to a traditional IRA, then recharacterized to a
+        // Roth IRA.
+        let forms = [
+            Irs5498 {
+                trustee_name: "First Bank".to_string(),
+                ira_contributions: Usd::from_dollars(7_000),
+                ..Default::default()
+            },
+            Irs5498 {
+                trustee_name: "Second Bank".to_string(),
+                account_type: IraType::RothIra,
+                recharacterized_contributions: Usd::from_dollars(7_000),
+                ..Default::default()
+            },
+        ];
+        let r =
+            reconcile_ira_contributions(&Rules2025, &forms, PARTICIPANT, Usd::from_dollars(7_000));
+        assert_eq!(r.traditional_contributions, Usd::ZERO);
+        assert_eq!(r.roth_contributions, Usd::from_dollars(7_000));
+        assert_eq!(r.excess_contributions, Usd::ZERO);
+        assert_eq!(
+            codes(&r),
+            [
+                "ira.deduction_exceeds_contributions",
+                "ira.recharacterization"
+            ]
+        );
+        assert!(
+            r.diagnostics()[1]
+                .message
+                .contains("Roth IRA at Second Bank")
+        );
+    }
+}
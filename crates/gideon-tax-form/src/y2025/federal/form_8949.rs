@@ -0,0 +1,354 @@
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+
+use gideon_tax_core::capital_loss::{self, CapitalLossCarryover, ScheduleD};
+use gideon_tax_core::diagnostics::Diagnostic;
+use gideon_tax_core::{Date, FilingStatus, Usd};
+
+use super::{GainType, Irs1099B};
+
+/// Days before or after a loss sale in which buying a substantially
+/// identical security makes it a wash sale (IRC §1091(a)).
+const WASH_SALE_WINDOW_DAYS: i64 = 30;
+
+/// Where a sale's totals go on Schedule D, by holding period and whether
+/// the broker reported basis to the IRS.
+///
+/// Sales not reported on a Form 1099-B (boxes C and F) do not come from
+/// the stream and are not listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Form8949Category {
+    /// Basis reported and no adjustments: Schedule D, line 1a, without
+    /// Form 8949.
+    ShortTermReported,
+    /// Box A: basis reported, with adjustments.
+    BoxA,
+    /// Box B: basis not reported.
+    BoxB,
+    /// Long-term counterpart of
+    /// [`ShortTermReported`](Self::ShortTermReported): line 8a.
+    LongTermReported,
+    /// Box D: basis reported, with adjustments.
+    BoxD,
+    /// Box E: basis not reported.
+    BoxE,
+}
+
+impl Form8949Category {
+    pub const ALL: [Form8949Category; 6] = [
+        Form8949Category::ShortTermReported,
+        Form8949Category::BoxA,
+        Form8949Category::BoxB,
+        Form8949Category::LongTermReported,
+        Form8949Category::BoxD,
+        Form8949Category::BoxE,
+    ];
+
+    /// The Schedule D line the category's totals go on.
+    pub fn schedule_d_line(self) -> &'static str {
+        match self {
+            Form8949Category::ShortTermReported => "1a",
+            Form8949Category::BoxA => "1b",
+            Form8949Category::BoxB => "2",
+            Form8949Category::LongTermReported => "8a",
+            Form8949Category::BoxD => "8b",
+            Form8949Category::BoxE => "9",
+        }
+    }
+
+    pub fn is_long_term(self) -> bool {
+        matches!(
+            self,
+            Form8949Category::LongTermReported | Form8949Category::BoxD | Form8949Category::BoxE
+        )
+    }
+
+    fn of(sale: &Irs1099B, long_term: bool) -> Self {
+        let adjusted = sale.wash_sale_loss_disallowed != Usd::ZERO;
+        match (long_term, sale.basis_reported_to_irs, adjusted) {
+            (false, true, false) => Form8949Category::ShortTermReported,
+            (false, true, true) => Form8949Category::BoxA,
+            (false, false, _) => Form8949Category::BoxB,
+            (true, true, false) => Form8949Category::LongTermReported,
+            (true, true, true) => Form8949Category::BoxD,
+            (true, false, _) => Form8949Category::BoxE,
+        }
+    }
+}
+
+/// Totals for one [`Form8949Category`]: the columns of a Form 8949 totals
+/// row, or of Schedule D, line 1a or 8a.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form8949Totals {
+    pub sales: u64,
+    /// Column (d).
+    pub proceeds: Usd,
+    /// Column (e).
+    pub cost_basis: Usd,
+    /// Column (g): disallowed wash sale losses (code W).
+    pub adjustments: Usd,
+    /// Column (h).
+    pub gain_or_loss: Usd,
+}
+
+/// What the aggregator keeps about one security, by description.
+#[derive(Debug, Clone, Copy, Default)]
+struct SecurityState {
+    /// Latest sale at a loss the broker did not already adjust.
+    last_loss_sale: Option<Date>,
+    /// Latest acquisition date of a lot sold.
+    last_acquired: Option<Date>,
+    possible_wash_sales: u32,
+}
+
+/// Sums Forms 1099-B into Form 8949 and Schedule D totals one sale at a
+/// time, without keeping the sales.
+///
+/// Memory grows with the number of distinct securities, not lots. Wash
+/// sales the broker reported in box 1g are carried as adjustments. Wash
+/// sales it could not see, such as across accounts, are flagged when a
+/// lot of a security was acquired within 30 days of another lot's
+/// unadjusted loss sale. Each security remembers only its latest loss sale
+/// and acquisition, so a replacement lot is matched against those alone;
+/// flagged sales are not adjusted, because the disallowed loss depends on
+/// the replacement shares.
+#[derive(Debug, Clone, Default)]
+pub struct Form8949Aggregator {
+    totals: [Form8949Totals; 6],
+    ordinary_gain: Usd,
+    securities: BTreeMap<String, SecurityState>,
+}
+
+impl Form8949Aggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one sale. A box 2 ordinary gain is kept apart, as it is not
+    /// reported on Form 8949.
+    pub fn add(&mut self, sale: &Irs1099B) {
+        let gain = sale.gain_or_loss();
+        let long_term = match sale.gain_type {
+            GainType::ShortTerm => false,
+            GainType::LongTerm => true,
+            GainType::Ordinary => {
+                self.ordinary_gain += gain;
+                return;
+            }
+        };
+        let category = Form8949Category::of(sale, long_term);
+        let t = &mut self.totals[category as usize];
+        t.sales += 1;
+        t.proceeds += sale.proceeds;
+        t.cost_basis += sale.cost_basis;
+        t.adjustments += sale.wash_sale_loss_disallowed;
+        t.gain_or_loss += gain;
+
+        // Look up before inserting so a known security's description is not
+        // cloned for every lot.
+        if !self.securities.contains_key(&sale.description) {
+            self.securities
+                .insert(sale.description.clone(), SecurityState::default());
+        }
+        let state = self
+            .securities
+            .get_mut(&sale.description)
+            .expect("inserted above");
+        let within_window = |a: Option<Date>, b: Option<Date>| match (a, b) {
+            (Some(a), Some(b)) => a.days_until(b).abs() <= WASH_SALE_WINDOW_DAYS,
+            _ => false,
+        };
+        let loss_sale = (gain < Usd::ZERO && sale.wash_sale_loss_disallowed == Usd::ZERO)
+            .then_some(sale.date_sold)
+            .flatten();
+        if within_window(loss_sale, state.last_acquired)
+            || within_window(sale.date_acquired, state.last_loss_sale)
+        {
+            state.possible_wash_sales += 1;
+        }
+        state.last_loss_sale = state.last_loss_sale.max(loss_sale);
+        state.last_acquired = state.last_acquired.max(sale.date_acquired);
+    }
+
+    pub fn finish(self) -> Form8949Summary {
+        Form8949Summary {
+            totals: self.totals,
+            ordinary_gain: self.ordinary_gain,
+            possible_wash_sales: self
+                .securities
+                .into_iter()
+                .filter(|(_, s)| s.possible_wash_sales > 0)
+                .map(|(description, s)| (description, s.possible_wash_sales))
+                .collect(),
+        }
+    }
+}
+
+impl<S: Borrow<Irs1099B>> Extend<S> for Form8949Aggregator {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, sales: I) {
+        for sale in sales {
+            self.add(sale.borrow());
+        }
+    }
+}
+
+/// Aggregates a stream of Forms 1099-B, such as from a broker import read
+/// record by record.
+pub fn aggregate_form_8949<S: Borrow<Irs1099B>>(
+    sales: impl IntoIterator<Item = S>,
+) -> Form8949Summary {
+    let mut aggregator = Form8949Aggregator::new();
+    aggregator.extend(sales);
+    aggregator.finish()
+}
+
+/// Form 8949 and Schedule D totals from [`Form8949Aggregator`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form8949Summary {
+    /// Indexed in [`Form8949Category::ALL`] order.
+    pub totals: [Form8949Totals; 6],
+    /// Box 2 ordinary gains, reported outside Schedule D.
+    pub ordinary_gain: Usd,
+    /// Securities with sales that may be unreported wash sales, by
+    /// description, with the number of such sales.
+    pub possible_wash_sales: Vec<(String, u32)>,
+}
+
+impl Form8949Summary {
+    pub fn totals(&self, category: Form8949Category) -> Form8949Totals {
+        self.totals[category as usize]
+    }
+
+    fn net(&self, long_term: bool) -> Usd {
+        Form8949Category::ALL
+            .into_iter()
+            .filter(|c| c.is_long_term() == long_term)
+            .map(|c| self.totals(c).gain_or_loss)
+            .sum()
+    }
+
+    /// Schedule D, lines 1a through 3, column (h).
+    pub fn short_term_gain_or_loss(&self) -> Usd {
+        self.net(false)
+    }
+
+    /// Schedule D, lines 8a through 10, column (h).
+    pub fn long_term_gain_or_loss(&self) -> Usd {
+        self.net(true)
+    }
+
+    /// Schedule D from these sales and the prior-year carryover, without
+    /// capital gain distributions or K-1 gains.
+    pub fn schedule_d(
+        &self,
+        carryover: &CapitalLossCarryover,
+        filing_status: FilingStatus,
+    ) -> ScheduleD {
+        capital_loss::schedule_d(
+            self.short_term_gain_or_loss(),
+            self.long_term_gain_or_loss(),
+            carryover,
+            filing_status,
+        )
+    }
+
+    /// Flags securities with possible wash sales the broker did not
+    /// report.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.possible_wash_sales
+            .iter()
+            .map(|(description, count)| {
+                Diagnostic::warning(
+                    "form_8949.possible_wash_sale",
+                    format!(
+                        "{count} sale(s) of {description} are within 30 days of another lot's \
+                         loss sale and may be wash sales not reported on Form 1099-B"
+                    ),
+                )
+                .with_fix("Enter the disallowed loss as a code W adjustment on Form 8949")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sale(
+        description: &str,
+        acquired: (u8, u8),
+        sold: (u8, u8),
+        proceeds: i64,
+        cost: i64,
+    ) -> Irs1099B {
+        Irs1099B {
+            description: description.into(),
+            date_acquired: Date::new(2025, acquired.0, acquired.1),
+            date_sold: Date::new(2025, sold.0, sold.1),
+            proceeds: Usd::from_dollars(proceeds),
+            cost_basis: Usd::from_dollars(cost),
+            basis_reported_to_irs: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn categories_and_schedule_d() {
+        let mut wash = sale("XYZ", (1, 2), (2, 3), 800, 1_000);
+        wash.wash_sale_loss_disallowed = Usd::from_dollars(200);
+        let mut long_term = sale("ABC", (1, 2), (12, 1), 5_000, 1_000);
+        long_term.gain_type = GainType::LongTerm;
+        let mut unreported = sale("DEF", (3, 1), (4, 1), 1_000, 3_000);
+        unreported.basis_reported_to_irs = false;
+        let mut ordinary = sale("CONTRACT", (3, 1), (4, 1), 700, 0);
+        ordinary.gain_type = GainType::Ordinary;
+
+        let sales = [
+            sale("XYZ", (1, 2), (1, 20), 1_500, 1_000),
+            wash,
+            long_term,
+            unreported,
+            ordinary,
+        ];
+        let summary = aggregate_form_8949(&sales);
+        let a = summary.totals(Form8949Category::BoxA);
+        assert_eq!(
+            (a.sales, a.adjustments, a.gain_or_loss),
+            (1, Usd::from_dollars(200), Usd::from_dollars(0))
+        );
+        assert_eq!(
+            summary
+                .totals(Form8949Category::ShortTermReported)
+                .gain_or_loss,
+            Usd::from_dollars(500)
+        );
+        assert_eq!(
+            summary.totals(Form8949Category::BoxB).gain_or_loss,
+            Usd::from_dollars(-2_000)
+        );
+        assert_eq!(summary.long_term_gain_or_loss(), Usd::from_dollars(4_000));
+        assert_eq!(summary.ordinary_gain, Usd::from_dollars(700));
+        let s = summary.schedule_d(&CapitalLossCarryover::default(), FilingStatus::Single);
+        assert_eq!(s.capital_gain_or_loss, Usd::from_dollars(2_500));
+        assert!(summary.possible_wash_sales.is_empty());
+    }
+
+    #[test]
+    fn flags_replacement_lot_within_30_days() {
+        let summary = aggregate_form_8949([
+            // Loss sale on March 10, not adjusted by the broker.
+            sale("XYZ", (1, 2), (3, 10), 500, 1_000),
+            // Replacement bought March 25, sold later at a gain.
+            sale("XYZ", (3, 25), (9, 1), 1_200, 600),
+            // Bought well outside the window.
+            sale("XYZ", (6, 1), (9, 1), 1_200, 600),
+            sale("ABC", (3, 25), (9, 1), 1_200, 600),
+        ]);
+        assert_eq!(summary.possible_wash_sales, vec![("XYZ".to_string(), 1)]);
+        assert_eq!(summary.diagnostics().len(), 1);
+    }
+}
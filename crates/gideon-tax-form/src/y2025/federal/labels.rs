@@ -0,0 +1,388 @@
+/// Language a return's forms are printed in.
+///
+/// Only labels change between languages; the data structures and amounts
+/// are the same.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FormLanguage {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl FormLanguage {
+    pub const ALL: [FormLanguage; 2] = [FormLanguage::English, FormLanguage::Spanish];
+
+    /// ISO 639-1 language code.
+    pub fn code(self) -> &'static str {
+        match self {
+            FormLanguage::English => "en",
+            FormLanguage::Spanish => "es",
+        }
+    }
+
+    /// Parses a language code, ignoring case and surrounding whitespace.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|l| l.code().eq_ignore_ascii_case(code))
+    }
+
+    /// Form 1040 labels, or Form 1040-SP in Spanish.
+    pub fn form_1040(self) -> &'static FormLabels {
+        match self {
+            FormLanguage::English => &FORM_1040,
+            FormLanguage::Spanish => &FORM_1040_SP,
+        }
+    }
+
+    /// Form 1040-SR labels, or its Spanish version.
+    pub fn form_1040_sr(self) -> &'static FormLabels {
+        match self {
+            FormLanguage::English => &FORM_1040_SR,
+            FormLanguage::Spanish => &FORM_1040_SR_SP,
+        }
+    }
+
+    /// Form W-2 labels.
+    pub fn w2(self) -> &'static FormLabels {
+        match self {
+            FormLanguage::English => &W2,
+            FormLanguage::Spanish => &W2_SP,
+        }
+    }
+}
+
+/// Printed form number, title, and line or box labels of one form variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormLabels {
+    pub number: &'static str,
+    pub title: &'static str,
+    /// Labels keyed by line or box, as in the form's schema.
+    pub lines: &'static [(&'static str, &'static str)],
+}
+
+impl FormLabels {
+    /// Label printed next to `line`, if the form has that line.
+    pub fn label(&self, line: &str) -> Option<&'static str> {
+        self.lines
+            .iter()
+            .find(|(l, _)| l.eq_ignore_ascii_case(line.trim()))
+            .map(|(_, label)| *label)
+    }
+}
+
+/// Form 1040.
+pub const FORM_1040: FormLabels = FormLabels {
+    number: "1040",
+    title: "U.S. Individual Income Tax Return",
+    lines: &[
+        ("1a", "Total amount from Form(s) W-2, box 1"),
+        ("1b", "Household employee wages not reported on Form(s) W-2"),
+        ("1c", "Tip income not reported on line 1a"),
+        ("1d", "Medicaid waiver payments not reported on Form(s) W-2"),
+        (
+            "1e",
+            "Taxable dependent care benefits from Form 2441, line 26",
+        ),
+        (
+            "1f",
+            "Employer-provided adoption benefits from Form 8839, line 29",
+        ),
+        ("1g", "Wages from Form 8919, line 6"),
+        ("1h", "Other earned income"),
+        ("1i", "Nontaxable combat pay election"),
+        ("1z", "Add lines 1a through 1h"),
+        ("2a", "Tax-exempt interest"),
+        ("2b", "Taxable interest"),
+        ("3a", "Qualified dividends"),
+        ("3b", "Ordinary dividends"),
+        ("4a", "IRA distributions"),
+        ("4b", "Taxable amount"),
+        ("5a", "Pensions and annuities"),
+        ("5b", "Taxable amount"),
+        ("6a", "Social security benefits"),
+        ("6b", "Taxable amount"),
+        ("7", "Capital gain or (loss)"),
+        ("8", "Additional income from Schedule 1, line 10"),
+        ("9", "This is your total income"),
+        ("10", "Adjustments to income from Schedule 1, line 26"),
+        ("11", "This is your adjusted gross income"),
+        (
+            "12",
+            "Standard deduction or itemized deductions (from Schedule A)",
+        ),
+        ("13a", "Qualified business income deduction"),
+        ("13b", "Additional deductions from Schedule 1-A, line 38"),
+        ("14", "Add lines 12, 13a, and 13b"),
+        ("15", "This is your taxable income"),
+        ("16", "Tax"),
+        ("17", "Amount from Schedule 2, line 3"),
+        ("18", "Add lines 16 and 17"),
+        ("19", "Child tax credit or credit for other dependents"),
+        ("20", "Amount from Schedule 3, line 8"),
+        ("21", "Add lines 19 and 20"),
+        ("22", "Subtract line 21 from line 18"),
+        ("23", "Other taxes, including self-employment tax"),
+        ("24", "This is your total tax"),
+        ("25a", "Federal income tax withheld from Form(s) W-2"),
+        ("25b", "Federal income tax withheld from Form(s) 1099"),
+        ("25c", "Federal income tax withheld from other forms"),
+        ("25d", "Add lines 25a through 25c"),
+        (
+            "26",
+            "Estimated tax payments and amount applied from prior year return",
+        ),
+        ("27", "Earned income credit (EIC)"),
+        ("28", "Additional child tax credit"),
+        ("29", "American opportunity credit from Form 8863, line 8"),
+        ("31", "Amount from Schedule 3, line 15"),
+        (
+            "32",
+            "These are your total other payments and refundable credits",
+        ),
+        ("33", "These are your total payments"),
+        ("34", "This is the amount you overpaid"),
+        ("35a", "Amount of line 34 you want refunded to you"),
+        (
+            "36",
+            "Amount of line 34 you want applied to your estimated tax",
+        ),
+        ("37", "This is the amount you owe"),
+        ("38", "Estimated tax penalty"),
+    ],
+};
+
+/// Form 1040-SP, the Spanish-language Form 1040.
+pub const FORM_1040_SP: FormLabels = FormLabels {
+    number: "1040-SP",
+    title: "Declaración de Impuestos de los Estados Unidos sobre los Ingresos Personales",
+    lines: &[
+        (
+            "1a",
+            "Cantidad total del (de los) Formulario(s) W-2, casilla 1",
+        ),
+        (
+            "1b",
+            "Salarios de empleado doméstico no declarados en el (los) Formulario(s) W-2",
+        ),
+        ("1c", "Ingresos de propinas no declarados en la línea 1a"),
+        (
+            "1d",
+            "Pagos de exención de Medicaid no declarados en el (los) Formulario(s) W-2",
+        ),
+        (
+            "1e",
+            "Beneficios tributables para el cuidado de dependientes del Formulario 2441, línea 26",
+        ),
+        (
+            "1f",
+            "Beneficios de adopción provistos por el empleador del Formulario 8839, línea 29",
+        ),
+        ("1g", "Salarios del Formulario 8919, línea 6"),
+        ("1h", "Otros ingresos del trabajo"),
+        ("1i", "Elección de paga no tributable por combate"),
+        ("1z", "Sume las líneas 1a a 1h"),
+        ("2a", "Intereses exentos de impuestos"),
+        ("2b", "Intereses tributables"),
+        ("3a", "Dividendos calificados"),
+        ("3b", "Dividendos ordinarios"),
+        ("4a", "Distribuciones de arreglos IRA"),
+        ("4b", "Cantidad tributable"),
+        ("5a", "Pensiones y anualidades"),
+        ("5b", "Cantidad tributable"),
+        ("6a", "Beneficios del Seguro Social"),
+        ("6b", "Cantidad tributable"),
+        ("7", "Ganancia o (pérdida) de capital"),
+        ("8", "Ingresos adicionales del Anexo 1, línea 10"),
+        ("9", "Éste es su ingreso total"),
+        ("10", "Ajustes a los ingresos del Anexo 1, línea 26"),
+        ("11", "Éste es su ingreso bruto ajustado"),
+        (
+            "12",
+            "Deducción estándar o deducciones detalladas (del Anexo A)",
+        ),
+        ("13a", "Deducción por ingreso calificado de negocio"),
+        ("13b", "Deducciones adicionales del Anexo 1-A, línea 38"),
+        ("14", "Sume las líneas 12, 13a y 13b"),
+        ("15", "Éste es su ingreso tributable"),
+        ("16", "Impuesto"),
+        ("17", "Cantidad del Anexo 2, línea 3"),
+        ("18", "Sume las líneas 16 y 17"),
+        (
+            "19",
+            "Crédito tributario por hijos o crédito por otros dependientes",
+        ),
+        ("20", "Cantidad del Anexo 3, línea 8"),
+        ("21", "Sume las líneas 19 y 20"),
+        ("22", "Reste la línea 21 de la línea 18"),
+        (
+            "23",
+            "Otros impuestos, incluido el impuesto sobre el trabajo por cuenta propia",
+        ),
+        ("24", "Éste es su impuesto total"),
+        (
+            "25a",
+            "Impuesto federal sobre el ingreso retenido del (de los) Formulario(s) W-2",
+        ),
+        (
+            "25b",
+            "Impuesto federal sobre el ingreso retenido del (de los) Formulario(s) 1099",
+        ),
+        (
+            "25c",
+            "Impuesto federal sobre el ingreso retenido de otros formularios",
+        ),
+        ("25d", "Sume las líneas 25a a 25c"),
+        (
+            "26",
+            "Pagos de impuesto estimado y cantidad aplicada de la declaración del año anterior",
+        ),
+        ("27", "Crédito por ingreso del trabajo (EIC)"),
+        ("28", "Crédito tributario adicional por hijos"),
+        (
+            "29",
+            "Crédito tributario de oportunidad americana del Formulario 8863, línea 8",
+        ),
+        ("31", "Cantidad del Anexo 3, línea 15"),
+        (
+            "32",
+            "Éstos son sus otros pagos y créditos reembolsables totales",
+        ),
+        ("33", "Éstos son sus pagos totales"),
+        ("34", "Ésta es la cantidad que usted pagó en exceso"),
+        (
+            "35a",
+            "Cantidad de la línea 34 que desea que se le reembolse a usted",
+        ),
+        (
+            "36",
+            "Cantidad de la línea 34 que desea que se le acredite a su impuesto estimado",
+        ),
+        ("37", "Ésta es la cantidad que usted adeuda"),
+        ("38", "Multa por pago insuficiente del impuesto estimado"),
+    ],
+};
+
+/// Form 1040-SR, which has the same lines as Form 1040.
+pub const FORM_1040_SR: FormLabels = FormLabels {
+    number: "1040-SR",
+    title: "U.S. Tax Return for Seniors",
+    lines: FORM_1040.lines,
+};
+
+/// Spanish version of Form 1040-SR, with the same lines as Form 1040-SP.
+pub const FORM_1040_SR_SP: FormLabels = FormLabels {
+    number: "1040-SR (SP)",
+    title: "Declaración de Impuestos de los Estados Unidos para Personas de 65 Años de Edad o Más",
+    lines: FORM_1040_SP.lines,
+};
+
+/// Form W-2.
+pub const W2: FormLabels = FormLabels {
+    number: "W-2",
+    title: "Wage and Tax Statement",
+    lines: &[
+        ("a", "Employee's social security number"),
+        ("b", "Employer identification number (EIN)"),
+        ("c", "Employer's name, address, and ZIP code"),
+        ("d", "Control number"),
+        ("e", "Employee's first name and initial, last name, Suff."),
+        ("f", "Employee's address and ZIP code"),
+        ("1", "Wages, tips, other compensation"),
+        ("2", "Federal income tax withheld"),
+        ("3", "Social security wages"),
+        ("4", "Social security tax withheld"),
+        ("5", "Medicare wages and tips"),
+        ("6", "Medicare tax withheld"),
+        ("7", "Social security tips"),
+        ("8", "Allocated tips"),
+        ("10", "Dependent care benefits"),
+        ("11", "Nonqualified plans"),
+        ("12", "See instructions for box 12"),
+        (
+            "13",
+            "Statutory employee, Retirement plan, Third-party sick pay",
+        ),
+        ("14", "Other"),
+        ("15", "State, Employer's state ID number"),
+        ("16", "State wages, tips, etc."),
+        ("17", "State income tax"),
+        ("18", "Local wages, tips, etc."),
+        ("19", "Local income tax"),
+        ("20", "Locality name"),
+    ],
+};
+
+/// Form W-2 with Spanish labels.
+pub const W2_SP: FormLabels = FormLabels {
+    number: "W-2",
+    title: "Comprobante de Salarios y Retención de Impuestos",
+    lines: &[
+        ("a", "Número de Seguro Social del empleado"),
+        ("b", "Número de identificación del empleador (EIN)"),
+        ("c", "Nombre, dirección y código postal del empleador"),
+        ("d", "Número de control"),
+        ("e", "Nombre, inicial y apellido del empleado, sufijo"),
+        ("f", "Dirección y código postal del empleado"),
+        ("1", "Salarios, propinas y otras compensaciones"),
+        ("2", "Impuesto federal sobre el ingreso retenido"),
+        ("3", "Salarios sujetos al impuesto del Seguro Social"),
+        ("4", "Impuesto del Seguro Social retenido"),
+        ("5", "Salarios y propinas sujetos al impuesto del Medicare"),
+        ("6", "Impuesto del Medicare retenido"),
+        ("7", "Propinas sujetas al impuesto del Seguro Social"),
+        ("8", "Propinas asignadas"),
+        ("10", "Beneficios para el cuidado de dependientes"),
+        ("11", "Planes no calificados"),
+        ("12", "Vea las instrucciones para la casilla 12"),
+        (
+            "13",
+            "Empleado estatutario, Plan de jubilación, Compensación por enfermedad pagada por terceros",
+        ),
+        ("14", "Otro"),
+        (
+            "15",
+            "Estado, Número de identificación estatal del empleador",
+        ),
+        ("16", "Salarios, propinas, etc., estatales"),
+        ("17", "Impuesto estatal sobre el ingreso"),
+        ("18", "Salarios, propinas, etc., locales"),
+        ("19", "Impuesto local sobre el ingreso"),
+        ("20", "Nombre de la localidad"),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_keys(labels: &FormLabels) -> Vec<&'static str> {
+        labels.lines.iter().map(|(l, _)| *l).collect()
+    }
+
+    #[test]
+    fn language_round_trip() {
+        for language in FormLanguage::ALL {
+            assert_eq!(FormLanguage::from_code(language.code()), Some(language));
+        }
+        assert_eq!(FormLanguage::from_code(" ES"), Some(FormLanguage::Spanish));
+        assert_eq!(FormLanguage::from_code("fr"), None);
+    }
+
+    #[test]
+    fn variants_label_the_same_lines() {
+        assert_eq!(line_keys(&FORM_1040), line_keys(&FORM_1040_SP));
+        assert_eq!(line_keys(&W2), line_keys(&W2_SP));
+    }
+
+    #[test]
+    fn label_lookup() {
+        assert_eq!(
+            FORM_1040_SP.label("35A"),
+            Some("Cantidad de la línea 34 que desea que se le reembolse a usted")
+        );
+        assert_eq!(W2.label("9"), None);
+        assert_eq!(FormLanguage::Spanish.form_1040().number, "1040-SP");
+    }
+}
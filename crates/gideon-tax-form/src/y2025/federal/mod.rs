@@ -0,0 +1,118 @@
+/// Serializes enums with `code` and `from_code` as their official code
+/// strings, such as `"DD"` for [`Box12Code::DD`].
+#[cfg(feature = "serde")]
+macro_rules! serde_as_code {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.code())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let code = <String as serde::Deserialize>::deserialize(deserializer)?;
+                <$ty>::from_code(&code).ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "unknown {} code: {code}",
+                        stringify!($ty)
+                    ))
+                })
+            }
+        }
+    )+};
+}
+
+mod anonymize;
+#[cfg(feature = "json")]
+mod bundle;
+mod form_1040;
+mod form_1040_sr;
+mod form_1040_x;
+mod form_1095_a;
+mod form_1095_c;
+mod form_1098_t;
+mod form_1099_b;
+mod form_1099_div;
+mod form_1099_g;
+mod form_1099_int;
+mod form_1099_misc;
+mod form_1099_nec;
+mod form_1099_r;
+mod form_1099_sa;
+mod form_5498;
+mod form_8949;
+mod labels;
+mod schedule_eic;
+mod schedule_k1_1041;
+mod schedule_k1_1065;
+mod schedule_k1_1120_s;
+mod source_forms;
+mod ssa_1099;
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategies;
+mod validate;
+mod w2;
+
+pub use anonymize::Anonymizer;
+#[cfg(feature = "json")]
+pub use bundle::{
+    BundleError, Carryovers, Elections, Filing, ReturnBundle, SCHEMA_VERSION, TAX_YEAR, export,
+    import,
+};
+pub use form_1040::{
+    DirectDeposit, Form1040Address, Form1040Dependent, Form1040Identity, Form1040Person,
+    Form1040Signature, IrsForm1040, PaidPreparer, TaxFromForms, ThirdPartyDesignee,
+};
+pub use form_1040_sr::{IrsForm1040Sr, StandardDeductionChartRow, standard_deduction_chart};
+pub use form_1040_x::{AmendedLine, IrsForm1040X, LedgerChange, PriorPayments, explain_changes};
+pub use form_1095_a::{CoveredIndividual, Irs1095A, PolicyAllocation};
+pub use form_1095_c::{Irs1095C, OfferCode, SafeHarborCode};
+pub use form_1098_t::Irs1098T;
+pub use form_1099_b::{GainType, Irs1099B};
+pub use form_1099_div::Irs1099Div;
+pub use form_1099_g::Irs1099G;
+pub use form_1099_int::Irs1099Int;
+pub use form_1099_misc::Irs1099Misc;
+pub use form_1099_nec::Irs1099Nec;
+pub use form_1099_r::Irs1099R;
+pub use form_1099_sa::{AccountType, DistributionCode, Irs1099Sa};
+pub use form_5498::{
+    IraContributionReconciliation, IraParticipant, IraType, Irs5498, Recharacterization,
+    reconcile_ira_contributions,
+};
+pub use form_8949::{
+    Form8949Aggregator, Form8949Category, Form8949Summary, Form8949Totals, aggregate_form_8949,
+};
+pub use labels::{
+    FORM_1040, FORM_1040_SP, FORM_1040_SR, FORM_1040_SR_SP, FormLabels, FormLanguage, W2, W2_SP,
+};
+pub use schedule_eic::{EicQualifyingChild, IrsScheduleEic};
+pub use schedule_k1_1041::{FinalYearDeduction, FinalYearDeductionCode, IrsK1041};
+pub use schedule_k1_1065::{IrsK1065, K1Entry, SelfEmploymentCode, SelfEmploymentEntry};
+pub use schedule_k1_1120_s::{BasisCode, BasisEntry, IrsK1120S};
+#[cfg(test)]
+pub(crate) use source_forms::tests::input as test_input;
+pub use source_forms::{JurisdictionWithholding, SourceForms};
+pub use ssa_1099::IrsSsa1099;
+pub(crate) use validate::is_valid_ssn;
+#[cfg(feature = "json")]
+pub use validate::validate;
+pub use validate::{validate_elections, validate_identity, validate_source_forms};
+pub use w2::{
+    Box12Code, Box12Entry, DifferenceSource, IrsW2, SOCIAL_SECURITY_WAGE_BASE, W2LocalTaxGrp,
+    W2StateTaxGrp, WageDifference, WageReconciliation,
+};
+
+#[cfg(feature = "serde")]
+serde_as_code!(
+    BasisCode,
+    Box12Code,
+    DistributionCode,
+    FinalYearDeductionCode,
+    FormLanguage,
+    IraType,
+    OfferCode,
+    SafeHarborCode,
+    SelfEmploymentCode,
+);
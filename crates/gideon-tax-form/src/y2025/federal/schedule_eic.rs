@@ -0,0 +1,44 @@
+/// Schedule EIC, Earned Income Credit: the qualifying children the credit
+/// on Form 1040, line 27, is claimed for.
+///
+/// See: <https://www.irs.gov/forms-pubs/about-schedule-eic-form-1040>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsScheduleEic {
+    /// Up to three children, one per column.
+    pub children: Vec<EicQualifyingChild>,
+}
+
+/// One column of Schedule EIC.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EicQualifyingChild {
+    /// Line 1.
+    pub first_name: String,
+    /// Line 1.
+    pub last_name: String,
+    /// Line 2.
+    pub ssn: String,
+    /// Line 3.
+    pub birth_year: i32,
+    /// Line 4a: under age 24 at the end of the year, a student, and younger
+    /// than you (or your spouse, if filing jointly).
+    pub student_under_24: bool,
+    /// Line 4b: permanently and totally disabled during any part of the
+    /// year.
+    pub permanently_disabled: bool,
+    /// Line 5.
+    pub relationship: String,
+    /// Line 6: months the child lived with you in the United States.
+    pub months_lived_with_you: u8,
+}
+
+impl EicQualifyingChild {
+    /// Whether the child meets the age test for `year`: under 19 at the end
+    /// of the year, under 24 and a student, or permanently and totally
+    /// disabled at any age.
+    pub fn meets_age_test(&self, year: i32) -> bool {
+        let age = year - self.birth_year;
+        age < 19 || (age < 24 && self.student_under_24) || self.permanently_disabled
+    }
+}
@@ -0,0 +1,232 @@
+use gideon_tax_core::{Owner, Usd};
+use gideon_tax_core::passthrough::{EntityKind, LossLimits, PassthroughItems};
+
+use super::K1Entry;
+
+/// Schedule K-1 (Form 1041), Beneficiary's Share of Income, Deductions,
+/// Credits, etc.
+///
+/// See: <https://www.irs.gov/instructions/i1041sk1>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsK1041 {
+    /// Box A.
+    pub estate_or_trust_ein: String,
+    /// Box B.
+    pub estate_or_trust_name: String,
+    /// Box C.
+    pub fiduciary_name_address: String,
+    /// Box E.
+    pub final_return: bool,
+    /// Box F.
+    pub beneficiary_tin: String,
+    /// Box G.
+    pub beneficiary_name_address: String,
+    /// Box 1.
+    pub interest_income: Usd,
+    /// Box 2a.
+    pub ordinary_dividends: Usd,
+    /// Box 2b.
+    pub qualified_dividends: Usd,
+    /// Box 3.
+    pub net_short_term_capital_gain: Usd,
+    /// Box 4a.
+    pub net_long_term_capital_gain: Usd,
+    /// Box 5.
+    pub other_portfolio_income: Usd,
+    /// Box 6.
+    pub ordinary_business_income: Usd,
+    /// Box 7.
+    pub net_rental_real_estate_income: Usd,
+    /// Box 8.
+    pub other_rental_income: Usd,
+    /// Box 9.
+    pub directly_apportioned_deductions: Vec<K1Entry>,
+    /// Box 10.
+    pub estate_tax_deduction: Usd,
+    /// Box 11, reported only on the final year's K-1.
+    pub final_year_deductions: Vec<FinalYearDeduction>,
+    /// Box 13.
+    pub credits: Vec<K1Entry>,
+    /// Box 14, section 199A statement. When absent, QBI is taken from
+    /// boxes 6–8 less box 9.
+    pub qualified_business_income: Option<Usd>,
+    /// Not on the form: `true` if the beneficiary's share of the trade or
+    /// business is nonpassive.
+    pub material_participation: bool,
+}
+
+/// One coded entry in box 11.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinalYearDeduction {
+    pub code: FinalYearDeductionCode,
+    pub amount: Usd,
+}
+
+/// Box 11 codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FinalYearDeductionCode {
+    /// Code A.
+    Section67eExpenses,
+    /// Code B.
+    NonmiscellaneousItemized,
+    /// Code C.
+    MiscellaneousItemized,
+    /// Code D.
+    ShortTermCapitalLossCarryover,
+    /// Code E.
+    LongTermCapitalLossCarryover,
+    /// Code F.
+    NetOperatingLossRegularTax,
+    /// Code G.
+    NetOperatingLossMinimumTax,
+}
+
+impl FinalYearDeductionCode {
+    pub const ALL: [FinalYearDeductionCode; 7] = [
+        FinalYearDeductionCode::Section67eExpenses,
+        FinalYearDeductionCode::NonmiscellaneousItemized,
+        FinalYearDeductionCode::MiscellaneousItemized,
+        FinalYearDeductionCode::ShortTermCapitalLossCarryover,
+        FinalYearDeductionCode::LongTermCapitalLossCarryover,
+        FinalYearDeductionCode::NetOperatingLossRegularTax,
+        FinalYearDeductionCode::NetOperatingLossMinimumTax,
+    ];
+
+    /// The official code printed in box 11.
+    pub fn code(self) -> &'static str {
+        match self {
+            FinalYearDeductionCode::Section67eExpenses => "A",
+            FinalYearDeductionCode::NonmiscellaneousItemized => "B",
+            FinalYearDeductionCode::MiscellaneousItemized => "C",
+            FinalYearDeductionCode::ShortTermCapitalLossCarryover => "D",
+            FinalYearDeductionCode::LongTermCapitalLossCarryover => "E",
+            FinalYearDeductionCode::NetOperatingLossRegularTax => "F",
+            FinalYearDeductionCode::NetOperatingLossMinimumTax => "G",
+        }
+    }
+
+    /// Parses a box 11 code, ignoring case and surrounding whitespace.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|c| c.code().eq_ignore_ascii_case(code))
+    }
+}
+
+impl IrsK1041 {
+    /// Total of box 11 entries with `code`.
+    pub fn final_year_deduction(&self, code: FinalYearDeductionCode) -> Usd {
+        self.final_year_deductions
+            .iter()
+            .filter(|d| d.code == code)
+            .map(|d| d.amount)
+            .sum()
+    }
+
+    /// Excess section 67(e) deductions on termination (box 11, code A),
+    /// an adjustment to income on Schedule 1, line 24k.
+    pub fn section_67e_excess_deductions(&self) -> Usd {
+        self.final_year_deduction(FinalYearDeductionCode::Section67eExpenses)
+    }
+
+    /// Routes the beneficiary's items to Form 1040 lines 2b and 3a–3b,
+    /// Schedule D (boxes 3–4a), Schedule E Part III (boxes 5–9), and the QBI
+    /// deduction.
+    ///
+    /// Box 10 goes to Schedule A, and box 11 codes B–G to Schedule A,
+    /// Schedule D, and Schedule 1 carryover lines; none is routed yet.
+    pub fn passthrough_items(&self) -> PassthroughItems {
+        let apportioned: Usd = self
+            .directly_apportioned_deductions
+            .iter()
+            .map(|d| d.amount)
+            .sum();
+        let business = self.ordinary_business_income - apportioned;
+        let rental_income = self.net_rental_real_estate_income + self.other_rental_income;
+        PassthroughItems {
+            entity_name: self.estate_or_trust_name.clone(),
+            entity_ein: self.estate_or_trust_ein.clone(),
+            kind: EntityKind::EstateOrTrust,
+            owner: Owner::Taxpayer,
+            material_participation: self.material_participation,
+            ordinary_income: business,
+            rental_income,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: self.other_portfolio_income,
+            section_179_deduction: Usd::ZERO,
+            interest: self.interest_income,
+            ordinary_dividends: self.ordinary_dividends,
+            qualified_dividends: self.qualified_dividends,
+            net_short_term_capital_gain: self.net_short_term_capital_gain,
+            net_long_term_capital_gain: self.net_long_term_capital_gain,
+            self_employment_earnings: Usd::ZERO,
+            qualified_business_income: self
+                .qualified_business_income
+                .unwrap_or(business + rental_income),
+            loss_limits: LossLimits::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn final_year_deduction_code_round_trip() {
+        for code in FinalYearDeductionCode::ALL {
+            assert_eq!(FinalYearDeductionCode::from_code(code.code()), Some(code));
+        }
+        assert_eq!(
+            FinalYearDeductionCode::from_code(" a "),
+            Some(FinalYearDeductionCode::Section67eExpenses)
+        );
+        assert_eq!(FinalYearDeductionCode::from_code("H"), None);
+    }
+
+    #[test]
+    fn routes_beneficiary_items() {
+        let k1 = IrsK1041 {
+            estate_or_trust_name: "Doe Family Trust".to_string(),
+            interest_income: Usd::from_dollars(800),
+            ordinary_dividends: Usd::from_dollars(1_200),
+            qualified_dividends: Usd::from_dollars(900),
+            other_portfolio_income: Usd::from_dollars(300),
+            net_rental_real_estate_income: Usd::from_dollars(5_000),
+            directly_apportioned_deductions: vec![K1Entry {
+                code: "A".to_string(),
+                amount: Usd::from_dollars(1_000),
+            }],
+            ..Default::default()
+        };
+        let items = k1.passthrough_items();
+        assert_eq!(items.kind, EntityKind::EstateOrTrust);
+        assert_eq!(items.interest, Usd::from_dollars(800));
+        assert_eq!(items.ordinary_income, Usd::from_dollars(-1_000));
+        assert_eq!(items.nonpassive_income(), Usd::from_dollars(300));
+        assert_eq!(items.rental_income, Usd::from_dollars(5_000));
+        assert_eq!(items.qualified_business_income, Usd::from_dollars(4_000));
+    }
+
+    #[test]
+    fn excess_deductions_on_termination() {
+        let k1 = IrsK1041 {
+            final_return: true,
+            final_year_deductions: vec![
+                FinalYearDeduction {
+                    code: FinalYearDeductionCode::Section67eExpenses,
+                    amount: Usd::from_dollars(2_500),
+                },
+                FinalYearDeduction {
+                    code: FinalYearDeductionCode::LongTermCapitalLossCarryover,
+                    amount: Usd::from_dollars(4_000),
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(k1.section_67e_excess_deductions(), Usd::from_dollars(2_500));
+    }
+}
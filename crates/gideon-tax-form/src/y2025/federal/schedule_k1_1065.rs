@@ -0,0 +1,223 @@
+use gideon_tax_core::{Owner, Usd};
+use gideon_tax_core::passthrough::{EntityKind, LossLimits, PassthroughItems};
+
+/// Schedule K-1 (Form 1065), Partner's Share of Income, Deductions,
+/// Credits, etc.
+///
+/// See: <https://www.irs.gov/instructions/i1065sk1>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsK1065 {
+    /// Box A.
+    pub partnership_ein: String,
+    /// Box B.
+    pub partnership_name_address: String,
+    /// Box D.
+    pub publicly_traded_partnership: bool,
+    /// Box E.
+    pub partner_tin: String,
+    /// Box F.
+    pub partner_name_address: String,
+    /// Box G.
+    pub general_partner: bool,
+    /// Box 1.
+    pub ordinary_business_income: Usd,
+    /// Box 2.
+    pub net_rental_real_estate_income: Usd,
+    /// Box 3.
+    pub other_net_rental_income: Usd,
+    /// Box 4a.
+    pub guaranteed_payments_services: Usd,
+    /// Box 4b.
+    pub guaranteed_payments_capital: Usd,
+    /// Box 5.
+    pub interest_income: Usd,
+    /// Box 6a.
+    pub ordinary_dividends: Usd,
+    /// Box 6b.
+    pub qualified_dividends: Usd,
+    /// Box 7.
+    pub royalties: Usd,
+    /// Box 8.
+    pub net_short_term_capital_gain: Usd,
+    /// Box 9a.
+    pub net_long_term_capital_gain: Usd,
+    /// Box 10.
+    pub net_section_1231_gain: Usd,
+    /// Box 12.
+    pub section_179_deduction: Usd,
+    /// Box 14.
+    pub self_employment: Vec<SelfEmploymentEntry>,
+    /// Box 15.
+    pub credits: Vec<K1Entry>,
+    /// Box 20, code Z statement. When absent, QBI is taken from boxes 1–3
+    /// less box 12.
+    pub qualified_business_income: Option<Usd>,
+    /// Not on the form: `true` if the partner materially participates in
+    /// the partnership's trade or business.
+    pub material_participation: bool,
+}
+
+/// One coded entry in box 14.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelfEmploymentEntry {
+    pub code: SelfEmploymentCode,
+    pub amount: Usd,
+}
+
+/// A coded entry in a K-1 box whose codes are listed in the partner's
+/// instructions (e.g. box 15 credits).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct K1Entry {
+    pub code: String,
+    pub amount: Usd,
+}
+
+/// Box 14 codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelfEmploymentCode {
+    /// Code A.
+    NetEarnings,
+    /// Code B.
+    GrossFarmingOrFishing,
+    /// Code C.
+    GrossNonfarm,
+}
+
+impl SelfEmploymentCode {
+    pub const ALL: [SelfEmploymentCode; 3] = [
+        SelfEmploymentCode::NetEarnings,
+        SelfEmploymentCode::GrossFarmingOrFishing,
+        SelfEmploymentCode::GrossNonfarm,
+    ];
+
+    /// The official code printed in box 14.
+    pub fn code(self) -> &'static str {
+        match self {
+            SelfEmploymentCode::NetEarnings => "A",
+            SelfEmploymentCode::GrossFarmingOrFishing => "B",
+            SelfEmploymentCode::GrossNonfarm => "C",
+        }
+    }
+
+    /// Parses a box 14 code, ignoring case and surrounding whitespace.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|c| c.code().eq_ignore_ascii_case(code))
+    }
+}
+
+impl IrsK1065 {
+    /// Guaranteed payments (box 4c).
+    pub fn guaranteed_payments(&self) -> Usd {
+        self.guaranteed_payments_services + self.guaranteed_payments_capital
+    }
+
+    /// Net earnings from self-employment (box 14, code A).
+    pub fn self_employment_earnings(&self) -> Usd {
+        self.self_employment
+            .iter()
+            .filter(|e| e.code == SelfEmploymentCode::NetEarnings)
+            .map(|e| e.amount)
+            .sum()
+    }
+
+    /// Routes the partner's items to Schedule E Part II (boxes 1–4 and 12),
+    /// Schedule B (boxes 5–6), Schedule D (boxes 8–9a), Schedule SE
+    /// (box 14), and the QBI deduction (box 20).
+    ///
+    /// Royalties (box 7) go to Schedule E Part I and section 1231 gain
+    /// (box 10) to Form 4797; neither is routed yet.
+    pub fn passthrough_items(&self) -> PassthroughItems {
+        let rental_income = self.net_rental_real_estate_income + self.other_net_rental_income;
+        PassthroughItems {
+            entity_name: self.partnership_name_address.clone(),
+            entity_ein: self.partnership_ein.clone(),
+            kind: EntityKind::Partnership,
+            owner: Owner::Taxpayer,
+            material_participation: self.material_participation,
+            ordinary_income: self.ordinary_business_income,
+            rental_income,
+            guaranteed_payments: self.guaranteed_payments(),
+            portfolio_income: Usd::ZERO,
+            section_179_deduction: self.section_179_deduction,
+            interest: self.interest_income,
+            ordinary_dividends: self.ordinary_dividends,
+            qualified_dividends: self.qualified_dividends,
+            net_short_term_capital_gain: self.net_short_term_capital_gain,
+            net_long_term_capital_gain: self.net_long_term_capital_gain,
+            self_employment_earnings: self.self_employment_earnings(),
+            qualified_business_income: self.qualified_business_income.unwrap_or(
+                self.ordinary_business_income + rental_income - self.section_179_deduction,
+            ),
+            loss_limits: LossLimits::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_employment_code_round_trip() {
+        for code in SelfEmploymentCode::ALL {
+            assert_eq!(SelfEmploymentCode::from_code(code.code()), Some(code));
+        }
+        assert_eq!(
+            SelfEmploymentCode::from_code(" c "),
+            Some(SelfEmploymentCode::GrossNonfarm)
+        );
+        assert_eq!(SelfEmploymentCode::from_code("D"), None);
+    }
+
+    #[test]
+    fn routes_general_partner_items() {
+        let k1 = IrsK1065 {
+            partnership_name_address: "Acme LP".to_string(),
+            general_partner: true,
+            material_participation: true,
+            ordinary_business_income: Usd::from_dollars(40_000),
+            other_net_rental_income: Usd::from_dollars(-2_000),
+            guaranteed_payments_services: Usd::from_dollars(24_000),
+            section_179_deduction: Usd::from_dollars(3_000),
+            interest_income: Usd::from_dollars(150),
+            self_employment: vec![
+                SelfEmploymentEntry {
+                    code: SelfEmploymentCode::NetEarnings,
+                    amount: Usd::from_dollars(61_000),
+                },
+                SelfEmploymentEntry {
+                    code: SelfEmploymentCode::GrossNonfarm,
+                    amount: Usd::from_dollars(90_000),
+                },
+            ],
+            ..Default::default()
+        };
+        let items = k1.passthrough_items();
+        assert_eq!(items.kind, EntityKind::Partnership);
+        assert_eq!(items.nonpassive_income(), Usd::from_dollars(61_000));
+        assert_eq!(items.passive_income(), Usd::from_dollars(-2_000));
+        assert_eq!(items.self_employment_earnings, Usd::from_dollars(61_000));
+        assert_eq!(items.interest, Usd::from_dollars(150));
+        // Guaranteed payments are not QBI.
+        assert_eq!(items.qualified_business_income, Usd::from_dollars(35_000));
+    }
+
+    #[test]
+    fn qbi_statement_overrides_estimate() {
+        let k1 = IrsK1065 {
+            ordinary_business_income: Usd::from_dollars(40_000),
+            qualified_business_income: Some(Usd::from_dollars(38_500)),
+            ..Default::default()
+        };
+        assert_eq!(
+            k1.passthrough_items().qualified_business_income,
+            Usd::from_dollars(38_500)
+        );
+    }
+}
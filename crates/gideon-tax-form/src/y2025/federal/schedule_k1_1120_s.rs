@@ -0,0 +1,325 @@
+use gideon_tax_core::{Owner, Usd};
+use gideon_tax_core::passthrough::{
+    BasisAdjustments, BasisLimitation, EntityKind, LossLimits, PassthroughItems, ShareholderBasis,
+};
+
+use super::K1Entry;
+
+/// Schedule K-1 (Form 1120-S), Shareholder's Share of Income, Deductions,
+/// Credits, etc.
+///
+/// See: <https://www.irs.gov/instructions/i1120ssk1>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsK1120S {
+    /// Box A.
+    pub corporation_ein: String,
+    /// Box B.
+    pub corporation_name_address: String,
+    /// Box E.
+    pub shareholder_tin: String,
+    /// Box F1.
+    pub shareholder_name_address: String,
+    /// Box G, percentage of stock ownership for the year in basis points.
+    pub ownership_bps: i64,
+    /// Box 1.
+    pub ordinary_business_income: Usd,
+    /// Box 2.
+    pub net_rental_real_estate_income: Usd,
+    /// Box 3.
+    pub other_net_rental_income: Usd,
+    /// Box 4.
+    pub interest_income: Usd,
+    /// Box 5a.
+    pub ordinary_dividends: Usd,
+    /// Box 5b.
+    pub qualified_dividends: Usd,
+    /// Box 6.
+    pub royalties: Usd,
+    /// Box 7.
+    pub net_short_term_capital_gain: Usd,
+    /// Box 8a.
+    pub net_long_term_capital_gain: Usd,
+    /// Box 9.
+    pub net_section_1231_gain: Usd,
+    /// Box 11.
+    pub section_179_deduction: Usd,
+    /// Box 13.
+    pub credits: Vec<K1Entry>,
+    /// Box 16.
+    pub items_affecting_basis: Vec<BasisEntry>,
+    /// Box 17, code V statement. When absent, QBI is taken from boxes 1–3
+    /// less box 11.
+    pub qualified_business_income: Option<Usd>,
+    /// Not on the form: `true` if the shareholder materially participates in
+    /// the corporation's trade or business.
+    pub material_participation: bool,
+    /// Not on the form: the shareholder's stock and debt basis at the start
+    /// of the year, and losses suspended in prior years (Form 7203).
+    pub beginning_basis: ShareholderBasis,
+}
+
+/// One coded entry in box 16.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BasisEntry {
+    pub code: BasisCode,
+    pub amount: Usd,
+}
+
+/// Box 16 codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BasisCode {
+    /// Code A.
+    TaxExemptInterest,
+    /// Code B.
+    OtherTaxExemptIncome,
+    /// Code C.
+    NondeductibleExpenses,
+    /// Code D.
+    Distributions,
+    /// Code E.
+    RepaymentOfLoans,
+    /// Code F.
+    ForeignTaxes,
+}
+
+impl BasisCode {
+    pub const ALL: [BasisCode; 6] = [
+        BasisCode::TaxExemptInterest,
+        BasisCode::OtherTaxExemptIncome,
+        BasisCode::NondeductibleExpenses,
+        BasisCode::Distributions,
+        BasisCode::RepaymentOfLoans,
+        BasisCode::ForeignTaxes,
+    ];
+
+    /// The official code printed in box 16.
+    pub fn code(self) -> &'static str {
+        match self {
+            BasisCode::TaxExemptInterest => "A",
+            BasisCode::OtherTaxExemptIncome => "B",
+            BasisCode::NondeductibleExpenses => "C",
+            BasisCode::Distributions => "D",
+            BasisCode::RepaymentOfLoans => "E",
+            BasisCode::ForeignTaxes => "F",
+        }
+    }
+
+    /// Parses a box 16 code, ignoring case and surrounding whitespace.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|c| c.code().eq_ignore_ascii_case(code))
+    }
+}
+
+impl IrsK1120S {
+    fn box_16(&self, codes: &[BasisCode]) -> Usd {
+        self.items_affecting_basis
+            .iter()
+            .filter(|e| codes.contains(&e.code))
+            .map(|e| e.amount)
+            .sum()
+    }
+
+    /// Items reported on this K-1 that can be limited by basis: losses in
+    /// boxes 1–3, 7, and 8a.
+    fn loss_items(&self) -> [Usd; 5] {
+        [
+            self.ordinary_business_income,
+            self.net_rental_real_estate_income,
+            self.other_net_rental_income,
+            self.net_short_term_capital_gain,
+            self.net_long_term_capital_gain,
+        ]
+    }
+
+    /// The year's basis adjustments from this K-1.
+    pub fn basis_adjustments(&self) -> BasisAdjustments {
+        let income_items = [
+            self.interest_income,
+            self.ordinary_dividends,
+            self.royalties,
+            self.net_section_1231_gain,
+        ];
+        let income: Usd = self
+            .loss_items()
+            .into_iter()
+            .chain(income_items)
+            .map(|a| a.max(Usd::ZERO))
+            .sum();
+        let losses: Usd = self
+            .loss_items()
+            .into_iter()
+            .map(|a| (-a).max(Usd::ZERO))
+            .sum::<Usd>()
+            + self.section_179_deduction;
+        BasisAdjustments {
+            income: income
+                + self.box_16(&[
+                    BasisCode::TaxExemptInterest,
+                    BasisCode::OtherTaxExemptIncome,
+                ]),
+            distributions: self.box_16(&[BasisCode::Distributions]),
+            nondeductible_expenses: self
+                .box_16(&[BasisCode::NondeductibleExpenses, BasisCode::ForeignTaxes]),
+            losses,
+        }
+    }
+
+    /// Applies this year's items to [`beginning_basis`](Self::beginning_basis).
+    pub fn basis_limitation(&self) -> BasisLimitation {
+        self.beginning_basis.apply(&self.basis_adjustments())
+    }
+
+    /// Routes the shareholder's items to Schedule E Part II, Schedule B,
+    /// Schedule D, and the QBI deduction after the basis limitation.
+    ///
+    /// When losses are limited, each current-year loss item is reduced pro
+    /// rata; allowed losses carried forward from prior years are treated as
+    /// ordinary. Distributions in excess of basis are long-term capital
+    /// gain. Royalties (box 6) and section 1231 gain (box 9) are not routed
+    /// yet.
+    pub fn passthrough_items(&self) -> PassthroughItems {
+        let adj = self.basis_adjustments();
+        let limit = self.basis_limitation();
+        let total_losses = adj.losses + self.beginning_basis.suspended_losses;
+        let allowed_current = if total_losses == Usd::ZERO {
+            Usd::ZERO
+        } else {
+            limit
+                .allowed_losses
+                .mul_div(adj.losses.cents(), total_losses.cents())
+        };
+        let allowed_prior = limit.allowed_losses - allowed_current;
+        let limited = |amount: Usd| {
+            if amount < Usd::ZERO {
+                amount.mul_div(allowed_current.cents(), adj.losses.cents())
+            } else {
+                amount
+            }
+        };
+
+        let ordinary_income = limited(self.ordinary_business_income) - allowed_prior;
+        let rental_income =
+            limited(self.net_rental_real_estate_income) + limited(self.other_net_rental_income);
+        let section_179_deduction = limited(-self.section_179_deduction).abs();
+        PassthroughItems {
+            entity_name: self.corporation_name_address.clone(),
+            entity_ein: self.corporation_ein.clone(),
+            kind: EntityKind::SCorporation,
+            owner: Owner::Taxpayer,
+            material_participation: self.material_participation,
+            ordinary_income,
+            rental_income,
+            guaranteed_payments: Usd::ZERO,
+            portfolio_income: Usd::ZERO,
+            section_179_deduction,
+            interest: self.interest_income,
+            ordinary_dividends: self.ordinary_dividends,
+            qualified_dividends: self.qualified_dividends,
+            net_short_term_capital_gain: limited(self.net_short_term_capital_gain),
+            net_long_term_capital_gain: limited(self.net_long_term_capital_gain)
+                + limit.excess_distributions,
+            self_employment_earnings: Usd::ZERO,
+            qualified_business_income: self
+                .qualified_business_income
+                .unwrap_or(ordinary_income + rental_income - section_179_deduction),
+            // Basis was applied above.
+            loss_limits: LossLimits::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basis_code_round_trip() {
+        for code in BasisCode::ALL {
+            assert_eq!(BasisCode::from_code(code.code()), Some(code));
+        }
+        assert_eq!(BasisCode::from_code("d"), Some(BasisCode::Distributions));
+        assert_eq!(BasisCode::from_code("G"), None);
+    }
+
+    #[test]
+    fn income_within_basis_flows_through() {
+        let k1 = IrsK1120S {
+            material_participation: true,
+            ordinary_business_income: Usd::from_dollars(50_000),
+            items_affecting_basis: vec![BasisEntry {
+                code: BasisCode::Distributions,
+                amount: Usd::from_dollars(20_000),
+            }],
+            ..Default::default()
+        };
+        let items = k1.passthrough_items();
+        assert_eq!(items.nonpassive_income(), Usd::from_dollars(50_000));
+        assert_eq!(items.qualified_business_income, Usd::from_dollars(50_000));
+        assert_eq!(
+            k1.basis_limitation().ending.stock,
+            Usd::from_dollars(30_000)
+        );
+    }
+
+    #[test]
+    fn loss_beyond_basis_is_suspended() {
+        let k1 = IrsK1120S {
+            material_participation: true,
+            ordinary_business_income: Usd::from_dollars(-30_000),
+            net_long_term_capital_gain: Usd::from_dollars(-10_000),
+            beginning_basis: ShareholderBasis {
+                stock: Usd::from_dollars(15_000),
+                debt: Usd::from_dollars(5_000),
+                suspended_losses: Usd::ZERO,
+            },
+            ..Default::default()
+        };
+        let limit = k1.basis_limitation();
+        assert_eq!(limit.allowed_losses, Usd::from_dollars(20_000));
+        assert_eq!(limit.ending.suspended_losses, Usd::from_dollars(20_000));
+        // Half of each loss item is allowed.
+        let items = k1.passthrough_items();
+        assert_eq!(items.ordinary_income, Usd::from_dollars(-15_000));
+        assert_eq!(items.net_long_term_capital_gain, Usd::from_dollars(-5_000));
+    }
+
+    #[test]
+    fn prior_suspended_loss_released_by_income() {
+        let k1 = IrsK1120S {
+            material_participation: true,
+            ordinary_business_income: Usd::from_dollars(8_000),
+            beginning_basis: ShareholderBasis {
+                suspended_losses: Usd::from_dollars(6_000),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let items = k1.passthrough_items();
+        assert_eq!(items.ordinary_income, Usd::from_dollars(2_000));
+        assert_eq!(k1.basis_limitation().ending.suspended_losses, Usd::ZERO);
+    }
+
+    #[test]
+    fn excess_distribution_is_capital_gain() {
+        let k1 = IrsK1120S {
+            beginning_basis: ShareholderBasis {
+                stock: Usd::from_dollars(1_000),
+                ..Default::default()
+            },
+            items_affecting_basis: vec![BasisEntry {
+                code: BasisCode::Distributions,
+                amount: Usd::from_dollars(4_000),
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            k1.passthrough_items().net_long_term_capital_gain,
+            Usd::from_dollars(3_000)
+        );
+    }
+}
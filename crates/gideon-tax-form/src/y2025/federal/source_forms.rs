@@ -0,0 +1,596 @@
+use std::collections::BTreeMap;
+
+use gideon_tax_core::passthrough::PassthroughItems;
+use gideon_tax_core::premium_tax_credit::MarketplaceCoverage;
+use gideon_tax_core::spine::ReturnInput;
+use gideon_tax_core::state::{State, StateInput};
+use gideon_tax_core::tips::EmployerTips;
+use gideon_tax_core::withholding::{ReportedWithholding, WithholdingSource};
+use gideon_tax_core::{Owner, Usd};
+
+use super::{
+    AccountType, Box12Code, DistributionCode, Irs1095A, Irs1098T, Irs1099B, Irs1099Div, Irs1099G,
+    Irs1099Int, Irs1099Nec, Irs1099R, Irs1099Sa, IrsK1041, IrsK1065, IrsK1120S, IrsSsa1099, IrsW2,
+};
+
+/// The information documents (source forms) received for a return.
+///
+/// Each form type maps its boxes onto the aggregated amounts of a
+/// [`ReturnInput`] via [`apply_to`](SourceForms::apply_to). Only federal
+/// income tax withheld is taken from Forms 1099-INT, DIV, NEC, B, R, and
+/// G; the spine does not yet take the income they report.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SourceForms {
+    pub w2: Vec<IrsW2>,
+    pub ssa_1099: Vec<IrsSsa1099>,
+    pub form_1099_int: Vec<Irs1099Int>,
+    pub form_1099_div: Vec<Irs1099Div>,
+    pub form_1099_nec: Vec<Irs1099Nec>,
+    pub form_1099_b: Vec<Irs1099B>,
+    pub form_1099_r: Vec<Irs1099R>,
+    pub form_1099_g: Vec<Irs1099G>,
+    pub form_1099_sa: Vec<Irs1099Sa>,
+    pub form_1095_a: Vec<Irs1095A>,
+    pub form_1098_t: Vec<Irs1098T>,
+    pub schedule_k1_1041: Vec<IrsK1041>,
+    pub schedule_k1_1065: Vec<IrsK1065>,
+    pub schedule_k1_1120_s: Vec<IrsK1120S>,
+}
+
+/// State and local income tax withheld on Forms W-2, by jurisdiction.
+///
+/// Jurisdictions are keyed by their codes and names as entered, trimmed
+/// and uppercased so rows from different employers match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JurisdictionWithholding {
+    /// Box 17, by the box 15 state.
+    pub states: BTreeMap<String, Usd>,
+    /// Box 19, by the box 15 state and box 20 locality.
+    pub localities: BTreeMap<(String, String), Usd>,
+}
+
+impl JurisdictionWithholding {
+    /// Box 17 withholding for `state`.
+    pub fn state(&self, state: State) -> Usd {
+        self.states.get(state.code()).copied().unwrap_or(Usd::ZERO)
+    }
+
+    pub fn state_total(&self) -> Usd {
+        self.states.values().copied().sum()
+    }
+
+    pub fn local_total(&self) -> Usd {
+        self.localities.values().copied().sum()
+    }
+}
+
+impl SourceForms {
+    /// Adds the amounts reported on every form to `input`.
+    ///
+    /// Local income tax withheld goes on Schedule A, line 5a. State income
+    /// tax withheld reaches it through the state return instead; see
+    /// [`apply_to_state`](SourceForms::apply_to_state).
+    pub fn apply_to(&self, input: &mut ReturnInput) {
+        self.apply(None, input);
+    }
+
+    /// Like [`apply_to`](SourceForms::apply_to) for a joint return: Forms
+    /// W-2 and K-1 issued to `spouse_ssn` count toward the spouse's
+    /// Schedule SE rather than the taxpayer's.
+    pub fn apply_to_joint(&self, spouse_ssn: &str, input: &mut ReturnInput) {
+        self.apply(Some(spouse_ssn), input);
+    }
+
+    fn apply(&self, spouse_ssn: Option<&str>, input: &mut ReturnInput) {
+        let owner = |tin: &str| {
+            let tin = digits(tin);
+            match spouse_ssn {
+                Some(ssn) if !tin.is_empty() && digits(ssn) == tin => Owner::Spouse,
+                _ => Owner::Taxpayer,
+            }
+        };
+        input.itemized_deductions.state_and_local_taxes += self.w2_withholding().local_total();
+        for form in &self.w2 {
+            input.w2_wages += form.wages_tips_other_comp;
+            input.fed_withholding += form.federal_income_tax_withheld;
+            let social_security = form.social_security_wages + form.social_security_tips;
+            match owner(&form.employee_ssn) {
+                Owner::Taxpayer => input.social_security_wages += social_security,
+                Owner::Spouse => input.spouse_social_security_wages += social_security,
+            }
+            let tips = &mut input.unreported_tips;
+            tips.social_security_wages_and_tips += social_security;
+            // Allocated tips are treated as unreported unless records show
+            // fewer tips were received.
+            if form.allocated_tips > Usd::ZERO {
+                tips.employers.push(EmployerTips {
+                    employer_name: form.employer_name_address.clone(),
+                    employer_ein: form.employer_ein.clone(),
+                    tips_received: form.allocated_tips,
+                    tips_reported: Usd::ZERO,
+                });
+            }
+        }
+        for form in &self.ssa_1099 {
+            input.social_security_benefits += form.net_benefits;
+            input.form_1099_withholding.extend(reported(
+                WithholdingSource::Ssa1099,
+                "Social Security Administration",
+                "",
+                form.voluntary_federal_income_tax_withheld,
+            ));
+        }
+        let withholding = &mut input.form_1099_withholding;
+        withholding.extend(self.form_1099_int.iter().filter_map(|f| {
+            reported(
+                WithholdingSource::Form1099Int,
+                &f.payer_name_address,
+                &f.payer_tin,
+                f.federal_income_tax_withheld,
+            )
+        }));
+        withholding.extend(self.form_1099_div.iter().filter_map(|f| {
+            reported(
+                WithholdingSource::Form1099Div,
+                &f.payer_name_address,
+                &f.payer_tin,
+                f.federal_income_tax_withheld,
+            )
+        }));
+        withholding.extend(self.form_1099_nec.iter().filter_map(|f| {
+            reported(
+                WithholdingSource::Form1099Nec,
+                &f.payer_name_address,
+                &f.payer_tin,
+                f.federal_income_tax_withheld,
+            )
+        }));
+        withholding.extend(self.form_1099_b.iter().filter_map(|f| {
+            reported(
+                WithholdingSource::Form1099B,
+                &f.payer_name_address,
+                &f.payer_tin,
+                f.federal_income_tax_withheld,
+            )
+        }));
+        withholding.extend(self.form_1099_r.iter().filter_map(|f| {
+            reported(
+                WithholdingSource::Form1099R,
+                &f.payer_name_address,
+                &f.payer_tin,
+                f.federal_income_tax_withheld,
+            )
+        }));
+        withholding.extend(self.form_1099_g.iter().filter_map(|f| {
+            reported(
+                WithholdingSource::Form1099G,
+                &f.payer_name_address,
+                &f.payer_tin,
+                f.federal_income_tax_withheld,
+            )
+        }));
+        // Archer and Medicare Advantage MSA distributions go on Form 8853.
+        for form in self
+            .form_1099_sa
+            .iter()
+            .filter(|f| f.account_type == AccountType::Hsa)
+        {
+            let hsa = &mut input.hsa_distributions;
+            hsa.gross_distributions += form.gross_distribution;
+            if form.distribution_code == DistributionCode::ExcessContributions {
+                hsa.rollovers_and_excess_withdrawn += form.gross_distribution;
+            }
+            if form.distribution_code.waives_additional_tax() {
+                hsa.additional_tax_exception = true;
+            }
+        }
+        for form in &self.form_1095_a {
+            let coverage = input
+                .marketplace_coverage
+                .get_or_insert_with(|| MarketplaceCoverage {
+                    region: form.poverty_region(),
+                    ..Default::default()
+                });
+            coverage.add_policy(&form.allocated_months());
+        }
+        input
+            .students
+            .extend(self.form_1098_t.iter().map(Irs1098T::student_expenses));
+        for form in &self.schedule_k1_1041 {
+            input.passthroughs.push(PassthroughItems {
+                owner: owner(&form.beneficiary_tin),
+                ..form.passthrough_items()
+            });
+            input.adjustments.section_67e_excess_deductions += form.section_67e_excess_deductions();
+        }
+        input
+            .passthroughs
+            .extend(self.schedule_k1_1065.iter().map(|form| PassthroughItems {
+                owner: owner(&form.partner_tin),
+                ..form.passthrough_items()
+            }));
+        input
+            .passthroughs
+            .extend(self.schedule_k1_1120_s.iter().map(|form| PassthroughItems {
+                owner: owner(&form.shareholder_tin),
+                ..form.passthrough_items()
+            }));
+    }
+
+    /// State and local income tax withheld on every Form W-2, summed by
+    /// jurisdiction.
+    pub fn w2_withholding(&self) -> JurisdictionWithholding {
+        let mut totals = JurisdictionWithholding::default();
+        for row in self.w2.iter().flat_map(|f| &f.state_tax) {
+            let state = row.state.trim().to_ascii_uppercase();
+            for local in &row.local_tax {
+                let locality = local.locality_name.trim().to_ascii_uppercase();
+                *totals
+                    .localities
+                    .entry((state.clone(), locality))
+                    .or_default() += local.local_income_tax;
+            }
+            *totals.states.entry(state).or_default() += row.state_income_tax;
+        }
+        totals
+    }
+
+    /// Adds state income tax withheld on Forms W-2 (box 17) to `input`,
+    /// from the rows whose box 15 names `input.state`. With
+    /// [`CombinedReturn`](gideon_tax_core::combined::CombinedReturn) it
+    /// then counts toward the federal SALT deduction.
+    pub fn apply_to_state(&self, input: &mut StateInput) {
+        input.withholding += self.w2_withholding().state(input.state);
+    }
+
+    /// Moving expense reimbursements excluded from wages (Form W-2, box 12,
+    /// code P), for
+    /// [`MilitaryMove::reimbursements`](gideon_tax_core::moving::MilitaryMove::reimbursements).
+    pub fn moving_expense_reimbursements(&self) -> Usd {
+        self.w2.iter().map(|f| f.box_12_total(Box12Code::P)).sum()
+    }
+}
+
+fn digits(tin: &str) -> String {
+    tin.chars().filter(char::is_ascii_digit).collect()
+}
+
+/// Withholding on one form, or `None` if nothing was withheld.
+fn reported(
+    source: WithholdingSource,
+    payer_name: &str,
+    payer_tin: &str,
+    amount: Usd,
+) -> Option<ReportedWithholding> {
+    (amount != Usd::ZERO).then(|| ReportedWithholding {
+        source,
+        payer_name: payer_name.to_string(),
+        payer_tin: payer_tin.to_string(),
+        amount,
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use gideon_tax_core::premium_tax_credit::{CoverageMonth, PovertyRegion};
+    use gideon_tax_core::rules::y2025::Rules2025;
+    use gideon_tax_core::spine::{Key, compute_spine};
+    use gideon_tax_core::withholding;
+    use gideon_tax_core::{FilingStatus, Rate, TaxYear, Usd};
+
+    use super::*;
+    use crate::y2025::federal::{
+        FinalYearDeduction, FinalYearDeductionCode, PolicyAllocation, W2LocalTaxGrp, W2StateTaxGrp,
+    };
+
+    /// A single 2025 return with no amounts.
+    pub(crate) fn input() -> ReturnInput {
+        ReturnInput::new(2025, FilingStatus::Single)
+    }
+
+    #[test]
+    fn w2_feeds_wages_and_withholding() {
+        let w2 = IrsW2 {
+            wages_tips_other_comp: Usd::from_dollars(40_000),
+            federal_income_tax_withheld: Usd::from_dollars(3_000),
+            ..Default::default()
+        };
+        let forms = SourceForms {
+            w2: vec![w2.clone(), w2],
+            ..Default::default()
+        };
+        let mut inp = input();
+        forms.apply_to(&mut inp);
+        assert_eq!(inp.w2_wages, Usd::from_dollars(80_000));
+        assert_eq!(inp.fed_withholding, Usd::from_dollars(6_000));
+    }
+
+    #[test]
+    fn ssa_1099_feeds_benefits_and_withholding() {
+        let forms = SourceForms {
+            ssa_1099: vec![
+                IrsSsa1099 {
+                    net_benefits: Usd::from_dollars(18_000),
+                    voluntary_federal_income_tax_withheld: Usd::from_dollars(1_800),
+                    ..Default::default()
+                },
+                IrsSsa1099 {
+                    net_benefits: Usd::from_dollars(-500),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let mut inp = input();
+        forms.apply_to(&mut inp);
+        assert_eq!(inp.social_security_benefits, Usd::from_dollars(17_500));
+        assert_eq!(
+            withholding::reported_total(&inp.form_1099_withholding),
+            Usd::from_dollars(1_800)
+        );
+    }
+
+    #[test]
+    fn form_1099_withholding_by_source() {
+        let forms = SourceForms {
+            form_1099_int: vec![Irs1099Int {
+                payer_name_address: "First Bank".to_string(),
+                interest_income: Usd::from_dollars(500),
+                federal_income_tax_withheld: Usd::from_dollars(120),
+                ..Default::default()
+            }],
+            form_1099_nec: vec![
+                Irs1099Nec {
+                    nonemployee_compensation: Usd::from_dollars(10_000),
+                    federal_income_tax_withheld: Usd::from_dollars(2_400),
+                    ..Default::default()
+                },
+                Irs1099Nec {
+                    nonemployee_compensation: Usd::from_dollars(1_000),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let mut inp = input();
+        forms.apply_to(&mut inp);
+        assert_eq!(inp.form_1099_withholding.len(), 2);
+        assert_eq!(inp.form_1099_withholding[0].payer_name, "First Bank");
+        assert_eq!(
+            withholding::by_source(&inp.form_1099_withholding)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![
+                (WithholdingSource::Form1099Int, Usd::from_dollars(120)),
+                (WithholdingSource::Form1099Nec, Usd::from_dollars(2_400)),
+            ]
+        );
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::Withholding], Usd::from_dollars(2_520));
+    }
+
+    #[test]
+    fn form_1099_sa_feeds_hsa_distributions() {
+        let forms = SourceForms {
+            form_1099_sa: vec![
+                Irs1099Sa {
+                    gross_distribution: Usd::from_dollars(1_200),
+                    ..Default::default()
+                },
+                Irs1099Sa {
+                    gross_distribution: Usd::from_dollars(300),
+                    distribution_code: DistributionCode::ExcessContributions,
+                    ..Default::default()
+                },
+                Irs1099Sa {
+                    gross_distribution: Usd::from_dollars(5_000),
+                    account_type: AccountType::ArcherMsa,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let mut inp = input();
+        forms.apply_to(&mut inp);
+        let hsa = inp.hsa_distributions;
+        assert_eq!(hsa.gross_distributions, Usd::from_dollars(1_500));
+        assert_eq!(hsa.rollovers_and_excess_withdrawn, Usd::from_dollars(300));
+        assert!(!hsa.additional_tax_exception);
+    }
+
+    #[test]
+    fn form_1095_a_feeds_premium_tax_credit() {
+        let month = CoverageMonth {
+            enrollment_premium: Usd::from_dollars(500),
+            slcsp_premium: Usd::from_dollars(450),
+            advance_payment: Usd::from_dollars(300),
+        };
+        let forms = SourceForms {
+            form_1095_a: vec![
+                Irs1095A {
+                    recipient_state: "AK".to_string(),
+                    monthly: [month; 12],
+                    ..Default::default()
+                },
+                Irs1095A {
+                    monthly: [month; 12],
+                    allocations: vec![PolicyAllocation {
+                        start_month: 0,
+                        end_month: 11,
+                        premium: Rate::ZERO,
+                        slcsp: Rate::ZERO,
+                        advance_payment: Rate::from_percent(50),
+                    }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let mut inp = input();
+        forms.apply_to(&mut inp);
+        let coverage = inp.marketplace_coverage.unwrap();
+        assert_eq!(coverage.region, PovertyRegion::Alaska);
+        assert_eq!(
+            coverage.months[0].enrollment_premium,
+            Usd::from_dollars(500)
+        );
+        assert_eq!(coverage.months[0].advance_payment, Usd::from_dollars(450));
+        // 60,000 is 318% of the Alaska poverty line for one person.
+        inp.w2_wages = Usd::from_dollars(60_000);
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(
+            ledger[&Key::ExcessAdvancePremiumTaxCredit],
+            Usd::from_dollars(1_625)
+        );
+    }
+
+    #[test]
+    fn schedules_k1_feed_passthroughs() {
+        let forms = SourceForms {
+            schedule_k1_1065: vec![IrsK1065 {
+                ordinary_business_income: Usd::from_dollars(12_000),
+                ..Default::default()
+            }],
+            schedule_k1_1120_s: vec![IrsK1120S::default()],
+            ..Default::default()
+        };
+        let mut inp = input();
+        forms.apply_to(&mut inp);
+        assert_eq!(inp.passthroughs.len(), 2);
+        assert_eq!(
+            inp.passthroughs[0].ordinary_income,
+            Usd::from_dollars(12_000)
+        );
+    }
+
+    #[test]
+    fn joint_return_attributes_forms_to_the_spouse() {
+        let w2 = |ssn: &str, wages| IrsW2 {
+            employee_ssn: ssn.to_string(),
+            social_security_wages: Usd::from_dollars(wages),
+            ..Default::default()
+        };
+        let forms = SourceForms {
+            w2: vec![w2("400-00-0001", 50_000), w2("400-00-0002", 30_000)],
+            schedule_k1_1065: vec![IrsK1065 {
+                partner_tin: "400000002".to_string(),
+                ..Default::default()
+            }],
+            schedule_k1_1120_s: vec![IrsK1120S {
+                shareholder_tin: "400-00-0001".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut inp = input();
+        forms.apply_to_joint("400-00-0002", &mut inp);
+        assert_eq!(inp.social_security_wages, Usd::from_dollars(50_000));
+        assert_eq!(inp.spouse_social_security_wages, Usd::from_dollars(30_000));
+        assert_eq!(
+            inp.unreported_tips.social_security_wages_and_tips,
+            Usd::from_dollars(80_000)
+        );
+        assert_eq!(inp.passthroughs[0].owner, Owner::Spouse);
+        assert_eq!(inp.passthroughs[1].owner, Owner::Taxpayer);
+    }
+
+    #[test]
+    fn schedule_k1_1041_excess_deductions_adjust_income() {
+        let forms = SourceForms {
+            schedule_k1_1041: vec![IrsK1041 {
+                interest_income: Usd::from_dollars(3_000),
+                final_return: true,
+                final_year_deductions: vec![FinalYearDeduction {
+                    code: FinalYearDeductionCode::Section67eExpenses,
+                    amount: Usd::from_dollars(1_200),
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut inp = input();
+        forms.apply_to(&mut inp);
+        assert_eq!(
+            inp.adjustments.section_67e_excess_deductions,
+            Usd::from_dollars(1_200)
+        );
+        let ledger = compute_spine(&Rules2025, &inp).unwrap();
+        assert_eq!(ledger[&Key::TaxableInterest], Usd::from_dollars(3_000));
+        assert_eq!(ledger[&Key::Adjustments], Usd::from_dollars(1_200));
+    }
+
+    #[test]
+    fn w2_state_rows_feed_state_withholding() {
+        let row = |state: &str, withheld| W2StateTaxGrp {
+            state: state.to_string(),
+            state_income_tax: Usd::from_dollars(withheld),
+            ..Default::default()
+        };
+        let forms = SourceForms {
+            w2: vec![
+                IrsW2 {
+                    state_tax: vec![row("CA", 1_500), row("OR", 300)],
+                    ..Default::default()
+                },
+                IrsW2 {
+                    state_tax: vec![row("ca", 250)],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let mut state = StateInput::new(TaxYear::Y2025, State::CA);
+        forms.apply_to_state(&mut state);
+        assert_eq!(state.withholding, Usd::from_dollars(1_750));
+    }
+
+    #[test]
+    fn w2_withholding_by_jurisdiction() {
+        let local = |name: &str, withheld| W2LocalTaxGrp {
+            locality_name: name.to_string(),
+            local_income_tax: Usd::from_dollars(withheld),
+            ..Default::default()
+        };
+        let row = |state: &str, withheld, locals| W2StateTaxGrp {
+            state: state.to_string(),
+            state_income_tax: Usd::from_dollars(withheld),
+            local_tax: locals,
+            ..Default::default()
+        };
+        let forms = SourceForms {
+            w2: vec![
+                IrsW2 {
+                    state_tax: vec![row("OH", 900, vec![local("Columbus", 600)])],
+                    ..Default::default()
+                },
+                IrsW2 {
+                    state_tax: vec![
+                        row(
+                            "oh",
+                            400,
+                            vec![local("COLUMBUS ", 250), local("Dublin", 50)],
+                        ),
+                        row("KY", 300, Vec::new()),
+                    ],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let totals = forms.w2_withholding();
+        assert_eq!(totals.state(State::OH), Usd::from_dollars(1_300));
+        assert_eq!(totals.state_total(), Usd::from_dollars(1_600));
+        assert_eq!(
+            totals.localities[&("OH".to_string(), "COLUMBUS".to_string())],
+            Usd::from_dollars(850)
+        );
+
+        let mut inp = input();
+        forms.apply_to(&mut inp);
+        assert_eq!(
+            inp.itemized_deductions.state_and_local_taxes,
+            Usd::from_dollars(900)
+        );
+    }
+}
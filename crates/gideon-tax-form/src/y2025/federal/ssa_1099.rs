@@ -0,0 +1,37 @@
+use gideon_tax_core::Usd;
+
+/// Form SSA-1099, Social Security Benefit Statement.
+///
+/// See: <https://www.ssa.gov/manage-benefits/get-tax-form-1099-1042s>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsSsa1099 {
+    /// Box 1.
+    pub beneficiary_name: String,
+    /// Box 2.
+    pub beneficiary_ssn: String,
+    /// Box 3.
+    pub benefits_paid: Usd,
+    /// Box 4.
+    pub benefits_repaid: Usd,
+    /// Box 5 (box 3 minus box 4). May be negative.
+    pub net_benefits: Usd,
+    /// Box 6.
+    pub voluntary_federal_income_tax_withheld: Usd,
+    /// Description of amount in box 3: Medicare Part B premiums.
+    pub medicare_part_b_premiums: Usd,
+    /// Description of amount in box 3: Medicare Part D premiums.
+    pub medicare_part_d_premiums: Usd,
+    /// Box 7.
+    pub address: Option<String>,
+    /// Box 8.
+    pub claim_number: Option<String>,
+}
+
+impl IrsSsa1099 {
+    /// Medicare premiums deducted from benefits, deductible as medical
+    /// expenses on Schedule A (or as self-employed health insurance).
+    pub fn medicare_premiums(&self) -> Usd {
+        self.medicare_part_b_premiums + self.medicare_part_d_premiums
+    }
+}
@@ -0,0 +1,87 @@
+//! Proptest strategies for 2025 source forms, enabled by the `proptest`
+//! feature. Return inputs and Schedule 1 adjustments are in
+//! [`gideon_tax_core::strategies`].
+
+use gideon_tax_core::Usd;
+use gideon_tax_core::strategies::{dollars, sometimes};
+use proptest::prelude::*;
+
+use super::{Box12Code, Box12Entry, IrsW2, SOCIAL_SECURITY_WAGE_BASE};
+
+/// A valid SSN in `XXX-XX-XXXX` form: area 001–665, never 000 or 666;
+/// nonzero group and serial.
+pub fn ssn() -> impl Strategy<Value = String> {
+    (1u32..=665, 1u32..=99, 1u32..=9999)
+        .prop_map(|(area, group, serial)| format!("{area:03}-{group:02}-{serial:04}"))
+}
+
+/// A valid EIN in `XX-XXXXXXX` form.
+pub fn ein() -> impl Strategy<Value = String> {
+    (10u32..=99, 0u32..=9_999_999).prop_map(|(prefix, serial)| format!("{prefix:02}-{serial:07}"))
+}
+
+/// A W-2 whose boxes agree with one another.
+///
+/// Box 1 is wages less any 401(k) deferral in box 12, code D. Boxes 3 and 5
+/// add the deferral back, box 3 stopping at the Social Security wage base,
+/// and boxes 4 and 6 are 6.2% and 1.45% of them. Withholding is at most 30%
+/// of box 1.
+pub fn w2() -> impl Strategy<Value = IrsW2> {
+    (
+        ssn(),
+        ein(),
+        dollars(0..=300_000),
+        sometimes(0..=23_500),
+        0i64..=30,
+    )
+        .prop_map(
+            |(employee_ssn, employer_ein, pay, deferral, withholding_percent)| {
+                let deferral = deferral.min(pay);
+                let wages = pay - deferral;
+                let social_security_wages = pay.min(SOCIAL_SECURITY_WAGE_BASE);
+                IrsW2 {
+                    employee_ssn,
+                    employer_ein,
+                    employer_name_address: "Employer Inc\n1 Main St\nAnytown, ST 00000".into(),
+                    employee_name: "Employee".into(),
+                    employee_address: "2 Main St\nAnytown, ST 00000".into(),
+                    wages_tips_other_comp: wages,
+                    federal_income_tax_withheld: wages.mul_div(withholding_percent, 100),
+                    social_security_wages,
+                    social_security_tax_withheld: social_security_wages.mul_div(62, 1_000),
+                    medicare_wages_and_tips: pay,
+                    medicare_tax_withheld: pay.mul_div(145, 10_000),
+                    box_12: if deferral == Usd::ZERO {
+                        Vec::new()
+                    } else {
+                        vec![Box12Entry {
+                            code: Box12Code::D,
+                            amount: deferral,
+                        }]
+                    },
+                    retirement_plan: deferral != Usd::ZERO,
+                    ..IrsW2::default()
+                }
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2025::federal::is_valid_ssn;
+
+    proptest! {
+        #[test]
+        fn generated_w2s_reconcile(form in w2()) {
+            prop_assert!(is_valid_ssn(&form.employee_ssn), "{}", form.employee_ssn);
+            prop_assert!(form.federal_income_tax_withheld <= form.wages_tips_other_comp);
+            let reconciliation = form.reconcile_wages();
+            prop_assert!(
+                reconciliation.diagnostics().is_empty(),
+                "{:?}",
+                reconciliation
+            );
+        }
+    }
+}
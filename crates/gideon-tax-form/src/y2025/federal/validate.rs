@@ -0,0 +1,487 @@
+//! Checks on a return's entered data, run before computing it.
+//!
+//! Each finding is a [`Diagnostic`] whose path names the offending field as
+//! the return bundle serializes it, such as
+//! `source_forms.w2[0].employee_ssn`, and whose fix says what to change.
+//! Errors are entries the IRS would reject or that make the computation
+//! wrong; warnings are entries worth a second look.
+
+use std::collections::BTreeMap;
+
+use gideon_tax_core::FilingStatus;
+#[cfg(feature = "json")]
+use gideon_tax_core::Usd;
+use gideon_tax_core::diagnostics::Diagnostic;
+use gideon_tax_core::parents_election::ChildInvestmentIncome;
+use gideon_tax_core::rules::y2025::Rules2025;
+
+use super::{Form1040Identity, SOCIAL_SECURITY_WAGE_BASE, SourceForms};
+
+/// Checks the filing status, names, SSNs, and dependents.
+pub fn validate_identity(identity: &Form1040Identity) -> Vec<Diagnostic> {
+    use FilingStatus::*;
+
+    let mut out = Vec::new();
+    let mut people = vec![("identity.taxpayer", &identity.taxpayer)];
+    if let Some(spouse) = &identity.spouse {
+        people.push(("identity.spouse", spouse));
+    }
+    for (path, person) in &people {
+        if person.first_name.trim().is_empty() || person.last_name.trim().is_empty() {
+            out.push(
+                Diagnostic::error("identity.name_missing", "first and last name are required")
+                    .at(*path)
+                    .with_fix("enter the name as it appears on the Social Security card"),
+            );
+        }
+        if !is_valid_ssn(&person.ssn) {
+            out.push(invalid_ssn(format!("{path}.ssn"), &person.ssn));
+        }
+    }
+
+    match (identity.filing_status, &identity.spouse) {
+        (MarriedFilingJointly, None) => out.push(
+            Diagnostic::error(
+                "identity.spouse_missing",
+                "a joint return needs the spouse's name and SSN",
+            )
+            .at("identity.spouse")
+            .with_fix("enter the spouse, or choose another filing status"),
+        ),
+        (MarriedFilingSeparately, None) if identity.filing_status_name.is_none() => out.push(
+            Diagnostic::error(
+                "identity.spouse_missing",
+                "married filing separately needs the spouse's name and SSN",
+            )
+            .at("identity.filing_status_name")
+            .with_fix("enter the spouse's name next to the filing status"),
+        ),
+        (Single | HeadOfHousehold, Some(_)) => out.push(
+            Diagnostic::warning(
+                "identity.unexpected_spouse",
+                format!(
+                    "a spouse is entered but the filing status is {:?}",
+                    identity.filing_status
+                ),
+            )
+            .at("identity.spouse")
+            .with_fix("remove the spouse, or file jointly or separately if married"),
+        ),
+        _ => {}
+    }
+    if identity.filing_status == HeadOfHousehold
+        && identity.dependents.is_empty()
+        && identity.filing_status_name.is_none()
+    {
+        out.push(
+            Diagnostic::error(
+                "identity.qualifying_person_missing",
+                "head of household needs a qualifying person",
+            )
+            .at("identity.dependents")
+            .with_fix(
+                "add the qualifying person as a dependent, or enter their name next to the \
+                 filing status if they are not one",
+            ),
+        );
+    }
+
+    if identity.can_be_claimed_as_dependent && !identity.dependents.is_empty() {
+        out.push(
+            Diagnostic::error(
+                "identity.dependent_claims_dependents",
+                "someone who can be claimed as a dependent cannot claim dependents \
+                 (IRC §152(b)(1))",
+            )
+            .at("identity.dependents")
+            .with_fix("remove the dependents or clear \"someone can claim you\""),
+        );
+    }
+    for (i, dependent) in identity.dependents.iter().enumerate() {
+        let path = format!("identity.dependents[{i}]");
+        if !is_valid_ssn(&dependent.ssn) {
+            out.push(invalid_ssn(format!("{path}.ssn"), &dependent.ssn));
+        }
+        if dependent.child_tax_credit && dependent.credit_for_other_dependents {
+            out.push(
+                Diagnostic::error(
+                    "identity.dependent_both_credits",
+                    "a dependent qualifies for the child tax credit or the credit for other \
+                     dependents, not both",
+                )
+                .at(path)
+                .with_fix("check only one box in column (5)"),
+            );
+        }
+    }
+
+    let mut seen: BTreeMap<String, String> = BTreeMap::new();
+    let ssns = people
+        .iter()
+        .map(|(path, person)| (format!("{path}.ssn"), &person.ssn))
+        .chain(
+            identity
+                .dependents
+                .iter()
+                .enumerate()
+                .map(|(i, d)| (format!("identity.dependents[{i}].ssn"), &d.ssn)),
+        );
+    for (path, ssn) in ssns {
+        let digits = digits(ssn);
+        if digits.is_empty() {
+            continue;
+        }
+        if let Some(first) = seen.get(&digits) {
+            out.push(
+                Diagnostic::error(
+                    "identity.duplicate_ssn",
+                    format!("the same SSN is entered at {first}"),
+                )
+                .at(path)
+                .with_fix("each person on the return needs their own SSN"),
+            );
+        } else {
+            seen.insert(digits, path);
+        }
+    }
+    out
+}
+
+/// Checks the source forms, and that W-2s belong to the taxpayer or
+/// spouse in `identity`.
+pub fn validate_source_forms(forms: &SourceForms, identity: &Form1040Identity) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+    let filers: Vec<String> = std::iter::once(&identity.taxpayer)
+        .chain(&identity.spouse)
+        .map(|p| digits(&p.ssn))
+        .collect();
+    for (i, w2) in forms.w2.iter().enumerate() {
+        let path = format!("source_forms.w2[{i}]");
+        if !is_valid_ein(&w2.employer_ein) {
+            out.push(invalid_ein(
+                format!("{path}.employer_ein"),
+                &w2.employer_ein,
+            ));
+        }
+        if !filers.contains(&digits(&w2.employee_ssn)) {
+            out.push(
+                Diagnostic::warning(
+                    "w2.ssn_mismatch",
+                    "the employee SSN is not the taxpayer's or the spouse's",
+                )
+                .at(format!("{path}.employee_ssn"))
+                .with_fix("check the SSN, or remove a W-2 that belongs to someone else"),
+            );
+        }
+        if w2.federal_income_tax_withheld > w2.wages_tips_other_comp {
+            out.push(
+                Diagnostic::warning(
+                    "w2.withholding_exceeds_wages",
+                    format!(
+                        "box 2 ({}) is more than box 1 ({})",
+                        w2.federal_income_tax_withheld, w2.wages_tips_other_comp
+                    ),
+                )
+                .at(format!("{path}.federal_income_tax_withheld"))
+                .with_fix("check boxes 1 and 2 for transposed or mistyped amounts"),
+            );
+        }
+        let social_security = w2.social_security_wages + w2.social_security_tips;
+        if social_security > SOCIAL_SECURITY_WAGE_BASE {
+            out.push(
+                Diagnostic::error(
+                    "w2.social_security_wage_base",
+                    format!(
+                        "boxes 3 and 7 total {social_security}, more than the \
+                         {SOCIAL_SECURITY_WAGE_BASE} wage base"
+                    ),
+                )
+                .at(format!("{path}.social_security_wages"))
+                .with_fix("check box 3, or ask the employer for a corrected W-2"),
+            );
+        }
+        out.extend(
+            w2.reconcile_wages()
+                .diagnostics()
+                .into_iter()
+                .map(|d| d.at(path.clone())),
+        );
+    }
+
+    let payers = [
+        (
+            "form_1099_int",
+            tins(&forms.form_1099_int, |f| &f.payer_tin),
+        ),
+        (
+            "form_1099_div",
+            tins(&forms.form_1099_div, |f| &f.payer_tin),
+        ),
+        (
+            "form_1099_nec",
+            tins(&forms.form_1099_nec, |f| &f.payer_tin),
+        ),
+        ("form_1099_b", tins(&forms.form_1099_b, |f| &f.payer_tin)),
+        ("form_1099_r", tins(&forms.form_1099_r, |f| &f.payer_tin)),
+        ("form_1099_g", tins(&forms.form_1099_g, |f| &f.payer_tin)),
+        ("form_1099_sa", tins(&forms.form_1099_sa, |f| &f.payer_tin)),
+    ];
+    for (field, tins) in payers {
+        for (i, tin) in tins.into_iter().enumerate() {
+            // Payers may be individuals, so an SSN is also a valid TIN.
+            if !is_valid_ein(tin) && !is_valid_ssn(tin) {
+                out.push(
+                    Diagnostic::error(
+                        "form_1099.payer_tin_invalid",
+                        format!("payer's TIN {tin:?} is not nine digits"),
+                    )
+                    .at(format!("source_forms.{field}[{i}].payer_tin"))
+                    .with_fix("enter the payer's TIN as printed on the form"),
+                );
+            }
+        }
+    }
+    out
+}
+
+/// Checks children's investment income elected onto the parent's return
+/// (Form 8814).
+pub fn validate_elections(children: &[ChildInvestmentIncome]) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+    for (i, child) in children.iter().enumerate() {
+        let path = format!("elections.children_investment_income[{i}]");
+        if !child.qualifies(&Rules2025) {
+            out.push(
+                Diagnostic::error(
+                    "elections.child_income_limit",
+                    format!(
+                        "{}'s gross income of {} is too much to report on the parent's return",
+                        child.child_name,
+                        child.gross_income()
+                    ),
+                )
+                .at(path.clone())
+                .with_fix("remove the election; the child must file their own return"),
+            );
+        }
+        if child.qualified_dividends > child.ordinary_dividends {
+            out.push(
+                Diagnostic::error(
+                    "elections.qualified_exceed_ordinary",
+                    "qualified dividends cannot exceed ordinary dividends",
+                )
+                .at(format!("{path}.qualified_dividends"))
+                .with_fix("check lines 2a and 2b of Form 8814"),
+            );
+        }
+    }
+    out
+}
+
+/// Runs every check on a bundle: identity, source forms, elections, and
+/// carryovers.
+#[cfg(feature = "json")]
+pub fn validate(bundle: &super::ReturnBundle) -> Vec<Diagnostic> {
+    let mut out = validate_identity(&bundle.identity);
+    out.extend(validate_source_forms(
+        &bundle.source_forms,
+        &bundle.identity,
+    ));
+    out.extend(validate_elections(
+        &bundle.elections.children_investment_income,
+    ));
+    for (i, nol) in bundle.carryovers.net_operating_losses.iter().enumerate() {
+        if nol.year >= 2025 {
+            out.push(
+                Diagnostic::error(
+                    "carryovers.nol_year",
+                    format!("a loss from {} cannot be carried into 2025", nol.year),
+                )
+                .at(format!("carryovers.net_operating_losses[{i}].year"))
+                .with_fix("enter the year the loss arose"),
+            );
+        }
+        if nol.amount < Usd::ZERO {
+            out.push(
+                Diagnostic::error(
+                    "carryovers.nol_negative",
+                    "enter a net operating loss carryover as a positive amount",
+                )
+                .at(format!("carryovers.net_operating_losses[{i}].amount")),
+            );
+        }
+    }
+    out
+}
+
+fn tins<T>(forms: &[T], tin: impl Fn(&T) -> &String) -> Vec<&str> {
+    forms.iter().map(|f| tin(f).as_str()).collect()
+}
+
+fn digits(tin: &str) -> String {
+    tin.chars().filter(char::is_ascii_digit).collect()
+}
+
+/// Nine digits once dashes and spaces are removed.
+fn nine_digits(tin: &str) -> Option<String> {
+    let digits = digits(tin);
+    (digits.len() == 9
+        && tin
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '-' || c == ' '))
+    .then_some(digits)
+}
+
+/// An SSN or ITIN the SSA or IRS could have issued: no area 000 or 666,
+/// no group 00 or serial 0000, and an area in the 900s only for ITINs,
+/// whose group is 50–65, 70–88, 90–92, or 94–99.
+pub(crate) fn is_valid_ssn(ssn: &str) -> bool {
+    let Some(digits) = nine_digits(ssn) else {
+        return false;
+    };
+    let area: u32 = digits[..3].parse().unwrap();
+    let group: u32 = digits[3..5].parse().unwrap();
+    let serial: u32 = digits[5..].parse().unwrap();
+    if area >= 900 {
+        return matches!(group, 50..=65 | 70..=88 | 90..=92 | 94..=99);
+    }
+    area != 0 && area != 666 && group != 0 && serial != 0
+}
+
+/// Nine digits, not starting with `00`.
+fn is_valid_ein(ein: &str) -> bool {
+    nine_digits(ein).is_some_and(|d| !d.starts_with("00"))
+}
+
+fn invalid_ssn(path: String, ssn: &str) -> Diagnostic {
+    Diagnostic::error(
+        "identity.ssn_invalid",
+        format!("{ssn:?} is not a valid SSN or ITIN"),
+    )
+    .at(path)
+    .with_fix("enter the nine-digit number from the Social Security card or ITIN letter")
+}
+
+fn invalid_ein(path: String, ein: &str) -> Diagnostic {
+    Diagnostic::error("w2.ein_invalid", format!("{ein:?} is not a valid EIN"))
+        .at(path)
+        .with_fix("enter the employer's nine-digit EIN from box b")
+}
+
+#[cfg(test)]
+mod tests {
+    use gideon_tax_core::Usd;
+
+    use super::*;
+    use crate::y2025::federal::{Form1040Dependent, Form1040Person, IrsW2};
+
+    fn person(first: &str, ssn: &str) -> Form1040Person {
+        Form1040Person {
+            first_name: first.to_string(),
+            last_name: "Doe".to_string(),
+            ssn: ssn.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn codes(diagnostics: &[Diagnostic]) -> Vec<(&str, &str)> {
+        diagnostics
+            .iter()
+            .map(|d| (d.code, d.path.as_deref().unwrap_or("")))
+            .collect()
+    }
+
+    #[test]
+    fn identity_checks() {
+        let identity = Form1040Identity {
+            filing_status: FilingStatus::MarriedFilingJointly,
+            taxpayer: person("Pat", "400-00-1234"),
+            dependents: vec![
+                Form1040Dependent {
+                    ssn: "401-01-0001".to_string(),
+                    child_tax_credit: true,
+                    credit_for_other_dependents: true,
+                    ..Default::default()
+                },
+                Form1040Dependent {
+                    ssn: "401 01 0001".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            codes(&validate_identity(&identity)),
+            [
+                ("identity.ssn_invalid", "identity.taxpayer.ssn"),
+                ("identity.spouse_missing", "identity.spouse"),
+                ("identity.dependent_both_credits", "identity.dependents[0]"),
+                ("identity.duplicate_ssn", "identity.dependents[1].ssn"),
+            ]
+        );
+        assert!(is_valid_ssn("912-70-1234"));
+        assert!(!is_valid_ssn("912-45-1234"));
+        assert!(!is_valid_ssn("666-12-1234"));
+    }
+
+    #[test]
+    fn source_form_checks() {
+        let identity = Form1040Identity {
+            taxpayer: person("Pat", "400-12-1234"),
+            ..Default::default()
+        };
+        let forms = SourceForms {
+            w2: vec![IrsW2 {
+                employee_ssn: "400-12-9999".to_string(),
+                employer_ein: "12-345678".to_string(),
+                wages_tips_other_comp: Usd::from_dollars(200_000),
+                federal_income_tax_withheld: Usd::from_dollars(30_000),
+                social_security_wages: Usd::from_dollars(200_000),
+                medicare_wages_and_tips: Usd::from_dollars(200_000),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let diagnostics = validate_source_forms(&forms, &identity);
+        assert_eq!(
+            codes(&diagnostics),
+            [
+                ("w2.ein_invalid", "source_forms.w2[0].employer_ein"),
+                ("w2.ssn_mismatch", "source_forms.w2[0].employee_ssn"),
+                (
+                    "w2.social_security_wage_base",
+                    "source_forms.w2[0].social_security_wages"
+                ),
+                ("w2.social_security_wages_gap", "source_forms.w2[0]"),
+            ]
+        );
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "error [w2.ein_invalid] at source_forms.w2[0].employer_ein: \"12-345678\" is not a \
+             valid EIN (enter the employer's nine-digit EIN from box b)"
+        );
+    }
+
+    #[test]
+    fn election_checks() {
+        let children = [ChildInvestmentIncome {
+            child_name: "Sam".to_string(),
+            ordinary_dividends: Usd::from_dollars(14_000),
+            qualified_dividends: Usd::from_dollars(15_000),
+            ..Default::default()
+        }];
+        assert_eq!(
+            codes(&validate_elections(&children)),
+            [
+                (
+                    "elections.child_income_limit",
+                    "elections.children_investment_income[0]"
+                ),
+                (
+                    "elections.qualified_exceed_ordinary",
+                    "elections.children_investment_income[0].qualified_dividends"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,490 @@
+use gideon_tax_core::Usd;
+use gideon_tax_core::diagnostics::Diagnostic;
+
+/// Social Security wage base for 2025 (maximum of boxes 3 and 7 combined).
+///
+/// See: <https://www.ssa.gov/oact/cola/cbb.html>
+pub const SOCIAL_SECURITY_WAGE_BASE: Usd = Usd::from_dollars(176_100);
+
+/// Differences of $1 or less are treated as rounding.
+const RECONCILIATION_TOLERANCE: Usd = Usd::from_dollars(1);
+
+/// Form W-2, Wage and Tax Statement.
+///
+/// See: <https://www.irs.gov/instructions/iw2w3>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrsW2 {
+    /// Box a.
+    pub employee_ssn: String,
+    /// Box b.
+    pub employer_ein: String,
+    /// Box c.
+    pub employer_name_address: String,
+    /// Box d.
+    pub control_number: Option<String>,
+    /// Box e.
+    pub employee_name: String,
+    /// Box f.
+    pub employee_address: String,
+    /// Box 1.
+    pub wages_tips_other_comp: Usd,
+    /// Box 2.
+    pub federal_income_tax_withheld: Usd,
+    /// Box 3.
+    pub social_security_wages: Usd,
+    /// Box 4.
+    pub social_security_tax_withheld: Usd,
+    /// Box 5.
+    pub medicare_wages_and_tips: Usd,
+    /// Box 6.
+    pub medicare_tax_withheld: Usd,
+    /// Box 7.
+    pub social_security_tips: Usd,
+    /// Box 8.
+    pub allocated_tips: Usd,
+    /// Box 10.
+    pub dependent_care_benefits: Usd,
+    /// Box 11.
+    pub nonqualified_plans: Usd,
+    /// Box 12.
+    pub box_12: Vec<Box12Entry>,
+    /// Box 13.
+    pub statutory_employee: bool,
+    /// Box 13.
+    pub retirement_plan: bool,
+    /// Box 13.
+    pub third_party_sick_pay: bool,
+    /// Box 14a.
+    pub box_14a_other: Option<String>,
+    /// Box 14b, comma-separated.
+    pub treasury_tipped_occupation_codes: Option<String>,
+    /// Boxes 15–20, one entry per state row.
+    pub state_tax: Vec<W2StateTaxGrp>,
+}
+
+/// One coded entry in box 12.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Box12Entry {
+    pub code: Box12Code,
+    pub amount: Usd,
+}
+
+/// Boxes 15–17: one state row, with any local rows (boxes 18–20) beneath it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct W2StateTaxGrp {
+    /// Box 15, two-letter state abbreviation.
+    pub state: String,
+    /// Box 15.
+    pub employer_state_id: Option<String>,
+    /// Box 16.
+    pub state_wages: Usd,
+    /// Box 17.
+    pub state_income_tax: Usd,
+    pub local_tax: Vec<W2LocalTaxGrp>,
+}
+
+/// Boxes 18–20: one locality row.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct W2LocalTaxGrp {
+    /// Box 18.
+    pub local_wages: Usd,
+    /// Box 19.
+    pub local_income_tax: Usd,
+    /// Box 20.
+    pub locality_name: String,
+}
+
+/// Box 12 codes.
+///
+/// See: <https://www.irs.gov/instructions/iw2w3>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Box12Code {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    J,
+    K,
+    L,
+    M,
+    N,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    V,
+    W,
+    Y,
+    Z,
+    AA,
+    BB,
+    DD,
+    EE,
+    FF,
+    GG,
+    HH,
+    II,
+    TA,
+    TP,
+    TT,
+}
+
+impl Box12Code {
+    /// Every code, in the order listed in the W-2 instructions.
+    pub const ALL: [Box12Code; 33] = {
+        use Box12Code::*;
+        [
+            A, B, C, D, E, F, G, H, J, K, L, M, N, P, Q, R, S, T, V, W, Y, Z, AA, BB, DD, EE, FF,
+            GG, HH, II, TA, TP, TT,
+        ]
+    };
+
+    /// The official code as printed in box 12.
+    pub fn code(self) -> &'static str {
+        use Box12Code::*;
+        match self {
+            A => "A",
+            B => "B",
+            C => "C",
+            D => "D",
+            E => "E",
+            F => "F",
+            G => "G",
+            H => "H",
+            J => "J",
+            K => "K",
+            L => "L",
+            M => "M",
+            N => "N",
+            P => "P",
+            Q => "Q",
+            R => "R",
+            S => "S",
+            T => "T",
+            V => "V",
+            W => "W",
+            Y => "Y",
+            Z => "Z",
+            AA => "AA",
+            BB => "BB",
+            DD => "DD",
+            EE => "EE",
+            FF => "FF",
+            GG => "GG",
+            HH => "HH",
+            II => "II",
+            TA => "TA",
+            TP => "TP",
+            TT => "TT",
+        }
+    }
+
+    /// Parses an official code (case-insensitive, surrounding whitespace
+    /// ignored).
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|c| c.code().eq_ignore_ascii_case(code))
+    }
+
+    /// Description from the W-2 instructions.
+    pub fn description(self) -> &'static str {
+        use Box12Code::*;
+        match self {
+            A => "Uncollected social security or RRTA tax on tips",
+            B => "Uncollected Medicare tax on tips",
+            C => "Taxable cost of group-term life insurance over $50,000",
+            D => "Elective deferrals under a section 401(k) plan",
+            E => "Elective deferrals under a section 403(b) plan",
+            F => "Elective deferrals under a section 408(k)(6) SEP",
+            G => "Elective deferrals and employer contributions to a section 457(b) plan",
+            H => "Elective deferrals under a section 501(c)(18)(D) plan",
+            J => "Nontaxable sick pay",
+            K => "20% excise tax on excess golden parachute payments",
+            L => "Substantiated employee business expense reimbursements",
+            M => {
+                "Uncollected social security or RRTA tax on group-term life insurance (former employees)"
+            }
+            N => "Uncollected Medicare tax on group-term life insurance (former employees)",
+            P => "Excludable moving expense reimbursements (Armed Forces)",
+            Q => "Nontaxable combat pay",
+            R => "Employer contributions to an Archer MSA",
+            S => "Employee salary reduction contributions under a section 408(p) SIMPLE plan",
+            T => "Adoption benefits",
+            V => "Income from exercise of nonstatutory stock option(s)",
+            W => "Employer contributions to a health savings account (HSA)",
+            Y => "Deferrals under a section 409A nonqualified deferred compensation plan",
+            Z => "Income under a section 409A plan failing to satisfy section 409A",
+            AA => "Designated Roth contributions under a section 401(k) plan",
+            BB => "Designated Roth contributions under a section 403(b) plan",
+            DD => "Cost of employer-sponsored health coverage",
+            EE => "Designated Roth contributions under a governmental section 457(b) plan",
+            FF => "Permitted benefits under a qualified small employer HRA",
+            GG => "Income from qualified equity grants under section 83(i)",
+            HH => "Aggregate deferrals under section 83(i) elections as of year-end",
+            II => "Medicaid waiver payments excluded under Notice 2014-7",
+            TA => "Employer contributions to Trump accounts under section 128",
+            TP => "Total cash tips reported to employer",
+            TT => "Total qualified overtime compensation",
+        }
+    }
+
+    /// `true` for amounts excluded from box 1 but included in boxes 3 and 5:
+    /// pre-tax elective deferrals, 409A deferrals, combat pay, and adoption
+    /// benefits.
+    fn excluded_from_box_1_only(self) -> bool {
+        use Box12Code::*;
+        matches!(self, D | E | F | G | H | S | Y | Q | T)
+    }
+}
+
+impl IrsW2 {
+    /// Sum of box 12 amounts reported under `code`.
+    pub fn box_12_total(&self, code: Box12Code) -> Usd {
+        self.box_12
+            .iter()
+            .filter(|e| e.code == code)
+            .map(|e| e.amount)
+            .sum()
+    }
+
+    /// Explains the differences between box 1 and boxes 3/5.
+    ///
+    /// Starting from box 1, adds back box 12 amounts that reduce box 1 but
+    /// not boxes 3/5 (401(k)/403(b)/457(b)/SIMPLE deferrals, combat pay,
+    /// adoption benefits), removes box 7 tips from the Social Security base,
+    /// and applies the Social Security wage base. Amounts that reduce all
+    /// three boxes (HSA contributions in code W, cafeteria-plan premiums)
+    /// cause no difference. Whatever remains is unexplained.
+    pub fn reconcile_wages(&self) -> WageReconciliation {
+        let mut differences = Vec::new();
+        for entry in &self.box_12 {
+            if entry.code.excluded_from_box_1_only() && entry.amount != Usd::ZERO {
+                differences.push(WageDifference {
+                    source: DifferenceSource::Box12(entry.code),
+                    social_security: entry.amount,
+                    medicare: entry.amount,
+                });
+            }
+        }
+        if self.social_security_tips != Usd::ZERO {
+            differences.push(WageDifference {
+                source: DifferenceSource::SocialSecurityTips,
+                social_security: -self.social_security_tips,
+                medicare: Usd::ZERO,
+            });
+        }
+
+        let uncapped: Usd =
+            self.wages_tips_other_comp + differences.iter().map(|d| d.social_security).sum::<Usd>();
+        let cap = (SOCIAL_SECURITY_WAGE_BASE - self.social_security_tips).max(Usd::ZERO);
+        if uncapped > cap {
+            differences.push(WageDifference {
+                source: DifferenceSource::WageBaseCap,
+                social_security: cap - uncapped,
+                medicare: Usd::ZERO,
+            });
+        }
+
+        let expected_ss: Usd =
+            self.wages_tips_other_comp + differences.iter().map(|d| d.social_security).sum::<Usd>();
+        let expected_medicare: Usd =
+            self.wages_tips_other_comp + differences.iter().map(|d| d.medicare).sum::<Usd>();
+
+        WageReconciliation {
+            differences,
+            expected_social_security_wages: expected_ss,
+            expected_medicare_wages: expected_medicare,
+            unexplained_social_security: self.social_security_wages - expected_ss,
+            unexplained_medicare: self.medicare_wages_and_tips - expected_medicare,
+        }
+    }
+}
+
+/// What accounts for part of the gap between box 1 and boxes 3/5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DifferenceSource {
+    /// A box 12 amount excluded from box 1 but not from boxes 3/5.
+    Box12(Box12Code),
+    /// Box 7 tips are reported separately from box 3.
+    SocialSecurityTips,
+    /// Box 3 stops at the Social Security wage base; box 5 has no cap.
+    WageBaseCap,
+}
+
+/// One explained difference, as an amount added to box 1 to arrive at the
+/// expected boxes 3 and 5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WageDifference {
+    pub source: DifferenceSource,
+    pub social_security: Usd,
+    pub medicare: Usd,
+}
+
+impl WageDifference {
+    /// Plain-language explanation of the difference.
+    pub fn explanation(&self) -> String {
+        match self.source {
+            DifferenceSource::Box12(code) => format!(
+                "Box 12 code {} ({}) reduces box 1 but not boxes 3 and 5",
+                code.code(),
+                code.description()
+            ),
+            DifferenceSource::SocialSecurityTips => {
+                "Social Security tips are reported in box 7 rather than box 3".to_string()
+            }
+            DifferenceSource::WageBaseCap => format!(
+                "Box 3 is capped at the {SOCIAL_SECURITY_WAGE_BASE} Social Security wage base"
+            ),
+        }
+    }
+}
+
+/// Result of [`IrsW2::reconcile_wages`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WageReconciliation {
+    pub differences: Vec<WageDifference>,
+    pub expected_social_security_wages: Usd,
+    pub expected_medicare_wages: Usd,
+    /// Box 3 minus the expected amount.
+    pub unexplained_social_security: Usd,
+    /// Box 5 minus the expected amount.
+    pub unexplained_medicare: Usd,
+}
+
+impl WageReconciliation {
+    /// Flags gaps that box 12 and box 7 do not explain.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        for (code, label, gap) in [
+            (
+                "w2.social_security_wages_gap",
+                "Box 3 (Social Security wages)",
+                self.unexplained_social_security,
+            ),
+            (
+                "w2.medicare_wages_gap",
+                "Box 5 (Medicare wages)",
+                self.unexplained_medicare,
+            ),
+        ] {
+            if gap.abs() <= RECONCILIATION_TOLERANCE {
+                continue;
+            }
+            let hint = if gap < Usd::ZERO {
+                "; pre-tax cafeteria plan (section 125) deductions are a common cause, \
+                 otherwise check for a data-entry error"
+            } else {
+                "; check box 1 and box 12 for a data-entry error"
+            };
+            out.push(Diagnostic::warning(
+                code,
+                format!("{label} differs from the amount expected from box 1 by {gap}{hint}"),
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn w2(box1: i64, box3: i64, box5: i64) -> IrsW2 {
+        IrsW2 {
+            wages_tips_other_comp: Usd::from_dollars(box1),
+            social_security_wages: Usd::from_dollars(box3),
+            medicare_wages_and_tips: Usd::from_dollars(box5),
+            ..Default::default()
+        }
+    }
+
+    fn entry(code: Box12Code, amount: i64) -> Box12Entry {
+        Box12Entry {
+            code,
+            amount: Usd::from_dollars(amount),
+        }
+    }
+
+    #[test]
+    fn code_round_trip() {
+        for code in Box12Code::ALL {
+            assert_eq!(Box12Code::from_code(code.code()), Some(code));
+        }
+        assert_eq!(Box12Code::from_code(" dd "), Some(Box12Code::DD));
+        assert_eq!(Box12Code::from_code("X"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_with_official_codes() {
+        let form = IrsW2 {
+            box_12: vec![entry(Box12Code::DD, 8_000)],
+            ..w2(60_000, 60_000, 60_000)
+        };
+        let json = serde_json::to_string(&form).unwrap();
+        assert!(json.contains(r#""code":"DD""#), "{json}");
+        let back: IrsW2 = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, form);
+        assert!(serde_json::from_str::<Box12Code>(r#""XX""#).is_err());
+    }
+
+    #[test]
+    fn matching_boxes_consistent() {
+        let r = w2(60_000, 60_000, 60_000).reconcile_wages();
+        assert!(r.differences.is_empty());
+        assert!(r.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn elective_deferral_explains_gap() {
+        let mut form = w2(60_000, 70_000, 70_000);
+        form.box_12 = vec![entry(Box12Code::D, 10_000), entry(Box12Code::W, 3_000)];
+        let r = form.reconcile_wages();
+        assert_eq!(r.differences.len(), 1);
+        assert_eq!(r.expected_social_security_wages, Usd::from_dollars(70_000));
+        assert_eq!(r.unexplained_medicare, Usd::ZERO);
+        assert!(r.diagnostics().is_empty());
+        assert!(r.differences[0].explanation().contains("code D"));
+    }
+
+    #[test]
+    fn wage_base_cap_and_tips() {
+        let mut form = w2(200_000, 171_100, 200_000);
+        form.social_security_tips = Usd::from_dollars(5_000);
+        let r = form.reconcile_wages();
+        assert_eq!(r.expected_social_security_wages, Usd::from_dollars(171_100));
+        assert_eq!(r.expected_medicare_wages, Usd::from_dollars(200_000));
+        assert!(r.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn unexplained_gap_flagged() {
+        let r = w2(60_000, 57_600, 57_600).reconcile_wages();
+        assert_eq!(r.unexplained_social_security, Usd::from_dollars(-2_400));
+        let diags = r.diagnostics();
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].code, "w2.social_security_wages_gap");
+        assert!(diags[0].message.contains("cafeteria"));
+    }
+
+    #[test]
+    fn rounding_within_tolerance() {
+        let mut form = w2(60_000, 60_000, 60_000);
+        form.medicare_wages_and_tips = Usd::from_cents(6_000_050);
+        assert!(form.reconcile_wages().diagnostics().is_empty());
+    }
+}
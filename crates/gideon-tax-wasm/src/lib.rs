@@ -0,0 +1,194 @@
+//! WebAssembly bindings for running the tax engine in a browser.
+//!
+//! Every function takes and returns JSON strings, so a page can compute a
+//! return without sending any of it to a server. Amounts are in cents, as
+//! they serialize everywhere else in the workspace. Errors are thrown as
+//! JavaScript `Error`s carrying the Rust error's message.
+//!
+//! Returns are passed as
+//! [`ReturnBundle`](gideon_tax_form::y2025::federal::ReturnBundle)
+//! documents, the format written by
+//! [`gideon_tax_form::y2025::federal::export`].
+
+use gideon_tax_core::projection::{self, ProjectionAssumptions, ScheduledEvent};
+use gideon_tax_core::rules::{self, DeductionParams, TaxYearRules};
+use gideon_tax_core::spine::{self, ReturnInput};
+use gideon_tax_core::{Error, Filer, FilingStatus, Usd};
+use gideon_tax_form::y2025::federal::{self, BundleError};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+/// Computes the return in a bundle and returns its ledger, an object from
+/// line name to amount, such as `{"AGI": 5000000, ...}`.
+#[wasm_bindgen(js_name = computeSpine)]
+pub fn compute_spine(bundle: &str) -> Result<String, JsError> {
+    Ok(compute_spine_json(bundle)?)
+}
+
+/// Computes a standard deduction from a [`StandardDeductionRequest`] and
+/// returns the amount.
+#[wasm_bindgen(js_name = standardDeduction)]
+pub fn standard_deduction(request: &str) -> Result<String, JsError> {
+    Ok(standard_deduction_json(request)?)
+}
+
+/// Projects the return in a bundle forward under a [`Scenario`] and returns
+/// the list of projected years, each with its year, wages, and ledger.
+#[wasm_bindgen(js_name = projectScenario)]
+pub fn project_scenario(bundle: &str, scenario: &str) -> Result<String, JsError> {
+    Ok(project_scenario_json(bundle, scenario)?)
+}
+
+/// Input to [`standard_deduction`]; see [`DeductionParams`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct StandardDeductionRequest {
+    /// Calendar year, such as 2025.
+    pub tax_year: i32,
+    #[serde(with = "gideon_tax_core::types::filing_status_serde")]
+    pub filing_status: FilingStatus,
+    #[serde(default)]
+    pub taxpayer: Filer,
+    #[serde(default)]
+    pub spouse: Option<Filer>,
+    #[serde(default)]
+    pub is_dependent: bool,
+    #[serde(default)]
+    pub is_dual_status_alien: bool,
+    #[serde(default)]
+    pub spouse_itemizes: bool,
+    #[serde(default)]
+    pub earned_income: Usd,
+}
+
+/// Input to [`project_scenario`]; see [`projection::project`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Scenario {
+    /// Years to project after the bundle's own.
+    pub years: u32,
+    pub assumptions: ProjectionAssumptions,
+    pub events: Vec<ScheduledEvent>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WasmError {
+    #[error("{0}")]
+    Bundle(#[from] BundleError),
+    #[error("invalid request: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Compute(#[from] Error),
+    /// No rules ship for this calendar year.
+    #[error("tax year {0} is not supported")]
+    UnsupportedYear(i32),
+}
+
+// The functions below do the work of the exports above. They return
+// `WasmError` rather than `JsError`, which can only be built on a wasm
+// target, so they can be tested natively.
+
+fn compute_spine_json(bundle: &str) -> Result<String, WasmError> {
+    let input = bundle_input(bundle)?;
+    let ledger = spine::compute_spine(bundle_rules(), &input)?;
+    Ok(serde_json::to_string(&ledger)?)
+}
+
+fn standard_deduction_json(request: &str) -> Result<String, WasmError> {
+    let request: StandardDeductionRequest = serde_json::from_str(request)?;
+    let rules = rules::rules_for_calendar_year(request.tax_year)
+        .ok_or(WasmError::UnsupportedYear(request.tax_year))?;
+    let deduction = rules.standard_deduction(&DeductionParams {
+        filing_status: request.filing_status,
+        taxpayer: request.taxpayer,
+        spouse: request.spouse,
+        is_dependent: request.is_dependent,
+        is_dual_status_alien: request.is_dual_status_alien,
+        spouse_itemizes: request.spouse_itemizes,
+        earned_income: request.earned_income,
+    });
+    Ok(serde_json::to_string(&deduction)?)
+}
+
+fn project_scenario_json(bundle: &str, scenario: &str) -> Result<String, WasmError> {
+    let input = bundle_input(bundle)?;
+    let scenario: Scenario = serde_json::from_str(scenario)?;
+    let years = projection::project(
+        bundle_rules(),
+        &input,
+        scenario.years,
+        &scenario.assumptions,
+        &scenario.events,
+    )?;
+    Ok(serde_json::to_string(&years)?)
+}
+
+/// Bundles hold 2025 forms.
+fn bundle_rules() -> &'static dyn TaxYearRules {
+    &rules::y2025::Rules2025
+}
+
+fn bundle_input(bundle: &str) -> Result<ReturnInput, WasmError> {
+    Ok(federal::import(bundle)?.to_input())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn bundle() -> String {
+        federal::export(&federal::ReturnBundle {
+            source_forms: federal::SourceForms {
+                w2: vec![federal::IrsW2 {
+                    wages_tips_other_comp: Usd::from_dollars(60_000),
+                    federal_income_tax_withheld: Usd::from_dollars(6_000),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn computes_a_bundle() {
+        let ledger: Value = serde_json::from_str(&compute_spine_json(&bundle()).unwrap()).unwrap();
+        assert_eq!(ledger["AGI"], 6_000_000);
+        assert_eq!(ledger["Deductions"], 1_575_000);
+        assert_eq!(ledger["TaxableIncome"], 4_425_000);
+        assert_eq!(ledger["Withholding"], 600_000);
+    }
+
+    #[test]
+    fn standard_deduction_by_year() {
+        let request = |year| {
+            format!(
+                r#"{{"tax_year": {year}, "filing_status": "MarriedFilingJointly",
+                    "taxpayer": {{"is_65_or_older": true, "is_blind": false}},
+                    "spouse": {{"is_65_or_older": false, "is_blind": false}}}}"#
+            )
+        };
+        assert_eq!(standard_deduction_json(&request(2025)).unwrap(), "3310000");
+        assert_eq!(standard_deduction_json(&request(2026)).unwrap(), "3385000");
+        assert!(matches!(
+            standard_deduction_json(&request(2024)),
+            Err(WasmError::UnsupportedYear(2024))
+        ));
+    }
+
+    #[test]
+    fn projects_a_scenario() {
+        let scenario = r#"{
+            "years": 2,
+            "assumptions": {"wage_growth": 500},
+            "events": [{"year": 2026, "event": "ChildBorn"}]
+        }"#;
+        let years: Value =
+            serde_json::from_str(&project_scenario_json(&bundle(), scenario).unwrap()).unwrap();
+        let years = years.as_array().unwrap();
+        assert_eq!(years.len(), 3);
+        assert_eq!(years[0]["year"], 2025);
+        assert_eq!(years[1]["w2_wages"], 6_300_000);
+        assert_eq!(years[2]["ledger"]["AGI"], 6_615_000);
+    }
+}
@@ -0,0 +1,208 @@
+//! How federal ledger lines are figured from one another.
+
+use std::fmt::Write;
+
+use gideon_tax_core::Usd;
+use gideon_tax_core::spine::{Key, Ledger, ReturnInput};
+
+/// One term of a line's formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Term {
+    /// Wages and tips, which come from the input rather than the ledger.
+    Wages,
+    Line(Key),
+}
+
+use Term::{Line, Wages};
+
+/// Terms added (`+1`) or subtracted (`-1`), and any limit on the result.
+type Formula = (&'static [(i8, Term)], Option<&'static str>);
+
+/// How `key` is figured, or `None` if it is figured from the input
+/// directly. Mirrors `compute_spine`.
+fn formula(key: Key) -> Option<Formula> {
+    Some(match key {
+        Key::TotalIncome => (
+            &[
+                (1, Wages),
+                (1, Line(Key::TaxableInterest)),
+                (1, Line(Key::OrdinaryDividends)),
+                (1, Line(Key::TaxableSocialSecurity)),
+                (1, Line(Key::CapitalGainOrLoss)),
+                (1, Line(Key::SupplementalIncome)),
+                (1, Line(Key::TaxableHsaDistributions)),
+                (1, Line(Key::TaxableScholarships)),
+                (1, Line(Key::ChildInvestmentIncome)),
+                (-1, Line(Key::NolDeduction)),
+            ],
+            None,
+        ),
+        Key::AGI => (
+            &[(1, Line(Key::TotalIncome)), (-1, Line(Key::Adjustments))],
+            None,
+        ),
+        Key::TaxableIncome => (
+            &[
+                (1, Line(Key::AGI)),
+                (-1, Line(Key::Deductions)),
+                (-1, Line(Key::Exemptions)),
+                (-1, Line(Key::QbiDeduction)),
+            ],
+            Some("not less than zero before the QBI deduction"),
+        ),
+        Key::AdditionalTax => (
+            &[
+                (1, Line(Key::SelfEmploymentTax)),
+                (1, Line(Key::HsaAdditionalTax)),
+                (1, Line(Key::UnreportedTipsTax)),
+                (1, Line(Key::ExcessAdvancePremiumTaxCredit)),
+            ],
+            None,
+        ),
+        Key::TotalTaxPreCredits => (
+            &[
+                (1, Line(Key::RegularTax)),
+                (1, Line(Key::ChildInvestmentTax)),
+                (1, Line(Key::AdditionalTax)),
+            ],
+            None,
+        ),
+        Key::TaxAfterNonRefundableCredits => (
+            &[
+                (1, Line(Key::TotalTaxPreCredits)),
+                (-1, Line(Key::NonRefundableCredits)),
+            ],
+            Some("not less than zero"),
+        ),
+        Key::RefundableCredits => (&[(1, Line(Key::NetPremiumTaxCredit))], None),
+        Key::TotalTax => (
+            &[
+                (1, Line(Key::TaxAfterNonRefundableCredits)),
+                (-1, Line(Key::RefundableCredits)),
+            ],
+            None,
+        ),
+        Key::TotalPayments => (
+            &[
+                (1, Line(Key::Withholding)),
+                (1, Line(Key::EstimatedPayments)),
+                (1, Line(Key::ExtensionPayment)),
+            ],
+            None,
+        ),
+        Key::Refund => (
+            &[(1, Line(Key::TotalPayments)), (-1, Line(Key::TotalTax))],
+            Some("zero if payments do not exceed the tax"),
+        ),
+        Key::AmountOwed => (
+            &[(1, Line(Key::TotalTax)), (-1, Line(Key::TotalPayments))],
+            Some("zero if payments cover the tax"),
+        ),
+        _ => return None,
+    })
+}
+
+/// Finds a key by its name, such as `TaxableIncome`, or its label, such as
+/// `taxable income`, ignoring case.
+pub fn find_key(name: &str) -> Option<Key> {
+    let name = name.trim();
+    Key::ALL.into_iter().find(|k| {
+        format!("{k:?}").eq_ignore_ascii_case(name) || k.label().eq_ignore_ascii_case(name)
+    })
+}
+
+/// The line's amount, the lines it is figured from, and the lines figured
+/// from it.
+pub fn explain(key: Key, input: &ReturnInput, ledger: &Ledger) -> String {
+    let amount = |term: Term| match term {
+        Wages => input.w2_wages + input.unreported_tips.total(),
+        Line(k) => ledger.get(&k).copied().unwrap_or(Usd::ZERO),
+    };
+    let label = |term: Term| match term {
+        Wages => "Wages, salaries, and tips",
+        Line(k) => k.label(),
+    };
+    let mut out = format!("{} ({key:?}): {}\n", key.label(), amount(Line(key)));
+    match formula(key) {
+        Some((terms, limit)) => {
+            for &(sign, term) in terms {
+                let op = if sign < 0 { '-' } else { '+' };
+                writeln!(
+                    out,
+                    "  {op} {:<40} {:>14}",
+                    label(term),
+                    amount(term).to_string()
+                )
+                .unwrap();
+            }
+            if let Some(limit) = limit {
+                writeln!(out, "  ({limit})").unwrap();
+            }
+        }
+        None => out.push_str("  Figured from the return's inputs.\n"),
+    }
+    let used_in: Vec<&str> = Key::ALL
+        .into_iter()
+        .filter(|&k| formula(k).is_some_and(|(terms, _)| terms.iter().any(|t| t.1 == Line(key))))
+        .map(Key::label)
+        .collect();
+    if !used_in.is_empty() {
+        writeln!(out, "Used in: {}", used_in.join(", ")).unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_keys_by_name_or_label() {
+        assert_eq!(find_key("taxableincome"), Some(Key::TaxableIncome));
+        assert_eq!(find_key(Key::AGI.label()), Some(Key::AGI));
+        assert_eq!(find_key("line 99"), None);
+    }
+
+    #[test]
+    fn formulas_match_the_spine() {
+        use crate::load::{RULES, compute};
+        use gideon_tax_form::y2025::federal::{IrsW2, ReturnBundle, SourceForms};
+
+        let bundle = ReturnBundle {
+            source_forms: SourceForms {
+                w2: vec![IrsW2 {
+                    wages_tips_other_comp: Usd::from_dollars(70_000),
+                    federal_income_tax_withheld: Usd::from_dollars(9_000),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let computed = compute(&bundle).unwrap();
+        let ledger = &computed.combined.federal;
+        assert_eq!(RULES.calendar_year(), computed.input.tax_year);
+        for key in Key::ALL {
+            let Some((terms, _)) = formula(key) else {
+                continue;
+            };
+            let sum: Usd = terms
+                .iter()
+                .map(|&(sign, term)| {
+                    let amount = match term {
+                        Wages => computed.input.w2_wages,
+                        Line(k) => ledger[&k],
+                    };
+                    if sign < 0 { Usd::ZERO - amount } else { amount }
+                })
+                .sum();
+            assert_eq!(sum.max(Usd::ZERO), ledger[&key], "{key:?}");
+        }
+        let text = explain(Key::AGI, &computed.input, ledger);
+        assert!(
+            text.starts_with("Adjusted gross income (AGI): $70000.00\n"),
+            "{text}"
+        );
+        assert!(text.contains("Used in: Taxable income\n"), "{text}");
+    }
+}
@@ -0,0 +1,150 @@
+//! Reading bundles and computing them.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use gideon_tax_core::TaxYear;
+use gideon_tax_core::combined::{CombinedReturn, StateFiling};
+use gideon_tax_core::rules::TaxYearRules;
+use gideon_tax_core::rules::y2025::Rules2025;
+use gideon_tax_core::spine::ReturnInput;
+use gideon_tax_core::state::ca::California2025;
+use gideon_tax_core::state::flat::flat_tax_state;
+use gideon_tax_core::state::{State, StateInput, StateRules};
+use gideon_tax_form::y2025::federal::{self, ReturnBundle};
+use serde::de::DeserializeOwned;
+
+use crate::CliError;
+
+/// Bundles hold 2025 forms.
+pub const RULES: &dyn TaxYearRules = &Rules2025;
+
+/// A bundle and the returns computed from it.
+pub struct Computed {
+    pub input: ReturnInput,
+    pub combined: CombinedReturn,
+    /// States named on the bundle's W-2s that have no rules in this
+    /// workspace, so were not computed.
+    pub skipped_states: Vec<String>,
+}
+
+/// Reads a file as TOML if its name ends in `.toml`, and as JSON
+/// otherwise.
+pub fn read<T: DeserializeOwned>(path: &Path) -> Result<T, CliError> {
+    let text = fs::read_to_string(path).map_err(|e| CliError::Io(path.into(), e))?;
+    parse(path, &text)
+}
+
+pub fn parse<T: DeserializeOwned>(path: &Path, text: &str) -> Result<T, CliError> {
+    if path
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("toml"))
+    {
+        toml::from_str(text).map_err(|e| CliError::Toml(path.into(), e))
+    } else {
+        serde_json::from_str(text).map_err(|e| CliError::Json(path.into(), e))
+    }
+}
+
+/// Reads a bundle, checking its schema version and tax year as
+/// [`federal::import`] does.
+pub fn read_bundle(path: &Path) -> Result<ReturnBundle, CliError> {
+    let doc: serde_json::Value = read(path)?;
+    federal::import(&doc.to_string()).map_err(|e| CliError::Bundle(path.into(), e))
+}
+
+/// Computes the federal return and a resident return for each state whose
+/// income tax is withheld on a W-2.
+pub fn compute(bundle: &ReturnBundle) -> Result<Computed, CliError> {
+    let input = bundle.to_input();
+    let codes: BTreeSet<String> = bundle
+        .source_forms
+        .w2
+        .iter()
+        .flat_map(|w2| &w2.state_tax)
+        .map(|row| row.state.trim().to_ascii_uppercase())
+        .filter(|code| !code.is_empty())
+        .collect();
+    let mut skipped_states = Vec::new();
+    let mut states: Vec<(&dyn StateRules, StateInput)> = Vec::new();
+    for code in codes {
+        let Some((state, rules)) = State::from_code(&code).and_then(|s| Some((s, state_rules(s)?)))
+        else {
+            skipped_states.push(code);
+            continue;
+        };
+        let mut state_input = StateInput::new(TaxYear::Y2025, state);
+        bundle.source_forms.apply_to_state(&mut state_input);
+        states.push((rules, state_input));
+    }
+    let filings: Vec<StateFiling<'_>> = states
+        .iter()
+        .map(|(rules, input)| StateFiling {
+            rules: *rules,
+            input,
+        })
+        .collect();
+    let combined = CombinedReturn::compute(RULES, &input, &filings).map_err(CliError::Compute)?;
+    Ok(Computed {
+        input,
+        combined,
+        skipped_states,
+    })
+}
+
+fn state_rules(state: State) -> Option<&'static dyn StateRules> {
+    match state {
+        State::CA => Some(&California2025),
+        _ => Some(flat_tax_state(state, TaxYear::Y2025)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gideon_tax_core::Usd;
+    use gideon_tax_core::spine::Key;
+    use gideon_tax_core::state::StateKey;
+
+    #[test]
+    fn reads_toml_and_json_alike() {
+        let json = r#"{"schema_version": 1, "identity": {"filing_status": "HeadOfHousehold"}}"#;
+        let toml = "schema_version = 1\n[identity]\nfiling_status = \"HeadOfHousehold\"\n";
+        let a: serde_json::Value = parse(Path::new("a.json"), json).unwrap();
+        let b: serde_json::Value = parse(Path::new("b.TOML"), toml).unwrap();
+        assert_eq!(a, b);
+        assert!(matches!(
+            parse::<serde_json::Value>(Path::new("c.toml"), json),
+            Err(CliError::Toml(..))
+        ));
+    }
+
+    #[test]
+    fn computes_states_named_on_w2s() {
+        let row = |state: &str| federal::W2StateTaxGrp {
+            state: state.to_string(),
+            state_income_tax: Usd::from_dollars(1_000),
+            ..Default::default()
+        };
+        let bundle = ReturnBundle {
+            source_forms: federal::SourceForms {
+                w2: vec![federal::IrsW2 {
+                    wages_tips_other_comp: Usd::from_dollars(80_000),
+                    state_tax: vec![row("ca"), row("ZZ")],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let computed = compute(&bundle).unwrap();
+        assert_eq!(
+            computed.combined.federal[&Key::AGI],
+            Usd::from_dollars(80_000)
+        );
+        let ca = computed.combined.state(State::CA).unwrap();
+        assert_eq!(ca[&StateKey::Withholding], Usd::from_dollars(1_000));
+        assert_eq!(computed.skipped_states, ["ZZ"]);
+    }
+}
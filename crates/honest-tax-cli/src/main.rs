@@ -0,0 +1,389 @@
+//! `honest-tax-cli`: computes return bundles from the command line.
+//!
+//! Bundles are the JSON documents written by
+//! [`gideon_tax_form::y2025::federal::export`], or the same document in
+//! TOML when the file name ends in `.toml`.
+
+mod explain;
+mod load;
+
+use std::fmt::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use gideon_tax_core::Usd;
+use gideon_tax_core::projection::{self, ProjectionAssumptions, ScheduledEvent};
+use gideon_tax_core::refund_change::{YearReturn, explain_refund_change};
+use gideon_tax_core::report::ReturnReport;
+use gideon_tax_core::spine::Key;
+use gideon_tax_form::y2025::federal::BundleError;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::load::{Computed, RULES};
+
+const USAGE: &str = "\
+usage: honest-tax-cli <command> [--json] <args>
+
+commands:
+  compute <bundle>               federal and state ledgers and a summary
+  diff <prior> <current>         lines that differ and why the refund moved
+  explain <key> <bundle>         how one federal line is figured
+  scenarios <bundle> <file>      project the return under each scenario
+
+Bundles and scenario files are JSON, or TOML if the name ends in .toml.";
+
+#[derive(Debug)]
+pub enum CliError {
+    Usage(String),
+    Io(PathBuf, std::io::Error),
+    Json(PathBuf, serde_json::Error),
+    Toml(PathBuf, toml::de::Error),
+    Bundle(PathBuf, BundleError),
+    Compute(gideon_tax_core::Error),
+    Projection(gideon_tax_core::Error),
+    UnknownKey(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{message}\n\n{USAGE}"),
+            CliError::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            CliError::Json(path, e) => write!(f, "{}: invalid JSON: {e}", path.display()),
+            CliError::Toml(path, e) => write!(f, "{}: invalid TOML: {e}", path.display()),
+            CliError::Bundle(path, e) => write!(f, "{}: {e}", path.display()),
+            CliError::Compute(e) => e.fmt(f),
+            CliError::Projection(e) => write!(f, "projection: {e}"),
+            CliError::UnknownKey(name) => write!(f, "no ledger line named {name:?}"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CliError::Io(_, e) => Some(e),
+            CliError::Json(_, e) => Some(e),
+            CliError::Toml(_, e) => Some(e),
+            CliError::Bundle(_, e) => Some(e),
+            CliError::Compute(e) => Some(e),
+            CliError::Projection(e) => Some(e),
+            CliError::Usage(_) | CliError::UnknownKey(_) => None,
+        }
+    }
+}
+
+/// A projection to run against a bundle; see [`projection::project`].
+#[derive(Debug, Clone, Deserialize)]
+struct Scenario {
+    name: String,
+    /// Years to project after the bundle's own.
+    #[serde(default)]
+    years: u32,
+    #[serde(default)]
+    assumptions: ProjectionAssumptions,
+    #[serde(default)]
+    events: Vec<ScheduledEvent>,
+}
+
+/// A scenarios file: `[[scenario]]` tables in TOML, or
+/// `{"scenario": [...]}` in JSON.
+#[derive(Debug, Clone, Deserialize)]
+struct ScenarioFile {
+    scenario: Vec<Scenario>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(out) => {
+            print!("{out}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("honest-tax-cli: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the command in `args` and returns what it prints.
+fn run(args: &[String]) -> Result<String, CliError> {
+    let json = args.iter().any(|a| a == "--json");
+    let mut args = args.iter().filter(|a| *a != "--json").map(String::as_str);
+    let command = args.next();
+    let rest: Vec<&str> = args.collect();
+    let usage = |message: &str| CliError::Usage(message.to_string());
+    match (command, rest.as_slice()) {
+        (Some("compute"), [bundle]) => compute(Path::new(bundle), json),
+        (Some("diff"), [prior, current]) => diff(Path::new(prior), Path::new(current), json),
+        (Some("explain"), [key, bundle]) => {
+            let key =
+                explain::find_key(key).ok_or_else(|| CliError::UnknownKey(key.to_string()))?;
+            let computed = load::compute(&load::read_bundle(Path::new(bundle))?)?;
+            Ok(explain::explain(
+                key,
+                &computed.input,
+                &computed.combined.federal,
+            ))
+        }
+        (Some("scenarios"), [bundle, file]) => scenarios(Path::new(bundle), Path::new(file), json),
+        (Some("-h" | "--help" | "help"), []) => Ok(format!("{USAGE}\n")),
+        (None, _) => Err(usage("no command given")),
+        (Some(c @ ("compute" | "diff" | "explain" | "scenarios")), _) => {
+            Err(usage(&format!("wrong number of arguments to {c}")))
+        }
+        (Some(c), _) => Err(usage(&format!("unknown command {c:?}"))),
+    }
+}
+
+fn compute(path: &Path, json: bool) -> Result<String, CliError> {
+    let computed = load::compute(&load::read_bundle(path)?)?;
+    let combined = &computed.combined;
+    if json {
+        let states: serde_json::Map<_, _> = combined
+            .states
+            .iter()
+            .map(|(state, ledger)| (state.code().to_string(), json!(ledger)))
+            .collect();
+        return Ok(format!(
+            "{:#}\n",
+            json!({
+                "federal": combined.federal,
+                "states": states,
+                "skipped_states": computed.skipped_states,
+            })
+        ));
+    }
+    let mut out = ReturnReport::build(RULES, &computed.input, combined).to_markdown();
+    skipped_note(&mut out, &computed);
+    Ok(out)
+}
+
+fn skipped_note(out: &mut String, computed: &Computed) {
+    if !computed.skipped_states.is_empty() {
+        write!(
+            out,
+            "\nNo rules for {}; those state returns were not computed.\n",
+            computed.skipped_states.join(", ")
+        )
+        .unwrap();
+    }
+}
+
+fn diff(prior_path: &Path, current_path: &Path, json: bool) -> Result<String, CliError> {
+    let prior = load::compute(&load::read_bundle(prior_path)?)?;
+    let current = load::compute(&load::read_bundle(current_path)?)?;
+    let amount =
+        |c: &Computed, key: &Key| c.combined.federal.get(key).copied().unwrap_or(Usd::ZERO);
+    let changed: Vec<(Key, Usd, Usd)> = Key::ALL
+        .iter()
+        .map(|k| (*k, amount(&prior, k), amount(&current, k)))
+        .filter(|(_, a, b)| a != b)
+        .collect();
+    let causes = explain_refund_change(
+        YearReturn {
+            rules: RULES,
+            input: &prior.input,
+        },
+        YearReturn {
+            rules: RULES,
+            input: &current.input,
+        },
+    )
+    .map_err(CliError::Compute)?
+    .causes;
+    if json {
+        let lines: serde_json::Map<_, _> = changed
+            .iter()
+            .map(|(k, a, b)| (format!("{k:?}"), json!({"prior": a, "current": b})))
+            .collect();
+        let causes: Vec<_> = causes
+            .iter()
+            .map(|c| json!({"amount": c.amount, "text": c.text}))
+            .collect();
+        return Ok(format!(
+            "{:#}\n",
+            json!({"federal": lines, "causes": causes})
+        ));
+    }
+    if changed.is_empty() {
+        return Ok("No federal lines differ.\n".to_string());
+    }
+    let mut out = String::new();
+    for (key, a, b) in &changed {
+        writeln!(
+            out,
+            "{:<50} {:>14} -> {:>14} ({})",
+            key.label(),
+            a.to_string(),
+            b.to_string(),
+            signed(*b - *a)
+        )
+        .unwrap();
+    }
+    if !causes.is_empty() {
+        out.push_str("\nWhy the refund changed:\n");
+        for cause in &causes {
+            writeln!(out, "  {:>14}  {}", signed(cause.amount), cause.text).unwrap();
+        }
+    }
+    Ok(out)
+}
+
+fn signed(amount: Usd) -> String {
+    if amount > Usd::ZERO {
+        format!("+{amount}")
+    } else {
+        amount.to_string()
+    }
+}
+
+fn scenarios(bundle: &Path, file: &Path, json: bool) -> Result<String, CliError> {
+    let base = load::read_bundle(bundle)?.to_input();
+    let file: ScenarioFile = load::read(file)?;
+    let mut results = Vec::new();
+    for scenario in &file.scenario {
+        let years = projection::project(
+            RULES,
+            &base,
+            scenario.years,
+            &scenario.assumptions,
+            &scenario.events,
+        )
+        .map_err(CliError::Projection)?;
+        results.push((scenario, years));
+    }
+    if json {
+        let doc: Vec<_> = results
+            .iter()
+            .map(|(s, years)| json!({"name": s.name, "years": years}))
+            .collect();
+        return Ok(format!("{:#}\n", json!(doc)));
+    }
+    let mut out = String::new();
+    for (scenario, years) in &results {
+        writeln!(out, "{}", scenario.name).unwrap();
+        writeln!(
+            out,
+            "  {:<6} {:>16} {:>16} {:>16} {:>16}",
+            "Year", "Wages", "AGI", "Total tax", "Refund (owed)"
+        )
+        .unwrap();
+        for year in years {
+            let line = |key| year.ledger.get(&key).copied().unwrap_or(Usd::ZERO);
+            writeln!(
+                out,
+                "  {:<6} {:>16} {:>16} {:>16} {:>16}",
+                year.year,
+                year.w2_wages.to_string(),
+                line(Key::AGI).to_string(),
+                line(Key::TotalTax).to_string(),
+                (line(Key::Refund) - line(Key::AmountOwed)).to_string(),
+            )
+            .unwrap();
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    use gideon_tax_form::y2025::federal::{self, IrsW2, ReturnBundle, SourceForms};
+
+    /// Writes a bundle with one W-2 to a temporary file.
+    fn bundle_file(name: &str, wages: i64, withheld: i64) -> PathBuf {
+        let bundle = ReturnBundle {
+            source_forms: SourceForms {
+                w2: vec![IrsW2 {
+                    wages_tips_other_comp: Usd::from_dollars(wages),
+                    federal_income_tax_withheld: Usd::from_dollars(withheld),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let path =
+            std::env::temp_dir().join(format!("honest-tax-cli-{}-{name}", std::process::id()));
+        fs::write(&path, federal::export(&bundle)).unwrap();
+        path
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn compute_prints_a_summary_or_json() {
+        let path = bundle_file("compute.json", 60_000, 6_000);
+        let path = path.to_str().unwrap();
+        let summary = run(&args(&["compute", path])).unwrap();
+        assert!(
+            summary.starts_with("# 2025 Tax Return Summary\n"),
+            "{summary}"
+        );
+        let doc: serde_json::Value =
+            serde_json::from_str(&run(&args(&["compute", "--json", path])).unwrap()).unwrap();
+        assert_eq!(doc["federal"]["AGI"], 6_000_000);
+        assert_eq!(doc["states"], json!({}));
+    }
+
+    #[test]
+    fn diff_lists_changed_lines_and_causes() {
+        let prior = bundle_file("prior.json", 60_000, 6_000);
+        let current = bundle_file("current.json", 65_000, 6_000);
+        let out = run(&args(&[
+            "diff",
+            prior.to_str().unwrap(),
+            current.to_str().unwrap(),
+        ]))
+        .unwrap();
+        assert!(
+            out.starts_with("Total income") && out.contains("(+$5000.00)"),
+            "{out}"
+        );
+        assert!(out.contains("Why the refund changed:"), "{out}");
+    }
+
+    #[test]
+    fn scenarios_project_each_named_scenario() {
+        let bundle = bundle_file("scenarios.json", 60_000, 6_000);
+        let file = std::env::temp_dir().join(format!(
+            "honest-tax-cli-{}-scenarios.toml",
+            std::process::id()
+        ));
+        fs::write(
+            &file,
+            "[[scenario]]\nname = \"flat\"\n\n[[scenario]]\nname = \"raise\"\nyears = 1\n\
+             [scenario.assumptions]\nwage_growth = 1000\n",
+        )
+        .unwrap();
+        let out = run(&args(&[
+            "scenarios",
+            bundle.to_str().unwrap(),
+            file.to_str().unwrap(),
+        ]))
+        .unwrap();
+        assert!(out.starts_with("flat\n"), "{out}");
+        assert!(out.contains("raise\n"), "{out}");
+        assert!(out.contains("2026          $66000.00"), "{out}");
+    }
+
+    #[test]
+    fn reports_usage_errors() {
+        assert!(matches!(run(&args(&[])), Err(CliError::Usage(_))));
+        assert!(matches!(
+            run(&args(&["explain", "AGI"])),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            run(&args(&["explain", "line 99", "x.json"])),
+            Err(CliError::UnknownKey(_))
+        ));
+    }
+}